@@ -1,6 +1,6 @@
 use codspeed_criterion_compat::{Criterion, criterion_group, criterion_main};
 use pathfinding::directed::edmonds_karp::{
-    DenseCapacity, EKFlows, EdmondsKarp, SparseCapacity, edmonds_karp,
+    AdjacencyCapacity, DenseCapacity, EKFlows, EdmondsKarp, SparseCapacity, edmonds_karp,
 };
 use std::collections::HashMap;
 
@@ -63,5 +63,49 @@ fn wikipedia_example_sparse(c: &mut Criterion) {
     wikipedia_example::<SparseCapacity<_>>(c, "wikipedia_example_sparse");
 }
 
-criterion_group!(benches, wikipedia_example_dense, wikipedia_example_sparse,);
+fn wikipedia_example_adjacency(c: &mut Criterion) {
+    wikipedia_example::<AdjacencyCapacity<_>>(c, "wikipedia_example_adjacency");
+}
+
+// A layered graph of 2000 nodes in which every node is only linked to a few
+// nodes of the next layer.
+fn large_sparse<EK: EdmondsKarp<i32>>(c: &mut Criterion, id: &str) {
+    const LAYERS: usize = 40;
+    const WIDTH: usize = 50;
+    let vertices = (0..LAYERS * WIDTH + 2).collect::<Vec<_>>();
+    let (source, sink) = (LAYERS * WIDTH, LAYERS * WIDTH + 1);
+    let mut edges = Vec::new();
+    for i in 0..WIDTH {
+        edges.push(((source, i), 10));
+        edges.push((((LAYERS - 1) * WIDTH + i, sink), 10));
+    }
+    for layer in 0..LAYERS - 1 {
+        for i in 0..WIDTH {
+            for j in [i, (i + 1) % WIDTH, (i + 7) % WIDTH] {
+                let capacity = i32::try_from((i * 31 + j * 17 + layer) % 9 + 1).unwrap();
+                edges.push(((layer * WIDTH + i, (layer + 1) * WIDTH + j), capacity));
+            }
+        }
+    }
+    c.bench_function(id, |b| {
+        b.iter(|| edmonds_karp::<_, _, _, EK>(&vertices, &source, &sink, edges.clone()));
+    });
+}
+
+fn large_sparse_sparse(c: &mut Criterion) {
+    large_sparse::<SparseCapacity<_>>(c, "large_sparse_sparse");
+}
+
+fn large_sparse_adjacency(c: &mut Criterion) {
+    large_sparse::<AdjacencyCapacity<_>>(c, "large_sparse_adjacency");
+}
+
+criterion_group!(
+    benches,
+    wikipedia_example_dense,
+    wikipedia_example_sparse,
+    wikipedia_example_adjacency,
+    large_sparse_sparse,
+    large_sparse_adjacency,
+);
 criterion_main!(benches);
@@ -0,0 +1,53 @@
+use codspeed_criterion_compat::{Criterion, criterion_group, criterion_main};
+use pathfinding::prelude::{astar, astar_indexed, dijkstra, dijkstra_indexed, dijkstra_radix};
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 400;
+
+// A dense graph with random costs, in which the cost of reaching a node is
+// improved many times during the search.
+fn build_network() -> Vec<Vec<(usize, u32)>> {
+    let mut rng = XorShiftRng::from_seed([
+        3, 42, 93, 129, 1, 85, 72, 42, 84, 23, 95, 212, 253, 10, 4, 2,
+    ]);
+    (0..SIZE)
+        .map(|_| {
+            let neighbours = (0..SIZE)
+                .filter(|_| rng.random_ratio(1, 4))
+                .collect::<Vec<_>>();
+            neighbours
+                .into_iter()
+                .map(|b| (b, rng.random_range(1..10_000)))
+                .collect()
+        })
+        .collect()
+}
+
+fn dense_dijkstra(c: &mut Criterion) {
+    let network = build_network();
+    let successors = |&n: &usize| network[n].iter().copied();
+    c.bench_function("dense_dijkstra", |b| {
+        b.iter(|| dijkstra(&0, successors, |_| false));
+    });
+    c.bench_function("dense_dijkstra_indexed", |b| {
+        b.iter(|| dijkstra_indexed(&0, successors, |_| false));
+    });
+    c.bench_function("dense_dijkstra_radix", |b| {
+        b.iter(|| dijkstra_radix(&0, successors, |_| false));
+    });
+}
+
+fn dense_astar(c: &mut Criterion) {
+    let network = build_network();
+    let successors = |&n: &usize| network[n].iter().copied();
+    c.bench_function("dense_astar", |b| {
+        b.iter(|| astar(&0, successors, |_| 0, |&n| n == SIZE - 1));
+    });
+    c.bench_function("dense_astar_indexed", |b| {
+        b.iter(|| astar_indexed(&0, successors, |_| 0, |&n| n == SIZE - 1));
+    });
+}
+
+criterion_group!(benches, dense_dijkstra, dense_astar);
+criterion_main!(benches);
@@ -0,0 +1,156 @@
+//! Exclude some nodes from any search by filtering the successors function.
+//!
+//! [`avoiding`] and [`avoiding_weighted`] wrap a successors function so that
+//! nodes rejected by an [`Avoid`] filter are never returned. The wrapped function
+//! can then be given to any search algorithm of this crate, which will route
+//! around the avoided nodes.
+
+use indexmap::IndexSet;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+/// Filter indicating whether a node must be avoided.
+///
+/// This trait is implemented for sets of forbidden nodes (`HashSet`, `BTreeSet`,
+/// `IndexSet`, slices and vectors) and for predicates `Fn(&N) -> bool` returning
+/// `true` for forbidden nodes.
+pub trait Avoid<N> {
+    /// Return `true` if `node` must be avoided.
+    fn avoids(&self, node: &N) -> bool;
+}
+
+impl<N, F> Avoid<N> for F
+where
+    F: Fn(&N) -> bool,
+{
+    fn avoids(&self, node: &N) -> bool {
+        self(node)
+    }
+}
+
+impl<N: Eq + Hash, S: BuildHasher> Avoid<N> for HashSet<N, S> {
+    fn avoids(&self, node: &N) -> bool {
+        self.contains(node)
+    }
+}
+
+impl<N: Eq + Hash, S: BuildHasher> Avoid<N> for IndexSet<N, S> {
+    fn avoids(&self, node: &N) -> bool {
+        self.contains(node)
+    }
+}
+
+impl<N: Ord> Avoid<N> for BTreeSet<N> {
+    fn avoids(&self, node: &N) -> bool {
+        self.contains(node)
+    }
+}
+
+impl<N: PartialEq> Avoid<N> for [N] {
+    fn avoids(&self, node: &N) -> bool {
+        self.contains(node)
+    }
+}
+
+impl<N: PartialEq> Avoid<N> for Vec<N> {
+    fn avoids(&self, node: &N) -> bool {
+        self.contains(node)
+    }
+}
+
+/// Wrap a successors function returning nodes so that the nodes rejected
+/// by `avoid` are skipped.
+///
+/// The start node of a search is not filtered and will be used even if `avoid`
+/// rejects it.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{avoiding, bfs};
+/// use std::collections::HashSet;
+///
+/// let successors = |&(x, y): &(i32, i32)| {
+///     vec![(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| (0..4).contains(&x) && (0..4).contains(&y))
+/// };
+/// // Wall with a gap at the top.
+/// let blocked = HashSet::from([(1, 0), (1, 1), (1, 2)]);
+/// let path = bfs(&(0, 0), avoiding(&blocked, successors), |&p| p == (2, 0)).unwrap();
+/// assert_eq!(path.len(), 9);
+/// assert!(path.iter().all(|p| !blocked.contains(p)));
+/// ```
+pub fn avoiding<'a, N, FN, IN, A>(
+    avoid: &'a A,
+    mut successors: FN,
+) -> impl FnMut(&N) -> AvoidingSuccessors<'a, IN::IntoIter, N, A>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    A: Avoid<N> + ?Sized,
+{
+    move |node| AvoidingSuccessors {
+        inner: successors(node).into_iter(),
+        avoid,
+        node: |n| n,
+    }
+}
+
+/// Wrap a successors function returning nodes along with a cost so that the
+/// nodes rejected by `avoid` are skipped.
+///
+/// The start node of a search is not filtered and will be used even if `avoid`
+/// rejects it.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{avoiding_weighted, dijkstra};
+///
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n * 2, 1)];
+/// let closed = |&n: &u32| n % 4 == 0;
+/// let (path, cost) = dijkstra(&1, avoiding_weighted(&closed, successors), |&n| n == 7).unwrap();
+/// assert_eq!(path, vec![1, 2, 3, 6, 7]);
+/// assert_eq!(cost, 4);
+/// ```
+pub fn avoiding_weighted<'a, N, C, FN, IN, A>(
+    avoid: &'a A,
+    mut successors: FN,
+) -> impl FnMut(&N) -> AvoidingSuccessors<'a, IN::IntoIter, N, A>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    A: Avoid<N> + ?Sized,
+{
+    move |node| AvoidingSuccessors {
+        inner: successors(node).into_iter(),
+        avoid,
+        node: |(n, _)| n,
+    }
+}
+
+/// Iterator over the successors which are not avoided, returned by the
+/// functions built by [`avoiding`] and [`avoiding_weighted`].
+pub struct AvoidingSuccessors<'a, I: Iterator, N, A: ?Sized> {
+    inner: I,
+    avoid: &'a A,
+    node: fn(&I::Item) -> &N,
+}
+
+impl<I, N, A> Iterator for AvoidingSuccessors<'_, I, N, A>
+where
+    I: Iterator,
+    A: Avoid<N> + ?Sized,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { inner, avoid, node } = self;
+        inner.find(|item| !avoid.avoids(node(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
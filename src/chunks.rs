@@ -0,0 +1,333 @@
+//! Pathfinding in a world made of grid chunks linked by portals, which can be
+//! loaded and unloaded at runtime.
+
+use crate::directed::dijkstra::dijkstra_edges;
+use crate::grid::Grid;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+type Vertex = (usize, usize);
+
+/// Portals leaving every location of a chunk, with the location they lead to and
+/// their cost.
+type Portals<K> = FxHashMap<Vertex, Vec<((K, Vertex), usize)>>;
+
+/// A world made of [`Grid`] chunks identified by keys of type `K`, in which locations
+/// are given as a chunk key and a vertex of this chunk.
+///
+/// Chunks are linked by portals, each of them joining a location of a chunk to a
+/// location of another chunk (or of the same chunk) in both directions at a given cost.
+/// Portals can be declared at any time, including before the chunks they link are
+/// loaded, and are only used when both of their locations belong to loaded chunks.
+///
+/// When a chunk is loaded, the shortest paths between its portal locations are
+/// precomputed. A query then only has to link the start and goal locations to the portal
+/// locations of their chunks and search the graph made of portal locations, before
+/// refining the result into a path made of adjacent vertices. Chunks which are not
+/// loaded cannot be crossed, and unloading a chunk frees its grid and its precomputed
+/// paths.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{ChunkedWorld, Grid};
+///
+/// let mut chunk = Grid::new(8, 8);
+/// chunk.fill();
+/// let mut world = ChunkedWorld::new();
+/// world.load_chunk((0, 0), chunk.clone());
+/// world.load_chunk((1, 0), chunk.clone());
+/// // The chunks are side by side, and their facing borders are linked at row 3.
+/// world.add_portal(&((0, 0), (7, 3)), &((1, 0), (0, 3)), 1);
+///
+/// let (path, cost) = world.find_path(&((0, 0), (0, 3)), &((1, 0), (7, 3))).unwrap();
+/// assert_eq!(cost, 15);
+/// assert_eq!(path.len(), 16);
+/// assert_eq!(path[8], ((1, 0), (0, 3)));
+///
+/// // Once the second chunk is unloaded, it cannot be reached anymore.
+/// world.unload_chunk(&(1, 0));
+/// assert_eq!(world.find_path(&((0, 0), (0, 3)), &((1, 0), (7, 3))), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChunkedWorld<K> {
+    chunks: FxHashMap<K, Chunk>,
+    portals: FxHashMap<K, Portals<K>>,
+}
+
+#[derive(Clone, Debug)]
+struct Chunk {
+    grid: Grid,
+    /// Cost of the shortest paths leaving every portal location to the other ones.
+    edges: FxHashMap<Vertex, Vec<(Vertex, usize)>>,
+    paths: FxHashMap<(Vertex, Vertex), Vec<Vertex>>,
+}
+
+/// The kind of link followed between two locations of a query.
+#[derive(Clone, Copy)]
+enum Link {
+    Portal,
+    Walk,
+    FromStart,
+    ToGoal,
+}
+
+impl<K> Default for ChunkedWorld<K> {
+    fn default() -> Self {
+        Self {
+            chunks: FxHashMap::default(),
+            portals: FxHashMap::default(),
+        }
+    }
+}
+
+impl<K> ChunkedWorld<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Create a world without any chunk or portal.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a chunk, replacing and returning the grid previously loaded with the
+    /// same key if any.
+    pub fn load_chunk(&mut self, key: K, grid: Grid) -> Option<Grid> {
+        let chunk = self.link_portals(&key, grid);
+        self.chunks.insert(key, chunk).map(|chunk| chunk.grid)
+    }
+
+    /// Unload a chunk and return its grid, or `None` if it was not loaded. The portals
+    /// leading to this chunk are kept, and will be used again if it is reloaded.
+    pub fn unload_chunk(&mut self, key: &K) -> Option<Grid> {
+        self.chunks.remove(key).map(|chunk| chunk.grid)
+    }
+
+    /// Check whether a chunk is loaded.
+    #[must_use]
+    pub fn is_loaded(&self, key: &K) -> bool {
+        self.chunks.contains_key(key)
+    }
+
+    /// The grid of a loaded chunk.
+    #[must_use]
+    pub fn chunk(&self, key: &K) -> Option<&Grid> {
+        self.chunks.get(key).map(|chunk| &chunk.grid)
+    }
+
+    /// Keys of the loaded chunks, in arbitrary order.
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = &K> {
+        self.chunks.keys()
+    }
+
+    /// Add a portal linking locations `a` and `b` in both directions, or change the
+    /// cost of the existing one.
+    pub fn add_portal(&mut self, a: &(K, Vertex), b: &(K, Vertex), cost: usize) {
+        for (from, to) in [(a, b), (b, a)] {
+            let portals = self
+                .portals
+                .entry(from.0.clone())
+                .or_default()
+                .entry(from.1)
+                .or_default();
+            match portals.iter_mut().find(|(target, _)| target == to) {
+                Some(portal) => portal.1 = cost,
+                None => portals.push((to.clone(), cost)),
+            }
+        }
+        self.relink(&a.0);
+        if b.0 != a.0 {
+            self.relink(&b.0);
+        }
+    }
+
+    /// Remove the portal linking locations `a` and `b`. Return `true` if it existed.
+    pub fn remove_portal(&mut self, a: &(K, Vertex), b: &(K, Vertex)) -> bool {
+        let mut removed = false;
+        for (from, to) in [(a, b), (b, a)] {
+            let Some(chunk_portals) = self.portals.get_mut(&from.0) else {
+                continue;
+            };
+            if let Some(portals) = chunk_portals.get_mut(&from.1) {
+                let len = portals.len();
+                portals.retain(|(target, _)| target != to);
+                removed |= portals.len() != len;
+                if portals.is_empty() {
+                    chunk_portals.remove(&from.1);
+                }
+            }
+            if chunk_portals.is_empty() {
+                self.portals.remove(&from.0);
+            }
+        }
+        if removed {
+            self.relink(&a.0);
+            if b.0 != a.0 {
+                self.relink(&b.0);
+            }
+        }
+        removed
+    }
+
+    /// Path from `start` to `goal` made of adjacent vertices of a same chunk and of
+    /// portals between chunks, along with its cost.
+    ///
+    /// `None` is returned if `start` or `goal` is not a vertex of a loaded chunk, or if
+    /// no path exists between them through the loaded chunks.
+    #[must_use]
+    pub fn find_path(
+        &self,
+        start: &(K, Vertex),
+        goal: &(K, Vertex),
+    ) -> Option<(Vec<(K, Vertex)>, usize)> {
+        if !self.has_location(start) || !self.has_location(goal) {
+            return None;
+        }
+        let (start_grid, goal_grid) = (&self.chunks[&start.0].grid, &self.chunks[&goal.0].grid);
+        // Temporary paths linking the start and goal locations to the portal locations
+        // of their chunks, or directly to each other if they share a chunk.
+        let mut targets = self.portal_vertices(&start.0, start_grid);
+        if start.0 == goal.0 {
+            targets.insert(goal.1);
+        }
+        let from_start = shortest_paths(start_grid, start.1, &targets);
+        let targets = self.portal_vertices(&goal.0, goal_grid);
+        let to_goal = shortest_paths(goal_grid, goal.1, &targets)
+            .into_iter()
+            .map(|(v, path)| (v, path.into_iter().rev().collect::<Vec<_>>()))
+            .collect::<FxHashMap<_, _>>();
+        let start_links = from_start
+            .iter()
+            .map(|(&v, path)| ((start.0.clone(), v), Link::FromStart, path.len() - 1))
+            .collect::<Vec<_>>();
+        let (steps, cost) = dijkstra_edges(
+            start,
+            |n| {
+                let mut successors = self.links(n);
+                if n == start {
+                    successors.extend_from_slice(&start_links);
+                }
+                if let Some(path) = to_goal.get(&n.1).filter(|_| n.0 == goal.0) {
+                    successors.push((goal.clone(), Link::ToGoal, path.len() - 1));
+                }
+                successors
+            },
+            |n| n == goal,
+        )?;
+        let mut path = vec![start.clone()];
+        let mut from = start.1;
+        for (location, link, _) in steps {
+            let walked = match link {
+                Link::Portal => None,
+                Link::Walk => self.chunks[&location.0].paths.get(&(from, location.1)),
+                Link::FromStart => from_start.get(&location.1),
+                Link::ToGoal => to_goal.get(&from),
+            };
+            match walked {
+                Some(walked) => path.extend(walked[1..].iter().map(|&v| (location.0.clone(), v))),
+                None => path.push(location.clone()),
+            }
+            from = location.1;
+        }
+        Some((path, cost))
+    }
+
+    fn has_location(&self, location: &(K, Vertex)) -> bool {
+        self.chunk(&location.0)
+            .is_some_and(|grid| grid.has_vertex(location.1))
+    }
+
+    /// Precomputed paths to the other portal locations of the chunk, and portals
+    /// leading to loaded chunks, leaving a location.
+    fn links(&self, location: &(K, Vertex)) -> Vec<((K, Vertex), Link, usize)> {
+        let (key, vertex) = location;
+        let walks = self
+            .chunks
+            .get(key)
+            .and_then(|chunk| chunk.edges.get(vertex))
+            .into_iter()
+            .flatten()
+            .map(|&(v, cost)| ((key.clone(), v), Link::Walk, cost));
+        let portals = self
+            .portals
+            .get(key)
+            .and_then(|portals| portals.get(vertex))
+            .into_iter()
+            .flatten()
+            .filter(|(to, _)| self.has_location(to))
+            .map(|(to, cost)| (to.clone(), Link::Portal, *cost));
+        walks.chain(portals).collect()
+    }
+
+    /// Locations of a chunk which are the end of a portal and a vertex of its grid.
+    fn portal_vertices(&self, key: &K, grid: &Grid) -> FxHashSet<Vertex> {
+        self.portals
+            .get(key)
+            .into_iter()
+            .flat_map(FxHashMap::keys)
+            .copied()
+            .filter(|&v| grid.has_vertex(v))
+            .collect()
+    }
+
+    /// Build a chunk made of `grid`, along with the shortest paths between its portal
+    /// locations.
+    fn link_portals(&self, key: &K, grid: Grid) -> Chunk {
+        let vertices = self.portal_vertices(key, &grid);
+        let mut edges = FxHashMap::<_, Vec<_>>::default();
+        let mut paths = FxHashMap::default();
+        for &from in &vertices {
+            for (to, path) in shortest_paths(&grid, from, &vertices) {
+                if to != from {
+                    edges.entry(from).or_default().push((to, path.len() - 1));
+                    paths.insert((from, to), path);
+                }
+            }
+        }
+        Chunk { grid, edges, paths }
+    }
+
+    /// Recompute the shortest paths between the portal locations of a chunk after its
+    /// portals have changed, if it is loaded.
+    fn relink(&mut self, key: &K) {
+        if let Some(chunk) = self.chunks.remove(key) {
+            let chunk = self.link_portals(key, chunk.grid);
+            self.chunks.insert(key.clone(), chunk);
+        }
+    }
+}
+
+/// Shortest paths inside `grid` from `from` to the vertices of `targets` it can reach,
+/// found with a breadth-first search.
+fn shortest_paths(
+    grid: &Grid,
+    from: Vertex,
+    targets: &FxHashSet<Vertex>,
+) -> FxHashMap<Vertex, Vec<Vertex>> {
+    let mut parents = FxHashMap::default();
+    parents.insert(from, from);
+    let mut to_see = VecDeque::from([from]);
+    while let Some(v) = to_see.pop_front() {
+        for n in grid.neighbours(v) {
+            if let Entry::Vacant(e) = parents.entry(n) {
+                e.insert(v);
+                to_see.push_back(n);
+            }
+        }
+    }
+    targets
+        .iter()
+        .filter(|t| parents.contains_key(t))
+        .map(|&t| {
+            let mut path = vec![t];
+            while *path.last().unwrap() != from {
+                path.push(parents[path.last().unwrap()]);
+            }
+            path.reverse();
+            (t, path)
+        })
+        .collect()
+}
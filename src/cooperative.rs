@@ -0,0 +1,176 @@
+//! Cooperative pathfinding of several agents on a [`Grid`], using a space-time
+//! [reservation table](ReservationTable) and windowed hierarchical cooperative A*
+//! ([WHCA*](https://www.davidsilver.uk/wp-content/uploads/2020/03/coop-path-AIWisdom.pdf)).
+
+use crate::directed::astar::astar;
+use crate::grid::Grid;
+use rustc_hash::FxHashMap;
+
+type Vertex = (usize, usize);
+
+/// A table recording which agent occupies which vertex at which time step, as well
+/// as which edges are being followed between two consecutive time steps.
+///
+/// Agents are identified by a `usize` chosen by the caller. Reservations made by an
+/// agent never prevent the same agent from moving, so that an agent can plan again
+/// without having to release its previous path first.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::ReservationTable;
+///
+/// let mut table = ReservationTable::new();
+/// table.reserve_path(0, &[(0, 0), (1, 0), (2, 0)], 0);
+/// assert!(table.is_reserved((1, 0), 1, 1));
+/// assert!(!table.is_reserved((1, 0), 1, 0));
+/// // Agent 1 cannot swap places with agent 0 between times 0 and 1.
+/// assert!(!table.can_move((1, 0), (0, 0), 0, 1));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReservationTable {
+    vertices: FxHashMap<(Vertex, usize), usize>,
+    edges: FxHashMap<(Vertex, Vertex, usize), usize>,
+}
+
+impl ReservationTable {
+    /// Create an empty reservation table.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserve `vertex` at time `time` for `agent`. Return `false`, without reserving
+    /// anything, if the vertex is already reserved by another agent at this time.
+    pub fn reserve(&mut self, agent: usize, vertex: Vertex, time: usize) -> bool {
+        let owner = self.vertices.entry((vertex, time)).or_insert(agent);
+        *owner == agent
+    }
+
+    /// Reserve the vertices of `path` for `agent`, the first one at time `start_time` and
+    /// every following one at the next time step, along with the edges between them.
+    /// Reservations already made by other agents are left untouched.
+    pub fn reserve_path(&mut self, agent: usize, path: &[Vertex], start_time: usize) {
+        for (i, &vertex) in path.iter().enumerate() {
+            self.reserve(agent, vertex, start_time + i);
+        }
+        for (i, w) in path.windows(2).enumerate() {
+            if w[0] != w[1] {
+                self.edges
+                    .entry((w[0], w[1], start_time + i))
+                    .or_insert(agent);
+            }
+        }
+    }
+
+    /// Remove all the reservations made by `agent`.
+    pub fn release(&mut self, agent: usize) {
+        self.vertices.retain(|_, owner| *owner != agent);
+        self.edges.retain(|_, owner| *owner != agent);
+    }
+
+    /// Remove all the reservations.
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.edges.clear();
+    }
+
+    /// Check whether `vertex` is reserved at time `time` by an agent other than `agent`.
+    #[must_use]
+    pub fn is_reserved(&self, vertex: Vertex, time: usize, agent: usize) -> bool {
+        self.vertices
+            .get(&(vertex, time))
+            .is_some_and(|&owner| owner != agent)
+    }
+
+    /// Check whether `agent` can move from `from` at time `time` to `to` at time
+    /// `time + 1`, that is whether `to` is free at time `time + 1` and no other
+    /// agent moves from `to` to `from` at the same time.
+    #[must_use]
+    pub fn can_move(&self, from: Vertex, to: Vertex, time: usize, agent: usize) -> bool {
+        !self.is_reserved(to, time + 1, agent)
+            && self
+                .edges
+                .get(&(to, from, time))
+                .is_none_or(|&owner| owner == agent)
+    }
+
+    /// Agent occupying `vertex` at time `time`, if any.
+    #[must_use]
+    pub fn owner(&self, vertex: Vertex, time: usize) -> Option<usize> {
+        self.vertices.get(&(vertex, time)).copied()
+    }
+}
+
+/// Plan the moves of `agent` from `start` towards `goal` on `grid`, avoiding the
+/// vertices and edges reserved by other agents in `table`.
+///
+/// The search takes place in space-time: at every time step, the agent either moves
+/// to a neighbouring vertex or waits where it is, each action costing 1. Only the
+/// first `window` time steps after `start_time` are planned. Once the time window is
+/// exhausted, the remaining distance to `goal` is estimated using
+/// [`Grid::distance`], ignoring other agents.
+///
+/// The returned path contains the position of the agent at every time step, starting
+/// with `start` at time `start_time`. It stops either when `goal` is reached, or after
+/// `window` moves, in which case the agent must plan again later from its last
+/// position. `None` is returned if `start` or `goal` is not a vertex of the grid, or if
+/// the agent is blocked by other agents.
+///
+/// To move several agents without collisions, plan for them one after the other,
+/// reserving every path in the table with [`ReservationTable::reserve_path`] before
+/// planning for the next agent. Replanning regularly, for example after half of the
+/// window has been followed, and changing the order of the agents, helps solving
+/// situations where an agent would otherwise be stuck.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{cooperative_astar, Grid, ReservationTable};
+///
+/// // A corridor where two agents must cross each other, with a niche at (3, 1).
+/// let mut grid = Grid::new(5, 2);
+/// for x in 0..5 {
+///     grid.add_vertex((x, 0));
+/// }
+/// grid.add_vertex((3, 1));
+///
+/// let mut table = ReservationTable::new();
+/// let first = cooperative_astar(&grid, &table, 0, (0, 0), (4, 0), 0, 10).unwrap();
+/// assert_eq!(first, vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]);
+/// table.reserve_path(0, &first, 0);
+///
+/// let second = cooperative_astar(&grid, &table, 1, (4, 0), (0, 0), 0, 10).unwrap();
+/// // The second agent waits in the niche while the first one goes by.
+/// assert_eq!(second.len(), 8);
+/// assert!(second.contains(&(3, 1)));
+/// ```
+#[must_use]
+pub fn cooperative_astar(
+    grid: &Grid,
+    table: &ReservationTable,
+    agent: usize,
+    start: Vertex,
+    goal: Vertex,
+    start_time: usize,
+    window: usize,
+) -> Option<Vec<Vertex>> {
+    if !grid.has_vertex(start) || !grid.has_vertex(goal) {
+        return None;
+    }
+    let horizon = start_time + window;
+    let (path, _) = astar(
+        &(start, start_time),
+        |&(vertex, time)| {
+            let mut moves = grid.neighbours(vertex);
+            moves.push(vertex);
+            moves
+                .into_iter()
+                .filter(move |&next| table.can_move(vertex, next, time, agent))
+                .map(move |next| ((next, time + 1), 1))
+        },
+        |&(vertex, _)| grid.distance(vertex, goal),
+        |&(vertex, time)| vertex == goal || time == horizon,
+    )?;
+    Some(path.into_iter().map(|(vertex, _)| vertex).collect())
+}
@@ -0,0 +1,364 @@
+//! Read graphs in the formats of the [DIMACS implementation
+//! challenges](http://www.diag.uniroma1.it/challenge9/format.shtml), used by many
+//! benchmark instances.
+//!
+//! Nodes are numbered from 1 as in the files, so vectors indexed by node have an unused
+//! entry at index 0. Lines starting with `c` are comments and are ignored, as well as
+//! empty lines.
+
+use std::io::BufRead;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error encountered while reading a DIMACS file.
+#[derive(Debug, Error)]
+pub enum DimacsError {
+    /// The input could not be read
+    #[error("cannot read input: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line is not valid for this format
+    #[error("line {0} is malformed")]
+    Syntax(usize),
+    /// The problem line is missing, duplicated, or of the wrong kind
+    #[error("line {line}: expected a `p {expected}` problem line")]
+    Problem {
+        /// Line number, starting from 1, or 0 if the problem line is missing.
+        line: usize,
+        /// Expected beginning of the problem line.
+        expected: &'static str,
+    },
+    /// The number of nodes declared by the problem line is too large to be represented
+    #[error("line {line} declares too many nodes ({nodes})")]
+    TooManyNodes {
+        /// Line number, starting from 1.
+        line: usize,
+        /// Number of nodes declared.
+        nodes: usize,
+    },
+    /// A line refers to a node outside of the range declared by the problem line
+    #[error("line {line} refers to invalid node {node}")]
+    InvalidNode {
+        /// Line number, starting from 1.
+        line: usize,
+        /// Invalid node.
+        node: usize,
+    },
+    /// The number of arcs does not match the problem line
+    #[error("expected {expected} arcs, found {found}")]
+    ArcCount {
+        /// Number of arcs declared in the problem line.
+        expected: usize,
+        /// Number of arcs found.
+        found: usize,
+    },
+    /// The source or the sink of a flow problem is missing
+    #[error("missing source or sink")]
+    MissingTerminal,
+    /// The source or the sink of a flow problem is designated twice, or they are the
+    /// same node
+    #[error("line {0} designates a terminal twice or the same node as source and sink")]
+    DuplicateTerminal(usize),
+}
+
+/// A directed graph read from a DIMACS shortest path (`.gr`) file by [`read_gr`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DimacsGraph<C> {
+    /// Number of nodes, which are numbered from 1 to `nodes`.
+    pub nodes: usize,
+    /// Arcs, given as source, destination and cost.
+    pub arcs: Vec<(usize, usize, C)>,
+}
+
+impl<C: Copy> DimacsGraph<C> {
+    /// Build the list of successors of every node along with the cost to reach them,
+    /// indexed by node. This can be used in a successors function such as
+    /// `|&n| successors[n].iter().copied()`.
+    #[must_use]
+    pub fn successors(&self) -> Vec<Vec<(usize, C)>> {
+        let mut successors = vec![Vec::new(); self.nodes + 1];
+        for &(from, to, cost) in &self.arcs {
+            successors[from].push((to, cost));
+        }
+        successors
+    }
+}
+
+/// A maximum flow problem read from a DIMACS (`.max`) file by [`read_max_flow`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DimacsFlow<C> {
+    /// Number of nodes, which are numbered from 1 to `nodes`.
+    pub nodes: usize,
+    /// Source of the flow.
+    pub source: usize,
+    /// Sink of the flow.
+    pub sink: usize,
+    /// Arcs, given as source, destination and capacity.
+    pub arcs: Vec<(usize, usize, C)>,
+}
+
+impl<C: Copy> DimacsFlow<C> {
+    /// The list of nodes, as expected by the flow algorithms such as
+    /// [`edmonds_karp`](crate::directed::edmonds_karp::edmonds_karp).
+    #[must_use]
+    pub fn vertices(&self) -> Vec<usize> {
+        (1..=self.nodes).collect()
+    }
+
+    /// The capacities of the arcs, as expected by the flow algorithms such as
+    /// [`edmonds_karp`](crate::directed::edmonds_karp::edmonds_karp).
+    pub fn capacities(&self) -> impl Iterator<Item = ((usize, usize), C)> + '_ {
+        self.arcs.iter().map(|&(from, to, c)| ((from, to), c))
+    }
+}
+
+/// Iterate over the meaningful lines of the input, along with their number, skipping
+/// comments and empty lines.
+fn records<R: BufRead>(input: R) -> impl Iterator<Item = Result<(usize, String), DimacsError>> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Ok(line) => {
+                let trimmed = line.trim();
+                (!trimmed.is_empty() && !trimmed.starts_with('c'))
+                    .then(|| Ok((i + 1, trimmed.to_string())))
+            }
+            Err(e) => Some(Err(e.into())),
+        })
+}
+
+/// Parse the fields of a line, which must all be of the same type and be present in
+/// the right number.
+fn fields<T: FromStr, const K: usize>(line: usize, fields: &[&str]) -> Result<[T; K], DimacsError> {
+    fields
+        .iter()
+        .map(|f| f.parse().map_err(|_| DimacsError::Syntax(line)))
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| DimacsError::Syntax(line))
+}
+
+/// Check that a vector of elements of type `T` indexed by node can hold `nodes` nodes,
+/// and return its length.
+fn node_vector_len<T>(line: usize, nodes: usize) -> Result<usize, DimacsError> {
+    nodes
+        .checked_add(1)
+        .filter(|&len| len <= isize::MAX.unsigned_abs() / size_of::<T>().max(1))
+        .ok_or(DimacsError::TooManyNodes { line, nodes })
+}
+
+/// Check that `node` lies between 1 and `nodes`.
+fn check_node(line: usize, node: usize, nodes: usize) -> Result<usize, DimacsError> {
+    if (1..=nodes).contains(&node) {
+        Ok(node)
+    } else {
+        Err(DimacsError::InvalidNode { line, node })
+    }
+}
+
+/// Read the arcs of a graph, whose problem line starts with `p {kind}`. Lines with
+/// other descriptors are given to `other`.
+#[expect(clippy::type_complexity)]
+fn read_arcs<R, C, F>(
+    input: R,
+    kind: &'static str,
+    mut other: F,
+) -> Result<(usize, Vec<(usize, usize, C)>), DimacsError>
+where
+    R: BufRead,
+    C: FromStr,
+    F: FnMut(usize, usize, &[&str]) -> Result<(), DimacsError>,
+{
+    let mut problem: Option<[usize; 2]> = None;
+    let mut arcs = Vec::new();
+    for record in records(input) {
+        let (line, record) = record?;
+        let tokens = record.split_whitespace().collect::<Vec<_>>();
+        match (tokens[0], problem) {
+            ("p", None) => {
+                if tokens.get(1) != Some(&kind) {
+                    return Err(DimacsError::Problem {
+                        line,
+                        expected: kind,
+                    });
+                }
+                let [nodes, arcs] = fields::<usize, 2>(line, &tokens[2..])?;
+                // The successors of every node must fit in a vector.
+                node_vector_len::<Vec<(usize, C)>>(line, nodes)?;
+                problem = Some([nodes, arcs]);
+            }
+            ("a", Some([nodes, _])) => {
+                let &[_, from, to, cost] = tokens.as_slice() else {
+                    return Err(DimacsError::Syntax(line));
+                };
+                let [from, to] = fields::<usize, 2>(line, &[from, to])?;
+                let [cost] = fields::<C, 1>(line, &[cost])?;
+                arcs.push((
+                    check_node(line, from, nodes)?,
+                    check_node(line, to, nodes)?,
+                    cost,
+                ));
+            }
+            (t, Some([nodes, _])) if t != "p" => other(line, nodes, &tokens)?,
+            _ => {
+                return Err(DimacsError::Problem {
+                    line,
+                    expected: kind,
+                });
+            }
+        }
+    }
+    let [nodes, expected] = problem.ok_or(DimacsError::Problem {
+        line: 0,
+        expected: kind,
+    })?;
+    if arcs.len() != expected {
+        return Err(DimacsError::ArcCount {
+            expected,
+            found: arcs.len(),
+        });
+    }
+    Ok((nodes, arcs))
+}
+
+/// Read a graph in the DIMACS shortest path format, used for example by the road
+/// networks of the 9th DIMACS implementation challenge.
+///
+/// The input contains a problem line `p sp <nodes> <arcs>`, followed by one
+/// `a <from> <to> <cost>` line for every arc. Costs can be of any type which can be
+/// parsed from a string.
+///
+/// # Errors
+///
+/// An error is returned if the input cannot be read or does not follow the format.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, read_gr};
+///
+/// let input = "c A small graph\np sp 3 3\na 1 2 4\na 2 3 1\na 1 3 7\n";
+/// let graph = read_gr::<_, u32>(input.as_bytes()).unwrap();
+/// let successors = graph.successors();
+/// let result = dijkstra(&1, |&n| successors[n].iter().copied(), |&n| n == 3);
+/// assert_eq!(result, Some((vec![1, 2, 3], 5)));
+/// ```
+pub fn read_gr<R: BufRead, C: FromStr>(input: R) -> Result<DimacsGraph<C>, DimacsError> {
+    let (nodes, arcs) = read_arcs(input, "sp", |line, _, _| Err(DimacsError::Syntax(line)))?;
+    Ok(DimacsGraph { nodes, arcs })
+}
+
+/// Read node coordinates in the DIMACS shortest path format (`.co` files), which can
+/// be used to build an heuristic for [`astar`](crate::directed::astar::astar).
+///
+/// The input contains a problem line `p aux sp co <nodes>`, followed by one
+/// `v <node> <x> <y>` line for every node. The coordinates are returned indexed by node,
+/// with `None` for nodes without coordinates.
+///
+/// # Errors
+///
+/// An error is returned if the input cannot be read or does not follow the format.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::read_coordinates;
+///
+/// let input = "p aux sp co 2\nv 1 -73530767 41085396\nv 2 -73530538 41086098\n";
+/// let coordinates = read_coordinates(input.as_bytes()).unwrap();
+/// assert_eq!(coordinates, vec![None, Some((-73530767, 41085396)), Some((-73530538, 41086098))]);
+/// ```
+pub fn read_coordinates<R: BufRead>(input: R) -> Result<Vec<Option<(i64, i64)>>, DimacsError> {
+    const EXPECTED: &str = "aux sp co";
+    let mut coordinates: Option<Vec<Option<(i64, i64)>>> = None;
+    for record in records(input) {
+        let (line, record) = record?;
+        let tokens = record.split_whitespace().collect::<Vec<_>>();
+        match (tokens[0], coordinates.as_mut()) {
+            ("p", None) => {
+                if tokens.get(1..4) != Some(&["aux", "sp", "co"]) {
+                    return Err(DimacsError::Problem {
+                        line,
+                        expected: EXPECTED,
+                    });
+                }
+                let [nodes] = fields::<usize, 1>(line, &tokens[4..])?;
+                let len = node_vector_len::<Option<(i64, i64)>>(line, nodes)?;
+                let mut vector = Vec::new();
+                vector
+                    .try_reserve_exact(len)
+                    .map_err(|_| DimacsError::TooManyNodes { line, nodes })?;
+                vector.resize(len, None);
+                coordinates = Some(vector);
+            }
+            ("v", Some(coordinates)) => {
+                let [node, x, y] = fields::<i64, 3>(line, &tokens[1..])?;
+                let node = usize::try_from(node)
+                    .map_err(|_| DimacsError::Syntax(line))
+                    .and_then(|node| check_node(line, node, coordinates.len() - 1))?;
+                coordinates[node] = Some((x, y));
+            }
+            (t, Some(_)) if t != "p" => return Err(DimacsError::Syntax(line)),
+            _ => {
+                return Err(DimacsError::Problem {
+                    line,
+                    expected: EXPECTED,
+                });
+            }
+        }
+    }
+    coordinates.ok_or(DimacsError::Problem {
+        line: 0,
+        expected: EXPECTED,
+    })
+}
+
+/// Read a maximum flow problem in the DIMACS format.
+///
+/// The input contains a problem line `p max <nodes> <arcs>`, the designation of the
+/// source with `n <node> s` and of the sink with `n <node> t`, which must be distinct
+/// nodes, and one `a <from> <to> <capacity>` line for every arc.
+///
+/// # Errors
+///
+/// An error is returned if the input cannot be read or does not follow the format.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{edmonds_karp_dense, read_max_flow};
+///
+/// let input = "p max 4 5\nn 1 s\nn 4 t\na 1 2 3\na 1 3 2\na 2 3 1\na 2 4 2\na 3 4 3\n";
+/// let problem = read_max_flow::<_, i32>(input.as_bytes()).unwrap();
+/// let (_, flow, _) = edmonds_karp_dense(
+///     &problem.vertices(),
+///     &problem.source,
+///     &problem.sink,
+///     problem.capacities(),
+/// );
+/// assert_eq!(flow, 5);
+/// ```
+pub fn read_max_flow<R: BufRead, C: FromStr>(input: R) -> Result<DimacsFlow<C>, DimacsError> {
+    let (mut source, mut sink) = (None, None);
+    let (nodes, arcs) = read_arcs(input, "max", |line, nodes, tokens| {
+        let terminal = match tokens {
+            ["n", _, "s"] => &mut source,
+            ["n", _, "t"] => &mut sink,
+            _ => return Err(DimacsError::Syntax(line)),
+        };
+        let node = tokens[1].parse().map_err(|_| DimacsError::Syntax(line))?;
+        if terminal.replace(check_node(line, node, nodes)?).is_some() || source == sink {
+            return Err(DimacsError::DuplicateTerminal(line));
+        }
+        Ok(())
+    })?;
+    match (source, sink) {
+        (Some(source), Some(sink)) => Ok(DimacsFlow {
+            nodes,
+            source,
+            sink,
+            arcs,
+        }),
+        _ => Err(DimacsError::MissingTerminal),
+    }
+}
@@ -0,0 +1,406 @@
+//! Plan and replan paths in a changing graph with [Anytime Dynamic
+//! A*](https://www.cs.cmu.edu/~maxim/files/ad_icaps05.pdf), which quickly finds
+//! suboptimal paths and improves them while repairing them after cost changes.
+
+use crate::FxIndexMap;
+use num_traits::{ToPrimitive, Zero};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Costs of a state, `None` standing for an infinite cost.
+#[derive(Clone, Debug)]
+struct State<C> {
+    /// Cost to the goal found so far.
+    g: Option<C>,
+    /// Cost to the goal through the best successor.
+    rhs: Option<C>,
+    /// Index of the best successor.
+    next: Option<usize>,
+}
+
+/// Priority of a state in the open list, made of the inflated estimate of the cost of
+/// the path going through it and of its cost to the goal.
+#[derive(Clone, Copy, Debug)]
+struct Key<C>(f64, Option<C>);
+
+impl<C: Ord> PartialEq for Key<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Key<C> {}
+
+impl<C: Ord> PartialOrd for Key<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Key<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .total_cmp(&other.0)
+            .then_with(|| cmp_costs(self.1.as_ref(), other.1.as_ref()))
+    }
+}
+
+/// Compare two costs, `None` standing for an infinite cost.
+fn cmp_costs<C: Ord>(a: Option<&C>, b: Option<&C>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn to_f64<C: ToPrimitive>(cost: Option<&C>) -> f64 {
+    cost.and_then(ToPrimitive::to_f64).unwrap_or(f64::INFINITY)
+}
+
+/// A planner using [Anytime Dynamic A*](https://www.cs.cmu.edu/~maxim/files/ad_icaps05.pdf)
+/// (AD*) to find a path from a start node to a goal node in a graph whose costs change
+/// over time, for example a robot discovering obstacles while it moves.
+///
+/// AD* combines the anytime behaviour of ARA*, which finds a first path quickly by
+/// inflating the heuristic by a suboptimality factor ε and then improves it as ε
+/// decreases, with the incremental repairs of D* Lite, which reuses the previous
+/// search when some edge costs change or the start node moves. Every call to
+/// [`plan`](Self::plan) returns a path whose cost is at most ε times the cost of a
+/// shortest path, reusing as much of the previous searches as possible.
+///
+/// The search goes backwards from the goal, so that the start node can move between
+/// plans. The graph is described by functions given to every method needing them, so
+/// that they can observe the changes made between two calls:
+///
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. This cost must be non-negative.
+/// - `predecessors` returns a list of nodes having an edge leading to a given node, along
+///   with the cost for moving from the predecessor to the node. It must be consistent
+///   with `successors`.
+/// - `heuristic` returns an approximation of the cost from the start node to a given
+///   node. It must be consistent, and never overestimate the actual cost.
+///
+/// When the costs of some edges change, [`update_costs`](Self::update_costs) must be
+/// called with the nodes these edges leave before the next plan. The suboptimality
+/// factor is typically decreased between plans while time allows it, and increased
+/// again when large changes happen.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::AnytimeDStar;
+///
+/// // A 20 × 20 grid where a wall grows over time.
+/// let mut walls = vec![(10, 0), (10, 1), (10, 2)];
+/// let neighbours = |walls: &[(i32, i32)], (x, y): (i32, i32)| {
+///     [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| (0..20).contains(&x) && (0..20).contains(&y))
+///         .filter(|p| !walls.contains(p))
+///         .map(|p| (p, 1))
+///         .collect::<Vec<_>>()
+/// };
+/// let heuristic = |&(x, y): &(i32, i32)| x.abs_diff(0) + y.abs_diff(0);
+///
+/// let mut planner = AnytimeDStar::new((0, 0), (19, 0), 3.0);
+/// let (_, cost) = planner
+///     .plan(|&n| neighbours(&walls, n), |&n| neighbours(&walls, n), heuristic)
+///     .expect("no path found");
+/// assert!(cost <= 3 * 25);
+///
+/// // Improve the path once more time is available.
+/// planner.set_suboptimality(1.0);
+/// let (_, cost) = planner
+///     .plan(|&n| neighbours(&walls, n), |&n| neighbours(&walls, n), heuristic)
+///     .expect("no path found");
+/// assert_eq!(cost, 25);
+///
+/// // The wall grows, and the edges leading to it disappear.
+/// walls.push((10, 3));
+/// planner.update_costs([(9, 3), (11, 3), (10, 4)], |&n| neighbours(&walls, n));
+/// let (path, cost) = planner
+///     .plan(|&n| neighbours(&walls, n), |&n| neighbours(&walls, n), heuristic)
+///     .expect("no path found");
+/// assert_eq!(cost, 27);
+/// assert!(!path.contains(&(10, 3)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AnytimeDStar<N, C> {
+    start: N,
+    goal: N,
+    epsilon: f64,
+    states: FxIndexMap<N, State<C>>,
+    open: FxHashMap<usize, Key<C>>,
+    heap: BinaryHeap<Reverse<(Key<C>, usize)>>,
+    closed: FxHashSet<usize>,
+    inconsistent: FxHashSet<usize>,
+}
+
+impl<N, C> AnytimeDStar<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + ToPrimitive,
+{
+    /// Create a planner looking for a path from `start` to `goal`, whose first plan will
+    /// be at most `epsilon` times as expensive as a shortest path.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `epsilon` is smaller than 1.
+    #[must_use]
+    pub fn new(start: N, goal: N, epsilon: f64) -> Self {
+        assert!(epsilon >= 1.0, "suboptimality factor must be at least 1");
+        let mut states = FxIndexMap::default();
+        states.insert(
+            goal.clone(),
+            State {
+                g: None,
+                rhs: Some(Zero::zero()),
+                next: None,
+            },
+        );
+        Self {
+            start,
+            goal,
+            epsilon,
+            states,
+            open: FxHashMap::default(),
+            heap: BinaryHeap::new(),
+            closed: FxHashSet::default(),
+            inconsistent: FxHashSet::from_iter([0]),
+        }
+    }
+
+    /// The suboptimality factor used by the next plan.
+    #[must_use]
+    pub const fn suboptimality(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// Set the suboptimality factor used by the next plans, which will be at most
+    /// `epsilon` times as expensive as a shortest path. A factor of 1 gives shortest
+    /// paths.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `epsilon` is smaller than 1.
+    pub fn set_suboptimality(&mut self, epsilon: f64) {
+        assert!(epsilon >= 1.0, "suboptimality factor must be at least 1");
+        self.epsilon = epsilon;
+    }
+
+    /// The node the planned paths start from.
+    pub const fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Move the start node, for example when a robot has followed a part of the
+    /// planned path. The heuristic given to the next plan must estimate the costs from
+    /// this new start node.
+    pub fn set_start(&mut self, start: N) {
+        self.start = start;
+    }
+
+    /// Take into account changes in the costs of the edges leaving the `changed` nodes,
+    /// including edges which have been added or removed. `successors` must describe the
+    /// updated graph.
+    pub fn update_costs<I, FN, IN>(&mut self, changed: I, mut successors: FN)
+    where
+        I: IntoIterator<Item = N>,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        for node in changed {
+            let index = self.insert(node);
+            if self.update_state(index, &mut successors) {
+                self.inconsistent.insert(index);
+            } else {
+                self.inconsistent.remove(&index);
+            }
+        }
+    }
+
+    /// Find a path from the start node to the goal, at most as expensive as the
+    /// suboptimality factor times the cost of a shortest path, along with its cost.
+    /// `None` is returned if the goal cannot be reached from the start node.
+    pub fn plan<FN, IN, FP, IP, FH>(
+        &mut self,
+        mut successors: FN,
+        mut predecessors: FP,
+        mut heuristic: FH,
+    ) -> Option<(Vec<N>, C)>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FP: FnMut(&N) -> IP,
+        IP: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+    {
+        // The keys depend on the suboptimality factor and the start node, which may
+        // have changed, so the open list is rebuilt with the inconsistent states.
+        let open = self
+            .open
+            .drain()
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        self.inconsistent.extend(open);
+        self.closed.clear();
+        self.heap.clear();
+        for index in std::mem::take(&mut self.inconsistent) {
+            if !self.is_consistent(index) {
+                self.push(index, &mut heuristic);
+            }
+        }
+        self.compute_or_improve_path(&mut successors, &mut predecessors, &mut heuristic);
+        self.path(&mut successors)
+    }
+
+    fn state(&self, node: &N) -> Option<&State<C>> {
+        self.states.get(node)
+    }
+
+    /// Index of the state of `node`, which is created with infinite costs if needed.
+    fn insert(&mut self, node: N) -> usize {
+        let entry = self.states.entry(node);
+        let index = entry.index();
+        entry.or_insert(State {
+            g: None,
+            rhs: None,
+            next: None,
+        });
+        index
+    }
+
+    fn key<FH>(&self, index: usize, heuristic: &mut FH) -> Key<C>
+    where
+        FH: FnMut(&N) -> C,
+    {
+        let (node, state) = self.states.get_index(index).unwrap(); // Cannot fail, the index is valid
+        let h = heuristic(node).to_f64().unwrap_or(f64::INFINITY);
+        if cmp_costs(state.g.as_ref(), state.rhs.as_ref()) == Ordering::Greater {
+            Key(to_f64(state.rhs.as_ref()) + self.epsilon * h, state.rhs)
+        } else {
+            Key(to_f64(state.g.as_ref()) + h, state.g)
+        }
+    }
+
+    fn push<FH>(&mut self, index: usize, heuristic: &mut FH)
+    where
+        FH: FnMut(&N) -> C,
+    {
+        let key = self.key(index, heuristic);
+        self.open.insert(index, key);
+        self.heap.push(Reverse((key, index)));
+    }
+
+    /// Recompute the cost to the goal through the best successor of a state and remove it
+    /// from the open list. Return `true` if the state is inconsistent.
+    fn update_state<FN, IN>(&mut self, index: usize, successors: &mut FN) -> bool
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        if index != 0 {
+            let node = self.states.get_index(index).unwrap().0; // Cannot fail, the index is valid
+            let mut best = None;
+            for (successor, cost) in successors(node) {
+                if let Some((i, _, state)) = self.states.get_full(&successor) {
+                    if let Some(g) = state.g {
+                        if best.is_none_or(|(_, c)| cost + g < c) {
+                            best = Some((i, cost + g));
+                        }
+                    }
+                }
+            }
+            let state = &mut self.states[index];
+            state.rhs = best.map(|(_, c)| c);
+            state.next = best.map(|(i, _)| i);
+        }
+        self.open.remove(&index);
+        !self.is_consistent(index)
+    }
+
+    fn is_consistent(&self, index: usize) -> bool {
+        let state = &self.states[index];
+        cmp_costs(state.g.as_ref(), state.rhs.as_ref()) == Ordering::Equal
+    }
+
+    fn compute_or_improve_path<FN, IN, FP, IP, FH>(
+        &mut self,
+        successors: &mut FN,
+        predecessors: &mut FP,
+        heuristic: &mut FH,
+    ) where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FP: FnMut(&N) -> IP,
+        IP: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+    {
+        while let Some(&Reverse((key, index))) = self.heap.peek() {
+            if self.open.get(&index) != Some(&key) {
+                self.heap.pop();
+                continue;
+            }
+            let start = self.states.get_index_of(&self.start);
+            let start_key = start.map_or(Key(f64::INFINITY, None), |s| self.key(s, heuristic));
+            if key >= start_key && start.is_some_and(|s| self.is_consistent(s)) {
+                break;
+            }
+            self.heap.pop();
+            self.open.remove(&index);
+            let (node, state) = self.states.get_index_mut(index).unwrap(); // Cannot fail, the index is valid
+            let node = node.clone();
+            let mut to_update = Vec::new();
+            if cmp_costs(state.g.as_ref(), state.rhs.as_ref()) == Ordering::Greater {
+                state.g = state.rhs;
+                self.closed.insert(index);
+            } else {
+                state.g = None;
+                to_update.push(index);
+            }
+            for (predecessor, _) in predecessors(&node) {
+                to_update.push(self.insert(predecessor));
+            }
+            for i in to_update {
+                if !self.update_state(i, successors) {
+                    self.inconsistent.remove(&i);
+                } else if self.closed.contains(&i) {
+                    self.inconsistent.insert(i);
+                } else {
+                    self.push(i, heuristic);
+                }
+            }
+        }
+    }
+
+    fn path<FN, IN>(&self, successors: &mut FN) -> Option<(Vec<N>, C)>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let mut node = &self.start;
+        self.state(node)?.g?;
+        let mut path = vec![node.clone()];
+        let mut cost = C::zero();
+        while *node != self.goal {
+            let next = self.states.get_index(self.state(node)?.next?)?.0;
+            cost = cost
+                + successors(node)
+                    .into_iter()
+                    .filter_map(|(n, c)| (n == *next).then_some(c))
+                    .min()?;
+            // Guard against a cycle of outdated successors.
+            if path.len() > self.states.len() {
+                return None;
+            }
+            path.push(next.clone());
+            node = next;
+        }
+        Some((path, cost))
+    }
+}
@@ -0,0 +1,187 @@
+//! Speed up repeated shortest path queries using [arc
+//! flags](https://en.wikipedia.org/wiki/Shortest_path_problem#Road_networks)
+//! computed on a partition of the graph.
+
+use super::dijkstra::{dijkstra, dijkstra_all};
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+
+/// An edge along with the set of regions it leads to optimally.
+#[derive(Clone, Debug)]
+struct Edge<N, C> {
+    to: N,
+    cost: C,
+    flags: Vec<u64>,
+}
+
+/// A graph whose edges have been annotated with arc flags.
+///
+/// The nodes are first partitioned into regions. Every edge then receives one flag
+/// per region, which is set if the edge lies on a shortest path towards a node of
+/// this region. A query towards a goal only follows the edges flagged for the region
+/// of the goal, which prunes most of the graph while still finding a shortest path.
+///
+/// Preprocessing runs one Dijkstra search (backwards) from every node having an incoming
+/// edge from another region, so partitions with short boundaries, such as grid cells
+/// for geographical graphs, give the best results. The graph must not change after
+/// the preprocessing.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, ArcFlags};
+///
+/// // A 20×20 grid where moving right or down costs 1, and left or up costs 2.
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [((x + 1, y), 1), ((x, y + 1), 1), ((x - 1, y), 2), ((x, y - 1), 2)]
+///         .into_iter()
+///         .filter(|&((x, y), _)| (0..20).contains(&x) && (0..20).contains(&y))
+/// };
+/// let nodes = (0..20).flat_map(|x| (0..20).map(move |y| (x, y))).collect::<Vec<_>>();
+/// // Split the grid into 4×4 regions of 5×5 nodes.
+/// let flags = ArcFlags::new(&nodes, |&(x, y)| (x / 5 * 4 + y / 5) as usize, successors);
+/// assert_eq!(flags.regions(), 16);
+///
+/// let result = flags.dijkstra(&(17, 2), &(3, 12));
+/// assert_eq!(result.map(|(_, cost)| cost), dijkstra(&(17, 2), successors, |&n| n == (3, 12)).map(|(_, cost)| cost));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArcFlags<N, C> {
+    regions: usize,
+    region: FxHashMap<N, usize>,
+    edges: FxHashMap<N, Vec<Edge<N, C>>>,
+}
+
+impl<N, C> ArcFlags<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    /// Partition the graph and compute the flags of every edge.
+    ///
+    /// - `nodes` contains the nodes of the graph.
+    /// - `partition` returns the region of a given node. Regions are numbered from 0,
+    ///   and should be reasonably compact as one flag per region is stored on every edge.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor. Successors not present in `nodes` are
+    ///   ignored.
+    pub fn new<FP, FN, IN>(nodes: &[N], mut partition: FP, mut successors: FN) -> Self
+    where
+        FP: FnMut(&N) -> usize,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let region = nodes
+            .iter()
+            .map(|n| (n.clone(), partition(n)))
+            .collect::<FxHashMap<_, _>>();
+        let regions = region.values().max().map_or(0, |&r| r + 1);
+        let words = regions.div_ceil(64);
+        let mut edges = FxHashMap::default();
+        let mut predecessors = FxHashMap::<N, Vec<(N, C)>>::default();
+        for node in nodes {
+            let out = successors(node)
+                .into_iter()
+                .filter(|(to, _)| region.contains_key(to))
+                .map(|(to, cost)| {
+                    predecessors
+                        .entry(to.clone())
+                        .or_default()
+                        .push((node.clone(), cost));
+                    Edge {
+                        to,
+                        cost,
+                        flags: vec![0; words],
+                    }
+                })
+                .collect::<Vec<_>>();
+            edges.insert(node.clone(), out);
+        }
+        let mut flags = Self {
+            regions,
+            region,
+            edges,
+        };
+        flags.compute_flags(&predecessors);
+        flags
+    }
+
+    fn compute_flags(&mut self, predecessors: &FxHashMap<N, Vec<(N, C)>>) {
+        let region = &self.region;
+        for (node, edges) in &mut self.edges {
+            // Edges within a region lead optimally to it from the inside.
+            let r = region[node];
+            for edge in edges.iter_mut().filter(|e| region[&e.to] == r) {
+                edge.flags[r / 64] |= 1 << (r % 64);
+            }
+        }
+        let boundary = predecessors
+            .iter()
+            .filter(|(node, preds)| preds.iter().any(|(p, _)| region[p] != region[*node]))
+            .map(|(node, _)| node.clone())
+            .collect::<Vec<_>>();
+        for target in boundary {
+            let r = self.region[&target];
+            let mut distances = dijkstra_all(&target, |n| {
+                predecessors.get(n).cloned().unwrap_or_default()
+            })
+            .into_iter()
+            .map(|(n, (_, c))| (n, c))
+            .collect::<FxHashMap<_, _>>();
+            distances.insert(target, Zero::zero());
+            for (node, &distance) in &distances {
+                for edge in &mut self.edges.get_mut(node).into_iter().flatten() {
+                    if distances
+                        .get(&edge.to)
+                        .is_some_and(|&d| d + edge.cost == distance)
+                    {
+                        edge.flags[r / 64] |= 1 << (r % 64);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Number of regions in the partition.
+    #[must_use]
+    pub const fn regions(&self) -> usize {
+        self.regions
+    }
+
+    /// Region of a node, or `None` if the node was not part of the graph.
+    #[must_use]
+    pub fn region(&self, node: &N) -> Option<usize> {
+        self.region.get(node).copied()
+    }
+
+    /// Successors of `node` lying on a shortest path towards some node of `region`,
+    /// along with the cost for moving to them.
+    pub fn successors_towards<'a>(
+        &'a self,
+        node: &N,
+        region: usize,
+    ) -> impl Iterator<Item = (N, C)> + use<'a, N, C> {
+        self.edges
+            .get(node)
+            .into_iter()
+            .flatten()
+            .filter(move |e| {
+                e.flags
+                    .get(region / 64)
+                    .is_some_and(|w| w & (1 << (region % 64)) != 0)
+            })
+            .map(|e| (e.to.clone(), e.cost))
+    }
+
+    /// Compute a shortest path from `start` to `goal` using the [Dijkstra search
+    /// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), only following
+    /// the edges flagged for the region of `goal`.
+    ///
+    /// The path is returned along with its total cost, or `None` if no path exists or
+    /// `goal` was not part of the graph.
+    pub fn dijkstra(&self, start: &N, goal: &N) -> Option<(Vec<N>, C)> {
+        let region = self.region(goal)?;
+        dijkstra(start, |n| self.successors_towards(n, region), |n| n == goal)
+    }
+}
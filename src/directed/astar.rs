@@ -3,15 +3,25 @@
 
 use indexmap::map::Entry::{Occupied, Vacant};
 use indexmap::IndexMap;
-use num_traits::Zero;
+use num_traits::{CheckedAdd, Zero};
 use rustc_hash::FxHasher;
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
+use std::cell::Cell;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
 use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 use std::iter::FusedIterator;
+use std::ops::{ControlFlow, Sub};
 
-use super::reverse_path;
-use crate::FxIndexMap;
+use super::search_context::SearchContext;
+use super::search_error::{SearchError, SearchLimits};
+use super::{
+    ChosenEdges, Frontier, NO_PARENT, Outcome, ParentIndex, from_parent, recycled_successors,
+    reverse_path, reverse_path_with_costs, to_parent,
+};
+use crate::indexed_heap::IndexedHeap;
+use crate::path::Path;
+use crate::radix_heap::{RadixHeap, RadixKey};
+use crate::{FxIndexMap, FxIndexSet, NodeRefs};
 
 /// Compute a shortest path using the [A* search
 /// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
@@ -20,7 +30,9 @@ use crate::FxIndexMap;
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `heuristic` returns an approximation of the cost from a given node to the goal. The
@@ -68,90 +80,875 @@ use crate::FxIndexMap;
 /// and is thus shorter.
 ///
 /// ```
-/// use pathfinding::prelude::astar;
+/// use pathfinding::prelude::astar;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let result = astar(&(1, 1),
+///                    |&(x, y)| vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///                                   (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)]
+///                               .into_iter().map(|p| (p, 1)),
+///                    |&(x, y)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3,
+///                    |&p| p == GOAL);
+/// assert_eq!(result.expect("no path found").1, 4);
+/// ```
+///
+/// Several starting nodes can be given at once, for example to reach a goal from the
+/// nearest of several entry points:
+///
+/// ```
+/// use pathfinding::NodeRefs;
+/// use pathfinding::prelude::astar;
+///
+/// let entries = [0, 20, 40];
+/// let result = astar(NodeRefs::from_iter(&entries),
+///                    |&n: &i32| [(n - 1, 1), (n + 1, 1)],
+///                    |&n| n.abs_diff(27),
+///                    |&n| n == 27);
+/// assert_eq!(result, Some(((20..=27).collect(), 7)));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn astar<'a, N, C, FN, IN, FH, FS, S>(
+    start: S,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    astar_with_hasher(
+        start,
+        successors,
+        heuristic,
+        success,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) using a custom hasher.
+///
+/// The shortest path starting from `start` up to a node for which `success` returns `true` is
+/// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
+/// is returned instead.
+///
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. This cost must be non-negative.
+/// - `heuristic` returns an approximation of the cost from a given node to the goal. The
+///   approximation must not be greater than the real cost, or a wrong shortest path may be returned.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// The returned path comprises both the start and end node.
+#[expect(clippy::missing_panics_doc)]
+pub fn astar_with_hasher<'a, N, C, FN, IN, FH, FS, S, H>(
+    start: S,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+    hasher: H,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
+{
+    let (parents, reached) = run_astar(start.into(), successors, heuristic, success, hasher);
+    reached.map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents.get_index(target).unwrap().1.1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), reusing the
+/// buffers of a [`SearchContext`] instead of allocating new ones.
+///
+/// This function behaves like [`astar`]. The context is cleared before the
+/// search starts.
+pub fn astar_with_context<N, C, FN, IN, FH, FS, S>(
+    context: &mut SearchContext<N, C, S>,
+    start: &N,
+    mut successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    S: BuildHasher,
+{
+    let parents = &mut context.parents;
+    run_astar_in(
+        start,
+        |node: &N, _| successors(node),
+        heuristic,
+        success,
+        parents,
+        &mut context.astar_heap,
+    )
+    .map(|target| {
+        (
+            reverse_path(parents, |&(p, _)| from_parent(p), target),
+            parents[target].1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), keeping the nodes
+/// to expand in an [`IndexedHeap`] instead of a binary heap.
+///
+/// This function behaves like [`astar`]. When a cheaper way to reach a node is found,
+/// its estimated cost is decreased in the heap instead of adding a new entry for the
+/// node, which keeps the heap small in graphs where costs are often improved.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_indexed;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let result = astar_indexed(&(1, 1),
+///                            |&(x, y)| vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///                                           (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)]
+///                                       .into_iter().map(|p| (p, 1)),
+///                            |&(x, y)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3,
+///                            |&p| p == GOAL);
+/// assert_eq!(result.expect("no path found").1, 4);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn astar_indexed<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    run_astar_in(
+        start,
+        |node: &N, _| successors(node),
+        heuristic,
+        success,
+        &mut parents,
+        &mut IndexedHeap::new(),
+    )
+    .map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents.get_index(target).unwrap().1.1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), keeping the nodes
+/// to expand in a [`RadixHeap`] instead of a binary heap.
+///
+/// This function behaves like [`astar`], but requires costs of an unsigned integer
+/// type and a consistent heuristic, that is one which never decreases by more than
+/// the cost of a move. The estimated costs of the expanded nodes then never decrease,
+/// which lets the radix heap order them with fewer comparisons.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_radix;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let result = astar_radix(&(1, 1),
+///                          |&(x, y)| vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///                                         (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)]
+///                                     .into_iter().map(|p| (p, 1)),
+///                          |&(x, y)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3,
+///                          |&p| p == GOAL);
+/// assert_eq!(result.expect("no path found").1, 4);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn astar_radix<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: RadixKey,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    run_astar_in(
+        start,
+        |node: &N, _| successors(node),
+        heuristic,
+        success,
+        &mut parents,
+        &mut RadixHeap::new(),
+    )
+    .map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents.get_index(target).unwrap().1.1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) with successors
+/// depending on the cost already spent to reach the node being expanded.
+///
+/// This function behaves like [`astar`], except that `successors` receives the
+/// total cost of the best path to the node along with the node itself. This allows
+/// the successors and their costs to depend on this cost, for example when it
+/// represents an arrival time. The costs must still be non-negative, and leaving a
+/// node later must never allow reaching a successor earlier.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_cost_aware;
+///
+/// // Moving gets slower as fatigue (the time already spent) accumulates.
+/// let result = astar_cost_aware(&0, |&n, time: u32| vec![(n + 1, 1 + time / 4)],
+///                               |&n| 6 - n.min(6), |&n| n == 6);
+/// assert_eq!(result, Some((vec![0, 1, 2, 3, 4, 5, 6], 8)));
+/// ```
+pub fn astar_cost_aware<N, C, FN, IN, FH, FS>(
+    start: &N,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, C) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    run_astar_in(
+        start,
+        successors,
+        heuristic,
+        success,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    )
+    .map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents[target].1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), only considering
+/// paths made of at most `max_hops` edges.
+///
+/// This function behaves like [`astar`], except that the returned path is the
+/// cheapest one among paths with at most `max_hops` edges. `None` is returned if
+/// no such path exists, even if a longer one does.
+///
+/// Since a node may be reached through paths with different numbers of edges,
+/// nodes may be expanded up to `max_hops` times each.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_bounded;
+///
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n * 2, 5)];
+/// let heuristic = |&n: &u32| 8u32.saturating_sub(n).min(5);
+/// assert_eq!(astar_bounded(&1, successors, heuristic, |&n| n == 8, 4).map(|(_, c)| c), Some(8));
+/// assert_eq!(astar_bounded(&1, successors, heuristic, |&n| n == 8, 2), None);
+/// ```
+pub fn astar_bounded<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+    max_hops: usize,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    astar(
+        &(start.clone(), 0),
+        |(node, hops)| {
+            let hops = *hops + 1;
+            (hops <= max_hops)
+                .then(|| successors(node))
+                .into_iter()
+                .flatten()
+                .map(move |(successor, cost)| ((successor, hops), cost))
+        },
+        |(node, _)| heuristic(node),
+        |(node, _)| success(node),
+    )
+    .map(|(path, cost)| (path.into_iter().map(|(node, _)| node).collect(), cost))
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), reporting why no
+/// path has been found in a [`SearchError`].
+///
+/// This function behaves like [`astar`] with a single start node, except that the
+/// search is stopped when one of the `limits` is reached, and that an error is returned
+/// instead of overflowing when adding costs. Services which must explain why a route
+/// has not been found can thus distinguish between the absence of a path, a budget
+/// exhausted by a large or unbounded graph, a cancelled request and an invalid cost.
+///
+/// # Errors
+///
+/// - [`SearchError::NoPath`] is returned when no path exists.
+/// - [`SearchError::BudgetExceeded`] or [`SearchError::Cancelled`] is returned when the
+///   search is stopped by the `limits`, along with the path to the node with the
+///   smallest heuristic value which has been expanded.
+/// - [`SearchError::CostOverflow`] is returned when the cost of a path, or this cost
+///   added to the heuristic, overflows.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{try_astar, SearchError, SearchLimits};
+///
+/// // Walk on a line of integers towards 20, without going below 0 or above 30.
+/// let successors = |&n: &u32| {
+///     [n.checked_sub(1), Some(n + 1)]
+///         .into_iter()
+///         .flatten()
+///         .filter(|&n| n <= 30)
+///         .map(|n| (n, 1))
+/// };
+/// let heuristic = |&n: &u32| n.abs_diff(20);
+/// let result = try_astar(&0, successors, heuristic, |&n| n == 20, SearchLimits::new());
+/// assert_eq!(result.map(|(_, cost)| cost), Ok(20));
+///
+/// let result = try_astar(&0, successors, heuristic, |&n| n == 40, SearchLimits::new());
+/// assert_eq!(result, Err(SearchError::NoPath));
+///
+/// let limits = SearchLimits::new().max_expansions(5);
+/// let error = try_astar(&0, successors, heuristic, |&n| n == 20, limits).unwrap_err();
+/// assert_eq!(error.closest(), Some(&(vec![0, 1, 2, 3, 4], 4)));
+/// ```
+pub fn try_astar<N, C, FN, IN, FH, FS, FC>(
+    start: &N,
+    successors: FN,
+    mut heuristic: FH,
+    success: FS,
+    limits: SearchLimits<FC>,
+) -> Result<(Vec<N>, C), SearchError<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + CheckedAdd + Sub<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    FC: FnMut() -> bool,
+{
+    // Index and heuristic value of the closest node expanded so far.
+    let mut closest = (0, heuristic(start));
+    let mut parents = FxIndexMap::default();
+    let estimate = closest.1;
+    let result = try_astar_in(
+        &mut parents,
+        start,
+        estimate,
+        successors,
+        heuristic,
+        success,
+        limits,
+        |holder: &SmallestCostHolder<C>| {
+            let h = holder.estimated_cost - holder.cost;
+            if h < closest.1 {
+                closest = (from_parent(holder.index), h);
+            }
+        },
+    );
+    result.map_err(|mut error| {
+        if let SearchError::BudgetExceeded { closest: path, .. }
+        | SearchError::Cancelled { closest: path, .. } = &mut error
+        {
+            *path = Some((
+                reverse_path(&parents, |&(p, _)| from_parent(p), closest.0),
+                parents[closest.0].1,
+            ));
+        }
+        error
+    })
+}
+
+/// Run an A* search from `start`, whose heuristic value is `estimate`, within `limits`
+/// and without overflowing when adding costs. `on_expand` is called with the entry of
+/// every node about to be expanded. The errors are returned without a closest path.
+#[expect(clippy::too_many_arguments)]
+pub(crate) fn try_astar_in<N, C, FN, IN, FH, FS, FC, FE>(
+    parents: &mut FxIndexMap<N, (ParentIndex, C)>,
+    start: &N,
+    estimate: C,
+    mut successors: FN,
+    heuristic: FH,
+    success: FS,
+    mut limits: SearchLimits<FC>,
+    mut on_expand: FE,
+) -> Result<(Vec<N>, C), SearchError<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + CheckedAdd,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    FC: FnMut() -> bool,
+    FE: FnMut(&SmallestCostHolder<C>),
+{
+    parents.clear();
+    parents.insert(start.clone(), (NO_PARENT, Zero::zero()));
+    let mut to_see = BinaryHeap::new();
+    to_see.push(SmallestCostHolder {
+        estimated_cost: estimate,
+        cost: Zero::zero(),
+        index: to_parent(0),
+    });
+    let mut expanded = 0;
+    let mut cancelled = false;
+    let outcome = astar_core(
+        parents,
+        &mut to_see,
+        |node, _| successors(node),
+        heuristic,
+        success,
+        |holder| {
+            if expanded == limits.max_expansions {
+                return false;
+            }
+            cancelled = (limits.cancelled)();
+            if cancelled {
+                return false;
+            }
+            on_expand(holder);
+            expanded += 1;
+            true
+        },
+        |a, b| a.checked_add(&b),
+    );
+    let path = |index| reverse_path(parents, |&(p, _)| from_parent(p), index);
+    match outcome {
+        Outcome::Reached(index) => Ok((path(index), parents[index].1)),
+        Outcome::Exhausted => Err(SearchError::NoPath),
+        Outcome::Suspended if cancelled => Err(SearchError::Cancelled {
+            expanded,
+            closest: None,
+        }),
+        Outcome::Suspended => Err(SearchError::BudgetExceeded {
+            max_expansions: limits.max_expansions,
+            closest: None,
+        }),
+        Outcome::Overflow(index) => Err(SearchError::CostOverflow { path: path(index) }),
+    }
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), and return it
+/// as a [`Path`] carrying the cost of every step.
+///
+/// This function behaves like [`astar`], with the additional requirement that
+/// costs can be subtracted from each other.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_path;
+///
+/// let path = astar_path(&(0, 0), |&(x, y)| vec![((x + 1, y), 2), ((x, y + 1), 3)],
+///                       |&(x, y)| (4 - x).max(0) * 2 + (2 - y).max(0) * 3, |&p| p == (4, 2))
+///     .expect("no path found");
+/// assert_eq!(path.steps(), 6);
+/// assert_eq!(path.total_cost(), 14);
+/// ```
+pub fn astar_path<N, C, FN, IN, FH, FS>(
+    start: &N,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let (parents, reached) = run_astar(
+        std::iter::once(start),
+        successors,
+        heuristic,
+        success,
+        BuildHasherDefault::<FxHasher>::default(),
+    );
+    reached.map(|target| reverse_path_with_costs(&parents, target))
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) in a graph where
+/// several edges may link the same nodes, and return the edges it goes through.
+///
+/// This function behaves like [`astar`], except that `successors` returns, for every
+/// edge leaving a node, the successor, a label identifying the edge, and the cost of the
+/// edge. When several edges lead to the same successor, the cheapest one is used, the
+/// first one returned winning ties.
+///
+/// The path is returned as the list of steps taken from `start`, every step being made
+/// of the node reached, the label of the edge followed and its cost, along with the total
+/// cost. The list is empty if `start` is a goal.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_edges;
+///
+/// // Going from one town to the next by bus or by train.
+/// let successors = |&n: &u32| vec![(n + 1, "bus", 3), (n + 1, "train", 2), (n + 2, "bus", 5)];
+/// let (steps, cost) = astar_edges(&0, successors, |&n| 4u32.saturating_sub(n), |&n| n == 4).unwrap();
+/// assert_eq!(steps, vec![(1, "train", 2), (2, "train", 2), (3, "train", 2), (4, "train", 2)]);
+/// assert_eq!(cost, 8);
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn astar_edges<N, E, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<(N, E, C)>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, E, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut edges = ChosenEdges::new();
+    let (path, cost) = astar(
+        start,
+        |node| edges.record(node, successors(node)),
+        heuristic,
+        success,
+    )?;
+    Some((edges.into_steps(path), cost))
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) with successors
+/// written into a reusable buffer.
+///
+/// This function behaves like [`astar`], except that `successors` receives a
+/// vector into which it must push (or extend with) the successors of the node
+/// along with the cost for moving to them. The same vector is reused for every
+/// expansion, so that no allocation is needed once it has grown large enough.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_extend;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let result = astar_extend(&(1, 1), |&(x, y), successors| {
+///     successors.extend([(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///                        (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)].map(|p| (p, 1)));
+/// }, |&(x, y)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3, |&p| p == GOAL);
+/// assert_eq!(result.expect("no path found").1, 4);
+/// ```
+pub fn astar_extend<N, C, FN, FH, FS>(
+    start: &N,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, &mut Vec<(N, C)>),
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let pool = Cell::new(Vec::new());
+    astar(
+        start,
+        recycled_successors(&pool, successors),
+        heuristic,
+        success,
+    )
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) towards several
+/// goals, and report which goal has been reached.
+///
+/// This function behaves like [`astar`], except that `success` returns `Some(goal)`
+/// when a goal is reached, and `None` otherwise. The goal label is returned along
+/// with the path and its total cost.
+///
+/// The `heuristic` must not overestimate the cost to reach the nearest goal.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_goal;
+///
+/// let result = astar_goal(&(0, 0), |&(x, y)| vec![((x + 1, y), 1), ((x, y + 1), 1)],
+///                         |&(x, y)| (3 - x.min(3)).min(5 - y.min(5)),
+///                         |&(x, y)| if x == 3 { Some('x') } else if y == 5 { Some('y') } else { None });
+/// let (path, cost, goal) = result.expect("no path found");
+/// assert_eq!((path.len(), cost, goal), (4, 3, 'x'));
+/// ```
+pub fn astar_goal<N, C, G, FN, IN, FH, FS>(
+    start: &N,
+    successors: FN,
+    heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<N>, C, G)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> Option<G>,
+{
+    let mut goal = None;
+    let (path, cost) = astar(start, successors, heuristic, |node| {
+        goal = success(node);
+        goal.is_some()
+    })?;
+    goal.map(|goal| (path, cost, goal))
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm), searching
+/// backwards from the goal.
+///
+/// The search starts from `goal` and follows the edges in reverse until a node for
+/// which `is_start` returns `true` is found. This is the most efficient way of finding
+/// the closest of many possible starting points leading to a single goal.
+///
+/// - `goal` is the node the path must lead to.
+/// - `predecessors` returns a list of nodes having an edge leading to a given node, along
+///   with the cost for moving from the predecessor to the node.
+/// - `heuristic` returns an approximation of the cost from the closest starting point to
+///   a given node. It must not be greater than the real cost.
+/// - `is_start` checks whether a node is an acceptable starting point.
+///
+/// The returned path is in the forward direction, going from the starting point to
+/// the goal, and comprises both of them.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_backward;
 ///
-/// static GOAL: (i32, i32) = (4, 6);
-/// let result = astar(&(1, 1),
-///                    |&(x, y)| vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
-///                                   (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)]
-///                               .into_iter().map(|p| (p, 1)),
-///                    |&(x, y)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3,
-///                    |&p| p == GOAL);
-/// assert_eq!(result.expect("no path found").1, 4);
+/// // Starting points are located on the line y = 0, and the goal is (3, 4).
+/// let result = astar_backward(&(3i32, 4i32),
+///                             |&(x, y)| vec![((x + 1, y), 1), ((x - 1, y), 1),
+///                                            ((x, y + 1), 1), ((x, y - 1), 1)],
+///                             |&(_, y)| y.abs(),
+///                             |&(_, y)| y == 0);
+/// let (path, cost) = result.expect("no path found");
+/// assert_eq!((path[0], path.len(), cost), ((3, 0), 5, 4));
 /// ```
-#[expect(clippy::missing_panics_doc)]
-pub fn astar<N, C, FN, IN, FH, FS>(
+pub fn astar_backward<N, C, FN, IN, FH, FS>(
+    goal: &N,
+    predecessors: FN,
+    heuristic: FH,
+    is_start: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let (mut path, cost) = astar(goal, predecessors, heuristic, is_start)?;
+    path.reverse();
+    Some((path, cost))
+}
+
+fn run_astar<'a, N, C, FN, IN, FH, FS, S>(
+    starts: impl IntoIterator<Item = &'a N>,
+    mut successors: FN,
+    heuristic: FH,
+    success: FS,
+    hasher: S,
+) -> (IndexMap<N, (ParentIndex, C), S>, Option<usize>)
+where
+    N: Eq + Hash + Clone + 'a,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    S: BuildHasher,
+{
+    let mut parents = IndexMap::with_hasher(hasher);
+    let reached = run_astar_multi_in(
+        starts,
+        |node: &N, _| successors(node),
+        heuristic,
+        success,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    );
+    (parents, reached)
+}
+
+fn run_astar_in<N, C, FN, IN, FH, FS, S>(
     start: &N,
     successors: FN,
     heuristic: FH,
     success: FS,
-) -> Option<(Vec<N>, C)>
+    parents: &mut IndexMap<N, (ParentIndex, C), S>,
+    to_see: &mut impl Frontier<SmallestCostHolder<C>>,
+) -> Option<usize>
 where
     N: Eq + Hash + Clone,
     C: Zero + Ord + Copy,
-    FN: FnMut(&N) -> IN,
+    FN: FnMut(&N, C) -> IN,
     IN: IntoIterator<Item = (N, C)>,
     FH: FnMut(&N) -> C,
     FS: FnMut(&N) -> bool,
+    S: BuildHasher,
 {
-    astar_with_hasher(
-        start,
+    run_astar_multi_in(
+        std::iter::once(start),
         successors,
         heuristic,
         success,
-        BuildHasherDefault::<FxHasher>::default(),
+        parents,
+        to_see,
     )
 }
 
-/// Compute a shortest path using the [A* search
-/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) using a custom hasher.
-///
-/// The shortest path starting from `start` up to a node for which `success` returns `true` is
-/// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
-/// is returned instead.
-///
-/// - `start` is the starting node.
-/// - `successors` returns a list of successors for a given node, along with the cost for moving
-///   from the node to the successor. This cost must be non-negative.
-/// - `heuristic` returns an approximation of the cost from a given node to the goal. The
-///   approximation must not be greater than the real cost, or a wrong shortest path may be returned.
-/// - `success` checks whether the goal has been reached. It is not a node as some problems require
-///   a dynamic solution instead of a fixed node.
-///
-/// A node will never be included twice in the path as determined by the `Eq` relationship.
-///
-/// The returned path comprises both the start and end node.
-#[expect(clippy::missing_panics_doc)]
-pub fn astar_with_hasher<N, C, FN, IN, FH, FS, S>(
-    start: &N,
+/// Same as [`run_astar_in`], but starting from several nodes at once. Starting
+/// nodes have no parent, which is represented as `NO_PARENT`.
+fn run_astar_multi_in<'a, N, C, FN, IN, FH, FS, S>(
+    starts: impl IntoIterator<Item = &'a N>,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+    parents: &mut IndexMap<N, (ParentIndex, C), S>,
+    to_see: &mut impl Frontier<SmallestCostHolder<C>>,
+) -> Option<usize>
+where
+    N: Eq + Hash + Clone + 'a,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, C) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    S: BuildHasher,
+{
+    parents.clear();
+    to_see.clear();
+    for start in starts {
+        if !parents.contains_key(start) {
+            let (index, _) = parents.insert_full(start.clone(), (NO_PARENT, Zero::zero()));
+            to_see.push(SmallestCostHolder {
+                estimated_cost: Zero::zero(),
+                cost: Zero::zero(),
+                index: to_parent(index),
+            });
+        }
+    }
+    let outcome = astar_core(
+        parents,
+        to_see,
+        successors,
+        heuristic,
+        success,
+        |_| true,
+        |a, b| Some(a + b),
+    );
+    match outcome {
+        Outcome::Reached(index) => Some(index),
+        Outcome::Exhausted | Outcome::Suspended | Outcome::Overflow(_) => None,
+    }
+}
+
+/// Core loop of the A* searches, expanding the nodes of `to_see` until one of them
+/// satisfies `success`. `expand` is called with every entry of `to_see` about to be
+/// expanded, and the search is suspended if it returns `false`. Costs are added with
+/// `add`, the search stopping if it returns `None`.
+fn astar_core<N, C, FN, IN, FH, FS, FE, FA, S>(
+    parents: &mut IndexMap<N, (ParentIndex, C), S>,
+    to_see: &mut impl Frontier<SmallestCostHolder<C>>,
     mut successors: FN,
     mut heuristic: FH,
     mut success: FS,
-    hasher: S
-) -> Option<(Vec<N>, C)>
+    mut expand: FE,
+    mut add: FA,
+) -> Outcome
 where
     N: Eq + Hash + Clone,
     C: Zero + Ord + Copy,
-    FN: FnMut(&N) -> IN,
+    FN: FnMut(&N, C) -> IN,
     IN: IntoIterator<Item = (N, C)>,
     FH: FnMut(&N) -> C,
     FS: FnMut(&N) -> bool,
-    S: BuildHasher
+    FE: FnMut(&SmallestCostHolder<C>) -> bool,
+    FA: FnMut(C, C) -> Option<C>,
+    S: BuildHasher,
 {
-    let mut to_see = BinaryHeap::new();
-    to_see.push(SmallestCostHolder {
-        estimated_cost: Zero::zero(),
-        cost: Zero::zero(),
-        index: 0,
-    });
-    let mut parents: IndexMap<N, (usize, C), S> = IndexMap::<_, _, S>::with_hasher(hasher);
-    parents.insert(start.clone(), (usize::MAX, Zero::zero()));
-    while let Some(SmallestCostHolder { cost, index, .. }) = to_see.pop() {
+    while let Some(holder) = to_see.pop() {
+        let SmallestCostHolder { cost, index, .. } = holder;
         let successors = {
-            let (node, &(_, c)) = parents.get_index(index).unwrap(); // Cannot fail
+            let (node, &(_, c)) = parents.get_index(from_parent(index)).unwrap(); // Cannot fail
             if success(node) {
-                let path = reverse_path(&parents, |&(p, _)| p, index);
-                return Some((path, cost));
+                return Outcome::Reached(from_parent(index));
             }
             // We may have inserted a node several time into the binary heap if we found
             // a better way to access it. Ensure that we are currently dealing with the
@@ -159,10 +956,16 @@ where
             if cost > c {
                 continue;
             }
-            successors(node)
+            if !expand(&holder) {
+                to_see.push(holder);
+                return Outcome::Suspended;
+            }
+            successors(node, cost)
         };
         for (successor, move_cost) in successors {
-            let new_cost = cost + move_cost;
+            let Some(new_cost) = add(cost, move_cost) else {
+                return Outcome::Overflow(from_parent(index));
+            };
             let h; // heuristic(&successor)
             let n; // index for successor
             match parents.entry(successor) {
@@ -182,22 +985,25 @@ where
                 }
             }
 
+            let Some(estimated_cost) = add(new_cost, h) else {
+                return Outcome::Overflow(from_parent(index));
+            };
             to_see.push(SmallestCostHolder {
-                estimated_cost: new_cost + h,
+                estimated_cost,
                 cost: new_cost,
-                index: n,
+                index: to_parent(n),
             });
         }
     }
-    None
+    Outcome::Exhausted
 }
 
 /// Compute all shortest paths using the [A* search
 /// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
 ///
-/// Whereas `astar` (non-deterministic-ally) returns a single shortest
-/// path, `astar_bag` returns all shortest paths (in a
-/// non-deterministic order).
+/// Whereas `astar` returns a single shortest path, chosen arbitrarily
+/// among the shortest ones, `astar_bag` returns all shortest paths (in
+/// an unspecified order, which only depends on the graph).
 ///
 /// The shortest paths starting from `start` up to a node for which `success` returns `true` are
 /// computed and returned in an iterator along with the cost (which, by definition, is the same for
@@ -232,14 +1038,18 @@ where
 {
     let mut to_see = BinaryHeap::new();
     let mut min_cost = None;
-    let mut sinks = HashSet::new();
+    let mut sinks = FxIndexSet::default();
     to_see.push(SmallestCostHolder {
         estimated_cost: Zero::zero(),
         cost: Zero::zero(),
         index: 0,
     });
-    let mut parents: FxIndexMap<N, (HashSet<usize>, C)> = FxIndexMap::default();
-    parents.insert(start.clone(), (HashSet::new(), Zero::zero()));
+    // The parents of every node are kept in a linked list whose cells are stored in
+    // `links`, every node only recording the last cell of its list, or `usize::MAX`.
+    // Every cell holds a parent index and the index of the previous cell.
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::MAX, Zero::zero()));
     while let Some(SmallestCostHolder {
         cost,
         index,
@@ -250,6 +1060,7 @@ where
         if matches!(min_cost, Some(min_cost) if estimated_cost > min_cost) {
             break;
         }
+        let index = from_parent(index);
         let successors = {
             let (node, &(_, c)) = parents.get_index(index).unwrap(); // Cannot fail
             if success(node) {
@@ -272,23 +1083,27 @@ where
                 Vacant(e) => {
                     h = heuristic(e.key());
                     n = e.index();
-                    let mut p = HashSet::new();
-                    p.insert(index);
-                    e.insert((p, new_cost));
+                    links.push((index, usize::MAX));
+                    e.insert((links.len() - 1, new_cost));
                 }
                 Occupied(mut e) => {
                     if e.get().1 > new_cost {
                         h = heuristic(e.key());
                         n = e.index();
+                        // The cell of the former last parent is reused, the other
+                        // ones are abandoned.
                         let s = e.get_mut();
-                        s.0.clear();
-                        s.0.insert(index);
+                        links[s.0] = (index, usize::MAX);
                         s.1 = new_cost;
                     } else {
                         if e.get().1 == new_cost {
                             // New parent with an identical cost, this is not
                             // considered as an insertion.
-                            e.get_mut().0.insert(index);
+                            let s = e.get_mut();
+                            if links.get(s.0).is_none_or(|&(p, _)| p != index) {
+                                links.push((index, s.0));
+                                s.0 = links.len() - 1;
+                            }
                         }
                         continue;
                     }
@@ -298,34 +1113,20 @@ where
             to_see.push(SmallestCostHolder {
                 estimated_cost: new_cost + h,
                 cost: new_cost,
-                index: n,
+                index: to_parent(n),
             });
         }
     }
 
-    min_cost.map(|cost| {
-        let parents = parents
-            .into_iter()
-            .map(|(k, (ps, _))| (k, ps.into_iter().collect()))
-            .collect();
-        (
-            AstarSolution {
-                sinks: sinks.into_iter().collect(),
-                parents,
-                current: vec![],
-                terminated: false,
-            },
-            cost,
-        )
-    })
+    min_cost.map(|cost| (AstarSolution::from_links(parents, &links, sinks), cost))
 }
 
 /// Compute all shortest paths using the [A* search
 /// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
 ///
-/// Whereas `astar` (non-deterministic-ally) returns a single shortest
-/// path, `astar_bag` returns all shortest paths (in a
-/// non-deterministic order).
+/// Whereas `astar` returns a single shortest path, chosen arbitrarily
+/// among the shortest ones, `astar_bag` returns all shortest paths (in
+/// an unspecified order, which only depends on the graph).
 ///
 /// This is a utility function which collects the results of the `astar_bag` function into a
 /// vector. Most of the time, it is more appropriate to use `astar_bag` directly.
@@ -351,21 +1152,338 @@ where
         .map(|(solutions, cost)| (solutions.collect(), cost))
 }
 
+/// Suspended state of an [A* search](astar) which can be resumed later,
+/// possibly in another process.
+///
+/// The search progresses by calling [`resume`](Self::resume) with a maximum number
+/// of node expansions. When the search completes, [`ControlFlow::Break`] is returned
+/// with the result of the search, as [`astar`] would return it. Otherwise,
+/// [`ControlFlow::Continue`] is returned and the checkpoint holds the whole state of
+/// the search. The successors, heuristic and success functions are not part of the state
+/// and must be given again every time the search is resumed.
+///
+/// When the `serde` feature is enabled, the checkpoint can be serialized and deserialized.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::AstarCheckpoint;
+/// use std::ops::ControlFlow;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let successors = |&(x, y): &(i32, i32)| {
+///     vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///          (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)]
+///     .into_iter().map(|p| (p, 1))
+/// };
+/// let heuristic = |&(x, y): &(i32, i32)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3;
+/// let mut checkpoint = AstarCheckpoint::new(&(1, 1));
+/// let result = loop {
+///     if let ControlFlow::Break(result) = checkpoint.resume(successors, heuristic, |&p| p == GOAL, 2) {
+///         break result;
+///     }
+/// };
+/// assert_eq!(result.expect("no path found").1, 4);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize, C: serde::Serialize + Copy",
+        deserialize = "N: serde::Deserialize<'de> + Eq + Hash, C: serde::Deserialize<'de> + Ord + Copy"
+    ))
+)]
+pub struct AstarCheckpoint<N, C> {
+    #[cfg_attr(feature = "serde", serde(with = "super::serde_parents"))]
+    parents: FxIndexMap<N, (ParentIndex, C)>,
+    to_see: BinaryHeap<SmallestCostHolder<C>>,
+}
+
+impl<N, C> AstarCheckpoint<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    /// Create a new checkpoint for a search starting at `start`. No
+    /// node is expanded until [`resume`](Self::resume) is called.
+    #[must_use]
+    pub fn new(start: &N) -> Self {
+        let mut parents = FxIndexMap::default();
+        parents.insert(start.clone(), (NO_PARENT, Zero::zero()));
+        let mut to_see = BinaryHeap::new();
+        to_see.push(SmallestCostHolder {
+            estimated_cost: Zero::zero(),
+            cost: Zero::zero(),
+            index: 0,
+        });
+        Self { parents, to_see }
+    }
+
+    /// Resume the search for at most `max_steps` node expansions.
+    ///
+    /// - `successors` returns a list of successors for a given node, along with the cost for
+    ///   moving from the node to the successor. This cost must be non-negative.
+    /// - `heuristic` returns an approximation of the cost from a given node to the goal. The
+    ///   approximation must not be greater than the real cost, or a wrong shortest path may be
+    ///   returned.
+    /// - `success` checks whether the goal has been reached.
+    /// - `max_steps` is the maximum number of nodes to expand before suspending the search.
+    ///
+    /// `ControlFlow::Break` is returned with the search result when the search has
+    /// completed, and `ControlFlow::Continue` is returned when the search has been
+    /// suspended after `max_steps` expansions.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the checkpoint has been corrupted, for example
+    /// when deserialized from tampered data.
+    pub fn resume<FN, IN, FH, FS>(
+        &mut self,
+        mut successors: FN,
+        heuristic: FH,
+        success: FS,
+        max_steps: usize,
+    ) -> ControlFlow<Option<(Vec<N>, C)>>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+        FS: FnMut(&N) -> bool,
+    {
+        let mut steps = 0;
+        let outcome = astar_core(
+            &mut self.parents,
+            &mut self.to_see,
+            |node, _| successors(node),
+            heuristic,
+            success,
+            |_| {
+                steps += 1;
+                steps <= max_steps
+            },
+            |a, b| Some(a + b),
+        );
+        match outcome {
+            Outcome::Reached(index) => {
+                let path = reverse_path(&self.parents, |&(p, _)| from_parent(p), index);
+                ControlFlow::Break(Some((path, self.parents[index].1)))
+            }
+            Outcome::Exhausted | Outcome::Overflow(_) => ControlFlow::Break(None),
+            Outcome::Suspended => ControlFlow::Continue(()),
+        }
+    }
+
+    /// Return the number of nodes discovered so far.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.parents.len()
+    }
+}
+
+/// Step-wise driver for an [A* search](astar), which owns the successors, heuristic
+/// and success functions along with an [`AstarCheckpoint`].
+///
+/// This lets an expensive search be spread over several calls, for example over
+/// several frames of a game, without blocking or using a background thread. The search
+/// progresses by a single node expansion with [`step`](Self::step), by a bounded number
+/// of expansions with [`run_for`](Self::run_for), or until completion with
+/// [`run`](Self::run). Once the search has completed, those methods keep returning
+/// its result.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::AstarSearch;
+/// use std::ops::ControlFlow;
+///
+/// static GOAL: (i32, i32) = (4, 6);
+/// let mut search = AstarSearch::new(
+///     &(1, 1),
+///     |&(x, y): &(i32, i32)| {
+///         vec![(x+1,y+2), (x+1,y-2), (x-1,y+2), (x-1,y-2),
+///              (x+2,y+1), (x+2,y-1), (x-2,y+1), (x-2,y-1)]
+///         .into_iter().map(|p| (p, 1))
+///     },
+///     |&(x, y)| (GOAL.0.abs_diff(x) + GOAL.1.abs_diff(y)) / 3,
+///     |&p| p == GOAL,
+/// );
+/// let mut frames = 0;
+/// let result = loop {
+///     frames += 1;
+///     // Expand at most 2 nodes per frame.
+///     if let ControlFlow::Break(result) = search.run_for(2) {
+///         break result;
+///     }
+/// };
+/// assert!(frames > 1);
+/// assert_eq!(result.expect("no path found").1, 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AstarSearch<N, C, FN, FH, FS> {
+    checkpoint: AstarCheckpoint<N, C>,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+    finished: bool,
+    result: Option<(Vec<N>, C)>,
+}
+
+impl<N, C, FN, IN, FH, FS> AstarSearch<N, C, FN, FH, FS>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    /// Prepare a search starting at `start`. The arguments have the same meaning as
+    /// for [`astar`]. No node is expanded until the search is run.
+    #[must_use]
+    pub fn new(start: &N, successors: FN, heuristic: FH, success: FS) -> Self {
+        Self::from_checkpoint(AstarCheckpoint::new(start), successors, heuristic, success)
+    }
+
+    /// Continue a search from a previously saved checkpoint.
+    #[must_use]
+    pub const fn from_checkpoint(
+        checkpoint: AstarCheckpoint<N, C>,
+        successors: FN,
+        heuristic: FH,
+        success: FS,
+    ) -> Self {
+        Self {
+            checkpoint,
+            successors,
+            heuristic,
+            success,
+            finished: false,
+            result: None,
+        }
+    }
+
+    /// Expand a single node. This is equivalent to `run_for(1)`.
+    pub fn step(&mut self) -> ControlFlow<Option<(Vec<N>, C)>> {
+        self.run_for(1)
+    }
+
+    /// Expand at most `max_steps` nodes. `ControlFlow::Break` is returned with the
+    /// search result when the search has completed, and `ControlFlow::Continue`
+    /// otherwise.
+    pub fn run_for(&mut self, max_steps: usize) -> ControlFlow<Option<(Vec<N>, C)>> {
+        if !self.finished {
+            if let ControlFlow::Break(result) = self.checkpoint.resume(
+                &mut self.successors,
+                &mut self.heuristic,
+                &mut self.success,
+                max_steps,
+            ) {
+                self.finished = true;
+                self.result = result;
+            }
+        }
+        if self.finished {
+            ControlFlow::Break(self.result.clone())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// Run the search until it completes and return its result.
+    pub fn run(&mut self) -> Option<(Vec<N>, C)> {
+        loop {
+            if let ControlFlow::Break(result) = self.run_for(usize::MAX) {
+                return result;
+            }
+        }
+    }
+
+    /// Check whether the search has completed.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Return the number of nodes discovered so far.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.checkpoint.discovered()
+    }
+
+    /// Return the current state of the search, which can be saved and later given to
+    /// [`from_checkpoint`](Self::from_checkpoint).
+    #[must_use]
+    pub const fn checkpoint(&self) -> &AstarCheckpoint<N, C> {
+        &self.checkpoint
+    }
+}
+
 /// This structure is used to implement Rust's max-heap as a min-heap
 /// version for A*. The smallest `estimated_cost` (which is the sum of
 /// the `cost` and the heuristic) is preferred. For the same
 /// `estimated_cost`, the highest `cost` will be favored, as it may
 /// indicate that the goal is nearer, thereby requiring fewer
-/// exploration steps.
-struct SmallestCostHolder<K> {
+/// exploration steps. With the `deterministic` feature, the node discovered first
+/// is favored when both costs are equal.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct SmallestCostHolder<K> {
     estimated_cost: K,
     cost: K,
-    index: usize,
+    #[cfg_attr(feature = "serde", serde(with = "super::serde_parent"))]
+    index: ParentIndex,
+}
+
+// Among nodes with the same estimated cost, the one with the highest cost is
+// favored as with the binary heap.
+impl<K: Ord + Copy> Frontier<SmallestCostHolder<K>> for IndexedHeap<(K, Reverse<K>)> {
+    fn push(&mut self, item: SmallestCostHolder<K>) {
+        Self::push(
+            self,
+            from_parent(item.index),
+            (item.estimated_cost, Reverse(item.cost)),
+        );
+    }
+
+    fn pop(&mut self) -> Option<SmallestCostHolder<K>> {
+        Self::pop(self).map(
+            |(index, (estimated_cost, Reverse(cost)))| SmallestCostHolder {
+                estimated_cost,
+                cost,
+                index: to_parent(index),
+            },
+        )
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
+
+impl<K: RadixKey> Frontier<SmallestCostHolder<K>> for RadixHeap<K, (K, ParentIndex)> {
+    fn push(&mut self, item: SmallestCostHolder<K>) {
+        Self::push(self, item.estimated_cost, (item.cost, item.index));
+    }
+
+    fn pop(&mut self) -> Option<SmallestCostHolder<K>> {
+        Self::pop(self).map(|(estimated_cost, (cost, index))| SmallestCostHolder {
+            estimated_cost,
+            cost,
+            index,
+        })
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
 }
 
 impl<K: PartialEq> PartialEq for SmallestCostHolder<K> {
     fn eq(&self, other: &Self) -> bool {
-        self.estimated_cost.eq(&other.estimated_cost) && self.cost.eq(&other.cost)
+        self.estimated_cost.eq(&other.estimated_cost)
+            && self.cost.eq(&other.cost)
+            && (!cfg!(feature = "deterministic") || self.index == other.index)
     }
 }
 
@@ -380,7 +1498,13 @@ impl<K: Ord> PartialOrd for SmallestCostHolder<K> {
 impl<K: Ord> Ord for SmallestCostHolder<K> {
     fn cmp(&self, other: &Self) -> Ordering {
         match other.estimated_cost.cmp(&self.estimated_cost) {
-            Ordering::Equal => self.cost.cmp(&other.cost),
+            Ordering::Equal => self.cost.cmp(&other.cost).then_with(|| {
+                if cfg!(feature = "deterministic") {
+                    other.index.cmp(&self.index)
+                } else {
+                    Ordering::Equal
+                }
+            }),
             s => s,
         }
     }
@@ -389,59 +1513,234 @@ impl<K: Ord> Ord for SmallestCostHolder<K> {
 /// Iterator structure created by the `astar_bag` function.
 #[derive(Clone)]
 pub struct AstarSolution<N> {
-    sinks: Vec<usize>,
-    parents: Vec<(N, Vec<usize>)>,
-    current: Vec<Vec<usize>>,
-    terminated: bool,
+    graph: SolutionGraph<N>,
+    cursor: SolutionCursor,
 }
 
-impl<N: Clone + Eq + Hash> AstarSolution<N> {
-    fn complete(&mut self) {
-        loop {
-            let ps = match self.current.last() {
-                None => self.sinks.clone(),
-                Some(last) => self.parents(*last.last().unwrap()).clone(),
-            };
-            if ps.is_empty() {
-                break;
+impl<N> AstarSolution<N> {
+    /// Build the solutions from the explored nodes, in the order they have been found,
+    /// along with the index of the last cell of their parents list in `links` (or
+    /// `usize::MAX`), and from the indices of the nodes which are goals. Every cell of
+    /// `links` holds a parent index and the index of the previous cell.
+    pub(crate) fn from_links<C>(
+        parents: impl IntoIterator<Item = (N, (usize, C))>,
+        links: &[(usize, usize)],
+        sinks: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        // Store the parents of all nodes contiguously, in the order they have been
+        // found, followed by the sinks.
+        let parents = parents.into_iter();
+        let mut nodes = Vec::with_capacity(parents.size_hint().0);
+        let mut offsets = Vec::with_capacity(parents.size_hint().0 + 2);
+        let mut edges = Vec::with_capacity(links.len());
+        for (node, (mut link, _)) in parents {
+            offsets.push(edges.len());
+            let first = edges.len();
+            while link != usize::MAX {
+                edges.push(links[link].0);
+                link = links[link].1;
             }
-            self.current.push(ps);
+            edges[first..].reverse();
+            nodes.push(node);
+        }
+        offsets.push(edges.len());
+        edges.extend(sinks);
+        offsets.push(edges.len());
+        Self {
+            graph: SolutionGraph {
+                nodes,
+                offsets,
+                edges,
+            },
+            cursor: SolutionCursor {
+                current: vec![],
+                terminated: false,
+            },
         }
     }
 
-    fn next_vec(&mut self) {
-        while self.current.pop_if(|v| v.len() == 1).is_some() {}
-        self.current.last_mut().map(Vec::pop);
+    /// Build the solutions from a list of paths sharing the same start node. The
+    /// solutions are all the paths going through the edges of the given ones, from the
+    /// start node to one of the last nodes of the given paths.
+    pub(crate) fn from_paths(paths: impl IntoIterator<Item = Vec<N>>) -> Self
+    where
+        N: Eq + Hash,
+    {
+        // As in `astar_bag`, the parents of every node are kept in a linked list.
+        let mut links: Vec<(usize, usize)> = Vec::new();
+        let mut parents: FxIndexMap<N, (usize, ())> = FxIndexMap::default();
+        let mut sinks = FxIndexSet::default();
+        for path in paths {
+            let mut parent = usize::MAX;
+            for node in path {
+                let entry = parents.entry(node);
+                let index = entry.index();
+                match entry {
+                    Vacant(e) => {
+                        let link = if parent == usize::MAX {
+                            usize::MAX
+                        } else {
+                            links.push((parent, usize::MAX));
+                            links.len() - 1
+                        };
+                        e.insert((link, ()));
+                    }
+                    Occupied(mut e) => {
+                        let s = e.get_mut();
+                        let mut link = s.0;
+                        while link != usize::MAX && links[link].0 != parent {
+                            link = links[link].1;
+                        }
+                        if link == usize::MAX && parent != usize::MAX {
+                            links.push((parent, s.0));
+                            s.0 = links.len() - 1;
+                        }
+                    }
+                }
+                parent = index;
+            }
+            sinks.insert(parent);
+        }
+        Self::from_links(parents, &links, sinks)
     }
+}
 
-    fn node(&self, i: usize) -> &N {
-        &self.parents[i].0
-    }
+#[derive(Clone)]
+struct SolutionGraph<N> {
+    nodes: Vec<N>,
+    // The parents of node `i` are `edges[offsets[i]..offsets[i + 1]]`, and the sinks
+    // are stored as the parents of an extra node following the last one.
+    offsets: Vec<usize>,
+    edges: Vec<usize>,
+}
 
-    fn parents(&self, i: usize) -> &Vec<usize> {
-        &self.parents[i].1
+impl<N> SolutionGraph<N> {
+    fn parents(&self, i: usize) -> (usize, usize) {
+        (self.offsets[i], self.offsets[i + 1])
     }
 }
 
-impl<N: Clone + Eq + Hash> Iterator for AstarSolution<N> {
-    type Item = Vec<N>;
+#[derive(Clone)]
+struct SolutionCursor {
+    // Ranges of `edges` being enumerated, the last index of each range designating
+    // the current choice.
+    current: Vec<(usize, usize)>,
+    terminated: bool,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
+impl SolutionCursor {
+    fn complete<N>(&mut self, graph: &SolutionGraph<N>) {
+        loop {
+            let (start, end) = match self.current.last() {
+                None => graph.parents(graph.nodes.len()),
+                Some(&(_, end)) => graph.parents(graph.edges[end - 1]),
+            };
+            if start == end {
+                break;
+            }
+            self.current.push((start, end));
+        }
+    }
+
+    fn next_vec(&mut self) {
+        while self
+            .current
+            .pop_if(|&mut (start, end)| end - start == 1)
+            .is_some()
+        {}
+        if let Some((_, end)) = self.current.last_mut() {
+            *end -= 1;
+        }
+    }
+
+    fn next_path<N: Clone>(&mut self, graph: &SolutionGraph<N>) -> Option<Vec<N>> {
         if self.terminated {
             return None;
         }
-        self.complete();
+        self.complete(graph);
         let path = self
             .current
             .iter()
             .rev()
-            .map(|v| v.last().copied().unwrap())
-            .map(|i| self.node(i).clone())
+            .map(|&(_, end)| graph.nodes[graph.edges[end - 1]].clone())
             .collect::<Vec<_>>();
         self.next_vec();
         self.terminated = self.current.is_empty();
         Some(path)
     }
+
+    /// Give away the second half of the remaining choices of the first level offering
+    /// more than one, so that the returned cursor enumerates the paths which would
+    /// have been enumerated last by this one.
+    #[cfg(feature = "rayon")]
+    fn split<N>(&mut self, graph: &SolutionGraph<N>) -> Option<Self> {
+        if self.terminated {
+            return None;
+        }
+        self.complete(graph);
+        let level = self
+            .current
+            .iter()
+            .position(|&(start, end)| end - start > 1)?;
+        let (start, end) = self.current[level];
+        let mid = start + (end - start) / 2;
+        self.current[level] = (mid, end);
+        let mut current = self.current[..level].to_vec();
+        current.push((start, mid));
+        Some(Self {
+            current,
+            terminated: false,
+        })
+    }
+}
+
+impl<N: Clone + Eq + Hash> Iterator for AstarSolution<N> {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next_path(&self.graph)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<N: Clone + Eq + Hash + Send + Sync> AstarSolution<N> {
+    /// Enumerate the remaining paths of the solution in parallel on the rayon thread pool,
+    /// without consuming them.
+    ///
+    /// The remaining choices are split between the threads, each of them enumerating its
+    /// share of the paths sequentially. Once collected, the paths come in the same order
+    /// as with the sequential iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::astar_bag;
+    /// use rayon::prelude::*;
+    ///
+    /// // All the monotone paths in a 6×6 grid.
+    /// let (solution, cost) = astar_bag(
+    ///     &(0, 0),
+    ///     |&(x, y)| [((x + 1, y), 1), ((x, y + 1), 1)].into_iter().filter(|&((x, y), _)| x < 6 && y < 6),
+    ///     |&(x, y)| 10 - x - y,
+    ///     |&p| p == (5, 5),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(cost, 10);
+    /// let diagonal = solution.par_iter().filter(|path| path.contains(&(2, 2))).count();
+    /// assert_eq!(diagonal, 120);
+    /// assert_eq!(solution.par_iter().collect::<Vec<_>>(), solution.collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    pub fn par_iter(&self) -> impl rayon::iter::ParallelIterator<Item = Vec<N>> + '_ {
+        use rayon::prelude::*;
+
+        let graph = &self.graph;
+        rayon::iter::split(self.cursor.clone(), move |mut cursor| {
+            let other = cursor.split(graph);
+            (cursor, other)
+        })
+        .flat_map_iter(move |mut cursor| std::iter::from_fn(move || cursor.next_path(graph)))
+    }
 }
 
 impl<N: Clone + Eq + Hash> FusedIterator for AstarSolution<N> {}
@@ -0,0 +1,209 @@
+//! Adapt successors functions by merging the duplicate successors of a node, and by
+//! expanding several nodes in a single call.
+
+use crate::FxIndexMap;
+use indexmap::map::Entry::{Occupied, Vacant};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Merge the successors of a node which appear several times in a single expansion,
+/// keeping the lowest cost for each of them.
+fn dedup<N, C, I>(successors: I) -> Vec<(N, C)>
+where
+    N: Eq + Hash,
+    C: Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut merged = FxIndexMap::default();
+    for (successor, cost) in successors {
+        match merged.entry(successor) {
+            Occupied(mut e) => {
+                if cost < *e.get() {
+                    e.insert(cost);
+                }
+            }
+            Vacant(e) => {
+                e.insert(cost);
+            }
+        }
+    }
+    merged.into_iter().collect()
+}
+
+/// Wrap a successors function so that a successor appearing several times in the list
+/// returned for a node only appears once, with the lowest of its costs.
+///
+/// Successors are returned in the order of their first appearance. Searches work with
+/// duplicate successors as well, but they then push the same node several times into
+/// their queue.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dedup_successors;
+///
+/// let mut successors =
+///     dedup_successors(|&n: &u32| vec![(n + 1, 3), (n * 2, 1), (n + 1, 2)]);
+/// assert_eq!(successors(&1), vec![(2, 1)]);
+/// assert_eq!(successors(&3), vec![(4, 2), (6, 1)]);
+/// ```
+pub fn dedup_successors<N, C, FN, IN>(mut successors: FN) -> impl FnMut(&N) -> Vec<(N, C)>
+where
+    N: Eq + Hash,
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    move |node| dedup(successors(node))
+}
+
+/// A successors function computing the successors of several nodes in a single call to
+/// a batch function, for successor generators which are more efficient when given many
+/// nodes at once, such as vectorized or GPU-based ones.
+///
+/// Searches ask for the successors of one node at a time. When the successors of a node
+/// have not been computed yet, the batch function is called with this node followed by
+/// up to `batch_size - 1` nodes which have been returned as successors but have never
+/// been asked for, in the order in which they were discovered: they are the nodes a
+/// search is the most likely to expand next. The successors of the nodes of the batch
+/// are kept until they are asked for.
+///
+/// Duplicate successors of a node are merged as done by [`dedup_successors`].
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, BatchedSuccessors};
+///
+/// // The batch function receives several nodes and returns the successors of each
+/// // of them.
+/// let mut batched = BatchedSuccessors::new(8, |nodes: &[u32]| {
+///     nodes
+///         .iter()
+///         .map(|&n| vec![(n + 1, 1), (n * 3, 2), (n + 1, 4)])
+///         .collect::<Vec<_>>()
+/// });
+/// let (path, cost) = dijkstra(&1, |n| batched.successors(n), |&n| n == 100).unwrap();
+/// assert_eq!(path, vec![1, 3, 9, 10, 11, 33, 99, 100]);
+/// assert_eq!(cost, 11);
+/// // Far fewer calls than expanded nodes have been made.
+/// assert!(batched.calls() * 4 < batched.expanded());
+/// ```
+#[derive(Clone, Debug)]
+pub struct BatchedSuccessors<N, C, FB> {
+    batch: FB,
+    batch_size: usize,
+    computed: FxHashMap<N, Vec<(N, C)>>,
+    discovered: VecDeque<N>,
+    asked: FxHashSet<N>,
+    calls: usize,
+}
+
+impl<N, C, FB> BatchedSuccessors<N, C, FB>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy,
+{
+    /// Create a successors function calling `batch` with at most `batch_size` nodes.
+    ///
+    /// `batch` must return one list of successors, along with the cost for moving from
+    /// the node to the successor, for every node it is given, in the same order.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `batch_size` is 0.
+    pub fn new<IN>(batch_size: usize, batch: FB) -> Self
+    where
+        FB: FnMut(&[N]) -> Vec<IN>,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        assert!(batch_size > 0, "batch size must be at least 1");
+        Self {
+            batch,
+            batch_size,
+            computed: FxHashMap::default(),
+            discovered: VecDeque::new(),
+            asked: FxHashSet::default(),
+            calls: 0,
+        }
+    }
+
+    /// Return the successors of `node`, calling the batch function if they have not been
+    /// computed yet.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the batch function does not return as many lists of
+    /// successors as it has been given nodes.
+    pub fn successors<IN>(&mut self, node: &N) -> Vec<(N, C)>
+    where
+        FB: FnMut(&[N]) -> Vec<IN>,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        self.asked.insert(node.clone());
+        let successors = match self.computed.remove(node) {
+            Some(successors) => successors,
+            None => self.compute(node),
+        };
+        self.discovered
+            .extend(successors.iter().map(|(n, _)| n.clone()));
+        successors
+    }
+
+    /// The number of calls made to the batch function.
+    #[must_use]
+    pub const fn calls(&self) -> usize {
+        self.calls
+    }
+
+    /// The number of distinct nodes whose successors have been asked for.
+    #[must_use]
+    pub fn expanded(&self) -> usize {
+        self.asked.len()
+    }
+
+    /// Forget the successors which have been computed but not asked for yet, as well as
+    /// the nodes which have been discovered or asked for, and reset the number of calls,
+    /// before running another search.
+    pub fn clear(&mut self) {
+        self.computed.clear();
+        self.discovered.clear();
+        self.asked.clear();
+        self.calls = 0;
+    }
+
+    /// Call the batch function with `node` and some of the discovered nodes, keep the
+    /// successors of the latter and return those of `node`.
+    fn compute<IN>(&mut self, node: &N) -> Vec<(N, C)>
+    where
+        FB: FnMut(&[N]) -> Vec<IN>,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let mut nodes = vec![node.clone()];
+        while nodes.len() < self.batch_size {
+            let Some(next) = self.discovered.pop_front() else {
+                break;
+            };
+            if !self.asked.contains(&next)
+                && !self.computed.contains_key(&next)
+                && !nodes.contains(&next)
+            {
+                nodes.push(next);
+            }
+        }
+        let results = (self.batch)(&nodes);
+        assert_eq!(
+            results.len(),
+            nodes.len(),
+            "batch function must return successors for every node"
+        );
+        self.calls += 1;
+        let mut results = nodes.into_iter().zip(results);
+        let (_, successors) = results.next().unwrap(); // Cannot fail
+        for (n, successors) in results {
+            self.computed.insert(n, dedup(successors));
+        }
+        dedup(successors)
+    }
+}
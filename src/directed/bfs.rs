@@ -1,13 +1,22 @@
 //! Compute a shortest path using the [breadth-first search
 //! algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
 
-use super::reverse_path;
-use crate::NodeRefs;
-use indexmap::map::Entry::Vacant;
+use super::astar::AstarSolution;
+use super::search_context::SearchContext;
+use super::search_error::{SearchError, SearchLimits};
+use super::shortest_path_tree::ShortestPathTree;
+use super::{NO_PARENT, ParentIndex, from_parent, recycled_successors, reverse_path, to_parent};
+use crate::path::Path;
+#[cfg(feature = "rayon")]
+use crate::sharded::ShardedMap;
+use crate::{FxIndexMap, NodeRefs};
+use indexmap::map::Entry::{Occupied, Vacant};
 use indexmap::{IndexMap, IndexSet};
 use rustc_hash::FxHasher;
+use std::cell::Cell;
 use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 use std::iter::FusedIterator;
+use std::ops::ControlFlow;
 
 /// Compute a shortest path using the [breadth-first search
 /// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
@@ -102,6 +111,372 @@ where
     bfs_core(&start.into(), successors, success, true, hasher)
 }
 
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), reusing the
+/// buffers of a [`SearchContext`] instead of allocating new ones.
+///
+/// This function behaves like [`bfs`]. The context is cleared before the
+/// search starts, and the depth of every discovered node is recorded as its cost.
+pub fn bfs_with_context<'a, N, S, FN, IN, FS, H>(
+    context: &mut SearchContext<N, usize, H>,
+    start: S,
+    successors: FN,
+    success: FS,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
+{
+    context.clear();
+    bfs_depth_core(
+        &mut context.parents,
+        &start.into(),
+        successors,
+        success,
+        usize::MAX,
+    )
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), only considering
+/// paths made of at most `max_hops` edges.
+///
+/// This function behaves like [`bfs`], except that nodes located `max_hops` steps away
+/// from the start are never expanded. `None` is returned if no path within this bound
+/// exists, even if a longer one does.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_bounded;
+///
+/// let successors = |&n: &u32| vec![n + 1, n * 2];
+/// assert_eq!(bfs_bounded(&1, successors, |&n| n == 10, 4), Some(vec![1, 2, 4, 5, 10]));
+/// assert_eq!(bfs_bounded(&1, successors, |&n| n == 10, 3), None);
+/// ```
+pub fn bfs_bounded<'a, N, S, FN, IN, FS>(
+    start: S,
+    successors: FN,
+    success: FS,
+    max_hops: usize,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    bfs_depth_core(
+        &mut FxIndexMap::default(),
+        &start.into(),
+        successors,
+        success,
+        max_hops,
+    )
+}
+
+fn bfs_depth_core<'a, N, FN, IN, FS, H>(
+    parents: &mut IndexMap<N, (ParentIndex, usize), H>,
+    start: &NodeRefs<'a, N>,
+    mut successors: FN,
+    mut success: FS,
+    max_depth: usize,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone + 'a,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
+{
+    for start_node in start {
+        if success(start_node) {
+            return Some(vec![start_node.clone()]);
+        }
+    }
+
+    parents.extend(start.into_iter().map(|n| (n.clone(), (NO_PARENT, 0))));
+
+    let mut i = 0;
+    while let Some((node, &(_, depth))) = parents.get_index(i) {
+        // Nodes are discovered in increasing depth order.
+        if depth >= max_depth {
+            break;
+        }
+        for successor in successors(node) {
+            if success(&successor) {
+                let mut path = reverse_path(parents, |&(p, _)| from_parent(p), i);
+                path.push(successor);
+                return Some(path);
+            }
+            if let Vacant(e) = parents.entry(successor) {
+                e.insert((to_parent(i), depth + 1));
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), reporting why no
+/// path has been found in a [`SearchError`].
+///
+/// This function behaves like [`bfs`] with a single start node, except that the search
+/// is stopped when one of the `limits` is reached. The costs carried by the error are
+/// numbers of steps.
+///
+/// # Errors
+///
+/// - [`SearchError::NoPath`] is returned when no path exists.
+/// - [`SearchError::BudgetExceeded`] or [`SearchError::Cancelled`] is returned when the
+///   search is stopped by the `limits`, without a closest path.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{try_bfs, SearchError, SearchLimits};
+///
+/// let successors = |&n: &u32| vec![n + 1, n * 2];
+/// let path = try_bfs(&1, successors, |&n| n == 10, SearchLimits::new());
+/// assert_eq!(path, Ok(vec![1, 2, 4, 5, 10]));
+/// let limits = SearchLimits::new().max_expansions(3);
+/// let error = try_bfs(&1, successors, |&n| n == 100, limits).unwrap_err();
+/// assert_eq!(error.to_string(), "the search budget of 3 expansions has been exceeded");
+/// ```
+pub fn try_bfs<N, FN, IN, FS, FC>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+    mut limits: SearchLimits<FC>,
+) -> Result<Vec<N>, SearchError<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+    FC: FnMut() -> bool,
+{
+    if success(start) {
+        return Ok(vec![start.clone()]);
+    }
+    let mut parents = FxIndexMap::default();
+    parents.insert(start.clone(), NO_PARENT);
+    let mut i = 0;
+    while let Some((node, _)) = parents.get_index(i) {
+        if i == limits.max_expansions || (limits.cancelled)() {
+            return Err(if i == limits.max_expansions {
+                SearchError::BudgetExceeded {
+                    max_expansions: limits.max_expansions,
+                    closest: None,
+                }
+            } else {
+                SearchError::Cancelled {
+                    expanded: i,
+                    closest: None,
+                }
+            });
+        }
+        for successor in successors(node) {
+            if success(&successor) {
+                let mut path = reverse_path(&parents, |&p| from_parent(p), i);
+                path.push(successor);
+                return Ok(path);
+            }
+            if let Vacant(e) = parents.entry(successor) {
+                e.insert(to_parent(i));
+            }
+        }
+        i += 1;
+    }
+    Err(SearchError::NoPath)
+}
+
+/// Explore all the nodes reachable from a starting point using the [breadth-first
+/// search algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), and return
+/// them as a [`ShortestPathTree`] in which the cost of a node is its distance in
+/// steps from the start.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_tree;
+///
+/// let tree = bfs_tree(&1, |&n| if n < 20 { vec![n + 1, n * 2] } else { vec![] });
+/// assert_eq!(tree.cost_to(&10), Some(4));
+/// assert_eq!(tree.path_to(&10), Some(vec![1, 2, 4, 5, 10]));
+/// assert_eq!(tree.iter().filter(|&(_, depth)| depth == 2).count(), 2);
+/// ```
+pub fn bfs_tree<N, FN, IN>(start: &N, successors: FN) -> ShortestPathTree<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut parents = FxIndexMap::default();
+    bfs_depth_core(
+        &mut parents,
+        &start.into(),
+        successors,
+        |_| false,
+        usize::MAX,
+    );
+    ShortestPathTree::from_parents(parents)
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), and return it
+/// as a [`Path`] in which every step costs `1`.
+///
+/// This function behaves like [`bfs`].
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_path;
+///
+/// let path = bfs_path(&1, |&n| vec![n + 1, n * 2], |&n| n == 10).expect("no path found");
+/// assert_eq!(path.nodes(), &[1, 2, 4, 5, 10]);
+/// assert_eq!(path.total_cost(), 4);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn bfs_path<'a, N, S, FN, IN, FS>(
+    start: S,
+    successors: FN,
+    success: FS,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    bfs(start, successors, success).map(|path| {
+        let mut nodes = path.into_iter();
+        let start = nodes.next().unwrap(); // Cannot fail, paths are never empty
+        Path::from_steps(start, nodes.map(|n| (n, 1)))
+    })
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search) with successors
+/// written into a reusable buffer.
+///
+/// This function behaves like [`bfs`], except that `successors` receives a
+/// vector into which it must push (or extend with) the successors of the node.
+/// The same vector is reused for every expansion, so that no allocation is needed
+/// once it has grown large enough.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_extend;
+///
+/// let result = bfs_extend(&1, |&n, successors| successors.extend([n + 1, n * 2]), |&n| n == 10);
+/// assert_eq!(result, Some(vec![1, 2, 4, 5, 10]));
+/// ```
+pub fn bfs_extend<'a, N, S, FN, FS>(start: S, successors: FN, success: FS) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N, &mut Vec<N>),
+    FS: FnMut(&N) -> bool,
+{
+    let pool = Cell::new(Vec::new());
+    bfs(start, recycled_successors(&pool, successors), success)
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search) towards several
+/// goals, and report which goal has been reached.
+///
+/// This function behaves like [`bfs`], except that `success` returns `Some(goal)`
+/// when a goal is reached, and `None` otherwise. The goal label is returned along
+/// with the path.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_goal;
+///
+/// let result = bfs_goal(&1, |&n| vec![n + 1, n * 2], |&n| match n {
+///     20 => Some("twenty"),
+///     9 => Some("nine"),
+///     _ => None,
+/// });
+/// assert_eq!(result, Some((vec![1, 2, 4, 8, 9], "nine")));
+/// ```
+pub fn bfs_goal<'a, N, G, S, FN, IN, FS>(
+    start: S,
+    successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, G)>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> Option<G>,
+{
+    let mut goal = None;
+    let path = bfs(start, successors, |node| {
+        goal = success(node);
+        goal.is_some()
+    })?;
+    goal.map(|goal| (path, goal))
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), searching
+/// backwards from the goal.
+///
+/// The search starts from `goal` and follows the edges in reverse until a node for
+/// which `is_start` returns `true` is found. This is the most efficient way of finding
+/// the closest of many possible starting points leading to a single goal.
+///
+/// - `goal` is the node (or nodes) the path must lead to.
+/// - `predecessors` returns a list of nodes having an edge leading to a given node.
+/// - `is_start` checks whether a node is an acceptable starting point.
+///
+/// The returned path is in the forward direction, going from the starting point to
+/// the goal, and comprises both of them.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_backward;
+///
+/// // Which multiple of 7 reaches 40 the soonest by doubling or adding one?
+/// let result = bfs_backward(&40, |&n| {
+///     let mut preds = vec![n - 1];
+///     if n % 2 == 0 { preds.push(n / 2); }
+///     preds.retain(|&p| p > 0);
+///     preds
+/// }, |&n| n % 7 == 0);
+/// assert_eq!(result, Some(vec![35, 36, 37, 38, 39, 40]));
+/// ```
+pub fn bfs_backward<'a, N, S, FN, IN, FS>(goal: S, predecessors: FN, is_start: FS) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut path = bfs(goal, predecessors, is_start)?;
+    path.reverse();
+    Some(path)
+}
+
 fn bfs_core<'a, N, FN, IN, FS, H>(
     start: &NodeRefs<'a, N>,
     mut successors: FN,
@@ -124,19 +499,19 @@ where
         }
     }
 
-    let mut parents: IndexMap<N, usize, H> = IndexMap::with_hasher(hasher);
-    parents.extend(start.into_iter().map(|n| (n.clone(), usize::MAX)));
+    let mut parents: IndexMap<N, ParentIndex, H> = IndexMap::with_hasher(hasher);
+    parents.extend(start.into_iter().map(|n| (n.clone(), NO_PARENT)));
 
     let mut i = 0;
     while let Some((node, _)) = parents.get_index(i) {
         for successor in successors(node) {
             if success(&successor) {
-                let mut path = reverse_path(&parents, |&p| p, i);
+                let mut path = reverse_path(&parents, |&p| from_parent(p), i);
                 path.push(successor);
                 return Some(path);
             }
             if let Vacant(e) = parents.entry(successor) {
-                e.insert(i);
+                e.insert(to_parent(i));
             }
         }
         i += 1;
@@ -144,6 +519,98 @@ where
     None
 }
 
+/// Compute all shortest paths using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search).
+///
+/// Whereas `bfs` returns a single shortest path, chosen arbitrarily
+/// among the shortest ones, `bfs_bag` returns all shortest paths (in
+/// an unspecified order, which only depends on the graph), as
+/// [`astar_bag`](super::astar::astar_bag) does in a weighted graph.
+///
+/// The shortest paths starting from `start` up to a node for which `success` returns `true` are
+/// computed and returned in an iterator, wrapped in a `Some`. If no paths are found, `None` is
+/// returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// Each path comprises both the start and an end node. Note that while every path shares the same
+/// start node, different paths may have different end nodes, but they all have the same length.
+///
+/// # Example
+///
+/// There are 10 shortest ways for a king to go from (0, 0) to (4, 2) on a chess board:
+///
+/// ```
+/// use pathfinding::prelude::bfs_bag;
+///
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)]
+///         .map(|(dx, dy)| (x + dx, y + dy))
+/// };
+/// let solutions = bfs_bag(&(0, 0), successors, |&p| p == (4, 2)).expect("no path found");
+/// let solutions = solutions.collect::<Vec<_>>();
+/// assert_eq!(solutions.len(), 10);
+/// assert!(solutions.iter().all(|path| path.len() == 5));
+/// ```
+pub fn bfs_bag<N, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<AstarSolution<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    // As in `astar_bag`, the parents of every node are kept in a linked list whose cells
+    // are stored in `links`, every node only recording the last cell of its list, or
+    // `usize::MAX`. Every cell holds a parent index and the index of the previous cell.
+    // Nodes are associated with their depth.
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    let mut parents: FxIndexMap<N, (usize, usize)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::MAX, 0));
+    let mut sinks = Vec::new();
+    let mut min_depth = None;
+
+    let mut i = 0;
+    while let Some((node, &(_, depth))) = parents.get_index(i) {
+        if min_depth.is_some_and(|d| depth > d) {
+            break;
+        }
+        if success(node) {
+            min_depth = Some(depth);
+            sinks.push(i);
+        }
+        // Nodes are explored by increasing depth, so no other successor can be on a
+        // shortest path once a goal has been reached.
+        if min_depth.is_none() {
+            for successor in successors(node) {
+                match parents.entry(successor) {
+                    Vacant(e) => {
+                        links.push((i, usize::MAX));
+                        e.insert((links.len() - 1, depth + 1));
+                    }
+                    Occupied(mut e) => {
+                        let s = e.get_mut();
+                        if s.1 == depth + 1 && links[s.0].0 != i {
+                            links.push((i, s.0));
+                            s.0 = links.len() - 1;
+                        }
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+    min_depth.map(|_| AstarSolution::from_links(parents, &links, sinks))
+}
+
 /// Return one of the shortest loop from start to start if it exists, `None` otherwise.
 ///
 /// - `start` is the starting node.
@@ -343,6 +810,241 @@ where
     })
 }
 
+/// Suspended state of a [breadth-first search](bfs) which can be resumed later,
+/// possibly in another process.
+///
+/// The search progresses by calling [`resume`](Self::resume) with a maximum number
+/// of node expansions. When the search completes, [`ControlFlow::Break`] is returned
+/// with the result of the search, as [`bfs`] would return it. Otherwise,
+/// [`ControlFlow::Continue`] is returned and the checkpoint holds the whole state of
+/// the search. The successors and success functions are not part of the state and must be
+/// given again every time the search is resumed.
+///
+/// When the `serde` feature is enabled, the checkpoint can be serialized and deserialized.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::BfsCheckpoint;
+/// use std::ops::ControlFlow;
+///
+/// let mut checkpoint = BfsCheckpoint::new(&1);
+/// let result = loop {
+///     if let ControlFlow::Break(result) = checkpoint.resume(|&n| vec![n + 1, n * 2], |&n| n == 19, 3) {
+///         break result;
+///     }
+/// };
+/// assert_eq!(result, Some(vec![1, 2, 4, 8, 9, 18, 19]));
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize",
+        deserialize = "N: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+pub struct BfsCheckpoint<N> {
+    #[cfg_attr(feature = "serde", serde(with = "super::serde_parents"))]
+    parents: FxIndexMap<N, ParentIndex>,
+    i: usize,
+}
+
+impl<N> BfsCheckpoint<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create a new checkpoint for a search starting at `start`. No
+    /// node is expanded until [`resume`](Self::resume) is called.
+    #[must_use]
+    pub fn new(start: &N) -> Self {
+        let mut parents = FxIndexMap::default();
+        parents.insert(start.clone(), NO_PARENT);
+        Self { parents, i: 0 }
+    }
+
+    /// Resume the search for at most `max_steps` node expansions.
+    ///
+    /// - `successors` returns a list of successors for a given node.
+    /// - `success` checks whether the goal has been reached.
+    /// - `max_steps` is the maximum number of nodes to expand before suspending the search.
+    ///
+    /// `ControlFlow::Break` is returned with the search result when the search has
+    /// completed, and `ControlFlow::Continue` is returned when the search has been
+    /// suspended after `max_steps` expansions.
+    pub fn resume<FN, IN, FS>(
+        &mut self,
+        mut successors: FN,
+        mut success: FS,
+        max_steps: usize,
+    ) -> ControlFlow<Option<Vec<N>>>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+        FS: FnMut(&N) -> bool,
+    {
+        if max_steps == 0 {
+            return ControlFlow::Continue(());
+        }
+        if self.i == 0 {
+            if let Some((start, _)) = self.parents.first() {
+                if success(start) {
+                    return ControlFlow::Break(Some(vec![start.clone()]));
+                }
+            }
+        }
+        for _ in 0..max_steps {
+            let Some((node, _)) = self.parents.get_index(self.i) else {
+                return ControlFlow::Break(None);
+            };
+            for successor in successors(node) {
+                if success(&successor) {
+                    let mut path = reverse_path(&self.parents, |&p| from_parent(p), self.i);
+                    path.push(successor);
+                    return ControlFlow::Break(Some(path));
+                }
+                if let Vacant(e) = self.parents.entry(successor) {
+                    e.insert(to_parent(self.i));
+                }
+            }
+            self.i += 1;
+        }
+        if self.i == self.parents.len() {
+            ControlFlow::Break(None)
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// Return the number of nodes discovered so far.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.parents.len()
+    }
+}
+
+/// Step-wise driver for a [breadth-first search](bfs), which owns the successors and
+/// success functions along with a [`BfsCheckpoint`].
+///
+/// This lets an expensive search be spread over several calls, for example over
+/// several frames of a game, without blocking or using a background thread. The search
+/// progresses by a single node expansion with [`step`](Self::step), by a bounded number
+/// of expansions with [`run_for`](Self::run_for), or until completion with
+/// [`run`](Self::run). Once the search has completed, those methods keep returning
+/// its result.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::BfsSearch;
+/// use std::ops::ControlFlow;
+///
+/// let mut search = BfsSearch::new(&1, |&n: &u32| vec![n + 1, n * 2], |&n| n == 19);
+/// let mut frames = 0;
+/// let result = loop {
+///     frames += 1;
+///     // Expand at most 3 nodes per frame.
+///     if let ControlFlow::Break(result) = search.run_for(3) {
+///         break result;
+///     }
+/// };
+/// assert!(frames > 1);
+/// assert_eq!(result, Some(vec![1, 2, 4, 8, 9, 18, 19]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BfsSearch<N, FN, FS> {
+    checkpoint: BfsCheckpoint<N>,
+    successors: FN,
+    success: FS,
+    finished: bool,
+    result: Option<Vec<N>>,
+}
+
+impl<N, FN, IN, FS> BfsSearch<N, FN, FS>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    /// Prepare a search starting at `start`. The arguments have the same meaning as
+    /// for [`bfs`]. No node is expanded until the search is run.
+    #[must_use]
+    pub fn new(start: &N, successors: FN, success: FS) -> Self {
+        Self::from_checkpoint(BfsCheckpoint::new(start), successors, success)
+    }
+
+    /// Continue a search from a previously saved checkpoint.
+    #[must_use]
+    pub const fn from_checkpoint(
+        checkpoint: BfsCheckpoint<N>,
+        successors: FN,
+        success: FS,
+    ) -> Self {
+        Self {
+            checkpoint,
+            successors,
+            success,
+            finished: false,
+            result: None,
+        }
+    }
+
+    /// Expand a single node. This is equivalent to `run_for(1)`.
+    pub fn step(&mut self) -> ControlFlow<Option<Vec<N>>> {
+        self.run_for(1)
+    }
+
+    /// Expand at most `max_steps` nodes. `ControlFlow::Break` is returned with the
+    /// search result when the search has completed, and `ControlFlow::Continue`
+    /// otherwise.
+    pub fn run_for(&mut self, max_steps: usize) -> ControlFlow<Option<Vec<N>>> {
+        if !self.finished {
+            if let ControlFlow::Break(result) =
+                self.checkpoint
+                    .resume(&mut self.successors, &mut self.success, max_steps)
+            {
+                self.finished = true;
+                self.result = result;
+            }
+        }
+        if self.finished {
+            ControlFlow::Break(self.result.clone())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// Run the search until it completes and return its result.
+    pub fn run(&mut self) -> Option<Vec<N>> {
+        loop {
+            if let ControlFlow::Break(result) = self.run_for(usize::MAX) {
+                return result;
+            }
+        }
+    }
+
+    /// Check whether the search has completed.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Return the number of nodes discovered so far.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.checkpoint.discovered()
+    }
+
+    /// Return the current state of the search, which can be saved and later given to
+    /// [`from_checkpoint`](Self::from_checkpoint).
+    #[must_use]
+    pub const fn checkpoint(&self) -> &BfsCheckpoint<N> {
+        &self.checkpoint
+    }
+}
+
 /// Visit all nodes that are reachable from a start node. The node will be visited
 /// in BFS order, starting from the `start` node and following the order returned
 /// by the `successors` function.
@@ -446,3 +1148,295 @@ where
     H: BuildHasher,
 {
 }
+
+/// Visit all nodes that are reachable from a start node along with their depth,
+/// that is the number of steps needed to reach them from the `start` node. The
+/// nodes will be visited in BFS order, starting from the `start` node (at depth 0)
+/// and following the order returned by the `successors` function.
+///
+/// # Example
+///
+/// Nodes within two hops of the start node:
+///
+/// ```
+/// use pathfinding::prelude::bfs_reach_depth;
+///
+/// let near = bfs_reach_depth(1, |&n| vec![n * 2, n * 3])
+///     .take_while(|&(_, depth)| depth <= 2)
+///     .collect::<Vec<_>>();
+/// assert_eq!(near, vec![(1, 0), (2, 1), (3, 1), (4, 2), (6, 2), (9, 2)]);
+/// ```
+pub fn bfs_reach_depth<N, FN, IN>(
+    start: N,
+    successors: FN,
+) -> BfsReachableDepth<N, FN, BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    bfs_reach_depth_with_hasher(start, successors, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Visit all nodes that are reachable from a start node along with their depth
+/// using a custom hasher. See [`bfs_reach_depth`] for more information.
+pub fn bfs_reach_depth_with_hasher<N, FN, IN, H>(
+    start: N,
+    successors: FN,
+    hasher: H,
+) -> BfsReachableDepth<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+    BfsReachableDepth {
+        inner: bfs_reach_with_hasher(start, successors, hasher),
+        depth: 0,
+        layer_end: 1,
+    }
+}
+
+/// Struct returned by [`bfs_reach_depth`].
+pub struct BfsReachableDepth<N, FN, H> {
+    inner: BfsReachable<N, FN, H>,
+    depth: usize,
+    layer_end: usize,
+}
+
+impl<N, FN, H> BfsReachableDepth<N, FN, H> {
+    /// Return a lower bound on the number of remaining reachable
+    /// nodes. Not all nodes are necessarily known in advance, and
+    /// new reachable nodes may be discovered while using the iterator.
+    pub fn remaining_nodes_low_bound(&self) -> usize {
+        self.inner.remaining_nodes_low_bound()
+    }
+}
+
+impl<N, FN, IN, H> Iterator for BfsReachableDepth<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+    type Item = (N, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Once every node of the current layer has been expanded, all the nodes
+        // of the next layer have been discovered.
+        if self.inner.i == self.layer_end {
+            self.depth += 1;
+            self.layer_end = self.inner.seen.len();
+        }
+        self.inner.next().map(|n| (n, self.depth))
+    }
+}
+
+impl<N, FN, IN, H> FusedIterator for BfsReachableDepth<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+}
+
+/// Visit all nodes that are reachable from a start node, layer by layer. Every
+/// item yielded by the iterator contains the nodes located at the same distance
+/// from the `start` node, the first layer containing only `start`. Within a layer,
+/// nodes are sorted in the order they were discovered.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_layers;
+///
+/// let layers = bfs_layers(1, |&n| vec![n * 2, n * 3].into_iter().filter(|&x| x < 15))
+///     .collect::<Vec<_>>();
+/// assert_eq!(layers, vec![vec![1], vec![2, 3], vec![4, 6, 9], vec![8, 12]]);
+/// ```
+pub fn bfs_layers<N, FN, IN>(
+    start: N,
+    successors: FN,
+) -> BfsLayers<N, FN, BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    bfs_layers_with_hasher(start, successors, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Visit all nodes that are reachable from a start node, layer by layer, using
+/// a custom hasher. See [`bfs_layers`] for more information.
+pub fn bfs_layers_with_hasher<N, FN, IN, H>(
+    start: N,
+    successors: FN,
+    hasher: H,
+) -> BfsLayers<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+    let mut seen = IndexSet::with_hasher(hasher);
+    seen.insert(start);
+    BfsLayers {
+        layer_start: 0,
+        seen,
+        successors,
+    }
+}
+
+/// Struct returned by [`bfs_layers`].
+pub struct BfsLayers<N, FN, H> {
+    layer_start: usize,
+    seen: IndexSet<N, H>,
+    successors: FN,
+}
+
+impl<N, FN, IN, H> Iterator for BfsLayers<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let layer_end = self.seen.len();
+        if self.layer_start == layer_end {
+            return None;
+        }
+        let layer = (self.layer_start..layer_end)
+            .map(|i| self.seen[i].clone())
+            .collect::<Vec<_>>();
+        for node in &layer {
+            for s in (self.successors)(node) {
+                self.seen.insert(s);
+            }
+        }
+        self.layer_start = layer_end;
+        Some(layer)
+    }
+}
+
+impl<N, FN, IN, H> FusedIterator for BfsLayers<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+}
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), expanding the nodes
+/// of every level in parallel on the rayon thread pool.
+///
+/// This function behaves like [`bfs`], and is worth using when the graph is large and
+/// computing the successors is costly enough to dominate the synchronization between
+/// threads. The nodes discovered so far are kept in a map split into shards, each one
+/// protected by its own lock.
+///
+/// When several shortest paths exist, which one is returned may vary between runs, as
+/// it depends on which thread first reaches every node.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::par_bfs;
+///
+/// let path = par_bfs(&1, |&n: &u32| vec![n + 1, n * 2], |&n| n == 100).unwrap();
+/// assert_eq!(path.len(), 9);
+/// assert_eq!(path.first(), Some(&1));
+/// assert_eq!(path.last(), Some(&100));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_bfs<N, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone + Send + Sync,
+    FN: Fn(&N) -> IN + Sync,
+    IN: IntoIterator<Item = N>,
+    FS: Fn(&N) -> bool + Sync,
+{
+    use rayon::prelude::*;
+
+    if success(start) {
+        return Some(vec![start.clone()]);
+    }
+    let parents = ShardedMap::new();
+    parents.insert_if_absent(start.clone(), None);
+    let mut frontier = vec![start.clone()];
+    while !frontier.is_empty() {
+        frontier = frontier
+            .par_iter()
+            .flat_map_iter(|node| {
+                successors(node)
+                    .into_iter()
+                    .filter(|s| parents.insert_if_absent(s.clone(), Some(node.clone())))
+            })
+            .collect::<Vec<_>>();
+        if let Some(target) = frontier.par_iter().find_first(|&n| success(n)) {
+            let parents = parents.into_inner();
+            let mut path = vec![target.clone()];
+            let mut node = target;
+            while let Some(Some(parent)) = parents.get(node) {
+                path.push(parent.clone());
+                node = parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Find all the nodes reachable from a start node using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search), expanding the nodes
+/// of every level in parallel on the rayon thread pool.
+///
+/// The nodes are returned by increasing distance from `start`, which comes first. The
+/// order of the nodes located at the same distance may vary between runs.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::par_bfs_reach;
+///
+/// let nodes = par_bfs_reach(0, |&n: &u32| [(n + 3) % 10, (n + 5) % 10]);
+/// assert_eq!(nodes.len(), 10);
+/// assert_eq!(nodes[0], 0);
+/// assert_eq!(nodes[1..3].iter().copied().max(), Some(5));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_bfs_reach<N, FN, IN>(start: N, successors: FN) -> Vec<N>
+where
+    N: Eq + Hash + Clone + Send + Sync,
+    FN: Fn(&N) -> IN + Sync,
+    IN: IntoIterator<Item = N>,
+{
+    use rayon::prelude::*;
+
+    let seen = ShardedMap::new();
+    seen.insert_if_absent(start.clone(), ());
+    let mut reached = vec![start];
+    let mut layer_start = 0;
+    while layer_start < reached.len() {
+        let next = reached[layer_start..]
+            .par_iter()
+            .flat_map_iter(|node| {
+                successors(node)
+                    .into_iter()
+                    .filter(|s| seen.insert_if_absent(s.clone(), ()))
+            })
+            .collect::<Vec<_>>();
+        layer_start = reached.len();
+        reached.extend(next);
+    }
+    reached
+}
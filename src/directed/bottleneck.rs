@@ -0,0 +1,158 @@
+//! Find paths whose quality is determined by their worst edge rather than by
+//! the sum of their edges.
+
+use super::reverse_path;
+use crate::FxIndexMap;
+use indexmap::map::Entry::{Occupied, Vacant};
+use num_traits::Bounded;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Compute a [widest path](https://en.wikipedia.org/wiki/Widest_path_problem), also
+/// called maximum bottleneck path, that is a path maximizing the smallest capacity
+/// of its edges.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the capacity
+///   of the edge going from the node to the successor.
+/// - `success` checks whether the goal has been reached.
+///
+/// The path is returned along with its bottleneck, which is the capacity of its narrowest
+/// edge, or `C::max_value()` if the start node is already a goal. `None` is returned if
+/// no path can be found.
+///
+/// This is a variant of [`dijkstra`](super::dijkstra::dijkstra) where the widest
+/// bottleneck found so far is expanded first. Capacities can be of any ordered type,
+/// and may be negative.
+///
+/// # Example
+///
+/// Find the route with the highest bandwidth between two routers.
+///
+/// ```
+/// use pathfinding::prelude::widest_path;
+///
+/// let links: [(char, char, u32); 4] = [('a', 'b', 100), ('b', 'd', 10), ('a', 'c', 40), ('c', 'd', 50)];
+/// let successors = |&n: &char| {
+///     links.iter().filter(move |l| l.0 == n).map(|l| (l.1, l.2))
+/// };
+/// assert_eq!(
+///     widest_path(&'a', successors, |&n| n == 'd'),
+///     Some((vec!['a', 'c', 'd'], 40))
+/// );
+/// ```
+pub fn widest_path<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Bounded,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    // Maximizing the smallest capacity is minimizing the largest reversed capacity.
+    bottleneck_search(
+        start,
+        Reverse(C::max_value()),
+        |node| successors(node).into_iter().map(|(n, c)| (n, Reverse(c))),
+        success,
+    )
+    .map(|(path, Reverse(c))| (path, c))
+}
+
+/// Compute a minimax path, that is a path minimizing the largest cost of its edges.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost of
+///   the edge going from the node to the successor.
+/// - `success` checks whether the goal has been reached.
+///
+/// The path is returned along with the cost of its most expensive edge, or
+/// `C::min_value()` if the start node is already a goal. `None` is returned if no path
+/// can be found.
+///
+/// This is the dual of [`widest_path`], and returns results of the same shape. It is
+/// useful for risk-averse routing, where the risk of a path is the one of its most
+/// dangerous edge. Costs can be of any ordered type, and may be negative.
+///
+/// # Example
+///
+/// Find the path whose steepest slope is the smallest one.
+///
+/// ```
+/// use pathfinding::prelude::minimax_path;
+///
+/// let slopes: [(char, char, u32); 4] = [('a', 'b', 5), ('b', 'd', 30), ('a', 'c', 12), ('c', 'd', 15)];
+/// let successors = |&n: &char| {
+///     slopes.iter().filter(move |l| l.0 == n).map(|l| (l.1, l.2))
+/// };
+/// assert_eq!(
+///     minimax_path(&'a', successors, |&n| n == 'd'),
+///     Some((vec!['a', 'c', 'd'], 15))
+/// );
+/// ```
+pub fn minimax_path<N, C, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Bounded,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    bottleneck_search(start, C::min_value(), successors, success)
+}
+
+/// Compute a path minimizing the largest cost of its edges, starting with `initial` as
+/// the cost of the empty path.
+fn bottleneck_search<N, C, FN, IN, FS>(
+    start: &N,
+    initial: C,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::MAX, initial));
+    let mut to_see = BinaryHeap::new();
+    to_see.push(Reverse((initial, 0)));
+    while let Some(Reverse((cost, index))) = to_see.pop() {
+        let (node, &(_, best)) = parents.get_index(index).unwrap(); // Cannot fail
+        // A better way to reach this node has been found since this entry was queued.
+        if cost > best {
+            continue;
+        }
+        if success(node) {
+            return Some((reverse_path(&parents, |&(p, _)| p, index), cost));
+        }
+        for (successor, edge) in successors(&node.clone()) {
+            let new_cost = cost.max(edge);
+            let n = match parents.entry(successor) {
+                Vacant(e) => {
+                    let n = e.index();
+                    e.insert((index, new_cost));
+                    n
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        e.insert((index, new_cost));
+                        e.index()
+                    } else {
+                        continue;
+                    }
+                }
+            };
+            to_see.push(Reverse((new_cost, n)));
+        }
+    }
+    None
+}
@@ -0,0 +1,245 @@
+//! Find conflict-free paths for several agents sharing a graph using
+//! [Conflict-Based Search](https://www.aaai.org/ocs/index.php/AAAI/AAAI12/paper/viewFile/5062/5239).
+
+use super::astar::astar;
+use super::bfs::bfs_reach;
+use rustc_hash::FxHashSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// A constraint forbidding an agent from being somewhere at a given time.
+#[derive(Clone, Debug)]
+enum Constraint<N> {
+    /// The agent cannot be at this node at this time.
+    Vertex(N, usize),
+    /// The agent cannot move from the first node to the second one between this
+    /// time and the next one.
+    Edge(N, N, usize),
+}
+
+#[derive(Clone, Debug)]
+struct Conflict<N> {
+    agents: (usize, usize),
+    constraints: (Constraint<N>, Constraint<N>),
+}
+
+struct Node<N> {
+    constraints: Vec<(usize, Constraint<N>)>,
+    paths: Vec<Vec<N>>,
+}
+
+/// Compute conflict-free paths for several agents moving simultaneously in a shared
+/// graph, minimizing the sum of the costs of all the paths.
+///
+/// - `agents` contains the start and goal nodes of every agent.
+/// - `successors` returns a list of successors for a given node. Every move takes one
+///   time step, and agents may also wait where they are for one time step.
+/// - `heuristic` returns an approximation of the number of moves needed to go from a node
+///   to a goal. It must not overestimate this number.
+///
+/// Two agents conflict if they are on the same node at the same time, or if they swap
+/// their positions between two consecutive time steps. Once its goal is reached, an agent
+/// stays there and may still conflict with other agents.
+///
+/// The high-level search explores a tree whose nodes add constraints to the agents
+/// involved in conflicts, while the paths of individual agents are found by
+/// [`astar`] over (node, time) pairs, respecting these constraints.
+///
+/// The returned paths contain the node occupied by every agent at every time step until
+/// it reaches its goal for good, starting with its start node. The cost of a path is its
+/// length minus one, waiting time included.
+///
+/// `None` is returned if the goal of an agent cannot be reached from its start, if
+/// two agents share the same start or the same goal, or if the constraints cannot be
+/// satisfied. In other cases where no solution exists, for example when two agents
+/// must swap their positions in a dead-end corridor, the search may not terminate.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{cbs, Grid};
+///
+/// // Two robots must go through a corridor in opposite directions, and can let the
+/// // other one go by using a niche at (2, 1).
+/// let mut grid = Grid::new(5, 2);
+/// for x in 0..5 {
+///     grid.add_vertex((x, 0));
+/// }
+/// grid.add_vertex((2, 1));
+///
+/// let paths = cbs(
+///     &[((0, 0), (4, 0)), ((4, 0), (0, 0))],
+///     |&n| grid.neighbours(n),
+///     |&n, &goal| grid.distance(n, goal),
+/// )
+/// .unwrap();
+/// assert_eq!(paths[0].last(), Some(&(4, 0)));
+/// assert_eq!(paths[1].last(), Some(&(0, 0)));
+/// // One robot enters the niche, while the other one waits for one time step.
+/// assert_eq!(paths[0].len() + paths[1].len() - 2, 11);
+/// ```
+pub fn cbs<N, FN, IN, FH>(
+    agents: &[(N, N)],
+    mut successors: FN,
+    mut heuristic: FH,
+) -> Option<Vec<Vec<N>>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FH: FnMut(&N, &N) -> usize,
+{
+    let starts = agents.iter().map(|(s, _)| s).collect::<FxHashSet<_>>();
+    let goals = agents.iter().map(|(_, g)| g).collect::<FxHashSet<_>>();
+    if starts.len() != agents.len() || goals.len() != agents.len() {
+        return None;
+    }
+    for (start, goal) in agents {
+        if !bfs_reach(start.clone(), &mut successors).any(|n| n == *goal) {
+            return None;
+        }
+    }
+    let paths = agents
+        .iter()
+        .map(|(start, goal)| low_level(start, goal, &[], &mut successors, &mut heuristic))
+        .collect::<Option<Vec<_>>>()?;
+    let mut nodes = vec![Node {
+        constraints: Vec::new(),
+        paths,
+    }];
+    let mut to_see = BinaryHeap::new();
+    to_see.push(Reverse((sum_of_costs(&nodes[0].paths), 0)));
+    while let Some(Reverse((_, index))) = to_see.pop() {
+        let Some(conflict) = first_conflict(&nodes[index].paths) else {
+            return Some(std::mem::take(&mut nodes[index].paths));
+        };
+        let (a, b) = conflict.agents;
+        for (agent, constraint) in [(a, conflict.constraints.0), (b, conflict.constraints.1)] {
+            let mut constraints = nodes[index].constraints.clone();
+            constraints.push((agent, constraint));
+            let agent_constraints = constraints
+                .iter()
+                .filter(|&&(i, _)| i == agent)
+                .map(|(_, c)| c.clone())
+                .collect::<Vec<_>>();
+            let (start, goal) = &agents[agent];
+            if let Some(path) = low_level(
+                start,
+                goal,
+                &agent_constraints,
+                &mut successors,
+                &mut heuristic,
+            ) {
+                let mut paths = nodes[index].paths.clone();
+                paths[agent] = path;
+                to_see.push(Reverse((sum_of_costs(&paths), nodes.len())));
+                nodes.push(Node { constraints, paths });
+            }
+        }
+        // The paths of explored nodes are not needed anymore.
+        nodes[index].paths = Vec::new();
+    }
+    None
+}
+
+fn sum_of_costs<N>(paths: &[Vec<N>]) -> usize {
+    paths.iter().map(|p| p.len() - 1).sum()
+}
+
+/// Shortest path from `start` to `goal` respecting the constraints of an agent.
+fn low_level<N, FN, IN, FH>(
+    start: &N,
+    goal: &N,
+    constraints: &[Constraint<N>],
+    successors: &mut FN,
+    heuristic: &mut FH,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FH: FnMut(&N, &N) -> usize,
+{
+    let mut vertices = FxHashSet::default();
+    let mut edges = FxHashSet::default();
+    let mut last = None;
+    let mut goal_last = None;
+    for constraint in constraints {
+        let time = match constraint {
+            Constraint::Vertex(n, t) => {
+                if n == goal {
+                    goal_last = goal_last.max(Some(*t));
+                }
+                vertices.insert((n, *t));
+                *t
+            }
+            Constraint::Edge(from, to, t) => {
+                edges.insert((from, to, *t));
+                *t + 1
+            }
+        };
+        last = last.max(Some(time));
+    }
+    // After the last constraint, time does not matter anymore and all later times are
+    // merged into a single one, which keeps the search space finite.
+    let cap = last.map_or(0, |t| t + 1);
+    let (path, _) = astar(
+        &(start.clone(), 0),
+        |(node, time)| {
+            let mut moves = successors(node).into_iter().collect::<Vec<_>>();
+            moves.push(node.clone());
+            moves
+                .into_iter()
+                .filter(|next| {
+                    !vertices.contains(&(next, time + 1)) && !edges.contains(&(node, next, *time))
+                })
+                .map(|next| ((next, (time + 1).min(cap)), 1))
+                .collect::<Vec<_>>()
+        },
+        |(node, _)| heuristic(node, goal),
+        |(node, time)| node == goal && goal_last.is_none_or(|t| *time > t),
+    )?;
+    Some(path.into_iter().map(|(node, _)| node).collect())
+}
+
+/// Position of an agent following `path` at time `time`, staying on the goal at the end.
+fn position<N>(path: &[N], time: usize) -> &N {
+    &path[time.min(path.len() - 1)]
+}
+
+fn first_conflict<N>(paths: &[Vec<N>]) -> Option<Conflict<N>>
+where
+    N: Eq + Clone,
+{
+    let len = paths.iter().map(Vec::len).max().unwrap_or(0);
+    for time in 0..len {
+        for (a, path_a) in paths.iter().enumerate() {
+            for (b, path_b) in paths.iter().enumerate().skip(a + 1) {
+                let (pos_a, pos_b) = (position(path_a, time), position(path_b, time));
+                if pos_a == pos_b {
+                    return Some(Conflict {
+                        agents: (a, b),
+                        constraints: (
+                            Constraint::Vertex(pos_a.clone(), time),
+                            Constraint::Vertex(pos_b.clone(), time),
+                        ),
+                    });
+                }
+                if time > 0 {
+                    let (prev_a, prev_b) = (position(path_a, time - 1), position(path_b, time - 1));
+                    if prev_a == pos_b && prev_b == pos_a {
+                        return Some(Conflict {
+                            agents: (a, b),
+                            constraints: (
+                                Constraint::Edge(prev_a.clone(), pos_a.clone(), time - 1),
+                                Constraint::Edge(prev_b.clone(), pos_b.clone(), time - 1),
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    None
+}
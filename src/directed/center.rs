@@ -0,0 +1,147 @@
+//! Find the [center](https://en.wikipedia.org/wiki/Graph_center) and the
+//! [1-median](https://en.wikipedia.org/wiki/Geometric_median) of a directed graph.
+
+use super::shortest_cycle::index_graph;
+use num_traits::Zero;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Find the center of a weighted directed graph, that is a node whose
+/// [eccentricity](https://en.wikipedia.org/wiki/Distance_(graph_theory)), the largest cost
+/// needed to reach any other node from it, is minimal.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `successors` are
+///   also taken into account.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. Costs must be non-negative.
+///
+/// The center is returned along with its eccentricity. If several nodes qualify, the one
+/// which comes first in the exploration order is returned. Nodes which cannot reach every
+/// other node are never considered, and `None` is returned if there is no node able to reach
+/// all the others. For an undirected graph, `successors` must return the edges in both
+/// directions.
+///
+/// A Dijkstra search is run from every node, and stops as soon as the node is known not to
+/// improve on the best one found so far.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::graph_center;
+///
+/// // A path of 5 nodes, whose middle node is the center.
+/// let successors = |&n: &i32| [(n - 1, 1), (n + 1, 1)].into_iter().filter(|&(m, _)| (0..5).contains(&m));
+/// assert_eq!(graph_center(&[0], successors), Some((2, 2)));
+/// ```
+pub fn graph_center<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(N, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (indices, adjacency) = index_graph(nodes, successors);
+    let mut costs = vec![None; adjacency.len()];
+    let mut best: Option<(usize, C)> = None;
+    for start in 0..adjacency.len() {
+        let mut eccentricity = Zero::zero();
+        // Nodes are reached by increasing cost, the last one giving the eccentricity.
+        let complete = sweep(&adjacency, start, &mut costs, |cost| {
+            eccentricity = cost;
+            best.is_none_or(|(_, b)| cost < b)
+        });
+        if complete {
+            best = Some((start, eccentricity));
+        }
+    }
+    best.map(|(node, eccentricity)| (indices[node].clone(), eccentricity))
+}
+
+/// Find the 1-median of a weighted directed graph, that is a node whose total cost to reach
+/// all the other nodes is minimal.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `successors` are
+///   also taken into account.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. Costs must be non-negative.
+///
+/// The median is returned along with its total cost. If several nodes qualify, the one
+/// which comes first in the exploration order is returned. Nodes which cannot reach every
+/// other node are never considered, and `None` is returned if there is no node able to reach
+/// all the others. For an undirected graph, `successors` must return the edges in both
+/// directions.
+///
+/// A Dijkstra search is run from every node, and stops as soon as the costs accumulated
+/// so far exceed the total cost of the best node found.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::graph_median;
+///
+/// // A star whose leaves are far from each other: the hub is the median.
+/// let successors = |&n: &u8| match n {
+///     0 => vec![(1, 3), (2, 3), (3, 3)],
+///     _ => vec![(0, 3)],
+/// };
+/// assert_eq!(graph_median(&[1], successors), Some((0, 9)));
+/// ```
+pub fn graph_median<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(N, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (indices, adjacency) = index_graph(nodes, successors);
+    let mut costs = vec![None; adjacency.len()];
+    let mut best: Option<(usize, C)> = None;
+    for start in 0..adjacency.len() {
+        let mut total = C::zero();
+        let complete = sweep(&adjacency, start, &mut costs, |cost| {
+            total = total + cost;
+            best.is_none_or(|(_, b)| total < b)
+        });
+        if complete {
+            best = Some((start, total));
+        }
+    }
+    best.map(|(node, total)| (indices[node].clone(), total))
+}
+
+/// Run a Dijkstra search from `start`, calling `visit` with the cost of every node in the
+/// order they are reached. The search is interrupted as soon as `visit` returns `false`.
+/// Return `true` if every node has been reached and visited.
+fn sweep<C, FV>(
+    adjacency: &[Vec<(usize, C)>],
+    start: usize,
+    costs: &mut [Option<C>],
+    mut visit: FV,
+) -> bool
+where
+    C: Zero + Ord + Copy,
+    FV: FnMut(C) -> bool,
+{
+    costs.fill(None);
+    costs[start] = Some(Zero::zero());
+    let mut to_see = BinaryHeap::from([Reverse((C::zero(), start))]);
+    let mut reached = 0;
+    while let Some(Reverse((cost, node))) = to_see.pop() {
+        if costs[node].is_some_and(|c| cost > c) {
+            continue;
+        }
+        if !visit(cost) {
+            return false;
+        }
+        reached += 1;
+        for &(next, edge) in &adjacency[node] {
+            let new_cost = cost + edge;
+            if costs[next].is_none_or(|c| new_cost < c) {
+                costs[next] = Some(new_cost);
+                to_see.push(Reverse((new_cost, next)));
+            }
+        }
+    }
+    reached == adjacency.len()
+}
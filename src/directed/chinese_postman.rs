@@ -0,0 +1,150 @@
+//! Solve the [Chinese postman problem](https://en.wikipedia.org/wiki/Chinese_postman_problem)
+//! (or route inspection problem) in a directed graph.
+
+use super::dijkstra::{build_path, dijkstra_all};
+use crate::kuhn_munkres::kuhn_munkres_min;
+use crate::matrix::Matrix;
+use crate::undirected::chinese_postman::ChinesePostmanError;
+use crate::{FxIndexMap, FxIndexSet};
+use num_traits::{Bounded, Signed, Zero};
+use std::hash::Hash;
+use std::iter::{Sum, repeat_n};
+
+/// Find a closed walk of minimum cost going through every edge of a directed graph
+/// at least once.
+///
+/// - `edges` is the list of edges of the graph, given as `(from, to, cost)` tuples.
+///   Costs must be non-negative.
+///
+/// When some nodes have more incoming than outgoing edges, or the other way around,
+/// some edges must be used several times. The cheapest way to duplicate edges so that
+/// every node has as many incoming and outgoing edges is found by assigning the excess
+/// edges to the missing ones through shortest paths, using the
+/// [Kuhn-Munkres algorithm](crate::kuhn_munkres). A walk is then built from the
+/// resulting Eulerian graph.
+///
+/// The walk is returned as a list of nodes starting and ending with the origin of the
+/// first edge, along with its total cost. An empty list of edges gives an empty walk.
+///
+/// # Errors
+///
+/// [`ChinesePostmanError::Disconnected`] is returned if no closed walk exists because
+/// the graph is not strongly connected.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::chinese_postman_directed;
+///
+/// // a -> b -> c -> a, with an extra b -> a edge which requires going through
+/// // a -> b a second time.
+/// let edges = [('a', 'b', 1), ('b', 'c', 2), ('c', 'a', 3), ('b', 'a', 4)];
+/// let (walk, cost) = chinese_postman_directed(&edges).unwrap();
+/// assert_eq!(cost, 11);
+/// assert_eq!(walk.len(), 6);
+/// assert_eq!(walk.first(), Some(&'a'));
+/// assert_eq!(walk.last(), Some(&'a'));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn chinese_postman_directed<N, C>(
+    edges: &[(N, N, C)],
+) -> Result<(Vec<N>, C), ChinesePostmanError>
+where
+    N: Eq + Hash + Clone,
+    C: Bounded + Sum<C> + Signed + Zero + Ord + Copy,
+{
+    let nodes = edges
+        .iter()
+        .flat_map(|(a, b, _)| [a, b])
+        .collect::<FxIndexSet<_>>();
+    let index = |n| nodes.get_index_of(n).unwrap(); // Cannot fail
+    let mut arcs = edges
+        .iter()
+        .map(|(a, b, _)| (index(a), index(b)))
+        .collect::<Vec<_>>();
+    let mut successors = vec![vec![]; nodes.len()];
+    let mut balance = vec![0isize; nodes.len()];
+    for (&(a, b), &(_, _, c)) in arcs.iter().zip(edges) {
+        successors[a].push((b, c));
+        balance[a] += 1;
+        balance[b] -= 1;
+    }
+    let mut cost = edges.iter().fold(C::zero(), |cost, &(_, _, c)| cost + c);
+
+    // Every edge missing from a node is matched with an edge in excess, and the
+    // shortest path between them is added to the graph.
+    let units = |sign: isize| {
+        balance
+            .iter()
+            .enumerate()
+            .flat_map(move |(n, &b)| repeat_n(n, usize::try_from(b * sign).unwrap_or_default()))
+            .collect::<Vec<_>>()
+    };
+    let (sources, targets) = (units(-1), units(1));
+    if !sources.is_empty() {
+        let mut reachables = FxIndexMap::default();
+        for &s in &sources {
+            reachables
+                .entry(s)
+                .or_insert_with(|| dijkstra_all(&s, |&n| successors[n].iter().copied()));
+        }
+        let mut weights = Matrix::new(sources.len(), targets.len(), C::zero());
+        for (i, s) in sources.iter().enumerate() {
+            for (j, t) in targets.iter().enumerate() {
+                weights[(i, j)] = reachables[s]
+                    .get(t)
+                    .ok_or(ChinesePostmanError::Disconnected)?
+                    .1;
+            }
+        }
+        let (extra, assignments) = kuhn_munkres_min(&weights);
+        cost = cost + extra;
+        for (s, &j) in sources.iter().zip(&assignments) {
+            let path = build_path(&targets[j], &reachables[s]);
+            arcs.extend(path.windows(2).map(|w| (w[0], w[1])));
+        }
+    }
+
+    let Some(&(start, _)) = arcs.first() else {
+        return Ok((vec![], cost));
+    };
+    let walk = eulerian_circuit(nodes.len(), &arcs, true, start)
+        .ok_or(ChinesePostmanError::Disconnected)?;
+    Ok((walk.into_iter().map(|n| nodes[n].clone()).collect(), cost))
+}
+
+/// Build a closed walk starting from `start` and going exactly once through every
+/// one of the `arcs` linking nodes smaller than `node_count`, using
+/// [Hierholzer's algorithm](https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm).
+/// When `directed` is `false`, arcs can be used in both directions. Return `None` if
+/// some arcs cannot be reached.
+pub(crate) fn eulerian_circuit(
+    node_count: usize,
+    arcs: &[(usize, usize)],
+    directed: bool,
+    start: usize,
+) -> Option<Vec<usize>> {
+    let mut adjacency = vec![vec![]; node_count];
+    for (i, &(a, b)) in arcs.iter().enumerate() {
+        adjacency[a].push((i, b));
+        if !directed {
+            adjacency[b].push((i, a));
+        }
+    }
+    let mut used = vec![false; arcs.len()];
+    let mut walk = Vec::with_capacity(arcs.len() + 1);
+    let mut stack = vec![start];
+    while let Some(&n) = stack.last() {
+        if let Some((i, next)) = adjacency[n].pop() {
+            if !used[i] {
+                used[i] = true;
+                stack.push(next);
+            }
+        } else {
+            walk.push(n);
+            stack.pop();
+        }
+    }
+    walk.reverse();
+    (walk.len() == arcs.len() + 1).then_some(walk)
+}
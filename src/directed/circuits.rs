@@ -0,0 +1,193 @@
+//! Enumerate the elementary circuits of a directed graph using
+//! [Johnson's algorithm](https://www.cs.tufts.edu/comp/150GA/homeworks/hw1/Johnson%2075.PDF).
+
+use crate::FxIndexSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Enumerate lazily all the elementary circuits of a directed graph, that is the cycles
+/// which do not go through the same node twice.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `successors` are
+///   also taken into account.
+/// - `successors` returns a list of successors for a given node. Duplicate successors
+///   are ignored.
+///
+/// Every circuit is returned once, starting with its node which comes first in the
+/// exploration order, and without repeating this node at the end. A node which is its
+/// own successor forms a circuit of length one.
+///
+/// The graph is explored when this function is called, then circuits are computed one
+/// by one when iterating, which makes this suitable for graphs with a very large number
+/// of circuits when only some of them are needed. Enumerating all the circuits takes
+/// `O((n + e) × (n + c))` time, where `n` is the number of nodes, `e` the number of edges
+/// and `c` the number of circuits.
+///
+/// Unlike [`brent`](super::cycle_detection::brent) and
+/// [`floyd`](super::cycle_detection::floyd), which find the cycle of a sequence where
+/// every node has a single successor, this works on any directed graph.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::elementary_circuits;
+///
+/// let successors = |&n: &u8| match n {
+///     0 => vec![1],
+///     1 => vec![0, 2],
+///     2 => vec![0, 2],
+///     _ => vec![],
+/// };
+/// let mut circuits = elementary_circuits(&[0], successors).collect::<Vec<_>>();
+/// circuits.sort();
+/// assert_eq!(circuits, vec![vec![0, 1], vec![0, 1, 2], vec![2]]);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn elementary_circuits<N, FN, IN>(nodes: &[N], mut successors: FN) -> ElementaryCircuits<N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut indices = nodes.iter().cloned().collect::<FxIndexSet<_>>();
+    let mut adjacency: Vec<Vec<usize>> = Vec::new();
+    while adjacency.len() < indices.len() {
+        let node = indices.get_index(adjacency.len()).unwrap().clone(); // Cannot fail
+        let mut next = successors(&node)
+            .into_iter()
+            .map(|n| indices.insert_full(n).0)
+            .collect::<Vec<_>>();
+        next.sort_unstable();
+        next.dedup();
+        adjacency.push(next);
+    }
+    let mut predecessors = vec![Vec::new(); adjacency.len()];
+    for (u, next) in adjacency.iter().enumerate() {
+        for &v in next {
+            predecessors[v].push(u);
+        }
+    }
+    let n = adjacency.len();
+    ElementaryCircuits {
+        nodes: indices,
+        adjacency,
+        predecessors,
+        start: 0,
+        in_component: vec![false; n],
+        blocked: vec![false; n],
+        closed: vec![false; n],
+        blocked_by: vec![Vec::new(); n],
+        path: Vec::new(),
+        stack: Vec::new(),
+    }
+}
+
+/// Iterator over the elementary circuits, returned by [`elementary_circuits`].
+pub struct ElementaryCircuits<N> {
+    nodes: FxIndexSet<N>,
+    adjacency: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    // Next node to use as the smallest node of the circuits.
+    start: usize,
+    // Strongly connected component of the current start node among the nodes which
+    // are not smaller.
+    in_component: Vec<bool>,
+    blocked: Vec<bool>,
+    // Whether a circuit has been found through the node since it was added to the path.
+    closed: Vec<bool>,
+    // Nodes to unblock when a node gets unblocked.
+    blocked_by: Vec<Vec<usize>>,
+    path: Vec<usize>,
+    // Nodes of the current path along with the position of their next successor.
+    stack: Vec<(usize, usize)>,
+}
+
+impl<N> ElementaryCircuits<N> {
+    // Mark the nodes reachable from `start` using `edges` and not smaller than `start`.
+    fn reach(edges: &[Vec<usize>], start: usize) -> Vec<bool> {
+        let mut seen = vec![false; edges.len()];
+        seen[start] = true;
+        let mut to_see = VecDeque::from([start]);
+        while let Some(u) = to_see.pop_front() {
+            for &v in &edges[u] {
+                if v > start && !seen[v] {
+                    seen[v] = true;
+                    to_see.push_back(v);
+                }
+            }
+        }
+        seen
+    }
+
+    fn prepare(&mut self, start: usize) {
+        let forward = Self::reach(&self.adjacency, start);
+        let backward = Self::reach(&self.predecessors, start);
+        for u in 0..self.adjacency.len() {
+            self.in_component[u] = forward[u] && backward[u];
+            self.blocked[u] = false;
+            self.closed[u] = false;
+            self.blocked_by[u].clear();
+        }
+        self.blocked[start] = true;
+        self.path.push(start);
+        self.stack.push((start, 0));
+    }
+
+    fn unblock(&mut self, node: usize) {
+        let mut to_unblock = vec![node];
+        while let Some(u) = to_unblock.pop() {
+            if self.blocked[u] {
+                self.blocked[u] = false;
+                to_unblock.append(&mut self.blocked_by[u]);
+            }
+        }
+    }
+}
+
+impl<N: Clone> Iterator for ElementaryCircuits<N> {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(&mut (u, ref mut position)) = self.stack.last_mut() else {
+                if self.start == self.adjacency.len() {
+                    return None;
+                }
+                self.start += 1;
+                self.prepare(self.start - 1);
+                continue;
+            };
+            let start = self.path[0];
+            let next = self.adjacency[u][*position..]
+                .iter()
+                .position(|&v| self.in_component[v]);
+            if let Some(offset) = next {
+                *position += offset + 1;
+                let v = self.adjacency[u][*position - 1];
+                if v == start {
+                    for &w in &self.path {
+                        self.closed[w] = true;
+                    }
+                    return Some(self.path.iter().map(|&w| self.nodes[w].clone()).collect());
+                } else if !self.blocked[v] {
+                    self.blocked[v] = true;
+                    self.closed[v] = false;
+                    self.path.push(v);
+                    self.stack.push((v, 0));
+                }
+            } else {
+                if self.closed[u] {
+                    self.unblock(u);
+                } else {
+                    for &v in &self.adjacency[u] {
+                        if self.in_component[v] && !self.blocked_by[v].contains(&u) {
+                            self.blocked_by[v].push(u);
+                        }
+                    }
+                }
+                self.stack.pop();
+                self.path.pop();
+            }
+        }
+    }
+}
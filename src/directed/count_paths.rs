@@ -1,36 +1,46 @@
 //! Count the total number of possible paths to reach a destination.
 
-use std::{collections::HashMap, hash::{BuildHasher, Hash}};
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, BuildHasherDefault, Hash},
+    ops::{AddAssign, Rem},
+};
 
-use rustc_hash::FxHashMap;
+use num_traits::{One, Zero};
+use rustc_hash::{FxHashMap, FxHasher};
 
-fn cached_count_paths<T, FN, IN, FS, H>(
+fn cached_count_paths<T, C, FN, IN, FS, FR, H>(
     start: T,
     successors: &mut FN,
     success: &mut FS,
-    cache: &mut HashMap<T, usize, H>,
-) -> usize
+    reduce: &FR,
+    cache: &mut HashMap<T, C, H>,
+) -> C
 where
     T: Eq + Hash,
+    C: Zero + One + AddAssign + Clone,
     FN: FnMut(&T) -> IN,
     IN: IntoIterator<Item = T>,
     FS: FnMut(&T) -> bool,
+    FR: Fn(C) -> C,
     H: BuildHasher,
 {
-    if let Some(&n) = cache.get(&start) {
-        return n;
+    if let Some(n) = cache.get(&start) {
+        return n.clone();
     }
 
     let count = if success(&start) {
-        1
+        reduce(C::one())
     } else {
-        successors(&start)
-            .into_iter()
-            .map(|successor| cached_count_paths(successor, successors, success, cache))
-            .sum()
+        let mut count = C::zero();
+        for successor in successors(&start) {
+            count += cached_count_paths(successor, successors, success, reduce, cache);
+            count = reduce(count);
+        }
+        count
     };
 
-    cache.insert(start, count);
+    cache.insert(start, count.clone());
 
     count
 }
@@ -56,17 +66,203 @@ where
 /// );
 /// assert_eq!(n, 3432);
 /// ```
-pub fn count_paths<T, FN, IN, FS>(start: T, mut successors: FN, mut success: FS) -> usize
+pub fn count_paths<T, FN, IN, FS>(start: T, successors: FN, success: FS) -> usize
+where
+    T: Eq + Hash,
+    FN: FnMut(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: FnMut(&T) -> bool,
+{
+    count_paths_with_hasher(
+        start,
+        successors,
+        success,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Count the total number of possible paths to reach a destination, using `C` to hold
+/// the count. There must be no loops in the graph, or the function will overflow its stack.
+///
+/// `C` can be a wider integer type such as `u128`, or an arbitrary precision integer type,
+/// when the number of paths does not fit in a `usize`. If `modulus` is given, the count
+/// is computed modulo this value, which must be positive, and every intermediate result
+/// is reduced so that no overflow can happen as long as twice the modulus fits in `C`.
+///
+/// # Example
+///
+/// On a 40x40 board, find the total paths from the bottom-left square to the top-right
+/// square, both exactly and modulo 1 000 000 007.
+///
+/// ```
+/// use pathfinding::prelude::count_paths_generic;
+///
+/// let successors = |&(x, y): &(u32, u32)| {
+///     [(x + 1, y), (x, y + 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| x < 40 && y < 40)
+/// };
+/// let n: u128 = count_paths_generic((0, 0), successors, |&c| c == (39, 39), None);
+/// assert_eq!(n, 27_217_014_869_199_032_015_600);
+/// let n = count_paths_generic((0, 0), successors, |&c| c == (39, 39), Some(1_000_000_007u64));
+/// assert_eq!(n, 929_264_847);
+/// ```
+pub fn count_paths_generic<T, C, FN, IN, FS>(
+    start: T,
+    successors: FN,
+    success: FS,
+    modulus: Option<C>,
+) -> C
+where
+    T: Eq + Hash,
+    C: Zero + One + AddAssign + Rem<Output = C> + Clone,
+    FN: FnMut(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: FnMut(&T) -> bool,
+{
+    count_paths_generic_with_hasher(
+        start,
+        successors,
+        success,
+        modulus,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Count the total number of possible paths to reach a destination using `C` to hold
+/// the count and a custom hasher. There must be no loops in the graph, or the function
+/// will overflow its stack.
+///
+/// See [`count_paths_generic`] for the meaning of `modulus`.
+pub fn count_paths_generic_with_hasher<T, C, FN, IN, FS, H>(
+    start: T,
+    mut successors: FN,
+    mut success: FS,
+    modulus: Option<C>,
+    hasher: H,
+) -> C
 where
     T: Eq + Hash,
+    C: Zero + One + AddAssign + Rem<Output = C> + Clone,
     FN: FnMut(&T) -> IN,
     IN: IntoIterator<Item = T>,
     FS: FnMut(&T) -> bool,
+    H: BuildHasher,
 {
     cached_count_paths(
         start,
         &mut successors,
         &mut success,
-        &mut FxHashMap::default(),
+        &move |n: C| match &modulus {
+            Some(m) => n % m.clone(),
+            None => n,
+        },
+        &mut HashMap::with_hasher(hasher),
     )
 }
+
+/// Count the total number of possible paths to reach a destination using a custom hasher.
+/// There must be no loops in the graph, or the function will overflow its stack.
+pub fn count_paths_with_hasher<T, FN, IN, FS, H>(
+    start: T,
+    mut successors: FN,
+    mut success: FS,
+    hasher: H,
+) -> usize
+where
+    T: Eq + Hash,
+    FN: FnMut(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: FnMut(&T) -> bool,
+    H: BuildHasher,
+{
+    cached_count_paths(
+        start,
+        &mut successors,
+        &mut success,
+        &|n| n,
+        &mut HashMap::with_hasher(hasher),
+    )
+}
+
+/// Count the number of possible paths to reach a destination, grouped by length, using
+/// `C` to hold the counts.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `success` checks whether the destination has been reached.
+/// - `max_len` is the maximum length, in number of edges, of the paths to count.
+/// - `modulus`, if given, is used to compute the counts modulo this value, as in
+///   [`count_paths_generic`].
+///
+/// The result has `max_len + 1` elements, the one at index `k` being the number of paths
+/// made of `k` edges. As with [`count_paths`], a path stops as soon as it reaches the
+/// destination. Paths may go through the same node several times, which makes this
+/// function usable on graphs with loops, where the total number of paths may be infinite.
+///
+/// This uses dynamic programming by level, and runs in `O(max_len × E)` where `E` is the
+/// number of edges reachable from `start` in `max_len` steps.
+///
+/// # Example
+///
+/// On a cycle of 4 nodes where every node is linked to its two neighbours, count the
+/// ways to go from 0 to 2, exactly and modulo 1 000.
+///
+/// ```
+/// use pathfinding::prelude::count_paths_with_length;
+///
+/// let successors = |&n: &u8| [(n + 1) % 4, (n + 3) % 4];
+/// let counts: Vec<u64> = count_paths_with_length(0, successors, |&n| n == 2, 6, None);
+/// assert_eq!(counts, vec![0, 0, 2, 0, 4, 0, 8]);
+/// let counts = count_paths_with_length(0, successors, |&n| n == 2, 20, Some(1000u32));
+/// assert_eq!(counts[20], 1024 % 1000);
+/// ```
+pub fn count_paths_with_length<T, C, FN, IN, FS>(
+    start: T,
+    mut successors: FN,
+    mut success: FS,
+    max_len: usize,
+    modulus: Option<C>,
+) -> Vec<C>
+where
+    T: Eq + Hash,
+    C: Zero + One + AddAssign + Rem<Output = C> + Clone,
+    FN: FnMut(&T) -> IN,
+    IN: IntoIterator<Item = T>,
+    FS: FnMut(&T) -> bool,
+{
+    let reduce = move |n: C| match &modulus {
+        Some(m) => n % m.clone(),
+        None => n,
+    };
+    let add = |total: &mut C, n: C| {
+        *total += n;
+        *total = reduce(std::mem::replace(total, C::zero()));
+    };
+    let mut counts = vec![C::zero(); max_len + 1];
+    // Number of paths of the current length ending on every node, which have not reached
+    // the destination yet.
+    let mut level = FxHashMap::default();
+    level.insert(start, reduce(C::one()));
+    for (len, count) in counts.iter_mut().enumerate() {
+        level.retain(|node, n| {
+            if success(node) {
+                add(count, n.clone());
+                false
+            } else {
+                true
+            }
+        });
+        if len == max_len || level.is_empty() {
+            break;
+        }
+        let mut next = FxHashMap::default();
+        for (node, n) in level {
+            for successor in successors(&node) {
+                add(next.entry(successor).or_insert_with(C::zero), n.clone());
+            }
+        }
+        level = next;
+    }
+    counts
+}
@@ -1,4 +1,7 @@
-//! Identify a cycle in an infinite sequence.
+//! Identify a cycle in an infinite sequence or in a directed graph.
+
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
 
 /// Identify a cycle in an infinite sequence using Floyd's algorithm.
 /// Return the cycle size, the first element, and the index of first element.
@@ -57,3 +60,69 @@ where
     }
     (lam, hare, mu)
 }
+
+/// Find a cycle in a directed graph, where every node may have any number of successors.
+///
+/// - `starts` is a list of nodes to explore the graph from.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The nodes of a cycle reachable from `starts` are returned in order, without repeating
+/// the first one at the end, or `None` if there is no such cycle. A node which is its
+/// own successor forms a cycle of one node.
+///
+/// This uses an iterative depth-first search, so it works on deep graphs, and stops as
+/// soon as a cycle is found. Unlike [`floyd`] and [`brent`], this works on finite
+/// graphs and returns the nodes making up the cycle.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::find_cycle;
+///
+/// // A dependency graph with a cycle between b, c and d.
+/// let successors = |&n: &char| match n {
+///     'a' => vec!['b', 'e'],
+///     'b' => vec!['c'],
+///     'c' => vec!['d', 'e'],
+///     'd' => vec!['b'],
+///     _ => vec![],
+/// };
+/// assert_eq!(find_cycle(&['a'], successors), Some(vec!['b', 'c', 'd']));
+/// assert_eq!(find_cycle(&['e'], successors), None);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn find_cycle<N, FN, IN>(starts: &[N], mut successors: FN) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    // Nodes on the current path are mapped to their position in the path, and nodes
+    // whose successors have all been explored are mapped to `None`.
+    let mut colors: FxHashMap<N, Option<usize>> = FxHashMap::default();
+    for start in starts {
+        if colors.contains_key(start) {
+            continue;
+        }
+        colors.insert(start.clone(), Some(0));
+        let mut path = vec![start.clone()];
+        let mut stack = vec![successors(start).into_iter()];
+        while let Some(next) = stack.last_mut() {
+            let Some(node) = next.next() else {
+                stack.pop();
+                colors.insert(path.pop().unwrap(), None); // Cannot fail
+                continue;
+            };
+            match colors.get(&node) {
+                Some(&Some(position)) => return Some(path.split_off(position)),
+                Some(None) => (),
+                None => {
+                    colors.insert(node.clone(), Some(path.len()));
+                    stack.push(successors(&node).into_iter());
+                    path.push(node);
+                }
+            }
+        }
+    }
+    None
+}
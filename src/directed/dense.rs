@@ -0,0 +1,287 @@
+//! Search algorithms for graphs whose nodes are dense `usize` identifiers.
+//!
+//! When nodes are numbered from `0` to `node_count - 1`, as in a graph stored in
+//! [compressed sparse row](https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format))
+//! form, the parents and costs of the explored nodes can be kept in vectors indexed
+//! by the nodes themselves. The functions of this module behave like their generic
+//! counterparts, but never hash a node.
+//!
+//! All the functions panic if a node greater than or equal to `node_count` is
+//! encountered.
+
+use num_traits::Zero;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Compute a shortest path using the [breadth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Breadth-first_search) in a graph whose nodes
+/// are all smaller than `node_count`.
+///
+/// This function behaves like [`bfs`](super::bfs::bfs), but records the explored
+/// nodes in a vector instead of a hash map.
+///
+/// # Panics
+///
+/// This function panics if `start` or a successor is greater than or equal to `node_count`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::bfs_dense;
+///
+/// // A ring of 10 nodes.
+/// let result = bfs_dense(&0, 10, |&n| [(n + 1) % 10, (n + 9) % 10], |&n| n == 7);
+/// assert_eq!(result, Some(vec![0, 9, 8, 7]));
+/// ```
+pub fn bfs_dense<FN, IN, FS>(
+    start: &usize,
+    node_count: usize,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<Vec<usize>>
+where
+    FN: FnMut(&usize) -> IN,
+    IN: IntoIterator<Item = usize>,
+    FS: FnMut(&usize) -> bool,
+{
+    let mut parents = vec![None; node_count];
+    parents[*start] = Some(usize::MAX);
+    let mut to_see = VecDeque::from([*start]);
+    while let Some(node) = to_see.pop_front() {
+        if success(&node) {
+            return Some(reverse_path(node, |n| {
+                parents[n].filter(|&p| p != usize::MAX)
+            }));
+        }
+        for next in successors(&node) {
+            if parents[next].is_none() {
+                parents[next] = Some(node);
+                to_see.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) in a graph whose nodes
+/// are all smaller than `node_count`.
+///
+/// This function behaves like [`dijkstra`](super::dijkstra::dijkstra), but records the
+/// explored nodes in vectors instead of a hash map.
+///
+/// # Panics
+///
+/// This function panics if `start` or a successor is greater than or equal to `node_count`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_dense;
+///
+/// // A graph stored in compressed sparse row form.
+/// let offsets = [0, 2, 3, 4, 4];
+/// let edges = [(1, 1), (2, 5), (2, 1), (3, 1)];
+/// let successors = |&n: &usize| edges[offsets[n]..offsets[n + 1]].iter().copied();
+/// let result = dijkstra_dense(&0, 4, successors, |&n| n == 3);
+/// assert_eq!(result, Some((vec![0, 1, 2, 3], 3)));
+/// ```
+pub fn dijkstra_dense<C, FN, IN, FS>(
+    start: &usize,
+    node_count: usize,
+    successors: FN,
+    success: FS,
+) -> Option<(Vec<usize>, C)>
+where
+    C: Zero + Ord + Copy,
+    FN: FnMut(&usize) -> IN,
+    IN: IntoIterator<Item = (usize, C)>,
+    FS: FnMut(&usize) -> bool,
+{
+    astar_dense(start, node_count, successors, |_| Zero::zero(), success)
+}
+
+/// Determine all reachable nodes from a starting point as well as the minimum cost to reach
+/// them and a possible optimal parent node using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), in a graph whose nodes
+/// are all smaller than `node_count`.
+///
+/// This function behaves like [`dijkstra_all`](super::dijkstra::dijkstra_all), but returns
+/// a vector indexed by the nodes, in which every reachable node (not including `start`) is
+/// associated with an optimal parent node and a cost from the start node. Other nodes are
+/// associated with `None`.
+///
+/// The [`build_path_dense`] function can be used to build a full path from the starting point
+/// to one of the reachable targets.
+///
+/// # Panics
+///
+/// This function panics if `start` or a successor is greater than or equal to `node_count`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{build_path_dense, dijkstra_all_dense};
+///
+/// let successors = |&n: &usize| [(n + 1, 1), (n * 2, 1)].into_iter().filter(|&(m, _)| m < 10);
+/// let reachables = dijkstra_all_dense(&1, 10, successors);
+/// assert_eq!(reachables[0], None);
+/// assert_eq!(reachables[1], None);
+/// assert_eq!(reachables[9], Some((8, 4)));
+/// assert_eq!(build_path_dense(&9, &reachables), vec![1, 2, 4, 8, 9]);
+/// ```
+pub fn dijkstra_all_dense<C, FN, IN>(
+    start: &usize,
+    node_count: usize,
+    mut successors: FN,
+) -> Vec<Option<(usize, C)>>
+where
+    C: Zero + Ord + Copy,
+    FN: FnMut(&usize) -> IN,
+    IN: IntoIterator<Item = (usize, C)>,
+{
+    let mut parents = vec![usize::MAX; node_count];
+    let mut costs = vec![None; node_count];
+    run_dense(
+        *start,
+        &mut successors,
+        &mut |_| Zero::zero(),
+        &mut |_| false,
+        &mut parents,
+        &mut costs,
+    );
+    costs[*start] = None;
+    parents
+        .into_iter()
+        .zip(costs)
+        .map(|(parent, cost)| cost.map(|cost| (parent, cost)))
+        .collect()
+}
+
+/// Compute a shortest path using the [A* search
+/// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) in a graph whose nodes
+/// are all smaller than `node_count`.
+///
+/// This function behaves like [`astar`](super::astar::astar), but records the explored
+/// nodes in vectors instead of a hash map.
+///
+/// # Panics
+///
+/// This function panics if `start` or a successor is greater than or equal to `node_count`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::astar_dense;
+///
+/// // A 10×10 grid whose cells are numbered row by row.
+/// let successors = |&n: &usize| {
+///     let (x, y) = (n % 10, n / 10);
+///     let mut next = vec![];
+///     if x > 0 { next.push((n - 1, 1)); }
+///     if x < 9 { next.push((n + 1, 1)); }
+///     if y > 0 { next.push((n - 10, 1)); }
+///     if y < 9 { next.push((n + 10, 1)); }
+///     next
+/// };
+/// let heuristic = |&n: &usize| (n % 10).abs_diff(7) + (n / 10).abs_diff(5);
+/// let result = astar_dense(&0, 100, successors, heuristic, |&n| n == 57);
+/// assert_eq!(result.map(|(_, cost)| cost), Some(12));
+/// ```
+pub fn astar_dense<C, FN, IN, FH, FS>(
+    start: &usize,
+    node_count: usize,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(Vec<usize>, C)>
+where
+    C: Zero + Ord + Copy,
+    FN: FnMut(&usize) -> IN,
+    IN: IntoIterator<Item = (usize, C)>,
+    FH: FnMut(&usize) -> C,
+    FS: FnMut(&usize) -> bool,
+{
+    let mut parents = vec![usize::MAX; node_count];
+    let mut costs = vec![None; node_count];
+    let target = run_dense(
+        *start,
+        &mut successors,
+        &mut heuristic,
+        &mut success,
+        &mut parents,
+        &mut costs,
+    )?;
+    let path = reverse_path(target, |n| Some(parents[n]).filter(|&p| p != usize::MAX));
+    costs[target].map(|cost| (path, cost))
+}
+
+/// Build a path leading from a starting node to a goal, using the parents returned by
+/// [`dijkstra_all_dense`]. The start node is the one without a parent.
+///
+/// # Panics
+///
+/// This function panics if `target` is greater than or equal to the length of `parents`.
+pub fn build_path_dense<C>(target: &usize, parents: &[Option<(usize, C)>]) -> Vec<usize> {
+    reverse_path(*target, |n| parents[n].as_ref().map(|&(p, _)| p))
+}
+
+/// Build the path leading to `target` by following the nodes returned by `parent`
+/// until one has none.
+fn reverse_path(target: usize, mut parent: impl FnMut(usize) -> Option<usize>) -> Vec<usize> {
+    let mut path = vec![target];
+    let mut node = target;
+    while let Some(p) = parent(node) {
+        node = p;
+        path.push(node);
+    }
+    path.reverse();
+    path
+}
+
+/// Run an A* search from `start`, recording in `parents` and `costs` the best known parent
+/// and cost of every reached node. The parent of `start` is `usize::MAX`. Return the first
+/// node for which `stop` returns `true`, if any.
+fn run_dense<C, FN, IN, FH, FS>(
+    start: usize,
+    successors: &mut FN,
+    heuristic: &mut FH,
+    stop: &mut FS,
+    parents: &mut [usize],
+    costs: &mut [Option<C>],
+) -> Option<usize>
+where
+    C: Zero + Ord + Copy,
+    FN: FnMut(&usize) -> IN,
+    IN: IntoIterator<Item = (usize, C)>,
+    FH: FnMut(&usize) -> C,
+    FS: FnMut(&usize) -> bool,
+{
+    costs[start] = Some(Zero::zero());
+    // Among nodes with the same estimated cost, the one with the largest cost so far is
+    // expanded first, as it is likely to be closer to the goal.
+    let mut to_see = BinaryHeap::from([Reverse((heuristic(&start), Reverse(C::zero()), start))]);
+    while let Some(Reverse((_, Reverse(cost), node))) = to_see.pop() {
+        // A node may have been pushed several times if a better way to reach it was
+        // found. Only deal with the best one.
+        if costs[node].is_some_and(|c| cost > c) {
+            continue;
+        }
+        if stop(&node) {
+            return Some(node);
+        }
+        for (next, move_cost) in successors(&node) {
+            let new_cost = cost + move_cost;
+            if costs[next].is_none_or(|c| new_cost < c) {
+                costs[next] = Some(new_cost);
+                parents[next] = node;
+                to_see.push(Reverse((
+                    new_cost + heuristic(&next),
+                    Reverse(new_cost),
+                    next,
+                )));
+            }
+        }
+    }
+    None
+}
@@ -5,8 +5,9 @@ use std::collections::HashMap;
 use std::{collections::HashSet, hash::BuildHasher};
 use std::hash::{BuildHasherDefault, Hash};
 use std::iter::FusedIterator;
+use std::ops::ControlFlow;
 
-use rustc_hash::{FxHasher};
+use rustc_hash::{FxHashMap, FxHasher};
 
 /// Compute a path using the [depth-first search
 /// algorithm](https://en.wikipedia.org/wiki/Depth-first_search).
@@ -151,27 +152,47 @@ where
 /// // (1*3)*2 == 6 which has been seen already
 /// assert_eq!(it.next(), Some(9));  // (1*3)*3
 /// ```
-pub fn dfs_reach<N, FN, IN>(start: N, successors: FN) -> DfsReachable<N, FN>
+pub fn dfs_reach<N, FN, IN>(
+    start: N,
+    successors: FN,
+) -> DfsReachable<N, FN, BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    dfs_reach_with_hasher(start, successors, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Visit all nodes that are reachable from a start node. The node will be visited
+/// in DFS order, starting from the `start` node and following the order returned
+/// by the `successors` function using a custom hasher.
+pub fn dfs_reach_with_hasher<N, FN, IN, H>(
+    start: N,
+    successors: FN,
+    hasher: H,
+) -> DfsReachable<N, FN, H>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+    H: BuildHasher,
 {
     DfsReachable {
         to_see: vec![start],
-        visited: HashSet::new(),
+        visited: HashSet::with_hasher(hasher),
         successors,
     }
 }
 
 /// Struct returned by [`dfs_reach`].
-pub struct DfsReachable<N, FN> {
+pub struct DfsReachable<N, FN, H> {
     to_see: Vec<N>,
-    visited: HashSet<N>,
+    visited: HashSet<N, H>,
     successors: FN,
 }
 
-impl<N, FN> DfsReachable<N, FN>
+impl<N, FN, H> DfsReachable<N, FN, H>
 where
     N: Eq + Hash,
 {
@@ -183,11 +204,12 @@ where
     }
 }
 
-impl<N, FN, IN> Iterator for DfsReachable<N, FN>
+impl<N, FN, IN, H> Iterator for DfsReachable<N, FN, H>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+    H: BuildHasher,
 {
     type Item = N;
 
@@ -208,7 +230,388 @@ where
     }
 }
 
-impl<N, FN, IN> FusedIterator for DfsReachable<N, FN>
+impl<N, FN, IN, H> FusedIterator for DfsReachable<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+}
+
+/// Visit all nodes that are reachable from a start node along with the node they have
+/// been discovered from and their depth in the resulting depth-first search tree. The
+/// nodes will be visited in the same order as with [`dfs_reach`].
+///
+/// As every reached node but the start one comes with its parent, the items can be
+/// used to build a spanning tree or to reconstruct the path leading to any reached node.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dfs_reach_tree, DfsReachableItem};
+///
+/// let items = dfs_reach_tree(1, |&n| vec![n * 2, n * 3].into_iter().filter(|&x| x < 10))
+///     .collect::<Vec<_>>();
+/// assert_eq!(items[0], DfsReachableItem { node: 1, parent: None, depth: 0 });
+/// assert_eq!(items[2], DfsReachableItem { node: 4, parent: Some(2), depth: 2 });
+/// assert_eq!(items.len(), 7);
+/// ```
+pub fn dfs_reach_tree<N, FN, IN>(
+    start: N,
+    successors: FN,
+) -> DfsReachableTree<N, FN, BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    dfs_reach_tree_with_hasher(start, successors, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Visit all nodes that are reachable from a start node along with their parent and
+/// depth using a custom hasher. See [`dfs_reach_tree`] for more information.
+pub fn dfs_reach_tree_with_hasher<N, FN, IN, H>(
+    start: N,
+    successors: FN,
+    hasher: H,
+) -> DfsReachableTree<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+    DfsReachableTree {
+        to_see: vec![(start, None, 0)],
+        visited: HashSet::with_hasher(hasher),
+        successors,
+    }
+}
+
+/// Struct returned by [`dfs_reach_tree`].
+pub struct DfsReachableTree<N, FN, H> {
+    to_see: Vec<(N, Option<N>, usize)>,
+    visited: HashSet<N, H>,
+    successors: FN,
+}
+
+/// Information about a node reached by [`dfs_reach_tree`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct DfsReachableItem<N> {
+    /// The node that was reached by [`dfs_reach_tree`].
+    pub node: N,
+    /// The previous node that the current node came from.
+    /// If the node is the first node, there will be no parent.
+    pub parent: Option<N>,
+    /// The number of steps from the starting node in the search tree.
+    pub depth: usize,
+}
+
+impl<N, FN, IN, H> Iterator for DfsReachableTree<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+    type Item = DfsReachableItem<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, parent, depth) = self.to_see.pop()?;
+            if self.visited.contains(&node) {
+                continue;
+            }
+            self.visited.insert(node.clone());
+            let mut to_insert = Vec::new();
+            for s in (self.successors)(&node) {
+                if !self.visited.contains(&s) {
+                    to_insert.push((s, Some(node.clone()), depth + 1));
+                }
+            }
+            self.to_see.extend(to_insert.into_iter().rev());
+            return Some(DfsReachableItem {
+                node,
+                parent,
+                depth,
+            });
+        }
+    }
+}
+
+impl<N, FN, IN, H> FusedIterator for DfsReachableTree<N, FN, H>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher,
+{
+}
+
+/// An event emitted by [`depth_first_visit`] while exploring a graph.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub enum DfsEvent<N> {
+    /// The node is reached for the first time.
+    Discover(N),
+    /// All the successors of the node have been explored.
+    Finish(N),
+    /// The edge leads from a node to a successor which is discovered through it.
+    TreeEdge(N, N),
+    /// The edge leads from a node to one of its ancestors in the search tree, or to
+    /// itself, which means that the graph contains a cycle.
+    BackEdge(N, N),
+    /// The edge leads from a node to a successor whose exploration is already finished,
+    /// either a descendant of the node (forward edge) or a node of another branch of the
+    /// search tree (cross edge).
+    CrossForwardEdge(N, N),
+}
+
+/// Explore all nodes reachable from the `starts` nodes in depth-first order, and report
+/// every step of the exploration to `visitor`.
+///
+/// - `starts` contains the nodes to start from. They are explored in order, unless
+///   they have already been discovered from a previous one.
+/// - `successors` returns a list of successors for a given node, which will be explored
+///   in order.
+/// - `visitor` is called with every [`DfsEvent`] of the exploration. A node is
+///   discovered right after the tree edge leading to it, and all the edges starting from
+///   a node are reported between the discovery and the end of the node.
+///
+/// The exploration stops as soon as `visitor` returns [`ControlFlow::Break`], whose
+/// value is returned. Otherwise, [`ControlFlow::Continue`] is returned once all the
+/// reachable nodes have been finished.
+///
+/// Topological sorts, strongly connected components or cycle detection can be built
+/// upon this function without having to write the traversal itself.
+///
+/// # Example
+///
+/// Sort nodes topologically by reversing their finishing order, and detect cycles
+/// using back edges:
+///
+/// ```
+/// use pathfinding::prelude::{DfsEvent, depth_first_visit};
+/// use std::ops::ControlFlow;
+///
+/// let successors = |&n: &u32| match n {
+///     1 => vec![2, 3],
+///     2 => vec![4],
+///     3 => vec![4],
+///     _ => vec![],
+/// };
+/// let mut order = vec![];
+/// let result = depth_first_visit(&[1], successors, |event| match event {
+///     DfsEvent::Finish(n) => {
+///         order.push(n);
+///         ControlFlow::Continue(())
+///     }
+///     DfsEvent::BackEdge(from, to) => ControlFlow::Break((from, to)),
+///     _ => ControlFlow::Continue(()),
+/// });
+/// assert_eq!(result, ControlFlow::Continue(()));
+/// order.reverse();
+/// assert_eq!(order, vec![1, 3, 2, 4]);
+///
+/// // Adding an edge from 4 to 1 creates a cycle.
+/// let successors = |&n: &u32| if n == 4 { vec![1] } else { successors(&n) };
+/// let result = depth_first_visit(&[1], successors, |event| match event {
+///     DfsEvent::BackEdge(from, to) => ControlFlow::Break((from, to)),
+///     _ => ControlFlow::Continue(()),
+/// });
+/// assert_eq!(result, ControlFlow::Break((4, 1)));
+/// ```
+pub fn depth_first_visit<N, FN, IN, FV, B>(
+    starts: &[N],
+    successors: FN,
+    mut visitor: FV,
+) -> ControlFlow<B>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FV: FnMut(DfsEvent<N>) -> ControlFlow<B>,
+{
+    let mut walk = DfsWalk::new(starts, successors);
+    while let Some(event) = walk.next_event() {
+        visitor(event)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// State of a depth-first exploration producing [`DfsEvent`] items one at a time.
+struct DfsWalk<'a, N, FN, IN>
+where
+    IN: IntoIterator<Item = N>,
+{
+    starts: std::slice::Iter<'a, N>,
+    /// The nodes being explored, along with their remaining successors.
+    stack: Vec<(N, IN::IntoIter)>,
+    /// The discovered nodes, associated with their discovery rank and with `true` once
+    /// they are finished.
+    states: FxHashMap<N, (usize, bool)>,
+    /// A node reached through a tree edge whose discovery has not been reported yet.
+    discovered: Option<N>,
+    successors: FN,
+}
+
+impl<'a, N, FN, IN> DfsWalk<'a, N, FN, IN>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    fn new(starts: &'a [N], successors: FN) -> Self {
+        DfsWalk {
+            starts: starts.iter(),
+            stack: Vec::new(),
+            states: FxHashMap::default(),
+            discovered: None,
+            successors,
+        }
+    }
+
+    fn discover(&mut self, node: N) {
+        let rank = self.states.len();
+        self.states.insert(node.clone(), (rank, false));
+        let successors = (self.successors)(&node).into_iter();
+        self.stack.push((node, successors));
+    }
+
+    fn next_event(&mut self) -> Option<DfsEvent<N>> {
+        if let Some(node) = self.discovered.take() {
+            return Some(DfsEvent::Discover(node));
+        }
+        let Some((node, successors)) = self.stack.last_mut() else {
+            let start = self.starts.find(|n| !self.states.contains_key(n))?.clone();
+            self.discover(start.clone());
+            return Some(DfsEvent::Discover(start));
+        };
+        let Some(next) = successors.next() else {
+            let (node, _) = self.stack.pop()?;
+            if let Some((_, finished)) = self.states.get_mut(&node) {
+                *finished = true;
+            }
+            return Some(DfsEvent::Finish(node));
+        };
+        let node = node.clone();
+        Some(match self.states.get(&next) {
+            None => {
+                self.discover(next.clone());
+                self.discovered = Some(next.clone());
+                DfsEvent::TreeEdge(node, next)
+            }
+            Some((_, false)) => DfsEvent::BackEdge(node, next),
+            Some((_, true)) => DfsEvent::CrossForwardEdge(node, next),
+        })
+    }
+
+    /// Tell apart forward and cross edges leading to an already finished node: the
+    /// target of a forward edge has been discovered after its source.
+    fn forward_or_cross(&self, from: &N, to: &N) -> DfsEdgeKind {
+        if self.states[from].0 < self.states[to].0 {
+            DfsEdgeKind::Forward
+        } else {
+            DfsEdgeKind::Cross
+        }
+    }
+}
+
+/// The kind of an edge reported by [`dfs_edges`], according to its role in the
+/// depth-first search forest.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum DfsEdgeKind {
+    /// The edge leads to a node discovered through it.
+    Tree,
+    /// The edge leads to an ancestor of its source in the search tree, or to the source
+    /// itself. Such an edge closes a cycle.
+    Back,
+    /// The edge leads to an already explored descendant of its source.
+    Forward,
+    /// The edge leads to a node of another branch of the search tree, or of another
+    /// search tree.
+    Cross,
+}
+
+/// Iterate over the edges reachable from the `starts` nodes, classified according to
+/// a depth-first exploration of the graph.
+///
+/// - `starts` contains the nodes to start from. They are explored in order, unless
+///   they have already been discovered from a previous one.
+/// - `successors` returns a list of successors for a given node, which will be explored
+///   in order.
+///
+/// Every edge is returned exactly once as a `(from, to, kind)` tuple, in the order in
+/// which it is examined by [`depth_first_visit`]. The graph contains a cycle if and only
+/// if one of the edges is a [`DfsEdgeKind::Back`] edge.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{DfsEdgeKind, dfs_edges};
+///
+/// let successors = |&n: &u8| match n {
+///     1 => vec![2, 3],
+///     2 => vec![3],
+///     3 => vec![1],
+///     4 => vec![3],
+///     _ => vec![],
+/// };
+/// let edges = dfs_edges(&[1, 4], successors).collect::<Vec<_>>();
+/// assert_eq!(
+///     edges,
+///     vec![
+///         (1, 2, DfsEdgeKind::Tree),
+///         (2, 3, DfsEdgeKind::Tree),
+///         (3, 1, DfsEdgeKind::Back),
+///         (1, 3, DfsEdgeKind::Forward),
+///         (4, 3, DfsEdgeKind::Cross),
+///     ]
+/// );
+/// ```
+pub fn dfs_edges<N, FN, IN>(starts: &[N], successors: FN) -> DfsEdges<'_, N, FN, IN>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    DfsEdges {
+        walk: DfsWalk::new(starts, successors),
+    }
+}
+
+/// Iterator returned by [`dfs_edges`].
+pub struct DfsEdges<'a, N, FN, IN>
+where
+    IN: IntoIterator<Item = N>,
+{
+    walk: DfsWalk<'a, N, FN, IN>,
+}
+
+impl<N, FN, IN> Iterator for DfsEdges<'_, N, FN, IN>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    type Item = (N, N, DfsEdgeKind);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.walk.next_event()? {
+                DfsEvent::TreeEdge(from, to) => return Some((from, to, DfsEdgeKind::Tree)),
+                DfsEvent::BackEdge(from, to) => return Some((from, to, DfsEdgeKind::Back)),
+                DfsEvent::CrossForwardEdge(from, to) => {
+                    let kind = self.walk.forward_or_cross(&from, &to);
+                    return Some((from, to, kind));
+                }
+                DfsEvent::Discover(_) | DfsEvent::Finish(_) => (),
+            }
+        }
+    }
+}
+
+impl<N, FN, IN> FusedIterator for DfsEdges<'_, N, FN, IN>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
@@ -0,0 +1,144 @@
+//! Precompute distances from a few pivot nodes in order to build a
+//! differential heuristic for [`astar`](super::astar::astar) on undirected graphs.
+
+use super::landmarks::{distances_from, select_farthest};
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+/// Distances from a set of pivot nodes, usable to estimate the distance between
+/// any two nodes of an undirected graph.
+///
+/// For every pivot `p`, the triangle inequality gives `|d(p, n) - d(p, goal)|` as a
+/// lower bound of the distance between `n` and `goal`. The estimate is the largest of
+/// those bounds over all pivots, which is both admissible and consistent as long as
+/// every edge can be followed in both directions for the same cost. For directed
+/// graphs, [`Landmarks`](super::landmarks::Landmarks) must be used instead.
+///
+/// When the `serde` feature is enabled, the precomputed tables can be serialized
+/// and deserialized, so that they can be computed once and shipped along with the
+/// graph.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, dijkstra, DifferentialHeuristic};
+///
+/// // A ring of 100 nodes with a shortcut between 0 and 50.
+/// let successors = |&n: &u32| {
+///     let mut succ = vec![((n + 1) % 100, 1), ((n + 99) % 100, 1)];
+///     match n {
+///         0 => succ.push((50, 10)),
+///         50 => succ.push((0, 10)),
+///         _ => (),
+///     }
+///     succ
+/// };
+/// let nodes = (0..100).collect::<Vec<_>>();
+/// let dh = DifferentialHeuristic::new(&nodes, 3, successors);
+///
+/// let goal = 55;
+/// let result = astar(&3, successors, dh.heuristic(&goal), |&n| n == goal);
+/// assert_eq!(result, dijkstra(&3, successors, |&n| n == goal));
+/// assert!(dh.estimate(&3, &goal) <= 18);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize, C: serde::Serialize",
+        deserialize = "N: serde::Deserialize<'de> + Eq + Hash, C: serde::Deserialize<'de>"
+    ))
+)]
+pub struct DifferentialHeuristic<N, C> {
+    pivots: Vec<N>,
+    distances: Vec<FxHashMap<N, C>>,
+}
+
+impl<N, C> DifferentialHeuristic<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+{
+    /// Select up to `count` pivots among `nodes` and precompute their distances.
+    ///
+    /// - `nodes` contains the nodes of the graph.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor.
+    ///
+    /// The first pivot is the node farthest from the first node of `nodes`, and every
+    /// following pivot is the node farthest from all the pivots selected so far. Fewer
+    /// than `count` pivots are selected if `nodes` is too small.
+    #[must_use]
+    pub fn new<FN, IN>(nodes: &[N], count: usize, mut successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let (pivots, distances) = select_farthest(nodes, count, &mut successors)
+            .into_iter()
+            .map(|(index, distances)| (nodes[index].clone(), distances))
+            .unzip();
+        Self { pivots, distances }
+    }
+
+    /// Precompute the distances for the given pivots.
+    ///
+    /// - `pivots` contains the nodes to use as pivots.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor.
+    #[must_use]
+    pub fn with_pivots<FN, IN>(pivots: &[N], mut successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        Self {
+            pivots: pivots.to_vec(),
+            distances: pivots
+                .iter()
+                .map(|p| distances_from(p, &mut successors))
+                .collect(),
+        }
+    }
+
+    /// The selected pivots.
+    #[must_use]
+    pub fn pivots(&self) -> &[N] {
+        &self.pivots
+    }
+
+    /// Lower bound of the distance between `from` and `to`.
+    #[must_use]
+    pub fn estimate(&self, from: &N, to: &N) -> C {
+        let goal = self.goal_distances(to);
+        self.estimate_with(from, &goal)
+    }
+
+    /// Build a heuristic estimating the distance from any node to `goal`, suitable
+    /// for use with [`astar`](super::astar::astar) and its variants.
+    pub fn heuristic(&self, goal: &N) -> impl Fn(&N) -> C + '_ {
+        let goal = self.goal_distances(goal);
+        move |node| self.estimate_with(node, &goal)
+    }
+
+    fn goal_distances(&self, goal: &N) -> Vec<Option<C>> {
+        self.distances
+            .iter()
+            .map(|d| d.get(goal).copied())
+            .collect()
+    }
+
+    fn estimate_with(&self, node: &N, goal: &[Option<C>]) -> C {
+        let mut best: C = Zero::zero();
+        for (distances, &to_goal) in self.distances.iter().zip(goal) {
+            // Nodes in different components than the pivot give no information.
+            if let (Some(g), Some(&n)) = (to_goal, distances.get(node)) {
+                best = best.max(if g > n { g - n } else { n - g });
+            }
+        }
+        best
+    }
+}
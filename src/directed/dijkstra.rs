@@ -1,15 +1,27 @@
 //! Compute a shortest path using the [Dijkstra search
 //! algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
 
-use super::reverse_path;
-use crate::FxIndexMap;
+use super::astar::{AstarSolution, try_astar_in};
+use super::search_context::SearchContext;
+use super::search_error::{SearchError, SearchLimits};
+use super::shortest_path_tree::ShortestPathTree;
+use super::{
+    ChosenEdges, Frontier, NO_PARENT, Outcome, ParentIndex, from_parent, recycled_successors,
+    reverse_path, reverse_path_with_costs, to_parent,
+};
+use crate::indexed_heap::IndexedHeap;
+use crate::path::Path;
+use crate::radix_heap::{RadixHeap, RadixKey};
+use crate::{FxIndexMap, FxIndexSet, NodeRefs};
 use indexmap::map::Entry::{Occupied, Vacant};
 use indexmap::IndexMap;
-use num_traits::Zero;
+use num_traits::{CheckedAdd, Zero};
 use rustc_hash::FxHasher;
+use std::cell::Cell;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+use std::ops::{ControlFlow, Sub};
 
 /// Compute a shortest path using the [Dijkstra search
 /// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
@@ -18,7 +30,9 @@ use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `success` checks whether the goal has been reached. It is not a node as some problems require
@@ -69,13 +83,28 @@ use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 ///                       |&p| p == GOAL);
 /// assert_eq!(result.expect("no path found").1, 4);
 /// ```
-pub fn dijkstra<N, C, FN, IN, FS>(
-    start: &N,
+///
+/// Several starting nodes can be given at once, for example to reach a goal from the
+/// nearest of several entry points:
+///
+/// ```
+/// use pathfinding::NodeRefs;
+/// use pathfinding::prelude::dijkstra;
+///
+/// let entries = [0, 20, 40];
+/// let result = dijkstra(NodeRefs::from_iter(&entries),
+///                       |&n: &i32| [(n - 1, 1), (n + 1, 1)],
+///                       |&n| n == 27);
+/// assert_eq!(result, Some(((20..=27).collect(), 7)));
+/// ```
+pub fn dijkstra<'a, N, C, FN, IN, S, FS>(
+    start: S,
     successors: FN,
     success: FS,
 ) -> Option<(Vec<N>, C)>
 where
-    N: Eq + Hash + Clone,
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
     C: Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
@@ -91,7 +120,9 @@ where
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `success` checks whether the goal has been reached. It is not a node as some problems require
@@ -100,21 +131,22 @@ where
 /// A node will never be included twice in the path as determined by the `Eq` relationship.
 ///
 /// The returned path comprises both the start and end node.
-pub fn dijkstra_with_hasher<N, C, FN, IN, FS, H>(
-    start: &N,
+pub fn dijkstra_with_hasher<'a, N, C, FN, IN, S, FS, H>(
+    start: S,
     mut successors: FN,
     mut success: FS,
     hasher: H,
 ) -> Option<(Vec<N>, C)>
 where
-    N: Eq + Hash + Clone,
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
     C: Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
     FS: FnMut(&N) -> bool,
     H: BuildHasher,
 {
-    dijkstra_internal(start, &mut successors, &mut success, hasher)
+    dijkstra_multi(start.into(), &mut successors, &mut success, hasher)
 }
 
 pub(crate) fn dijkstra_internal<N, C, FN, IN, FS, H>(
@@ -131,15 +163,585 @@ where
     FS: FnMut(&N) -> bool,
     H: BuildHasher,
 {
-    let (parents, reached) = run_dijkstra(start, successors, success, hasher);
+    dijkstra_multi(std::iter::once(start), successors, success, hasher)
+}
+
+fn dijkstra_multi<'a, N, C, FN, IN, FS, H>(
+    starts: impl IntoIterator<Item = &'a N>,
+    successors: &mut FN,
+    success: &mut FS,
+    hasher: H,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone + 'a,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
+{
+    let mut parents = IndexMap::with_hasher(hasher);
+    let reached = run_dijkstra_multi_in(
+        starts,
+        &mut |node: &N, _| successors(node),
+        success,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    );
     reached.map(|target| {
         (
-            reverse_path(&parents, |&(p, _)| p, target),
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
             parents.get_index(target).unwrap().1.1,
         )
     })
 }
 
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), reusing the
+/// buffers of a [`SearchContext`] instead of allocating new ones.
+///
+/// This function behaves like [`dijkstra`]. The context is cleared before the
+/// search starts.
+pub fn dijkstra_with_context<N, C, FN, IN, FS, H>(
+    context: &mut SearchContext<N, C, H>,
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
+{
+    let parents = &mut context.parents;
+    run_dijkstra_in(
+        start,
+        &mut |node: &N, _| successors(node),
+        &mut success,
+        parents,
+        &mut context.dijkstra_heap,
+    )
+    .map(|target| {
+        (
+            reverse_path(parents, |&(p, _)| from_parent(p), target),
+            parents[target].1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), keeping the nodes
+/// to expand in an [`IndexedHeap`] instead of a binary heap.
+///
+/// This function behaves like [`dijkstra`]. When a cheaper way to reach a node is found,
+/// its cost is decreased in the heap instead of adding a new entry for the node, which
+/// keeps the heap small in graphs where costs are often improved.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_indexed;
+///
+/// let result = dijkstra_indexed(&1, |&n: &u32| vec![(n + 1, 1), (n * 2, 3)], |&n| n == 20);
+/// assert_eq!(result.map(|(_, cost)| cost), Some(10));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_indexed<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    run_dijkstra_in(
+        start,
+        &mut |node: &N, _| successors(node),
+        &mut success,
+        &mut parents,
+        &mut IndexedHeap::new(),
+    )
+    .map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents.get_index(target).unwrap().1.1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), keeping the nodes
+/// to expand in a [`RadixHeap`] instead of a binary heap.
+///
+/// This function behaves like [`dijkstra`], but requires costs of an unsigned integer
+/// type. As the costs of the expanded nodes never decrease, the radix heap is able to
+/// order them with fewer comparisons, which speeds up large searches.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_radix;
+///
+/// let result = dijkstra_radix(&1, |&n: &u32| vec![(n + 1, 1u32), (n * 2, 3)], |&n| n == 20);
+/// assert_eq!(result.map(|(_, cost)| cost), Some(10));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_radix<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: RadixKey,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    run_dijkstra_in(
+        start,
+        &mut |node: &N, _| successors(node),
+        &mut success,
+        &mut parents,
+        &mut RadixHeap::new(),
+    )
+    .map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents.get_index(target).unwrap().1.1,
+        )
+    })
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) with successors
+/// depending on the cost already spent to reach the node being expanded.
+///
+/// This function behaves like [`dijkstra`], except that `successors` receives the
+/// total cost of the best path to the node along with the node itself. This allows
+/// the successors and their costs to depend on this cost, for example when it
+/// represents an arrival time. The costs must still be non-negative, and leaving a
+/// node later must never allow reaching a successor earlier.
+///
+/// # Example
+///
+/// A gate located at node 3 only opens at time 10. Waiting before crossing is part
+/// of the crossing cost.
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_cost_aware;
+///
+/// let result = dijkstra_cost_aware(&0, |&n, time| {
+///     let wait = if n == 3 { 10u32.saturating_sub(time) } else { 0 };
+///     vec![(n + 1, wait + 1)]
+/// }, |&n| n == 5);
+/// assert_eq!(result, Some((vec![0, 1, 2, 3, 4, 5], 12)));
+/// ```
+pub fn dijkstra_cost_aware<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, C) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut parents = FxIndexMap::default();
+    run_dijkstra_in(
+        start,
+        &mut successors,
+        &mut success,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    )
+    .map(|target| {
+        (
+            reverse_path(&parents, |&(p, _)| from_parent(p), target),
+            parents[target].1,
+        )
+    })
+}
+
+/// Compute an earliest-arrival path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) on a graph whose
+/// travel times depend on the time at which edges are taken.
+///
+/// - `start` is the starting node.
+/// - `departure` is the time at which the search leaves `start`.
+/// - `successors` returns a list of successors for a given node, given the time at which
+///   this node is reached, along with the time needed to reach each successor from the
+///   node when leaving it at this time. This travel time includes any waiting needed
+///   before leaving the node, for example until the next departure of a scheduled service.
+/// - `success` checks whether the goal has been reached.
+///
+/// The returned value is the path along with the arrival time at the goal.
+///
+/// The graph must satisfy the FIFO property: leaving a node later must never allow
+/// reaching a successor earlier, that is `t + travel(t)` must never decrease as `t`
+/// grows. This is the case for most transit and traffic models, and guarantees that
+/// the earliest arrival at every node is the best time to leave it. When this property
+/// does not hold, the returned path may arrive later than the best one.
+///
+/// # Example
+///
+/// A train leaves station 1 every 20 minutes, starting at minute 0, and takes 10 minutes
+/// to reach station 2. Walking from 0 to 1 takes 5 minutes, and directly from 0 to 2
+/// takes 32 minutes.
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_time_dependent;
+///
+/// let successors = |&station: &u32, time: u32| match station {
+///     0 => vec![(1, 5), (2, 32)],
+///     1 => vec![(2, (20 - time % 20) % 20 + 10)],
+///     _ => vec![],
+/// };
+/// // Leaving at 8:00, the train leaving at 8:20 reaches station 2 at 8:30.
+/// assert_eq!(
+///     dijkstra_time_dependent(&0, 8 * 60, successors, |&n| n == 2),
+///     Some((vec![0, 1, 2], 8 * 60 + 30))
+/// );
+/// // Leaving at 8:16, the next train leaves at 8:40 and walking is faster.
+/// assert_eq!(
+///     dijkstra_time_dependent(&0, 8 * 60 + 16, successors, |&n| n == 2),
+///     Some((vec![0, 2], 8 * 60 + 48))
+/// );
+/// ```
+pub fn dijkstra_time_dependent<N, C, FN, IN, FS>(
+    start: &N,
+    departure: C,
+    mut successors: FN,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, C) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    dijkstra_cost_aware(
+        start,
+        |node, elapsed| successors(node, departure + elapsed),
+        success,
+    )
+    .map(|(path, elapsed)| (path, departure + elapsed))
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), only considering
+/// paths made of at most `max_hops` edges.
+///
+/// This function behaves like [`dijkstra`], except that the returned path is the
+/// cheapest one among paths with at most `max_hops` edges. `None` is returned if
+/// no such path exists, even if a longer one does.
+///
+/// Since a node may be reached through paths with different numbers of edges,
+/// nodes may be expanded up to `max_hops` times each.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_bounded;
+///
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n * 2, 5)];
+/// assert_eq!(dijkstra_bounded(&1, successors, |&n| n == 8, 10), Some((vec![1, 2, 3, 4, 5, 6, 7, 8], 7)));
+/// assert_eq!(dijkstra_bounded(&1, successors, |&n| n == 8, 4), Some((vec![1, 2, 3, 4, 8], 8)));
+/// assert_eq!(dijkstra_bounded(&1, successors, |&n| n == 8, 2), None);
+/// ```
+pub fn dijkstra_bounded<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+    max_hops: usize,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    dijkstra(
+        &(start.clone(), 0),
+        |(node, hops)| {
+            let hops = *hops + 1;
+            (hops <= max_hops)
+                .then(|| successors(node))
+                .into_iter()
+                .flatten()
+                .map(move |(successor, cost)| ((successor, hops), cost))
+        },
+        |(node, _)| success(node),
+    )
+    .map(|(path, cost)| (path.into_iter().map(|(node, _)| node).collect(), cost))
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), reporting why no
+/// path has been found in a [`SearchError`].
+///
+/// This function behaves like [`dijkstra`] with a single start node, except that the
+/// search is stopped when one of the `limits` is reached, and that an error is returned
+/// instead of overflowing when adding costs. See [`try_astar`](super::astar::try_astar)
+/// for details.
+///
+/// # Errors
+///
+/// - [`SearchError::NoPath`] is returned when no path exists.
+/// - [`SearchError::BudgetExceeded`] or [`SearchError::Cancelled`] is returned when the
+///   search is stopped by the `limits`. No closest path is given, as nothing tells which
+///   of the expanded nodes is the nearest to the goal.
+/// - [`SearchError::CostOverflow`] is returned when the cost of a path overflows.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{try_dijkstra, SearchError, SearchLimits};
+///
+/// // Costs double at every step, which quickly overflows an `u8`.
+/// let successors = |&n: &u32| vec![(n + 1, 1u8 << n.min(7))];
+/// let result = try_dijkstra(&0, successors, |&n| n == 5, SearchLimits::new());
+/// assert_eq!(result, Ok((vec![0, 1, 2, 3, 4, 5], 31)));
+/// let result = try_dijkstra(&0, successors, |&n| n == 10, SearchLimits::new());
+/// assert_eq!(result, Err(SearchError::CostOverflow { path: (0..=8).collect() }));
+/// ```
+pub fn try_dijkstra<N, C, FN, IN, FS, FC>(
+    start: &N,
+    successors: FN,
+    success: FS,
+    limits: SearchLimits<FC>,
+) -> Result<(Vec<N>, C), SearchError<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + CheckedAdd,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+    FC: FnMut() -> bool,
+{
+    try_astar_in(
+        &mut FxIndexMap::default(),
+        start,
+        Zero::zero(),
+        successors,
+        |_| Zero::zero(),
+        success,
+        limits,
+        |_| (),
+    )
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), and return it
+/// as a [`Path`] carrying the cost of every step.
+///
+/// This function behaves like [`dijkstra`], with the additional requirement that
+/// costs can be subtracted from each other.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_path;
+///
+/// let path = dijkstra_path(&1, |&n| vec![(n + 1, 1), (n * 2, 3)], |&n| n == 10)
+///     .expect("no path found");
+/// assert_eq!(path.nodes(), &[1, 2, 3, 4, 5, 10]);
+/// assert_eq!(path.step_costs(), &[1, 1, 1, 1, 3]);
+/// assert_eq!(path.total_cost(), 7);
+/// ```
+pub fn dijkstra_path<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let (parents, reached) = run_dijkstra(
+        start,
+        &mut successors,
+        &mut success,
+        BuildHasherDefault::<FxHasher>::default(),
+    );
+    reached.map(|target| reverse_path_with_costs(&parents, target))
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) in a graph where
+/// several edges may link the same nodes, and return the edges it goes through.
+///
+/// This function behaves like [`dijkstra`], except that `successors` returns, for
+/// every edge leaving a node, the successor, a label identifying the edge, and the cost
+/// of the edge. When several edges lead to the same successor, the cheapest one is used,
+/// the first one returned winning ties.
+///
+/// The path is returned as the list of steps taken from `start`, every step being made
+/// of the node reached, the label of the edge followed and its cost, along with the total
+/// cost. The list is empty if `start` is a goal.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_edges;
+///
+/// // Two roads link a and b, and the toll road is faster.
+/// let successors = |&n: &char| match n {
+///     'a' => vec![('b', "toll road", 2), ('b', "free road", 5), ('c', "path", 9)],
+///     'b' => vec![('c', "bridge", 3)],
+///     _ => vec![],
+/// };
+/// let (steps, cost) = dijkstra_edges(&'a', successors, |&n| n == 'c').unwrap();
+/// assert_eq!(steps, vec![('b', "toll road", 2), ('c', "bridge", 3)]);
+/// assert_eq!(cost, 5);
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn dijkstra_edges<N, E, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    success: FS,
+) -> Option<(Vec<(N, E, C)>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, E, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut edges = ChosenEdges::new();
+    let (path, cost) = dijkstra(start, |node| edges.record(node, successors(node)), success)?;
+    Some((edges.into_steps(path), cost))
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) with successors
+/// written into a reusable buffer.
+///
+/// This function behaves like [`dijkstra`], except that `successors` receives a
+/// vector into which it must push (or extend with) the successors of the node
+/// along with the cost for moving to them. The same vector is reused for every
+/// expansion, so that no allocation is needed once it has grown large enough.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_extend;
+///
+/// let result = dijkstra_extend(&1, |&n, successors| {
+///     successors.push((n + 1, 1));
+///     successors.push((n * 2, 3));
+/// }, |&n| n == 10);
+/// assert_eq!(result, Some((vec![1, 2, 3, 4, 5, 10], 7)));
+/// ```
+pub fn dijkstra_extend<N, C, FN, FS>(start: &N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, &mut Vec<(N, C)>),
+    FS: FnMut(&N) -> bool,
+{
+    let pool = Cell::new(Vec::new());
+    dijkstra(start, recycled_successors(&pool, successors), success)
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) towards several
+/// goals, and report which goal has been reached.
+///
+/// This function behaves like [`dijkstra`], except that `success` returns `Some(goal)`
+/// when a goal is reached, and `None` otherwise. The goal label is returned along
+/// with the path and its total cost.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_goal;
+///
+/// let exits = [(17, "north"), (12, "south")];
+/// let result = dijkstra_goal(&1, |&n| vec![(n + 1, 1), (n * 2, 3)],
+///                            |n| exits.iter().find(|(e, _)| e == n).map(|&(_, label)| label));
+/// assert_eq!(result, Some((vec![1, 2, 3, 6, 12], 8, "south")));
+/// ```
+pub fn dijkstra_goal<N, C, G, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C, G)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> Option<G>,
+{
+    let mut goal = None;
+    let (path, cost) = dijkstra(start, successors, |node| {
+        goal = success(node);
+        goal.is_some()
+    })?;
+    goal.map(|goal| (path, cost, goal))
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), searching
+/// backwards from the goal.
+///
+/// The search starts from `goal` and follows the edges in reverse until a node for
+/// which `is_start` returns `true` is found. This is the most efficient way of finding
+/// the closest of many possible starting points leading to a single goal.
+///
+/// - `goal` is the node the path must lead to.
+/// - `predecessors` returns a list of nodes having an edge leading to a given node, along
+///   with the cost for moving from the predecessor to the node.
+/// - `is_start` checks whether a node is an acceptable starting point.
+///
+/// The returned path is in the forward direction, going from the starting point to
+/// the goal, and comprises both of them.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_backward;
+///
+/// // Depots are located at 0 and 100; moving right costs 1, and moving left costs 3.
+/// let result = dijkstra_backward(&40, |&n: &i32| vec![(n - 1, 1), (n + 1, 3)],
+///                                |&n| n == 0 || n == 100);
+/// let (path, cost) = result.expect("no path found");
+/// assert_eq!((path[0], path.len(), cost), (0, 41, 40));
+/// ```
+pub fn dijkstra_backward<N, C, FN, IN, FS>(
+    goal: &N,
+    predecessors: FN,
+    is_start: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let (mut path, cost) = dijkstra(goal, predecessors, is_start)?;
+    path.reverse();
+    Some((path, cost))
+}
+
 /// Determine all reachable nodes from a starting point as well as the
 /// minimum cost to reach them and a possible optimal parent node
 /// using the [Dijkstra search
@@ -208,114 +810,466 @@ where
     C: Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
-    H: BuildHasher + Default,
+    H: BuildHasher + Default,
+{
+    dijkstra_partial_with_hasher(start, successors, |_| false, hasher).0
+}
+
+/// Determine all reachable nodes from a starting point as well as the minimum cost to
+/// reach them using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), and return them as a
+/// [`ShortestPathTree`].
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+///
+/// Unlike [`dijkstra_all`], the result includes `start` and can directly be queried for the
+/// cost of or a path to any reachable node.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_tree;
+///
+/// fn successors(&n: &u32) -> Vec<(u32, usize)> {
+///   if n <= 4 { vec![(n*2, 10), (n*2+1, 10)] } else { vec![] }
+/// }
+///
+/// let tree = dijkstra_tree(&1, successors);
+/// assert_eq!(tree.len(), 9);
+/// assert_eq!(tree.cost_to(&9), Some(30));
+/// assert_eq!(tree.path_to(&9), Some(vec![1, 2, 4, 9]));
+/// assert_eq!(tree.path_to(&10), None);
+/// ```
+pub fn dijkstra_tree<N, C, FN, IN>(start: &N, mut successors: FN) -> ShortestPathTree<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (parents, _) = run_dijkstra(
+        start,
+        &mut successors,
+        &mut |_| false,
+        BuildHasherDefault::<FxHasher>::default(),
+    );
+    ShortestPathTree::from_parents(parents)
+}
+
+/// Determine all nodes reachable from a starting point for at most a given cost,
+/// as well as the minimum cost to reach them and a possible optimal parent node
+/// using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+/// - `max_cost` is the maximum cost allowed to reach a node.
+///
+/// The result is a map where every node (not including `start`) which can be reached
+/// for a cost lower than or equal to `max_cost` is associated with an optimal parent node
+/// and a cost from the start node. Nodes farther away are never expanded, which makes
+/// this function suitable for computing isochrones in large or infinite graphs.
+///
+/// The [`build_path`] function can be used to build a full path from the starting point to one
+/// of the reachable targets.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_all_within;
+///
+/// // An infinite line where every step costs 3.
+/// let reachables = dijkstra_all_within(&0i32, |&n| vec![(n - 1, 3), (n + 1, 3)], 7);
+/// assert_eq!(reachables.len(), 4);
+/// assert_eq!(reachables[&2], (1, 6));
+/// assert_eq!(reachables[&-2], (-1, 6));
+/// ```
+pub fn dijkstra_all_within<N, C, FN, IN>(
+    start: &N,
+    successors: FN,
+    max_cost: C,
+) -> HashMap<N, (N, C), BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    dijkstra_all_within_with_hasher(
+        start,
+        successors,
+        max_cost,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Determine all nodes reachable from a starting point for at most a given cost,
+/// as well as the minimum cost to reach them and a possible optimal parent node
+/// using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) with a custom hasher.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+/// - `max_cost` is the maximum cost allowed to reach a node.
+///
+/// The result is a map where every node (not including `start`) which can be reached
+/// for a cost lower than or equal to `max_cost` is associated with an optimal parent node
+/// and a cost from the start node.
+///
+/// The [`build_path`] function can be used to build a full path from the starting point to one
+/// of the reachable targets.
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_all_within_with_hasher<N, C, FN, IN, H>(
+    start: &N,
+    mut successors: FN,
+    max_cost: C,
+    hasher: H,
+) -> HashMap<N, (N, C), H>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    H: BuildHasher + Default,
+{
+    let mut parents = IndexMap::with_hasher(hasher);
+    run_dijkstra_in(
+        start,
+        &mut |node: &N, cost: C| {
+            successors(node)
+                .into_iter()
+                .filter(move |&(_, move_cost)| cost + move_cost <= max_cost)
+        },
+        &mut |_: &N| false,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    );
+    parents
+        .iter()
+        .skip(1)
+        .map(|(n, (p, c))| {
+            (
+                n.clone(),
+                (parents.get_index(from_parent(*p)).unwrap().0.clone(), *c),
+            )
+        }) // unwrap() cannot fail
+        .collect()
+}
+
+/// Determine some reachable nodes from a starting point as well as the minimum cost to
+/// reach them and a possible optimal parent node
+/// using the [Dijkstra search algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+/// - `stop` is a function which is called every time a node is examined (including `start`).
+///   A `true` return value will stop the algorithm.
+///
+/// The result is a map where every node examined before the algorithm stopped (not including
+/// `start`) is associated with an optimal parent node and a cost from the start node, as well
+/// as the node which caused the algorithm to stop if any.
+///
+/// The [`build_path`] function can be used to build a full path from the starting point to one
+/// of the reachable targets.
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_partial<N, C, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    stop: FS
+) -> (HashMap<N, (N, C), BuildHasherDefault<FxHasher>>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    dijkstra_partial_with_hasher(start, successors, stop, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Determine some reachable nodes from a starting point as well as the minimum cost to
+/// reach them and a possible optimal parent node
+/// using the [Dijkstra search algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm)
+/// with a custom hasher.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+/// - `stop` is a function which is called every time a node is examined (including `start`).
+///   A `true` return value will stop the algorithm.
+///
+/// The result is a map where every node examined before the algorithm stopped (not including
+/// `start`) is associated with an optimal parent node and a cost from the start node, as well
+/// as the node which caused the algorithm to stop if any.
+///
+/// The [`build_path`] function can be used to build a full path from the starting point to one
+/// of the reachable targets.
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_partial_with_hasher<N, C, FN, IN, FS, H>(
+    start: &N,
+    mut successors: FN,
+    mut stop: FS,
+    hasher: H
+) -> (HashMap<N, (N, C), H>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher + Default,
+{
+    let (parents, reached) = run_dijkstra(start, &mut successors, &mut stop, hasher);
+    (
+        parents
+            .iter()
+            .skip(1)
+            .map(|(n, (p, c))| {
+                (
+                    n.clone(),
+                    (parents.get_index(from_parent(*p)).unwrap().0.clone(), *c),
+                )
+            }) // unwrap() cannot fail
+            .collect(),
+        reached.map(|i| parents.get_index(i).unwrap().0.clone()),
+    )
+}
+
+/// Compute all shortest paths using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
+///
+/// Whereas `dijkstra` returns a single shortest path, chosen arbitrarily
+/// among the shortest ones, `dijkstra_bag` returns all shortest paths (in
+/// an unspecified order, which only depends on the graph). It behaves like
+/// [`astar_bag`](super::astar::astar_bag) without a heuristic.
+///
+/// The shortest paths starting from `start` up to a node for which `success` returns `true` are
+/// computed and returned in an iterator along with the cost (which, by definition, is the same for
+/// each shortest path), wrapped in a `Some`. If no paths are found, `None` is returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. This cost must be non-negative.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// Each path comprises both the start and an end node. Note that while every path shares the same
+/// start node, different paths may have different end nodes.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_bag;
+///
+/// // 1 --(1)--> 2 --(2)--> 4
+/// // 1 --(2)--> 3 --(1)--> 4
+/// // 1 --(4)--> 4
+/// let successors = |&n: &u32| match n {
+///     1 => vec![(2, 1), (3, 2), (4, 4)],
+///     2 => vec![(4, 2)],
+///     3 => vec![(4, 1)],
+///     _ => vec![],
+/// };
+/// let (solutions, cost) = dijkstra_bag(&1, successors, |&n| n == 4).expect("no path found");
+/// assert_eq!(cost, 3);
+/// let mut solutions = solutions.collect::<Vec<_>>();
+/// solutions.sort();
+/// assert_eq!(solutions, vec![vec![1, 2, 4], vec![1, 3, 4]]);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn dijkstra_bag<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(AstarSolution<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut to_see = BinaryHeap::new();
+    let mut min_cost = None;
+    let mut sinks = FxIndexSet::default();
+    to_see.push(SmallestHolder {
+        cost: Zero::zero(),
+        index: to_parent(0),
+    });
+    // As in `astar_bag`, the parents of every node are kept in a linked list whose cells
+    // are stored in `links`, every node only recording the last cell of its list, or
+    // `usize::MAX`. Every cell holds a parent index and the index of the previous cell.
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (usize::MAX, Zero::zero()));
+    while let Some(SmallestHolder { cost, index }) = to_see.pop() {
+        if min_cost.is_some_and(|min_cost| cost > min_cost) {
+            break;
+        }
+        let index = from_parent(index);
+        let successors = {
+            let (node, &(_, c)) = parents.get_index(index).unwrap(); // Cannot fail
+            // Discard the entries superseded by a cheaper path, as in `astar_bag`.
+            if cost > c {
+                continue;
+            }
+            if success(node) {
+                min_cost = Some(cost);
+                sinks.insert(index);
+            }
+            successors(node)
+        };
+        for (successor, move_cost) in successors {
+            let new_cost = cost + move_cost;
+            let n; // index for successor
+            match parents.entry(successor) {
+                Vacant(e) => {
+                    n = e.index();
+                    links.push((index, usize::MAX));
+                    e.insert((links.len() - 1, new_cost));
+                }
+                Occupied(mut e) => {
+                    if e.get().1 > new_cost {
+                        n = e.index();
+                        // The cell of the former last parent is reused, the other
+                        // ones are abandoned.
+                        let s = e.get_mut();
+                        links[s.0] = (index, usize::MAX);
+                        s.1 = new_cost;
+                    } else {
+                        if e.get().1 == new_cost {
+                            // New parent with an identical cost, this is not
+                            // considered as an insertion.
+                            let s = e.get_mut();
+                            if links.get(s.0).is_none_or(|&(p, _)| p != index) {
+                                links.push((index, s.0));
+                                s.0 = links.len() - 1;
+                            }
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            to_see.push(SmallestHolder {
+                cost: new_cost,
+                index: to_parent(n),
+            });
+        }
+    }
+
+    min_cost.map(|cost| (AstarSolution::from_links(parents, &links, sinks), cost))
+}
+
+fn run_dijkstra<N, C, FN, IN, FS, H>(
+    start: &N,
+    successors: &mut FN,
+    stop: &mut FS,
+    hasher: H,
+) -> (IndexMap<N, (ParentIndex, C), H>, Option<usize>)
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
 {
-    dijkstra_partial_with_hasher(start, successors, |_| false, hasher).0
+    let mut parents = IndexMap::with_hasher(hasher);
+    let target_reached = run_dijkstra_in(
+        start,
+        &mut |node: &N, _| successors(node),
+        stop,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    );
+    (parents, target_reached)
 }
 
-/// Determine some reachable nodes from a starting point as well as the minimum cost to
-/// reach them and a possible optimal parent node
-/// using the [Dijkstra search algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm).
-///
-/// - `start` is the starting node.
-/// - `successors` returns a list of successors for a given node, along with the cost for moving
-///   from the node to the successor.
-/// - `stop` is a function which is called every time a node is examined (including `start`).
-///   A `true` return value will stop the algorithm.
-///
-/// The result is a map where every node examined before the algorithm stopped (not including
-/// `start`) is associated with an optimal parent node and a cost from the start node, as well
-/// as the node which caused the algorithm to stop if any.
-///
-/// The [`build_path`] function can be used to build a full path from the starting point to one
-/// of the reachable targets.
-#[expect(clippy::missing_panics_doc)]
-pub fn dijkstra_partial<N, C, FN, IN, FS>(
+fn run_dijkstra_in<N, C, FN, IN, FS, H>(
     start: &N,
-    successors: FN,
-    stop: FS
-) -> (HashMap<N, (N, C), BuildHasherDefault<FxHasher>>, Option<N>)
+    successors: &mut FN,
+    stop: &mut FS,
+    parents: &mut IndexMap<N, (ParentIndex, C), H>,
+    to_see: &mut impl Frontier<SmallestHolder<C>>,
+) -> Option<usize>
 where
     N: Eq + Hash + Clone,
     C: Zero + Ord + Copy,
-    FN: FnMut(&N) -> IN,
+    FN: FnMut(&N, C) -> IN,
     IN: IntoIterator<Item = (N, C)>,
     FS: FnMut(&N) -> bool,
+    H: BuildHasher,
 {
-    dijkstra_partial_with_hasher(start, successors, stop, BuildHasherDefault::<FxHasher>::default())
+    run_dijkstra_multi_in(std::iter::once(start), successors, stop, parents, to_see)
 }
 
-/// Determine some reachable nodes from a starting point as well as the minimum cost to
-/// reach them and a possible optimal parent node
-/// using the [Dijkstra search algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm)
-/// with a custom hasher.
-///
-/// - `start` is the starting node.
-/// - `successors` returns a list of successors for a given node, along with the cost for moving
-///   from the node to the successor.
-/// - `stop` is a function which is called every time a node is examined (including `start`).
-///   A `true` return value will stop the algorithm.
-///
-/// The result is a map where every node examined before the algorithm stopped (not including
-/// `start`) is associated with an optimal parent node and a cost from the start node, as well
-/// as the node which caused the algorithm to stop if any.
-///
-/// The [`build_path`] function can be used to build a full path from the starting point to one
-/// of the reachable targets.
-#[expect(clippy::missing_panics_doc)]
-pub fn dijkstra_partial_with_hasher<N, C, FN, IN, FS, H>(
-    start: &N,
-    mut successors: FN,
-    mut stop: FS,
-    hasher: H
-) -> (HashMap<N, (N, C), H>, Option<N>)
+/// Same as [`run_dijkstra_in`], but starting from several nodes at once. Starting
+/// nodes have no parent, which is represented as `NO_PARENT`.
+fn run_dijkstra_multi_in<'a, N, C, FN, IN, FS, H>(
+    starts: impl IntoIterator<Item = &'a N>,
+    successors: &mut FN,
+    stop: &mut FS,
+    parents: &mut IndexMap<N, (ParentIndex, C), H>,
+    to_see: &mut impl Frontier<SmallestHolder<C>>,
+) -> Option<usize>
 where
-    N: Eq + Hash + Clone,
+    N: Eq + Hash + Clone + 'a,
     C: Zero + Ord + Copy,
-    FN: FnMut(&N) -> IN,
+    FN: FnMut(&N, C) -> IN,
     IN: IntoIterator<Item = (N, C)>,
     FS: FnMut(&N) -> bool,
-    H: BuildHasher + Default,
+    H: BuildHasher,
 {
-    let (parents, reached) = run_dijkstra(start, &mut successors, &mut stop, hasher);
-    (
-        parents
-            .iter()
-            .skip(1)
-            .map(|(n, (p, c))| (n.clone(), (parents.get_index(*p).unwrap().0.clone(), *c))) // unwrap() cannot fail
-            .collect(),
-        reached.map(|i| parents.get_index(i).unwrap().0.clone()),
-    )
+    parents.clear();
+    to_see.clear();
+    for start in starts {
+        if !parents.contains_key(start) {
+            let (index, _) = parents.insert_full(start.clone(), (NO_PARENT, Zero::zero()));
+            to_see.push(SmallestHolder {
+                cost: Zero::zero(),
+                index: to_parent(index),
+            });
+        }
+    }
+    match dijkstra_core(parents, to_see, successors, stop, |_| true) {
+        Outcome::Reached(index) => Some(index),
+        Outcome::Exhausted | Outcome::Suspended | Outcome::Overflow(_) => None,
+    }
 }
 
-fn run_dijkstra<N, C, FN, IN, FS, H>(
-    start: &N,
+/// Core loop of the Dijkstra searches, expanding the nodes of `to_see` until one of them
+/// satisfies `stop`. `expand` is called with every entry of `to_see` about to be
+/// expanded, and the search is suspended if it returns `false`.
+fn dijkstra_core<N, C, FN, IN, FS, FE, H>(
+    parents: &mut IndexMap<N, (ParentIndex, C), H>,
+    to_see: &mut impl Frontier<SmallestHolder<C>>,
     successors: &mut FN,
     stop: &mut FS,
-    hasher: H,
-) -> (IndexMap<N, (usize, C), H>, Option<usize>)
+    mut expand: FE,
+) -> Outcome
 where
     N: Eq + Hash + Clone,
     C: Zero + Ord + Copy,
-    FN: FnMut(&N) -> IN,
+    FN: FnMut(&N, C) -> IN,
     IN: IntoIterator<Item = (N, C)>,
     FS: FnMut(&N) -> bool,
+    FE: FnMut(&SmallestHolder<C>) -> bool,
     H: BuildHasher,
 {
-    let mut to_see = BinaryHeap::new();
-    to_see.push(SmallestHolder {
-        cost: Zero::zero(),
-        index: 0,
-    });
-    let mut parents: IndexMap<N, (usize, C), H> = IndexMap::with_hasher(hasher);
-    parents.insert(start.clone(), (usize::MAX, Zero::zero()));
-    let mut target_reached = None;
-    while let Some(SmallestHolder { cost, index }) = to_see.pop() {
+    while let Some(holder) = to_see.pop() {
+        let SmallestHolder { cost, index } = holder;
         let successors = {
-            let (node, &(_, c)) = parents.get_index(index).unwrap();
+            let (node, &(_, c)) = parents.get_index(from_parent(index)).unwrap();
             if stop(node) {
-                target_reached = Some(index);
-                break;
+                return Outcome::Reached(from_parent(index));
             }
             // We may have inserted a node several time into the binary heap if we found
             // a better way to access it. Ensure that we are currently dealing with the
@@ -323,7 +1277,11 @@ where
             if cost > c {
                 continue;
             }
-            successors(node)
+            if !expand(&holder) {
+                to_see.push(holder);
+                return Outcome::Suspended;
+            }
+            successors(node, cost)
         };
         for (successor, move_cost) in successors {
             let new_cost = cost + move_cost;
@@ -345,11 +1303,211 @@ where
 
             to_see.push(SmallestHolder {
                 cost: new_cost,
-                index: n,
+                index: to_parent(n),
             });
         }
     }
-    (parents, target_reached)
+    Outcome::Exhausted
+}
+
+/// Compute a flow field leading every node to its nearest goal, using a multi-source
+/// [Dijkstra search](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) started from
+/// all the goals at once.
+///
+/// - `goals` are the nodes to lead to.
+/// - `predecessors` returns a list of nodes from which a given node can be reached, along
+///   with the cost for moving from them to the node. For an undirected graph, or a grid
+///   where moves can be done in both directions for the same cost, this is the same as
+///   the successors function.
+///
+/// The result is a map where every node able to reach a goal is associated with the next
+/// node to move to in order to reach the nearest goal, along with the remaining cost to
+/// this goal. Goals are associated with themselves and a zero cost.
+///
+/// Many agents can then steer towards the goals by following the map, without running
+/// a search for each of them.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::flow_field;
+///
+/// // Exits are located at 0 and 10 on a line.
+/// let field = flow_field(&[0, 10], |&n: &i32| {
+///     [(n - 1, 1), (n + 1, 1)].into_iter().filter(|&(m, _)| (0..=10).contains(&m))
+/// });
+/// assert_eq!(field[&3], (2, 3));
+/// assert_eq!(field[&7], (8, 3));
+/// assert_eq!(field[&10], (10, 0));
+///
+/// // Follow the field from 4.
+/// let mut n = 4;
+/// while field[&n].0 != n {
+///     n = field[&n].0;
+/// }
+/// assert_eq!(n, 0);
+/// ```
+pub fn flow_field<N, C, FN, IN>(
+    goals: &[N],
+    predecessors: FN,
+) -> HashMap<N, (N, C), BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    flow_field_with_hasher(
+        goals,
+        predecessors,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Compute a flow field leading every node to its nearest goal, using a multi-source
+/// [Dijkstra search](https://en.wikipedia.org/wiki/Dijkstra's_algorithm) started from
+/// all the goals at once, with a custom hasher.
+///
+/// - `goals` are the nodes to lead to.
+/// - `predecessors` returns a list of nodes from which a given node can be reached, along
+///   with the cost for moving from them to the node.
+///
+/// The result is a map where every node able to reach a goal is associated with the next
+/// node to move to in order to reach the nearest goal, along with the remaining cost to
+/// this goal. Goals are associated with themselves and a zero cost.
+pub fn flow_field_with_hasher<N, C, FN, IN, H>(
+    goals: &[N],
+    mut predecessors: FN,
+    hasher: H,
+) -> HashMap<N, (N, C), H>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    H: BuildHasher + Default,
+{
+    let mut parents = IndexMap::with_hasher(hasher);
+    run_dijkstra_multi_in(
+        goals,
+        &mut |node: &N, _| predecessors(node),
+        &mut |_: &N| false,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    );
+    parents
+        .iter()
+        .enumerate()
+        .map(|(i, (n, &(p, c)))| {
+            let next = parents.get_index(if p == NO_PARENT { i } else { from_parent(p) });
+            (
+                n.clone(),
+                (next.map_or_else(|| n.clone(), |(next, _)| next.clone()), c),
+            )
+        })
+        .collect()
+}
+
+/// Partition a graph into the regions closest to each of some seed nodes, using a
+/// multi-source [Dijkstra search](https://en.wikipedia.org/wiki/Dijkstra's_algorithm)
+/// started from all the seeds at once.
+///
+/// - `seeds` are the nodes around which regions are built.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+///
+/// The result is a map where every node reachable from a seed is associated with its nearest
+/// seed, along with the cost to reach it from this seed. Seeds are associated with themselves
+/// and a zero cost. When a node is as close to several seeds, one of them is picked.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::voronoi_partition;
+///
+/// // Seeds are located at 0 and 10 on a line.
+/// let regions = voronoi_partition(&[0, 10], |&n: &i32| {
+///     [(n - 1, 1), (n + 1, 1)].into_iter().filter(|&(m, _)| (0..=10).contains(&m))
+/// });
+/// assert_eq!(regions[&3], (0, 3));
+/// assert_eq!(regions[&7], (10, 3));
+/// assert_eq!(regions[&10], (10, 0));
+/// assert_eq!(regions.len(), 11);
+/// ```
+pub fn voronoi_partition<N, C, FN, IN>(
+    seeds: &[N],
+    successors: FN,
+) -> HashMap<N, (N, C), BuildHasherDefault<FxHasher>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    voronoi_partition_with_hasher(seeds, successors, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Partition a graph into the regions closest to each of some seed nodes, using a
+/// multi-source [Dijkstra search](https://en.wikipedia.org/wiki/Dijkstra's_algorithm)
+/// started from all the seeds at once, with a custom hasher.
+///
+/// - `seeds` are the nodes around which regions are built.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor.
+///
+/// The result is a map where every node reachable from a seed is associated with its nearest
+/// seed, along with the cost to reach it from this seed. Seeds are associated with themselves
+/// and a zero cost.
+#[expect(clippy::missing_panics_doc)]
+pub fn voronoi_partition_with_hasher<N, C, FN, IN, H>(
+    seeds: &[N],
+    mut successors: FN,
+    hasher: H,
+) -> HashMap<N, (N, C), H>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    H: BuildHasher + Default,
+{
+    let mut parents = IndexMap::with_hasher(hasher);
+    run_dijkstra_multi_in(
+        seeds,
+        &mut |node: &N, _| successors(node),
+        &mut |_: &N| false,
+        &mut parents,
+        &mut BinaryHeap::new(),
+    );
+    // A parent may have been discovered after its child, so the seed of every node
+    // is found by walking up the tree, remembering the seeds already known.
+    let mut owners = vec![usize::MAX; parents.len()];
+    let mut chain = Vec::new();
+    for i in 0..parents.len() {
+        let mut j = i;
+        while owners[j] == usize::MAX {
+            chain.push(j);
+            match parents[j].0 {
+                NO_PARENT => {
+                    owners[j] = j;
+                    break;
+                }
+                p => j = from_parent(p),
+            }
+        }
+        let owner = owners[j];
+        for k in chain.drain(..) {
+            owners[k] = owner;
+        }
+    }
+    parents
+        .iter()
+        .zip(owners)
+        .map(|((n, &(_, c)), owner)| {
+            let seed = parents.get_index(owner).unwrap().0; // unwrap() cannot fail
+            (n.clone(), (seed.clone(), c))
+        })
+        .collect()
 }
 
 /// Build a path leading to a target according to a parents map, which must
@@ -375,8 +1533,9 @@ where
 ///
 /// ```
 /// use pathfinding::prelude::build_path;
+/// use std::collections::HashMap;
 ///
-/// let parents = (2..=100).map(|n| (n, (n/2, 1))).collect();
+/// let parents: HashMap<_, _> = (2..=100).map(|n| (n, (n/2, 1))).collect();
 /// assert_eq!(vec![1, 2, 4, 9, 18], build_path(&18, &parents));
 /// assert_eq!(vec![1], build_path(&1, &parents));
 /// assert_eq!(vec![101], build_path(&101, &parents));
@@ -397,14 +1556,48 @@ where
     rev
 }
 
-struct SmallestHolder<K> {
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct SmallestHolder<K> {
     cost: K,
-    index: usize,
+    #[cfg_attr(feature = "serde", serde(with = "super::serde_parent"))]
+    index: ParentIndex,
+}
+
+impl<K: Ord + Copy> Frontier<SmallestHolder<K>> for IndexedHeap<K> {
+    fn push(&mut self, item: SmallestHolder<K>) {
+        Self::push(self, from_parent(item.index), item.cost);
+    }
+
+    fn pop(&mut self) -> Option<SmallestHolder<K>> {
+        Self::pop(self).map(|(index, cost)| SmallestHolder {
+            cost,
+            index: to_parent(index),
+        })
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
+
+impl<K: RadixKey> Frontier<SmallestHolder<K>> for RadixHeap<K, ParentIndex> {
+    fn push(&mut self, item: SmallestHolder<K>) {
+        Self::push(self, item.cost, item.index);
+    }
+
+    fn pop(&mut self) -> Option<SmallestHolder<K>> {
+        Self::pop(self).map(|(cost, index)| SmallestHolder { cost, index })
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
 }
 
 impl<K: PartialEq> PartialEq for SmallestHolder<K> {
     fn eq(&self, other: &Self) -> bool {
-        self.cost == other.cost
+        self.cost == other.cost && (!cfg!(feature = "deterministic") || self.index == other.index)
     }
 }
 
@@ -418,15 +1611,23 @@ impl<K: Ord> PartialOrd for SmallestHolder<K> {
 
 impl<K: Ord> Ord for SmallestHolder<K> {
     fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+        // With the `deterministic` feature, the node discovered first is favored when
+        // costs are equal.
+        other.cost.cmp(&self.cost).then_with(|| {
+            if cfg!(feature = "deterministic") {
+                other.index.cmp(&self.index)
+            } else {
+                Ordering::Equal
+            }
+        })
     }
 }
 
 /// Struct returned by [`dijkstra_reach`].
 pub struct DijkstraReachable<N, C, FN, H> {
     to_see: BinaryHeap<SmallestHolder<C>>,
-    seen: HashSet<usize, H>,
-    parents: FxIndexMap<N, (usize, C)>,
+    seen: HashSet<ParentIndex, H>,
+    parents: FxIndexMap<N, (ParentIndex, C)>,
     total_costs: HashMap<N, C, H>,
     successors: FN,
 }
@@ -460,11 +1661,14 @@ where
             }
             let item;
             let successors = {
-                let (node, (parent_index, _)) = self.parents.get_index(index).unwrap();
+                let (node, (parent_index, _)) = self.parents.get_index(from_parent(index)).unwrap();
                 let total_cost = self.total_costs[node];
                 item = Some(DijkstraReachableItem {
                     node: node.clone(),
-                    parent: self.parents.get_index(*parent_index).map(|x| x.0.clone()),
+                    parent: self
+                        .parents
+                        .get_index(from_parent(*parent_index))
+                        .map(|x| x.0.clone()),
                     total_cost,
                 });
                 (self.successors)(node)
@@ -491,7 +1695,7 @@ where
 
                 self.to_see.push(SmallestHolder {
                     cost: new_cost,
-                    index: n,
+                    index: to_parent(n),
                 });
             }
             return item;
@@ -536,8 +1740,8 @@ where
         index: 0,
     });
 
-    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
-    parents.insert(start.clone(), (usize::MAX, Zero::zero()));
+    let mut parents: FxIndexMap<N, (ParentIndex, C)> = FxIndexMap::default();
+    parents.insert(start.clone(), (NO_PARENT, Zero::zero()));
 
     let mut total_costs = HashMap::with_hasher(hasher.clone());
     total_costs.insert(start.clone(), Zero::zero());
@@ -552,3 +1756,244 @@ where
         successors,
     }
 }
+
+/// Suspended state of a [Dijkstra search](dijkstra) which can be resumed later,
+/// possibly in another process.
+///
+/// The search progresses by calling [`resume`](Self::resume) with a maximum number
+/// of node expansions. When the search completes, [`ControlFlow::Break`] is returned
+/// with the result of the search, as [`dijkstra`] would return it. Otherwise,
+/// [`ControlFlow::Continue`] is returned and the checkpoint holds the whole state of
+/// the search. The successors and success functions are not part of the state and must be
+/// given again every time the search is resumed.
+///
+/// When the `serde` feature is enabled, the checkpoint can be serialized and deserialized.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::DijkstraCheckpoint;
+/// use std::ops::ControlFlow;
+///
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n * 2, 3)];
+/// let mut checkpoint = DijkstraCheckpoint::new(&1);
+/// let mut suspensions = 0;
+/// let result = loop {
+///     match checkpoint.resume(successors, |&n| n == 100, 10) {
+///         ControlFlow::Break(result) => break result,
+///         // The checkpoint could be saved here and resumed later.
+///         ControlFlow::Continue(()) => suspensions += 1,
+///     }
+/// };
+/// assert!(suspensions > 0);
+/// assert_eq!(result.expect("no path found").1, 18);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize, C: serde::Serialize + Copy",
+        deserialize = "N: serde::Deserialize<'de> + Eq + Hash, C: serde::Deserialize<'de> + Ord + Copy"
+    ))
+)]
+pub struct DijkstraCheckpoint<N, C> {
+    #[cfg_attr(feature = "serde", serde(with = "super::serde_parents"))]
+    parents: FxIndexMap<N, (ParentIndex, C)>,
+    to_see: BinaryHeap<SmallestHolder<C>>,
+}
+
+impl<N, C> DijkstraCheckpoint<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    /// Create a new checkpoint for a search starting at `start`. No
+    /// node is expanded until [`resume`](Self::resume) is called.
+    #[must_use]
+    pub fn new(start: &N) -> Self {
+        let mut parents = FxIndexMap::default();
+        parents.insert(start.clone(), (NO_PARENT, Zero::zero()));
+        let mut to_see = BinaryHeap::new();
+        to_see.push(SmallestHolder {
+            cost: Zero::zero(),
+            index: 0,
+        });
+        Self { parents, to_see }
+    }
+
+    /// Resume the search for at most `max_steps` node expansions.
+    ///
+    /// - `successors` returns a list of successors for a given node, along with the cost for
+    ///   moving from the node to the successor. This cost must be non-negative.
+    /// - `success` checks whether the goal has been reached.
+    /// - `max_steps` is the maximum number of nodes to expand before suspending the search.
+    ///
+    /// `ControlFlow::Break` is returned with the search result when the search has
+    /// completed, and `ControlFlow::Continue` is returned when the search has been
+    /// suspended after `max_steps` expansions.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the checkpoint has been corrupted, for example
+    /// when deserialized from tampered data.
+    pub fn resume<FN, IN, FS>(
+        &mut self,
+        mut successors: FN,
+        mut success: FS,
+        max_steps: usize,
+    ) -> ControlFlow<Option<(Vec<N>, C)>>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FS: FnMut(&N) -> bool,
+    {
+        let mut steps = 0;
+        let outcome = dijkstra_core(
+            &mut self.parents,
+            &mut self.to_see,
+            &mut |node: &N, _| successors(node),
+            &mut success,
+            |_| {
+                steps += 1;
+                steps <= max_steps
+            },
+        );
+        match outcome {
+            Outcome::Reached(index) => {
+                let path = reverse_path(&self.parents, |&(p, _)| from_parent(p), index);
+                ControlFlow::Break(Some((path, self.parents[index].1)))
+            }
+            Outcome::Exhausted | Outcome::Overflow(_) => ControlFlow::Break(None),
+            Outcome::Suspended => ControlFlow::Continue(()),
+        }
+    }
+
+    /// Return the number of nodes discovered so far.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.parents.len()
+    }
+}
+
+/// Step-wise driver for a [Dijkstra search](dijkstra), which owns the successors and
+/// success functions along with a [`DijkstraCheckpoint`].
+///
+/// This lets an expensive search be spread over several calls, for example over
+/// several frames of a game, without blocking or using a background thread. The search
+/// progresses by a single node expansion with [`step`](Self::step), by a bounded number
+/// of expansions with [`run_for`](Self::run_for), or until completion with
+/// [`run`](Self::run). Once the search has completed, those methods keep returning
+/// its result.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::DijkstraSearch;
+/// use std::ops::ControlFlow;
+///
+/// let mut search = DijkstraSearch::new(&1, |&n: &u32| vec![(n + 1, 1), (n * 2, 3)], |&n| n == 100);
+/// let mut frames = 0;
+/// let result = loop {
+///     frames += 1;
+///     // Expand at most 10 nodes per frame.
+///     if let ControlFlow::Break(result) = search.run_for(10) {
+///         break result;
+///     }
+/// };
+/// assert!(frames > 1);
+/// assert_eq!(result.expect("no path found").1, 18);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DijkstraSearch<N, C, FN, FS> {
+    checkpoint: DijkstraCheckpoint<N, C>,
+    successors: FN,
+    success: FS,
+    finished: bool,
+    result: Option<(Vec<N>, C)>,
+}
+
+impl<N, C, FN, IN, FS> DijkstraSearch<N, C, FN, FS>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    /// Prepare a search starting at `start`. The arguments have the same meaning as
+    /// for [`dijkstra`]. No node is expanded until the search is run.
+    #[must_use]
+    pub fn new(start: &N, successors: FN, success: FS) -> Self {
+        Self::from_checkpoint(DijkstraCheckpoint::new(start), successors, success)
+    }
+
+    /// Continue a search from a previously saved checkpoint.
+    #[must_use]
+    pub const fn from_checkpoint(
+        checkpoint: DijkstraCheckpoint<N, C>,
+        successors: FN,
+        success: FS,
+    ) -> Self {
+        Self {
+            checkpoint,
+            successors,
+            success,
+            finished: false,
+            result: None,
+        }
+    }
+
+    /// Expand a single node. This is equivalent to `run_for(1)`.
+    pub fn step(&mut self) -> ControlFlow<Option<(Vec<N>, C)>> {
+        self.run_for(1)
+    }
+
+    /// Expand at most `max_steps` nodes. `ControlFlow::Break` is returned with the
+    /// search result when the search has completed, and `ControlFlow::Continue`
+    /// otherwise.
+    pub fn run_for(&mut self, max_steps: usize) -> ControlFlow<Option<(Vec<N>, C)>> {
+        if !self.finished {
+            if let ControlFlow::Break(result) =
+                self.checkpoint
+                    .resume(&mut self.successors, &mut self.success, max_steps)
+            {
+                self.finished = true;
+                self.result = result;
+            }
+        }
+        if self.finished {
+            ControlFlow::Break(self.result.clone())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// Run the search until it completes and return its result.
+    pub fn run(&mut self) -> Option<(Vec<N>, C)> {
+        loop {
+            if let ControlFlow::Break(result) = self.run_for(usize::MAX) {
+                return result;
+            }
+        }
+    }
+
+    /// Check whether the search has completed.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Return the number of nodes discovered so far.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.checkpoint.discovered()
+    }
+
+    /// Return the current state of the search, which can be saved and later given to
+    /// [`from_checkpoint`](Self::from_checkpoint).
+    #[must_use]
+    pub const fn checkpoint(&self) -> &DijkstraCheckpoint<N, C> {
+        &self.checkpoint
+    }
+}
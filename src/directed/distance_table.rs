@@ -0,0 +1,131 @@
+//! Compute the costs of the shortest paths between sets of nodes, sharing a
+//! single Dijkstra search between all the paths leaving or reaching a same node.
+
+use super::dijkstra::dijkstra_partial;
+use num_traits::Zero;
+use rustc_hash::FxHashSet;
+use std::hash::Hash;
+
+/// Compute the costs of the shortest paths from `start` to every node of `goals`
+/// using a single [Dijkstra search](super::dijkstra::dijkstra).
+///
+/// - `start` is the starting node.
+/// - `goals` are the nodes to reach.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. This cost must be non-negative.
+///
+/// The search stops as soon as all the goals have been reached. The returned vector holds
+/// the cost to reach every goal, in the order of `goals`, or `None` for the goals which
+/// cannot be reached.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::one_to_many;
+///
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n * 2, 3)];
+/// assert_eq!(one_to_many(&1, &[4, 1, 10], successors), vec![Some(3), Some(0), Some(7)]);
+/// let bounded = |&n: &u32| if n < 10 { vec![(n + 1, 1)] } else { vec![] };
+/// assert_eq!(one_to_many(&3, &[5, 2], bounded), vec![Some(2), None]);
+/// ```
+pub fn one_to_many<N, C, FN, IN>(start: &N, goals: &[N], successors: FN) -> Vec<Option<C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut remaining = goals.iter().collect::<FxHashSet<_>>();
+    let (reached, _) = dijkstra_partial(start, successors, |n| {
+        remaining.remove(n);
+        remaining.is_empty()
+    });
+    // Every goal found has been examined before the search stopped, so its cost is final.
+    goals
+        .iter()
+        .map(|goal| {
+            if goal == start {
+                Some(Zero::zero())
+            } else {
+                reached.get(goal).map(|&(_, cost)| cost)
+            }
+        })
+        .collect()
+}
+
+/// Compute the costs of the shortest paths from every node of `starts` to `goal` using
+/// a single [Dijkstra search](super::dijkstra::dijkstra) going backwards from `goal`.
+///
+/// - `starts` are the starting nodes.
+/// - `goal` is the node to reach.
+/// - `predecessors` returns a list of nodes having an edge leading to a given node, along
+///   with the cost for moving from the predecessor to the node. This cost must be
+///   non-negative.
+///
+/// The search stops as soon as all the starting nodes have been reached. The returned
+/// vector holds the cost from every starting node, in the order of `starts`, or `None`
+/// for those from which `goal` cannot be reached.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::many_to_one;
+///
+/// // Moving right costs 1 and moving left costs 3, so the predecessor on the left of a
+/// // node leads to it at a cost of 1.
+/// let predecessors = |&n: &i32| vec![(n - 1, 1), (n + 1, 3)];
+/// assert_eq!(many_to_one(&[0, 12, 10], &10, predecessors), vec![Some(10), Some(6), Some(0)]);
+/// ```
+pub fn many_to_one<N, C, FN, IN>(starts: &[N], goal: &N, predecessors: FN) -> Vec<Option<C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    one_to_many(goal, starts, predecessors)
+}
+
+/// Compute the costs of the shortest paths from every node of `starts` to every node of
+/// `goals`, running one [Dijkstra search](super::dijkstra::dijkstra) per starting node
+/// which stops as soon as all the goals have been reached.
+///
+/// - `starts` are the starting nodes.
+/// - `goals` are the nodes to reach.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. This cost must be non-negative.
+///
+/// The returned table has one row per starting node, in the order of `starts`, holding the
+/// cost to reach every goal, in the order of `goals`, or `None` for the goals which cannot
+/// be reached from this starting node.
+///
+/// When there are many more starting nodes than goals and the predecessors of a node can be
+/// computed, calling [`many_to_one`] for every goal runs fewer searches.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::many_to_many;
+///
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n * 2, 3)];
+/// assert_eq!(
+///     many_to_many(&[1, 2], &[4, 8], successors),
+///     vec![vec![Some(3), Some(6)], vec![Some(2), Some(5)]],
+/// );
+/// ```
+pub fn many_to_many<N, C, FN, IN>(
+    starts: &[N],
+    goals: &[N],
+    mut successors: FN,
+) -> Vec<Vec<Option<C>>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    starts
+        .iter()
+        .map(|start| one_to_many(start, goals, &mut successors))
+        .collect()
+}
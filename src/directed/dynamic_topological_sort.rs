@@ -0,0 +1,240 @@
+//! Maintain a topological order of a directed acyclic graph while edges are
+//! being added, using the [Pearce-Kelly
+//! algorithm](https://www.doc.ic.ac.uk/~phjk/Publications/DynamicTopoSortAlg-JEA-07.pdf).
+
+use crate::FxIndexSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::hash::Hash;
+use thiserror::Error;
+
+/// Error returned when adding an edge would create a cycle.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("adding this edge would create a cycle")]
+pub struct CycleError<N> {
+    /// Existing path going from the target of the rejected edge back to its
+    /// source, both included. Adding the edge would have closed this path into
+    /// a cycle.
+    pub path: Vec<N>,
+}
+
+/// A directed acyclic graph along with a topological order of its nodes, kept
+/// up-to-date as edges are added.
+///
+/// When an edge from `a` to `b` is added while `b` comes before `a` in the current
+/// order, only the nodes whose position lies between those of `b` and `a` may have
+/// to be moved. Those reachable from `b` and those reaching `a` are searched for, and
+/// reordered among the positions they already occupy. An edge closing a cycle is
+/// detected during this search and rejected. Adding an edge which agrees with the
+/// current order does not require any search.
+///
+/// This is much cheaper than running [`topological_sort`](super::topological_sort::topological_sort)
+/// again after every insertion, for example when validating new dependencies in a
+/// build system.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::DynamicTopologicalOrder;
+///
+/// let mut deps = DynamicTopologicalOrder::new();
+/// deps.add_edge("compile", "link").unwrap();
+/// deps.add_edge("generate", "compile").unwrap();
+/// deps.add_edge("link", "package").unwrap();
+/// assert_eq!(deps.order(), vec!["generate", "compile", "link", "package"]);
+///
+/// let err = deps.add_edge("package", "generate").unwrap_err();
+/// assert_eq!(err.path, vec!["generate", "compile", "link", "package"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynamicTopologicalOrder<N> {
+    nodes: FxIndexSet<N>,
+    // Position of every node in the order.
+    position: Vec<usize>,
+    // Node at every position in the order.
+    at: Vec<usize>,
+    successors: Vec<FxHashSet<usize>>,
+    predecessors: Vec<FxHashSet<usize>>,
+}
+
+impl<N> Default for DynamicTopologicalOrder<N> {
+    fn default() -> Self {
+        Self {
+            nodes: FxIndexSet::default(),
+            position: Vec::new(),
+            at: Vec::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+}
+
+impl<N> DynamicTopologicalOrder<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create an empty graph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node to the graph, at the end of the current order. Return `false`
+    /// if the node was already present.
+    pub fn add_node(&mut self, node: N) -> bool {
+        self.index(node).1
+    }
+
+    fn index(&mut self, node: N) -> (usize, bool) {
+        let (index, inserted) = self.nodes.insert_full(node);
+        if inserted {
+            self.position.push(index);
+            self.at.push(index);
+            self.successors.push(FxHashSet::default());
+            self.predecessors.push(FxHashSet::default());
+        }
+        (index, inserted)
+    }
+
+    /// Add an edge from `from` to `to`, adding the nodes to the graph if needed, and
+    /// update the order so that `from` comes before `to`.
+    ///
+    /// # Errors
+    ///
+    /// If the edge would create a cycle, it is not added and a [`CycleError`] containing
+    /// the existing path from `to` to `from` is returned.
+    pub fn add_edge(&mut self, from: N, to: N) -> Result<(), CycleError<N>> {
+        let x = self.index(from).0;
+        let y = self.index(to).0;
+        if x == y {
+            return Err(CycleError {
+                path: vec![self.nodes[x].clone()],
+            });
+        }
+        let (lower, upper) = (self.position[y], self.position[x]);
+        if lower < upper {
+            // `to` comes before `from` and may need to be moved after it.
+            let forward = self.search_forward(y, x, upper)?;
+            let backward = self.search_backward(x, lower);
+            self.reorder(backward, forward);
+        }
+        self.successors[x].insert(y);
+        self.predecessors[y].insert(x);
+        Ok(())
+    }
+
+    /// Nodes reachable from `start` whose position is not after `upper`. Fail if
+    /// `target` is one of them.
+    fn search_forward(
+        &self,
+        start: usize,
+        target: usize,
+        upper: usize,
+    ) -> Result<Vec<usize>, CycleError<N>> {
+        let mut parents = FxHashMap::default();
+        parents.insert(start, start);
+        let mut to_see = vec![start];
+        let mut found = vec![start];
+        while let Some(node) = to_see.pop() {
+            for &next in &self.successors[node] {
+                if next == target {
+                    let mut path = vec![self.nodes[target].clone()];
+                    let mut n = node;
+                    loop {
+                        path.push(self.nodes[n].clone());
+                        if n == start {
+                            break;
+                        }
+                        n = parents[&n];
+                    }
+                    path.reverse();
+                    return Err(CycleError { path });
+                }
+                if self.position[next] < upper && !parents.contains_key(&next) {
+                    parents.insert(next, node);
+                    to_see.push(next);
+                    found.push(next);
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// Nodes reaching `start` whose position is after `lower`.
+    fn search_backward(&self, start: usize, lower: usize) -> Vec<usize> {
+        let mut seen = FxHashSet::default();
+        seen.insert(start);
+        let mut to_see = vec![start];
+        let mut found = vec![start];
+        while let Some(node) = to_see.pop() {
+            for &next in &self.predecessors[node] {
+                if self.position[next] > lower && seen.insert(next) {
+                    to_see.push(next);
+                    found.push(next);
+                }
+            }
+        }
+        found
+    }
+
+    /// Reassign the positions occupied by the nodes of `backward` and `forward` so that
+    /// all nodes of `backward` come first, each set keeping its relative order.
+    fn reorder(&mut self, mut backward: Vec<usize>, mut forward: Vec<usize>) {
+        backward.sort_unstable_by_key(|&n| self.position[n]);
+        forward.sort_unstable_by_key(|&n| self.position[n]);
+        let mut positions = backward
+            .iter()
+            .chain(&forward)
+            .map(|&n| self.position[n])
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+        for (node, position) in backward.into_iter().chain(forward).zip(positions) {
+            self.position[node] = position;
+            self.at[position] = node;
+        }
+    }
+
+    /// Remove the edge from `from` to `to`. Return `false` if the edge was not present.
+    /// The current order remains valid and is left unchanged.
+    pub fn remove_edge(&mut self, from: &N, to: &N) -> bool {
+        let (Some(x), Some(y)) = (self.nodes.get_index_of(from), self.nodes.get_index_of(to))
+        else {
+            return false;
+        };
+        self.predecessors[y].remove(&x);
+        self.successors[x].remove(&y)
+    }
+
+    /// Check whether the graph contains an edge from `from` to `to`.
+    #[must_use]
+    pub fn has_edge(&self, from: &N, to: &N) -> bool {
+        match (self.nodes.get_index_of(from), self.nodes.get_index_of(to)) {
+            (Some(x), Some(y)) => self.successors[x].contains(&y),
+            _ => false,
+        }
+    }
+
+    /// Position of a node in the current order, or `None` if the node is absent
+    /// from the graph.
+    #[must_use]
+    pub fn position(&self, node: &N) -> Option<usize> {
+        self.nodes.get_index_of(node).map(|i| self.position[i])
+    }
+
+    /// Nodes of the graph in the current topological order.
+    #[must_use]
+    pub fn order(&self) -> Vec<N> {
+        self.at.iter().map(|&i| self.nodes[i].clone()).collect()
+    }
+
+    /// Number of nodes in the graph.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check whether the graph contains no node.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
@@ -6,12 +6,20 @@
 //! network. When the network is modified, the flow is recomputed and tries to
 //! take advantage of computations already performed on unchanged or augmented
 //! edges.
+//!
+//! Three representations of the network are available: [`DenseCapacity`] uses
+//! matrices and suits small dense graphs, while [`SparseCapacity`] and
+//! [`AdjacencyCapacity`] only store existing edges. [`AdjacencyCapacity`] keeps
+//! every edge next to its reverse one in adjacency lists, and is the fastest
+//! choice for graphs with many nodes and few edges.
 
 use super::bfs::bfs;
-use crate::{FxIndexSet, matrix::Matrix};
+use crate::matrix::Matrix;
+use indexmap::IndexSet;
 use num_traits::{Bounded, Signed, Zero};
+use rustc_hash::{FxHashMap, FxHasher};
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
-use std::hash::Hash;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 
 /// Type alias for Edmonds-Karp maximum flow result.
 pub type EKFlows<N, C> = (Vec<Edge<N, C>>, C, Vec<Edge<N, C>>);
@@ -56,10 +64,43 @@ where
     C: Zero + Bounded + Signed + Ord + Copy,
     IC: IntoIterator<Item = Edge<N, C>>,
     EK: EdmondsKarp<C>,
+{
+    edmonds_karp_with_hasher::<N, C, IC, EK, _>(
+        vertices,
+        source,
+        sink,
+        caps,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Compute the maximum flow and the minimal cut of a directed graph using the
+/// [Edmonds Karp algorithm](https://en.wikipedia.org/wiki/Edmonds–Karp_algorithm)
+/// and a custom hasher to map nodes to indices.
+///
+/// See [`edmonds_karp`] for a description of the parameters and of the result.
+///
+/// # Panics
+///
+/// This function panics if `source` or `sink` is not found in `vertices`.
+pub fn edmonds_karp_with_hasher<N, C, IC, EK, H>(
+    vertices: &[N],
+    source: &N,
+    sink: &N,
+    caps: IC,
+    hasher: H,
+) -> EKFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = Edge<N, C>>,
+    EK: EdmondsKarp<C>,
+    H: BuildHasher,
 {
     // Build a correspondence between N and 0..vertices.len() so that we can
     // work with matrices more easily.
-    let reverse = vertices.iter().collect::<FxIndexSet<_>>();
+    let mut reverse: IndexSet<&N, H> = IndexSet::with_capacity_and_hasher(vertices.len(), hasher);
+    reverse.extend(vertices);
     let mut capacities = EK::new(
         vertices.len(),
         reverse
@@ -114,6 +155,21 @@ where
     edmonds_karp::<N, C, IC, SparseCapacity<C>>(vertices, source, sink, caps)
 }
 
+/// Helper for the `edmonds_karp` function using adjacency lists for large sparse graphs.
+pub fn edmonds_karp_adjacency<N, C, IC>(
+    vertices: &[N],
+    source: &N,
+    sink: &N,
+    caps: IC,
+) -> EKFlows<N, C>
+where
+    N: Eq + Hash + Copy,
+    C: Zero + Bounded + Signed + Ord + Copy,
+    IC: IntoIterator<Item = Edge<N, C>>,
+{
+    edmonds_karp::<N, C, IC, AdjacencyCapacity<C>>(vertices, source, sink, caps)
+}
+
 /// Representation of capacity and flow data.
 pub trait EdmondsKarp<C: Copy + Zero + Signed + Ord + Bounded> {
     /// Create a new empty structure.
@@ -571,3 +627,151 @@ impl<C: Copy + Zero + Signed + Ord + Bounded> EdmondsKarp<C> for DenseCapacity<C
             .collect()
     }
 }
+
+/// Adjacency lists capacity and flow data.
+///
+/// Every edge is stored along with its reverse one, and the edges leaving a node
+/// are listed next to it, so that the residual successors of a node are found
+/// without looking at the other nodes.
+#[derive(Clone, Debug)]
+pub struct AdjacencyCapacity<C> {
+    common: Common<C>,
+    // Edge `e ^ 1` is the reverse of edge `e`.
+    edges: Vec<ResidualEdge<C>>,
+    adjacency: Vec<Vec<usize>>,
+    indices: FxHashMap<(usize, usize), usize>,
+}
+
+#[derive(Clone, Debug)]
+struct ResidualEdge<C> {
+    to: usize,
+    residual: C,
+    flow: C,
+}
+
+impl<C: Copy + Zero> AdjacencyCapacity<C> {
+    fn edge(&self, from: usize, to: usize) -> Option<&ResidualEdge<C>> {
+        self.indices.get(&(from, to)).map(|&e| &self.edges[e])
+    }
+
+    fn edge_index(&mut self, from: usize, to: usize) -> usize {
+        if let Some(&e) = self.indices.get(&(from, to)) {
+            return e;
+        }
+        let e = self.edges.len();
+        for (from, to) in [(from, to), (to, from)] {
+            self.indices.insert((from, to), self.edges.len());
+            self.adjacency[from].push(self.edges.len());
+            self.edges.push(ResidualEdge {
+                to,
+                residual: Zero::zero(),
+                flow: Zero::zero(),
+            });
+        }
+        e
+    }
+}
+
+impl<C: Copy + Zero + Signed + Ord + Bounded> EdmondsKarp<C> for AdjacencyCapacity<C> {
+    fn new(size: usize, source: usize, sink: usize) -> Self {
+        assert!(source < size, "source is greater or equal than size");
+        assert!(sink < size, "sink is greater or equal than size");
+        Self {
+            common: Common {
+                size,
+                source,
+                sink,
+                total_capacity: Zero::zero(),
+                details: true,
+            },
+            edges: Vec::new(),
+            adjacency: vec![Vec::new(); size],
+            indices: FxHashMap::default(),
+        }
+    }
+
+    fn from_matrix(source: usize, sink: usize, capacities: Matrix<C>) -> Self {
+        assert!(
+            capacities.is_square(),
+            "capacities matrix is not a square one"
+        );
+        let size = capacities.rows;
+        assert!(source < size, "source is greater or equal than matrix side");
+        assert!(sink < size, "sink is greater or equal than matrix side");
+        let mut result = Self::new(size, source, sink);
+        for from in 0..size {
+            for to in 0..size {
+                let capacity = capacities[(from, to)];
+                if capacity > Zero::zero() {
+                    result.set_capacity(from, to, capacity);
+                }
+            }
+        }
+        result
+    }
+
+    fn common(&self) -> &Common<C> {
+        &self.common
+    }
+
+    fn common_mut(&mut self) -> &mut Common<C> {
+        &mut self.common
+    }
+
+    fn residual_successors(&self, from: usize) -> Vec<(usize, C)> {
+        self.adjacency[from]
+            .iter()
+            .map(|&e| &self.edges[e])
+            .filter_map(|edge| (edge.residual > Zero::zero()).then_some((edge.to, edge.residual)))
+            .collect()
+    }
+
+    fn residual_capacity(&self, from: usize, to: usize) -> C {
+        self.edge(from, to)
+            .map_or_else(Zero::zero, |edge| edge.residual)
+    }
+
+    fn flow(&self, from: usize, to: usize) -> C {
+        self.edge(from, to)
+            .map_or_else(Zero::zero, |edge| edge.flow)
+    }
+
+    fn flows(&self) -> Vec<((usize, usize), C)> {
+        let mut flows = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(from, edges)| {
+                edges.iter().filter_map(move |&e| {
+                    let edge = &self.edges[e];
+                    (edge.flow > Zero::zero()).then_some(((from, edge.to), edge.flow))
+                })
+            })
+            .collect::<Vec<_>>();
+        flows.sort_unstable_by_key(|&(edge, _)| edge);
+        flows
+    }
+
+    fn add_flow(&mut self, from: usize, to: usize, capacity: C) {
+        let e = self.edge_index(from, to);
+        let direct = &mut self.edges[e];
+        direct.flow = direct.flow + capacity;
+        direct.residual = direct.residual - capacity;
+        let reverse = &mut self.edges[e ^ 1];
+        reverse.flow = reverse.flow - capacity;
+        reverse.residual = reverse.residual + capacity;
+    }
+
+    fn add_residual_capacity(&mut self, from: usize, to: usize, capacity: C) {
+        let e = self.edge_index(from, to);
+        self.edges[e].residual = self.edges[e].residual + capacity;
+    }
+
+    fn flows_from(&self, from: usize) -> Vec<usize> {
+        self.adjacency[from]
+            .iter()
+            .map(|&e| &self.edges[e])
+            .filter_map(|edge| (edge.flow > Zero::zero()).then_some(edge.to))
+            .collect()
+    }
+}
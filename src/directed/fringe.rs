@@ -1,7 +1,9 @@
 //! Compute a shortest path using the [Fringe search
 //! algorithm](https://en.wikipedia.org/wiki/Fringe_search).
 
-use super::reverse_path;
+use super::astar::AstarSolution;
+use super::{NO_PARENT, ParentIndex, from_parent, reverse_path, to_parent};
+use crate::{FxIndexMap, FxIndexSet, NodeRefs};
 use indexmap::map::Entry::{Occupied, Vacant};
 use indexmap::IndexMap;
 use num_traits::{Bounded, Zero};
@@ -17,7 +19,9 @@ use std::mem;
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `heuristic` returns an approximation of the cost from a given node to the goal. The
@@ -79,14 +83,15 @@ use std::mem;
 /// assert_eq!(result.expect("no path found").1, 4);
 /// ```
 #[expect(clippy::missing_panics_doc)]
-pub fn fringe<N, C, FN, IN, FH, FS>(
-    start: &N,
+pub fn fringe<'a, N, C, FN, IN, FH, FS, S>(
+    start: S,
     successors: FN,
     heuristic: FH,
     success: FS,
 ) -> Option<(Vec<N>, C)>
 where
-    N: Eq + Hash + Clone,
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
     C: Bounded + Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
@@ -103,7 +108,9 @@ where
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `heuristic` returns an approximation of the cost from a given node to the goal. The
@@ -115,15 +122,16 @@ where
 ///
 /// The returned path comprises both the start and end node.
 #[expect(clippy::missing_panics_doc)]
-pub fn fringe_with_hasher<N, C, FN, IN, FH, FS, H>(
-    start: &N,
+pub fn fringe_with_hasher<'a, N, C, FN, IN, FH, FS, S, H>(
+    start: S,
     mut successors: FN,
     mut heuristic: FH,
     mut success: FS,
     hasher: H
 ) -> Option<(Vec<N>, C)>
 where
-    N: Eq + Hash + Clone,
+    N: Eq + Hash + Clone + 'a,
+    S: Into<NodeRefs<'a, N>>,
     C: Bounded + Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
@@ -133,12 +141,142 @@ where
 {
     let mut now = VecDeque::new();
     let mut later = VecDeque::new();
-    let mut parents: IndexMap<N, (usize, C), H> = IndexMap::with_hasher(hasher);
+    let mut parents: IndexMap<N, (ParentIndex, C), H> = IndexMap::with_hasher(hasher);
+    let mut flimit = C::max_value();
+    for start in start.into() {
+        if let Vacant(e) = parents.entry(start.clone()) {
+            flimit = flimit.min(heuristic(e.key()));
+            now.push_back(e.index());
+            e.insert((NO_PARENT, Zero::zero()));
+        }
+    }
+
+    loop {
+        if now.is_empty() {
+            return None;
+        }
+        let mut fmin = C::max_value();
+        while let Some(i) = now.pop_front() {
+            let (g, successors) = {
+                let (node, &(_, g)) = parents.get_index(i).unwrap(); // Cannot fail
+                let f = g + heuristic(node);
+                if f > flimit {
+                    if f < fmin {
+                        fmin = f;
+                    }
+                    later.push_back(i);
+                    continue;
+                }
+                if success(node) {
+                    let path = reverse_path(&parents, |&(p, _)| from_parent(p), i);
+                    return Some((path, g));
+                }
+                (g, successors(node))
+            };
+            for (successor, cost) in successors {
+                let g_successor = g + cost;
+                let n; // index for successor
+                match parents.entry(successor) {
+                    Vacant(e) => {
+                        n = e.index();
+                        e.insert((to_parent(i), g_successor));
+                    }
+                    Occupied(mut e) => {
+                        if e.get().1 > g_successor {
+                            n = e.index();
+                            e.insert((to_parent(i), g_successor));
+                        } else {
+                            continue;
+                        }
+                    }
+                }
+                if !remove(&mut later, &n) {
+                    remove(&mut now, &n);
+                }
+                now.push_front(n);
+            }
+        }
+        mem::swap(&mut now, &mut later);
+        flimit = fmin;
+    }
+}
+
+/// Compute all shortest paths using the [Fringe search
+/// algorithm](https://en.wikipedia.org/wiki/Fringe_search).
+///
+/// Whereas `fringe` returns a single shortest path, chosen arbitrarily
+/// among the shortest ones, `fringe_bag` returns all shortest paths (in
+/// an unspecified order, which only depends on the graph), as
+/// [`astar_bag`](super::astar::astar_bag) does.
+///
+/// The shortest paths starting from `start` up to a node for which `success` returns `true` are
+/// computed and returned in an iterator along with the cost (which, by definition, is the same for
+/// each shortest path), wrapped in a `Some`. If no paths are found, `None` is returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. This cost must be non-negative.
+/// - `heuristic` returns an approximation of the cost from a given node to the goal. The
+///   approximation must not be greater than the real cost, or a wrong shortest path may be returned.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// Each path comprises both the start and an end node. Note that while every path shares the same
+/// start node, different paths may have different end nodes.
+///
+/// # Example
+///
+/// There are 10 shortest ways for a king to go from (0, 0) to (4, 2) on a chess board:
+///
+/// ```
+/// use pathfinding::prelude::fringe_bag;
+///
+/// static GOAL: (i32, i32) = (4, 2);
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)]
+///         .map(|(dx, dy)| ((x + dx, y + dy), 1))
+/// };
+/// let (solutions, cost) = fringe_bag(&(0, 0),
+///                                    successors,
+///                                    |&(x, y)| GOAL.0.abs_diff(x).max(GOAL.1.abs_diff(y)),
+///                                    |&p| p == GOAL)
+///     .expect("no path found");
+/// assert_eq!(cost, 4);
+/// assert_eq!(solutions.count(), 10);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn fringe_bag<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(AstarSolution<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Bounded + Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut now = VecDeque::new();
+    let mut later = VecDeque::new();
+    let mut min_cost = None;
+    let mut sinks = FxIndexSet::default();
+    // As in `astar_bag`, the parents of every node are kept in a linked list whose cells
+    // are stored in `links`, every node only recording the last cell of its list, or
+    // `usize::MAX`. Every cell holds a parent index and the index of the previous cell.
+    let mut links: Vec<(usize, usize)> = Vec::new();
+    let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
     let mut flimit = heuristic(start);
     now.push_back(0);
     parents.insert(start.clone(), (usize::MAX, Zero::zero()));
 
-    loop {
+    // Once a goal has been found, the current iteration is completed so that all the
+    // nodes whose estimated cost is not greater than the goal one get expanded.
+    while min_cost.is_none() {
         if now.is_empty() {
             return None;
         }
@@ -155,8 +293,8 @@ where
                     continue;
                 }
                 if success(node) {
-                    let path = reverse_path(&parents, |&(p, _)| p, i);
-                    return Some((path, g));
+                    min_cost = Some(g);
+                    sinks.insert(i);
                 }
                 (g, successors(node))
             };
@@ -166,13 +304,27 @@ where
                 match parents.entry(successor) {
                     Vacant(e) => {
                         n = e.index();
-                        e.insert((i, g_successor));
+                        links.push((i, usize::MAX));
+                        e.insert((links.len() - 1, g_successor));
                     }
                     Occupied(mut e) => {
                         if e.get().1 > g_successor {
                             n = e.index();
-                            e.insert((i, g_successor));
+                            // The cell of the former last parent is reused, the other
+                            // ones are abandoned.
+                            let s = e.get_mut();
+                            links[s.0] = (i, usize::MAX);
+                            s.1 = g_successor;
                         } else {
+                            if e.get().1 == g_successor {
+                                // New parent with an identical cost, the successor does not
+                                // need to be explored again.
+                                let s = e.get_mut();
+                                if links.get(s.0).is_none_or(|&(p, _)| p != i) {
+                                    links.push((i, s.0));
+                                    s.0 = links.len() - 1;
+                                }
+                            }
                             continue;
                         }
                     }
@@ -186,6 +338,8 @@ where
         mem::swap(&mut now, &mut later);
         flimit = fmin;
     }
+
+    min_cost.map(|cost| (AstarSolution::from_links(parents, &links, sinks), cost))
 }
 
 fn remove<T: Eq>(v: &mut VecDeque<T>, e: &T) -> bool {
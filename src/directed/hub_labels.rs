@@ -0,0 +1,389 @@
+//! Answer distance queries by intersecting precomputed [hub
+//! labels](https://en.wikipedia.org/wiki/Hub_labels).
+
+use crate::FxIndexSet;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Distance labels allowing to compute the distance between any two nodes by
+/// looking at their labels only.
+///
+/// Every node receives an outgoing label, made of hubs along with the distance from
+/// the node to each hub, and an incoming label, made of hubs along with the distance
+/// from each hub to the node. For any two nodes `s` and `t` connected by a path, there
+/// exists a hub on a shortest path from `s` to `t` present both in the outgoing label
+/// of `s` and in the incoming label of `t`. A query then only merges two sorted labels,
+/// without exploring the graph, which makes it suitable for workloads requiring a very
+/// large number of distance queries.
+///
+/// The labels are built using pruned searches run from every node in decreasing order
+/// of importance, a hub being only added to a label if the labels built so far do not
+/// already give the right distance. Labels are small when the most important nodes come
+/// first. [`HubLabels::new`] computes the order of importance by simulating the
+/// contraction of the nodes as in [contraction
+/// hierarchies](https://en.wikipedia.org/wiki/Contraction_hierarchies): the node whose
+/// removal requires the fewest shortcuts compared with the number of edges it removes
+/// is contracted first, and the nodes contracted last are the most important ones.
+/// [`HubLabels::with_order`] accepts an order computed by other means.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, HubLabels};
+///
+/// // A 10×10 grid where moving right or down costs 1, and left or up costs 3.
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [((x + 1, y), 1), ((x, y + 1), 1), ((x - 1, y), 3), ((x, y - 1), 3)]
+///         .into_iter()
+///         .filter(|&((x, y), _)| (0..10).contains(&x) && (0..10).contains(&y))
+/// };
+/// let nodes = (0..10).flat_map(|x| (0..10).map(move |y| (x, y))).collect::<Vec<_>>();
+/// let labels = HubLabels::new(&nodes, successors);
+///
+/// assert_eq!(labels.distance(&(2, 7), &(8, 1)), Some(24));
+/// assert_eq!(labels.distance(&(8, 1), &(2, 7)), Some(24));
+/// assert_eq!(labels.distance(&(4, 4), &(4, 4)), Some(0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HubLabels<N, C> {
+    nodes: FxIndexSet<N>,
+    out_labels: Vec<Vec<(usize, C)>>,
+    in_labels: Vec<Vec<(usize, C)>>,
+}
+
+impl<N, C> HubLabels<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    /// Build the labels of a graph, processing its nodes in the reverse order of their
+    /// contraction.
+    ///
+    /// - `nodes` contains the nodes of the graph.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor. Successors not present in `nodes` are
+    ///   ignored.
+    pub fn new<FN, IN>(nodes: &[N], successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let mut result = Self::empty(nodes);
+        let (out_edges, in_edges) = result.edges(successors);
+        let order = contraction_order(&out_edges, &in_edges);
+        result.nodes = order.iter().map(|&i| result.nodes[i].clone()).collect();
+        let rank = invert(&order);
+        let renumber = |edges: Vec<Vec<(usize, C)>>| {
+            let mut renumbered = vec![Vec::new(); edges.len()];
+            for (i, e) in edges.into_iter().enumerate() {
+                renumbered[rank[i]] = e.into_iter().map(|(j, c)| (rank[j], c)).collect();
+            }
+            renumbered
+        };
+        let (out_edges, in_edges) = (renumber(out_edges), renumber(in_edges));
+        result.build(&out_edges, &in_edges);
+        result
+    }
+
+    /// Build the labels of a graph, processing its nodes in the given order.
+    ///
+    /// - `nodes` contains the nodes of the graph, the most important ones first.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor. Successors not present in `nodes` are
+    ///   ignored.
+    pub fn with_order<FN, IN>(nodes: &[N], successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let mut result = Self::empty(nodes);
+        let (out_edges, in_edges) = result.edges(successors);
+        result.build(&out_edges, &in_edges);
+        result
+    }
+
+    fn empty(nodes: &[N]) -> Self {
+        Self {
+            nodes: nodes.iter().cloned().collect(),
+            out_labels: Vec::new(),
+            in_labels: Vec::new(),
+        }
+    }
+
+    /// Outgoing and incoming edges of every node, using node indices.
+    #[expect(clippy::type_complexity)]
+    fn edges<FN, IN>(&self, mut successors: FN) -> (Vec<Vec<(usize, C)>>, Vec<Vec<(usize, C)>>)
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let mut out_edges = vec![Vec::new(); self.nodes.len()];
+        let mut in_edges = vec![Vec::new(); self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            for (successor, cost) in successors(node) {
+                if let Some(j) = self.nodes.get_index_of(&successor) {
+                    out_edges[i].push((j, cost));
+                    in_edges[j].push((i, cost));
+                }
+            }
+        }
+        (out_edges, in_edges)
+    }
+
+    fn build(&mut self, out_edges: &[Vec<(usize, C)>], in_edges: &[Vec<(usize, C)>]) {
+        self.out_labels = vec![Vec::new(); self.nodes.len()];
+        self.in_labels = vec![Vec::new(); self.nodes.len()];
+        let mut distances = vec![None; self.nodes.len()];
+        for hub in 0..self.nodes.len() {
+            self.pruned_search(hub, out_edges, true, &mut distances);
+            self.pruned_search(hub, in_edges, false, &mut distances);
+        }
+    }
+
+    /// Run a search from `hub`, forwards to fill incoming labels or backwards to
+    /// fill outgoing labels, pruning the nodes whose distance is already given by
+    /// the current labels. `distances` is left filled with `None` on return.
+    fn pruned_search(
+        &mut self,
+        hub: usize,
+        edges: &[Vec<(usize, C)>],
+        forward: bool,
+        distances: &mut [Option<C>],
+    ) {
+        let mut touched = vec![hub];
+        let mut to_see = BinaryHeap::new();
+        distances[hub] = Some(Zero::zero());
+        to_see.push(Reverse((C::zero(), hub)));
+        while let Some(Reverse((cost, node))) = to_see.pop() {
+            if distances[node].is_some_and(|d| d < cost) {
+                continue;
+            }
+            let known = if forward {
+                merge(&self.out_labels[hub], &self.in_labels[node])
+            } else {
+                merge(&self.out_labels[node], &self.in_labels[hub])
+            };
+            if known.is_some_and(|k| k <= cost) {
+                continue;
+            }
+            if forward {
+                self.in_labels[node].push((hub, cost));
+            } else {
+                self.out_labels[node].push((hub, cost));
+            }
+            for &(next, move_cost) in &edges[node] {
+                let new_cost = cost + move_cost;
+                if distances[next].is_none_or(|d| new_cost < d) {
+                    if distances[next].is_none() {
+                        touched.push(next);
+                    }
+                    distances[next] = Some(new_cost);
+                    to_see.push(Reverse((new_cost, next)));
+                }
+            }
+        }
+        for node in touched {
+            distances[node] = None;
+        }
+    }
+
+    /// Distance from `from` to `to`, or `None` if `to` cannot be reached from `from`
+    /// or if one of the nodes was not part of the graph.
+    #[must_use]
+    pub fn distance(&self, from: &N, to: &N) -> Option<C> {
+        let from = self.nodes.get_index_of(from)?;
+        let to = self.nodes.get_index_of(to)?;
+        merge(&self.out_labels[from], &self.in_labels[to])
+    }
+
+    /// Total number of entries in all labels, which determines the memory used by the
+    /// labels as well as the average query time.
+    #[must_use]
+    pub fn labels_size(&self) -> usize {
+        self.out_labels
+            .iter()
+            .chain(&self.in_labels)
+            .map(Vec::len)
+            .sum()
+    }
+}
+
+/// Smallest distance going through a hub common to both labels, which are sorted by
+/// hub rank.
+fn merge<C: Zero + Ord + Copy>(out_label: &[(usize, C)], in_label: &[(usize, C)]) -> Option<C> {
+    let (mut i, mut j) = (0, 0);
+    let mut best: Option<C> = None;
+    while i < out_label.len() && j < in_label.len() {
+        let ((hi, ci), (hj, cj)) = (out_label[i], in_label[j]);
+        match hi.cmp(&hj) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                let d = ci + cj;
+                best = Some(best.map_or(d, |b| b.min(d)));
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    best
+}
+
+fn invert(order: &[usize]) -> Vec<usize> {
+    let mut rank = vec![0; order.len()];
+    for (r, &i) in order.iter().enumerate() {
+        rank[i] = r;
+    }
+    rank
+}
+
+/// Maximum number of nodes settled by a witness search during the contraction. Giving up
+/// earlier only adds unneeded shortcuts, which makes the order less accurate.
+const WITNESS_SETTLED: usize = 64;
+
+/// Graph of the nodes not contracted yet, along with the shortcuts replacing the
+/// contracted ones.
+struct Contraction<C> {
+    out_edges: Vec<FxHashMap<usize, C>>,
+    in_edges: Vec<FxHashMap<usize, C>>,
+    contracted_neighbours: Vec<usize>,
+}
+
+impl<C: Zero + Ord + Copy> Contraction<C> {
+    fn new(out_edges: &[Vec<(usize, C)>], in_edges: &[Vec<(usize, C)>]) -> Self {
+        let cheapest = |edges: &[Vec<(usize, C)>]| {
+            edges
+                .iter()
+                .enumerate()
+                .map(|(i, edges)| {
+                    let mut cheapest = FxHashMap::default();
+                    for &(j, cost) in edges.iter().filter(|&&(j, _)| j != i) {
+                        cheapest
+                            .entry(j)
+                            .and_modify(|c: &mut C| *c = (*c).min(cost))
+                            .or_insert(cost);
+                    }
+                    cheapest
+                })
+                .collect()
+        };
+        Self {
+            out_edges: cheapest(out_edges),
+            in_edges: cheapest(in_edges),
+            contracted_neighbours: vec![0; out_edges.len()],
+        }
+    }
+
+    /// Shortcuts needed to keep the distances between the other nodes when removing
+    /// `node`, as `(from, to, cost)` triples.
+    fn shortcuts(&self, node: usize) -> Vec<(usize, usize, C)> {
+        let mut shortcuts = Vec::new();
+        for (&from, &in_cost) in &self.in_edges[node] {
+            let targets = self.out_edges[node]
+                .iter()
+                .filter(|&(&to, _)| to != from)
+                .map(|(&to, &out_cost)| (to, in_cost + out_cost))
+                .collect::<Vec<_>>();
+            let Some(limit) = targets.iter().map(|&(_, cost)| cost).max() else {
+                continue;
+            };
+            let witnesses = self.witness_search(from, node, limit);
+            shortcuts.extend(
+                targets
+                    .into_iter()
+                    .filter(|&(to, cost)| witnesses.get(&to).is_none_or(|&c| c > cost))
+                    .map(|(to, cost)| (from, to, cost)),
+            );
+        }
+        shortcuts
+    }
+
+    /// Distances from `from` to the nodes reachable without going through `avoided`
+    /// for at most `limit`, possibly missing some of them.
+    fn witness_search(&self, from: usize, avoided: usize, limit: C) -> FxHashMap<usize, C> {
+        let mut distances = FxHashMap::default();
+        distances.insert(from, C::zero());
+        let mut to_see = BinaryHeap::from([Reverse((C::zero(), from))]);
+        let mut settled = 0;
+        while let Some(Reverse((cost, node))) = to_see.pop() {
+            if distances[&node] < cost {
+                continue;
+            }
+            settled += 1;
+            if settled > WITNESS_SETTLED {
+                break;
+            }
+            for (&next, &move_cost) in &self.out_edges[node] {
+                let new_cost = cost + move_cost;
+                if next != avoided
+                    && new_cost <= limit
+                    && distances.get(&next).is_none_or(|&c| new_cost < c)
+                {
+                    distances.insert(next, new_cost);
+                    to_see.push(Reverse((new_cost, next)));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Priority of `node` if its contraction requires `shortcuts` shortcuts: the edge
+    /// difference, increased by the number of its contracted neighbours so that the
+    /// contraction spreads uniformly over the graph.
+    #[expect(clippy::cast_possible_wrap)]
+    fn priority(&self, node: usize, shortcuts: usize) -> isize {
+        let degree = self.out_edges[node].len() + self.in_edges[node].len();
+        shortcuts as isize - degree as isize + self.contracted_neighbours[node] as isize
+    }
+
+    /// Remove `node` from the graph, adding the `shortcuts` replacing it.
+    fn contract(&mut self, node: usize, shortcuts: Vec<(usize, usize, C)>) {
+        for (from, to, cost) in shortcuts {
+            self.out_edges[from]
+                .entry(to)
+                .and_modify(|c| *c = (*c).min(cost))
+                .or_insert(cost);
+            self.in_edges[to]
+                .entry(from)
+                .and_modify(|c| *c = (*c).min(cost))
+                .or_insert(cost);
+        }
+        for from in std::mem::take(&mut self.in_edges[node]).into_keys() {
+            self.out_edges[from].remove(&node);
+            self.contracted_neighbours[from] += 1;
+        }
+        for to in std::mem::take(&mut self.out_edges[node]).into_keys() {
+            self.in_edges[to].remove(&node);
+            self.contracted_neighbours[to] += 1;
+        }
+    }
+}
+
+/// Order the nodes from the most to the least important one, by contracting them in
+/// increasing order of priority. Priorities are updated lazily: a node whose priority
+/// has increased since it was queued is queued again.
+fn contraction_order<C: Zero + Ord + Copy>(
+    out_edges: &[Vec<(usize, C)>],
+    in_edges: &[Vec<(usize, C)>],
+) -> Vec<usize> {
+    let mut graph = Contraction::new(out_edges, in_edges);
+    let mut to_see = (0..out_edges.len())
+        .map(|node| Reverse((graph.priority(node, graph.shortcuts(node).len()), node)))
+        .collect::<BinaryHeap<_>>();
+    let mut order = Vec::with_capacity(out_edges.len());
+    while let Some(Reverse((priority, node))) = to_see.pop() {
+        let shortcuts = graph.shortcuts(node);
+        let current = graph.priority(node, shortcuts.len());
+        if current > priority && to_see.peek().is_some_and(|&Reverse((p, _))| current > p) {
+            to_see.push(Reverse((current, node)));
+            continue;
+        }
+        graph.contract(node, shortcuts);
+        order.push(node);
+    }
+    order.reverse();
+    order
+}
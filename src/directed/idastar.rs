@@ -1,6 +1,8 @@
 //! Compute a shortest path using the [IDA* search
 //! algorithm](https://en.wikipedia.org/wiki/Iterative_deepening_A*).
 
+use super::astar::AstarSolution;
+use crate::{FxIndexSet, NodeRefs};
 use indexmap::IndexSet;
 use num_traits::Zero;
 use rustc_hash::FxHasher;
@@ -13,7 +15,9 @@ use std::{hash::{BuildHasher, BuildHasherDefault, Hash}, ops::ControlFlow};
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `heuristic` returns an approximation of the cost from a given node to the goal. The
@@ -72,14 +76,15 @@ use std::{hash::{BuildHasher, BuildHasherDefault, Hash}, ops::ControlFlow};
 ///                    |&p| p == GOAL);
 /// assert_eq!(result.expect("no path found").1, 4);
 /// ```
-pub fn idastar<N, C, FN, IN, FH, FS>(
-    start: &N,
+pub fn idastar<'a, N, C, FN, IN, FH, FS, S>(
+    start: S,
     successors: FN,
     heuristic: FH,
     success: FS,
 ) -> Option<(Vec<N>, C)>
 where
-    N: Eq + Clone + Hash,
+    N: Eq + Clone + Hash + 'a,
+    S: Into<NodeRefs<'a, N>>,
     C: Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
@@ -96,7 +101,9 @@ where
 /// computed and returned along with its total cost, in a `Some`. If no path can be found, `None`
 /// is returned instead.
 ///
-/// - `start` is the starting node.
+/// - `start` is the starting node, or a [`NodeRefs`] set of starting nodes. With several
+///   starting nodes, the path starts from the one leading to the cheapest path, as if they
+///   were all linked to a virtual start through zero-cost edges.
 /// - `successors` returns a list of successors for a given node, along with the cost for moving
 ///   from the node to the successor. This cost must be non-negative.
 /// - `heuristic` returns an approximation of the cost from a given node to the goal. The
@@ -107,15 +114,16 @@ where
 /// A node will never be included twice in the path as determined by the `Eq` relationship.
 ///
 /// The returned path comprises both the start and end node.
-pub fn idastar_with_hasher<N, C, FN, IN, FH, FS, H>(
-    start: &N,
+pub fn idastar_with_hasher<'a, N, C, FN, IN, FH, FS, S, H>(
+    start: S,
     mut successors: FN,
     mut heuristic: FH,
     mut success: FS,
     hasher: H
 ) -> Option<(Vec<N>, C)>
 where
-    N: Eq + Clone + Hash,
+    N: Eq + Clone + Hash + 'a,
+    S: Into<NodeRefs<'a, N>>,
     C: Zero + Ord + Copy,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = (N, C)>,
@@ -123,23 +131,35 @@ where
     FS: FnMut(&N) -> bool,
     H: BuildHasher,
 {
+    let starts = start.into();
     let mut path = IndexSet::<N, H>::with_hasher(hasher);
-    path.insert(start.clone());
 
     std::iter::repeat(())
-        .try_fold(heuristic(start), |bound, ()| {
-            search(
-                &mut path,
-                Zero::zero(),
-                bound,
-                &mut successors,
-                &mut heuristic,
-                &mut success,
-            )
-            .map_break(Some)?
-            // .filter(|min| *min > bound)
-            .map_or(ControlFlow::Break(None), ControlFlow::Continue)
-        })
+        .try_fold(
+            starts.iter().map(|start| heuristic(start)).min()?,
+            |bound, ()| {
+                // Every iteration explores the paths from every starting node, and the next
+                // bound is the smallest one exceeding the current bound from any of them.
+                let mut min = None;
+                for start in starts.iter() {
+                    path.clear();
+                    path.insert((*start).clone());
+                    let m = search(
+                        &mut path,
+                        Zero::zero(),
+                        bound,
+                        &mut successors,
+                        &mut heuristic,
+                        &mut success,
+                    )
+                    .map_break(Some)?;
+                    if let Some(m) = m {
+                        min = Some(min.map_or(m, |n: C| n.min(m)));
+                    }
+                }
+                min.map_or(ControlFlow::Break(None), ControlFlow::Continue)
+            },
+        )
         .break_value()
         .unwrap_or_default() // To avoid a missing panics section, as this always break
 }
@@ -179,7 +199,13 @@ where
                 })
             })
             .collect::<Vec<_>>();
-        neighbs.sort_unstable_by(|(_, _, c1), (_, _, c2)| c1.cmp(c2));
+        // A stable sort explores successors with the same cost in the order they were
+        // given when the `deterministic` feature is enabled.
+        if cfg!(feature = "deterministic") {
+            neighbs.sort_by_key(|&(_, _, c)| c);
+        } else {
+            neighbs.sort_unstable_by(|(_, _, c1), (_, _, c2)| c1.cmp(c2));
+        }
         neighbs
     };
     let mut min = None;
@@ -193,3 +219,151 @@ where
     }
     ControlFlow::Continue(min)
 }
+
+/// Compute all shortest paths using the [IDA* search
+/// algorithm](https://en.wikipedia.org/wiki/Iterative_deepening_A*).
+///
+/// Whereas `idastar` returns a single shortest path, chosen arbitrarily
+/// among the shortest ones, `idastar_bag` returns all shortest paths (in
+/// an unspecified order, which only depends on the graph), found by exploring
+/// the whole search tree bounded by the final threshold. Unlike
+/// [`astar_bag`](super::astar::astar_bag), the memory used during the search
+/// only grows with the length of the paths, but the shortest paths are
+/// collected before being returned.
+///
+/// The shortest paths starting from `start` up to a node for which `success` returns `true` are
+/// computed and returned in an iterator along with the cost (which, by definition, is the same for
+/// each shortest path), wrapped in a `Some`. If no paths are found, `None` is returned.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for moving
+///   from the node to the successor. This cost must be non-negative.
+/// - `heuristic` returns an approximation of the cost from a given node to the goal. The
+///   approximation must not be greater than the real cost, or a wrong shortest path may be returned.
+/// - `success` checks whether the goal has been reached. It is not a node as some problems require
+///   a dynamic solution instead of a fixed node.
+///
+/// A node will never be included twice in the path as determined by the `Eq` relationship.
+///
+/// Each path comprises both the start and an end node. Note that while every path shares the same
+/// start node, different paths may have different end nodes.
+///
+/// ### Warning
+///
+/// The number of results with the same value might be very large in some graphs. Use with caution.
+///
+/// # Example
+///
+/// There are 10 shortest ways for a king to go from (0, 0) to (4, 2) on a chess board:
+///
+/// ```
+/// use pathfinding::prelude::idastar_bag;
+///
+/// static GOAL: (i32, i32) = (4, 2);
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)]
+///         .map(|(dx, dy)| ((x + dx, y + dy), 1))
+/// };
+/// let (solutions, cost) = idastar_bag(&(0, 0),
+///                                     successors,
+///                                     |&(x, y)| GOAL.0.abs_diff(x).max(GOAL.1.abs_diff(y)),
+///                                     |&p| p == GOAL)
+///     .expect("no path found");
+/// assert_eq!(cost, 4);
+/// assert_eq!(solutions.count(), 10);
+/// ```
+pub fn idastar_bag<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut success: FS,
+) -> Option<(AstarSolution<N>, C)>
+where
+    N: Eq + Clone + Hash,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut path = FxIndexSet::default();
+    path.insert(start.clone());
+    let mut bound = heuristic(start);
+    let mut solutions = None;
+    loop {
+        let min = search_all(
+            &mut path,
+            Zero::zero(),
+            bound,
+            &mut successors,
+            &mut heuristic,
+            &mut success,
+            &mut solutions,
+        );
+        if let Some((paths, cost)) = solutions {
+            return Some((AstarSolution::from_paths(paths), cost));
+        }
+        bound = min?;
+    }
+}
+
+/// Explore all the paths whose estimated cost does not exceed `bound`, and record in
+/// `solutions` the cheapest ones leading to a goal along with their cost. Return the
+/// smallest estimated cost exceeding `bound`, if any.
+fn search_all<N, C, FN, IN, FH, FS, H>(
+    path: &mut IndexSet<N, H>,
+    cost: C,
+    bound: C,
+    successors: &mut FN,
+    heuristic: &mut FH,
+    success: &mut FS,
+    solutions: &mut Option<(Vec<Vec<N>>, C)>,
+) -> Option<C>
+where
+    N: Eq + Clone + Hash,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher,
+{
+    let neighbs = {
+        let start = &path[path.len() - 1];
+        let f = cost + heuristic(start);
+        if f > bound {
+            return Some(f);
+        }
+        if success(start) {
+            match solutions {
+                Some((paths, c)) if *c == cost => paths.push(path.iter().cloned().collect()),
+                Some((_, c)) if *c < cost => (),
+                _ => *solutions = Some((vec![path.iter().cloned().collect()], cost)),
+            }
+        }
+        // The exploration goes on past the goal, as other goals may be reached at no
+        // additional cost.
+        successors(start)
+            .into_iter()
+            .filter(|(n, _)| !path.contains(n))
+            .collect::<Vec<_>>()
+    };
+    let mut min = None;
+    for (node, extra) in neighbs {
+        let (idx, _) = path.insert_full(node);
+        match search_all(
+            path,
+            cost + extra,
+            bound,
+            successors,
+            heuristic,
+            success,
+            solutions,
+        ) {
+            Some(m) if min.is_none_or(|n| n >= m) => min = Some(m),
+            _ => (),
+        }
+        path.swap_remove_index(idx);
+    }
+    min
+}
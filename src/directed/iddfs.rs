@@ -57,24 +57,85 @@
 ///                  |&p| p == GOAL);
 /// assert_eq!(result.expect("no path found").len(), 5);
 /// ```
-pub fn iddfs<N, FN, IN, FS>(start: N, mut successors: FN, mut success: FS) -> Option<Vec<N>>
+pub fn iddfs<N, FN, IN, FS>(start: N, successors: FN, success: FS) -> Option<Vec<N>>
 where
     N: Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
     FS: FnMut(&N) -> bool,
 {
-    let mut path = vec![start];
+    match iddfs_bounded(start, successors, success, usize::MAX) {
+        IddfsOutcome::Found { path, .. } => Some(path),
+        IddfsOutcome::NoSolution | IddfsOutcome::CutOff => None,
+    }
+}
 
-    let mut current_max_depth: usize = 1;
+/// The outcome of [`iddfs_bounded`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IddfsOutcome<N> {
+    /// A shortest path has been found, made of `depth` moves.
+    Found {
+        /// The path, comprising both the start and end node.
+        path: Vec<N>,
+        /// The number of moves in the path, one less than its number of nodes.
+        depth: usize,
+    },
+    /// There is no path at all, the whole graph reachable from the start node having
+    /// been explored before reaching the maximum depth.
+    NoSolution,
+    /// There is no path within the maximum depth, but longer paths have been cut off
+    /// and may lead to the goal.
+    CutOff,
+}
 
-    loop {
+/// Compute a shortest path using the [iterative deepening depth-first search
+/// algorithm](https://en.wikipedia.org/wiki/Iterative_deepening_depth-first_search),
+/// considering only paths made of at most `max_depth` moves.
+///
+/// This function behaves like [`iddfs`], but stops deepening the search once
+/// `max_depth` is reached. The result tells apart problems without solution from
+/// problems whose solutions, if any, are too deep, and gives the depth at which the
+/// solution has been found.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{IddfsOutcome, iddfs_bounded};
+///
+/// let successors = |&n: &u32| [n + 1, n * 2].into_iter().filter(|&m| m <= 20);
+/// assert_eq!(
+///     iddfs_bounded(1, successors, |&n| n == 10, 4),
+///     IddfsOutcome::Found { path: vec![1, 2, 4, 5, 10], depth: 4 },
+/// );
+/// assert_eq!(iddfs_bounded(1, successors, |&n| n == 10, 3), IddfsOutcome::CutOff);
+/// assert_eq!(iddfs_bounded(1, successors, |&n| n == 30, 30), IddfsOutcome::NoSolution);
+/// ```
+pub fn iddfs_bounded<N, FN, IN, FS>(
+    start: N,
+    mut successors: FN,
+    mut success: FS,
+    max_depth: usize,
+) -> IddfsOutcome<N>
+where
+    N: Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut path = vec![start];
+
+    // The depth given to `step` is a number of nodes, one more than the number of moves.
+    for current_max_depth in 1..=max_depth.saturating_add(1) {
         match step(&mut path, &mut successors, &mut success, current_max_depth) {
-            Path::FoundOptimum => return Some(path),
-            Path::NoneAtThisDepth => current_max_depth += 1,
-            Path::Impossible => return None,
+            Path::FoundOptimum => {
+                let depth = path.len() - 1;
+                return IddfsOutcome::Found { path, depth };
+            }
+            Path::NoneAtThisDepth => (),
+            Path::Impossible => return IddfsOutcome::NoSolution,
         }
     }
+    IddfsOutcome::CutOff
 }
 
 #[derive(Debug)]
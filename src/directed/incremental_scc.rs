@@ -0,0 +1,246 @@
+//! Maintain the [strongly connected
+//! components](https://en.wikipedia.org/wiki/Strongly_connected_component)
+//! of a directed graph while edges are being added.
+
+use crate::FxIndexSet;
+use rustc_hash::FxHashSet;
+use std::hash::Hash;
+
+/// Strongly connected components of a directed graph growing by edge insertions.
+///
+/// Components are stored as the nodes of a condensation graph, which is acyclic.
+/// When an edge is inserted between two components, the condensation graph is searched
+/// for a path going back from the target component to the source component. If there
+/// is none, the edge is simply added to the condensation graph. Otherwise, the new edge
+/// closes a cycle, and all the components lying on a path from the target to the source
+/// are merged into a single one.
+///
+/// Only the components reachable from the target of the new edge are explored,
+/// which is much cheaper than computing the components of the whole graph again
+/// after every insertion when the graph is large.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::IncrementalScc;
+///
+/// let mut scc = IncrementalScc::new();
+/// assert!(!scc.add_edge("app", "lib"));
+/// assert!(!scc.add_edge("lib", "utils"));
+/// assert!(!scc.same_component(&"app", &"utils"));
+/// assert_eq!(scc.components_len(), 3);
+///
+/// // Making utils depend on app creates a cycle including all three nodes.
+/// assert!(scc.add_edge("utils", "app"));
+/// assert!(scc.same_component(&"app", &"utils"));
+/// assert_eq!(scc.components_len(), 1);
+/// let mut component = scc.component(&"lib").unwrap();
+/// component.sort_unstable();
+/// assert_eq!(component, vec!["app", "lib", "utils"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IncrementalScc<N> {
+    nodes: FxIndexSet<N>,
+    // Union-find structure whose roots represent the components.
+    parent: Vec<usize>,
+    // The following fields are only meaningful for component representatives.
+    members: Vec<Vec<usize>>,
+    successors: Vec<FxHashSet<usize>>,
+    predecessors: Vec<FxHashSet<usize>>,
+    components: usize,
+}
+
+impl<N> Default for IncrementalScc<N> {
+    fn default() -> Self {
+        Self {
+            nodes: FxIndexSet::default(),
+            parent: Vec::new(),
+            members: Vec::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+            components: 0,
+        }
+    }
+}
+
+impl<N> IncrementalScc<N>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create an empty graph.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node to the graph, in its own component. Return `false` if the node
+    /// was already present.
+    pub fn add_node(&mut self, node: N) -> bool {
+        self.index(node).1
+    }
+
+    fn index(&mut self, node: N) -> (usize, bool) {
+        let (index, inserted) = self.nodes.insert_full(node);
+        if inserted {
+            self.parent.push(index);
+            self.members.push(vec![index]);
+            self.successors.push(FxHashSet::default());
+            self.predecessors.push(FxHashSet::default());
+            self.components += 1;
+        }
+        (index, inserted)
+    }
+
+    fn find(&self, mut index: usize) -> usize {
+        while self.parent[index] != index {
+            index = self.parent[index];
+        }
+        index
+    }
+
+    fn find_compress(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        let mut i = index;
+        while self.parent[i] != root {
+            let next = self.parent[i];
+            self.parent[i] = root;
+            i = next;
+        }
+        root
+    }
+
+    /// Add an edge from `from` to `to`, adding the nodes to the graph if needed.
+    ///
+    /// Return `true` if the edge closes a cycle which merges several components
+    /// into one, and `false` otherwise.
+    pub fn add_edge(&mut self, from: N, to: N) -> bool {
+        let from = self.index(from).0;
+        let to = self.index(to).0;
+        let (from, to) = (self.find_compress(from), self.find_compress(to));
+        if from == to || self.successors[from].contains(&to) {
+            return false;
+        }
+        // Components reachable from `to`. If `from` is one of them, the new edge
+        // closes a cycle.
+        let reachable = self.reach(to, true, |_| true);
+        if !reachable.contains(&from) {
+            self.successors[from].insert(to);
+            self.predecessors[to].insert(from);
+            return false;
+        }
+        // Components on a path from `to` to `from` are those reachable from `to`
+        // which can also reach `from`.
+        let cycle = self.reach(from, false, |c| reachable.contains(&c));
+        self.merge(&cycle);
+        true
+    }
+
+    /// Components reachable from `start` (including itself), following either the
+    /// successors or the predecessors, and only going through allowed components.
+    fn reach<F>(&self, start: usize, forward: bool, mut allowed: F) -> FxHashSet<usize>
+    where
+        F: FnMut(usize) -> bool,
+    {
+        let edges = if forward {
+            &self.successors
+        } else {
+            &self.predecessors
+        };
+        let mut seen = FxHashSet::default();
+        seen.insert(start);
+        let mut to_see = vec![start];
+        while let Some(c) = to_see.pop() {
+            for &next in &edges[c] {
+                if allowed(next) && seen.insert(next) {
+                    to_see.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    fn merge(&mut self, cycle: &FxHashSet<usize>) {
+        let root = cycle
+            .iter()
+            .copied()
+            .max_by_key(|&c| self.members[c].len())
+            .unwrap(); // Cannot fail, the cycle contains at least two components
+        for &c in cycle {
+            if c == root {
+                continue;
+            }
+            self.parent[c] = root;
+            let members = std::mem::take(&mut self.members[c]);
+            self.members[root].extend(members);
+            for s in std::mem::take(&mut self.successors[c]) {
+                self.predecessors[s].remove(&c);
+                if !cycle.contains(&s) {
+                    self.successors[root].insert(s);
+                    self.predecessors[s].insert(root);
+                }
+            }
+            for p in std::mem::take(&mut self.predecessors[c]) {
+                self.successors[p].remove(&c);
+                if !cycle.contains(&p) {
+                    self.predecessors[root].insert(p);
+                    self.successors[p].insert(root);
+                }
+            }
+        }
+        self.successors[root].retain(|s| !cycle.contains(s));
+        self.predecessors[root].retain(|p| !cycle.contains(p));
+        self.components -= cycle.len() - 1;
+    }
+
+    /// Check whether both nodes belong to the same strongly connected component.
+    /// Nodes absent from the graph do not belong to any component.
+    #[must_use]
+    pub fn same_component(&self, a: &N, b: &N) -> bool {
+        match (self.nodes.get_index_of(a), self.nodes.get_index_of(b)) {
+            (Some(a), Some(b)) => self.find(a) == self.find(b),
+            _ => false,
+        }
+    }
+
+    /// Nodes belonging to the same strongly connected component as `node`, including
+    /// `node` itself, or `None` if `node` is absent from the graph.
+    #[must_use]
+    pub fn component(&self, node: &N) -> Option<Vec<N>> {
+        let root = self.find(self.nodes.get_index_of(node)?);
+        Some(self.members_of(root))
+    }
+
+    fn members_of(&self, root: usize) -> Vec<N> {
+        self.members[root]
+            .iter()
+            .map(|&i| self.nodes[i].clone())
+            .collect()
+    }
+
+    /// All the strongly connected components of the graph.
+    #[must_use]
+    pub fn components(&self) -> Vec<Vec<N>> {
+        (0..self.nodes.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|root| self.members_of(root))
+            .collect()
+    }
+
+    /// Number of strongly connected components.
+    #[must_use]
+    pub const fn components_len(&self) -> usize {
+        self.components
+    }
+
+    /// Number of nodes in the graph.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check whether the graph contains no node.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
@@ -0,0 +1,262 @@
+//! Precompute distances to a few landmarks in order to build an admissible
+//! heuristic for [`astar`](super::astar::astar), also known as the ALT
+//! (A*, Landmarks, Triangle inequality) technique.
+
+use super::dijkstra::dijkstra_all;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+/// Distances from and to a set of landmark nodes, usable to estimate the
+/// distance between any two nodes of a graph.
+///
+/// For every landmark `L`, the triangle inequality gives two lower bounds of
+/// the distance from `v` to `t`: `d(L, t) - d(L, v)` and `d(v, L) - d(t, L)`.
+/// The estimate is the largest of those bounds over all landmarks, which is
+/// both admissible and consistent. It can thus be used as the heuristic of
+/// [`astar`](super::astar::astar) on graphs where no geometric heuristic exists,
+/// such as road networks with travel times.
+///
+/// The quality of the estimate depends on the landmarks: nodes located at the
+/// periphery of the graph usually give the best results. [`Landmarks::new`] picks
+/// them automatically by repeatedly choosing the node farthest from the landmarks
+/// already selected.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, dijkstra, Landmarks};
+///
+/// // A ring of 100 nodes where going clockwise costs 1 and counter-clockwise costs 2.
+/// let successors = |&n: &u32| vec![((n + 1) % 100, 1), ((n + 99) % 100, 2)];
+/// let predecessors = |&n: &u32| vec![((n + 99) % 100, 1), ((n + 1) % 100, 2)];
+/// let nodes = (0..100).collect::<Vec<_>>();
+/// let landmarks = Landmarks::new(&nodes, 4, successors, predecessors);
+///
+/// let goal = 60;
+/// let result = astar(&10, successors, landmarks.heuristic(&goal), |&n| n == goal);
+/// assert_eq!(result, dijkstra(&10, successors, |&n| n == goal));
+/// assert!(landmarks.estimate(&10, &goal) <= 50);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Landmarks<N, C> {
+    nodes: Vec<N>,
+    from: Vec<FxHashMap<N, C>>,
+    to: Vec<FxHashMap<N, C>>,
+}
+
+impl<N, C> Landmarks<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+{
+    /// Select up to `count` landmarks among `nodes` and precompute their distances.
+    ///
+    /// - `nodes` contains the nodes of the graph. Unreachable nodes are favored as
+    ///   landmarks, so it should not contain nodes irrelevant to future queries.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor.
+    /// - `predecessors` returns a list of predecessors for a given node, along with the cost
+    ///   for moving from the predecessor to the node. For an undirected graph, this is the
+    ///   same as `successors`.
+    ///
+    /// The first landmark is the node farthest from the first node of `nodes`, and
+    /// every following landmark is the node farthest from all the landmarks selected
+    /// so far. Fewer than `count` landmarks are selected if `nodes` is too small.
+    #[must_use]
+    pub fn new<FN, IN, FP, IP>(
+        nodes: &[N],
+        count: usize,
+        mut successors: FN,
+        mut predecessors: FP,
+    ) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FP: FnMut(&N) -> IP,
+        IP: IntoIterator<Item = (N, C)>,
+    {
+        let mut result = Self {
+            nodes: Vec::new(),
+            from: Vec::new(),
+            to: Vec::new(),
+        };
+        for (index, from) in select_farthest(nodes, count, &mut successors) {
+            result.nodes.push(nodes[index].clone());
+            result
+                .to
+                .push(distances_from(&nodes[index], &mut predecessors));
+            result.from.push(from);
+        }
+        result
+    }
+
+    /// Precompute the distances for the given landmarks.
+    ///
+    /// - `landmarks` contains the nodes to use as landmarks.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor.
+    /// - `predecessors` returns a list of predecessors for a given node, along with the cost
+    ///   for moving from the predecessor to the node.
+    #[must_use]
+    pub fn with_landmarks<FN, IN, FP, IP>(
+        landmarks: &[N],
+        mut successors: FN,
+        mut predecessors: FP,
+    ) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FP: FnMut(&N) -> IP,
+        IP: IntoIterator<Item = (N, C)>,
+    {
+        let mut result = Self {
+            nodes: Vec::with_capacity(landmarks.len()),
+            from: Vec::with_capacity(landmarks.len()),
+            to: Vec::with_capacity(landmarks.len()),
+        };
+        for landmark in landmarks {
+            result.add(landmark, &mut successors, &mut predecessors);
+        }
+        result
+    }
+
+    fn add<FN, IN, FP, IP>(&mut self, landmark: &N, successors: &mut FN, predecessors: &mut FP)
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FP: FnMut(&N) -> IP,
+        IP: IntoIterator<Item = (N, C)>,
+    {
+        self.nodes.push(landmark.clone());
+        self.from.push(distances_from(landmark, successors));
+        self.to.push(distances_from(landmark, predecessors));
+    }
+
+    /// The selected landmarks.
+    #[must_use]
+    pub fn landmarks(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Lower bound of the distance from `from` to `to`.
+    #[must_use]
+    pub fn estimate(&self, from: &N, to: &N) -> C {
+        let goal = self.goal_distances(to);
+        self.estimate_with(from, &goal)
+    }
+
+    /// Build a heuristic estimating the distance from any node to `goal`, suitable
+    /// for use with [`astar`](super::astar::astar) and its variants.
+    ///
+    /// The distances between `goal` and the landmarks are looked up once, so that
+    /// every call to the heuristic only requires one lookup per landmark.
+    pub fn heuristic(&self, goal: &N) -> impl Fn(&N) -> C + '_ {
+        let goal = self.goal_distances(goal);
+        move |node| self.estimate_with(node, &goal)
+    }
+
+    fn goal_distances(&self, goal: &N) -> Vec<(Option<C>, Option<C>)> {
+        self.from
+            .iter()
+            .zip(&self.to)
+            .map(|(from, to)| (from.get(goal).copied(), to.get(goal).copied()))
+            .collect()
+    }
+
+    fn estimate_with(&self, node: &N, goal: &[(Option<C>, Option<C>)]) -> C {
+        let mut best: C = Zero::zero();
+        for ((from, to), &(from_goal, to_goal)) in self.from.iter().zip(&self.to).zip(goal) {
+            // d(L, goal) - d(L, node)
+            if let (Some(lg), Some(&ln)) = (from_goal, from.get(node)) {
+                if lg > ln {
+                    best = best.max(lg - ln);
+                }
+            }
+            // d(node, L) - d(goal, L)
+            if let (Some(gl), Some(&nl)) = (to_goal, to.get(node)) {
+                if nl > gl {
+                    best = best.max(nl - gl);
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Distances from `start` to every reachable node, including `start` itself.
+pub(super) fn distances_from<N, C, FN, IN>(start: &N, successors: &mut FN) -> FxHashMap<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut distances = dijkstra_all(start, successors)
+        .into_iter()
+        .map(|(node, (_, cost))| (node, cost))
+        .collect::<FxHashMap<_, _>>();
+    distances.insert(start.clone(), Zero::zero());
+    distances
+}
+
+/// Select up to `count` nodes among `nodes`, the first one being the farthest from
+/// the first node of `nodes` and every following one being the farthest from those
+/// already selected. The index of every selected node is returned along with the
+/// distances from this node.
+pub(super) fn select_farthest<N, C, FN, IN>(
+    nodes: &[N],
+    count: usize,
+    successors: &mut FN,
+) -> Vec<(usize, FxHashMap<N, C>)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut selected: Vec<(usize, FxHashMap<N, C>)> = Vec::new();
+    let Some(first) = nodes.first() else {
+        return selected;
+    };
+    // Smallest distance from a selected node to every node, `None` meaning that
+    // the node has not been reached from any selected node yet. The distances from
+    // the first node are used until the first node has been selected.
+    let seed = distances_from(first, successors);
+    let mut closest = nodes
+        .iter()
+        .map(|n| seed.get(n).copied())
+        .collect::<Vec<_>>();
+    while selected.len() < count {
+        let Some(index) = farthest(&closest, |i| selected.iter().all(|&(j, _)| i != j)) else {
+            break;
+        };
+        let from = distances_from(&nodes[index], successors);
+        for (node, best) in nodes.iter().zip(&mut closest) {
+            let d = from.get(node).copied();
+            *best = if selected.is_empty() {
+                d
+            } else {
+                match (*best, d) {
+                    (Some(b), Some(d)) => Some(b.min(d)),
+                    (b, d) => b.or(d),
+                }
+            };
+        }
+        selected.push((index, from));
+    }
+    selected
+}
+
+/// Index of the allowed node with the largest distance, unreached nodes (with a
+/// `None` distance) being considered as the farthest.
+fn farthest<C, F>(distances: &[Option<C>], mut allowed: F) -> Option<usize>
+where
+    C: Ord + Copy,
+    F: FnMut(usize) -> bool,
+{
+    (0..distances.len())
+        .filter(|&i| allowed(i))
+        .max_by_key(|&i| (distances[i].is_none(), distances[i]))
+}
@@ -0,0 +1,118 @@
+//! Find a cycle whose mean edge cost is minimal using
+//! [Karp's algorithm](https://doi.org/10.1016/0012-365X(78)90011-0).
+
+use super::shortest_cycle::index_graph;
+use crate::utils::times;
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::ops::Sub;
+
+/// Compare the means `a.0 / a.1` and `b.0 / b.1`, whose denominators are positive.
+fn compare_means<C: Zero + Ord + Copy>(a: (C, usize), b: (C, usize)) -> Ordering {
+    times(a.0, b.1).cmp(&times(b.0, a.1))
+}
+
+/// Find a cycle of a directed graph whose mean cost, that is its total cost divided by
+/// its number of edges, is minimal.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `successors` are
+///   also taken into account.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. Costs may be negative.
+///
+/// The cycle is returned along with its total cost and its number of edges, whose ratio
+/// is the minimum mean cost. The cycle starts with its node which comes first in the
+/// exploration order, and does not repeat this node at the end. `None` is returned if
+/// the graph is acyclic.
+///
+/// This is used for example to find the throughput of synchronous systems, or to find
+/// cycles to cancel in a minimum cost flow. Karp's algorithm runs in `O(n × e)` time
+/// and uses `O(n²)` memory, where `n` is the number of nodes and `e` the number of edges.
+/// Means are compared exactly by multiplying costs by numbers of edges, which must not
+/// overflow `C`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::min_mean_cycle;
+///
+/// // Two cycles go through 0: 0 → 1 → 0 with a mean cost of 4, and 0 → 1 → 2 → 0
+/// // with a mean cost of 3.
+/// let successors = |&n: &u8| match n {
+///     0 => vec![(1, 2)],
+///     1 => vec![(0, 6), (2, 3)],
+///     2 => vec![(0, 4)],
+///     _ => vec![],
+/// };
+/// assert_eq!(min_mean_cycle(&[0], successors), Some((vec![0, 1, 2], 9, 3)));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn min_mean_cycle<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(Vec<N>, C, usize)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (indices, adjacency) = index_graph(nodes, successors);
+    let n = adjacency.len();
+    // costs[k][v] is the minimum cost of a walk made of k edges ending at v, starting
+    // anywhere, and parents[k][v] is the node preceding v on this walk.
+    let mut costs = vec![vec![Some(C::zero()); n]];
+    let mut parents = vec![vec![usize::MAX; n]];
+    for k in 1..=n {
+        let mut level = vec![None; n];
+        let mut level_parents = vec![usize::MAX; n];
+        for (u, next) in adjacency.iter().enumerate() {
+            let Some(cost) = costs[k - 1][u] else {
+                continue;
+            };
+            for &(v, edge) in next {
+                let new_cost = cost + edge;
+                if level[v].is_none_or(|c| new_cost < c) {
+                    level[v] = Some(new_cost);
+                    level_parents[v] = u;
+                }
+            }
+        }
+        costs.push(level);
+        parents.push(level_parents);
+    }
+    // The minimum mean is the minimum over v of the maximum over k of
+    // (costs[n][v] - costs[k][v]) / (n - k).
+    let (_, last) = (0..n)
+        .filter_map(|v| {
+            let total = costs[n][v]?;
+            let worst = (0..n)
+                .filter_map(|k| Some((total - costs[k][v]?, n - k)))
+                .max_by(|&a, &b| compare_means(a, b))?;
+            Some((worst, v))
+        })
+        .min_by(|&(a, _), &(b, _)| compare_means(a, b))?;
+    // Every cycle of the walk ending at this node has the minimum mean.
+    let mut walk = vec![last];
+    for k in (1..=n).rev() {
+        walk.push(parents[k][*walk.last().unwrap()]);
+    }
+    walk.reverse();
+    let mut seen = vec![usize::MAX; n];
+    let (first, end) = walk
+        .iter()
+        .enumerate()
+        .find_map(|(j, &v)| {
+            let i = seen[v];
+            seen[v] = j;
+            (i != usize::MAX).then_some((i, j))
+        })
+        .unwrap(); // Cannot fail: the walk has n + 1 nodes
+    let cost = costs[end][walk[end]].unwrap() - costs[first][walk[first]].unwrap();
+    let mut cycle = walk[first..end].to_vec();
+    let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+    cycle.rotate_left(smallest);
+    Some((
+        cycle.into_iter().map(|i| indices[i].clone()).collect(),
+        cost,
+        end - first,
+    ))
+}
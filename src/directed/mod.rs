@@ -1,28 +1,120 @@
 //! Algorithms for directed graphs.
 
 use indexmap::IndexMap;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
 
+use std::cell::Cell;
+use std::collections::BinaryHeap;
+use std::collections::hash_map::Entry;
 use std::hash::{BuildHasher, Hash};
+use std::ops::Sub;
 
+use crate::path::Path;
+
+pub mod anytime_dstar;
+pub mod arc_flags;
 pub mod astar;
+pub mod batched;
 pub mod bfs;
+pub mod bottleneck;
+pub mod cbs;
+pub mod center;
+pub mod chinese_postman;
+pub mod circuits;
 pub mod count_paths;
 pub mod cycle_detection;
+pub mod dense;
 pub mod dfs;
+pub mod differential_heuristic;
 pub mod dijkstra;
+pub mod distance_table;
+pub mod dynamic_topological_sort;
 pub mod edmonds_karp;
 pub mod fringe;
+pub mod hub_labels;
 pub mod idastar;
 pub mod iddfs;
+pub mod incremental_scc;
+pub mod landmarks;
+pub mod min_mean_cycle;
+pub mod pareto;
+pub mod path_cache;
+pub mod path_cover;
+pub mod pattern_database;
+pub mod real_time;
+pub mod recorder;
+pub mod repair;
+pub mod reverse;
+pub mod search_context;
+pub mod search_error;
+pub mod semiring;
+pub mod shortest_cycle;
+pub mod shortest_path_tree;
+pub mod simple_paths;
 pub mod strongly_connected_components;
+pub mod tie_breaking;
+pub mod time_expanded;
 pub mod topological_sort;
+pub mod turn_costs;
 pub mod yen;
 
+/// The index of a parent node in the bookkeeping maps of the searches, stored as a
+/// `u32` when the `u32-index` feature is enabled.
+#[cfg(not(feature = "u32-index"))]
+pub(crate) type ParentIndex = usize;
+/// The index of a parent node in the bookkeeping maps of the searches, stored as a
+/// `u32` when the `u32-index` feature is enabled.
+#[cfg(feature = "u32-index")]
+pub(crate) type ParentIndex = u32;
+
+/// The parent of the starting nodes.
+pub(crate) const NO_PARENT: ParentIndex = ParentIndex::MAX;
+
+/// Convert a node index into a [`ParentIndex`].
+#[cfg(not(feature = "u32-index"))]
+#[inline]
+pub(crate) const fn to_parent(index: usize) -> ParentIndex {
+    index
+}
+
+/// Convert a node index into a [`ParentIndex`].
+///
+/// # Panics
+///
+/// This function panics if there are too many nodes for the index to fit.
+#[cfg(feature = "u32-index")]
+#[inline]
+pub(crate) fn to_parent(index: usize) -> ParentIndex {
+    ParentIndex::try_from(index)
+        .ok()
+        .filter(|&p| p != NO_PARENT)
+        .expect("too many nodes for the u32-index feature")
+}
+
+/// Convert a [`ParentIndex`] into a node index, [`NO_PARENT`] becoming `usize::MAX`.
+#[cfg(not(feature = "u32-index"))]
+#[inline]
+pub(crate) const fn from_parent(parent: ParentIndex) -> usize {
+    parent
+}
+
+/// Convert a [`ParentIndex`] into a node index, [`NO_PARENT`] becoming `usize::MAX`.
+#[cfg(feature = "u32-index")]
+#[inline]
+pub(crate) const fn from_parent(parent: ParentIndex) -> usize {
+    if parent == NO_PARENT {
+        usize::MAX
+    } else {
+        parent as usize
+    }
+}
+
 fn reverse_path<N, V, F, S>(parents: &IndexMap<N, V, S>, mut parent: F, start: usize) -> Vec<N>
 where
     N: Eq + Hash + Clone,
-    F: FnMut(&V) -> usize,    
-    S: BuildHasher
+    F: FnMut(&V) -> usize,
+    S: BuildHasher,
 {
     let mut i = start;
     let path = std::iter::from_fn(|| {
@@ -36,3 +128,263 @@ where
     // unfold iterator is not double-ended due to its iterative nature.
     path.into_iter().rev().cloned().collect()
 }
+
+fn reverse_path_with_costs<N, C, S>(
+    parents: &IndexMap<N, (ParentIndex, C), S>,
+    start: usize,
+) -> Path<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Copy + Sub<Output = C>,
+    S: BuildHasher,
+{
+    let mut i = start;
+    let steps = std::iter::from_fn(|| {
+        parents.get_index(i).map(|(node, &(parent, cost))| {
+            i = from_parent(parent);
+            (node, cost)
+        })
+    })
+    .collect::<Vec<_>>();
+    Path::from_cumulative(
+        steps
+            .into_iter()
+            .rev()
+            .map(|(node, cost)| (node.clone(), cost)),
+    )
+}
+
+/// A priority queue holding the nodes to expand during a search.
+pub(crate) trait Frontier<T> {
+    fn push(&mut self, item: T);
+    fn pop(&mut self) -> Option<T>;
+    fn clear(&mut self);
+}
+
+impl<T: Ord> Frontier<T> for BinaryHeap<T> {
+    fn push(&mut self, item: T) {
+        Self::push(self, item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Self::pop(self)
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
+
+/// How a run of the core loop of a search ended.
+pub(crate) enum Outcome {
+    /// A node for which the success function returned `true` has been reached, at this
+    /// index.
+    Reached(usize),
+    /// Every reachable node has been expanded.
+    Exhausted,
+    /// The search has been suspended before expanding a node, which has been put back
+    /// into the frontier.
+    Suspended,
+    /// Adding costs overflowed while expanding the node at this index.
+    Overflow(usize),
+}
+
+/// Serialization of a [`ParentIndex`] as a `u64`, [`NO_PARENT`] being stored as
+/// `u64::MAX`, so that serialized searches do not depend on the `u32-index` feature.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_parent {
+    use super::{NO_PARENT, ParentIndex, from_parent};
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) const fn widen(parent: ParentIndex) -> u64 {
+        if parent == NO_PARENT {
+            u64::MAX
+        } else {
+            from_parent(parent) as u64
+        }
+    }
+
+    pub(crate) fn narrow(parent: u64) -> Option<ParentIndex> {
+        if parent == u64::MAX {
+            Some(NO_PARENT)
+        } else {
+            ParentIndex::try_from(parent)
+                .ok()
+                .filter(|&p| p != NO_PARENT)
+        }
+    }
+
+    // The signature is imposed by `serde(with)`.
+    #[cfg_attr(feature = "u32-index", expect(clippy::trivially_copy_pass_by_ref))]
+    pub(crate) fn serialize<S: Serializer>(
+        parent: &ParentIndex,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        widen(*parent).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ParentIndex, D::Error> {
+        narrow(u64::deserialize(deserializer)?)
+            .ok_or_else(|| D::Error::custom("parent index out of range"))
+    }
+}
+
+/// Serialization of the bookkeeping maps of the searches as sequences of pairs, with
+/// parent indices stored as in [`serde_parent`].
+#[cfg(feature = "serde")]
+pub(crate) mod serde_parents {
+    use super::ParentIndex;
+    use super::serde_parent::{narrow, widen};
+    use crate::FxIndexMap;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::hash::Hash;
+
+    /// A value of a bookkeeping map, holding the index of the parent of a node.
+    pub(crate) trait WithParent: Sized {
+        type Wide;
+        fn widen(&self) -> Self::Wide;
+        fn narrow(wide: Self::Wide) -> Option<Self>;
+    }
+
+    impl WithParent for ParentIndex {
+        type Wide = u64;
+        fn widen(&self) -> u64 {
+            widen(*self)
+        }
+        fn narrow(wide: u64) -> Option<Self> {
+            narrow(wide)
+        }
+    }
+
+    impl<C: Copy> WithParent for (ParentIndex, C) {
+        type Wide = (u64, C);
+        fn widen(&self) -> (u64, C) {
+            (widen(self.0), self.1)
+        }
+        fn narrow((parent, cost): (u64, C)) -> Option<Self> {
+            narrow(parent).map(|parent| (parent, cost))
+        }
+    }
+
+    pub(crate) fn serialize<N, V, S>(
+        parents: &FxIndexMap<N, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        N: Serialize,
+        V: WithParent<Wide: Serialize>,
+        S: Serializer,
+    {
+        serializer.collect_seq(parents.iter().map(|(node, value)| (node, value.widen())))
+    }
+
+    pub(crate) fn deserialize<'de, N, V, D>(deserializer: D) -> Result<FxIndexMap<N, V>, D::Error>
+    where
+        N: Deserialize<'de> + Eq + Hash,
+        V: WithParent<Wide: Deserialize<'de>>,
+        D: Deserializer<'de>,
+    {
+        Vec::<(N, V::Wide)>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(node, wide)| V::narrow(wide).map(|value| (node, value)))
+            .collect::<Option<_>>()
+            .ok_or_else(|| D::Error::custom("parent index out of range"))
+    }
+}
+
+/// Successors stored in a buffer which is handed back to its pool once
+/// the iterator has been dropped, so that it can be reused for the next
+/// expansion without reallocating.
+struct RecycledSuccessors<'a, T> {
+    items: Vec<T>,
+    pool: &'a Cell<Vec<T>>,
+}
+
+impl<T> Iterator for RecycledSuccessors<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+}
+
+impl<T> Drop for RecycledSuccessors<'_, T> {
+    fn drop(&mut self) {
+        self.items.clear();
+        self.pool.set(std::mem::take(&mut self.items));
+    }
+}
+
+/// Turn an extend-style successors function into one returning an iterator,
+/// reusing the buffer stored in `pool` for every expansion.
+fn recycled_successors<'a, N, T, F>(
+    pool: &'a Cell<Vec<T>>,
+    mut successors: F,
+) -> impl FnMut(&N) -> RecycledSuccessors<'a, T>
+where
+    F: FnMut(&N, &mut Vec<T>),
+{
+    move |node| {
+        let mut items = pool.take();
+        successors(node, &mut items);
+        // Successors are popped from the end of the buffer.
+        items.reverse();
+        RecycledSuccessors { items, pool }
+    }
+}
+
+/// The cheapest edge seen from every node to each of its successors, used to tell
+/// which edge a path went through when several edges link the same nodes.
+struct ChosenEdges<N, E, C>(FxHashMap<(N, N), (E, C)>);
+
+impl<N, E, C> ChosenEdges<N, E, C>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy,
+{
+    fn new() -> Self {
+        Self(FxHashMap::default())
+    }
+
+    /// Remember the cheapest edge to every successor of `node`, and return the
+    /// successors along with the cost of the edges leading to them.
+    fn record<IN>(&mut self, node: &N, successors: IN) -> Vec<(N, C)>
+    where
+        IN: IntoIterator<Item = (N, E, C)>,
+    {
+        successors
+            .into_iter()
+            .map(|(successor, edge, cost)| {
+                match self.0.entry((node.clone(), successor.clone())) {
+                    Entry::Occupied(mut e) if cost < e.get().1 => {
+                        e.insert((edge, cost));
+                    }
+                    Entry::Occupied(_) => (),
+                    Entry::Vacant(e) => {
+                        e.insert((edge, cost));
+                    }
+                }
+                (successor, cost)
+            })
+            .collect()
+    }
+
+    /// Turn a path into the list of steps taken from its first node, every step
+    /// being made of the node reached, the edge followed and its cost.
+    fn into_steps(mut self, path: Vec<N>) -> Vec<(N, E, C)> {
+        let mut path = path.into_iter();
+        let Some(mut from) = path.next() else {
+            return Vec::new();
+        };
+        path.map(|to| {
+            let key = (std::mem::replace(&mut from, to.clone()), to);
+            let (edge, cost) = self.0.remove(&key).unwrap(); // Cannot fail
+            (key.1, edge, cost)
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,145 @@
+//! Compute the [Pareto front](https://en.wikipedia.org/wiki/Pareto_front) of the
+//! paths between two nodes when edges have two independent costs.
+
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Compute the paths from `start` to a node for which `success` returns `true` which
+/// are not dominated by any other path, using a bi-criteria label-setting search.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the pair of
+///   costs for moving from the node to the successor. Both costs must be non-negative.
+/// - `success` checks whether the goal has been reached.
+///
+/// A path dominates another one if none of its costs is larger and at least one of them
+/// is smaller. The returned iterator yields the non-dominated paths along with their
+/// costs by increasing first cost, hence by decreasing second cost. When several paths
+/// have exactly the same costs, only one of them is returned.
+///
+/// Paths are computed lazily: every path is returned as soon as it is known to be
+/// part of the Pareto front, and the search stops when the iterator is dropped.
+///
+/// Unlike a search on a combination of the two costs, this returns every trade-off
+/// between the costs, including those which no weighted sum would select.
+///
+/// # Example
+///
+/// Going from 0 to 3 costs some time and some toll.
+///
+/// ```
+/// use pathfinding::prelude::pareto_paths;
+///
+/// let successors = |&n: &u8| match n {
+///     0 => vec![(1, (10, 0)), (2, (5, 4))],
+///     1 => vec![(3, (10, 0))],
+///     2 => vec![(3, (5, 4)), (1, (1, 0))],
+///     _ => vec![],
+/// };
+/// let front = pareto_paths(&0, successors, |&n| n == 3).collect::<Vec<_>>();
+/// assert_eq!(
+///     front,
+///     vec![
+///         (vec![0, 2, 3], (10, 8)),
+///         (vec![0, 2, 1, 3], (16, 4)),
+///         (vec![0, 1, 3], (20, 0)),
+///     ]
+/// );
+/// ```
+pub fn pareto_paths<N, C1, C2, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    success: FS,
+) -> ParetoPaths<N, C1, C2, FN, FS>
+where
+    N: Eq + Hash + Clone,
+    C1: Zero + Ord + Copy,
+    C2: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, (C1, C2))>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut to_see = BinaryHeap::new();
+    to_see.push(Reverse((Zero::zero(), Zero::zero(), 0)));
+    ParetoPaths {
+        labels: vec![(start.clone(), usize::MAX)],
+        to_see,
+        best: FxHashMap::default(),
+        best_goal: None,
+        successors,
+        success,
+    }
+}
+
+/// Iterator over the non-dominated paths, returned by [`pareto_paths`].
+pub struct ParetoPaths<N, C1, C2, FN, FS> {
+    // Every label is made of a node and the index of its parent label.
+    labels: Vec<(N, usize)>,
+    to_see: BinaryHeap<Reverse<(C1, C2, usize)>>,
+    // Smallest second cost among the labels already settled for every node. Since
+    // labels are settled by lexicographic order of their costs, a new label is
+    // dominated if and only if its second cost is not smaller.
+    best: FxHashMap<N, C2>,
+    best_goal: Option<C2>,
+    successors: FN,
+    success: FS,
+}
+
+impl<N, C1, C2, FN, FS> ParetoPaths<N, C1, C2, FN, FS>
+where
+    N: Eq + Hash + Clone,
+    C2: Ord + Copy,
+{
+    fn is_dominated(&self, node: &N, cost: C2) -> bool {
+        self.best_goal.is_some_and(|c| c <= cost) || self.best.get(node).is_some_and(|&c| c <= cost)
+    }
+
+    fn path(&self, index: usize) -> Vec<N> {
+        let mut path = Vec::new();
+        let mut i = index;
+        while i != usize::MAX {
+            let (node, parent) = &self.labels[i];
+            path.push(node.clone());
+            i = *parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl<N, C1, C2, FN, IN, FS> Iterator for ParetoPaths<N, C1, C2, FN, FS>
+where
+    N: Eq + Hash + Clone,
+    C1: Zero + Ord + Copy,
+    C2: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, (C1, C2))>,
+    FS: FnMut(&N) -> bool,
+{
+    type Item = (Vec<N>, (C1, C2));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Reverse((c1, c2, index))) = self.to_see.pop() {
+            let node = self.labels[index].0.clone();
+            if self.is_dominated(&node, c2) {
+                continue;
+            }
+            self.best.insert(node.clone(), c2);
+            if (self.success)(&node) {
+                self.best_goal = Some(c2);
+                return Some((self.path(index), (c1, c2)));
+            }
+            for (next, (e1, e2)) in (self.successors)(&node) {
+                let (n1, n2) = (c1 + e1, c2 + e2);
+                if !self.is_dominated(&next, n2) {
+                    self.to_see.push(Reverse((n1, n2, self.labels.len())));
+                    self.labels.push((next, index));
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,196 @@
+//! Cache shortest paths between pairs of nodes, and invalidate them when
+//! the graph changes.
+
+use super::dijkstra::dijkstra;
+use num_traits::Zero;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::hash::Hash;
+
+type PathWithCost<N, C> = (Vec<N>, C);
+
+/// A cache of shortest paths keyed by their start and goal nodes.
+///
+/// Paths are computed on demand by [`PathCache::get_or_insert_with`] or
+/// [`PathCache::dijkstra`] and kept until they are invalidated. Every cached
+/// path is indexed by the nodes it goes through, so that when the graph changes
+/// only the affected paths have to be dropped:
+///
+/// - [`PathCache::invalidate_edge`] must be called when an edge is removed or
+///   becomes more expensive. It drops the paths going through this edge.
+/// - [`PathCache::invalidate_node`] must be called when a node is removed or when
+///   several of its edges change. It drops the paths going through this node.
+///
+/// Adding an edge or making it cheaper may create shorter paths between any pair of
+/// nodes, as well as paths where none existed before. [`PathCache::clear`] must be
+/// called in this case.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::PathCache;
+///
+/// let mut blocked = Vec::new();
+/// let mut cache = PathCache::new();
+/// let successors = |blocked: &[(u32, u32)]| {
+///     let blocked = blocked.to_vec();
+///     move |&n: &u32| {
+///         [(n + 1, 1), (n + 2, 3)]
+///             .into_iter()
+///             .filter(|&(m, _)| !blocked.contains(&(n, m)))
+///             .collect::<Vec<_>>()
+///     }
+/// };
+///
+/// assert_eq!(cache.dijkstra(&0, &3, successors(&blocked)), Some((vec![0, 1, 2, 3], 3)));
+/// assert_eq!(cache.dijkstra(&5, &7, successors(&blocked)), Some((vec![5, 6, 7], 2)));
+///
+/// // Removing the edge from 2 to 3 only affects the first path.
+/// blocked.push((2, 3));
+/// assert_eq!(cache.invalidate_edge(&2, &3), 1);
+/// assert_eq!(cache.len(), 1);
+/// assert_eq!(cache.dijkstra(&0, &3, successors(&blocked)), Some((vec![0, 1, 3], 4)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PathCache<N, C> {
+    // A `None` result records that no path exists.
+    paths: FxHashMap<(N, N), Option<PathWithCost<N, C>>>,
+    by_node: FxHashMap<N, FxHashSet<(N, N)>>,
+}
+
+impl<N, C> Default for PathCache<N, C> {
+    fn default() -> Self {
+        Self {
+            paths: FxHashMap::default(),
+            by_node: FxHashMap::default(),
+        }
+    }
+}
+
+impl<N, C> PathCache<N, C>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached result for the path from `start` to `goal`. The outer `Option` is `None`
+    /// if nothing has been cached, and the inner one is `None` if it has been cached
+    /// that no path exists.
+    #[must_use]
+    pub fn get(&self, start: &N, goal: &N) -> Option<Option<&(Vec<N>, C)>> {
+        self.paths
+            .get(&(start.clone(), goal.clone()))
+            .map(Option::as_ref)
+    }
+
+    /// Store the result of a search from `start` to `goal`, replacing the previous one
+    /// if any. `None` records that no path exists.
+    pub fn insert(&mut self, start: N, goal: N, result: Option<(Vec<N>, C)>) {
+        let key = (start, goal);
+        self.remove(&key);
+        if let Some((path, _)) = &result {
+            for node in path {
+                self.by_node
+                    .entry(node.clone())
+                    .or_default()
+                    .insert(key.clone());
+            }
+        }
+        self.paths.insert(key, result);
+    }
+
+    /// Return the cached result for the path from `start` to `goal`, computing it with
+    /// `compute` and storing it first if needed.
+    pub fn get_or_insert_with<F>(&mut self, start: &N, goal: &N, compute: F) -> Option<&(Vec<N>, C)>
+    where
+        F: FnOnce(&N, &N) -> Option<(Vec<N>, C)>,
+    {
+        let key = (start.clone(), goal.clone());
+        if !self.paths.contains_key(&key) {
+            let result = compute(start, goal);
+            self.insert(start.clone(), goal.clone(), result);
+        }
+        self.paths[&key].as_ref()
+    }
+
+    /// Return a shortest path from `start` to `goal` along with its cost, computing
+    /// it with [`dijkstra`] if it is not cached already.
+    pub fn dijkstra<FN, IN>(&mut self, start: &N, goal: &N, successors: FN) -> Option<(Vec<N>, C)>
+    where
+        C: Zero + Ord + Copy,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        self.get_or_insert_with(start, goal, |start, goal| {
+            dijkstra(start, successors, |n| n == goal)
+        })
+        .cloned()
+    }
+
+    fn remove(&mut self, key: &(N, N)) {
+        if let Some(Some((path, _))) = self.paths.remove(key) {
+            for node in &path {
+                if let Some(keys) = self.by_node.get_mut(node) {
+                    keys.remove(key);
+                    if keys.is_empty() {
+                        self.by_node.remove(node);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Drop the cached paths going through the edge from `from` to `to`, and return
+    /// their number.
+    pub fn invalidate_edge(&mut self, from: &N, to: &N) -> usize {
+        let Some(keys) = self.by_node.get(from) else {
+            return 0;
+        };
+        let affected = keys
+            .iter()
+            .filter(|key| {
+                self.paths[*key]
+                    .as_ref()
+                    .is_some_and(|(path, _)| path.windows(2).any(|w| w[0] == *from && w[1] == *to))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in &affected {
+            self.remove(key);
+        }
+        affected.len()
+    }
+
+    /// Drop the cached paths going through `node`, including those starting or ending
+    /// there, and return their number.
+    pub fn invalidate_node(&mut self, node: &N) -> usize {
+        let Some(keys) = self.by_node.remove(node) else {
+            return 0;
+        };
+        for key in &keys {
+            self.remove(key);
+        }
+        keys.len()
+    }
+
+    /// Drop every cached result, including those recording that no path exists.
+    pub fn clear(&mut self) {
+        self.paths.clear();
+        self.by_node.clear();
+    }
+
+    /// Number of cached results.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Check whether the cache is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
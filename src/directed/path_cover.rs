@@ -0,0 +1,256 @@
+//! Cover the nodes of a directed acyclic graph with a minimum number of paths, and find
+//! its largest sets of unrelated nodes, using a [maximum bipartite
+//! matching](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm).
+
+use crate::FxIndexSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Compute a [minimum path cover](https://en.wikipedia.org/wiki/Path_cover) of a
+/// directed acyclic graph, that is a minimum set of vertex-disjoint paths such that
+/// every node belongs to exactly one path.
+///
+/// - `nodes` is the list of nodes of the graph.
+/// - `successors` returns a list of successors for a given node. Successors which
+///   do not belong to `nodes` are ignored.
+///
+/// Every edge of the graph can only be used to go from a node to the next one in a
+/// path: paths are not allowed to skip over nodes already used by another path. A node
+/// without any edge forms a path of its own.
+///
+/// The paths are returned in the order of their first node in `nodes`. If the graph
+/// contains a cycle which prevents building the paths, one of the nodes of this cycle
+/// is returned in an `Err`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::minimum_path_cover;
+///
+/// // 1 -> 2 -> 3
+/// //  \-> 4 -> 5 -> 3
+/// let successors = |&n: &u32| match n {
+///     1 => vec![2, 4],
+///     2 => vec![3],
+///     4 => vec![5],
+///     5 => vec![3],
+///     _ => vec![],
+/// };
+/// let paths = minimum_path_cover(&[1, 2, 3, 4, 5], successors).unwrap();
+/// assert_eq!(paths.len(), 2);
+/// assert_eq!(paths.concat().len(), 5);
+/// ```
+///
+/// # Errors
+///
+/// A node belonging to a cycle of the graph is returned if the paths cannot be built.
+pub fn minimum_path_cover<N, FN, IN>(nodes: &[N], mut successors: FN) -> Result<Vec<Vec<N>>, N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let indices = nodes.iter().collect::<FxIndexSet<_>>();
+    let adjacency = indices
+        .iter()
+        .map(|n| {
+            successors(n)
+                .into_iter()
+                .filter_map(|s| indices.get_index_of(&s))
+                .collect()
+        })
+        .collect::<Vec<_>>();
+    let (next, previous) = maximum_matching(&adjacency, indices.len());
+    let mut paths = Vec::new();
+    let mut covered = vec![false; indices.len()];
+    for start in (0..indices.len()).filter(|&i| previous[i] == UNMATCHED) {
+        let mut path = vec![indices[start].clone()];
+        covered[start] = true;
+        let mut i = start;
+        while next[i] != UNMATCHED {
+            i = next[i];
+            path.push(indices[i].clone());
+            covered[i] = true;
+        }
+        paths.push(path);
+    }
+    // Nodes which cannot be reached from the first node of a path are matched along
+    // a cycle.
+    if let Some(i) = covered.iter().position(|&c| !c) {
+        return Err(indices[i].clone());
+    }
+    Ok(paths)
+}
+
+/// Compute a maximum [antichain](https://en.wikipedia.org/wiki/Antichain) of a directed
+/// acyclic graph, that is a largest set of nodes such that no node of the set can be
+/// reached from another one.
+///
+/// - `nodes` is the list of nodes of the graph.
+/// - `successors` returns a list of successors for a given node. Successors which
+///   do not belong to `nodes` are ignored.
+///
+/// By [Dilworth's theorem](https://en.wikipedia.org/wiki/Dilworth%27s_theorem), the size
+/// of a maximum antichain is the minimum number of paths needed to cover the nodes when
+/// paths are allowed to share nodes. The antichain is obtained from a maximum matching in
+/// the transitive closure of the graph, which is built in `O(n·(n+e))` time and `O(n²)`
+/// space for `n` nodes and `e` edges.
+///
+/// The nodes of the antichain are returned in the order of `nodes`.
+///
+/// # Example
+///
+/// In the divisibility order of the integers from 1 to 10, the integers from 6 to 10
+/// cannot divide each other, and the largest sets of integers which cannot divide
+/// each other have 5 elements:
+///
+/// ```
+/// use pathfinding::prelude::maximum_antichain;
+///
+/// let nodes = (1..=10).collect::<Vec<u32>>();
+/// let successors = |&n: &u32| (2..).map(move |k| k * n).take_while(|&m| m <= 10);
+/// let antichain = maximum_antichain(&nodes, successors).unwrap();
+/// assert_eq!(antichain.len(), 5);
+/// for a in &antichain {
+///     assert!(antichain.iter().all(|b| a == b || b % a != 0));
+/// }
+/// ```
+///
+/// # Errors
+///
+/// If the graph contains a cycle, one of its nodes is returned.
+pub fn maximum_antichain<N, FN, IN>(nodes: &[N], mut successors: FN) -> Result<Vec<N>, N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let indices = nodes.iter().collect::<FxIndexSet<_>>();
+    let adjacency = indices
+        .iter()
+        .map(|n| {
+            successors(n)
+                .into_iter()
+                .filter_map(|s| indices.get_index_of(&s))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // Connect every node to all the nodes it can reach.
+    let mut closure = Vec::with_capacity(adjacency.len());
+    let mut seen = vec![usize::MAX; adjacency.len()];
+    for start in 0..adjacency.len() {
+        let mut reached = Vec::new();
+        let mut to_see = adjacency[start].clone();
+        while let Some(n) = to_see.pop() {
+            if n == start {
+                return Err(indices[start].clone());
+            }
+            if seen[n] != start {
+                seen[n] = start;
+                reached.push(n);
+                to_see.extend_from_slice(&adjacency[n]);
+            }
+        }
+        closure.push(reached);
+    }
+    let (match_left, match_right) = maximum_matching(&closure, closure.len());
+    // By Kőnig's theorem, a minimum vertex cover is made of the left vertices which
+    // cannot be reached through an alternating path from an unmatched left vertex,
+    // and of the right vertices which can. The nodes whose left and right vertices are
+    // both outside this cover form a maximum antichain.
+    let mut left_reached = vec![false; closure.len()];
+    let mut right_reached = vec![false; closure.len()];
+    let mut to_see = (0..closure.len())
+        .filter(|&u| match_left[u] == UNMATCHED)
+        .collect::<Vec<_>>();
+    for &u in &to_see {
+        left_reached[u] = true;
+    }
+    while let Some(u) = to_see.pop() {
+        for &v in &closure[u] {
+            if !right_reached[v] {
+                right_reached[v] = true;
+                let w = match_right[v];
+                if w != UNMATCHED && !left_reached[w] {
+                    left_reached[w] = true;
+                    to_see.push(w);
+                }
+            }
+        }
+    }
+    Ok((0..closure.len())
+        .filter(|&n| left_reached[n] && !right_reached[n])
+        .map(|n| indices[n].clone())
+        .collect())
+}
+
+/// Marker of a vertex which is not matched.
+const UNMATCHED: usize = usize::MAX;
+
+/// Compute a maximum matching in a bipartite graph using the [Hopcroft-Karp
+/// algorithm](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm). Left vertex
+/// `u` is connected to the right vertices `adjacency[u]`, which are all smaller than
+/// `right_count`. Return the right vertex matched with every left vertex, and the left
+/// vertex matched with every right vertex, or `UNMATCHED`.
+fn maximum_matching(adjacency: &[Vec<usize>], right_count: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut match_left = vec![UNMATCHED; adjacency.len()];
+    let mut match_right = vec![UNMATCHED; right_count];
+    let mut layers = vec![0; adjacency.len()];
+    let mut to_see = VecDeque::new();
+    loop {
+        // Build the layers of the alternating paths starting from unmatched left vertices.
+        for (u, layer) in layers.iter_mut().enumerate() {
+            if match_left[u] == UNMATCHED {
+                *layer = 0;
+                to_see.push_back(u);
+            } else {
+                *layer = usize::MAX;
+            }
+        }
+        let mut augmentable = false;
+        while let Some(u) = to_see.pop_front() {
+            for &v in &adjacency[u] {
+                match match_right[v] {
+                    UNMATCHED => augmentable = true,
+                    w if layers[w] == usize::MAX => {
+                        layers[w] = layers[u] + 1;
+                        to_see.push_back(w);
+                    }
+                    _ => (),
+                }
+            }
+        }
+        if !augmentable {
+            return (match_left, match_right);
+        }
+        // Look for augmenting paths following the layers, every left vertex remembering
+        // the position of the edge being tried.
+        let mut cursors = vec![0; adjacency.len()];
+        for root in 0..adjacency.len() {
+            if match_left[root] != UNMATCHED {
+                continue;
+            }
+            let mut stack = vec![root];
+            while let Some(&u) = stack.last() {
+                let Some(&v) = adjacency[u].get(cursors[u]) else {
+                    // Dead end, which must not be tried again during this phase.
+                    layers[u] = usize::MAX;
+                    stack.pop();
+                    continue;
+                };
+                match match_right[v] {
+                    UNMATCHED => {
+                        for &u in &stack {
+                            let v = adjacency[u][cursors[u]];
+                            match_left[u] = v;
+                            match_right[v] = u;
+                        }
+                        break;
+                    }
+                    w if layers[w] == layers[u] + 1 => stack.push(w),
+                    _ => cursors[u] += 1,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Precompute the distances to the goal in an abstraction of a search space, also
+//! known as a pattern database, in order to build admissible heuristics for
+//! [`idastar`](super::idastar::idastar) or [`astar`](super::astar::astar) in domains
+//! such as sliding puzzles or Rubik's cube.
+
+/// Marker of the patterns from which the goal cannot be reached.
+const UNREACHED: u8 = u8::MAX;
+
+/// Largest distance stored in the table. Larger distances are stored as this value,
+/// which keeps the heuristic admissible.
+const MAX_DISTANCE: u8 = u8::MAX - 1;
+
+/// Distances from every pattern of an abstracted search space to the abstracted goal.
+///
+/// A pattern is an abstraction of a state which only keeps a part of it, for example
+/// the positions of some of the tiles of a sliding puzzle. The distance from a pattern
+/// to the goal pattern is a lower bound of the distance from any state having this
+/// pattern to the goal, and can be used as an admissible heuristic.
+///
+/// Patterns are identified by a rank between 0 and the size of the database, computed
+/// by a user-provided function. [`arrangement_rank`] can be used when the pattern
+/// is made of the distinct positions of some pieces. Distances are stored in a compact
+/// table using one byte per pattern, with distances larger than 254 being stored as
+/// 254. The table can be saved with [`as_bytes`](Self::as_bytes) and loaded with
+/// [`from_bytes`](Self::from_bytes), or serialized when the `serde` feature is enabled,
+/// so that it is only computed once.
+///
+/// When several databases use disjoint sets of pieces and every move only moves pieces
+/// of a single database, as with the tiles of a sliding puzzle, their distances can be
+/// added and the sum is still admissible (additive pattern databases). Otherwise, the
+/// maximum of their distances must be used.
+///
+/// # Example
+///
+/// Sort five numbers by swapping adjacent ones, using the positions of 0 and 1 as
+/// the pattern.
+///
+/// ```
+/// use pathfinding::prelude::{arrangement_count, arrangement_rank, idastar, PatternDatabase};
+///
+/// // Abstract moves: a pattern element is swapped with a neighbour, which may be
+/// // the other pattern element. Moves can be reversed, so these are also the
+/// // predecessors.
+/// let moves = |pattern: &[usize; 2]| {
+///     let mut moves = Vec::new();
+///     for i in 0..2 {
+///         for to in [pattern[i].wrapping_sub(1), pattern[i] + 1] {
+///             if to < 5 {
+///                 let mut next = *pattern;
+///                 next[i] = to;
+///                 if pattern[1 - i] == to {
+///                     next[1 - i] = pattern[i];
+///                 }
+///                 moves.push((next, 1));
+///             }
+///         }
+///     }
+///     moves
+/// };
+/// let database = PatternDatabase::new(
+///     &[[0, 1]],
+///     arrangement_count(5, 2),
+///     |p| arrangement_rank(p, 5),
+///     moves,
+/// );
+///
+/// let successors = |state: &[u8; 5]| {
+///     (0..4).map(|i| {
+///         let mut next = *state;
+///         next.swap(i, i + 1);
+///         (next, 1)
+///     }).collect::<Vec<_>>()
+/// };
+/// let heuristic = database.heuristic(|state: &[u8; 5]| {
+///     let position = |n| state.iter().position(|&x| x == n).unwrap();
+///     arrangement_rank(&[position(0), position(1)], 5)
+/// });
+/// let goal = [0, 1, 2, 3, 4];
+/// let (_, cost) = idastar(&[4, 3, 2, 1, 0], successors, heuristic, |s| *s == goal).unwrap();
+/// assert_eq!(cost, 10u32);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternDatabase {
+    distances: Vec<u8>,
+}
+
+impl PatternDatabase {
+    /// Compute the distances from every pattern to the closest goal pattern.
+    ///
+    /// - `goals` contains the goal patterns.
+    /// - `size` is the number of patterns, all ranks must be smaller than this value.
+    /// - `rank` returns the rank of a pattern. Two distinct patterns must have distinct
+    ///   ranks.
+    /// - `predecessors` returns the patterns from which a given pattern can be reached
+    ///   in one abstract move, along with the cost of this move. When moves can be
+    ///   reversed for the same cost, such as in sliding puzzles, those are the same as
+    ///   the successors of the pattern.
+    ///
+    /// The cost of an abstract move must not be larger than the cost of the moves it
+    /// abstracts for the distances to be admissible. A cost of 0 can be used for moves
+    /// which do not change the pattern.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rank` returns a value larger than or equal to `size`.
+    #[must_use]
+    pub fn new<P, FR, FP, IP>(goals: &[P], size: usize, mut rank: FR, mut predecessors: FP) -> Self
+    where
+        P: Clone,
+        FR: FnMut(&P) -> usize,
+        FP: FnMut(&P) -> IP,
+        IP: IntoIterator<Item = (P, u8)>,
+    {
+        let mut distances = vec![UNREACHED; size];
+        // Dial's algorithm: patterns are stored in buckets indexed by their tentative
+        // distance, and buckets are processed in increasing order.
+        let mut buckets = vec![goals.to_vec()];
+        let mut distance = 0;
+        while distance < buckets.len() {
+            while let Some(pattern) = buckets[distance].pop() {
+                let index = rank(&pattern);
+                if distances[index] != UNREACHED {
+                    continue;
+                }
+                distances[index] =
+                    u8::try_from(distance).map_or(MAX_DISTANCE, |d| d.min(MAX_DISTANCE));
+                for (predecessor, cost) in predecessors(&pattern) {
+                    if distances[rank(&predecessor)] == UNREACHED {
+                        let d = distance + usize::from(cost);
+                        if d >= buckets.len() {
+                            buckets.resize_with(d + 1, Vec::new);
+                        }
+                        buckets[d].push(predecessor);
+                    }
+                }
+            }
+            distance += 1;
+        }
+        Self { distances }
+    }
+
+    /// Load a database from the bytes returned by [`as_bytes`](Self::as_bytes).
+    #[must_use]
+    pub const fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { distances: bytes }
+    }
+
+    /// The table of distances, indexed by rank, with one byte per pattern. The
+    /// value 255 is used for patterns from which no goal can be reached.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.distances
+    }
+
+    /// The number of patterns in the database.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// The distance from the pattern with the given rank to the closest goal pattern,
+    /// or `None` if no goal can be reached from this pattern.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rank` is not smaller than the size of the database.
+    #[must_use]
+    pub fn distance(&self, rank: usize) -> Option<u8> {
+        let distance = self.distances[rank];
+        (distance != UNREACHED).then_some(distance)
+    }
+
+    /// Build a heuristic suitable for use with [`idastar`](super::idastar::idastar),
+    /// [`astar`](super::astar::astar) and their variants, where `rank` returns the rank
+    /// of the pattern of a given state.
+    ///
+    /// States whose pattern cannot reach the goal get an estimate of 255, as the goal
+    /// cannot be reached from them either.
+    pub fn heuristic<N, C, FR>(&self, rank: FR) -> impl Fn(&N) -> C
+    where
+        C: From<u8>,
+        FR: Fn(&N) -> usize,
+    {
+        move |state| C::from(self.distances[rank(state)])
+    }
+}
+
+/// The number of ways to place `pieces` distinguishable pieces on `cells` cells, with
+/// at most one piece per cell, which is the size of a [`PatternDatabase`] whose
+/// patterns are ranked with [`arrangement_rank`].
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::arrangement_count;
+///
+/// // Positions of 7 tiles of the 15-puzzle.
+/// assert_eq!(arrangement_count(16, 7), 57_657_600);
+/// ```
+#[must_use]
+pub fn arrangement_count(cells: usize, pieces: usize) -> usize {
+    if pieces > cells {
+        return 0;
+    }
+    (cells - pieces + 1..=cells).product()
+}
+
+/// Rank the positions of distinguishable pieces placed on distinct cells between 0 and
+/// `cells`, in lexicographic order. The result is smaller than
+/// [`arrangement_count(cells, positions.len())`](arrangement_count).
+///
+/// # Panics
+///
+/// This function may panic or return an incorrect result if a position is not smaller
+/// than `cells` or if two positions are equal.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::arrangement_rank;
+///
+/// assert_eq!(arrangement_rank(&[0, 1], 3), 0);
+/// assert_eq!(arrangement_rank(&[0, 2], 3), 1);
+/// assert_eq!(arrangement_rank(&[1, 0], 3), 2);
+/// assert_eq!(arrangement_rank(&[2, 1], 3), 5);
+/// ```
+#[must_use]
+pub fn arrangement_rank(positions: &[usize], cells: usize) -> usize {
+    let mut rank = 0;
+    for (i, &position) in positions.iter().enumerate() {
+        let smaller_free = position - positions[..i].iter().filter(|&&p| p < position).count();
+        rank += smaller_free * arrangement_count(cells - i - 1, positions.len() - i - 1);
+    }
+    rank
+}
@@ -0,0 +1,234 @@
+//! Move an agent towards a goal using real-time heuristic searches, which only look a
+//! bounded number of nodes ahead before every move and learn better heuristic values
+//! along the way.
+
+use super::reverse_path;
+use crate::FxIndexMap;
+use indexmap::map::Entry::{Occupied, Vacant};
+use num_traits::Zero;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+/// Heuristic values learned by real-time searches, for agents which must move every
+/// frame and cannot wait for a complete [`astar`](super::astar::astar) search on large
+/// maps.
+///
+/// A real-time search only looks a bounded number of nodes ahead of the agent before
+/// deciding where it moves next, and raises the heuristic values of the nodes it looked
+/// at so that the agent does not get stuck in the dead ends the heuristic does not know
+/// about. The learned values are kept between moves, and between trials reaching the
+/// same goal: an agent repeating a trip along the moves chosen by
+/// [`lrta_star`](Self::lrta_star) or [`rtaa_star`](Self::rtaa_star) eventually follows a
+/// shortest path.
+///
+/// The heuristic given to the searches returns an approximation of the cost from a given
+/// node to the goal, which must never be greater than the real cost. The learned values
+/// are only valid for a given goal, and must be [forgotten](Self::forget) when it
+/// changes.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::RealTimeSearch;
+///
+/// // A corridor along the x axis with a dead end at x = 5, which the heuristic ignores.
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| (0..=10).contains(&x) && (0..=2).contains(&y))
+///         .filter(|&(x, y)| x != 5 || y == 2)
+///         .map(|p| (p, 1))
+///         .collect::<Vec<_>>()
+/// };
+/// let goal: (i32, i32) = (10, 0);
+/// let heuristic = |&(x, y): &(i32, i32)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+///
+/// let mut search = RealTimeSearch::new();
+/// let mut agent = (0, 0);
+/// let mut moves = 0;
+/// while agent != goal {
+///     agent = search.lrta_star(&agent, successors, heuristic).expect("agent is stuck");
+///     moves += 1;
+/// }
+/// assert!(moves >= 14);
+/// // The value in front of the wall has been raised above the heuristic.
+/// assert!(search.heuristic(&(4, 0), heuristic) > 6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RealTimeSearch<N, C> {
+    learned: FxHashMap<N, C>,
+}
+
+impl<N, C> Default for RealTimeSearch<N, C> {
+    fn default() -> Self {
+        Self {
+            learned: FxHashMap::default(),
+        }
+    }
+}
+
+impl<N, C> RealTimeSearch<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    /// Create a search which has not learned any heuristic value yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The heuristic value of `node`, as learned by the previous moves or given by
+    /// `heuristic` if it has not been learned.
+    pub fn heuristic<FH>(&self, node: &N, mut heuristic: FH) -> C
+    where
+        FH: FnMut(&N) -> C,
+    {
+        self.learned
+            .get(node)
+            .copied()
+            .unwrap_or_else(|| heuristic(node))
+    }
+
+    /// Number of nodes whose heuristic value has been learned.
+    #[must_use]
+    pub fn learned_len(&self) -> usize {
+        self.learned.len()
+    }
+
+    /// Forget the learned heuristic values, for example when the goal changes.
+    pub fn forget(&mut self) {
+        self.learned.clear();
+    }
+
+    /// Choose the next move of an agent located on `current` using [Learning Real-Time
+    /// A*](https://doi.org/10.1016/0004-3702(90)90054-4) (LRTA*) with a lookahead of one
+    /// move.
+    ///
+    /// - `current` is the node the agent is located on. The agent stops once it has
+    ///   reached the goal, so it is never a goal node.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor. This cost must be non-negative.
+    /// - `heuristic` returns an approximation of the cost from a given node to the goal.
+    ///
+    /// The successor minimizing the cost of the move plus its heuristic value is returned,
+    /// and the heuristic value of `current` is raised to this sum. `None` is returned if
+    /// `current` has no successor.
+    pub fn lrta_star<FN, IN, FH>(
+        &mut self,
+        current: &N,
+        mut successors: FN,
+        mut heuristic: FH,
+    ) -> Option<N>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+    {
+        let mut best: Option<(N, C)> = None;
+        for (successor, cost) in successors(current) {
+            let estimate = cost + self.heuristic(&successor, &mut heuristic);
+            if best.as_ref().is_none_or(|&(_, c)| estimate < c) {
+                best = Some((successor, estimate));
+            }
+        }
+        let (next, estimate) = best?;
+        let value = self.heuristic(current, &mut heuristic).max(estimate);
+        self.learned.insert(current.clone(), value);
+        Some(next)
+    }
+
+    /// Choose the next moves of an agent located on `current` using [Real-Time Adaptive
+    /// A*](https://doi.org/10.1145/1160633.1160682) (RTAA*), which runs an A* search
+    /// limited to `lookahead` expansions.
+    ///
+    /// - `current` is the node the agent is located on.
+    /// - `lookahead` is the maximum number of nodes expanded by the search.
+    /// - `successors` returns a list of successors for a given node, along with the cost
+    ///   for moving from the node to the successor. This cost must be non-negative.
+    /// - `heuristic` returns an approximation of the cost from a given node to the goal.
+    /// - `success` checks whether the goal has been reached.
+    ///
+    /// The search stops when it is about to expand a goal or when the lookahead has been
+    /// exhausted, and the most promising node of its frontier is chosen as a target. The
+    /// heuristic values of the expanded nodes are raised to the estimated cost of the path
+    /// going through this target minus their distance from `current`.
+    ///
+    /// The path from `current` to the target is returned along with its cost. The agent
+    /// can follow all of it, or only its first moves before calling this method again.
+    /// `None` is returned if the search ran out of nodes to expand before reaching a goal,
+    /// in which case no goal can be reached from `current`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `lookahead` is 0.
+    pub fn rtaa_star<FN, IN, FH, FS>(
+        &mut self,
+        current: &N,
+        lookahead: usize,
+        mut successors: FN,
+        mut heuristic: FH,
+        mut success: FS,
+    ) -> Option<(Vec<N>, C)>
+    where
+        C: Sub<Output = C>,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+        FS: FnMut(&N) -> bool,
+    {
+        assert!(lookahead > 0, "lookahead must be at least 1");
+        let mut to_see = BinaryHeap::new();
+        to_see.push(Reverse((
+            self.heuristic(current, &mut heuristic),
+            Reverse(Zero::zero()),
+            0,
+        )));
+        let mut parents: FxIndexMap<N, (usize, C)> = FxIndexMap::default();
+        parents.insert(current.clone(), (usize::MAX, Zero::zero()));
+        let mut expanded = FxHashSet::default();
+        let (target, estimate) = loop {
+            let Reverse((estimate, Reverse(cost), index)) = to_see.pop()?;
+            let (node, &(_, c)) = parents.get_index(index).unwrap(); // Cannot fail
+            if cost > c || expanded.contains(&index) {
+                continue;
+            }
+            if expanded.len() == lookahead || success(node) {
+                break (index, estimate);
+            }
+            expanded.insert(index);
+            for (successor, move_cost) in successors(node) {
+                let new_cost = cost + move_cost;
+                let h;
+                let n;
+                match parents.entry(successor) {
+                    Vacant(e) => {
+                        h = self.heuristic(e.key(), &mut heuristic);
+                        n = e.index();
+                        e.insert((index, new_cost));
+                    }
+                    Occupied(mut e) => {
+                        if e.get().1 > new_cost {
+                            h = self.heuristic(e.key(), &mut heuristic);
+                            n = e.index();
+                            e.insert((index, new_cost));
+                        } else {
+                            continue;
+                        }
+                    }
+                }
+                to_see.push(Reverse((new_cost + h, Reverse(new_cost), n)));
+            }
+        };
+        for index in expanded {
+            let (node, &(_, cost)) = parents.get_index(index).unwrap(); // Cannot fail
+            let value = self.heuristic(node, &mut heuristic).max(estimate - cost);
+            self.learned.insert(node.clone(), value);
+        }
+        let cost = parents[target].1;
+        Some((reverse_path(&parents, |&(p, _)| p, target), cost))
+    }
+}
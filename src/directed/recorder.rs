@@ -0,0 +1,372 @@
+//! Record the nodes expanded, the successors found, the parents updated and the
+//! heuristic estimates of a search, to replay them in a visualizer or to debug a
+//! heuristic without modifying the search algorithms.
+
+use crate::FxIndexSet;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::hash::Hash;
+
+/// An event recorded in a [`SearchRecording`]. Nodes are designated by their index in
+/// the recording, as returned by [`SearchRecording::node`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SearchEvent<C> {
+    /// The successors of a node have been asked for by the search.
+    Expand(usize),
+    /// A successor has been returned for the node expanded last, along with the cost
+    /// of the edge leading to it.
+    Successor {
+        /// The index of the expanded node.
+        from: usize,
+        /// The index of the successor.
+        to: usize,
+        /// The cost for moving from the expanded node to the successor.
+        cost: C,
+    },
+    /// The successor returned last has been reached for the first time, or with a lower
+    /// cost than before, and the expanded node has become its parent.
+    Relax {
+        /// The index of the successor.
+        node: usize,
+        /// The index of the expanded node, which is the new parent of the successor.
+        parent: usize,
+        /// The cost of the cheapest known path from the start to the successor.
+        cost: C,
+    },
+    /// The heuristic has been evaluated for a node.
+    Estimate {
+        /// The index of the node.
+        node: usize,
+        /// The value returned by the heuristic.
+        estimate: C,
+    },
+    /// The success function has accepted a node as a goal.
+    Goal(usize),
+}
+
+/// The events recorded by a [`SearchRecorder`] during a search, in the order in which
+/// they happened.
+///
+/// Every node is stored once, in the order in which it first appeared, and is
+/// designated in the events by its index. With the `serde` feature, a recording can be
+/// serialized, and with the `json` feature, it can be directly exported to and
+/// imported from JSON.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "N: serde::Serialize, C: serde::Serialize",
+        deserialize = "N: serde::Deserialize<'de> + Eq + Hash, C: serde::Deserialize<'de>"
+    ))
+)]
+pub struct SearchRecording<N, C> {
+    nodes: FxIndexSet<N>,
+    events: Vec<SearchEvent<C>>,
+}
+
+impl<N, C> Default for SearchRecording<N, C> {
+    fn default() -> Self {
+        Self {
+            nodes: FxIndexSet::default(),
+            events: Vec::new(),
+        }
+    }
+}
+
+impl<N, C> SearchRecording<N, C>
+where
+    N: Eq + Hash + Clone,
+{
+    /// The recorded events, in the order in which they happened.
+    #[must_use]
+    pub fn events(&self) -> &[SearchEvent<C>] {
+        &self.events
+    }
+
+    /// The node with index `index`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if no node has this index.
+    #[must_use]
+    pub fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    /// The nodes which appear in the recording, in the order of their indices.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// The expanded nodes, in the order in which they were expanded. A node expanded
+    /// several times appears several times.
+    pub fn expanded(&self) -> impl Iterator<Item = &N> {
+        self.events.iter().filter_map(|event| match event {
+            SearchEvent::Expand(node) => Some(&self.nodes[*node]),
+            _ => None,
+        })
+    }
+
+    fn index(&mut self, node: &N) -> usize {
+        match self.nodes.get_index_of(node) {
+            Some(index) => index,
+            None => self.nodes.insert_full(node.clone()).0,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<N, C> SearchRecording<N, C>
+where
+    N: Eq + Hash + serde::Serialize + serde::de::DeserializeOwned,
+    C: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Export the recording to JSON, as an object with a list of `nodes` and a list of
+    /// `events`.
+    ///
+    /// This method is only available when the `json` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a node or a cost cannot be serialized to JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Import a recording previously exported with [`to_json`](Self::to_json).
+    ///
+    /// This method is only available when the `json` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input does not describe a recording.
+    pub fn from_json(input: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(input)
+    }
+}
+
+/// Record the events of a search into a [`SearchRecording`] by wrapping the functions
+/// given to the search.
+///
+/// The wrapped functions can be given to any search, such as
+/// [`astar`](super::astar::astar), [`dijkstra`](super::dijkstra::dijkstra) or
+/// [`bfs`](super::bfs::bfs), and behave exactly as the original ones. Only the
+/// wrapped functions record events: a search can be recorded partially, for example
+/// without recording the evaluations of its heuristic.
+///
+/// The [`Relax`](SearchEvent::Relax) events are deduced from the costs of the
+/// successors by the recorder, starting from the first expanded node: a successor gets
+/// a new parent when it is reached for the first time or with a strictly lower cost
+/// than before, as in [`astar`](super::astar::astar),
+/// [`dijkstra`](super::dijkstra::dijkstra) and [`bfs`](super::bfs::bfs). For other
+/// searches, such as [`fringe`](super::fringe::fringe) or
+/// [`idastar`](super::idastar::idastar) which do not keep track of the parents, those
+/// events might not match what the search does.
+///
+/// `C` is the type of the costs, or `()` for an unweighted search using
+/// [`unweighted_successors`](SearchRecorder::unweighted_successors). To render the
+/// explored part of a graph in the DOT language, use
+/// [`SearchTrace`](crate::dot::SearchTrace) instead.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, SearchEvent, SearchRecorder};
+///
+/// // Move on a line, where moving by 1 costs 2 and moving by 3 costs 3.
+/// let recorder = SearchRecorder::new();
+/// let result = astar(
+///     &0i32,
+///     recorder.successors(|&n| vec![(n + 1, 2), (n - 1, 2), (n + 3, 3)]),
+///     recorder.heuristic(|&n| (7 - n).abs()),
+///     recorder.success(|&n| n == 7),
+/// );
+/// assert_eq!(result, Some((vec![0, 3, 6, 7], 8)));
+///
+/// let recording = recorder.into_recording();
+/// assert_eq!(recording.expanded().collect::<Vec<_>>(), vec![&0, &3, &6]);
+/// // Replay the search.
+/// for event in recording.events() {
+///     match *event {
+///         SearchEvent::Expand(n) => println!("expanding {}", recording.node(n)),
+///         SearchEvent::Successor { from, to, cost } => {
+///             let (from, to) = (recording.node(from), recording.node(to));
+///             println!("{from} -> {to} costs {cost}");
+///         }
+///         SearchEvent::Relax { node, parent, cost } => {
+///             let (node, parent) = (recording.node(node), recording.node(parent));
+///             println!("{node} is now reached through {parent} for {cost}");
+///         }
+///         SearchEvent::Estimate { node, estimate } => {
+///             println!("{} is estimated at {estimate}", recording.node(node));
+///         }
+///         SearchEvent::Goal(n) => println!("{} is a goal", recording.node(n)),
+///     }
+/// }
+/// // The search stopped as soon as the goal was accepted.
+/// let Some(&SearchEvent::Goal(goal)) = recording.events().last() else {
+///     panic!("the goal has not been reached");
+/// };
+/// assert_eq!(recording.node(goal), &7);
+/// ```
+#[derive(Debug)]
+pub struct SearchRecorder<N, C> {
+    recording: RefCell<SearchRecording<N, C>>,
+    // The cost of the cheapest known path to every node, indexed as in the recording.
+    costs: RefCell<FxHashMap<usize, C>>,
+}
+
+impl<N, C> Default for SearchRecorder<N, C> {
+    fn default() -> Self {
+        Self {
+            recording: RefCell::new(SearchRecording::default()),
+            costs: RefCell::new(FxHashMap::default()),
+        }
+    }
+}
+
+impl<N, C> SearchRecorder<N, C>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Create a recorder with an empty recording.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap a successors function, recording the expansion of every node along with
+    /// the successors which are returned for it and the parents which are updated.
+    pub fn successors<FN, IN>(&self, mut successors: FN) -> impl FnMut(&N) -> Vec<(N, C)>
+    where
+        C: Zero + Ord + Clone,
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        move |node| {
+            let successors = successors(node).into_iter().collect::<Vec<_>>();
+            self.expand(node, &successors, C::zero(), |cost, move_cost| {
+                cost.clone() + move_cost.clone()
+            });
+            successors
+        }
+    }
+
+    // Record the expansion of `node`, which costs `start` if it has not been reached
+    // before, and the relaxation of its successors whose cost is given by `add`. A
+    // successor is relaxed when it is new or when its cost is strictly lower.
+    fn expand<FA>(&self, node: &N, successors: &[(N, C)], start: C, add: FA)
+    where
+        C: PartialOrd + Clone,
+        FA: Fn(&C, &C) -> C,
+    {
+        let mut recording = self.recording.borrow_mut();
+        let mut costs = self.costs.borrow_mut();
+        let from = recording.index(node);
+        recording.events.push(SearchEvent::Expand(from));
+        let cost = costs.entry(from).or_insert(start).clone();
+        for (successor, move_cost) in successors {
+            let to = recording.index(successor);
+            recording.events.push(SearchEvent::Successor {
+                from,
+                to,
+                cost: move_cost.clone(),
+            });
+            let new_cost = add(&cost, move_cost);
+            if costs.get(&to).is_none_or(|old_cost| new_cost < *old_cost) {
+                costs.insert(to, new_cost.clone());
+                recording.events.push(SearchEvent::Relax {
+                    node: to,
+                    parent: from,
+                    cost: new_cost,
+                });
+            }
+        }
+    }
+
+    /// Wrap a heuristic, recording the estimate it returns for every node.
+    pub fn heuristic<FH>(&self, mut heuristic: FH) -> impl FnMut(&N) -> C
+    where
+        C: Clone,
+        FH: FnMut(&N) -> C,
+    {
+        move |node| {
+            let estimate = heuristic(node);
+            let mut recording = self.recording.borrow_mut();
+            let node = recording.index(node);
+            recording.events.push(SearchEvent::Estimate {
+                node,
+                estimate: estimate.clone(),
+            });
+            estimate
+        }
+    }
+
+    /// Wrap a success function, recording the nodes it accepts as goals.
+    pub fn success<FS>(&self, mut success: FS) -> impl FnMut(&N) -> bool
+    where
+        FS: FnMut(&N) -> bool,
+    {
+        move |node| {
+            let found = success(node);
+            if found {
+                let mut recording = self.recording.borrow_mut();
+                let node = recording.index(node);
+                recording.events.push(SearchEvent::Goal(node));
+            }
+            found
+        }
+    }
+
+    /// Stop recording and return the recorded events.
+    #[must_use]
+    pub fn into_recording(self) -> SearchRecording<N, C> {
+        self.recording.into_inner()
+    }
+}
+
+impl<N> SearchRecorder<N, ()>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Wrap the successors function of an unweighted search, recording the expansion
+    /// of every node along with the successors which are returned for it and the
+    /// parents which are updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::{bfs, SearchRecorder};
+    ///
+    /// let recorder = SearchRecorder::new();
+    /// let path = bfs(
+    ///     &1u32,
+    ///     recorder.unweighted_successors(|&n| vec![n + 1, n * 2]),
+    ///     recorder.success(|&n| n == 9),
+    /// );
+    /// assert_eq!(path, Some(vec![1, 2, 4, 8, 9]));
+    /// let recording = recorder.into_recording();
+    /// assert!(recording.nodes().any(|&n| n == 16));
+    /// ```
+    pub fn unweighted_successors<FN, IN>(&self, mut successors: FN) -> impl FnMut(&N) -> Vec<N>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+    {
+        move |node| {
+            let successors = successors(node)
+                .into_iter()
+                .map(|successor| (successor, ()))
+                .collect::<Vec<_>>();
+            self.expand(node, &successors, (), |(), ()| ());
+            successors
+                .into_iter()
+                .map(|(successor, ())| successor)
+                .collect()
+        }
+    }
+}
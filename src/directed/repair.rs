@@ -0,0 +1,122 @@
+//! Repair a path locally after the graph it goes through has changed.
+
+use super::dijkstra::{DijkstraCheckpoint, dijkstra};
+use crate::avoid::Avoid;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+use std::ops::ControlFlow;
+
+/// Repair a path after some nodes of the graph have changed, by searching a detour
+/// around the affected part of the path before falling back to a full search.
+///
+/// - `path` is the path to repair, from its start to its goal.
+/// - `changed` holds the nodes which have changed, for example grid cells which became
+///   obstacles or whose edges got more expensive. It can be any [`Avoid`] filter, such
+///   as a set of nodes or a predicate. A changed edge can be given through one of its
+///   ends.
+/// - `successors` returns the successors of a node in the updated graph, along with
+///   the cost for moving from the node to the successor. This cost must be non-negative.
+/// - `max_expansions` is the maximum number of nodes the local search can expand.
+///
+/// The affected part of the path goes from the first to the last step which either
+/// does not exist anymore in the updated graph or touches a changed node. A Dijkstra
+/// search is run from the node preceding this part, and stops as soon as it reaches
+/// one of the nodes following it, from which the original path is used again. If this
+/// search does not succeed within `max_expansions` expansions, a full Dijkstra search
+/// from the start of the path to its goal is run instead.
+///
+/// The path is returned unchanged if no step is affected. Otherwise, the repaired path
+/// is returned along with its cost in the updated graph, or `None` if the goal cannot be
+/// reached anymore. A repaired path may be longer than a path computed from scratch,
+/// as parts of the graph which did not change may now offer shortcuts.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::repair_path;
+///
+/// let grid_successors = |blocked: &'static [(i32, i32)]| {
+///     move |&(x, y): &(i32, i32)| {
+///         [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///             .into_iter()
+///             .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+///             .filter(|p| !blocked.contains(p))
+///             .map(|p| (p, 1))
+///             .collect::<Vec<_>>()
+///     }
+/// };
+/// let path = (0..10).map(|x| (x, 5)).collect::<Vec<_>>();
+///
+/// // An obstacle appears on the path, which goes around it.
+/// let (repaired, cost) = repair_path(&path, &[(4, 5)][..], grid_successors(&[(4, 5)]), 50)
+///     .expect("no path found");
+/// assert_eq!(cost, 11);
+/// assert!(!repaired.contains(&(4, 5)));
+/// assert_eq!(repaired[..3], path[..3]);
+/// assert_eq!(repaired[repaired.len() - 4..], path[6..]);
+/// ```
+pub fn repair_path<N, C, A, FN, IN>(
+    path: &[N],
+    changed: &A,
+    mut successors: FN,
+    max_expansions: usize,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    A: Avoid<N> + ?Sized,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (start, goal) = (path.first()?, path.last()?);
+    // Cost of every step in the updated graph, or `None` if it is affected.
+    let costs = path
+        .windows(2)
+        .map(|w| {
+            if changed.avoids(&w[0]) || changed.avoids(&w[1]) {
+                return None;
+            }
+            successors(&w[0])
+                .into_iter()
+                .filter(|(n, _)| *n == w[1])
+                .map(|(_, cost)| cost)
+                .min()
+        })
+        .collect::<Vec<_>>();
+    let (Some(first), Some(last)) = (
+        costs.iter().position(Option::is_none),
+        costs.iter().rposition(Option::is_none),
+    ) else {
+        let cost = costs
+            .into_iter()
+            .flatten()
+            .fold(Zero::zero(), |a: C, c| a + c);
+        return Some((path.to_vec(), cost));
+    };
+    // Nodes following the affected part, with the latest position of each of them.
+    let rejoin = path
+        .iter()
+        .enumerate()
+        .skip(last + 1)
+        .map(|(i, n)| (n, i))
+        .collect::<FxHashMap<_, _>>();
+    let mut search = DijkstraCheckpoint::new(&path[first]);
+    let detour = match search.resume(&mut successors, |n| rejoin.contains_key(n), max_expansions) {
+        ControlFlow::Break(detour) => detour,
+        ControlFlow::Continue(()) => None,
+    };
+    let Some((detour, detour_cost)) = detour else {
+        return dijkstra(start, successors, |n| n == goal);
+    };
+    let end = rejoin[detour.last()?];
+    let cost = costs[..first]
+        .iter()
+        .chain(&costs[end..])
+        .flatten()
+        .fold(detour_cost, |a, &c| a + c);
+    let mut repaired = path[..first].to_vec();
+    repaired.extend(detour);
+    repaired.extend_from_slice(&path[end + 1..]);
+    Some((repaired, cost))
+}
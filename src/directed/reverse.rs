@@ -0,0 +1,183 @@
+//! Reverse the edges of a graph described by a successors function, to run searches
+//! which need the predecessors of the nodes.
+
+use crate::FxIndexMap;
+use indexmap::map::Entry::{Occupied, Vacant};
+use std::hash::Hash;
+
+/// The predecessors of the nodes of a graph described by a successors function, built
+/// once by exploring the graph so that they can be given to the searches going
+/// backward from a goal, such as
+/// [`dijkstra_backward`](super::dijkstra::dijkstra_backward),
+/// [`bfs_backward`](super::bfs::bfs_backward) or the bidirectional searches.
+///
+/// The graph is explored from some start nodes, and only the edges between the nodes
+/// reachable from them are reversed. Giving every node of the graph as a start node
+/// reverses the whole graph. The successors function is called exactly once for every
+/// reached node.
+///
+/// `C` is the type of the costs of the edges, or `()` for a graph built by
+/// [`new_unweighted`](Self::new_unweighted). The predecessors of a node are returned
+/// in the order in which the edges leading to it were found.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra_backward, ReversedGraph};
+///
+/// // Integers from 1 to 30, where moving from n to 2n costs 1 and from n to n + 1
+/// // costs 2.
+/// let successors = |&n: &u32| {
+///     [(2 * n, 1), (n + 1, 2)]
+///         .into_iter()
+///         .filter(|&(m, _)| m <= 30)
+/// };
+/// let reversed = ReversedGraph::new(&[1], successors);
+/// assert_eq!(reversed.len(), 30);
+/// assert_eq!(reversed.predecessors(&12), vec![(6, 1), (11, 2)]);
+///
+/// // Find the cheapest way to reach 25 by going backward from it.
+/// let (path, cost) =
+///     dijkstra_backward(&25, |n| reversed.predecessors(n), |&n| n == 1).unwrap();
+/// assert_eq!(path, vec![1, 2, 3, 6, 12, 24, 25]);
+/// assert_eq!(cost, 8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReversedGraph<N, C> {
+    // Every reached node, with the index of its predecessors and the cost of the
+    // edges coming from them.
+    nodes: FxIndexMap<N, Vec<(usize, C)>>,
+}
+
+impl<N, C> ReversedGraph<N, C>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Explore a weighted graph from some start nodes and reverse its edges.
+    ///
+    /// - `starts` are the nodes the exploration starts from.
+    /// - `successors` returns a list of successors for a given node, along with the
+    ///   cost for moving from the node to the successor.
+    pub fn new<FN, IN>(starts: &[N], mut successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        let mut nodes = FxIndexMap::default();
+        for start in starts {
+            nodes.entry(start.clone()).or_insert_with(Vec::new);
+        }
+        let mut i = 0;
+        while let Some((node, _)) = nodes.get_index(i) {
+            for (successor, cost) in successors(node) {
+                match nodes.entry(successor) {
+                    Occupied(mut e) => e.get_mut().push((i, cost)),
+                    Vacant(e) => {
+                        e.insert(vec![(i, cost)]);
+                    }
+                }
+            }
+            i += 1;
+        }
+        Self { nodes }
+    }
+
+    /// The number of nodes which have been reached.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check whether no node has been reached, which only happens when no start node
+    /// has been given.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Check whether `node` has been reached.
+    #[must_use]
+    pub fn contains(&self, node: &N) -> bool {
+        self.nodes.contains_key(node)
+    }
+
+    /// The nodes which have been reached, in the order in which they were reached.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.keys()
+    }
+
+    /// The predecessors of `node`, along with the cost for moving from the predecessor
+    /// to `node`. The list is empty if `node` has not been reached.
+    #[must_use]
+    pub fn predecessors(&self, node: &N) -> Vec<(N, C)>
+    where
+        C: Clone,
+    {
+        self.nodes.get(node).map_or_else(Vec::new, |predecessors| {
+            predecessors
+                .iter()
+                .map(|(p, cost)| (self.node(*p).clone(), cost.clone()))
+                .collect()
+        })
+    }
+
+    /// The predecessors of `node`, without the costs of the edges. The list is empty if
+    /// `node` has not been reached.
+    #[must_use]
+    pub fn predecessor_nodes(&self, node: &N) -> Vec<N> {
+        self.nodes.get(node).map_or_else(Vec::new, |predecessors| {
+            predecessors
+                .iter()
+                .map(|&(p, _)| self.node(p).clone())
+                .collect()
+        })
+    }
+
+    fn node(&self, index: usize) -> &N {
+        self.nodes.get_index(index).unwrap().0 // Cannot fail
+    }
+}
+
+impl<N> ReversedGraph<N, ()>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Explore an unweighted graph from some start nodes and reverse its edges. The
+    /// predecessors of a node are then returned by
+    /// [`predecessor_nodes`](Self::predecessor_nodes).
+    ///
+    /// - `starts` are the nodes the exploration starts from.
+    /// - `successors` returns a list of successors for a given node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::{bfs_bidirectional, ReversedGraph};
+    ///
+    /// // Moves of a knight on a chessboard.
+    /// let successors = |&(x, y): &(i32, i32)| {
+    ///     [(1, 2), (2, 1), (2, -1), (1, -2), (-1, -2), (-2, -1), (-2, 1), (-1, 2)]
+    ///         .into_iter()
+    ///         .map(move |(dx, dy)| (x + dx, y + dy))
+    ///         .filter(|&(x, y)| (0..8).contains(&x) && (0..8).contains(&y))
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// let reversed = ReversedGraph::new_unweighted(&[(0, 0)], successors);
+    /// assert_eq!(reversed.len(), 64);
+    /// let path = bfs_bidirectional(
+    ///     &(0, 0),
+    ///     &(7, 7),
+    ///     successors,
+    ///     |n| reversed.predecessor_nodes(n),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(path.len(), 7);
+    /// ```
+    pub fn new_unweighted<FN, IN>(starts: &[N], mut successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+    {
+        Self::new(starts, |node| successors(node).into_iter().map(|n| (n, ())))
+    }
+}
@@ -0,0 +1,144 @@
+//! Reusable buffers for running many searches without reallocating their bookkeeping.
+
+use indexmap::IndexMap;
+use rustc_hash::FxHasher;
+use std::collections::BinaryHeap;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+
+use super::ParentIndex;
+use super::astar::SmallestCostHolder;
+use super::dijkstra::SmallestHolder;
+
+/// Buffers used by the search algorithms, which can be kept between queries
+/// to avoid allocating new ones every time.
+///
+/// A context is passed to [`astar_with_context`](super::astar::astar_with_context),
+/// [`dijkstra_with_context`](super::dijkstra::dijkstra_with_context) or
+/// [`bfs_with_context`](super::bfs::bfs_with_context). Those functions clear
+/// the buffers before starting, but keep their allocated capacity, so that
+/// running many queries does not require reallocating the buffers once they
+/// have grown large enough.
+///
+/// `bfs_with_context` requires a context whose cost type is `usize`, as the
+/// depth of each node is recorded as its cost.
+///
+/// # Memory allocation
+///
+/// Custom allocators, such as a per-frame arena, are not supported: the internal
+/// maps and heaps always use the global allocator, as the collections they are
+/// built upon do not accept a custom allocator on stable Rust.
+///
+/// Keeping a context around, preallocated using [`SearchContext::with_capacity`]
+/// or [`SearchContext::reserve`], reduces the allocations made by the searches
+/// without removing them:
+///
+/// - a heap may hold several entries for the same node, as a node is pushed again
+///   every time a cheaper path to it is found, so that the heaps can outgrow the
+///   number of discovered nodes and the reserved capacity;
+/// - every path returned by a search is a newly allocated `Vec`;
+/// - the collections returned by the successors function are allocated by the
+///   caller.
+///
+/// Buffers which have grown keep their capacity for the following searches.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra_with_context, SearchContext};
+///
+/// let mut context = SearchContext::new();
+/// for goal in 10..20 {
+///     let (path, cost) = dijkstra_with_context(&mut context, &1, |&n| vec![(n + 1, 1), (n * 2, 3)],
+///                                              |&n| n == goal)
+///         .expect("no path found");
+///     assert_eq!(path.last(), Some(&goal));
+///     assert!(cost <= goal - 1);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SearchContext<N, C, H = BuildHasherDefault<FxHasher>> {
+    pub(crate) parents: IndexMap<N, (ParentIndex, C), H>,
+    pub(crate) dijkstra_heap: BinaryHeap<SmallestHolder<C>>,
+    pub(crate) astar_heap: BinaryHeap<SmallestCostHolder<C>>,
+}
+
+impl<N, C> SearchContext<N, C> {
+    /// Create an empty context.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(BuildHasherDefault::<FxHasher>::default())
+    }
+}
+
+impl<N, C: Ord> SearchContext<N, C> {
+    /// Create an empty context able to hold `capacity` nodes without
+    /// reallocating, as long as the heaps hold no stale entries.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, BuildHasherDefault::<FxHasher>::default())
+    }
+}
+
+impl<N, C> Default for SearchContext<N, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, C, H: BuildHasher> SearchContext<N, C, H> {
+    /// Create an empty context using a custom hasher.
+    #[must_use]
+    pub const fn with_hasher(hasher: H) -> Self {
+        Self {
+            parents: IndexMap::with_hasher(hasher),
+            dijkstra_heap: BinaryHeap::new(),
+            astar_heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Create an empty context able to hold `capacity` nodes without
+    /// reallocating, as long as the heaps hold no stale entries, using a custom
+    /// hasher.
+    #[must_use]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: H) -> Self
+    where
+        C: Ord,
+    {
+        Self {
+            parents: IndexMap::with_capacity_and_hasher(capacity, hasher),
+            dijkstra_heap: BinaryHeap::with_capacity(capacity),
+            astar_heap: BinaryHeap::with_capacity(capacity),
+        }
+    }
+
+    /// Reserve room for at least `additional` more nodes than the
+    /// buffers currently hold.
+    pub fn reserve(&mut self, additional: usize)
+    where
+        N: Eq + Hash,
+        C: Ord,
+    {
+        self.parents.reserve(additional);
+        self.dijkstra_heap.reserve(additional);
+        self.astar_heap.reserve(additional);
+    }
+
+    /// Number of nodes the context can hold without reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.parents.capacity()
+    }
+
+    /// Empty the buffers while keeping their allocated capacity.
+    pub fn clear(&mut self) {
+        self.parents.clear();
+        self.dijkstra_heap.clear();
+        self.astar_heap.clear();
+    }
+
+    /// Number of nodes discovered by the last search run with this context.
+    #[must_use]
+    pub fn discovered(&self) -> usize {
+        self.parents.len()
+    }
+}
@@ -0,0 +1,134 @@
+//! Report why a search did not return a path, for the `try_` variants of the searches
+//! such as [`try_astar`](super::astar::try_astar).
+
+use thiserror::Error;
+
+/// Error returned by the `try_` variants of the searches, such as
+/// [`try_astar`](super::astar::try_astar), when no path has been returned.
+///
+/// When a search guided by a heuristic has been stopped before completion, the path to
+/// the closest node to the goal expanded so far is given along with its cost, as a
+/// fallback route or to report the progress of the search. The closest node is the one
+/// with the smallest heuristic value. Searches without a heuristic give no closest path.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum SearchError<N, C> {
+    /// The whole reachable graph has been explored without finding a goal.
+    #[error("no path exists")]
+    NoPath,
+    /// The maximum number of expansions given in the [`SearchLimits`] has been reached
+    /// before finding a goal.
+    #[error("the search budget of {max_expansions} expansions has been exceeded")]
+    BudgetExceeded {
+        /// The maximum number of expansions.
+        max_expansions: usize,
+        /// The path to the closest node to the goal along with its cost, if the search
+        /// is guided by a heuristic.
+        closest: Option<(Vec<N>, C)>,
+    },
+    /// The cancellation function given in the [`SearchLimits`] has requested to stop.
+    #[error("the search has been cancelled after {expanded} expansions")]
+    Cancelled {
+        /// The number of nodes expanded before the cancellation.
+        expanded: usize,
+        /// The path to the closest node to the goal along with its cost, if the search
+        /// is guided by a heuristic.
+        closest: Option<(Vec<N>, C)>,
+    },
+    /// The cost of a path, or its cost added to the heuristic, cannot be represented
+    /// by the cost type.
+    #[error("the cost of a path overflowed")]
+    CostOverflow {
+        /// The path to the node whose successor could not be reached without
+        /// overflowing.
+        path: Vec<N>,
+    },
+}
+
+impl<N, C> SearchError<N, C> {
+    /// The path to the closest node to the goal along with its cost, if a search guided
+    /// by a heuristic has been stopped before completion.
+    #[must_use]
+    pub const fn closest(&self) -> Option<&(Vec<N>, C)> {
+        match self {
+            Self::BudgetExceeded { closest, .. } | Self::Cancelled { closest, .. } => {
+                closest.as_ref()
+            }
+            Self::NoPath | Self::CostOverflow { .. } => None,
+        }
+    }
+}
+
+/// Limits given to the `try_` variants of the searches, such as
+/// [`try_astar`](super::astar::try_astar).
+///
+/// By default, the number of expansions is not limited and the search cannot be
+/// cancelled.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{try_dijkstra, SearchError, SearchLimits};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// // The flag could be set by another thread, for example when a request times out.
+/// let stop = AtomicBool::new(false);
+/// let limits = SearchLimits::new()
+///     .max_expansions(100)
+///     .cancel_when(|| stop.load(Ordering::Relaxed));
+/// let successors = |&n: &u32| vec![(n + 1, 1), (n + 2, 3)];
+/// let result = try_dijkstra(&0, successors, |&n| n == 1000, limits);
+/// assert!(matches!(
+///     result,
+///     Err(SearchError::BudgetExceeded { max_expansions: 100, .. })
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SearchLimits<FC> {
+    pub(crate) max_expansions: usize,
+    pub(crate) cancelled: FC,
+}
+
+const fn never() -> bool {
+    false
+}
+
+impl SearchLimits<fn() -> bool> {
+    /// Create limits which do not limit the search.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_expansions: usize::MAX,
+            cancelled: never,
+        }
+    }
+}
+
+impl Default for SearchLimits<fn() -> bool> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<FC> SearchLimits<FC> {
+    /// Stop the search with [`SearchError::BudgetExceeded`] instead of expanding more
+    /// than `max_expansions` nodes.
+    #[must_use]
+    pub const fn max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = max_expansions;
+        self
+    }
+
+    /// Stop the search with [`SearchError::Cancelled`] as soon as `cancelled` returns
+    /// `true`. It is called before every expansion, and must thus be cheap, such as
+    /// reading an atomic flag or checking a deadline every few calls.
+    #[must_use]
+    pub fn cancel_when<G>(self, cancelled: G) -> SearchLimits<G>
+    where
+        G: FnMut() -> bool,
+    {
+        SearchLimits {
+            max_expansions: self.max_expansions,
+            cancelled,
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Solve [algebraic path problems](https://en.wikipedia.org/wiki/Semiring#Applications)
+//! for any [semiring](https://en.wikipedia.org/wiki/Semiring), covering shortest paths,
+//! most probable paths, path counting and reachability with the same algorithm.
+//!
+//! The specialized searches such as [`dijkstra`](super::dijkstra::dijkstra) are not
+//! built on top of this module: they keep their own loop, which relies on the total
+//! order of costs to expand every node once. [`semiring_paths`] with [`Tropical`]
+//! weights computes the same costs as [`dijkstra_all`](super::dijkstra::dijkstra_all),
+//! and is meant for the semirings these searches do not cover.
+
+use crate::FxIndexMap;
+use num_traits::{One, Zero};
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::{Add, Mul};
+
+/// A semiring used to combine the weights of edges along a path and the weights of
+/// different paths.
+///
+/// - [`Semiring::times`] extends a path with an edge, and must be associative with
+///   [`Semiring::one`] as its neutral element.
+/// - [`Semiring::plus`] combines two paths going to the same node, and must be associative
+///   and commutative with [`Semiring::zero`] as its neutral element.
+/// - `times` must distribute over `plus`, and `zero` must be absorbing for `times`.
+pub trait Semiring: Clone + PartialEq {
+    /// Weight of the absence of path, neutral element of [`Semiring::plus`].
+    fn zero() -> Self;
+    /// Weight of the empty path, neutral element of [`Semiring::times`].
+    fn one() -> Self;
+    /// Combine the weights of two alternative paths.
+    #[must_use]
+    fn plus(&self, other: &Self) -> Self;
+    /// Combine the weight of a path with the weight of an edge extending it.
+    #[must_use]
+    fn times(&self, other: &Self) -> Self;
+}
+
+/// The tropical semiring, where the weight of a path is the sum of the costs of its
+/// edges and the best path is the cheapest one. `Tropical(None)` represents the absence
+/// of path.
+///
+/// This is the semiring solved by [`dijkstra`](super::dijkstra::dijkstra), which is
+/// much faster when only this semiring is needed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tropical<C>(pub Option<C>);
+
+impl<C: Zero + Ord + Copy> Semiring for Tropical<C> {
+    fn zero() -> Self {
+        Self(None)
+    }
+
+    fn one() -> Self {
+        Self(Some(C::zero()))
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        match (self.0, other.0) {
+            (Some(a), Some(b)) => Self(Some(a.min(b))),
+            (a, b) => Self(a.or(b)),
+        }
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0.zip(other.0).map(|(a, b)| a + b))
+    }
+}
+
+/// The Viterbi semiring, where the weight of a path is the product of the probabilities
+/// of its edges and the best path is the most probable one. Probabilities must lie
+/// between zero and one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Viterbi<T>(pub T);
+
+impl<T: Zero + One + PartialOrd + Copy> Semiring for Viterbi<T> {
+    fn zero() -> Self {
+        Self(T::zero())
+    }
+
+    fn one() -> Self {
+        Self(T::one())
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        if other.0 > self.0 { *other } else { *self }
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+/// The counting semiring, where the weight of a path is the product of the
+/// multiplicities of its edges, and the weights of alternative paths are added. With
+/// edges of weight one, this counts the paths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Counting<T>(pub T);
+
+impl<T: Zero + One + Add<Output = T> + Mul<Output = T> + PartialEq + Copy> Semiring
+    for Counting<T>
+{
+    fn zero() -> Self {
+        Self(T::zero())
+    }
+
+    fn one() -> Self {
+        Self(T::one())
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+/// The boolean semiring, where a node has weight `true` if it can be reached using
+/// edges of weight `true`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reachability(pub bool);
+
+impl Semiring for Reachability {
+    fn zero() -> Self {
+        Self(false)
+    }
+
+    fn one() -> Self {
+        Self(true)
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Self(self.0 || other.0)
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        Self(self.0 && other.0)
+    }
+}
+
+/// Compute, for every node reachable from `start`, the combination using
+/// [`Semiring::plus`] of the weights of all the paths from `start` to this node,
+/// the weight of a path being the combination of the weights of its edges using
+/// [`Semiring::times`].
+///
+/// - `start` is the starting node, whose weight includes the empty path.
+/// - `successors` returns a list of successors for a given node, along with the weight of
+///   the edge going from the node to the successor.
+///
+/// Nodes whose weight is [`Semiring::zero`] are not included in the result.
+///
+/// This uses the generic single-source algorithm from [Mohri's "Semiring frameworks and
+/// algorithms for shortest-distance problems"](https://cs.nyu.edu/~mohri/pub/jalc.pdf),
+/// which propagates weight differences through the graph until nothing changes. It
+/// terminates on any graph for semirings where a cycle can never improve a path, such
+/// as [`Tropical`] with non-negative costs, [`Viterbi`] or [`Reachability`]. For other
+/// semirings such as [`Counting`], the graph must be acyclic, or the function will never
+/// return.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{semiring_paths, Counting, Tropical, Viterbi};
+///
+/// // A small acyclic graph with 0 as its source.
+/// let edges = [(0, 1, 2), (0, 2, 5), (1, 2, 1), (1, 3, 6), (2, 3, 2)];
+/// let successors = |&n: &u8| edges.iter().filter(move |e| e.0 == n).map(|e| (e.1, e.2));
+///
+/// let shortest = semiring_paths(&0, |n| successors(n).map(|(m, c)| (m, Tropical(Some(c)))));
+/// assert_eq!(shortest[&3], Tropical(Some(5)));
+///
+/// let count = semiring_paths(&0, |n| successors(n).map(|(m, _)| (m, Counting(1))));
+/// assert_eq!(count[&3], Counting(3));
+///
+/// // Edges with a cost of 1 are reliable, other ones are used half of the time.
+/// let probable = semiring_paths(&0, |n| {
+///     successors(n).map(|(m, c)| (m, Viterbi(if c == 1 { 1.0 } else { 0.5 })))
+/// });
+/// assert_eq!(probable[&3], Viterbi(0.25));
+/// ```
+#[must_use]
+#[expect(clippy::missing_panics_doc)]
+pub fn semiring_paths<N, S, FN, IN>(start: &N, mut successors: FN) -> FxHashMap<N, S>
+where
+    N: Eq + Hash + Clone,
+    S: Semiring,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, S)>,
+{
+    // For every node, its current weight and the weight not yet propagated to its
+    // successors.
+    let mut weights = FxIndexMap::default();
+    weights.insert(start.clone(), (S::one(), S::one()));
+    let mut to_see = VecDeque::from([0]);
+    let mut queued = vec![true];
+    while let Some(index) = to_see.pop_front() {
+        queued[index] = false;
+        let (node, (_, residual)) = weights.get_index_mut(index).unwrap(); // Cannot fail
+        let residual = std::mem::replace(residual, S::zero());
+        let node = node.clone();
+        for (next, weight) in successors(&node) {
+            let delta = residual.times(&weight);
+            let entry = weights.entry(next);
+            let next_index = entry.index();
+            if next_index == queued.len() {
+                queued.push(false);
+            }
+            let (total, pending) = entry.or_insert_with(|| (S::zero(), S::zero()));
+            let new_total = total.plus(&delta);
+            if new_total != *total {
+                *total = new_total;
+                *pending = pending.plus(&delta);
+                if !queued[next_index] {
+                    queued[next_index] = true;
+                    to_see.push_back(next_index);
+                }
+            }
+        }
+    }
+    weights
+        .into_iter()
+        .filter(|(_, (total, _))| *total != S::zero())
+        .map(|(node, (total, _))| (node, total))
+        .collect()
+}
@@ -0,0 +1,157 @@
+//! Find a shortest cycle in a directed graph, whose length is the
+//! [girth](https://en.wikipedia.org/wiki/Girth_(graph_theory)) of the graph.
+
+use crate::FxIndexSet;
+use num_traits::Zero;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Explore the graph from `nodes`, and return the nodes found along with the list of
+/// successors of every node, given by their index. Only the cheapest edge is kept when
+/// several edges link the same nodes.
+pub(crate) fn index_graph<N, C, FN, IN>(
+    nodes: &[N],
+    mut successors: FN,
+) -> (FxIndexSet<N>, Vec<Vec<(usize, C)>>)
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut indices = nodes.iter().cloned().collect::<FxIndexSet<_>>();
+    let mut adjacency = Vec::new();
+    while let Some(node) = indices.get_index(adjacency.len()) {
+        let mut next = successors(&node.clone())
+            .into_iter()
+            .map(|(n, c)| (indices.insert_full(n).0, c))
+            .collect::<Vec<_>>();
+        next.sort_unstable();
+        next.dedup_by_key(|&mut (n, _)| n);
+        adjacency.push(next);
+    }
+    (indices, adjacency)
+}
+
+/// Find a shortest cycle in a weighted directed graph.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `successors` are
+///   also taken into account.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor. Costs must be non-negative.
+///
+/// The cycle is returned along with its total cost, starting with its node which comes
+/// first in the exploration order, and without repeating this node at the end. A node
+/// which is its own successor forms a cycle of one node. `None` is returned if the
+/// graph is acyclic.
+///
+/// A Dijkstra search is run from every node, only going through nodes coming later in
+/// the exploration order, and stops as soon as it cannot improve on the best cycle found
+/// so far.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::shortest_cycle;
+///
+/// let successors = |&n: &u8| match n {
+///     0 => vec![(1, 1)],
+///     1 => vec![(2, 1), (3, 5)],
+///     2 => vec![(0, 10), (3, 1)],
+///     3 => vec![(1, 2)],
+///     _ => vec![],
+/// };
+/// assert_eq!(shortest_cycle(&[0], successors), Some((vec![1, 2, 3], 4)));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn shortest_cycle<N, C, FN, IN>(nodes: &[N], successors: FN) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (indices, adjacency) = index_graph(nodes, successors);
+    let mut best: Option<(usize, usize, C)> = None;
+    let mut parents = vec![usize::MAX; adjacency.len()];
+    let mut costs = vec![None; adjacency.len()];
+    let mut best_parents = Vec::new();
+    for start in 0..adjacency.len() {
+        let mut found = false;
+        costs[start..].fill(None);
+        costs[start] = Some(Zero::zero());
+        let mut to_see = BinaryHeap::from([Reverse((C::zero(), start))]);
+        while let Some(Reverse((cost, node))) = to_see.pop() {
+            if best.is_some_and(|(_, _, c)| cost >= c) {
+                break;
+            }
+            if costs[node].is_some_and(|c| cost > c) {
+                continue;
+            }
+            for &(next, edge) in &adjacency[node] {
+                let new_cost = cost + edge;
+                if next == start {
+                    if best.is_none_or(|(_, _, c)| new_cost < c) {
+                        best = Some((start, node, new_cost));
+                        found = true;
+                    }
+                } else if next > start && costs[next].is_none_or(|c| new_cost < c) {
+                    costs[next] = Some(new_cost);
+                    parents[next] = node;
+                    to_see.push(Reverse((new_cost, next)));
+                }
+            }
+        }
+        if found {
+            best_parents.clone_from(&parents);
+        }
+    }
+    best.map(|(start, last, cost)| {
+        let mut cycle = vec![last];
+        while *cycle.last().unwrap() != start {
+            cycle.push(best_parents[*cycle.last().unwrap()]);
+        }
+        let cycle = cycle
+            .into_iter()
+            .rev()
+            .map(|i| indices[i].clone())
+            .collect();
+        (cycle, cost)
+    })
+}
+
+/// Find a shortest cycle in an unweighted directed graph, whose length is the
+/// [girth](https://en.wikipedia.org/wiki/Girth_(graph_theory)) of the graph.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `successors` are
+///   also taken into account.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The cycle is returned along with its number of edges, as described in
+/// [`shortest_cycle`]. `None` is returned if the graph is acyclic.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::girth;
+///
+/// // On a ring of 10 nodes with shortcuts from every multiple of 3 to the
+/// // next multiple of 3, the shortest cycle has 4 nodes.
+/// let successors = |&n: &u8| {
+///     let mut next = vec![(n + 1) % 10];
+///     if n % 3 == 0 {
+///         next.push((n + 3) % 10);
+///     }
+///     next
+/// };
+/// assert_eq!(girth(&[0], successors), Some((vec![0, 3, 6, 9], 4)));
+/// ```
+pub fn girth<N, FN, IN>(nodes: &[N], mut successors: FN) -> Option<(Vec<N>, usize)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    shortest_cycle(nodes, |n| successors(n).into_iter().map(|n| (n, 1)))
+}
@@ -0,0 +1,121 @@
+//! A tree of shortest paths from a start node to every reachable node, as built by
+//! [`dijkstra_tree`](super::dijkstra::dijkstra_tree) or [`bfs_tree`](super::bfs::bfs_tree).
+
+use super::{ParentIndex, from_parent, reverse_path};
+use crate::FxIndexMap;
+use std::hash::Hash;
+use std::iter::Map;
+
+/// Shortest paths from a start node to every node reachable from it, along with their
+/// costs.
+///
+/// Every node but the start one is associated with its parent on a shortest path
+/// from the start node, so that the tree can answer queries about any node without
+/// having to run a new search.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_tree;
+///
+/// // Every node from 1 to 4 leads to its double and the value after it for a cost of 10.
+/// let tree = dijkstra_tree(&1, |&n: &u32| if n <= 4 { vec![(n * 2, 10), (n * 2 + 1, 10)] } else { vec![] });
+/// assert_eq!(tree.start(), &1);
+/// assert_eq!(tree.cost_to(&9), Some(30));
+/// assert_eq!(tree.parent(&9), Some(&4));
+/// assert_eq!(tree.path_to(&9), Some(vec![1, 2, 4, 9]));
+/// assert_eq!(tree.cost_to(&10), None);
+/// assert_eq!(tree.iter().filter(|&(_, cost)| cost <= 20).count(), 7);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShortestPathTree<N, C> {
+    // Every node is associated with the index of its parent, `NO_PARENT` for the
+    // start node, and with its cost.
+    parents: FxIndexMap<N, (ParentIndex, C)>,
+}
+
+impl<N, C> ShortestPathTree<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    /// Build a tree from a map whose first entry is the start node.
+    pub(crate) const fn from_parents(parents: FxIndexMap<N, (ParentIndex, C)>) -> Self {
+        Self { parents }
+    }
+
+    /// The start node of the tree.
+    ///
+    /// # Panics
+    ///
+    /// This function never panics, as the tree always contains its start node.
+    #[must_use]
+    pub fn start(&self) -> &N {
+        self.parents.get_index(0).unwrap().0
+    }
+
+    /// The number of nodes in the tree, including the start node.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Check whether the tree is empty, which is never the case since it contains
+    /// at least its start node.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Check whether `node` is reachable from the start node.
+    #[must_use]
+    pub fn contains(&self, node: &N) -> bool {
+        self.parents.contains_key(node)
+    }
+
+    /// The cost of a shortest path from the start node to `node`, or `None` if
+    /// `node` is not reachable.
+    #[must_use]
+    pub fn cost_to(&self, node: &N) -> Option<C> {
+        self.parents.get(node).map(|&(_, cost)| cost)
+    }
+
+    /// The node preceding `node` on a shortest path from the start node, or `None`
+    /// if `node` is the start node or is not reachable.
+    #[must_use]
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        let &(parent, _) = self.parents.get(node)?;
+        self.parents
+            .get_index(from_parent(parent))
+            .map(|(parent, _)| parent)
+    }
+
+    /// A shortest path from the start node to `node`, including both ends, or `None`
+    /// if `node` is not reachable.
+    #[must_use]
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let index = self.parents.get_index_of(node)?;
+        Some(reverse_path(&self.parents, |&(p, _)| from_parent(p), index))
+    }
+
+    /// Iterate over the nodes of the tree along with the cost of reaching them, in
+    /// the order in which they have been discovered, starting with the start node.
+    pub fn iter(&self) -> ShortestPathTreeIter<'_, N, C> {
+        self.into_iter()
+    }
+}
+
+/// Iterator over the nodes of a [`ShortestPathTree`] along with their costs.
+pub type ShortestPathTreeIter<'a, N, C> = Map<
+    indexmap::map::Iter<'a, N, (ParentIndex, C)>,
+    fn((&'a N, &'a (ParentIndex, C))) -> (&'a N, C),
+>;
+
+impl<'a, N, C: Copy> IntoIterator for &'a ShortestPathTree<N, C> {
+    type Item = (&'a N, C);
+    type IntoIter = ShortestPathTreeIter<'a, N, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parents.iter().map(|(node, &(_, cost))| (node, cost))
+    }
+}
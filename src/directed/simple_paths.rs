@@ -0,0 +1,135 @@
+//! Enumerate all the [simple paths](https://en.wikipedia.org/wiki/Path_(graph_theory))
+//! between two nodes, that is the paths which never go through the same node twice.
+
+use rustc_hash::FxHashSet;
+use std::hash::Hash;
+
+/// Enumerate lazily all the simple paths from `start` to a node for which `success`
+/// returns `true`.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node.
+/// - `success` checks whether the goal has been reached.
+/// - `max_len`, if given, is the maximum length of the paths, in number of edges.
+/// - `max_count`, if given, is the maximum number of paths to return.
+///
+/// A path stops as soon as it reaches a goal, so goals only appear at the end of the
+/// returned paths. Paths are returned in depth-first order, not by increasing length:
+/// use [`yen`](super::yen::yen) to get the shortest ones first.
+///
+/// The number of simple paths can grow exponentially with the size of the graph, which
+/// is what `max_len` and `max_count` protect against. Memory usage is proportional to
+/// the length of the longest path explored.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::all_simple_paths;
+///
+/// // A diamond from 0 to 3 with an extra edge from 1 to 2, and a loop back from 2 to 0.
+/// let successors = |&n: &u8| match n {
+///     0 => vec![1, 2],
+///     1 => vec![2, 3],
+///     2 => vec![0, 3],
+///     _ => vec![],
+/// };
+/// let paths = all_simple_paths(&0, successors, |&n| n == 3, None, None).collect::<Vec<_>>();
+/// assert_eq!(paths, vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 3]]);
+///
+/// let paths = all_simple_paths(&0, successors, |&n| n == 3, Some(2), None).collect::<Vec<_>>();
+/// assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+/// ```
+pub fn all_simple_paths<N, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    success: FS,
+    max_len: Option<usize>,
+    max_count: Option<usize>,
+) -> AllSimplePaths<N, FN, IN, FS>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    AllSimplePaths {
+        path: vec![start.clone()],
+        on_path: FxHashSet::from_iter([start.clone()]),
+        stack: Vec::new(),
+        started: false,
+        max_len: max_len.unwrap_or(usize::MAX),
+        remaining: max_count.unwrap_or(usize::MAX),
+        successors,
+        success,
+    }
+}
+
+/// Iterator over the simple paths, returned by [`all_simple_paths`].
+pub struct AllSimplePaths<N, FN, IN, FS>
+where
+    IN: IntoIterator,
+{
+    // Current path, whose last node is the one being explored.
+    path: Vec<N>,
+    on_path: FxHashSet<N>,
+    // Successors not yet explored for every node of the current path.
+    stack: Vec<IN::IntoIter>,
+    started: bool,
+    max_len: usize,
+    remaining: usize,
+    successors: FN,
+    success: FS,
+}
+
+impl<N, FN, IN, FS> Iterator for AllSimplePaths<N, FN, IN, FS>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            if (self.success)(&self.path[0]) {
+                self.remaining -= 1;
+                return Some(self.path.clone());
+            }
+            if self.max_len > 0 {
+                self.stack
+                    .push((self.successors)(&self.path[0]).into_iter());
+            }
+        }
+        while let Some(successors) = self.stack.last_mut() {
+            let Some(node) = successors.next() else {
+                self.stack.pop();
+                if let Some(node) = self.path.pop() {
+                    self.on_path.remove(&node);
+                }
+                continue;
+            };
+            if self.on_path.contains(&node) {
+                continue;
+            }
+            if (self.success)(&node) {
+                let mut path = self.path.clone();
+                path.push(node);
+                self.remaining -= 1;
+                return Some(path);
+            }
+            // The new node is at depth `self.path.len()`, and can only be extended if
+            // this is smaller than the maximum length.
+            if self.path.len() < self.max_len {
+                self.stack.push((self.successors)(&node).into_iter());
+                self.on_path.insert(node.clone());
+                self.path.push(node);
+            }
+        }
+        None
+    }
+}
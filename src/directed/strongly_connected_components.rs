@@ -5,49 +5,51 @@
 //! algorithm](https://en.wikipedia.org/wiki/Path-based_strong_component_algorithm)
 //! is used.
 
+use rustc_hash::FxHasher;
 use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 
-struct Params<N, FN>
+struct Params<N, FN, H>
 where
     N: Hash + Eq,
 {
-    preorders: HashMap<N, Option<usize>>,
+    preorders: HashMap<N, Option<usize>, H>,
     c: usize,
     successors: FN,
     p: Vec<N>,
     s: Vec<N>,
     scc: Vec<Vec<N>>,
-    scca: HashSet<N>,
+    scca: HashSet<N, H>,
 }
 
-impl<N, FN, IN> Params<N, FN>
+impl<N, FN, IN, H> Params<N, FN, H>
 where
     N: Clone + Hash + Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+    H: BuildHasher + Clone,
 {
-    fn new(nodes: &[N], successors: FN) -> Self {
+    fn new(nodes: &[N], successors: FN, hasher: H) -> Self {
+        let mut preorders = HashMap::with_capacity_and_hasher(nodes.len(), hasher.clone());
+        preorders.extend(nodes.iter().map(|n| (n.clone(), None)));
         Self {
-            preorders: nodes
-                .iter()
-                .map(|n| (n.clone(), None))
-                .collect::<HashMap<N, Option<usize>>>(),
+            preorders,
             c: 0,
             successors,
             p: Vec::new(),
             s: Vec::new(),
             scc: Vec::new(),
-            scca: HashSet::new(),
+            scca: HashSet::with_hasher(hasher),
         }
     }
 }
 
-fn recurse_onto<N, FN, IN>(v: &N, params: &mut Params<N, FN>)
+fn recurse_onto<N, FN, IN, H>(v: &N, params: &mut Params<N, FN, H>)
 where
     N: Clone + Hash + Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+    H: BuildHasher,
 {
     params.preorders.insert(v.clone(), Some(params.c));
     params.c += 1;
@@ -92,7 +94,33 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut params = Params::new(&[], successors);
+    strongly_connected_components_from_with_hasher(
+        start,
+        successors,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Partition nodes reachable from a starting point into strongly connected components
+/// using a custom hasher.
+///
+/// - `start` is the node we want to explore the graph from.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The function returns a list of strongly connected components sets. It will contain
+/// at least one component (the one containing the `start` node).
+pub fn strongly_connected_components_from_with_hasher<N, FN, IN, H>(
+    start: &N,
+    successors: FN,
+    hasher: H,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher + Clone,
+{
+    let mut params = Params::new(&[], successors, hasher);
     recurse_onto(start, &mut params);
     params.scc
 }
@@ -104,15 +132,40 @@ where
 ///
 /// The function returns the strongly connected component containing the node,
 /// which is guaranteed to contain at least `node`.
-#[expect(clippy::missing_panics_doc)]
 pub fn strongly_connected_component<N, FN, IN>(node: &N, successors: FN) -> Vec<N>
 where
     N: Clone + Hash + Eq,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+{
+    strongly_connected_component_with_hasher(
+        node,
+        successors,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Compute the strongly connected component containing a given node using a custom hasher.
+///
+/// - `node` is the node we want the strongly connected component for.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The function returns the strongly connected component containing the node,
+/// which is guaranteed to contain at least `node`.
+#[expect(clippy::missing_panics_doc)]
+pub fn strongly_connected_component_with_hasher<N, FN, IN, H>(
+    node: &N,
+    successors: FN,
+    hasher: H,
+) -> Vec<N>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher + Clone,
 {
     // The unwrap() cannot fail as there will always be at least one group.
-    strongly_connected_components_from(node, successors)
+    strongly_connected_components_from_with_hasher(node, successors, hasher)
         .pop()
         .unwrap()
 }
@@ -129,7 +182,31 @@ where
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut params = Params::new(nodes, successors);
+    strongly_connected_components_with_hasher(
+        nodes,
+        successors,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Partition all strongly connected components in a graph using a custom hasher.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node.
+///
+/// The function returns a list of strongly connected components sets.
+pub fn strongly_connected_components_with_hasher<N, FN, IN, H>(
+    nodes: &[N],
+    successors: FN,
+    hasher: H,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher + Clone,
+{
+    let mut params = Params::new(nodes, successors, hasher);
     while let Some(node) = params.preorders.keys().find(|_| true).cloned() {
         recurse_onto(&node, &mut params);
     }
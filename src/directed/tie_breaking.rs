@@ -0,0 +1,188 @@
+//! Break ties between equally good paths randomly but reproducibly, so that several
+//! agents searching the same graph with different seeds spread across them.
+
+use num_traits::Zero;
+use rustc_hash::FxBuildHasher;
+use std::hash::{BuildHasher, Hash};
+use std::ops::Add;
+
+/// Mix the bits of `state`, as done by the `SplitMix64` generator.
+const fn mix(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Derive a random value from `seed` and `value`. The hasher has no random seed, so
+/// the result only depends on them.
+fn draw<T: Hash>(seed: u64, value: &T) -> u64 {
+    mix(seed ^ mix(FxBuildHasher.hash_one(value)))
+}
+
+/// Shuffle `items` in place using the `SplitMix64` generator starting at `state`.
+#[expect(clippy::cast_possible_truncation)]
+fn shuffle<T>(items: &mut [T], mut state: u64) {
+    for i in (1..items.len()).rev() {
+        state = mix(state);
+        // The result is at most `i`, and thus fits in an `usize`.
+        let j = ((u128::from(state) * (i as u128 + 1)) >> 64) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// Wrap a successors function so that the successors of every node are returned in a
+/// random order determined by `seed` and the node.
+///
+/// Breadth-first searches, as well as the searches using a priority queue when the
+/// `deterministic` feature is enabled, favour the successors given first among those
+/// reached at the same depth or cost: shuffling them makes the search return one of
+/// the equally good paths at random. The order only depends on `seed`, on the node and
+/// on its successors, so that the same seed always gives the same results, even across
+/// several searches. Searches with different seeds, for example
+/// one per agent, tend to return different paths.
+///
+/// The successors may be nodes, or pairs of a node and a cost.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{bfs, shuffled_successors};
+///
+/// // Move right or down on a 5×5 board: every path to the opposite corner is a
+/// // shortest one.
+/// let successors = |&(x, y): &(u32, u32)| {
+///     [(x + 1, y), (x, y + 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| x < 5 && y < 5)
+///         .collect::<Vec<_>>()
+/// };
+/// let path = |seed| bfs(&(0, 0), shuffled_successors(seed, successors), |&n| n == (4, 4));
+/// assert_eq!(path(42), path(42));
+/// assert_eq!(path(42).unwrap().len(), 9);
+/// assert!((0..10).any(|seed| path(seed) != path(42)));
+/// ```
+pub fn shuffled_successors<N, FN, IN>(
+    seed: u64,
+    mut successors: FN,
+) -> impl FnMut(&N) -> Vec<IN::Item>
+where
+    N: Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator,
+{
+    move |node| {
+        let mut successors = successors(node).into_iter().collect::<Vec<_>>();
+        shuffle(&mut successors, draw(seed, node));
+        successors
+    }
+}
+
+/// A cost made of the original cost of a path and of a random tie-breaking value,
+/// compared lexicographically.
+///
+/// Adding random tie-breaking values to the edges of a graph does not change the
+/// shortest paths for the original costs, but makes the searches choose among them,
+/// as well as among nodes of equal priority in their queue, according to those values.
+/// [`tie_broken_successors`] builds such costs from a seed.
+///
+/// Tie-breaking values are added using saturating arithmetic: they are drawn below
+/// 2<sup>32</sup>, so that they cannot saturate before a path has 2<sup>32</sup>
+/// edges.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TieBroken<C> {
+    /// The original cost.
+    pub cost: C,
+    /// The tie-breaking value, only used to compare equal costs.
+    pub tie: u64,
+}
+
+impl<C> TieBroken<C> {
+    /// Build a cost with a null tie-breaking value, for example to turn an admissible
+    /// heuristic for the original costs into one for the tie-broken costs.
+    #[must_use]
+    pub const fn new(cost: C) -> Self {
+        Self { cost, tie: 0 }
+    }
+}
+
+impl<C: Add<Output = C>> Add for TieBroken<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            cost: self.cost + rhs.cost,
+            tie: self.tie.saturating_add(rhs.tie),
+        }
+    }
+}
+
+impl<C: Zero> Zero for TieBroken<C> {
+    fn zero() -> Self {
+        Self::new(C::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.cost.is_zero() && self.tie == 0
+    }
+}
+
+/// Wrap a weighted successors function so that the cost of every edge is given a
+/// random tie-breaking value determined by `seed` and the nodes at both ends of the
+/// edge, and shuffle the successors as [`shuffled_successors`] does.
+///
+/// Searches using the resulting [`TieBroken`] costs return a path whose original cost
+/// is minimal, chosen at random among the paths of minimal cost, and break ties
+/// between nodes of equal priority randomly as well. The same seed always gives the
+/// same results. A heuristic `h` for the original costs can be used as
+/// `|n| TieBroken::new(h(n))`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, tie_broken_successors, TieBroken};
+///
+/// // Move in four directions on a 5×5 board, where every move costs 2.
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+///         .map(|n| (n, 2))
+///         .collect::<Vec<_>>()
+/// };
+/// let path = |seed| {
+///     astar(
+///         &(0, 0),
+///         tie_broken_successors(seed, successors),
+///         |&(x, y)| TieBroken::new(2 * (8 - x - y)),
+///         |&n| n == (4, 4),
+///     )
+///     .unwrap()
+/// };
+/// let (first, cost) = path(1);
+/// assert_eq!(cost.cost, 16);
+/// assert_eq!(path(1).0, first);
+/// assert!((2..10).any(|seed| path(seed).0 != first));
+/// ```
+pub fn tie_broken_successors<N, C, FN, IN>(
+    seed: u64,
+    mut successors: FN,
+) -> impl FnMut(&N) -> Vec<(N, TieBroken<C>)>
+where
+    N: Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    move |node| {
+        let from = FxBuildHasher.hash_one(node);
+        let mut successors = successors(node)
+            .into_iter()
+            .map(|(successor, cost)| {
+                let tie = draw(seed, &(from, &successor)) >> 32;
+                (successor, TieBroken { cost, tie })
+            })
+            .collect::<Vec<_>>();
+        shuffle(&mut successors, draw(seed, node));
+        successors
+    }
+}
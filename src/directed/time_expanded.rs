@@ -0,0 +1,121 @@
+//! Build successor functions for time-expanded graphs, whose nodes are
+//! `(state, time)` pairs, in order to search for schedules over time.
+
+/// Turn a time-dependent successor function into a successor function over
+/// `(state, time)` pairs, usable with [`dijkstra`](super::dijkstra::dijkstra),
+/// [`astar`](super::astar::astar) and the other weighted search algorithms.
+///
+/// - `successors` returns, for a given state and time, a list of actions, each one
+///   given as the next state, the duration of the action, and its cost.
+/// - `wait_cost` is the cost of waiting for one time step without changing state, or
+///   `None` if waiting is not allowed.
+/// - `horizon` is the latest time that may be reached. Actions ending after it are
+///   discarded, which keeps the graph finite.
+///
+/// Since the time is part of the nodes, the same state can be visited at different
+/// times, and actions may depend on the time at which they start, for example to
+/// model timetables or resources which are only available during some periods.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, time_expanded_successors};
+///
+/// // A ferry leaves the harbour at times 3 and 8 and takes 2 time units to reach
+/// // the island. Walking to the bridge takes 4 time units, and crossing it 5.
+/// let successors = time_expanded_successors(
+///     |&place: &&str, time| match place {
+///         "harbour" if time == 3 || time == 8 => vec![("island", 2, 2)],
+///         "home" => vec![("harbour", 1, 1), ("bridge", 4, 4)],
+///         "bridge" => vec![("island", 5, 5)],
+///         _ => vec![],
+///     },
+///     Some(1),
+///     20,
+/// );
+/// let (path, cost) = dijkstra(&("home", 0), successors, |&(place, _)| place == "island").unwrap();
+/// // Waiting for the first ferry is cheaper than walking through the bridge.
+/// assert_eq!(path.last(), Some(&("island", 5)));
+/// assert_eq!(cost, 5);
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn time_expanded_successors<N, C, FN, IN>(
+    mut successors: FN,
+    wait_cost: Option<C>,
+    horizon: usize,
+) -> impl FnMut(&(N, usize)) -> Vec<((N, usize), C)>
+where
+    N: Clone,
+    C: Copy,
+    FN: FnMut(&N, usize) -> IN,
+    IN: IntoIterator<Item = (N, usize, C)>,
+{
+    move |(state, time)| {
+        let mut next = successors(state, *time)
+            .into_iter()
+            .filter_map(|(s, duration, cost)| {
+                time.checked_add(duration)
+                    .filter(|&t| t <= horizon)
+                    .map(|t| ((s, t), cost))
+            })
+            .collect::<Vec<_>>();
+        if let Some(cost) = wait_cost {
+            if *time < horizon {
+                next.push(((state.clone(), time + 1), cost));
+            }
+        }
+        next
+    }
+}
+
+/// Turn a time-dependent unweighted successor function into a successor function over
+/// `(state, time)` pairs, usable with [`bfs`](super::bfs::bfs) and the other unweighted
+/// search algorithms. Every action, including waiting, takes one time step.
+///
+/// - `successors` returns the states reachable in one time step from a given state at
+///   a given time.
+/// - `wait` tells whether waiting without changing state is allowed.
+/// - `horizon` is the latest time that may be reached.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{bfs, time_expanded_successors_unweighted};
+///
+/// // A door at position 3 on a line is only open at even times.
+/// let successors = time_expanded_successors_unweighted(
+///     |&x: &u32, time| {
+///         [x.wrapping_sub(1), x + 1]
+///             .into_iter()
+///             .filter(move |&n| n <= 5 && (n != 3 || (time + 1) % 2 == 0))
+///     },
+///     true,
+///     20,
+/// );
+/// let path = bfs(&(0, 0), successors, |&(x, _)| x == 5).unwrap();
+/// assert_eq!(path.last(), Some(&(5, 6)));
+/// ```
+pub fn time_expanded_successors_unweighted<N, FN, IN>(
+    mut successors: FN,
+    wait: bool,
+    horizon: usize,
+) -> impl FnMut(&(N, usize)) -> Vec<(N, usize)>
+where
+    N: Clone,
+    FN: FnMut(&N, usize) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    move |(state, time)| {
+        if *time >= horizon {
+            return Vec::new();
+        }
+        let mut next = successors(state, *time)
+            .into_iter()
+            .map(|s| (s, time + 1))
+            .collect::<Vec<_>>();
+        if wait {
+            next.push((state.clone(), time + 1));
+        }
+        next
+    }
+}
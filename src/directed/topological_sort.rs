@@ -1,7 +1,9 @@
 //! Find a topological order in a directed graph if one exists.
 
+use num_traits::Zero;
+use rustc_hash::FxHasher;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::hash::Hash;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 use std::mem;
 
 /// Find a topological order in a directed graph if one exists.
@@ -73,16 +75,47 @@ use std::mem;
 /// set.sort();
 /// assert_eq!(set, vec![7, 8, 9]);
 /// ```
-pub fn topological_sort<N, FN, IN>(roots: &[N], mut successors: FN) -> Result<Vec<N>, N>
+pub fn topological_sort<N, FN, IN>(roots: &[N], successors: FN) -> Result<Vec<N>, N>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
 {
-    let mut marked = HashSet::with_capacity(roots.len());
-    let mut temp = HashSet::new();
+    topological_sort_with_hasher(roots, successors, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Find a topological order in a directed graph if one exists, using a custom hasher.
+///
+/// - `roots` is a collection of nodes that ought to be explored.
+/// - `successors` returns a list of successors for a given node, including possibly
+///   nodes that were not present in `roots`.
+///
+/// The function returns an acceptable topological order of nodes given as roots or
+/// discovered, or an error if a cycle is detected.
+///
+/// # Errors
+///
+/// If a cycle is found, `Err(n)` is returned with `n` being an arbitrary node involved in a cycle.
+pub fn topological_sort_with_hasher<N, FN, IN, H>(
+    roots: &[N],
+    mut successors: FN,
+    hasher: H,
+) -> Result<Vec<N>, N>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    H: BuildHasher + Clone,
+{
+    let mut marked = HashSet::with_capacity_and_hasher(roots.len(), hasher.clone());
+    let mut temp = HashSet::with_hasher(hasher.clone());
     let mut sorted = VecDeque::with_capacity(roots.len());
-    let mut roots: HashSet<N> = roots.iter().cloned().collect::<HashSet<_>>();
+    let mut roots: HashSet<N, H> = {
+        let mut set = HashSet::with_capacity_and_hasher(roots.len(), hasher);
+        set.extend(roots.iter().cloned());
+        set
+    };
+
     while let Some(node) = roots.iter().next().cloned() {
         temp.clear();
         visit(
@@ -97,18 +130,19 @@ where
     Ok(sorted.into_iter().collect())
 }
 
-fn visit<N, FN, IN>(
+fn visit<N, FN, IN, H>(
     node: &N,
     successors: &mut FN,
-    unmarked: &mut HashSet<N>,
-    marked: &mut HashSet<N>,
-    temp: &mut HashSet<N>,
+    unmarked: &mut HashSet<N, H>,
+    marked: &mut HashSet<N, H>,
+    temp: &mut HashSet<N, H>,
     sorted: &mut VecDeque<N>,
 ) -> Result<(), N>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+    H: BuildHasher,
 {
     unmarked.remove(node);
     if marked.contains(node) {
@@ -153,23 +187,57 @@ where
 /// [`strongly_connected_components`](super::strongly_connected_components::strongly_connected_components)
 /// function on the list of remaining nodes.
 #[expect(clippy::type_complexity)]
-#[expect(clippy::missing_panics_doc)]
 pub fn topological_sort_into_groups<N, FN, IN>(
+    nodes: &[N],
+    successors: FN,
+) -> Result<Vec<Vec<N>>, (Vec<Vec<N>>, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    topological_sort_into_groups_with_hasher(
+        nodes,
+        successors,
+        BuildHasherDefault::<FxHasher>::default(),
+    )
+}
+
+/// Topologically sort a directed graph into groups of independent nodes using a custom hasher.
+///
+/// - `nodes` is a collection of nodes.
+/// - `successors` returns a list of successors for a given node.
+///
+/// See [`topological_sort_into_groups`] for a description of the result.
+///
+/// # Errors
+///
+/// A tuple `(groups, remaining)` containing a (possibly empty) partial list of
+/// groups, and a list of remaining nodes that could not be grouped due to cycles.
+#[expect(clippy::type_complexity)]
+#[expect(clippy::missing_panics_doc)]
+pub fn topological_sort_into_groups_with_hasher<N, FN, IN, H>(
     nodes: &[N],
     mut successors: FN,
+    hasher: H,
 ) -> Result<Vec<Vec<N>>, (Vec<Vec<N>>, Vec<N>)>
 where
     N: Eq + Hash + Clone,
     FN: FnMut(&N) -> IN,
     IN: IntoIterator<Item = N>,
+    H: BuildHasher + Clone,
 {
     if nodes.is_empty() {
         return Ok(Vec::new());
     }
-    let mut succs_map = HashMap::<N, HashSet<N>>::with_capacity(nodes.len());
-    let mut preds_map = HashMap::<N, usize>::with_capacity(nodes.len());
+    let mut preds_map =
+        HashMap::<N, usize, H>::with_capacity_and_hasher(nodes.len(), hasher.clone());
+    let mut succs_map =
+        HashMap::<N, HashSet<N, H>, H>::with_capacity_and_hasher(nodes.len(), hasher);
     for node in nodes {
-        succs_map.insert(node.clone(), successors(node).into_iter().collect());
+        let mut succs = HashSet::with_hasher(succs_map.hasher().clone());
+        succs.extend(successors(node));
+        succs_map.insert(node.clone(), succs);
         preds_map.insert(node.clone(), 0);
     }
     for succs in succs_map.values() {
@@ -213,3 +281,362 @@ where
     groups.push(prev_group);
     Ok(groups)
 }
+
+/// Topologically sort a directed graph of tasks having durations into groups of
+/// tasks sharing the same earliest start time.
+///
+/// - `nodes` is a collection of nodes, which must be exhaustive.
+/// - `successors` returns the nodes depending on a given node, which can only start
+///   once this node is over.
+/// - `duration` returns the duration of a given node, which must be non-negative.
+///
+/// This function works like [`topological_sort_into_groups`], but rather than
+/// placing a node in the group following the one of its latest predecessor, it
+/// computes the earliest time at which the node can start, which is the latest time
+/// at which one of its predecessors is over, with nodes without predecessors starting
+/// at zero. Nodes sharing the same earliest start time are grouped together, and the
+/// groups are returned along with their start time in increasing order of start
+/// time. Within a group, nodes appear in the order in which they are given in
+/// `nodes`.
+///
+/// When nodes are run as soon as they can start, the whole graph is over once the
+/// latest group start time plus duration of its nodes is reached, which is the length
+/// of the critical path of the graph.
+///
+/// The function returns a collection of groups if there are no cycles in the
+/// graph and an error otherwise.
+///
+/// # Errors
+///
+/// A tuple `(groups, remaining)` containing a (possibly empty) partial list of
+/// groups of the nodes which do not depend on a cycle, and a list of remaining nodes
+/// that could not be grouped due to cycles.
+///
+/// # Panics
+///
+/// This function panics if `successors` returns a node which is not in `nodes`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::topological_sort_into_timed_groups;
+///
+/// // Building a house: the foundations (3 days) come first, then the walls (5 days)
+/// // and the plumbing (2 days), then the roof (4 days) once the walls are built and
+/// // the painting (1 day) once the walls and the plumbing are done.
+/// let tasks = ["foundations", "walls", "plumbing", "roof", "painting"];
+/// let groups = topological_sort_into_timed_groups(
+///     &tasks,
+///     |&task| match task {
+///         "foundations" => vec!["walls", "plumbing"],
+///         "walls" => vec!["roof", "painting"],
+///         "plumbing" => vec!["painting"],
+///         _ => vec![],
+///     },
+///     |&task| match task {
+///         "foundations" => 3,
+///         "walls" => 5,
+///         "plumbing" => 2,
+///         "roof" => 4,
+///         _ => 1,
+///     },
+/// );
+/// assert_eq!(
+///     groups,
+///     Ok(vec![
+///         (0, vec!["foundations"]),
+///         (3, vec!["walls", "plumbing"]),
+///         (8, vec!["roof", "painting"]),
+///     ])
+/// );
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn topological_sort_into_timed_groups<N, C, FN, IN, FD>(
+    nodes: &[N],
+    mut successors: FN,
+    mut duration: FD,
+) -> Result<Vec<(C, Vec<N>)>, (Vec<(C, Vec<N>)>, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FD: FnMut(&N) -> C,
+{
+    let mut indices = HashMap::<N, usize, BuildHasherDefault<FxHasher>>::with_capacity_and_hasher(
+        nodes.len(),
+        BuildHasherDefault::default(),
+    );
+    let mut unique = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        if !indices.contains_key(node) {
+            indices.insert(node.clone(), unique.len());
+            unique.push(node.clone());
+        }
+    }
+    let mut pending = vec![0usize; unique.len()];
+    let successors = unique
+        .iter()
+        .map(|node| {
+            successors(node)
+                .into_iter()
+                .map(|s| *indices.get(&s).expect("successor not found in nodes"))
+                .inspect(|&s| pending[s] += 1)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut starts = vec![C::zero(); unique.len()];
+    let mut ready = (0..unique.len())
+        .filter(|&i| pending[i] == 0)
+        .collect::<Vec<_>>();
+    let mut sorted = Vec::with_capacity(unique.len());
+    while let Some(i) = ready.pop() {
+        sorted.push(i);
+        let end = starts[i] + duration(&unique[i]);
+        for &s in &successors[i] {
+            starts[s] = starts[s].max(end);
+            pending[s] -= 1;
+            if pending[s] == 0 {
+                ready.push(s);
+            }
+        }
+    }
+    sorted.sort_unstable_by_key(|&i| (starts[i], i));
+    let groups = sorted
+        .chunk_by(|&a, &b| starts[a] == starts[b])
+        .map(|group| {
+            let nodes = group.iter().map(|&i| unique[i].clone()).collect();
+            (starts[group[0]], nodes)
+        })
+        .collect::<Vec<_>>();
+    if sorted.len() == unique.len() {
+        Ok(groups)
+    } else {
+        let remaining = (0..unique.len())
+            .filter(|&i| pending[i] > 0)
+            .map(|i| unique[i].clone())
+            .collect();
+        Err((groups, remaining))
+    }
+}
+
+/// A scheduler handing out the nodes of a directed acyclic graph once all their
+/// predecessors have been completed, for example to run dependent tasks in parallel.
+///
+/// Whereas [`topological_sort_into_groups`] waits for a whole group to be done
+/// before the next one can start, the scheduler makes a node available as soon as
+/// its own predecessors are completed. Nodes are handed out by
+/// [`next_ready`](Self::next_ready) and reported as done with
+/// [`complete`](Self::complete), possibly in a different order than they were
+/// handed out.
+///
+/// The scheduler itself is not shared between threads: it is typically owned by a
+/// coordinating thread, or protected by a mutex which is only held while getting
+/// a node or reporting a completion.
+///
+/// If the graph contains a cycle, the nodes of the cycle and the ones depending on
+/// them are never handed out, and the scheduler is [stalled](Self::is_stalled) once
+/// all the other nodes are completed.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::TopologicalScheduler;
+/// use std::sync::{mpsc, Mutex};
+///
+/// // Tasks 1 to 12, where a task depends on all its divisors.
+/// let tasks = (1..=12).collect::<Vec<u32>>();
+/// let scheduler = Mutex::new(TopologicalScheduler::new(&tasks, |&n| {
+///     (2..).map(move |k| k * n).take_while(|&m| m <= 12)
+/// }));
+/// let (done, results) = mpsc::channel();
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         let (scheduler, done) = (&scheduler, done.clone());
+///         s.spawn(move || {
+///             loop {
+///                 let next = {
+///                     let mut scheduler = scheduler.lock().unwrap();
+///                     if scheduler.is_finished() {
+///                         break;
+///                     }
+///                     scheduler.next_ready()
+///                 };
+///                 match next {
+///                     Some(task) => {
+///                         done.send(task).unwrap(); // Run the task
+///                         scheduler.lock().unwrap().complete(&task);
+///                     }
+///                     None => std::thread::yield_now(),
+///                 }
+///             }
+///         });
+///     }
+/// });
+/// drop(done);
+/// let order = results.iter().collect::<Vec<_>>();
+/// assert_eq!(order.len(), 12);
+/// assert_eq!(order[0], 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TopologicalScheduler<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize, BuildHasherDefault<FxHasher>>,
+    successors: Vec<Vec<usize>>,
+    // Number of predecessors of every node which have not been completed yet.
+    pending: Vec<usize>,
+    states: Vec<TaskState>,
+    ready: VecDeque<usize>,
+    running: usize,
+    completed: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TaskState {
+    Waiting,
+    Ready,
+    Running,
+    Completed,
+}
+
+impl<N: Eq + Hash + Clone> TopologicalScheduler<N> {
+    /// Create a scheduler for the given nodes.
+    ///
+    /// - `nodes` is a collection of nodes, which must be exhaustive.
+    /// - `successors` returns the nodes depending on a given node, which can only
+    ///   be handed out once this node has been completed.
+    ///
+    /// Nodes without predecessors are ready to be handed out in the order in which
+    /// they appear in `nodes`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `successors` returns a node which is not in `nodes`.
+    pub fn new<FN, IN>(nodes: &[N], mut successors: FN) -> Self
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+    {
+        let mut indices =
+            HashMap::with_capacity_and_hasher(nodes.len(), BuildHasherDefault::default());
+        let mut unique = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            if !indices.contains_key(node) {
+                indices.insert(node.clone(), unique.len());
+                unique.push(node.clone());
+            }
+        }
+        let mut pending = vec![0; unique.len()];
+        // The last node having each node as a successor, to ignore duplicate edges.
+        let mut last_predecessor = vec![usize::MAX; unique.len()];
+        let successors = unique
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                successors(node)
+                    .into_iter()
+                    .map(|s| *indices.get(&s).expect("successor not found in nodes"))
+                    .filter(|&s| {
+                        let new = last_predecessor[s] != i;
+                        last_predecessor[s] = i;
+                        new
+                    })
+                    .inspect(|&s| pending[s] += 1)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let ready = (0..unique.len())
+            .filter(|&i| pending[i] == 0)
+            .collect::<VecDeque<_>>();
+        let mut states = vec![TaskState::Waiting; unique.len()];
+        for &i in &ready {
+            states[i] = TaskState::Ready;
+        }
+        Self {
+            nodes: unique,
+            indices,
+            successors,
+            pending,
+            states,
+            ready,
+            running: 0,
+            completed: 0,
+        }
+    }
+
+    /// Hand out a node whose predecessors have all been completed, and mark it as
+    /// running. Return `None` if no node is ready at this time.
+    pub fn next_ready(&mut self) -> Option<N> {
+        let i = self.ready.pop_front()?;
+        self.states[i] = TaskState::Running;
+        self.running += 1;
+        Some(self.nodes[i].clone())
+    }
+
+    /// Hand out all the nodes which are ready at this time, and mark them as running.
+    pub fn take_ready(&mut self) -> Vec<N> {
+        std::iter::from_fn(|| self.next_ready()).collect()
+    }
+
+    /// Report that a node handed out by [`next_ready`](Self::next_ready) or
+    /// [`take_ready`](Self::take_ready) has been completed, making its successors
+    /// ready if they do not wait for another node.
+    ///
+    /// Return `false`, without doing anything, if `node` was not running.
+    pub fn complete(&mut self, node: &N) -> bool {
+        let Some(&i) = self.indices.get(node) else {
+            return false;
+        };
+        if self.states[i] != TaskState::Running {
+            return false;
+        }
+        self.states[i] = TaskState::Completed;
+        self.running -= 1;
+        self.completed += 1;
+        for &s in &self.successors[i] {
+            self.pending[s] -= 1;
+            if self.pending[s] == 0 {
+                self.states[s] = TaskState::Ready;
+                self.ready.push_back(s);
+            }
+        }
+        true
+    }
+
+    /// The number of nodes ready to be handed out.
+    #[must_use]
+    pub fn ready_len(&self) -> usize {
+        self.ready.len()
+    }
+
+    /// The number of nodes handed out and not completed yet.
+    #[must_use]
+    pub const fn running_len(&self) -> usize {
+        self.running
+    }
+
+    /// Check whether all the nodes have been completed.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.completed == self.nodes.len()
+    }
+
+    /// Check whether no progress can be made anymore although some nodes have not
+    /// been completed, which happens when the remaining nodes are part of a cycle
+    /// or depend on one.
+    #[must_use]
+    pub fn is_stalled(&self) -> bool {
+        self.ready.is_empty() && self.running == 0 && !self.is_finished()
+    }
+
+    /// The nodes which have not been handed out yet, in the order they were given.
+    #[must_use]
+    pub fn remaining(&self) -> Vec<N> {
+        self.nodes
+            .iter()
+            .zip(&self.states)
+            .filter(|&(_, &state)| matches!(state, TaskState::Waiting | TaskState::Ready))
+            .map(|(node, _)| node.clone())
+            .collect()
+    }
+}
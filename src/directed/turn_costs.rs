@@ -0,0 +1,262 @@
+//! Take turn costs and turn restrictions into account by searching an
+//! edge-based version of a node-based graph, or its line graph.
+
+use super::dijkstra::dijkstra;
+use num_traits::Zero;
+use std::hash::Hash;
+
+/// Turn a node-based successor function and a turn cost function into a successor
+/// function over the directed edges of the graph, usable with
+/// [`dijkstra`], [`astar`](super::astar::astar) and the other
+/// weighted search algorithms.
+///
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor.
+/// - `turn_cost` returns the cost of going through `via` when coming from `from` and
+///   going to `to`, or `None` if this turn is forbidden. Forbidding U-turns is done by
+///   returning `None` when `from` and `to` are equal.
+///
+/// Nodes of the edge-based graph are `(previous, current)` pairs, where `previous` is
+/// `None` at the start of the search. The cost of moving between two of them is the cost
+/// of the edge followed plus the cost of the turn made. [`dijkstra_with_turns`] wraps
+/// this function for the common case of a Dijkstra search.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, edge_based_successors};
+///
+/// // A square block of 3×3 intersections, where turning left costs 5.
+/// let successors = |&(x, y): &(i32, i32)| {
+///     [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///         .into_iter()
+///         .filter(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y))
+///         .map(|n| (n, 10))
+/// };
+/// let turn_cost = |a: &(i32, i32), b: &(i32, i32), c: &(i32, i32)| {
+///     let (d1, d2) = ((b.0 - a.0, b.1 - a.1), (c.0 - b.0, c.1 - b.1));
+///     match d1.0 * d2.1 - d1.1 * d2.0 {
+///         0 if d1 == d2 => Some(0),
+///         0 => None, // No U-turn
+///         z if z > 0 => Some(5),
+///         _ => Some(0),
+///     }
+/// };
+/// let (path, cost) = astar(
+///     &(None, (0, 0)),
+///     edge_based_successors(successors, turn_cost),
+///     |&(_, (x, y))| (2 - x).abs() * 10 + (2 - y).abs() * 10,
+///     |&(_, n)| n == (2, 2),
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 40);
+/// assert_eq!(path.last(), Some(&(Some((1, 2)), (2, 2))));
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn edge_based_successors<N, C, FN, IN, FT>(
+    mut successors: FN,
+    mut turn_cost: FT,
+) -> impl FnMut(&(Option<N>, N)) -> Vec<((Option<N>, N), C)>
+where
+    N: Clone,
+    C: Zero + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FT: FnMut(&N, &N, &N) -> Option<C>,
+{
+    move |(previous, current)| {
+        successors(current)
+            .into_iter()
+            .filter_map(|(next, cost)| {
+                let turn = match previous {
+                    Some(previous) => turn_cost(previous, current, &next)?,
+                    None => Zero::zero(),
+                };
+                Some(((Some(current.clone()), next), cost + turn))
+            })
+            .collect()
+    }
+}
+
+/// Turn a node-based successor function into a successor function over the directed
+/// edges of the graph, its [line graph](https://en.wikipedia.org/wiki/Line_graph), usable
+/// with [`bfs`](super::bfs::bfs), [`dfs`](super::dfs::dfs) and the other unweighted
+/// search algorithms.
+///
+/// - `successors` returns a list of successors for a given node.
+/// - `allowed` checks whether going through `via` when coming from `from` and going to
+///   `to` is allowed. Forbidding U-turns is done by returning `false` when `from` and
+///   `to` are equal.
+///
+/// Nodes of the line graph are `(previous, current)` pairs as in
+/// [`edge_based_successors`], where `previous` is `None` at the start of the search.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{bfs, line_graph_successors};
+///
+/// // A dead end at 3 must be left through a U-turn to reach 4.
+/// let successors = |&n: &u8| match n {
+///     0 => vec![1],
+///     1 => vec![0, 2],
+///     2 => vec![1, 3],
+///     3 => vec![2, 4],
+///     _ => vec![],
+/// };
+/// let path = bfs(
+///     &(None, 0),
+///     line_graph_successors(successors, |&from, _, &to| from != to),
+///     |&(_, n)| n == 4,
+/// )
+/// .unwrap();
+/// assert_eq!(path.last(), Some(&(Some(3), 4)));
+/// // Coming back from 1 to 0 is not allowed anymore.
+/// let reachable = bfs(
+///     &(None, 0),
+///     line_graph_successors(successors, |&from, _, &to| from != to),
+///     |&(previous, n)| previous == Some(1) && n == 0,
+/// );
+/// assert_eq!(reachable, None);
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn line_graph_successors<N, FN, IN, FA>(
+    mut successors: FN,
+    mut allowed: FA,
+) -> impl FnMut(&(Option<N>, N)) -> Vec<(Option<N>, N)>
+where
+    N: Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FA: FnMut(&N, &N, &N) -> bool,
+{
+    move |(previous, current)| {
+        successors(current)
+            .into_iter()
+            .filter(|next| {
+                previous
+                    .as_ref()
+                    .is_none_or(|previous| allowed(previous, current, next))
+            })
+            .map(|next| (Some(current.clone()), next))
+            .collect()
+    }
+}
+
+/// Turn a successor function returning labelled edges into a successor function over
+/// the labelled edges of the graph, usable with the unweighted search algorithms.
+///
+/// - `successors` returns a list of successors for a given node, along with the label
+///   of the edge leading to them, for example the bus line or the mode of transport
+///   used. Several edges with different labels can lead to the same successor.
+/// - `allowed` checks whether following the edge labelled `outgoing` from `via` is
+///   allowed after having reached `via` through the edge labelled `incoming`.
+///
+/// Nodes of the line graph are `(label, current)` pairs, where `label` is the label of
+/// the edge followed to reach `current`, or `None` at the start of the search. A label
+/// can include the node the edge comes from if `allowed` needs it.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{bfs, labelled_line_graph_successors};
+///
+/// // Stops served by bus lines 'A' and 'B', where changing line is only possible at
+/// // stop 2.
+/// let successors = |&n: &u8| match n {
+///     0 => vec![(1, 'A'), (3, 'B')],
+///     1 => vec![(2, 'A'), (4, 'A')],
+///     2 => vec![(5, 'B')],
+///     3 => vec![(4, 'B')],
+///     4 => vec![(5, 'A'), (6, 'B')],
+///     _ => vec![],
+/// };
+/// let path = bfs(
+///     &(None, 0),
+///     labelled_line_graph_successors(successors, |incoming, &via, outgoing| {
+///         incoming == outgoing || via == 2
+///     }),
+///     |&(_, n)| n == 6,
+/// )
+/// .unwrap();
+/// assert_eq!(path, vec![(None, 0), (Some('B'), 3), (Some('B'), 4), (Some('B'), 6)]);
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn labelled_line_graph_successors<N, L, FN, IN, FA>(
+    mut successors: FN,
+    mut allowed: FA,
+) -> impl FnMut(&(Option<L>, N)) -> Vec<(Option<L>, N)>
+where
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, L)>,
+    FA: FnMut(&L, &N, &L) -> bool,
+{
+    move |(incoming, current)| {
+        successors(current)
+            .into_iter()
+            .filter(|(_, outgoing)| {
+                incoming
+                    .as_ref()
+                    .is_none_or(|incoming| allowed(incoming, current, outgoing))
+            })
+            .map(|(next, outgoing)| (Some(outgoing), next))
+            .collect()
+    }
+}
+
+/// Compute a shortest path using the [Dijkstra search
+/// algorithm](https://en.wikipedia.org/wiki/Dijkstra's_algorithm), taking turn costs
+/// and turn restrictions into account.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor.
+/// - `turn_cost` returns the cost of going through `via` when coming from `from` and
+///   going to `to`, or `None` if this turn is forbidden.
+/// - `success` checks whether the goal has been reached.
+///
+/// The search takes place on the directed edges of the graph, as described in
+/// [`edge_based_successors`], so the returned path may go through the same node
+/// several times, for example when a forbidden left turn is replaced by three right
+/// turns around a block.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::dijkstra_with_turns;
+///
+/// // Node 1 is a crossroad where turning from 0 to 2 is forbidden. The detour goes
+/// // through 3 and comes back to 1.
+/// let successors = |&n: &u8| match n {
+///     0 => vec![(1, 1)],
+///     1 => vec![(2, 1), (3, 1)],
+///     3 => vec![(1, 1)],
+///     _ => vec![],
+/// };
+/// let turn_cost = |&from: &u8, _: &u8, &to: &u8| (from != 0 || to != 2).then_some(0);
+/// assert_eq!(
+///     dijkstra_with_turns(&0, successors, turn_cost, |&n| n == 2),
+///     Some((vec![0, 1, 3, 1, 2], 4))
+/// );
+/// ```
+pub fn dijkstra_with_turns<N, C, FN, IN, FT, FS>(
+    start: &N,
+    successors: FN,
+    turn_cost: FT,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FT: FnMut(&N, &N, &N) -> Option<C>,
+    FS: FnMut(&N) -> bool,
+{
+    dijkstra(
+        &(None, start.clone()),
+        edge_based_successors(successors, turn_cost),
+        |(_, node)| success(node),
+    )
+    .map(|(path, cost)| (path.into_iter().map(|(_, node)| node).collect(), cost))
+}
@@ -19,6 +19,8 @@ struct Path<N: Eq + Hash + Clone, C: Zero + Ord + Copy> {
     nodes: Vec<N>,
     /// The total cost of the path
     cost: C,
+    /// The order in which the path was found
+    index: usize,
 }
 
 impl<N, C> PartialOrd for Path<N, C>
@@ -37,10 +39,17 @@ where
     C: Zero + Ord + Copy,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Compare costs first, then amount of nodes
+        // Compare costs first, then amount of nodes, then the order in which paths
+        // were found if the `deterministic` feature is enabled
         let cmp = self.cost.cmp(&other.cost);
         match cmp {
-            Ordering::Equal => self.nodes.len().cmp(&other.nodes.len()),
+            Ordering::Equal => self.nodes.len().cmp(&other.nodes.len()).then_with(|| {
+                if cfg!(feature = "deterministic") {
+                    self.index.cmp(&other.index)
+                } else {
+                    Ordering::Equal
+                }
+            }),
             _ => cmp,
         }
     }
@@ -150,7 +159,11 @@ where
 
     let mut visited = HashSet::with_hasher(hasher.clone());
     // A vector containing our paths.
-    let mut routes = vec![Path { nodes: n, cost: c }];
+    let mut routes = vec![Path {
+        nodes: n,
+        cost: c,
+        index: 0,
+    }];
     // A min-heap to store our lowest-cost route candidate
     let mut k_routes = BinaryHeap::new();
     for ki in 0..(k - 1) {
@@ -158,80 +171,208 @@ where
             // We have no more routes to explore, or we have found enough.
             break;
         }
-        // Take the most recent route to explore new spurs.
-        let previous = &routes[ki].nodes;
-        // Iterate over every node except the sink node.
-        for i in 0..(previous.len() - 1) {
-            let spur_node = &previous[i];
-            let root_path = &previous[0..i];
-
-            let mut filtered_edges = HashSet::with_hasher(hasher.clone());
-            for path in &routes {
-                if path.nodes.len() > i + 1
-                    && &path.nodes[0..i] == root_path
-                    && &path.nodes[i] == spur_node
-                {
-                    filtered_edges.insert((&path.nodes[i], &path.nodes[i + 1]));
-                }
+        // Take the most recent route to explore new spurs, deviating from every
+        // node except the sink node.
+        for i in 0..(routes[ki].nodes.len() - 1) {
+            if let Some(nodes) = spur_path(&routes, ki, i, &mut successors, &mut success, &hasher) {
+                add_candidate(nodes, &mut visited, &mut k_routes, &mut successors);
             }
-            let filtered_nodes: HashSet<&N, H> = HashSet::from_iter(root_path);
-            // We are creating a new successor function that will not return the
-            // filtered edges and nodes that routes already used.
-            let mut filtered_successor = |n: &N| {
-                successors(n)
-                    .into_iter()
-                    .filter(|(n2, _)| {
-                        !filtered_nodes.contains(&n2) && !filtered_edges.contains(&(n, n2))
-                    })
-                    .collect::<Vec<_>>()
-            };
+        }
+        select_routes(&mut routes, &mut k_routes, k);
+    }
 
-            // Let us find the spur path from the spur node to the sink using.
-            if let Some((spur_path, _)) =
-                dijkstra_internal(spur_node, &mut filtered_successor, &mut success, hasher.clone())
-            {
-                let nodes: Vec<N> = root_path.iter().cloned().chain(spur_path).collect();
-                // If we have found the same path before, we will not add it.
-                if !visited.contains(&nodes) {
-                    // Since we don't know the root_path cost, we need to recalculate.
-                    let cost = make_cost(&nodes, &mut successors);
-                    let path = Path { nodes, cost };
-                    // Mark as visited
-                    visited.insert(path.nodes.clone());
-                    // Build a min-heap
-                    k_routes.push(Reverse(path));
-                }
-            }
+    routes.sort_unstable();
+    routes
+        .into_iter()
+        .map(|Path { nodes, cost, .. }| (nodes, cost))
+        .collect()
+}
+
+/// Compute the k-shortest paths using the [Yen's search
+/// algorithm](https://en.wikipedia.org/wiki/Yen%27s_algorithm), running the spur path
+/// searches of every iteration in parallel on the rayon thread pool.
+///
+/// This function behaves like [`yen`] and returns the same paths in the same order.
+/// Every path found requires one search per node of the path, which makes the
+/// parallel version worth using on large graphs or when many paths are requested.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::par_yen;
+///
+/// let successors = |&(x, y): &(u8, u8)| {
+///     [((x + 1, y), 1), ((x, y + 1), 2)]
+///         .into_iter()
+///         .filter(|&((x, y), _)| x < 4 && y < 4)
+/// };
+/// let paths = par_yen(&(0, 0), successors, |&n| n == (3, 3), 5);
+/// assert_eq!(paths.len(), 5);
+/// assert!(paths.iter().all(|(_, cost)| *cost == 9));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_yen<N, C, FN, IN, FS>(
+    start: &N,
+    successors: FN,
+    success: FS,
+    k: usize,
+) -> Vec<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone + Send + Sync,
+    C: Zero + Ord + Copy + Send + Sync,
+    FN: Fn(&N) -> IN + Sync,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: Fn(&N) -> bool + Sync,
+{
+    use rayon::prelude::*;
+
+    let hasher = BuildHasherDefault::<FxHasher>::default();
+    let Some((n, c)) = dijkstra_internal(start, &mut &successors, &mut &success, hasher.clone())
+    else {
+        return vec![];
+    };
+
+    let mut visited = HashSet::with_hasher(hasher.clone());
+    let mut routes = vec![Path {
+        nodes: n,
+        cost: c,
+        index: 0,
+    }];
+    let mut k_routes = BinaryHeap::new();
+    for ki in 0..(k - 1) {
+        if routes.len() <= ki || routes.len() == k {
+            break;
         }
-        if let Some(k_route) = k_routes.pop() {
-            let route = k_route.0;
-            let cost = route.cost;
-            routes.push(route);
-            // If we have other potential best routes with the same cost, we can insert
-            // them in the found routes since we will not find a better alternative.
-            while routes.len() < k {
-                let Some(k_route) = k_routes.peek() else {
-                    break;
-                };
-                if k_route.0.cost == cost {
-                    let Some(k_route) = k_routes.pop() else {
-                        break; // Cannot break
-                    };
-                    routes.push(k_route.0);
-                } else {
-                    break; // Other routes have higher cost
-                }
-            }
+        // Candidates are collected in the order of their spur node, and considered in
+        // this order to find the same paths as the sequential version.
+        let candidates = (0..(routes[ki].nodes.len() - 1))
+            .into_par_iter()
+            .map(|i| spur_path(&routes, ki, i, &successors, &success, &hasher))
+            .collect::<Vec<_>>();
+        for nodes in candidates.into_iter().flatten() {
+            add_candidate(nodes, &mut visited, &mut k_routes, &mut &successors);
         }
+        select_routes(&mut routes, &mut k_routes, k);
     }
 
     routes.sort_unstable();
     routes
         .into_iter()
-        .map(|Path { nodes, cost }| (nodes, cost))
+        .map(|Path { nodes, cost, .. }| (nodes, cost))
         .collect()
 }
 
+/// Compute the path deviating from the `ki`-th route at its `i`-th node, using neither
+/// the edges which leave this node in the routes sharing the same root path, nor the
+/// nodes of the root path.
+fn spur_path<N, C, FN, IN, FS, H>(
+    routes: &[Path<N, C>],
+    ki: usize,
+    i: usize,
+    mut successors: FN,
+    mut success: FS,
+    hasher: &H,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+    H: BuildHasher + Clone + Default,
+{
+    let previous = &routes[ki].nodes;
+    let spur_node = &previous[i];
+    let root_path = &previous[0..i];
+
+    let mut filtered_edges = HashSet::with_hasher(hasher.clone());
+    for path in routes {
+        if path.nodes.len() > i + 1 && &path.nodes[0..i] == root_path && &path.nodes[i] == spur_node
+        {
+            filtered_edges.insert((&path.nodes[i], &path.nodes[i + 1]));
+        }
+    }
+    let filtered_nodes: HashSet<&N, H> = HashSet::from_iter(root_path);
+    // We are creating a new successor function that will not return the
+    // filtered edges and nodes that routes already used.
+    let mut filtered_successor = |n: &N| {
+        successors(n)
+            .into_iter()
+            .filter(|(n2, _)| !filtered_nodes.contains(&n2) && !filtered_edges.contains(&(n, n2)))
+            .collect::<Vec<_>>()
+    };
+
+    // Let us find the spur path from the spur node to the sink using.
+    dijkstra_internal(
+        spur_node,
+        &mut filtered_successor,
+        &mut success,
+        hasher.clone(),
+    )
+    .map(|(spur_path, _)| root_path.iter().cloned().chain(spur_path).collect())
+}
+
+/// Add a path to the candidates unless it has been found before.
+fn add_candidate<N, C, FN, IN, H>(
+    nodes: Vec<N>,
+    visited: &mut HashSet<Vec<N>, H>,
+    k_routes: &mut BinaryHeap<Reverse<Path<N, C>>>,
+    successors: &mut FN,
+) where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    H: BuildHasher,
+{
+    // If we have found the same path before, we will not add it.
+    if !visited.contains(&nodes) {
+        // Since we don't know the root_path cost, we need to recalculate.
+        let cost = make_cost(&nodes, successors);
+        let path = Path {
+            nodes,
+            cost,
+            index: visited.len() + 1,
+        };
+        // Mark as visited
+        visited.insert(path.nodes.clone());
+        // Build a min-heap
+        k_routes.push(Reverse(path));
+    }
+}
+
+/// Move the cheapest candidate to the routes, along with the other candidates of
+/// the same cost as long as less than `k` routes are known.
+fn select_routes<N, C>(
+    routes: &mut Vec<Path<N, C>>,
+    k_routes: &mut BinaryHeap<Reverse<Path<N, C>>>,
+    k: usize,
+) where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    if let Some(k_route) = k_routes.pop() {
+        let route = k_route.0;
+        let cost = route.cost;
+        routes.push(route);
+        // If we have other potential best routes with the same cost, we can insert
+        // them in the found routes since we will not find a better alternative.
+        while routes.len() < k {
+            let Some(k_route) = k_routes.peek() else {
+                break;
+            };
+            if k_route.0.cost == cost {
+                let Some(k_route) = k_routes.pop() else {
+                    break; // Cannot break
+                };
+                routes.push(k_route.0);
+            } else {
+                break; // Other routes have higher cost
+            }
+        }
+    }
+}
+
 fn make_cost<N, FN, IN, C>(nodes: &[N], successors: &mut FN) -> C
 where
     N: Eq,
@@ -0,0 +1,281 @@
+//! Render graphs and explored search spaces in the
+//! [DOT language](https://graphviz.org/doc/info/lang.html) used by Graphviz.
+//!
+//! Nodes are identified by their `Debug` representation. Attribute callbacks return
+//! the content of a DOT attribute list, such as `color=red, label="start"`, or an empty
+//! string for no attributes.
+
+use crate::FxIndexSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Quote a node identifier, escaping the characters which need it.
+fn quote<N: Debug>(node: &N) -> String {
+    let name = format!("{node:?}")
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    format!("\"{name}\"")
+}
+
+/// Render a statement, followed by its attribute list if it is not empty.
+fn statement(line: &str, attributes: &str) -> String {
+    if attributes.is_empty() {
+        format!("    {line};\n")
+    } else {
+        format!("    {line} [{attributes}];\n")
+    }
+}
+
+/// Render a whole graph, made of nodes and of edges along with their attributes.
+fn render<'a, N>(
+    directed: bool,
+    nodes: impl IntoIterator<Item = (&'a N, String)>,
+    edges: impl IntoIterator<Item = (&'a N, &'a N, String)>,
+) -> String
+where
+    N: Debug + 'a,
+{
+    let (keyword, arrow) = if directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    let mut dot = format!("{keyword} {{\n");
+    for (node, attributes) in nodes {
+        dot += &statement(&quote(node), &attributes);
+    }
+    for (from, to, attributes) in edges {
+        dot += &statement(
+            &format!("{} {arrow} {}", quote(from), quote(to)),
+            &attributes,
+        );
+    }
+    dot + "}\n"
+}
+
+/// Render a graph described by a list of edges in the DOT language.
+///
+/// - `edges` is a list of edges, each one made of a source node, a destination node, and
+///   some data attached to the edge such as its cost.
+/// - `directed` selects between a directed graph (`digraph`) and an undirected one
+///   (`graph`). In an undirected graph, every edge must be given only once.
+/// - `node_attributes` returns the attributes of a node.
+/// - `edge_attributes` returns the attributes of an edge.
+///
+/// Nodes are declared in the order in which they first appear in `edges`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::graph_to_dot;
+///
+/// let edges = [('a', 'b', 3), ('b', 'c', 4)];
+/// let dot = graph_to_dot(
+///     edges,
+///     true,
+///     |&n| if n == 'a' { "shape=box".to_string() } else { String::new() },
+///     |_, _, c| format!("label={c}"),
+/// );
+/// assert_eq!(
+///     dot,
+///     "digraph {\n    \"'a'\" [shape=box];\n    \"'b'\";\n    \"'c'\";\n    \
+///      \"'a'\" -> \"'b'\" [label=3];\n    \"'b'\" -> \"'c'\" [label=4];\n}\n"
+/// );
+/// ```
+pub fn graph_to_dot<N, E, I, FNA, FEA>(
+    edges: I,
+    directed: bool,
+    mut node_attributes: FNA,
+    mut edge_attributes: FEA,
+) -> String
+where
+    N: Eq + Hash + Debug,
+    I: IntoIterator<Item = (N, N, E)>,
+    FNA: FnMut(&N) -> String,
+    FEA: FnMut(&N, &N, &E) -> String,
+{
+    let edges = edges.into_iter().collect::<Vec<_>>();
+    let nodes = edges
+        .iter()
+        .flat_map(|(from, to, _)| [from, to])
+        .collect::<FxIndexSet<_>>();
+    render(
+        directed,
+        nodes.into_iter().map(|n| (n, node_attributes(n))),
+        edges
+            .iter()
+            .map(|(from, to, data)| (from, to, edge_attributes(from, to, data))),
+    )
+}
+
+/// A record of the nodes expanded by a search algorithm and of the edges it generated,
+/// which can be rendered in the DOT language.
+///
+/// The trace is filled by wrapping the successors function given to a search algorithm
+/// with [`SearchTrace::record`], or with [`SearchTrace::record_unweighted`] for
+/// algorithms such as [`bfs`](crate::directed::bfs::bfs) whose successors have no cost.
+/// This works with any algorithm taking a successors function.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, SearchTrace};
+///
+/// let mut trace = SearchTrace::new();
+/// let (path, _) = astar(
+///     &(0, 0),
+///     trace.record(|&(x, y): &(i32, i32)| {
+///         vec![((x + 1, y), 1), ((x, y + 1), 1)].into_iter().filter(|&((x, y), _)| x < 3 && y < 3)
+///     }),
+///     |&(x, y)| (2 - x) + (2 - y),
+///     |&n| n == (2, 2),
+/// )
+/// .unwrap();
+/// // Draw the expanded nodes in black, the frontier in grey, and the path in red.
+/// let dot = trace.to_dot(
+///     |_, order| match order {
+///         Some(i) => format!("label=\"{i}\""),
+///         None => "color=grey".to_string(),
+///     },
+///     |from, to, _| {
+///         let on_path = path.windows(2).any(|w| (&w[0], &w[1]) == (from, to));
+///         if on_path { "color=red".to_string() } else { String::new() }
+///     },
+/// );
+/// assert!(dot.starts_with("digraph {\n    \"(0, 0)\" [label=\"0\"];\n"));
+/// // Every node of the path but the goal has been expanded.
+/// assert_eq!(trace.expanded().len(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SearchTrace<N, C = ()> {
+    expanded: Vec<N>,
+    edges: Vec<(N, N, C)>,
+}
+
+impl<N, C> Default for SearchTrace<N, C> {
+    fn default() -> Self {
+        Self {
+            expanded: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N, C> SearchTrace<N, C>
+where
+    N: Eq + Hash + Clone + Debug,
+    C: Clone,
+{
+    /// Create an empty trace.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap a successors function returning successors along with their cost, so that
+    /// every call is recorded into this trace.
+    pub fn record<FN, IN>(
+        &mut self,
+        mut successors: FN,
+    ) -> impl FnMut(&N) -> Vec<(N, C)> + use<'_, N, C, FN, IN>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = (N, C)>,
+    {
+        move |node| {
+            let next = successors(node).into_iter().collect::<Vec<_>>();
+            self.expanded.push(node.clone());
+            self.edges.extend(
+                next.iter()
+                    .map(|(n, c)| (node.clone(), n.clone(), c.clone())),
+            );
+            next
+        }
+    }
+
+    /// The nodes whose successors have been requested, in order. A node appears several
+    /// times if the search expanded it several times.
+    #[must_use]
+    pub fn expanded(&self) -> &[N] {
+        &self.expanded
+    }
+
+    /// The edges generated during the search, in order.
+    #[must_use]
+    pub fn edges(&self) -> &[(N, N, C)] {
+        &self.edges
+    }
+
+    /// Remove everything recorded so far.
+    pub fn clear(&mut self) {
+        self.expanded.clear();
+        self.edges.clear();
+    }
+
+    /// Render the explored part of the graph in the DOT language.
+    ///
+    /// - `node_attributes` returns the attributes of a node, given the node and the
+    ///   position at which it was first expanded, or `None` if it has been generated but
+    ///   never expanded.
+    /// - `edge_attributes` returns the attributes of a generated edge.
+    ///
+    /// Expanded nodes are declared first, in expansion order, followed by the
+    /// other generated nodes.
+    pub fn to_dot<FNA, FEA>(&self, mut node_attributes: FNA, mut edge_attributes: FEA) -> String
+    where
+        FNA: FnMut(&N, Option<usize>) -> String,
+        FEA: FnMut(&N, &N, &C) -> String,
+    {
+        let nodes = self
+            .expanded
+            .iter()
+            .chain(self.edges.iter().map(|(_, to, _)| to))
+            .collect::<FxIndexSet<_>>();
+        let expanded = self.expanded.iter().collect::<FxIndexSet<_>>().len();
+        render(
+            true,
+            nodes.iter().enumerate().map(|(i, n)| {
+                let order = (i < expanded).then_some(i);
+                (*n, node_attributes(n, order))
+            }),
+            self.edges
+                .iter()
+                .map(|(from, to, c)| (from, to, edge_attributes(from, to, c))),
+        )
+    }
+}
+
+impl<N> SearchTrace<N>
+where
+    N: Eq + Hash + Clone + Debug,
+{
+    /// Wrap a successors function returning successors without cost, so that every call
+    /// is recorded into this trace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::{bfs, SearchTrace};
+    ///
+    /// let mut trace = SearchTrace::new();
+    /// let path = bfs(&1, trace.record_unweighted(|&n: &u32| vec![n + 1, n * 2]), |&n| n == 6);
+    /// assert_eq!(path, Some(vec![1, 2, 3, 6]));
+    /// assert_eq!(trace.expanded(), &[1, 2, 3]);
+    /// ```
+    pub fn record_unweighted<FN, IN>(
+        &mut self,
+        mut successors: FN,
+    ) -> impl FnMut(&N) -> Vec<N> + use<'_, N, FN, IN>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+    {
+        move |node| {
+            let next = successors(node).into_iter().collect::<Vec<_>>();
+            self.expanded.push(node.clone());
+            self.edges
+                .extend(next.iter().map(|n| (node.clone(), n.clone(), ())));
+            next
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Compute the weighted [edit distance](https://en.wikipedia.org/wiki/Edit_distance)
+//! between two sequences, along with an optimal alignment, as a shortest path in the
+//! edit lattice.
+//!
+//! The nodes of the lattice are pairs `(i, j)` meaning that the first `i` elements of
+//! the first sequence have been transformed into the first `j` elements of the second
+//! one. The search is guided by the difference between the remaining lengths, and can be
+//! restricted to a band around the diagonal of the lattice (Ukkonen's optimization), so
+//! that similar sequences are aligned in nearly linear time.
+
+use crate::directed::astar::astar;
+use num_traits::Zero;
+
+/// An operation of an alignment returned by [`edit_alignment`] or
+/// [`banded_edit_alignment`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EditOperation {
+    /// The current elements of both sequences are equal and are kept.
+    Keep,
+    /// The current element of the first sequence is replaced by the current element
+    /// of the second sequence.
+    Substitute,
+    /// The current element of the second sequence is inserted.
+    Insert,
+    /// The current element of the first sequence is deleted.
+    Delete,
+}
+
+/// Costs of the edit operations. Keeping an element is free.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EditCosts<C> {
+    /// Cost of inserting an element.
+    pub insertion: C,
+    /// Cost of deleting an element.
+    pub deletion: C,
+    /// Cost of replacing an element by a different one.
+    pub substitution: C,
+}
+
+impl EditCosts<usize> {
+    /// Unit costs, giving the [Levenshtein
+    /// distance](https://en.wikipedia.org/wiki/Levenshtein_distance).
+    pub const LEVENSHTEIN: Self = Self {
+        insertion: 1,
+        deletion: 1,
+        substitution: 1,
+    };
+}
+
+/// Compute the edit distance between `a` and `b`, which is the smallest total cost of
+/// the operations transforming `a` into `b`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{edit_distance, EditCosts};
+///
+/// assert_eq!(edit_distance(b"kitten", b"sitting", &EditCosts::LEVENSHTEIN), 3);
+/// let costs = EditCosts { insertion: 2, deletion: 2, substitution: 3 };
+/// assert_eq!(edit_distance(b"kitten", b"sitting", &costs), 8);
+/// ```
+pub fn edit_distance<T, C>(a: &[T], b: &[T], costs: &EditCosts<C>) -> C
+where
+    T: PartialEq,
+    C: Zero + Ord + Copy,
+{
+    edit_alignment(a, b, costs).1
+}
+
+/// Compute an optimal alignment of `a` and `b`, that is a sequence of operations of
+/// minimal cost transforming `a` into `b`, along with its cost.
+///
+/// The search is first restricted to a narrow band around the diagonal of the edit
+/// lattice, which is widened until the result is guaranteed to be optimal. This is
+/// much faster than the full computation when the sequences are similar. When
+/// several alignments have the same cost, any of them may be returned.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{edit_alignment, EditCosts, EditOperation::*};
+///
+/// let (alignment, cost) = edit_alignment(b"kitten", b"sitting", &EditCosts::LEVENSHTEIN);
+/// assert_eq!(cost, 3);
+/// assert_eq!(alignment.len(), 7);
+/// assert_eq!(alignment.iter().filter(|&&op| op == Keep).count(), 4);
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn edit_alignment<T, C>(a: &[T], b: &[T], costs: &EditCosts<C>) -> (Vec<EditOperation>, C)
+where
+    T: PartialEq,
+    C: Zero + Ord + Copy,
+{
+    let longest = a.len().max(b.len());
+    let indel = costs.insertion.min(costs.deletion);
+    let mut band = a.len().abs_diff(b.len()).max(8);
+    loop {
+        // The search cannot fail as the band contains the end of the lattice.
+        let (alignment, cost) = banded_edit_alignment(a, b, costs, band).unwrap();
+        // Leaving the band requires more than `band` insertions or deletions, so
+        // the alignment is optimal when it is cheaper than that.
+        if band >= longest || (indel > C::zero() && cost < multiple(indel, band + 1)) {
+            return (alignment, cost);
+        }
+        band = band.saturating_mul(2);
+    }
+}
+
+/// Compute an alignment of `a` and `b` of minimal cost among those which stay within
+/// `band` of the diagonal of the edit lattice, that is which never have consumed more
+/// than `band` elements more from one sequence than from the other one, along with
+/// its cost.
+///
+/// `None` is returned if the lengths of `a` and `b` differ by more than `band`.
+/// The result is an optimal alignment if its cost is smaller than `band + 1` times
+/// the smallest of the insertion and deletion costs.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{banded_edit_alignment, EditCosts};
+///
+/// let costs = EditCosts::LEVENSHTEIN;
+/// assert_eq!(banded_edit_alignment(b"abcdef", b"bcdefa", &costs, 1).map(|(_, c)| c), Some(2));
+/// assert_eq!(banded_edit_alignment(b"abcdef", b"abc", &costs, 2), None);
+/// ```
+pub fn banded_edit_alignment<T, C>(
+    a: &[T],
+    b: &[T],
+    costs: &EditCosts<C>,
+    band: usize,
+) -> Option<(Vec<EditOperation>, C)>
+where
+    T: PartialEq,
+    C: Zero + Ord + Copy,
+{
+    let (n, m) = (a.len(), b.len());
+    if n.abs_diff(m) > band {
+        return None;
+    }
+    // Multiples of the deletion and insertion costs, used by the heuristic which
+    // counts the operations needed to compensate for the difference of lengths.
+    let deletions = multiples(costs.deletion, n);
+    let insertions = multiples(costs.insertion, m);
+    let (path, cost) = astar(
+        &(0, 0),
+        |&(i, j): &(usize, usize)| {
+            let mut successors = Vec::with_capacity(3);
+            if i < n && j < m {
+                let cost = if a[i] == b[j] {
+                    C::zero()
+                } else {
+                    costs.substitution
+                };
+                successors.push(((i + 1, j + 1), cost));
+            }
+            if i < n && (i + 1).abs_diff(j) <= band {
+                successors.push(((i + 1, j), costs.deletion));
+            }
+            if j < m && i.abs_diff(j + 1) <= band {
+                successors.push(((i, j + 1), costs.insertion));
+            }
+            successors
+        },
+        |&(i, j)| {
+            let (ra, rb) = (n - i, m - j);
+            if ra > rb {
+                deletions[ra - rb]
+            } else {
+                insertions[rb - ra]
+            }
+        },
+        |&p| p == (n, m),
+    )?;
+    let alignment = path
+        .windows(2)
+        .map(|w| match (w[1].0 - w[0].0, w[1].1 - w[0].1) {
+            (1, 1) if a[w[0].0] == b[w[0].1] => EditOperation::Keep,
+            (1, 1) => EditOperation::Substitute,
+            (1, 0) => EditOperation::Delete,
+            _ => EditOperation::Insert,
+        })
+        .collect();
+    Some((alignment, cost))
+}
+
+/// Return `k * cost`.
+fn multiple<C: Zero + Copy>(cost: C, k: usize) -> C {
+    (0..k).fold(C::zero(), |total, _| total + cost)
+}
+
+/// Return the multiples of `cost` from 0 to `k * cost`.
+fn multiples<C: Zero + Copy>(cost: C, k: usize) -> Vec<C> {
+    std::iter::successors(Some(C::zero()), |&total| Some(total + cost))
+        .take(k + 1)
+        .collect()
+}
@@ -0,0 +1,208 @@
+//! Generate random graphs, for example to benchmark or to test the algorithms of this
+//! crate on many inputs.
+//!
+//! This module is only available when the `generators` feature is enabled.
+//!
+//! Every generator draws its random numbers from the given random number generator, so
+//! that a seeded generator such as `rand_xorshift::XorShiftRng` always produces the same
+//! graph. Nodes are numbered from 0, and edges are returned as lists of pairs to which
+//! weights can be attached afterwards if needed.
+
+use crate::grid::Grid;
+use rand::Rng;
+
+/// Generate an [Erdős–Rényi](https://en.wikipedia.org/wiki/Erd%C5%91s%E2%80%93R%C3%A9nyi_model)
+/// random graph, in which every possible edge between `nodes` nodes is present
+/// independently with the given `probability`.
+///
+/// If `directed` is `true`, every ordered pair of distinct nodes is considered,
+/// otherwise every unordered pair is considered once and returned with the smallest
+/// node first. Self-loops are never generated.
+///
+/// This takes O(n²) time where n is the number of nodes.
+///
+/// # Panics
+///
+/// This function panics if `probability` is not between 0 and 1.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{erdos_renyi, kruskal};
+/// use rand::prelude::*;
+/// use rand_xorshift::XorShiftRng;
+///
+/// let mut rng = XorShiftRng::seed_from_u64(42);
+/// let edges = erdos_renyi(10, 0.5, false, &mut rng)
+///     .into_iter()
+///     .map(|(a, b)| (a, b, rng.random_range(1..10)))
+///     .collect::<Vec<_>>();
+/// let tree = kruskal(&edges).collect::<Vec<_>>();
+/// assert!(tree.len() < 10);
+/// ```
+pub fn erdos_renyi<R: Rng + ?Sized>(
+    nodes: usize,
+    probability: f64,
+    directed: bool,
+    rng: &mut R,
+) -> Vec<(usize, usize)> {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "probability must be between 0 and 1"
+    );
+    let mut edges = Vec::new();
+    for from in 0..nodes {
+        let first = if directed { 0 } else { from + 1 };
+        for to in first..nodes {
+            if from != to && rng.random_bool(probability) {
+                edges.push((from, to));
+            }
+        }
+    }
+    edges
+}
+
+/// Generate a [random geometric graph](https://en.wikipedia.org/wiki/Random_geometric_graph)
+/// by placing `nodes` nodes uniformly in the unit square and linking every pair of nodes
+/// whose euclidean distance is at most `radius`.
+///
+/// The positions of the nodes are returned along with the undirected edges, the
+/// smallest node coming first in every edge. The positions can be used to weight the
+/// edges and to build a heuristic for [`astar`](crate::directed::astar::astar).
+///
+/// This takes O(n²) time where n is the number of nodes.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::random_geometric;
+/// use rand::prelude::*;
+/// use rand_xorshift::XorShiftRng;
+///
+/// let mut rng = XorShiftRng::seed_from_u64(42);
+/// let (positions, edges) = random_geometric(50, 0.2, &mut rng);
+/// assert_eq!(positions.len(), 50);
+/// for (a, b) in edges {
+///     let ((xa, ya), (xb, yb)) = (positions[a], positions[b]);
+///     assert!((xa - xb).hypot(ya - yb) <= 0.2);
+/// }
+/// ```
+#[expect(clippy::type_complexity)]
+pub fn random_geometric<R: Rng + ?Sized>(
+    nodes: usize,
+    radius: f64,
+    rng: &mut R,
+) -> (Vec<(f64, f64)>, Vec<(usize, usize)>) {
+    let positions = (0..nodes)
+        .map(|_| (rng.random::<f64>(), rng.random::<f64>()))
+        .collect::<Vec<_>>();
+    let mut edges = Vec::new();
+    for (from, &(xa, ya)) in positions.iter().enumerate() {
+        for (to, &(xb, yb)) in positions.iter().enumerate().skip(from + 1) {
+            if (xa - xb).hypot(ya - yb) <= radius {
+                edges.push((from, to));
+            }
+        }
+    }
+    (positions, edges)
+}
+
+/// Generate a [`Grid`] of the given dimensions in which every cell is an obstacle,
+/// i.e. is absent from the grid, independently with the given `probability`.
+///
+/// Nothing guarantees that two given cells are connected; [`Grid::bfs_reachable`]
+/// can be used to check it.
+///
+/// # Panics
+///
+/// This function panics if `probability` is not between 0 and 1.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::random_obstacle_grid;
+/// use rand::prelude::*;
+/// use rand_xorshift::XorShiftRng;
+///
+/// let mut rng = XorShiftRng::seed_from_u64(42);
+/// let grid = random_obstacle_grid(20, 10, 0.3, &mut rng);
+/// assert!(grid.vertices_len() < 200);
+/// ```
+pub fn random_obstacle_grid<R: Rng + ?Sized>(
+    width: usize,
+    height: usize,
+    probability: f64,
+    rng: &mut R,
+) -> Grid {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "probability must be between 0 and 1"
+    );
+    let mut grid = Grid::new(width, height);
+    grid.fill();
+    for y in 0..height {
+        for x in 0..width {
+            if rng.random_bool(probability) {
+                grid.remove_vertex((x, y));
+            }
+        }
+    }
+    grid
+}
+
+/// Generate a scale-free graph following the
+/// [Barabási–Albert model](https://en.wikipedia.org/wiki/Barab%C3%A1si%E2%80%93Albert_model):
+/// nodes are added one by one, and every new node is linked to `edges_per_node`
+/// distinct existing nodes chosen with a probability proportional to their degree.
+///
+/// The graph starts with node `edges_per_node` linked to all the nodes before it. The
+/// returned undirected edges all go from the newer node to the older one, and there are
+/// `edges_per_node × (nodes - edges_per_node)` of them.
+///
+/// # Panics
+///
+/// This function panics if `edges_per_node` is zero or is not smaller than `nodes`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::barabasi_albert;
+/// use rand::prelude::*;
+/// use rand_xorshift::XorShiftRng;
+///
+/// let mut rng = XorShiftRng::seed_from_u64(42);
+/// let edges = barabasi_albert(100, 2, &mut rng);
+/// assert_eq!(edges.len(), 2 * 98);
+/// ```
+pub fn barabasi_albert<R: Rng + ?Sized>(
+    nodes: usize,
+    edges_per_node: usize,
+    rng: &mut R,
+) -> Vec<(usize, usize)> {
+    assert!(
+        edges_per_node > 0 && edges_per_node < nodes,
+        "edges per node must be positive and smaller than the number of nodes"
+    );
+    let mut edges = (0..edges_per_node)
+        .map(|to| (edges_per_node, to))
+        .collect::<Vec<_>>();
+    // Every node appears in `ends` as many times as its degree, so that picking a
+    // uniform element of `ends` picks a node with a probability proportional to its
+    // degree.
+    let mut ends = edges.iter().flat_map(|&(a, b)| [a, b]).collect::<Vec<_>>();
+    let mut targets = Vec::with_capacity(edges_per_node);
+    for from in edges_per_node + 1..nodes {
+        targets.clear();
+        while targets.len() < edges_per_node {
+            let to = ends[rng.random_range(0..ends.len())];
+            if !targets.contains(&to) {
+                targets.push(to);
+            }
+        }
+        for &to in &targets {
+            edges.push((from, to));
+            ends.extend([from, to]);
+        }
+    }
+    edges
+}
@@ -0,0 +1,173 @@
+//! Goal bounding, as described by Rabin and Sturtevant, on a [`Grid`].
+
+use crate::directed::astar::astar;
+use crate::grid::Grid;
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+
+type Vertex = (usize, usize);
+
+/// A rectangle given by its top-left and bottom-right corners, both included.
+type Bounds = (Vertex, Vertex);
+
+/// A [`Grid`] along with, for every vertex and every edge leaving it, the bounding box
+/// of the goals which can be reached optimally by starting with this edge.
+///
+/// During a search towards a goal, an edge whose bounding box does not contain the
+/// goal cannot start a shortest path to it and does not need to be followed. Most
+/// edges are pruned this way, and a search using [`find_path`](Self::find_path) or
+/// [`neighbours`](Self::neighbours) expands few vertices besides the ones of the path.
+///
+/// The preprocessing runs a breadth-first search from every vertex, which takes a time
+/// quadratic in the number of vertices, and stores up to eight bounding boxes per vertex.
+/// It is thus meant for grids which do not change often.
+///
+/// The grid is copied during the preprocessing, so later modifications of the
+/// original grid are not taken into account.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{GoalBoundedGrid, Grid};
+///
+/// let mut grid = Grid::new(20, 20);
+/// grid.fill();
+/// // A wall with a single opening at the bottom.
+/// for y in 0..19 {
+///     grid.remove_vertex((10, y));
+/// }
+/// let bounded = GoalBoundedGrid::new(&grid);
+///
+/// let (path, cost) = bounded.find_path((2, 2), (17, 2)).expect("no path found");
+/// assert_eq!(cost, 15 + 2 * 17);
+/// assert!(path.contains(&(10, 19)));
+///
+/// // Going left from (12, 5) never leads optimally to the right part of the grid.
+/// assert!(!bounded.neighbours((12, 5), (17, 2)).contains(&(11, 5)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GoalBoundedGrid {
+    grid: Grid,
+    bounds: FxHashMap<Vertex, [Option<Bounds>; 8]>,
+}
+
+impl GoalBoundedGrid {
+    /// Precompute the bounding boxes of every edge of `grid`.
+    #[must_use]
+    pub fn new(grid: &Grid) -> Self {
+        let mut bounded = Self {
+            grid: grid.clone(),
+            bounds: FxHashMap::default(),
+        };
+        bounded.build_bounds();
+        bounded
+    }
+
+    /// The grid the bounding boxes have been built from.
+    #[must_use]
+    pub const fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Bounding box, as its top-left and bottom-right corners, of the goals which can be
+    /// reached optimally from `vertex` by going first to `neighbour`.
+    ///
+    /// `None` is returned if there is no edge between `vertex` and `neighbour`.
+    #[must_use]
+    pub fn bounding_box(&self, vertex: Vertex, neighbour: Vertex) -> Option<Bounds> {
+        if !self.grid.has_edge(vertex, neighbour) {
+            return None;
+        }
+        self.bounds.get(&vertex)?[direction(vertex, neighbour)]
+    }
+
+    /// Neighbours of `vertex` through which a shortest path to `goal` may start. They
+    /// can be used as the successors of a search towards `goal`.
+    #[must_use]
+    pub fn neighbours(&self, vertex: Vertex, goal: Vertex) -> Vec<Vertex> {
+        let Some(bounds) = self.bounds.get(&vertex) else {
+            return vec![];
+        };
+        let mut neighbours = self.grid.neighbours(vertex);
+        neighbours.retain(|&n| {
+            bounds[direction(vertex, n)].is_some_and(|(min, max)| {
+                (min.0..=max.0).contains(&goal.0) && (min.1..=max.1).contains(&goal.1)
+            })
+        });
+        neighbours
+    }
+
+    /// Shortest path from `start` to `goal`, found by an A* search following only the
+    /// edges whose bounding box contains `goal`, along with its cost.
+    ///
+    /// `None` is returned if `start` or `goal` is not a vertex of the grid, or if no
+    /// path exists between them.
+    #[must_use]
+    pub fn find_path(&self, start: Vertex, goal: Vertex) -> Option<(Vec<Vertex>, usize)> {
+        if !self.grid.has_vertex(goal) {
+            return None;
+        }
+        astar(
+            &start,
+            |&n| self.neighbours(n, goal).into_iter().map(|m| (m, 1)),
+            |&n| self.grid.distance(n, goal),
+            |&n| n == goal,
+        )
+    }
+
+    fn build_bounds(&mut self) {
+        let (width, height) = (self.grid.width, self.grid.height);
+        let index = |(x, y): Vertex| y * width + x;
+        let mut depths = vec![usize::MAX; width * height];
+        let mut first_moves = vec![0u8; width * height];
+        let mut reached = Vec::new();
+        let mut to_see = VecDeque::new();
+        for start in &self.grid {
+            // Breadth-first search recording, for every vertex, the set of edges leaving
+            // `start` which begin a shortest path to it.
+            depths[index(start)] = 0;
+            reached.push(start);
+            for n in self.grid.neighbours(start) {
+                depths[index(n)] = 1;
+                first_moves[index(n)] = 1 << direction(start, n);
+                reached.push(n);
+                to_see.push_back(n);
+            }
+            while let Some(v) = to_see.pop_front() {
+                let (depth, moves) = (depths[index(v)], first_moves[index(v)]);
+                for n in self.grid.neighbours(v) {
+                    let i = index(n);
+                    if depths[i] == usize::MAX {
+                        depths[i] = depth + 1;
+                        reached.push(n);
+                        to_see.push_back(n);
+                    }
+                    if depths[i] == depth + 1 {
+                        first_moves[i] |= moves;
+                    }
+                }
+            }
+            let mut bounds = [None; 8];
+            for v in reached.drain(..) {
+                let i = index(v);
+                for (d, b) in bounds.iter_mut().enumerate() {
+                    if first_moves[i] & (1 << d) != 0 {
+                        let (min, max) = b.get_or_insert((v, v));
+                        *min = (min.0.min(v.0), min.1.min(v.1));
+                        *max = (max.0.max(v.0), max.1.max(v.1));
+                    }
+                }
+                depths[i] = usize::MAX;
+                first_moves[i] = 0;
+            }
+            self.bounds.insert(start, bounds);
+        }
+    }
+}
+
+/// Index, between 0 and 7, of the direction going from `from` to the adjacent
+/// vertex `to`.
+const fn direction(from: Vertex, to: Vertex) -> usize {
+    let d = (to.1 + 1 - from.1) * 3 + (to.0 + 1 - from.0);
+    if d > 4 { d - 1 } else { d }
+}
@@ -0,0 +1,732 @@
+//! Read and write graphs in the [GraphML](http://graphml.graphdrawing.org/) format, used
+//! by tools such as Gephi, yEd or NetworkX.
+//!
+//! This module is only available when the `graphml` feature is enabled.
+//!
+//! A [`GraphmlGraph`] holds explicit lists of nodes and edges, identified by their
+//! GraphML identifiers, along with their typed attributes. Attributes are designated
+//! by their name (the `attr.name` of their GraphML key), and their value keeps the
+//! type declared by the key. Only the first graph of a document is read, and nested
+//! graphs, hyperedges and ports are not supported.
+
+use quick_xml::Reader;
+use quick_xml::errors::IllFormedError;
+use quick_xml::events::{BytesStart, Event};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use thiserror::Error;
+
+/// Error encountered while reading or writing a GraphML document.
+#[derive(Debug, Error)]
+pub enum GraphmlError {
+    /// The input is not a well-formed XML document
+    #[error("cannot read input: {0}")]
+    Xml(#[from] quick_xml::Error),
+    /// The output could not be written
+    #[error("cannot write output: {0}")]
+    Io(#[from] std::io::Error),
+    /// A mandatory attribute of an element is missing
+    #[error("missing attribute `{attribute}` on element `{element}`")]
+    MissingAttribute {
+        /// Name of the element.
+        element: &'static str,
+        /// Name of the missing attribute.
+        attribute: &'static str,
+    },
+    /// A data element refers to an undeclared key
+    #[error("unknown key `{0}`")]
+    UnknownKey(String),
+    /// A key declares a type which is not one of the GraphML types
+    #[error("unsupported attribute type `{0}`")]
+    UnsupportedType(String),
+    /// A value cannot be parsed according to the type of its key
+    #[error("invalid value `{value}` for key `{key}`")]
+    InvalidValue {
+        /// Identifier of the key.
+        key: String,
+        /// Value which could not be parsed.
+        value: String,
+    },
+    /// A node identifier is used by several nodes
+    #[error("node `{0}` is declared several times")]
+    DuplicateNode(String),
+    /// An edge refers to a node which is not part of the graph
+    #[error("edge refers to unknown node `{0}`")]
+    UnknownNode(String),
+    /// An attribute name is used with values of different types
+    #[error("attribute `{0}` has values of different types")]
+    TypeConflict(String),
+    /// The document does not contain any graph
+    #[error("no graph found")]
+    MissingGraph,
+    /// A node contains a nested graph
+    #[error("nested graphs are not supported")]
+    NestedGraph,
+}
+
+/// Value of a GraphML attribute. Values of keys declared as `int` or `long` are read as
+/// integers, and values of keys declared as `float` or `double` as floating-point
+/// numbers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphmlValue {
+    /// Value of a `boolean` key.
+    Boolean(bool),
+    /// Value of an `int` or `long` key.
+    Integer(i64),
+    /// Value of a `float` or `double` key.
+    Float(f64),
+    /// Value of a `string` key.
+    String(String),
+}
+
+impl GraphmlValue {
+    /// The boolean value, if this is a boolean.
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// The integer value, if this is an integer.
+    #[must_use]
+    pub const fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// The floating-point value, if this is a number. Integers are converted.
+    #[must_use]
+    #[expect(clippy::cast_precision_loss)]
+    pub const fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(i) => Some(*i as f64),
+            Self::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// The string value, if this is a string.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The GraphML type used when writing this value.
+    const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Boolean(_) => "boolean",
+            Self::Integer(_) => "long",
+            Self::Float(_) => "double",
+            Self::String(_) => "string",
+        }
+    }
+
+    /// Parse a value of the given (normalized) GraphML type.
+    fn parse(type_name: &str, text: &str) -> Option<Self> {
+        match type_name {
+            "boolean" => match text.trim() {
+                t if t.eq_ignore_ascii_case("true") || t == "1" => Some(Self::Boolean(true)),
+                t if t.eq_ignore_ascii_case("false") || t == "0" => Some(Self::Boolean(false)),
+                _ => None,
+            },
+            "long" => text.trim().parse().ok().map(Self::Integer),
+            "double" => text.trim().parse().ok().map(Self::Float),
+            _ => Some(Self::String(text.to_string())),
+        }
+    }
+}
+
+impl From<bool> for GraphmlValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<i64> for GraphmlValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for GraphmlValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<String> for GraphmlValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for GraphmlValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+/// A node of a [`GraphmlGraph`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphmlNode {
+    /// Identifier of the node.
+    pub id: String,
+    /// Attributes of the node, by name.
+    pub attributes: BTreeMap<String, GraphmlValue>,
+}
+
+/// An edge of a [`GraphmlGraph`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphmlEdge {
+    /// Identifier of the source node.
+    pub source: String,
+    /// Identifier of the target node.
+    pub target: String,
+    /// Attributes of the edge, by name.
+    pub attributes: BTreeMap<String, GraphmlValue>,
+}
+
+/// A graph read from or written to a GraphML document.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, read_graphml, write_graphml, GraphmlGraph};
+///
+/// let mut graph = GraphmlGraph::new(false);
+/// graph.add_node("a").insert("label".into(), "start".into());
+/// graph.add_node("b");
+/// graph.add_node("c");
+/// graph.add_edge("a", "b").insert("weight".into(), 2i64.into());
+/// graph.add_edge("b", "c").insert("weight".into(), 3i64.into());
+/// graph.add_edge("a", "c").insert("weight".into(), 7i64.into());
+///
+/// let mut output = Vec::new();
+/// write_graphml(&graph, &mut output).unwrap();
+/// let read = read_graphml(output.as_slice()).unwrap();
+/// assert_eq!(read, graph);
+///
+/// let successors = read.successors(|e| e.attributes["weight"].as_i64().unwrap());
+/// let (path, cost) = dijkstra(&0, |&n| successors[n].iter().copied(), |&n| n == 2).unwrap();
+/// assert_eq!((path, cost), (vec![0, 1, 2], 5));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphmlGraph {
+    /// `true` if edges are directed by default, `false` otherwise.
+    pub directed: bool,
+    /// Attributes of the graph itself, by name.
+    pub attributes: BTreeMap<String, GraphmlValue>,
+    /// Nodes of the graph.
+    pub nodes: Vec<GraphmlNode>,
+    /// Edges of the graph.
+    pub edges: Vec<GraphmlEdge>,
+}
+
+impl GraphmlGraph {
+    /// Create an empty graph.
+    #[must_use]
+    pub fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            ..Self::default()
+        }
+    }
+
+    /// Add a node, and return its attributes so that they can be filled.
+    #[expect(clippy::missing_panics_doc)]
+    pub fn add_node(&mut self, id: impl Into<String>) -> &mut BTreeMap<String, GraphmlValue> {
+        self.nodes.push(GraphmlNode {
+            id: id.into(),
+            attributes: BTreeMap::new(),
+        });
+        &mut self.nodes.last_mut().unwrap().attributes
+    }
+
+    /// Add an edge, and return its attributes so that they can be filled.
+    #[expect(clippy::missing_panics_doc)]
+    pub fn add_edge(
+        &mut self,
+        source: impl Into<String>,
+        target: impl Into<String>,
+    ) -> &mut BTreeMap<String, GraphmlValue> {
+        self.edges.push(GraphmlEdge {
+            source: source.into(),
+            target: target.into(),
+            attributes: BTreeMap::new(),
+        });
+        &mut self.edges.last_mut().unwrap().attributes
+    }
+
+    /// Build the list of successors of every node along with the cost to reach them,
+    /// as computed by `cost`. Nodes are designated by their index in
+    /// [`nodes`](Self::nodes). In an undirected graph, every edge can be followed in
+    /// both directions.
+    ///
+    /// This can be used in a successors function such as
+    /// `|&n| successors[n].iter().copied()`. If several nodes share the same
+    /// identifier, edges refer to the first of them.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an edge refers to a node which is not part of the graph.
+    /// This cannot happen with a graph returned by [`read_graphml`].
+    pub fn successors<C, F>(&self, mut cost: F) -> Vec<Vec<(usize, C)>>
+    where
+        C: Clone,
+        F: FnMut(&GraphmlEdge) -> C,
+    {
+        let mut indices = FxHashMap::default();
+        for (i, node) in self.nodes.iter().enumerate() {
+            indices.entry(node.id.as_str()).or_insert(i);
+        }
+        let index = |id: &str| {
+            *indices
+                .get(id)
+                .unwrap_or_else(|| panic!("unknown node {id}"))
+        };
+        let mut successors = vec![Vec::new(); self.nodes.len()];
+        for edge in &self.edges {
+            let (source, target, cost) = (index(&edge.source), index(&edge.target), cost(edge));
+            if !self.directed && source != target {
+                successors[target].push((source, cost.clone()));
+            }
+            successors[source].push((target, cost));
+        }
+        successors
+    }
+}
+
+/// A key declared in a GraphML document.
+struct Key {
+    name: String,
+    domain: String,
+    type_name: &'static str,
+    default: Option<GraphmlValue>,
+}
+
+/// Element of the graph currently receiving data.
+#[derive(Clone, Copy)]
+enum Owner {
+    Graph,
+    Node(usize),
+    Edge(usize),
+}
+
+/// Text being collected, either the default value of a key or the value of a data
+/// element, along with the identifier of the key.
+enum Text {
+    Default(String, String),
+    Data(String, String),
+}
+
+/// Get the unescaped value of an attribute.
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, GraphmlError> {
+    element
+        .try_get_attribute(name)
+        .map_err(quick_xml::Error::from)?
+        .map(|a| Ok(a.unescape_value()?.into_owned()))
+        .transpose()
+}
+
+/// Get the unescaped value of an attribute which must be present.
+fn required(
+    element: &BytesStart,
+    element_name: &'static str,
+    name: &'static str,
+) -> Result<String, GraphmlError> {
+    attribute(element, name)?.ok_or(GraphmlError::MissingAttribute {
+        element: element_name,
+        attribute: name,
+    })
+}
+
+/// Parse the text collected for `key`.
+fn value(
+    keys: &FxHashMap<String, Key>,
+    key: &str,
+    text: &str,
+) -> Result<(String, GraphmlValue), GraphmlError> {
+    let k = keys
+        .get(key)
+        .ok_or_else(|| GraphmlError::UnknownKey(key.to_string()))?;
+    let value =
+        GraphmlValue::parse(k.type_name, text).ok_or_else(|| GraphmlError::InvalidValue {
+            key: key.to_string(),
+            value: text.to_string(),
+        })?;
+    Ok((k.name.clone(), value))
+}
+
+/// State of the reader of a GraphML document.
+#[derive(Default)]
+struct Parser {
+    keys: FxHashMap<String, Key>,
+    current_key: Option<String>,
+    graph: Option<GraphmlGraph>,
+    owner: Option<Owner>,
+    text: Option<Text>,
+}
+
+impl Parser {
+    /// Handle the start of an element, and return `true` if the graph is complete.
+    fn start(&mut self, element: &BytesStart, empty: bool) -> Result<bool, GraphmlError> {
+        match (element.local_name().as_ref(), self.graph.as_mut()) {
+            (b"key", None) => {
+                let id = required(element, "key", "id")?;
+                let type_name = match attribute(element, "attr.type")?.as_deref() {
+                    None | Some("string") => "string",
+                    Some("boolean") => "boolean",
+                    Some("int" | "long") => "long",
+                    Some("float" | "double") => "double",
+                    Some(t) => return Err(GraphmlError::UnsupportedType(t.to_string())),
+                };
+                let key = Key {
+                    name: attribute(element, "attr.name")?.unwrap_or_else(|| id.clone()),
+                    domain: attribute(element, "for")?.unwrap_or_else(|| "all".to_string()),
+                    type_name,
+                    default: None,
+                };
+                self.keys.insert(id.clone(), key);
+                self.current_key = (!empty).then_some(id);
+            }
+            (b"default", None) => {
+                if let (Some(key), false) = (&self.current_key, empty) {
+                    self.text = Some(Text::Default(key.clone(), String::new()));
+                }
+            }
+            (b"graph", None) => {
+                let directed = attribute(element, "edgedefault")?.as_deref() != Some("undirected");
+                self.graph = Some(GraphmlGraph::new(directed));
+                self.owner = Some(Owner::Graph);
+                return Ok(empty);
+            }
+            (b"graph", Some(_)) => return Err(GraphmlError::NestedGraph),
+            (b"node", Some(graph)) => {
+                graph.add_node(required(element, "node", "id")?);
+                if !empty {
+                    self.owner = Some(Owner::Node(graph.nodes.len() - 1));
+                }
+            }
+            (b"edge", Some(graph)) => {
+                graph.add_edge(
+                    required(element, "edge", "source")?,
+                    required(element, "edge", "target")?,
+                );
+                if !empty {
+                    self.owner = Some(Owner::Edge(graph.edges.len() - 1));
+                }
+            }
+            (b"data", Some(_)) => {
+                let key = required(element, "data", "key")?;
+                self.text = Some(Text::Data(key, String::new()));
+                if empty {
+                    self.end(b"data")?;
+                }
+            }
+            _ => (),
+        }
+        Ok(false)
+    }
+
+    /// Collect text if it belongs to a default value or to a data element.
+    fn text(&mut self, text: &str) {
+        if let Some(Text::Default(_, s) | Text::Data(_, s)) = &mut self.text {
+            s.push_str(text);
+        }
+    }
+
+    /// Handle the end of an element, and return `true` if the graph is complete.
+    fn end(&mut self, name: &[u8]) -> Result<bool, GraphmlError> {
+        match name {
+            b"key" => self.current_key = None,
+            b"default" => {
+                if let Some(Text::Default(key, s)) = self.text.take() {
+                    let (_, default) = value(&self.keys, &key, &s)?;
+                    if let Some(k) = self.keys.get_mut(&key) {
+                        k.default = Some(default);
+                    }
+                }
+            }
+            b"data" => {
+                if let (Some(Text::Data(key, s)), Some(graph), Some(owner)) =
+                    (self.text.take(), self.graph.as_mut(), self.owner)
+                {
+                    let (name, value) = value(&self.keys, &key, &s)?;
+                    attributes(graph, owner).insert(name, value);
+                }
+            }
+            b"node" | b"edge" => self.owner = Some(Owner::Graph),
+            b"graph" => return Ok(true),
+            _ => (),
+        }
+        Ok(false)
+    }
+
+    /// Apply the default values of the keys, and check that node identifiers are unique
+    /// and that edges refer to existing nodes.
+    fn finish(self) -> Result<GraphmlGraph, GraphmlError> {
+        let mut graph = self.graph.ok_or(GraphmlError::MissingGraph)?;
+        for key in self.keys.values() {
+            let Some(default) = &key.default else {
+                continue;
+            };
+            let apply = |attributes: &mut BTreeMap<String, GraphmlValue>| {
+                attributes
+                    .entry(key.name.clone())
+                    .or_insert_with(|| default.clone());
+            };
+            let all = key.domain == "all";
+            if all || key.domain == "node" {
+                graph
+                    .nodes
+                    .iter_mut()
+                    .for_each(|n| apply(&mut n.attributes));
+            }
+            if all || key.domain == "edge" {
+                graph
+                    .edges
+                    .iter_mut()
+                    .for_each(|e| apply(&mut e.attributes));
+            }
+            if all || key.domain == "graph" {
+                apply(&mut graph.attributes);
+            }
+        }
+        let mut ids = FxHashSet::default();
+        if let Some(duplicate) = graph.nodes.iter().find(|n| !ids.insert(n.id.as_str())) {
+            return Err(GraphmlError::DuplicateNode(duplicate.id.clone()));
+        }
+        if let Some(unknown) = graph
+            .edges
+            .iter()
+            .flat_map(|e| [&e.source, &e.target])
+            .find(|id| !ids.contains(id.as_str()))
+        {
+            return Err(GraphmlError::UnknownNode(unknown.clone()));
+        }
+        Ok(graph)
+    }
+}
+
+/// Read the first graph of a GraphML document.
+///
+/// Attributes which are missing from a node, an edge or the graph receive the default
+/// value of their key, if any. The `directed` attribute of individual edges is
+/// ignored: all edges follow the `edgedefault` attribute of the graph.
+///
+/// # Errors
+///
+/// An error is returned if the input cannot be read, is not well-formed XML, does not
+/// describe a graph as explained in the [module documentation](self), or declares
+/// several nodes with the same identifier.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{read_graphml, GraphmlValue};
+///
+/// let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+///   <key id="d0" for="node" attr.name="color" attr.type="string">
+///     <default>yellow</default>
+///   </key>
+///   <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+///   <graph id="G" edgedefault="directed">
+///     <node id="n0"><data key="d0">green</data></node>
+///     <node id="n1"/>
+///     <edge source="n0" target="n1"><data key="d1">1.5</data></edge>
+///   </graph>
+/// </graphml>"#;
+/// let graph = read_graphml(input.as_bytes()).unwrap();
+/// assert!(graph.directed);
+/// assert_eq!(graph.nodes[0].attributes["color"], GraphmlValue::from("green"));
+/// assert_eq!(graph.nodes[1].attributes["color"], GraphmlValue::from("yellow"));
+/// assert_eq!(graph.edges[0].attributes["weight"], GraphmlValue::Float(1.5));
+/// ```
+pub fn read_graphml<R: BufRead>(input: R) -> Result<GraphmlGraph, GraphmlError> {
+    // Text is not trimmed, so that strings keep their leading and trailing whitespace.
+    let mut reader = Reader::from_reader(input);
+    let mut buffer = Vec::new();
+    let mut parser = Parser::default();
+    loop {
+        let complete = match reader.read_event_into(&mut buffer)? {
+            Event::Start(e) => parser.start(&e, false)?,
+            Event::Empty(e) => parser.start(&e, true)?,
+            Event::Text(t) => {
+                parser.text(&t.unescape()?);
+                false
+            }
+            Event::CData(t) => {
+                parser.text(
+                    &reader
+                        .decoder()
+                        .decode(&t)
+                        .map_err(quick_xml::Error::from)?,
+                );
+                false
+            }
+            Event::End(e) => parser.end(e.local_name().as_ref())?,
+            Event::Eof if parser.graph.is_some() => {
+                return Err(quick_xml::Error::IllFormed(IllFormedError::MissingEndTag(
+                    "graph".to_string(),
+                ))
+                .into());
+            }
+            Event::Eof => true,
+            _ => false,
+        };
+        if complete {
+            return parser.finish();
+        }
+        buffer.clear();
+    }
+}
+
+/// The attributes of the element receiving data.
+fn attributes(graph: &mut GraphmlGraph, owner: Owner) -> &mut BTreeMap<String, GraphmlValue> {
+    match owner {
+        Owner::Graph => &mut graph.attributes,
+        Owner::Node(i) => &mut graph.nodes[i].attributes,
+        Owner::Edge(i) => &mut graph.edges[i].attributes,
+    }
+}
+
+/// Declare the keys needed for a list of attribute maps, and return their identifiers
+/// by attribute name.
+fn declare_keys<'a, W: Write>(
+    output: &mut W,
+    domain: &str,
+    attributes: impl IntoIterator<Item = &'a BTreeMap<String, GraphmlValue>>,
+) -> Result<FxHashMap<&'a str, String>, GraphmlError> {
+    let mut types = BTreeMap::new();
+    for (name, value) in attributes.into_iter().flatten() {
+        if *types.entry(name.as_str()).or_insert(value.type_name()) != value.type_name() {
+            return Err(GraphmlError::TypeConflict(name.clone()));
+        }
+    }
+    let mut ids = FxHashMap::default();
+    for (i, (name, type_name)) in types.into_iter().enumerate() {
+        let id = format!("{}{i}", &domain[..1]);
+        writeln!(
+            output,
+            r#"  <key id="{id}" for="{domain}" attr.name="{}" attr.type="{type_name}"/>"#,
+            quick_xml::escape::escape(name)
+        )?;
+        ids.insert(name, id);
+    }
+    Ok(ids)
+}
+
+/// Write the data elements of an element.
+fn write_data<W: Write>(
+    output: &mut W,
+    indent: &str,
+    ids: &FxHashMap<&str, String>,
+    attributes: &BTreeMap<String, GraphmlValue>,
+) -> Result<(), GraphmlError> {
+    for (name, value) in attributes {
+        let text = match value {
+            GraphmlValue::Boolean(b) => b.to_string(),
+            GraphmlValue::Integer(i) => i.to_string(),
+            // Non-finite values use the XML Schema notation.
+            GraphmlValue::Float(f) if f.is_nan() => "NaN".to_string(),
+            GraphmlValue::Float(f) if f.is_infinite() => {
+                if *f > 0.0 { "INF" } else { "-INF" }.to_string()
+            }
+            GraphmlValue::Float(f) => f.to_string(),
+            GraphmlValue::String(s) => quick_xml::escape::escape(s).into_owned(),
+        };
+        writeln!(
+            output,
+            r#"{indent}<data key="{}">{text}</data>"#,
+            ids[name.as_str()]
+        )?;
+    }
+    Ok(())
+}
+
+/// Write a graph as a GraphML document.
+///
+/// Keys are declared for every attribute name used by the graph, its nodes or its edges,
+/// with a type matching the values: `boolean`, `long`, `double` or `string`. The
+/// document can be read back with [`read_graphml`].
+///
+/// # Errors
+///
+/// An error is returned if the output cannot be written, or if an attribute name has
+/// values of different types among nodes, among edges, or in the graph attributes.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{write_graphml, GraphmlGraph};
+///
+/// let mut graph = GraphmlGraph::new(true);
+/// graph.add_node("a");
+/// graph.add_node("b");
+/// graph.add_edge("a", "b").insert("capacity".into(), 3i64.into());
+/// let mut output = Vec::new();
+/// write_graphml(&graph, &mut output).unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.contains(r#"<key id="e0" for="edge" attr.name="capacity" attr.type="long"/>"#));
+/// assert!(output.contains(r#"<data key="e0">3</data>"#));
+/// ```
+pub fn write_graphml<W: Write>(graph: &GraphmlGraph, mut output: W) -> Result<(), GraphmlError> {
+    writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        output,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#
+    )?;
+    let graph_ids = declare_keys(&mut output, "graph", [&graph.attributes])?;
+    let node_ids = declare_keys(
+        &mut output,
+        "node",
+        graph.nodes.iter().map(|n| &n.attributes),
+    )?;
+    let edge_ids = declare_keys(
+        &mut output,
+        "edge",
+        graph.edges.iter().map(|e| &e.attributes),
+    )?;
+    let edge_default = if graph.directed {
+        "directed"
+    } else {
+        "undirected"
+    };
+    writeln!(output, r#"  <graph edgedefault="{edge_default}">"#)?;
+    write_data(&mut output, "    ", &graph_ids, &graph.attributes)?;
+    for node in &graph.nodes {
+        let id = quick_xml::escape::escape(&node.id);
+        if node.attributes.is_empty() {
+            writeln!(output, r#"    <node id="{id}"/>"#)?;
+        } else {
+            writeln!(output, r#"    <node id="{id}">"#)?;
+            write_data(&mut output, "      ", &node_ids, &node.attributes)?;
+            writeln!(output, "    </node>")?;
+        }
+    }
+    for edge in &graph.edges {
+        let (source, target) = (
+            quick_xml::escape::escape(&edge.source),
+            quick_xml::escape::escape(&edge.target),
+        );
+        if edge.attributes.is_empty() {
+            writeln!(output, r#"    <edge source="{source}" target="{target}"/>"#)?;
+        } else {
+            writeln!(output, r#"    <edge source="{source}" target="{target}">"#)?;
+            write_data(&mut output, "      ", &edge_ids, &edge.attributes)?;
+            writeln!(output, "    </edge>")?;
+        }
+    }
+    writeln!(output, "  </graph>")?;
+    writeln!(output, "</graphml>")?;
+    Ok(())
+}
@@ -3,10 +3,11 @@
 
 use super::matrix::Matrix;
 use crate::FxIndexSet;
+use crate::directed::astar::astar;
 use crate::directed::bfs::bfs_reach;
 use crate::directed::dfs::dfs_reach;
-use crate::utils::constrain;
-use num_traits::ToPrimitive;
+use crate::utils::{constrain, times};
+use num_traits::{One, ToPrimitive, Zero};
 use std::collections::BTreeSet;
 use std::fmt;
 use std::iter::FusedIterator;
@@ -384,6 +385,60 @@ impl Grid {
         candidates
     }
 
+    /// Return the list of neighbours of a given vertex along with the cost of moving
+    /// to them, which is one for horizontal and vertical moves and `diagonal_cost`
+    /// for diagonal moves when diagonal mode is enabled. If `vertex` is absent from
+    /// the grid, an empty list is returned. Only existing vertices will be returned.
+    ///
+    /// The result can be returned as is by the `successors` function of
+    /// [`dijkstra`](crate::directed::dijkstra::dijkstra) or
+    /// [`astar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::{dijkstra, Grid};
+    ///
+    /// let mut grid = Grid::new(4, 3);
+    /// grid.fill();
+    /// grid.remove_vertex((1, 1));
+    /// grid.enable_diagonal_mode();
+    ///
+    /// // Diagonal moves cost more than two orthogonal moves, so they are avoided.
+    /// let (path, cost) =
+    ///     dijkstra(&(0, 0), |&p| grid.neighbours_with_distance(p, 3), |&p| p == (3, 2))
+    ///         .unwrap();
+    /// assert_eq!(cost, 5);
+    /// assert_eq!(path.len(), 6);
+    ///
+    /// // Diagonal moves cost as much as orthogonal ones.
+    /// let (path, cost) =
+    ///     dijkstra(&(0, 0), |&p| grid.neighbours_with_distance(p, 1), |&p| p == (3, 2))
+    ///         .unwrap();
+    /// assert_eq!(cost, 3);
+    /// assert_eq!(path.len(), 4);
+    /// ```
+    #[must_use]
+    pub fn neighbours_with_distance<C>(
+        &self,
+        vertex: (usize, usize),
+        diagonal_cost: C,
+    ) -> Vec<((usize, usize), C)>
+    where
+        C: One + Copy,
+    {
+        self.neighbours(vertex)
+            .into_iter()
+            .map(|v| {
+                if v.0 == vertex.0 || v.1 == vertex.1 {
+                    (v, C::one())
+                } else {
+                    (v, diagonal_cost)
+                }
+            })
+            .collect()
+    }
+
     /// Return a set of the indices reachable from a candidate starting point
     /// and for which the given predicate is valid using BFS. This can be used for example
     /// to implement a flood-filling algorithm. Since the indices are collected
@@ -440,6 +495,114 @@ impl Grid {
         .collect()
     }
 
+    /// Return the shortest path between two vertices, moving from a vertex to one of
+    /// its neighbours at every step, or `None` if `goal` cannot be reached from
+    /// `start` or one of them is absent from the grid.
+    ///
+    /// The search uses the [A* search
+    /// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) guided by the
+    /// [`distance`](Self::distance) to the goal, which takes diagonal mode into
+    /// account. Every move has the same cost, even diagonal ones: see
+    /// [`shortest_path_weighted`](Self::shortest_path_weighted) to make diagonal moves
+    /// more expensive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::Grid;
+    ///
+    /// let mut grid = Grid::new(5, 3);
+    /// grid.fill();
+    /// for y in 0..2 {
+    ///     grid.remove_vertex((2, y));
+    /// }
+    /// let path = grid.shortest_path((0, 0), (4, 0)).unwrap();
+    /// assert_eq!(path.len(), 9);
+    /// grid.enable_diagonal_mode();
+    /// let path = grid.shortest_path((0, 0), (4, 0)).unwrap();
+    /// assert_eq!(path, vec![(0, 0), (1, 1), (2, 2), (3, 1), (4, 0)]);
+    /// grid.remove_vertex((2, 2));
+    /// assert_eq!(grid.shortest_path((0, 0), (4, 0)), None);
+    /// ```
+    #[must_use]
+    pub fn shortest_path(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Option<Vec<(usize, usize)>> {
+        if !self.has_vertex(start) || !self.has_vertex(goal) {
+            return None;
+        }
+        astar(
+            &start,
+            |&n| self.neighbours(n).into_iter().map(|m| (m, 1)),
+            |&n| self.distance(n, goal),
+            |&n| n == goal,
+        )
+        .map(|(path, _)| path)
+    }
+
+    /// Return the cheapest path between two vertices along with its cost, when
+    /// horizontal and vertical moves cost one and diagonal moves cost `diagonal_cost`,
+    /// or `None` if `goal` cannot be reached from `start` or one of them is absent from
+    /// the grid.
+    ///
+    /// The search uses the [A* search
+    /// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm) guided by the
+    /// Manhattan distance to the goal, or by the octile distance if diagonal mode is
+    /// enabled. `diagonal_cost` is ignored if diagonal mode is disabled, and must not be
+    /// negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::{Grid, TotalCost};
+    ///
+    /// let mut grid = Grid::new(5, 5);
+    /// grid.fill();
+    /// grid.enable_diagonal_mode();
+    /// // Diagonal moves are √2 times longer than other moves.
+    /// let diagonal = TotalCost::new(std::f64::consts::SQRT_2);
+    /// let (path, cost) = grid.shortest_path_weighted((0, 0), (4, 2), diagonal).unwrap();
+    /// assert_eq!(path.len(), 5);
+    /// assert!((cost.into_inner() - (2.0 + 2.0 * std::f64::consts::SQRT_2)).abs() < 1e-9);
+    ///
+    /// // Without diagonal mode, only horizontal and vertical moves are used.
+    /// grid.disable_diagonal_mode();
+    /// let (path, cost) = grid.shortest_path_weighted((0, 0), (4, 2), diagonal).unwrap();
+    /// assert_eq!((path.len(), cost.into_inner()), (7, 6.0));
+    /// ```
+    #[must_use]
+    pub fn shortest_path_weighted<C>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        diagonal_cost: C,
+    ) -> Option<(Vec<(usize, usize)>, C)>
+    where
+        C: Zero + One + Ord + Copy,
+    {
+        if !self.has_vertex(start) || !self.has_vertex(goal) {
+            return None;
+        }
+        // A diagonal move is never more expensive than the two moves replacing it.
+        let diagonal = diagonal_cost.min(C::one() + C::one());
+        astar(
+            &start,
+            |&n| self.neighbours_with_distance(n, diagonal_cost),
+            |&n| {
+                let (dx, dy) = (n.0.abs_diff(goal.0), n.1.abs_diff(goal.1));
+                if self.diagonal_mode {
+                    let (small, large) = (dx.min(dy), dx.max(dy));
+                    times(C::one(), large - small) + times(diagonal, small)
+                } else {
+                    times(C::one(), dx + dy)
+                }
+            },
+            |&n| n == goal,
+        )
+    }
+
     /// Iterate over vertices.
     #[must_use]
     pub fn iter(&self) -> GridIterator<'_> {
@@ -0,0 +1,227 @@
+//! Distance functions between points, to be used as heuristics by searches such as
+//! [`astar`](crate::directed::astar::astar) or
+//! [`idastar`](crate::directed::idastar::idastar).
+//!
+//! Every function takes the goal and returns a closure computing the distance between
+//! a node and the goal. Points can be pairs, triples or arrays of coordinates, as
+//! described by the [`Coordinates`] trait.
+//!
+//! A heuristic must never overestimate the cost of reaching the goal for the search to
+//! return an optimal path. The heuristic should be chosen according to the moves
+//! allowed in the graph:
+//!
+//! - [`manhattan`] when moves are only allowed along the axes, with a unit cost;
+//! - [`chebyshev`] when diagonal moves are allowed and cost as much as other moves;
+//! - [`octile`] when diagonal moves are allowed on a plane with a different cost;
+//! - [`euclidean`] or [`integer_euclidean`] when moves are allowed in any direction and
+//!   cost their length.
+
+use integer_sqrt::IntegerSquareRoot;
+use num_traits::{Float, PrimInt, Zero};
+use std::ops::{Add, Mul, Sub};
+
+/// Points made of coordinates along several axes.
+pub trait Coordinates {
+    /// Type of the coordinates.
+    type Scalar: Copy;
+
+    /// Number of axes.
+    const DIMENSIONS: usize;
+
+    /// Return the coordinate along `axis`, between 0 and `DIMENSIONS - 1`.
+    #[must_use]
+    fn coordinate(&self, axis: usize) -> Self::Scalar;
+}
+
+impl<T: Copy> Coordinates for (T, T) {
+    type Scalar = T;
+    const DIMENSIONS: usize = 2;
+
+    fn coordinate(&self, axis: usize) -> T {
+        [self.0, self.1][axis]
+    }
+}
+
+impl<T: Copy> Coordinates for (T, T, T) {
+    type Scalar = T;
+    const DIMENSIONS: usize = 3;
+
+    fn coordinate(&self, axis: usize) -> T {
+        [self.0, self.1, self.2][axis]
+    }
+}
+
+impl<T: Copy, const N: usize> Coordinates for [T; N] {
+    type Scalar = T;
+    const DIMENSIONS: usize = N;
+
+    fn coordinate(&self, axis: usize) -> T {
+        self[axis]
+    }
+}
+
+/// Absolute difference between two numbers, which also works with unsigned numbers.
+fn difference<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+/// Absolute differences between the coordinates of two points.
+fn differences<P>(a: &P, b: &P) -> impl Iterator<Item = P::Scalar>
+where
+    P: Coordinates,
+    P::Scalar: PartialOrd + Sub<Output = P::Scalar>,
+{
+    (0..P::DIMENSIONS).map(|axis| difference(a.coordinate(axis), b.coordinate(axis)))
+}
+
+/// Build a heuristic returning the [Manhattan
+/// distance](https://en.wikipedia.org/wiki/Taxicab_geometry) to `goal`, which is the
+/// sum of the absolute differences of the coordinates.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, manhattan};
+///
+/// let goal = (4u32, 6u32);
+/// let result = astar(
+///     &(1, 1),
+///     |&(x, y)| vec![((x + 1, y), 1), ((x, y + 1), 1)],
+///     manhattan(goal),
+///     |&p| p == goal,
+/// );
+/// assert_eq!(result.expect("no path found").1, 8);
+/// ```
+pub fn manhattan<P>(goal: P) -> impl Fn(&P) -> P::Scalar
+where
+    P: Coordinates,
+    P::Scalar: Zero + PartialOrd + Sub<Output = P::Scalar>,
+{
+    move |p| differences(p, &goal).fold(Zero::zero(), |s, d| s + d)
+}
+
+/// Build a heuristic returning the [Chebyshev
+/// distance](https://en.wikipedia.org/wiki/Chebyshev_distance) to `goal`, which is the
+/// largest absolute difference between the coordinates.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::chebyshev;
+///
+/// let heuristic = chebyshev((1i32, 2i32, 3i32));
+/// assert_eq!(heuristic(&(4, -5, 3)), 7);
+/// ```
+pub fn chebyshev<P>(goal: P) -> impl Fn(&P) -> P::Scalar
+where
+    P: Coordinates,
+    P::Scalar: Zero + PartialOrd + Sub<Output = P::Scalar>,
+{
+    move |p| differences(p, &goal).fold(Zero::zero(), |m, d| if d > m { d } else { m })
+}
+
+/// Build a heuristic returning the octile distance to `goal` on a plane where a move
+/// along an axis costs `straight` and a diagonal move costs `diagonal`.
+///
+/// Integer costs are commonly used to approximate a diagonal move costing √2 times a
+/// straight move, such as 10 and 14, or 5 and 7.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::octile;
+///
+/// let heuristic = octile((0u32, 0u32), 10, 14);
+/// assert_eq!(heuristic(&(5, 2)), 3 * 10 + 2 * 14);
+/// ```
+pub fn octile<T>(goal: (T, T), straight: T, diagonal: T) -> impl Fn(&(T, T)) -> T
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    move |p| {
+        let (dx, dy) = (difference(p.0, goal.0), difference(p.1, goal.1));
+        let (small, large) = if dx < dy { (dx, dy) } else { (dy, dx) };
+        straight * (large - small) + diagonal * small
+    }
+}
+
+/// Build a heuristic returning the [Euclidean
+/// distance](https://en.wikipedia.org/wiki/Euclidean_distance) to `goal` for points
+/// with floating-point coordinates.
+///
+/// Since floating-point numbers do not implement `Ord`, the distance will usually be
+/// converted or wrapped, for example into a
+/// [`TotalCost`](crate::total_cost::TotalCost), before being used by a search.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::euclidean;
+///
+/// let heuristic = euclidean((0.0, 0.0));
+/// assert_eq!(heuristic(&(3.0, 4.0)), 5.0);
+/// ```
+pub fn euclidean<P>(goal: P) -> impl Fn(&P) -> P::Scalar
+where
+    P: Coordinates,
+    P::Scalar: Float,
+{
+    move |p| squared_euclidean_distance(p, &goal).sqrt()
+}
+
+/// Build a function returning the square of the [Euclidean
+/// distance](https://en.wikipedia.org/wiki/Euclidean_distance) to `goal`, which is
+/// exact with integer coordinates.
+///
+/// The squared distance overestimates distances larger than 1 and must not be used as a
+/// heuristic when the cost of a move is its length, but it can be used to compare distances
+/// or when the cost of a move is the square of its length. See [`integer_euclidean`]
+/// for a suitable heuristic with integer coordinates.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::squared_euclidean;
+///
+/// let distance = squared_euclidean([1u64, 2, 3]);
+/// assert_eq!(distance(&[4, 6, 3]), 25);
+/// ```
+pub fn squared_euclidean<P>(goal: P) -> impl Fn(&P) -> P::Scalar
+where
+    P: Coordinates,
+    P::Scalar: Zero + PartialOrd + Sub<Output = P::Scalar> + Mul<Output = P::Scalar>,
+{
+    move |p| squared_euclidean_distance(p, &goal)
+}
+
+/// Build a heuristic returning the [Euclidean
+/// distance](https://en.wikipedia.org/wiki/Euclidean_distance) to `goal` for points
+/// with integer coordinates, rounded down so that it never overestimates the distance.
+///
+/// The square of the distance is computed first and must fit in the type of the
+/// coordinates.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::integer_euclidean;
+///
+/// let heuristic = integer_euclidean((0i32, 0i32));
+/// assert_eq!(heuristic(&(3, -4)), 5);
+/// assert_eq!(heuristic(&(1, 1)), 1);
+/// ```
+pub fn integer_euclidean<P>(goal: P) -> impl Fn(&P) -> P::Scalar
+where
+    P: Coordinates,
+    P::Scalar: PrimInt,
+{
+    move |p| squared_euclidean_distance(p, &goal).integer_sqrt()
+}
+
+fn squared_euclidean_distance<P>(a: &P, b: &P) -> P::Scalar
+where
+    P: Coordinates,
+    P::Scalar: Zero + PartialOrd + Sub<Output = P::Scalar> + Mul<Output = P::Scalar>,
+{
+    differences(a, b).fold(Zero::zero(), |s, d| s + d * d)
+}
@@ -0,0 +1,299 @@
+//! Hierarchical pathfinding ([HPA*](https://webdocs.cs.ualberta.ca/~mmueller/ps/hpastar.pdf))
+//! on a [`Grid`].
+
+use crate::directed::astar::astar;
+use crate::directed::bfs::bfs;
+use crate::grid::Grid;
+use rustc_hash::FxHashMap;
+
+type Vertex = (usize, usize);
+
+/// Entrances shorter than this get a single transition in their middle, longer
+/// ones get one transition at each end.
+const LONG_ENTRANCE: usize = 6;
+
+/// A [`Grid`] partitioned into square clusters, along with an abstract graph
+/// allowing to answer pathfinding queries without exploring the whole grid.
+///
+/// The grid is cut into clusters of `cluster_size` × `cluster_size` vertices.
+/// Along every border shared by two clusters, contiguous runs of vertices
+/// connected across the border form entrances, each of them giving one or two
+/// transitions between the clusters. In diagonal mode, runs of diagonal crossings
+/// form entrances as well. The shortest paths between the transitions
+/// of a same cluster are precomputed, so that a query only has to connect the start
+/// and goal vertices to the transitions of their clusters and search the small
+/// abstract graph made of transitions.
+///
+/// The paths found are not always the shortest ones, as they are constrained to go
+/// through transitions, but are usually close to them. The cost of a query depends
+/// mostly on the number of clusters, and not on the number of vertices of the grid.
+///
+/// The grid is copied during the preprocessing, so later modifications of the
+/// original grid are not taken into account.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{Grid, HierarchicalGrid};
+///
+/// let mut grid = Grid::new(40, 40);
+/// grid.fill();
+/// // A wall with a single opening at the bottom.
+/// for y in 0..39 {
+///     grid.remove_vertex((20, y));
+/// }
+/// let hpa = HierarchicalGrid::new(&grid, 8);
+///
+/// let (path, cost) = hpa.find_path((2, 2), (37, 2)).expect("no path found");
+/// assert_eq!(path.first(), Some(&(2, 2)));
+/// assert_eq!(path.last(), Some(&(37, 2)));
+/// assert!(path.contains(&(20, 39)));
+/// assert_eq!(cost, path.len() - 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HierarchicalGrid {
+    grid: Grid,
+    cluster_size: usize,
+    transitions: FxHashMap<Vertex, Vec<Vertex>>,
+    edges: FxHashMap<Vertex, Vec<(Vertex, usize)>>,
+    paths: FxHashMap<(Vertex, Vertex), Vec<Vertex>>,
+}
+
+impl HierarchicalGrid {
+    /// Partition `grid` into clusters of `cluster_size` × `cluster_size` vertices and
+    /// precompute the abstract graph.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `cluster_size` is zero.
+    #[must_use]
+    pub fn new(grid: &Grid, cluster_size: usize) -> Self {
+        assert!(cluster_size > 0, "cluster size must be positive");
+        let mut hpa = Self {
+            grid: grid.clone(),
+            cluster_size,
+            transitions: FxHashMap::default(),
+            edges: FxHashMap::default(),
+            paths: FxHashMap::default(),
+        };
+        hpa.build_entrances();
+        hpa.build_intra_edges();
+        hpa
+    }
+
+    /// The grid the abstract graph has been built from.
+    #[must_use]
+    pub const fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// The size of the clusters.
+    #[must_use]
+    pub const fn cluster_size(&self) -> usize {
+        self.cluster_size
+    }
+
+    /// The cluster containing a vertex, as `(column, row)` coordinates.
+    #[must_use]
+    pub const fn cluster(&self, vertex: Vertex) -> Vertex {
+        (vertex.0 / self.cluster_size, vertex.1 / self.cluster_size)
+    }
+
+    /// Number of transitions, which are the nodes of the abstract graph.
+    #[must_use]
+    pub fn transitions_len(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn add_transition(&mut self, a: Vertex, b: Vertex) {
+        for (from, to) in [(a, b), (b, a)] {
+            let edges = self.edges.entry(from).or_insert_with(|| {
+                self.transitions
+                    .entry((from.0 / self.cluster_size, from.1 / self.cluster_size))
+                    .or_default()
+                    .push(from);
+                Vec::new()
+            });
+            if !edges.iter().any(|&(n, _)| n == to) {
+                edges.push((to, 1));
+            }
+        }
+    }
+
+    fn build_entrances(&mut self) {
+        let size = self.cluster_size;
+        // Vertical borders, between columns x - 1 and x.
+        for x in (size..self.grid.width).step_by(size) {
+            for y0 in (0..self.grid.height).step_by(size) {
+                let ys = y0..(y0 + size).min(self.grid.height);
+                self.add_entrances(ys.clone().map(|y| ((x - 1, y), (x, y))));
+                // Diagonal crossings, which only exist in diagonal mode.
+                let ys = ys.start..ys.end - 1;
+                self.add_entrances(ys.clone().map(|y| ((x - 1, y), (x, y + 1))));
+                self.add_entrances(ys.map(|y| ((x - 1, y + 1), (x, y))));
+            }
+        }
+        // Horizontal borders, between rows y - 1 and y.
+        for y in (size..self.grid.height).step_by(size) {
+            for x0 in (0..self.grid.width).step_by(size) {
+                let xs = x0..(x0 + size).min(self.grid.width);
+                self.add_entrances(xs.clone().map(|x| ((x, y - 1), (x, y))));
+                let xs = xs.start..xs.end - 1;
+                self.add_entrances(xs.clone().map(|x| ((x, y - 1), (x + 1, y))));
+                self.add_entrances(xs.map(|x| ((x + 1, y - 1), (x, y))));
+            }
+        }
+        // Diagonal crossings through a point where four clusters meet.
+        for x in (size..self.grid.width).step_by(size) {
+            for y in (size..self.grid.height).step_by(size) {
+                for (a, b) in [((x - 1, y - 1), (x, y)), ((x, y - 1), (x - 1, y))] {
+                    if self.grid.has_edge(a, b) {
+                        self.add_transition(a, b);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Add transitions for the entrances found along a border, given as pairs of
+    /// facing vertices.
+    fn add_entrances(&mut self, pairs: impl Iterator<Item = (Vertex, Vertex)>) {
+        let pairs = pairs.collect::<Vec<_>>();
+        let mut runs = Vec::new();
+        let mut start = None;
+        for (i, &(a, b)) in pairs.iter().enumerate() {
+            if self.grid.has_edge(a, b) {
+                start.get_or_insert(i);
+            } else if let Some(s) = start.take() {
+                runs.push(s..i);
+            }
+        }
+        if let Some(s) = start {
+            runs.push(s..pairs.len());
+        }
+        for run in runs {
+            if run.len() >= LONG_ENTRANCE {
+                let (a, b) = pairs[run.start];
+                self.add_transition(a, b);
+                let (a, b) = pairs[run.end - 1];
+                self.add_transition(a, b);
+            } else {
+                let (a, b) = pairs[run.start + run.len() / 2];
+                self.add_transition(a, b);
+            }
+        }
+    }
+
+    fn build_intra_edges(&mut self) {
+        let transitions = self.transitions.values().cloned().collect::<Vec<_>>();
+        for cluster in transitions {
+            for (i, &a) in cluster.iter().enumerate() {
+                for &b in &cluster[i + 1..] {
+                    if let Some(path) = self.local_path(a, b) {
+                        let cost = path.len() - 1;
+                        self.edges.get_mut(&a).unwrap().push((b, cost)); // Cannot fail, a is a transition
+                        self.edges.get_mut(&b).unwrap().push((a, cost)); // Cannot fail, b is a transition
+                        self.paths
+                            .insert((b, a), path.iter().rev().copied().collect());
+                        self.paths.insert((a, b), path);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Shortest path between two vertices of a same cluster, staying inside the cluster.
+    fn local_path(&self, from: Vertex, to: Vertex) -> Option<Vec<Vertex>> {
+        let cluster = self.cluster(from);
+        bfs(
+            &from,
+            |&n| {
+                let mut neighbours = self.grid.neighbours(n);
+                neighbours.retain(|&m| self.cluster(m) == cluster);
+                neighbours
+            },
+            |&n| n == to,
+        )
+    }
+
+    /// Path from `start` to `goal` going through the abstract graph, made of `start`,
+    /// the transitions used, and `goal`, along with its cost.
+    ///
+    /// `None` is returned if `start` or `goal` is not a vertex of the grid, or if no
+    /// path exists between them.
+    #[must_use]
+    pub fn abstract_path(&self, start: Vertex, goal: Vertex) -> Option<(Vec<Vertex>, usize)> {
+        self.search(start, goal).map(|(path, cost, _)| (path, cost))
+    }
+
+    /// Path from `start` to `goal` going through the abstract graph, refined into a path
+    /// made of adjacent vertices, along with its cost.
+    ///
+    /// `None` is returned if `start` or `goal` is not a vertex of the grid, or if no
+    /// path exists between them.
+    #[must_use]
+    pub fn find_path(&self, start: Vertex, goal: Vertex) -> Option<(Vec<Vertex>, usize)> {
+        let (waypoints, cost, temporary) = self.search(start, goal)?;
+        let mut path = vec![start];
+        for w in waypoints.windows(2) {
+            let key = (w[0], w[1]);
+            match temporary.get(&key).or_else(|| self.paths.get(&key)) {
+                Some(segment) => path.extend_from_slice(&segment[1..]),
+                // Transitions between two clusters are adjacent vertices.
+                None => path.push(w[1]),
+            }
+        }
+        Some((path, cost))
+    }
+
+    #[expect(clippy::type_complexity)]
+    fn search(
+        &self,
+        start: Vertex,
+        goal: Vertex,
+    ) -> Option<(Vec<Vertex>, usize, FxHashMap<(Vertex, Vertex), Vec<Vertex>>)> {
+        if !self.grid.has_vertex(start) || !self.grid.has_vertex(goal) {
+            return None;
+        }
+        // Temporary edges linking the start and goal vertices to the transitions of
+        // their clusters, or directly to each other if they share a cluster.
+        let mut temporary = FxHashMap::default();
+        let mut start_edges = Vec::new();
+        let mut goal_edges = FxHashMap::default();
+        if self.cluster(start) == self.cluster(goal) {
+            if let Some(path) = self.local_path(start, goal) {
+                start_edges.push((goal, path.len() - 1));
+                temporary.insert((start, goal), path);
+            }
+        }
+        let empty = Vec::new();
+        for &t in self.transitions.get(&self.cluster(start)).unwrap_or(&empty) {
+            if let Some(path) = self.local_path(start, t) {
+                start_edges.push((t, path.len() - 1));
+                temporary.insert((start, t), path);
+            }
+        }
+        for &t in self.transitions.get(&self.cluster(goal)).unwrap_or(&empty) {
+            if let Some(path) = self.local_path(t, goal) {
+                goal_edges.insert(t, path.len() - 1);
+                temporary.insert((t, goal), path);
+            }
+        }
+        let (path, cost) = astar(
+            &start,
+            |&n| {
+                let mut successors = self.edges.get(&n).cloned().unwrap_or_default();
+                if n == start {
+                    successors.extend_from_slice(&start_edges);
+                }
+                if let Some(&cost) = goal_edges.get(&n) {
+                    successors.push((goal, cost));
+                }
+                successors
+            },
+            |&n| self.grid.distance(n, goal),
+            |&n| n == goal,
+        )?;
+        Some((path, cost, temporary))
+    }
+}
@@ -0,0 +1,164 @@
+//! An [indexed priority queue](https://en.wikipedia.org/wiki/Priority_queue) whose
+//! elements are identified by an index, and whose keys can be decreased in place.
+
+/// A binary heap returning its element with the smallest key first, in which every
+/// element is identified by an index and stored at most once.
+///
+/// Pushing an element which is already present only lowers its key if the new one is
+/// smaller, instead of adding a duplicate entry. Searches in which the cost of a node
+/// is improved many times can then keep a smaller heap than with a
+/// [`BinaryHeap`](std::collections::BinaryHeap), at the price of maintaining the position
+/// of every element.
+///
+/// Memory proportional to the largest index ever pushed is used, indices are thus
+/// expected to be small, such as the ones of nodes in an `IndexMap`.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::IndexedHeap;
+///
+/// let mut heap = IndexedHeap::new();
+/// heap.push(0, 10);
+/// heap.push(1, 20);
+/// assert!(heap.push(1, 5));
+/// assert!(!heap.push(0, 15));
+/// assert_eq!(heap.len(), 2);
+/// assert_eq!(heap.pop(), Some((1, 5)));
+/// assert_eq!(heap.pop(), Some((0, 10)));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IndexedHeap<K> {
+    heap: Vec<(K, usize)>,
+    positions: Vec<usize>,
+}
+
+impl<K: Ord> IndexedHeap<K> {
+    /// Create an empty heap.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    /// The number of elements in the heap.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Check whether the heap is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Check whether the element identified by `index` is in the heap.
+    #[must_use]
+    pub fn contains(&self, index: usize) -> bool {
+        self.position(index).is_some()
+    }
+
+    /// The key of the element identified by `index`, or `None` if it is not in the heap.
+    #[must_use]
+    pub fn key(&self, index: usize) -> Option<&K> {
+        self.position(index).map(|pos| &self.heap[pos].0)
+    }
+
+    /// Add the element identified by `index` to the heap with priority `key`. If it is
+    /// already present, its key is replaced by `key` if this one is smaller.
+    ///
+    /// Return `true` if the element has been added or its key decreased.
+    pub fn push(&mut self, index: usize, key: K) -> bool {
+        if let Some(pos) = self.position(index) {
+            if key >= self.heap[pos].0 {
+                return false;
+            }
+            self.heap[pos].0 = key;
+            self.sift_up(pos);
+        } else {
+            if index >= self.positions.len() {
+                self.positions.resize(index + 1, usize::MAX);
+            }
+            self.positions[index] = self.heap.len();
+            self.heap.push((key, index));
+            self.sift_up(self.heap.len() - 1);
+        }
+        true
+    }
+
+    /// Remove and return the index and key of an element with the smallest key, or
+    /// `None` if the heap is empty.
+    pub fn pop(&mut self) -> Option<(usize, K)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let (key, index) = self.heap.swap_remove(0);
+        self.positions[index] = usize::MAX;
+        if let Some(&(_, first)) = self.heap.first() {
+            self.positions[first] = 0;
+            self.sift_down(0);
+        }
+        Some((index, key))
+    }
+
+    /// Remove all the elements from the heap.
+    pub fn clear(&mut self) {
+        for (_, index) in self.heap.drain(..) {
+            self.positions[index] = usize::MAX;
+        }
+    }
+
+    fn position(&self, index: usize) -> Option<usize> {
+        self.positions
+            .get(index)
+            .copied()
+            .filter(|&pos| pos != usize::MAX)
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a].1] = a;
+        self.positions[self.heap[b].1] = b;
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[pos].0 >= self.heap[parent].0 {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = 2 * pos + 1;
+            if left >= self.heap.len() {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < self.heap.len() && self.heap[right].0 < self.heap[left].0 {
+                right
+            } else {
+                left
+            };
+            if self.heap[child].0 >= self.heap[pos].0 {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+}
+
+impl<K: Ord> Default for IndexedHeap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
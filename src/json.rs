@@ -0,0 +1,234 @@
+//! Load graphs described in a simple JSON format, for example when they are posted by a
+//! web front-end.
+//!
+//! This module is only available when the `json` feature is enabled.
+//!
+//! A graph is an object with a list of `nodes`, each one having an `id` and an optional
+//! `position`, a list of `edges`, each one having a `source`, a `target` and a `weight`,
+//! and an optional `directed` flag which defaults to `true`:
+//!
+//! ```json
+//! {
+//!   "directed": false,
+//!   "nodes": [
+//!     { "id": "a", "position": [0.0, 0.0] },
+//!     { "id": "b", "position": [3.0, 4.0] }
+//!   ],
+//!   "edges": [
+//!     { "source": "a", "target": "b", "weight": 5 }
+//!   ]
+//! }
+//! ```
+//!
+//! Node identifiers and weights can be of any type which can be deserialized, such as
+//! strings or integers for the former and integers or floating-point numbers for the
+//! latter.
+
+use num_traits::Zero;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+use std::io::Read;
+use thiserror::Error;
+
+/// Error encountered while loading a JSON graph.
+#[derive(Debug, Error)]
+pub enum JsonGraphError {
+    /// The input is not valid JSON or does not describe a graph
+    #[error("cannot parse graph: {0}")]
+    Json(#[from] serde_json::Error),
+    /// A node identifier is used by several nodes
+    #[error("node {0} has the same identifier as a previous node")]
+    DuplicateNode(usize),
+    /// An edge refers to a node which is not part of the graph
+    #[error("edge {0} refers to an unknown node")]
+    UnknownNode(usize),
+}
+
+/// A node of a [`JsonGraph`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonNode<N> {
+    /// Identifier of the node.
+    pub id: N,
+    /// Position of the node, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<(f64, f64)>,
+}
+
+/// A weighted edge of a [`JsonGraph`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonEdge<N, C> {
+    /// Identifier of the source node.
+    pub source: N,
+    /// Identifier of the target node.
+    pub target: N,
+    /// Weight of the edge.
+    pub weight: C,
+}
+
+const fn directed() -> bool {
+    true
+}
+
+/// A graph loaded from or serialized to JSON.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{astar, JsonGraph};
+///
+/// let input = r#"{
+///     "directed": false,
+///     "nodes": [
+///         { "id": "a", "position": [0, 0] },
+///         { "id": "b", "position": [3, 0] },
+///         { "id": "c", "position": [3, 4] }
+///     ],
+///     "edges": [
+///         { "source": "a", "target": "b", "weight": 3 },
+///         { "source": "b", "target": "c", "weight": 4 },
+///         { "source": "a", "target": "c", "weight": 9 }
+///     ]
+/// }"#;
+/// let graph = JsonGraph::<String, u32>::from_json(input).unwrap();
+/// let goal = "c".to_string();
+/// let result = astar(
+///     &"a".to_string(),
+///     graph.successors(),
+///     graph.heuristic(&goal, |(x1, y1), (x2, y2)| (x1 - x2).hypot(y1 - y2) as u32),
+///     |n| *n == goal,
+/// );
+/// assert_eq!(result, Some((vec!["a".into(), "b".into(), "c".into()], 7)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonGraph<N, C> {
+    /// `true` if edges can only be followed from their source to their target, `false`
+    /// if they can be followed both ways.
+    #[serde(default = "directed")]
+    pub directed: bool,
+    /// Nodes of the graph.
+    pub nodes: Vec<JsonNode<N>>,
+    /// Edges of the graph.
+    pub edges: Vec<JsonEdge<N, C>>,
+}
+
+impl<N, C> JsonGraph<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Clone,
+{
+    /// Check that node identifiers are unique and that edges refer to existing nodes.
+    ///
+    /// # Errors
+    ///
+    /// The index of the first duplicated node or of the first edge referring to an
+    /// unknown node is returned in the error.
+    pub fn validate(&self) -> Result<(), JsonGraphError> {
+        let mut ids = FxHashSet::default();
+        if let Some(i) = self.nodes.iter().position(|n| !ids.insert(&n.id)) {
+            return Err(JsonGraphError::DuplicateNode(i));
+        }
+        match self
+            .edges
+            .iter()
+            .position(|e| !ids.contains(&e.source) || !ids.contains(&e.target))
+        {
+            Some(i) => Err(JsonGraphError::UnknownNode(i)),
+            None => Ok(()),
+        }
+    }
+
+    /// Load and validate a graph from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input does not describe a graph, or if the graph does
+    /// not pass [validation](Self::validate).
+    pub fn from_json(input: &str) -> Result<Self, JsonGraphError>
+    where
+        N: DeserializeOwned,
+        C: DeserializeOwned,
+    {
+        let graph: Self = serde_json::from_str(input)?;
+        graph.validate()?;
+        Ok(graph)
+    }
+
+    /// Load and validate a graph from a reader, such as a file or the body of a request.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input cannot be read or does not describe a graph, or
+    /// if the graph does not pass [validation](Self::validate).
+    pub fn from_reader<R: Read>(input: R) -> Result<Self, JsonGraphError>
+    where
+        N: DeserializeOwned,
+        C: DeserializeOwned,
+    {
+        let graph: Self = serde_json::from_reader(input)?;
+        graph.validate()?;
+        Ok(graph)
+    }
+
+    /// Serialize the graph to a JSON string, in the format read by
+    /// [`from_json`](Self::from_json).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a node identifier or a weight cannot be serialized.
+    pub fn to_json(&self) -> Result<String, JsonGraphError>
+    where
+        N: Serialize,
+        C: Serialize,
+    {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Build a successors function, to be given to a search algorithm, returning the
+    /// neighbours of a node along with the weight of the edge leading to them. In an
+    /// undirected graph, every edge can be followed in both directions.
+    ///
+    /// The function owns a copy of the adjacency lists, so that the graph can be
+    /// dropped or modified afterwards.
+    pub fn successors(&self) -> impl Fn(&N) -> Vec<(N, C)> + use<N, C> {
+        let mut adjacency: FxHashMap<N, Vec<(N, C)>> = FxHashMap::default();
+        for edge in &self.edges {
+            adjacency
+                .entry(edge.source.clone())
+                .or_default()
+                .push((edge.target.clone(), edge.weight.clone()));
+            if !self.directed && edge.source != edge.target {
+                adjacency
+                    .entry(edge.target.clone())
+                    .or_default()
+                    .push((edge.source.clone(), edge.weight.clone()));
+            }
+        }
+        move |node| adjacency.get(node).cloned().unwrap_or_default()
+    }
+
+    /// Build a heuristic function towards `goal`, to be given to a search algorithm such
+    /// as [`astar`](crate::directed::astar::astar), which applies `distance` to the
+    /// positions of a node and of the goal. The heuristic is zero when one of the
+    /// positions is unknown.
+    ///
+    /// As usual, `distance` must never overestimate the cost of the cheapest path between
+    /// two nodes for the search algorithms to find an optimal path.
+    pub fn heuristic<FD>(&self, goal: &N, distance: FD) -> impl Fn(&N) -> C + use<N, C, FD>
+    where
+        C: Zero,
+        FD: Fn((f64, f64), (f64, f64)) -> C,
+    {
+        let positions = self
+            .nodes
+            .iter()
+            .filter_map(|n| Some((n.id.clone(), n.position?)))
+            .collect::<FxHashMap<_, _>>();
+        let target = positions.get(goal).copied();
+        move |node| match (positions.get(node), target) {
+            (Some(&position), Some(target)) => distance(position, target),
+            _ => C::zero(),
+        }
+    }
+}
@@ -118,6 +118,20 @@ pub fn kuhn_munkres<C, W>(weights: &W) -> (C, Vec<usize>)
 where
     C: Bounded + Sum<C> + Signed + Zero + Ord + Copy,
     W: Weights<C>,
+{
+    let (assignments, lx, ly) = hungarian(weights, vec![Zero::zero(); weights.columns()]);
+    (
+        lx.into_iter().sum::<C>() + ly.into_iter().sum(),
+        assignments,
+    )
+}
+
+/// Run the Hungarian algorithm starting from the labelling `ly` of the columns, and
+/// return the assignments along with the final labellings of the rows and columns.
+fn hungarian<C, W>(weights: &W, mut ly: Vec<C>) -> (Vec<usize>, Vec<C>, Vec<C>)
+where
+    C: Bounded + Signed + Zero + Ord + Copy,
+    W: Weights<C>,
 {
     // We call x the rows and y the columns. (nx, ny) is the size of the matrix.
     let nx = weights.rows();
@@ -130,12 +144,16 @@ where
     let mut xy: Vec<Option<usize>> = vec![None; nx];
     let mut yx: Vec<Option<usize>> = vec![None; ny];
     // lx is the labelling for x nodes, ly the labelling for y nodes. We start
-    // with an acceptable labelling with the maximum possible values for lx
-    // and 0 for ly.
+    // with an acceptable labelling with the minimum possible values for lx
+    // given ly.
     let mut lx: Vec<C> = (0..nx)
-        .map(|row| (0..ny).map(|col| weights.at(row, col)).max().unwrap())
+        .map(|row| {
+            (0..ny)
+                .map(|col| weights.at(row, col) - ly[col])
+                .max()
+                .unwrap()
+        })
         .collect::<Vec<_>>();
-    let mut ly: Vec<C> = vec![Zero::zero(); ny];
     // s, augmenting, and slack will be reset every time they are reused. augmenting
     // contains Some(prev) when the corresponding node belongs to the augmenting path.
     let mut s = FxIndexSet::<usize>::default();
@@ -222,8 +240,9 @@ where
         }
     }
     (
-        lx.into_iter().sum::<C>() + ly.into_iter().sum(),
         xy.into_iter().map(Option::unwrap).collect::<Vec<_>>(),
+        lx,
+        ly,
     )
 }
 
@@ -262,3 +281,184 @@ where
     let (total, assignments) = kuhn_munkres(&weights.neg());
     (-total, assignments)
 }
+
+/// An optimal assignment computed by [`kuhn_munkres_with_duals`] or
+/// [`kuhn_munkres_min_with_duals`], along with the dual potentials (the labels of the
+/// rows and columns) certifying its optimality.
+///
+/// When maximizing, the sum of the labels of a row and a column is never smaller than
+/// the weight between them, and is equal to it for assigned pairs. When minimizing, it
+/// is never larger than the weight, and equal to it for assigned pairs. In both cases,
+/// the difference is the [slack](Self::slack) of the pair, which bounds how much its
+/// weight can change while the assignments stay optimal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KuhnMunkresSolution<C> {
+    total: C,
+    assignments: Vec<usize>,
+    row_labels: Vec<C>,
+    column_labels: Vec<C>,
+    minimize: bool,
+}
+
+impl<C> KuhnMunkresSolution<C>
+where
+    C: Signed + Copy,
+{
+    /// The total weight of the assignments.
+    #[must_use]
+    pub const fn total(&self) -> C {
+        self.total
+    }
+
+    /// The column assigned to every row.
+    #[must_use]
+    pub fn assignments(&self) -> &[usize] {
+        &self.assignments
+    }
+
+    /// The dual potential of every row.
+    #[must_use]
+    pub fn row_labels(&self) -> &[C] {
+        &self.row_labels
+    }
+
+    /// The dual potential of every column, which can be given back to warm-start the
+    /// resolution of a slightly modified problem.
+    #[must_use]
+    pub fn column_labels(&self) -> &[C] {
+        &self.column_labels
+    }
+
+    /// The slack of the pair made of `row` and `column`, which is never negative and is
+    /// zero for assigned pairs.
+    ///
+    /// When maximizing, the weight of an unassigned pair can increase by up to its slack
+    /// while the assignments stay optimal. When minimizing, it can decrease by up to its
+    /// slack.
+    #[must_use]
+    pub fn slack<W: Weights<C>>(&self, weights: &W, row: usize, column: usize) -> C {
+        let labels = self.row_labels[row] + self.column_labels[column];
+        if self.minimize {
+            weights.at(row, column) - labels
+        } else {
+            labels - weights.at(row, column)
+        }
+    }
+}
+
+/// Compute a maximum weight maximum matching between two disjoints sets of
+/// vertices using the
+/// [Kuhn-Munkres algorithm](https://en.wikipedia.org/wiki/Hungarian_algorithm),
+/// and return the dual potentials along with the assignments.
+///
+/// This function behaves like [`kuhn_munkres()`], and its result can be used for
+/// sensitivity analysis through [`KuhnMunkresSolution::slack`]. When a problem has to
+/// be solved again after some of its weights have changed, the column labels of the
+/// previous solution can be given as `column_labels` to warm-start the resolution. Any
+/// labels can be given, as the row labels are computed to make them acceptable, but the
+/// closer they are to optimal labels, the fewer steps the resolution takes.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{kuhn_munkres_with_duals, Matrix};
+///
+/// // The art gallery example of `kuhn_munkres`.
+/// let mut weights = Matrix::from_rows(vec![
+///     vec![100, 110, 90],
+///     vec![95, 130, 75],
+///     vec![95, 140, 65],
+/// ]).unwrap();
+/// let solution = kuhn_munkres_with_duals(&weights, None);
+/// assert_eq!(solution.total(), 325);
+/// assert_eq!(solution.assignments(), &[2, 0, 1]);
+///
+/// // The assignments stay optimal as long as Ann does not value the Statue more than
+/// // $25 above her current estimate, and assigned pairs have no slack.
+/// assert_eq!(solution.slack(&weights, 0, 1), 25);
+/// assert_eq!(solution.slack(&weights, 0, 2), 0);
+///
+/// // Ann now values the Cloth $15 more, and the assignments are computed again
+/// // starting from the previous labels.
+/// weights[(0, 0)] += 15;
+/// let solution = kuhn_munkres_with_duals(&weights, Some(solution.column_labels()));
+/// assert_eq!(solution.total(), 330);
+/// assert_eq!(solution.assignments(), &[0, 2, 1]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics if the number of rows is larger than the number of
+/// columns, if the total assignments weight overflows or underflows, or if
+/// `column_labels` are given for weights which do not have as many rows as columns
+/// or do not have one label per column.
+///
+/// Also, using indefinite values such as positive or negative infinity or
+/// NaN can cause this function to loop endlessly.
+pub fn kuhn_munkres_with_duals<C, W>(
+    weights: &W,
+    column_labels: Option<&[C]>,
+) -> KuhnMunkresSolution<C>
+where
+    C: Bounded + Sum<C> + Signed + Zero + Ord + Copy,
+    W: Weights<C>,
+{
+    let ly = match column_labels {
+        Some(labels) => {
+            assert!(
+                weights.rows() == weights.columns() && labels.len() == weights.columns(),
+                "column labels can only be given for square weights, one per column"
+            );
+            labels.to_vec()
+        }
+        None => vec![Zero::zero(); weights.columns()],
+    };
+    let (assignments, row_labels, column_labels) = hungarian(weights, ly);
+    KuhnMunkresSolution {
+        total: assignments
+            .iter()
+            .enumerate()
+            .map(|(row, &col)| weights.at(row, col))
+            .sum(),
+        assignments,
+        row_labels,
+        column_labels,
+        minimize: false,
+    }
+}
+
+/// Compute a minimum weight maximum matching between two disjoints sets of
+/// vertices using the
+/// [Kuhn-Munkres algorithm](https://en.wikipedia.org/wiki/Hungarian_algorithm),
+/// and return the dual potentials along with the assignments.
+///
+/// This function behaves like [`kuhn_munkres_min()`], and its result and
+/// `column_labels` are used as in [`kuhn_munkres_with_duals()`].
+///
+/// # Panics
+///
+/// This function panics if the number of rows is larger than the number of
+/// columns, if the total assignments weight overflows or underflows, or if
+/// `column_labels` are given for weights which do not have as many rows as columns
+/// or do not have one label per column.
+///
+/// Also, using indefinite values such as positive or negative infinity or
+/// NaN can cause this function to loop endlessly.
+pub fn kuhn_munkres_min_with_duals<C, W>(
+    weights: &W,
+    column_labels: Option<&[C]>,
+) -> KuhnMunkresSolution<C>
+where
+    C: Bounded + Sum<C> + Signed + Zero + Ord + Copy,
+    W: Weights<C>,
+{
+    let negated = column_labels.map(|labels| labels.iter().map(|&l| -l).collect::<Vec<_>>());
+    let solution = kuhn_munkres_with_duals(&weights.neg(), negated.as_deref());
+    KuhnMunkresSolution {
+        total: -solution.total,
+        assignments: solution.assignments,
+        row_labels: solution.row_labels.into_iter().map(|l| -l).collect(),
+        column_labels: solution.column_labels.into_iter().map(|l| -l).collect(),
+        minimize: true,
+    }
+}
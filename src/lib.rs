@@ -14,36 +14,87 @@
 //! ### Directed graphs
 //!
 //! - [A*](directed/astar/index.html): find the shortest path in a weighted graph using an heuristic to guide the process ([⇒ Wikipedia][A*])
+//! - [Anytime D*](directed/anytime_dstar/index.html): plan paths in a graph whose costs change over time, improving a suboptimal path while repairing it after changes ([⇒ Wikipedia][D*])
+//! - [arc flags](directed/arc_flags/index.html): precompute edge flags on a partitioned graph to speed up repeated Dijkstra queries
+//! - [batched successors](directed/batched/index.html): merge duplicate successors and compute the successors of several nodes in a single call
 //! - [BFS](directed/bfs/index.html): explore nearest successors first, then widen the search ([⇒ Wikipedia][BFS])
 //! - [Bidirectional search](directed/bfs/fn.bfs_bidirectional.html): simultaneously explore paths forwards from the start and backwards from the goal ([=> Wikipedia][Bidirectional search])
 //! - [Brent](directed/cycle_detection/index.html): find a cycle in an infinite sequence ([⇒ Wikipedia][Brent])
+//! - [CBS](directed/cbs/index.html): find conflict-free paths for several agents sharing a graph ([⇒ Wikipedia][CBS])
+//! - [Chinese postman](directed/chinese_postman/index.html): find the cheapest closed walk going through every edge at least once ([⇒ Wikipedia][Chinese postman])
+//! - [dense node identifiers](directed/dense/index.html): run BFS, Dijkstra and A* on graphs whose nodes are `usize` indices, storing costs and parents in vectors instead of hash maps
 //! - [DFS](directed/dfs/index.html): explore a graph by going as far as possible, then backtrack ([⇒ Wikipedia][DFS])
+//! - [differential heuristic](directed/differential_heuristic/index.html): precompute distances from a few pivots to build an A* heuristic on undirected graphs
 //! - [Dijkstra](directed/dijkstra/index.html): find the shortest path in a weighted graph ([⇒ Wikipedia][Dijkstra])
+//! - [distance tables](directed/distance_table/index.html): compute the costs of the shortest paths from one node to many, from many nodes to one, or between two sets of nodes, sharing Dijkstra searches
 //! - [Edmonds Karp](directed/edmonds_karp/index.html): find the maximum flow in a weighted graph ([⇒ Wikipedia][Edmonds Karp])
+//! - [find cycle](directed/cycle_detection/fn.find_cycle.html): find a cycle in a directed graph using a depth-first search
 //! - [Floyd](directed/cycle_detection/index.html): find a cycle in an infinite sequence ([⇒ Wikipedia][Floyd])
 //! - [Fringe](directed/fringe/index.html): find the shortest path in a weighted graph using an heuristic to guide the process ([⇒ Wikipedia][Fringe])
+//! - [graph center](directed/center/index.html): find the node minimizing the largest or the total cost to reach the other nodes ([⇒ Wikipedia][Graph center])
+//! - [hub labels](directed/hub_labels/index.html): precompute node labels to answer distance queries without exploring the graph ([⇒ Wikipedia][Hub labels])
 //! - [IDA*](directed/idastar/index.html): explore longer and longer paths in a weighted graph at the cost of multiple similar examinations ([⇒ Wikipedia][IDA*])
 //! - [IDDFS](directed/iddfs/index.html): explore longer and longer paths in an unweighted graph at the cost of multiple similar examinations ([⇒ Wikipedia][IDDFS])
-//! - [paths counting](directed/count_paths/index.html): count the paths to the destination in an acyclic graph
+//! - [Johnson](directed/circuits/index.html): enumerate all the elementary circuits of a directed graph
+//! - [Karp](directed/min_mean_cycle/index.html): find the cycle with the minimum mean edge cost
+//! - [landmarks](directed/landmarks/index.html): precompute distances to a few nodes to build an A* heuristic on graphs without geometry
+//! - [minimax path](directed/bottleneck/fn.minimax_path.html): find the path minimizing the largest cost of its edges
+//! - [Pareto paths](directed/pareto/index.html): find all the non-dominated paths when edges have two independent costs ([⇒ Wikipedia][Pareto front])
+//! - [pattern databases](directed/pattern_database/index.html): precompute distances in an abstraction of the search space to build additive heuristics for IDA* and A* ([⇒ Wikipedia][Pattern database])
+//! - [path cache](directed/path_cache/index.html): cache shortest paths and drop only those affected by graph changes
+//! - [path cover](directed/path_cover/index.html): cover the nodes of an acyclic graph with a minimum number of vertex-disjoint paths ([⇒ Wikipedia][Path cover]), or find a maximum antichain ([⇒ Wikipedia][Dilworth])
+//! - [path repair](directed/repair/index.html): repair a path around the nodes which changed with a bounded local search, before falling back to a full search
+//! - [paths counting](directed/count_paths/index.html): count the paths to the destination in an acyclic graph, possibly modulo some value, or by length in any graph
+//! - [real-time search](directed/real_time/index.html): move an agent towards a goal using LRTA* or RTAA*, which look a bounded number of nodes ahead before every move and learn better heuristic values along the way
+//! - [search recorder](directed/recorder/index.html): record the nodes expanded, the successors found and the heuristic estimates of a search, to replay them or export them to JSON
+//! - [reversed graph](directed/reverse/index.html): build the predecessors of the nodes of a graph described by a successors function, to search backward from a goal
+//! - [semiring paths](directed/semiring/index.html): combine the weights of all paths using any semiring, covering shortest paths, most probable paths, path counting and reachability ([⇒ Wikipedia][Semiring])
+//! - [shortest cycle](directed/shortest_cycle/index.html): find a cycle of minimal cost, whose length in an unweighted graph is its girth ([⇒ Wikipedia][Girth])
+//! - [simple paths](directed/simple_paths/index.html): enumerate all the paths to the destination which never go through the same node twice
 //! - [strongly connected components](directed/strongly_connected_components/index.html): find strongly connected components in a directed graph ([⇒ Wikipedia][Strongly connected components])
-//! - [topological sorting](directed/topological_sort/index.html): find an acceptable topological order in a directed graph ([⇒ Wikipedia][Topological sorting])
+//! - [incremental strongly connected components](directed/incremental_scc/index.html): maintain strongly connected components while edges are added
+//! - [tie breaking](directed/tie_breaking/index.html): break ties between equally good paths randomly but reproducibly, given a seed
+//! - [time-expanded graphs](directed/time_expanded/index.html): search over (state, time) pairs with waiting actions and a time horizon
+//! - [topological sorting](directed/topological_sort/index.html): find an acceptable topological order in a directed graph, or group tasks by earliest start time given their durations ([⇒ Wikipedia][Topological sorting])
+//! - [dynamic topological sorting](directed/dynamic_topological_sort/index.html): maintain a topological order while edges are added, rejecting those creating a cycle
+//! - [turn costs](directed/turn_costs/index.html): search the edge-based version of a graph or its line graph to take turn costs and restrictions into account
+//! - [widest path](directed/bottleneck/fn.widest_path.html): find the path maximizing the smallest capacity of its edges ([⇒ Wikipedia][Widest path])
 //! - [Yen](directed/yen/index.html): find k-shortest paths using Dijkstra ([⇒ Wikipedia][Yen])
 //!
 //! ### Undirected graphs
 //!
-//! - [connected components](undirected/connected_components/index.html): find disjoint connected sets of vertices ([⇒ Wikipedia][Connected components])
+//! - [Chinese postman](undirected/chinese_postman/index.html): find the cheapest closed walk going through every edge at least once ([⇒ Wikipedia][Chinese postman])
+//! - [connected components](undirected/connected_components/index.html): find disjoint connected sets of vertices, possibly by exploring an implicit graph ([⇒ Wikipedia][Connected components])
 //! - [Kruskal](undirected/kruskal/index.html): find a minimum-spanning-tree ([⇒ Wikipedia][Kruskal])
 //! - [Prim](undirected/prim/index.html): find a minimum-spanning-tree ([⇒ Wikipedia][Prim])
+//! - [shortest cycle](undirected/shortest_cycle/index.html): find a cycle of minimal cost, whose length in an unweighted graph is its girth ([⇒ Wikipedia][Girth])
 //! - [cliques](undirected/cliques/index.html): find maximum cliques in a graph ([= Wikipedia][BronKerbosch])
 //!
 //! ### Matching
 //!
-//! - [Kuhn-Munkres](kuhn_munkres/index.html) (Hungarian algorithm): find the maximum (or minimum) matching in a weighted bipartite graph ([⇒ Wikipedia][Kuhn-Munkres])
+//! - [Kuhn-Munkres](kuhn_munkres/index.html) (Hungarian algorithm): find the maximum (or minimum) matching in a weighted bipartite graph, optionally along with its dual potentials ([⇒ Wikipedia][Kuhn-Munkres])
 //!
 //! ### Miscellaneous structures
 //!
-//! - A [`Grid`](grid/index.html) type representing a rectangular grid in which vertices can be added or removed, with automatic creation of edges between adjacent vertices.
-//! - A [`Matrix`](matrix/index.html) type to store data of arbitrary types, with neighbour-aware methods.
+//! - A [`ChunkedWorld`](chunks/index.html) type made of `Grid` chunks linked by portals, which can be loaded and unloaded at runtime, searched hierarchically through the portals of the loaded chunks.
+//! - Functions reading graphs, node coordinates and flow problems in the [DIMACS](dimacs/index.html) formats used by standard benchmark instances.
+//! - A [`graph_to_dot`](dot/fn.graph_to_dot.html) function and a [`SearchTrace`](dot/struct.SearchTrace.html) type recording the nodes explored by any search algorithm, both rendering graphs in the DOT language of Graphviz.
+//! - Functions computing the weighted [edit distance](edit_distance/index.html) and an optimal alignment between two sequences, restricting the search to a band when they are similar.
+//! - A [`GoalBoundedGrid`](goal_bounding/index.html) type storing, for every edge of a `Grid`, the bounding box of the goals reached optimally through it, to prune the edges explored by A*.
+//! - A [`Grid`](grid/index.html) type representing a rectangular grid in which vertices can be added or removed, with automatic creation of edges between adjacent vertices and shortest path methods.
+//! - A [`ReservationTable`](cooperative/index.html) type and a [`cooperative_astar`](cooperative/fn.cooperative_astar.html) function planning the moves of several agents on a `Grid` without collisions using WHCA*.
+//! - [Heuristic](heuristics/index.html) functions computing the Manhattan, Chebyshev, octile or Euclidean distance to a goal, ready to be given to A* or IDA*.
+//! - A [`HierarchicalGrid`](hpa/index.html) type partitioning a `Grid` into clusters to answer pathfinding queries on large grids using HPA*.
+//! - An [`IndexedHeap`](indexed_heap/index.html) type, a priority queue whose keys can be decreased in place, used by the `_indexed` variants of Dijkstra and A* to avoid duplicate entries.
+//! - A [`Matrix`](matrix/index.html) type to store data of arbitrary types, with neighbour-aware methods and cheapest paths over cell costs.
+//! - An [`Mcts`](mcts/index.html) type running Monte Carlo tree searches with UCT selection over user-provided actions and rewards, to choose actions in stochastic or adversarial domains within an iteration or time budget.
+//! - A [`NavMesh`](navmesh/index.html) type describing a walkable area made of convex polygons, searched with A* and smoothed using the funnel algorithm.
+//! - A [`Path`](path/index.html) type describing a path along with the cost of every step, returned by the `_path` variants of the search algorithms.
+//! - A [`PrunedGrid`](swamps/index.html) type detecting the dead ends and swamps of a `Grid`, regions that shortest paths between vertices outside them can avoid.
+//! - A [`RadixHeap`](radix_heap/index.html) type, a priority queue for unsigned integer keys used by the `_radix` variants of Dijkstra and A* to speed up large searches.
+//! - A [`SearchError`](directed/search_error/index.html) type returned by the `try_` variants of A*, Dijkstra and BFS, telling whether no path exists or whether the search exceeded its budget, was cancelled or overflowed its cost type.
+//! - A [`SymmetryReducedGrid`](symmetry_reduction/index.html) type decomposing the open areas of a `Grid` into empty rectangles, so that searches only expand the vertices on their perimeters.
+//! - Heuristics building and improving the routes of capacity-constrained vehicles serving customers from a depot, for the [vehicle routing problem](vrp/index.html) ([⇒ Wikipedia][Vehicle routing]).
 //!
 //! ## Example
 //!
@@ -69,12 +120,69 @@
 //! assert_eq!(result.expect("no path found").len(), 5);
 //! ```
 //!
+//! ## Optional features
+//!
+//! - `serde`: implement `Serialize` and `Deserialize` for the search checkpoints
+//!   (such as [`DijkstraCheckpoint`](directed::dijkstra::DijkstraCheckpoint)) so that
+//!   long-running searches can be suspended and resumed in another process.
+//! - `graphml`: read and write graphs in the [GraphML](http://graphml.graphdrawing.org/)
+//!   format (see the `graphml` module) for interchange with tools such as Gephi or
+//!   NetworkX.
+//! - `json`: load graphs from a simple JSON format (see the `json` module), for
+//!   example when they are posted by a web front-end. This implies `serde`.
+//! - `deterministic`: specify how ties between nodes or paths of equal cost are broken,
+//!   as described below.
+//! - `reference`: provide slow but obviously correct exhaustive implementations of
+//!   shortest path, assignment and maximum flow (see the `reference` module), to be used
+//!   as baselines in differential tests or fuzzers.
+//! - `generators`: generate random graphs and grids from a seeded random number generator
+//!   (see the `generators` module) for benchmarks and property tests.
+//! - `u32-index`: store the indices of parent nodes used by the Dijkstra, A\*, BFS and
+//!   fringe searches as `u32` instead of `usize`, which halves their size on 64-bit
+//!   targets. Searches exploring 2³² nodes or more will panic.
+//! - `rayon`: provide parallel versions of some algorithms, such as
+//!   `par_bfs`, running on the [rayon](https://docs.rs/rayon)
+//!   thread pool.
+//!
+//! ## Determinism
+//!
+//! The algorithms of this crate never read the clock nor draw random numbers, and their
+//! internal collections use a hasher without random seed, so that they can be used on
+//! targets such as `wasm32-unknown-unknown` without `getrandom`. Given the same graph,
+//! with successors returned in the same order, they return the same results and call
+//! the successors functions in the same order. The only exception is a Monte Carlo tree
+//! search run with [`MctsBudget::Time`](mcts::MctsBudget::Time), whose number of
+//! iterations depends on the clock, and which cannot be used on targets without a
+//! system time.
+//!
+//! Without the `deterministic` feature, ties between nodes or paths of equal cost are
+//! still broken consistently, but in a way which depends on the implementation of the
+//! standard library (such as `BinaryHeap` or `sort_unstable`), which may change between
+//! Rust releases. With the `deterministic` feature, ties are broken in a documented way,
+//! which is useful when several programs built separately must agree, for example in
+//! lockstep multiplayer simulations:
+//!
+//! - Searches using a priority queue (Dijkstra, A\*, Yen) expand first, among the nodes
+//!   of equal priority, the one which was discovered first, and return paths of equal
+//!   cost and length in the order in which they were found.
+//! - IDA\* explores successors of equal estimated cost in the order they were given, and
+//!   Kruskal considers edges of equal weight in the order they were given.
+//!
+//! Functions returning standard `HashSet` or `HashMap` values, such as
+//! [`connected_components`](undirected::connected_components::connected_components),
+//! compute the same content everywhere, but iterating over those collections yields an
+//! order which depends on their random seed: sort their content, or use the
+//! [`ConnectedComponents`](undirected::connected_components::ConnectedComponents) variant
+//! with deterministic collections, before relying on the iteration order.
+//!
 //! ## Note on floating-point types
 //!
 //! Several algorithms require that the numerical types used to describe
 //! edge weights implement `Ord`. If you wish to use Rust built-in
 //! floating-point types (such as `f32`) that implement `PartialOrd`
-//! in this context, you can wrap them into compliant types using the
+//! in this context, you can wrap them into the [`TotalCost`](total_cost::TotalCost)
+//! type which rejects NaN values and works with every weighted algorithm of
+//! this crate. You can also use compliant types from the
 //! [ordered-float](https://crates.io/crates/ordered-float) crate.
 //!
 //! The minimum supported Rust version (MSRV) is Rust 1.86.0.
@@ -83,35 +191,76 @@
 //! [BFS]: https://en.wikipedia.org/wiki/Breadth-first_search
 //! [Bidirectional search]: https://en.wikipedia.org/wiki/Bidirectional_search
 //! [Brent]: https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+//! [CBS]: https://en.wikipedia.org/wiki/Multi-agent_pathfinding#Conflict-Based_Search
 //! [BronKerbosch]: https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+//! [Chinese postman]: https://en.wikipedia.org/wiki/Chinese_postman_problem
 //! [Connected components]: https://en.wikipedia.org/wiki/Connected_component_(graph_theory)
+//! [D*]: https://en.wikipedia.org/wiki/D*
 //! [DFS]: https://en.wikipedia.org/wiki/Depth-first_search
 //! [Dijkstra]: https://en.wikipedia.org/wiki/Dijkstra's_algorithm
+//! [Dilworth]: https://en.wikipedia.org/wiki/Dilworth%27s_theorem
 //! [Edmonds Karp]: https://en.wikipedia.org/wiki/Edmonds–Karp_algorithm
 //! [Floyd]: https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
 //! [Fringe]: https://en.wikipedia.org/wiki/Fringe_search
+//! [Girth]: https://en.wikipedia.org/wiki/Girth_(graph_theory)
+//! [Graph center]: https://en.wikipedia.org/wiki/Graph_center
+//! [Hub labels]: https://en.wikipedia.org/wiki/Hub_labels
 //! [IDA*]: https://en.wikipedia.org/wiki/Iterative_deepening_A*
 //! [IDDFS]: https://en.wikipedia.org/wiki/Iterative_deepening_depth-first_search
 //! [Kruskal]: https://en.wikipedia.org/wiki/Kruskal's_algorithm
 //! [Kuhn-Munkres]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+//! [Pareto front]: https://en.wikipedia.org/wiki/Pareto_front
+//! [Path cover]: https://en.wikipedia.org/wiki/Path_cover
+//! [Pattern database]: https://en.wikipedia.org/wiki/Pattern_database
 //! [Prim]: https://en.wikipedia.org/wiki/Prim's_algorithm
 //! [Rust]: https://rust-lang.org/
+//! [Semiring]: https://en.wikipedia.org/wiki/Semiring
 //! [Strongly connected components]: https://en.wikipedia.org/wiki/Strongly_connected_component
 //! [Topological sorting]: https://en.wikipedia.org/wiki/Topological_sorting
+//! [Vehicle routing]: https://en.wikipedia.org/wiki/Vehicle_routing_problem
+//! [Widest path]: https://en.wikipedia.org/wiki/Widest_path_problem
 //! [Yen]: https://en.wikipedia.org/wiki/Yen's_algorithm
 
 use deprecate_until::deprecate_until;
 pub use num_traits;
 
+pub mod avoid;
+pub mod chunks;
+pub mod cooperative;
+pub mod dimacs;
 pub mod directed;
+pub mod dot;
+pub mod edit_distance;
+#[cfg(feature = "generators")]
+pub mod generators;
+pub mod goal_bounding;
+#[cfg(feature = "graphml")]
+pub mod graphml;
 pub mod grid;
+pub mod heuristics;
+pub mod hpa;
+pub mod indexed_heap;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod kuhn_munkres;
 pub mod matrix;
+pub mod mcts;
+pub mod navmesh;
+pub mod path;
+pub mod radix_heap;
+#[cfg(feature = "reference")]
+pub mod reference;
+pub mod swamps;
+pub mod symmetry_reduction;
+pub mod total_cost;
 pub mod undirected;
 pub mod utils;
+pub mod vrp;
 
 mod noderefs;
 pub use noderefs::NodeRefs;
+#[cfg(feature = "rayon")]
+mod sharded;
 
 use indexmap::{IndexMap, IndexSet};
 use rustc_hash::FxHasher;
@@ -122,26 +271,87 @@ type FxIndexSet<K> = IndexSet<K, BuildHasherDefault<FxHasher>>;
 
 /// Export all public functions and structures for an easy access.
 pub mod prelude {
+    pub use crate::avoid::*;
+    pub use crate::chunks::*;
+    pub use crate::cooperative::*;
+    pub use crate::dimacs::*;
+    pub use crate::directed::anytime_dstar::*;
+    pub use crate::directed::arc_flags::*;
     pub use crate::directed::astar::*;
+    pub use crate::directed::batched::*;
     pub use crate::directed::bfs::*;
+    pub use crate::directed::bottleneck::*;
+    pub use crate::directed::cbs::*;
+    pub use crate::directed::center::*;
+    pub use crate::directed::chinese_postman::*;
+    pub use crate::directed::circuits::*;
     pub use crate::directed::count_paths::*;
     pub use crate::directed::cycle_detection::*;
+    pub use crate::directed::dense::*;
     pub use crate::directed::dfs::*;
+    pub use crate::directed::differential_heuristic::*;
     pub use crate::directed::dijkstra::*;
+    pub use crate::directed::distance_table::*;
+    pub use crate::directed::dynamic_topological_sort::*;
     pub use crate::directed::edmonds_karp::*;
     pub use crate::directed::fringe::*;
+    pub use crate::directed::hub_labels::*;
     pub use crate::directed::idastar::*;
     pub use crate::directed::iddfs::*;
+    pub use crate::directed::incremental_scc::*;
+    pub use crate::directed::landmarks::*;
+    pub use crate::directed::min_mean_cycle::*;
+    pub use crate::directed::pareto::*;
+    pub use crate::directed::path_cache::*;
+    pub use crate::directed::path_cover::*;
+    pub use crate::directed::pattern_database::*;
+    pub use crate::directed::real_time::*;
+    pub use crate::directed::recorder::*;
+    pub use crate::directed::repair::*;
+    pub use crate::directed::reverse::*;
+    pub use crate::directed::search_context::*;
+    pub use crate::directed::search_error::*;
+    pub use crate::directed::semiring::*;
+    pub use crate::directed::shortest_cycle::*;
+    pub use crate::directed::shortest_path_tree::*;
+    pub use crate::directed::simple_paths::*;
     pub use crate::directed::strongly_connected_components::*;
+    pub use crate::directed::tie_breaking::*;
+    pub use crate::directed::time_expanded::*;
     pub use crate::directed::topological_sort::*;
+    pub use crate::directed::turn_costs::*;
     pub use crate::directed::yen::*;
+    pub use crate::dot::*;
+    pub use crate::edit_distance::*;
+    #[cfg(feature = "generators")]
+    pub use crate::generators::*;
+    pub use crate::goal_bounding::*;
+    #[cfg(feature = "graphml")]
+    pub use crate::graphml::*;
     pub use crate::grid::*;
+    pub use crate::heuristics::*;
+    pub use crate::hpa::*;
+    pub use crate::indexed_heap::*;
+    #[cfg(feature = "json")]
+    pub use crate::json::*;
     pub use crate::kuhn_munkres::*;
     pub use crate::matrix::*;
+    pub use crate::mcts::*;
+    pub use crate::navmesh::*;
+    pub use crate::path::*;
+    pub use crate::radix_heap::*;
+    #[cfg(feature = "reference")]
+    pub use crate::reference::*;
+    pub use crate::swamps::*;
+    pub use crate::symmetry_reduction::*;
+    pub use crate::total_cost::*;
+    pub use crate::undirected::chinese_postman::*;
     pub use crate::undirected::cliques::*;
     pub use crate::undirected::connected_components::*;
     pub use crate::undirected::kruskal::*;
+    pub use crate::undirected::shortest_cycle::*;
     pub use crate::utils::*;
+    pub use crate::vrp::*;
 }
 
 /// Deprecated: moved into the `directed` module.
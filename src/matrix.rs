@@ -1,13 +1,14 @@
 //! Matrix of an arbitrary type and utilities to rotate, transpose, etc.
 
+use crate::directed::astar::astar;
 use crate::directed::bfs::bfs_reach;
 use crate::directed::dfs::dfs_reach;
-use crate::utils::{constrain, in_direction, move_in_direction, uint_sqrt};
+use crate::utils::{constrain, in_direction, move_in_direction, times, uint_sqrt};
 use deprecate_until::deprecate_until;
-use num_traits::Signed;
+use num_traits::{Signed, Zero};
 use std::collections::BTreeSet;
 use std::iter::FusedIterator;
-use std::ops::{Deref, DerefMut, Index, IndexMut, Neg, Range};
+use std::ops::{Deref, DerefMut, Index, IndexMut, Mul, Neg, Range};
 use std::slice::{Iter, IterMut};
 use thiserror::Error;
 
@@ -253,6 +254,103 @@ impl<C: Copy> Matrix<C> {
     }
 }
 
+impl<C> Matrix<C>
+where
+    C: Zero + Ord + Copy + Mul<Output = C>,
+{
+    /// Find the cheapest path between two cells of a matrix whose values are the costs
+    /// of entering the cells, using the [A* search
+    /// algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
+    ///
+    /// - `start` is the starting cell, whose cost is not counted.
+    /// - `goal` is the cell to reach.
+    /// - `moves` describes the moves allowed between cells. A move into a cell costs the
+    ///   value of the cell, multiplied by the weight of the move if diagonal moves are
+    ///   allowed.
+    ///
+    /// The search is guided by the Manhattan distance to the goal when only orthogonal
+    /// moves are allowed, and by the octile distance otherwise, scaled by the lowest cost
+    /// of a cell. Costs must be non-negative, and every cell can be entered: cells which
+    /// must be avoided can be given a cost larger than the cost of any path going around
+    /// them.
+    ///
+    /// The path from `start` to `goal` is returned along with its total cost, or `None`
+    /// if one of them is outside the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pathfinding::prelude::{Matrix, TerrainMoves};
+    ///
+    /// // Crossing the swamp (9) is slower than going around it on the road (1).
+    /// let terrain = Matrix::from_rows(vec![
+    ///     vec![1, 9, 9, 1],
+    ///     vec![1, 9, 9, 1],
+    ///     vec![1, 1, 1, 1],
+    /// ]).unwrap();
+    /// let (path, cost) = terrain
+    ///     .terrain_path((0, 0), (0, 3), TerrainMoves::Orthogonal)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     path,
+    ///     vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3), (1, 3), (0, 3)]
+    /// );
+    /// assert_eq!(cost, 7);
+    ///
+    /// // Diagonal moves cost 14 and straight ones 10 times the value of the cell.
+    /// let moves = TerrainMoves::Octile { straight: 10, diagonal: 14 };
+    /// let (path, cost) = terrain.terrain_path((0, 0), (0, 3), moves).unwrap();
+    /// assert_eq!(path, vec![(0, 0), (1, 0), (2, 1), (2, 2), (1, 3), (0, 3)]);
+    /// assert_eq!(cost, 10 + 14 + 10 + 14 + 10);
+    /// ```
+    #[must_use]
+    pub fn terrain_path(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        moves: TerrainMoves<C>,
+    ) -> Option<(Vec<(usize, usize)>, C)> {
+        let inside = |(r, c): (usize, usize)| r < self.rows && c < self.columns;
+        if !inside(start) || !inside(goal) {
+            return None;
+        }
+        let lowest = self.data.iter().copied().min()?;
+        match moves {
+            TerrainMoves::Orthogonal => astar(
+                &start,
+                |&n| self.neighbours(n, false).map(|m| (m, self[m])),
+                |&n| times(lowest, n.0.abs_diff(goal.0) + n.1.abs_diff(goal.1)),
+                |&n| n == goal,
+            ),
+            TerrainMoves::Octile { straight, diagonal } => {
+                // Two diagonal moves can replace two straight ones and vice versa, so the
+                // heuristic must not count any move as more expensive than this.
+                let h_straight = straight.min(diagonal);
+                let h_diagonal = diagonal.min(h_straight + h_straight);
+                astar(
+                    &start,
+                    |&n| {
+                        self.neighbours(n, true).map(move |m| {
+                            let weight = if m.0 == n.0 || m.1 == n.1 {
+                                straight
+                            } else {
+                                diagonal
+                            };
+                            (m, self[m] * weight)
+                        })
+                    },
+                    |&n| {
+                        let (dr, dc) = (n.0.abs_diff(goal.0), n.1.abs_diff(goal.1));
+                        let (small, large) = (dr.min(dc), dr.max(dc));
+                        lowest * (times(h_straight, large - small) + times(h_diagonal, small))
+                    },
+                    |&n| n == goal,
+                )
+            }
+        }
+    }
+}
+
 impl<C: Clone + Signed> Neg for Matrix<C> {
     type Output = Self;
 
@@ -891,6 +989,22 @@ pub enum MatrixFormatError {
     WrongLength,
 }
 
+/// The moves allowed between cells by [`Matrix::terrain_path`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerrainMoves<C> {
+    /// Only horizontal and vertical moves are allowed.
+    Orthogonal,
+    /// Diagonal moves are allowed too. The cost of entering a cell is multiplied by
+    /// `straight` for horizontal and vertical moves, and by `diagonal` for diagonal
+    /// moves, such as 10 and 14 to approximate diagonal moves being √2 times longer.
+    Octile {
+        /// The weight of horizontal and vertical moves.
+        straight: C,
+        /// The weight of diagonal moves.
+        diagonal: C,
+    },
+}
+
 /// Row iterator returned by `iter()` on a matrix.
 pub struct RowIterator<'a, C> {
     matrix: &'a Matrix<C>,
@@ -0,0 +1,327 @@
+//! Choose actions in stochastic or adversarial domains using a [Monte Carlo tree
+//! search](https://en.wikipedia.org/wiki/Monte_Carlo_tree_search).
+
+use std::time::{Duration, Instant};
+
+/// The budget of a call to [`Mcts::search`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MctsBudget {
+    /// Run a given number of iterations. The clock is never read, so that the search is
+    /// reproducible and usable on targets without a system time.
+    Iterations(usize),
+    /// Run iterations until the given duration has elapsed. The results depend on the
+    /// speed of the machine, and reading the clock panics on targets without a system
+    /// time such as `wasm32-unknown-unknown`.
+    Time(Duration),
+}
+
+#[derive(Clone, Debug)]
+struct Node<A> {
+    /// The action leading to this node from its parent.
+    action: Option<A>,
+    parent: usize,
+    children: Vec<usize>,
+    /// Actions which have not been expanded yet, or `None` if the node has not been
+    /// reached yet.
+    untried: Option<Vec<A>>,
+    visits: usize,
+    /// Sum of the rewards, from the point of view of the player who chose the action
+    /// leading to this node.
+    total: f64,
+}
+
+impl<A> Node<A> {
+    const fn new(action: Option<A>, parent: usize) -> Self {
+        Self {
+            action,
+            parent,
+            children: Vec::new(),
+            untried: None,
+            visits: 0,
+            total: 0.0,
+        }
+    }
+}
+
+/// A [Monte Carlo tree search](https://en.wikipedia.org/wiki/Monte_Carlo_tree_search)
+/// choosing the action to play from a root state of type `S`, by building a tree of the
+/// states reached through actions of type `A`.
+///
+/// Every iteration of the search goes through four steps:
+///
+/// - selection: starting from the root, the child maximizing the UCT (Upper Confidence
+///   bounds applied to Trees) score is followed until a node having actions which have not
+///   been tried yet, or a terminal node, is reached;
+/// - expansion: one of the untried actions is applied to create a new child;
+/// - rollout: the new state is evaluated, typically by simulating random actions until a
+///   terminal state is reached;
+/// - backpropagation: the reward of the rollout is added to the nodes which have been
+///   followed.
+///
+/// The domain is described by functions given to [`search`](Self::search):
+///
+/// - `actions` returns the actions which can be chosen from a given state. A state
+///   without any action is terminal.
+/// - `apply` returns the state reached by choosing an action from a given state. For a
+///   stochastic domain, it can draw the outcome of the action at random.
+/// - `rollout` returns the reward obtained from a given state. In a single-agent domain,
+///   this is the reward of the agent. In a two-player zero-sum game with alternating moves
+///   (see [`set_alternating`](Self::set_alternating)), this is the reward of the player
+///   who chose the action leading to the state, the opponent's reward being its negation.
+///
+/// Only the root state is stored: every iteration applies again the actions leading to
+/// the nodes it goes through, so that the statistics of a node average over the random
+/// outcomes of those actions. The actions available from a node are computed the first
+/// time it is reached.
+///
+/// The UCT score of a child is its mean reward plus the exploration constant times
+/// `sqrt(ln(n) / m)`, where `n` and `m` are the number of visits of the parent and of the
+/// child. The default exploration constant is `sqrt(2)`, suited to rewards between 0 and
+/// 1, and should be scaled along with the rewards.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{Mcts, MctsBudget};
+///
+/// // A game of Nim: players alternately take 1, 2 or 3 sticks, and the player taking the
+/// // last stick wins. From 10 sticks, taking 2 leaves a multiple of 4 to the opponent,
+/// // which is a losing position.
+/// let actions = |&sticks: &u32| (1..=sticks.min(3)).collect::<Vec<_>>();
+/// let apply = |&sticks: &u32, &taken: &u32| sticks - taken;
+/// // Players follow a fixed strategy taking a single stick during rollouts, so that the
+/// // player making the last move of an odd number of moves wins.
+/// let rollout = |&sticks: &u32| if sticks % 2 == 0 { 1.0 } else { -1.0 };
+///
+/// let mut mcts = Mcts::new(10);
+/// mcts.set_alternating(true);
+/// mcts.search(MctsBudget::Iterations(5000), actions, apply, rollout);
+/// assert_eq!(mcts.best_action(), Some(&2));
+///
+/// // After playing 2 and seeing the opponent take 3 sticks, the search goes on from the
+/// // part of the tree already explored.
+/// mcts.advance(&2, 8);
+/// mcts.advance(&3, 5);
+/// assert_eq!(mcts.root(), &5);
+/// mcts.search(MctsBudget::Iterations(1000), actions, apply, rollout);
+/// assert_eq!(mcts.best_action(), Some(&1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Mcts<S, A> {
+    root: S,
+    nodes: Vec<Node<A>>,
+    exploration: f64,
+    alternating: bool,
+}
+
+impl<S, A> Mcts<S, A> {
+    /// Create a search starting from `root`, in a single-agent domain and with the default
+    /// exploration constant.
+    #[must_use]
+    pub fn new(root: S) -> Self {
+        Self {
+            root,
+            nodes: vec![Node::new(None, usize::MAX)],
+            exploration: std::f64::consts::SQRT_2,
+            alternating: false,
+        }
+    }
+
+    /// The state the search starts from.
+    #[must_use]
+    pub const fn root(&self) -> &S {
+        &self.root
+    }
+
+    /// The exploration constant of the UCT score.
+    #[must_use]
+    pub const fn exploration(&self) -> f64 {
+        self.exploration
+    }
+
+    /// Set the exploration constant of the UCT score. Larger values favour the actions
+    /// which have been tried less often over those with the best mean reward.
+    pub const fn set_exploration(&mut self, exploration: f64) {
+        self.exploration = exploration;
+    }
+
+    /// Set whether the domain is a two-player zero-sum game in which the players choose
+    /// actions alternately, starting with the player choosing the action at the root.
+    /// Rewards are then negated every time they are propagated to the parent of a node.
+    pub const fn set_alternating(&mut self, alternating: bool) {
+        self.alternating = alternating;
+    }
+
+    /// Number of iterations which went through the root, including those run before the
+    /// last call to [`advance`](Self::advance) and kept along with the subtree.
+    #[must_use]
+    pub fn iterations(&self) -> usize {
+        self.nodes[0].visits
+    }
+
+    /// Run iterations of the search until `budget` is exhausted, and return the number of
+    /// iterations which have been run.
+    pub fn search<FA, IA, FP, FR>(
+        &mut self,
+        budget: MctsBudget,
+        mut actions: FA,
+        mut apply: FP,
+        mut rollout: FR,
+    ) -> usize
+    where
+        FA: FnMut(&S) -> IA,
+        IA: IntoIterator<Item = A>,
+        FP: FnMut(&S, &A) -> S,
+        FR: FnMut(&S) -> f64,
+    {
+        let mut iterations = 0;
+        match budget {
+            MctsBudget::Iterations(n) => {
+                while iterations < n {
+                    self.iterate(&mut actions, &mut apply, &mut rollout);
+                    iterations += 1;
+                }
+            }
+            MctsBudget::Time(duration) => {
+                let start = Instant::now();
+                while start.elapsed() < duration {
+                    self.iterate(&mut actions, &mut apply, &mut rollout);
+                    iterations += 1;
+                }
+            }
+        }
+        iterations
+    }
+
+    /// The most visited action from the root, which is the one to choose once the search
+    /// is over, or `None` if no action has been expanded.
+    #[must_use]
+    pub fn best_action(&self) -> Option<&A> {
+        self.nodes[0]
+            .children
+            .iter()
+            .map(|&c| &self.nodes[c])
+            .max_by_key(|child| child.visits)
+            .and_then(|child| child.action.as_ref())
+    }
+
+    /// The actions expanded from the root, along with their number of visits and their
+    /// mean reward for the player choosing the action at the root.
+    pub fn action_stats(&self) -> impl Iterator<Item = (&A, usize, f64)> {
+        self.nodes[0].children.iter().filter_map(|&c| {
+            let child = &self.nodes[c];
+            #[expect(clippy::cast_precision_loss)]
+            let mean = child.total / child.visits as f64;
+            child.action.as_ref().map(|a| (a, child.visits, mean))
+        })
+    }
+
+    /// Move the root to `state`, which has been reached by choosing `action`, keeping the
+    /// part of the tree below this action if it has been expanded.
+    pub fn advance(&mut self, action: &A, state: S)
+    where
+        A: PartialEq,
+    {
+        self.root = state;
+        let child = self.nodes[0]
+            .children
+            .iter()
+            .copied()
+            .find(|&c| self.nodes[c].action.as_ref() == Some(action));
+        let Some(child) = child else {
+            self.nodes = vec![Node::new(None, usize::MAX)];
+            return;
+        };
+        // Move the subtree of the child to the front, renumbering its nodes in
+        // breadth-first order.
+        let mut order = vec![child];
+        let mut i = 0;
+        while i < order.len() {
+            order.extend_from_slice(&self.nodes[order[i]].children);
+            i += 1;
+        }
+        let mut renumbered = vec![usize::MAX; self.nodes.len()];
+        for (new, &o) in order.iter().enumerate() {
+            renumbered[o] = new;
+        }
+        let mut old = std::mem::take(&mut self.nodes)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        self.nodes = order
+            .into_iter()
+            .filter_map(|o| old[o].take())
+            .map(|mut node| {
+                node.parent = renumbered.get(node.parent).copied().unwrap_or(usize::MAX);
+                for c in &mut node.children {
+                    *c = renumbered[*c];
+                }
+                node
+            })
+            .collect();
+        self.nodes[0].action = None;
+    }
+
+    fn iterate<FA, IA, FP, FR>(&mut self, actions: &mut FA, apply: &mut FP, rollout: &mut FR)
+    where
+        FA: FnMut(&S) -> IA,
+        IA: IntoIterator<Item = A>,
+        FP: FnMut(&S, &A) -> S,
+        FR: FnMut(&S) -> f64,
+    {
+        let (mut index, mut state) = (0, None);
+        loop {
+            let current = state.as_ref().unwrap_or(&self.root);
+            let next = self.nodes.len();
+            let node = &mut self.nodes[index];
+            let untried = node.untried.get_or_insert_with(|| {
+                let mut untried = actions(current).into_iter().collect::<Vec<_>>();
+                untried.reverse();
+                untried
+            });
+            if let Some(action) = untried.pop() {
+                let reached = apply(current, &action);
+                node.children.push(next);
+                self.nodes.push(Node::new(Some(action), index));
+                (index, state) = (next, Some(reached));
+                break;
+            }
+            let Some(child) = self.select(index) else {
+                break;
+            };
+            if let Some(action) = &self.nodes[child].action {
+                let reached = apply(current, action);
+                state = Some(reached);
+            }
+            index = child;
+        }
+        let mut reward = rollout(state.as_ref().unwrap_or(&self.root));
+        loop {
+            let node = &mut self.nodes[index];
+            node.visits += 1;
+            node.total += reward;
+            if index == 0 {
+                break;
+            }
+            index = node.parent;
+            if self.alternating {
+                reward = -reward;
+            }
+        }
+    }
+
+    /// The child of a node with the best UCT score, or `None` if it has no children.
+    fn select(&self, index: usize) -> Option<usize> {
+        #[expect(clippy::cast_precision_loss)]
+        let log_visits = (self.nodes[index].visits as f64).ln();
+        self.nodes[index].children.iter().copied().max_by(|&a, &b| {
+            let score = |c: usize| {
+                let child = &self.nodes[c];
+                #[expect(clippy::cast_precision_loss)]
+                let visits = child.visits as f64;
+                child.total / visits + self.exploration * (log_visits / visits).sqrt()
+            };
+            score(a).total_cmp(&score(b))
+        })
+    }
+}
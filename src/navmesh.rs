@@ -0,0 +1,382 @@
+//! Navigation meshes made of convex polygons, searched with A* and turned into
+//! smooth paths using the funnel algorithm.
+
+use crate::directed::astar::astar;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::cmp::Ordering;
+use std::ops::Add;
+use thiserror::Error;
+
+type Point = (f64, f64);
+
+/// Error encountered while attempting to build a [`NavMesh`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum NavMeshError {
+    /// A polygon has fewer than three vertices
+    #[error("polygon {0} has fewer than three vertices")]
+    TooFewVertices(usize),
+    /// A polygon refers to a vertex which does not exist
+    #[error("polygon {polygon} refers to missing vertex {vertex}")]
+    MissingVertex {
+        /// Index of the polygon.
+        polygon: usize,
+        /// Index of the missing vertex.
+        vertex: usize,
+    },
+    /// A polygon is not convex, or has a null area
+    #[error("polygon {0} is not convex")]
+    NotConvex(usize),
+}
+
+/// A portal crossed when going from a polygon to the next one, given by its left and
+/// right ends as seen by the traveller.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Portal {
+    /// End of the portal on the left of the traveller.
+    pub left: Point,
+    /// End of the portal on the right of the traveller.
+    pub right: Point,
+}
+
+/// A navigation mesh, describing the walkable area of a 2D world as a set of convex
+/// polygons sharing some of their edges.
+///
+/// Queries are answered in two steps:
+///
+/// - [`astar`] is used on the adjacency graph of the
+///   polygons, where polygons are connected if they share an edge, to find the
+///   corridor of polygons going from the start to the goal. The cost of moving between
+///   two polygons is the distance between their centroids.
+/// - The [`funnel`] algorithm then pulls the path tight through the portals of this
+///   corridor, producing the shortest path staying inside the corridor.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::NavMesh;
+///
+/// // An L-shaped room made of three squares.
+/// let vertices = vec![
+///     (0.0, 0.0), (1.0, 0.0), (2.0, 0.0),
+///     (0.0, 1.0), (1.0, 1.0), (2.0, 1.0),
+///     (0.0, 2.0), (1.0, 2.0),
+/// ];
+/// let polygons = vec![vec![0, 1, 4, 3], vec![1, 2, 5, 4], vec![3, 4, 7, 6]];
+/// let mesh = NavMesh::new(vertices, polygons).unwrap();
+///
+/// let path = mesh.find_path((1.5, 0.5), (0.75, 1.5)).unwrap();
+/// // The path turns around the inner corner of the room.
+/// assert_eq!(path, vec![(1.5, 0.5), (1.0, 1.0), (0.75, 1.5)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NavMesh {
+    vertices: Vec<Point>,
+    polygons: Vec<Vec<usize>>,
+    centroids: Vec<Point>,
+    // Neighbours of every polygon, along with the vertices of the shared edge in the
+    // counter-clockwise order of the polygon.
+    neighbours: Vec<Vec<(usize, (usize, usize))>>,
+}
+
+impl NavMesh {
+    /// Build a navigation mesh from its vertices and its convex polygons, each polygon
+    /// being given as a list of indices into `vertices`. Polygons may be given in
+    /// clockwise or counter-clockwise order. Two polygons are connected if they share
+    /// an edge, that is if they both use the same two consecutive vertices.
+    ///
+    /// # Errors
+    ///
+    /// - [`NavMeshError::TooFewVertices`] if a polygon has fewer than three vertices.
+    /// - [`NavMeshError::MissingVertex`] if a polygon refers to a missing vertex.
+    /// - [`NavMeshError::NotConvex`] if a polygon is not convex.
+    pub fn new(vertices: Vec<Point>, mut polygons: Vec<Vec<usize>>) -> Result<Self, NavMeshError> {
+        for (index, polygon) in polygons.iter_mut().enumerate() {
+            if polygon.len() < 3 {
+                return Err(NavMeshError::TooFewVertices(index));
+            }
+            if let Some(&vertex) = polygon.iter().find(|&&v| v >= vertices.len()) {
+                return Err(NavMeshError::MissingVertex {
+                    polygon: index,
+                    vertex,
+                });
+            }
+            let points = polygon.iter().map(|&v| vertices[v]).collect::<Vec<_>>();
+            let area = (0..points.len())
+                .map(|i| cross(points[0], points[i], points[(i + 1) % points.len()]))
+                .sum::<f64>();
+            if area == 0.0 {
+                return Err(NavMeshError::NotConvex(index));
+            }
+            if area < 0.0 {
+                polygon.reverse();
+            }
+            let n = polygon.len();
+            let turns_left = (0..n).all(|i| {
+                let [a, b, c] = [i, (i + 1) % n, (i + 2) % n].map(|j| vertices[polygon[j]]);
+                cross(a, b, c) >= 0.0
+            });
+            if !turns_left {
+                return Err(NavMeshError::NotConvex(index));
+            }
+        }
+        let mut edges = FxHashMap::<(usize, usize), Vec<(usize, (usize, usize))>>::default();
+        for (index, polygon) in polygons.iter().enumerate() {
+            for i in 0..polygon.len() {
+                let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                edges
+                    .entry((a.min(b), a.max(b)))
+                    .or_default()
+                    .push((index, (a, b)));
+            }
+        }
+        let mut neighbours = vec![Vec::new(); polygons.len()];
+        for sharing in edges.values() {
+            for &(p, edge) in sharing {
+                for &(q, _) in sharing {
+                    if p != q {
+                        neighbours[p].push((q, edge));
+                    }
+                }
+            }
+        }
+        let centroids = polygons
+            .iter()
+            .map(|polygon| {
+                let (x, y, n) = polygon.iter().fold((0.0, 0.0, 0.0), |(x, y, n), &v| {
+                    (x + vertices[v].0, y + vertices[v].1, n + 1.0)
+                });
+                (x / n, y / n)
+            })
+            .collect();
+        Ok(Self {
+            vertices,
+            polygons,
+            centroids,
+            neighbours,
+        })
+    }
+
+    /// The vertices of the mesh.
+    #[must_use]
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// The polygons of the mesh, as lists of vertex indices in counter-clockwise order.
+    #[must_use]
+    pub fn polygons(&self) -> &[Vec<usize>] {
+        &self.polygons
+    }
+
+    /// The polygons sharing an edge with `polygon`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `polygon` is not a valid polygon index.
+    pub fn neighbours(&self, polygon: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbours[polygon].iter().map(|&(q, _)| q)
+    }
+
+    /// The polygon containing `point`, if any. If `point` lies on an edge shared
+    /// by several polygons, any of them may be returned.
+    #[must_use]
+    pub fn polygon_at(&self, point: Point) -> Option<usize> {
+        (0..self.polygons.len()).find(|&index| {
+            let polygon = &self.polygons[index];
+            (0..polygon.len()).all(|i| {
+                let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                cross(self.vertices[a], self.vertices[b], point) >= 0.0
+            })
+        })
+    }
+
+    /// Sequence of adjacent polygons going from `start` to `goal`, both included,
+    /// or `None` if `goal` cannot be reached from `start`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `start` or `goal` is not a valid polygon index.
+    #[must_use]
+    pub fn corridor(&self, start: usize, goal: usize) -> Option<Vec<usize>> {
+        let target = self.centroids[goal];
+        astar(
+            &start,
+            |&p| {
+                self.neighbours[p]
+                    .iter()
+                    .map(move |&(q, _)| (q, Cost(distance(self.centroids[p], self.centroids[q]))))
+            },
+            |&p| Cost(distance(self.centroids[p], target)),
+            |&p| p == goal,
+        )
+        .map(|(corridor, _)| corridor)
+    }
+
+    /// Portals crossed when following a corridor of adjacent polygons, as returned
+    /// by [`NavMesh::corridor`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if two consecutive polygons of the corridor are not
+    /// adjacent.
+    #[must_use]
+    pub fn portals(&self, corridor: &[usize]) -> Vec<Portal> {
+        corridor
+            .windows(2)
+            .map(|w| {
+                let &(_, (a, b)) = self.neighbours[w[0]]
+                    .iter()
+                    .find(|&&(q, _)| q == w[1])
+                    .expect("polygons of the corridor must be adjacent");
+                // The interior of the polygon is on the left of its edges, so the
+                // first vertex is on the right of a traveller leaving through it.
+                Portal {
+                    left: self.vertices[b],
+                    right: self.vertices[a],
+                }
+            })
+            .collect()
+    }
+
+    /// Shortest path from `start` to `goal` going through the corridor found by
+    /// [`NavMesh::corridor`], as a list of points starting with `start` and ending with
+    /// `goal`. The intermediate points are vertices of the mesh around which the
+    /// path turns.
+    ///
+    /// `None` is returned if `start` or `goal` is outside of the mesh, or if no path
+    /// exists between them.
+    #[must_use]
+    pub fn find_path(&self, start: Point, goal: Point) -> Option<Vec<Point>> {
+        let corridor = self.corridor(self.polygon_at(start)?, self.polygon_at(goal)?)?;
+        Some(funnel(start, goal, &self.portals(&corridor)))
+    }
+}
+
+/// Compute the shortest path from `start` to `goal` going through a sequence of
+/// portals using the funnel algorithm, also known as string pulling.
+///
+/// The returned path starts with `start`, ends with `goal`, and goes through the
+/// portal ends around which it has to turn.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{funnel, Portal};
+///
+/// // A corridor making a turn to the left.
+/// let portals = [
+///     Portal { left: (1.0, 1.0), right: (1.0, 0.0) },
+///     Portal { left: (1.0, 1.0), right: (2.0, 1.0) },
+/// ];
+/// let path = funnel((0.5, 0.5), (1.5, 2.0), &portals);
+/// assert_eq!(path, vec![(0.5, 0.5), (1.0, 1.0), (1.5, 2.0)]);
+/// ```
+#[must_use]
+pub fn funnel(start: Point, goal: Point, portals: &[Portal]) -> Vec<Point> {
+    let portals = std::iter::once(Portal {
+        left: start,
+        right: start,
+    })
+    .chain(portals.iter().copied())
+    .chain(std::iter::once(Portal {
+        left: goal,
+        right: goal,
+    }))
+    .collect::<Vec<_>>();
+    let mut path = vec![start];
+    let (mut apex, mut left, mut right) = (start, start, start);
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 1;
+    while i < portals.len() {
+        let Portal {
+            left: new_left,
+            right: new_right,
+        } = portals[i];
+        // Try to narrow the funnel on the right side.
+        if cross(apex, right, new_right) >= 0.0 {
+            if apex == right || cross(apex, left, new_right) < 0.0 {
+                right = new_right;
+                right_index = i;
+            } else {
+                // The right side crosses the left one, which becomes the new apex.
+                apex = left;
+                if path.last() != Some(&apex) {
+                    path.push(apex);
+                }
+                right = apex;
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+        // Try to narrow the funnel on the left side.
+        if cross(apex, left, new_left) <= 0.0 {
+            if apex == left || cross(apex, right, new_left) > 0.0 {
+                left = new_left;
+                left_index = i;
+            } else {
+                // The left side crosses the right one, which becomes the new apex.
+                apex = right;
+                if path.last() != Some(&apex) {
+                    path.push(apex);
+                }
+                left = apex;
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    if path.last() != Some(&goal) {
+        path.push(goal);
+    }
+    path
+}
+
+/// Cross product of `b - a` and `c - a`, positive if `c` is on the left of the line
+/// going from `a` to `b`.
+fn cross(a: Point, b: Point, c: Point) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+/// Totally ordered distance used as a cost by [`astar`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cost(f64);
+
+impl Eq for Cost {}
+
+impl PartialOrd for Cost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Add for Cost {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Zero for Cost {
+    fn zero() -> Self {
+        Self(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
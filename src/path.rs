@@ -0,0 +1,217 @@
+//! A path result type carrying nodes along with individual step costs.
+
+use num_traits::Zero;
+use std::iter::FusedIterator;
+use std::ops::Sub;
+
+/// A path through a graph, made of at least one node, along with the cost
+/// of every step and the total cost.
+///
+/// Paths are returned by the `_path` variants of the search functions, such as
+/// [`dijkstra_path`](crate::directed::dijkstra::dijkstra_path) or
+/// [`astar_path`](crate::directed::astar::astar_path), and can also be built
+/// manually.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::Path;
+///
+/// let mut path = Path::new('a');
+/// path.push('b', 3);
+/// path.push('c', 4);
+/// assert_eq!(path.nodes(), &['a', 'b', 'c']);
+/// assert_eq!(path.step_costs(), &[3, 4]);
+/// assert_eq!(path.total_cost(), 7);
+/// assert_eq!(path.edges().collect::<Vec<_>>(), vec![(&'a', &'b', 3), (&'b', &'c', 4)]);
+///
+/// let back = path.clone().reversed();
+/// assert_eq!(back.nodes(), &['c', 'b', 'a']);
+/// let round_trip = path.concat(back);
+/// assert_eq!(round_trip.nodes(), &['a', 'b', 'c', 'b', 'a']);
+/// assert_eq!(round_trip.total_cost(), 14);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Path<N, C> {
+    nodes: Vec<N>,
+    costs: Vec<C>,
+    total: C,
+}
+
+impl<N, C> Path<N, C>
+where
+    C: Zero + Copy,
+{
+    /// Create a path containing only the `start` node, with a zero cost.
+    #[must_use]
+    pub fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start],
+            costs: Vec::new(),
+            total: Zero::zero(),
+        }
+    }
+
+    /// Create a path starting at `start` and going through every node of `steps`,
+    /// each one being associated with the cost of reaching it from the previous node.
+    #[must_use]
+    pub fn from_steps<I>(start: N, steps: I) -> Self
+    where
+        I: IntoIterator<Item = (N, C)>,
+    {
+        let mut path = Self::new(start);
+        path.extend(steps);
+        path
+    }
+
+    /// Append a `node` to the path, reached from the current last node for `cost`.
+    pub fn push(&mut self, node: N, cost: C) {
+        self.nodes.push(node);
+        self.costs.push(cost);
+        self.total = self.total + cost;
+    }
+
+    /// Nodes along the path, including the start and end nodes.
+    #[must_use]
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Cost of every step. The cost at index `i` is the cost of going from the
+    /// node at index `i` to the node at index `i + 1`.
+    #[must_use]
+    pub fn step_costs(&self) -> &[C] {
+        &self.costs
+    }
+
+    /// Total cost of the path.
+    #[must_use]
+    pub const fn total_cost(&self) -> C {
+        self.total
+    }
+
+    /// Number of steps (edges) in the path. A path containing only its start
+    /// node has zero steps.
+    #[must_use]
+    pub fn steps(&self) -> usize {
+        self.costs.len()
+    }
+
+    /// The first node of the path.
+    #[must_use]
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    /// The last node of the path.
+    #[must_use]
+    pub fn end(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+
+    /// Iterate over the nodes of the path.
+    pub fn iter(&self) -> std::slice::Iter<'_, N> {
+        self.nodes.iter()
+    }
+
+    /// Iterate over the edges of the path as `(from, to, cost)` tuples.
+    pub fn edges(&self) -> impl DoubleEndedIterator<Item = (&N, &N, C)> + FusedIterator {
+        self.nodes
+            .windows(2)
+            .zip(&self.costs)
+            .map(|(w, &c)| (&w[0], &w[1], c))
+    }
+
+    /// Return the same path walked in the opposite direction, assuming that
+    /// every edge costs the same in both directions.
+    #[must_use]
+    pub fn reversed(mut self) -> Self {
+        self.nodes.reverse();
+        self.costs.reverse();
+        self
+    }
+
+    /// Concatenate `other` at the end of this path. The start node of `other`
+    /// is not repeated, as it is expected to be the end node of this path.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the start node of `other` is not the end node
+    /// of this path.
+    #[must_use]
+    pub fn concat(mut self, other: Self) -> Self
+    where
+        N: PartialEq,
+    {
+        let mut nodes = other.nodes.into_iter();
+        assert!(
+            nodes.next().as_ref() == Some(self.end()),
+            "paths must share their junction node"
+        );
+        self.nodes.extend(nodes);
+        self.costs.extend(other.costs);
+        self.total = self.total + other.total;
+        self
+    }
+
+    /// Return the nodes of the path.
+    #[must_use]
+    pub fn into_nodes(self) -> Vec<N> {
+        self.nodes
+    }
+}
+
+impl<N, C> Path<N, C>
+where
+    C: Zero + Copy + Sub<Output = C>,
+{
+    /// Build a path from nodes and the cumulative cost to reach each of them.
+    pub(crate) fn from_cumulative<I>(steps: I) -> Self
+    where
+        I: IntoIterator<Item = (N, C)>,
+    {
+        let mut steps = steps.into_iter();
+        let (start, mut previous) = steps.next().unwrap(); // Cannot fail, paths are never empty
+        let mut path = Self::new(start);
+        for (node, cost) in steps {
+            path.push(node, cost - previous);
+            previous = cost;
+        }
+        path
+    }
+}
+
+impl<N, C> Extend<(N, C)> for Path<N, C>
+where
+    C: Zero + Copy,
+{
+    fn extend<T: IntoIterator<Item = (N, C)>>(&mut self, iter: T) {
+        for (node, cost) in iter {
+            self.push(node, cost);
+        }
+    }
+}
+
+impl<N, C> From<Path<N, C>> for (Vec<N>, C) {
+    fn from(path: Path<N, C>) -> Self {
+        (path.nodes, path.total)
+    }
+}
+
+impl<N, C> IntoIterator for Path<N, C> {
+    type Item = N;
+    type IntoIter = std::vec::IntoIter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.into_iter()
+    }
+}
+
+impl<'a, N, C> IntoIterator for &'a Path<N, C> {
+    type Item = &'a N;
+    type IntoIter = std::slice::Iter<'a, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.iter()
+    }
+}
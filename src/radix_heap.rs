@@ -0,0 +1,129 @@
+//! A [radix heap](https://en.wikipedia.org/wiki/Radix_heap), a priority queue
+//! for unsigned integer keys which never decrease below the last extracted one.
+
+use num_traits::Zero;
+use std::mem;
+
+/// Unsigned integer types which can be used as keys in a [`RadixHeap`].
+pub trait RadixKey: Zero + Ord + Copy {
+    /// The number of bits of the type.
+    const BITS: u32;
+
+    /// The position of the highest bit which differs between `self` and `other`,
+    /// plus one, or `0` if they are equal.
+    fn radix_distance(self, other: Self) -> usize;
+}
+
+macro_rules! radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                fn radix_distance(self, other: Self) -> usize {
+                    (Self::BITS - (self ^ other).leading_zeros()) as usize
+                }
+            }
+        )*
+    };
+}
+
+radix_key!(u8, u16, u32, u64, u128, usize);
+
+/// A monotone priority queue returning its smallest key first, as long as no key
+/// smaller than the last extracted one is ever pushed.
+///
+/// Keys are spread into buckets according to the highest bit in which they differ
+/// from the last extracted key, so that every key is moved between buckets at most
+/// once per bit. This makes the radix heap faster than a binary heap for searches such
+/// as Dijkstra's algorithm, in which the extracted costs never decrease.
+///
+/// A key smaller than the last extracted one is treated as if it was equal to it, and
+/// will be returned as such.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::RadixHeap;
+///
+/// let mut heap = RadixHeap::new();
+/// heap.push(5u32, 'a');
+/// heap.push(2, 'b');
+/// heap.push(9, 'c');
+/// assert_eq!(heap.pop(), Some((2, 'b')));
+/// heap.push(3, 'd');
+/// assert_eq!(heap.pop(), Some((3, 'd')));
+/// assert_eq!(heap.pop(), Some((5, 'a')));
+/// assert_eq!(heap.pop(), Some((9, 'c')));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RadixHeap<K, V> {
+    last: K,
+    len: usize,
+    buckets: Vec<Vec<(K, V)>>,
+}
+
+impl<K: RadixKey, V> RadixHeap<K, V> {
+    /// Create an empty heap.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            last: K::zero(),
+            len: 0,
+            buckets: (0..=K::BITS).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    /// The number of elements in the heap.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the heap is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add `value` to the heap with priority `key`.
+    pub fn push(&mut self, key: K, value: V) {
+        let key = key.max(self.last);
+        self.buckets[key.radix_distance(self.last)].push((key, value));
+        self.len += 1;
+    }
+
+    /// Remove and return an element with the smallest key, or `None` if the heap
+    /// is empty.
+    #[expect(clippy::missing_panics_doc)]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        if self.buckets[0].is_empty() {
+            let i = self.buckets.iter().position(|b| !b.is_empty())?;
+            // All the keys of the bucket are now closer to the smallest of them than to
+            // the previous last key, and go into lower buckets.
+            let mut bucket = mem::take(&mut self.buckets[i]);
+            self.last = bucket.iter().map(|&(k, _)| k).min().unwrap(); // Cannot fail
+            for (key, value) in bucket.drain(..) {
+                self.buckets[key.radix_distance(self.last)].push((key, value));
+            }
+            self.buckets[i] = bucket;
+        }
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+
+    /// Remove all the elements from the heap, and reset the last extracted key to
+    /// zero.
+    pub fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.last = K::zero();
+        self.len = 0;
+    }
+}
+
+impl<K: RadixKey, V> Default for RadixHeap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,223 @@
+//! Simple exhaustive implementations of some problems solved by this crate, to be used
+//! as known-correct baselines when testing custom successors or heuristics, or when
+//! fuzzing.
+//!
+//! This module is only available when the `reference` feature is enabled.
+//!
+//! Those functions enumerate every candidate solution and take exponential time. They
+//! are only meant to be used on small graphs, and favour obviousness over speed.
+
+use crate::FxIndexSet;
+use crate::directed::edmonds_karp::Edge;
+use crate::kuhn_munkres::Weights;
+use num_traits::Zero;
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+
+/// Compute a shortest path by enumerating every simple path starting at `start`.
+///
+/// - `start` is the starting node.
+/// - `successors` returns a list of successors for a given node, along with the cost for
+///   moving from the node to the successor.
+/// - `success` checks whether the goal has been reached.
+///
+/// As with [`dijkstra`](crate::directed::dijkstra::dijkstra), a path stops at the first
+/// node for which `success` returns `true`. Costs may be negative as long as no cycle
+/// has a negative cost. When several paths have the same minimal cost, the first one
+/// encountered in a depth-first exploration of the successors is returned.
+///
+/// The part of the graph reachable from `start` must be finite, and small enough for
+/// all its simple paths to be enumerated.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{brute_force_shortest_path, dijkstra};
+///
+/// let successors = |&n: &u32| if n < 20 { vec![(n + 1, 1), (n * 2, 3)] } else { vec![] };
+/// let expected = dijkstra(&1, successors, |&n| n == 17).map(|(_, cost)| cost);
+/// let result = brute_force_shortest_path(&1, successors, |&n| n == 17);
+/// assert_eq!(result.map(|(_, cost)| cost), expected);
+/// ```
+pub fn brute_force_shortest_path<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut path = vec![start.clone()];
+    let mut best = None;
+    explore_paths(
+        &mut path,
+        Zero::zero(),
+        &mut successors,
+        &mut success,
+        &mut best,
+    );
+    best
+}
+
+fn explore_paths<N, C, FN, IN, FS>(
+    path: &mut Vec<N>,
+    cost: C,
+    successors: &mut FN,
+    success: &mut FS,
+    best: &mut Option<(Vec<N>, C)>,
+) where
+    N: Eq + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let node = &path[path.len() - 1];
+    if success(node) {
+        if best.as_ref().is_none_or(|&(_, c)| cost < c) {
+            *best = Some((path.clone(), cost));
+        }
+        return;
+    }
+    for (successor, move_cost) in successors(node) {
+        if !path.contains(&successor) {
+            path.push(successor);
+            explore_paths(path, cost + move_cost, successors, success, best);
+            path.pop();
+        }
+    }
+}
+
+/// Compute a maximum weight assignment of the rows of `weights` to distinct columns by
+/// enumerating every possible assignment.
+///
+/// The result has the same form as the one of
+/// [`kuhn_munkres`](crate::kuhn_munkres::kuhn_munkres): the total weight of the
+/// assignment, and the column assigned to every row. When several assignments have the
+/// same total weight, the lexicographically smallest one is returned.
+///
+/// # Panics
+///
+/// This function panics if the number of rows is larger than the number of columns.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{brute_force_assignment, kuhn_munkres, Matrix};
+///
+/// let weights = Matrix::from_rows(vec![vec![1, 2, 3], vec![2, 4, 6], vec![3, 6, 9]]).unwrap();
+/// let (total, _) = kuhn_munkres(&weights);
+/// assert_eq!(brute_force_assignment(&weights), (total, vec![0, 1, 2]));
+/// ```
+pub fn brute_force_assignment<C, W>(weights: &W) -> (C, Vec<usize>)
+where
+    C: Zero + Ord + Copy,
+    W: Weights<C>,
+{
+    assert!(
+        weights.rows() <= weights.columns(),
+        "number of rows must not be larger than number of columns"
+    );
+    let mut assignment = Vec::with_capacity(weights.rows());
+    let mut used = vec![false; weights.columns()];
+    let mut best = None;
+    explore_assignments(weights, &mut assignment, &mut used, Zero::zero(), &mut best);
+    best.unwrap_or_else(|| (Zero::zero(), Vec::new()))
+}
+
+fn explore_assignments<C, W>(
+    weights: &W,
+    assignment: &mut Vec<usize>,
+    used: &mut [bool],
+    total: C,
+    best: &mut Option<(C, Vec<usize>)>,
+) where
+    C: Zero + Ord + Copy,
+    W: Weights<C>,
+{
+    let row = assignment.len();
+    if row == weights.rows() {
+        if best.as_ref().is_none_or(|&(t, _)| total > t) {
+            *best = Some((total, assignment.clone()));
+        }
+        return;
+    }
+    for column in 0..weights.columns() {
+        if !used[column] {
+            used[column] = true;
+            assignment.push(column);
+            let total = total + weights.at(row, column);
+            explore_assignments(weights, assignment, used, total, best);
+            assignment.pop();
+            used[column] = false;
+        }
+    }
+}
+
+/// Compute the value of a maximum flow from `source` to `sink` by enumerating every
+/// cut separating them and returning the capacity of the smallest one, which is equal to
+/// the maximum flow by the max-flow min-cut theorem.
+///
+/// The arguments are the same as for
+/// [`edmonds_karp`](crate::directed::edmonds_karp::edmonds_karp). As with this
+/// function, if the capacity between two vertices is given several times, the last one
+/// is used. `source` and `sink` must be distinct.
+///
+/// # Panics
+///
+/// This function panics if `source`, `sink`, or the ends of an edge are not found in
+/// `vertices`, or if there are more than 63 vertices besides the source and the sink.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{brute_force_max_flow, edmonds_karp_dense};
+///
+/// let vertices = ['s', 'a', 'b', 't'];
+/// let caps = [(('s', 'a'), 3), (('s', 'b'), 2), (('a', 'b'), 1), (('a', 't'), 2), (('b', 't'), 3)];
+/// let (_, max_flow, _) = edmonds_karp_dense(&vertices, &'s', &'t', caps);
+/// assert_eq!(brute_force_max_flow(&vertices, &'s', &'t', caps), max_flow);
+/// ```
+pub fn brute_force_max_flow<N, C, IC>(vertices: &[N], source: &N, sink: &N, caps: IC) -> C
+where
+    N: Eq + Hash,
+    C: Zero + Ord + Copy,
+    IC: IntoIterator<Item = Edge<N, C>>,
+{
+    let indices = vertices.iter().collect::<FxIndexSet<_>>();
+    let index = |n: &N| {
+        indices
+            .get_index_of(n)
+            .unwrap_or_else(|| panic!("vertex not found in vertices"))
+    };
+    let (source, sink) = (index(source), index(sink));
+    let mut capacities = FxHashMap::default();
+    for ((from, to), capacity) in caps {
+        capacities.insert((index(&from), index(&to)), capacity);
+    }
+    let others = (0..vertices.len())
+        .filter(|&v| v != source && v != sink)
+        .collect::<Vec<_>>();
+    assert!(
+        others.len() < 64,
+        "too many vertices for an exhaustive search"
+    );
+    let mut on_source_side = vec![false; vertices.len()];
+    on_source_side[source] = true;
+    (0..1u64 << others.len())
+        .map(|subset| {
+            for (i, &v) in others.iter().enumerate() {
+                on_source_side[v] = subset & (1 << i) != 0;
+            }
+            capacities
+                .iter()
+                .filter(|&(&(from, to), _)| on_source_side[from] && !on_source_side[to])
+                .fold(C::zero(), |cut, (_, &capacity)| cut + capacity)
+        })
+        .min()
+        .unwrap_or_else(Zero::zero)
+}
@@ -0,0 +1,55 @@
+//! A hash map split into shards protected by their own lock, so that several
+//! threads can insert into it concurrently.
+
+use rustc_hash::{FxBuildHasher, FxHashMap};
+use std::hash::{BuildHasher, Hash};
+use std::sync::{Mutex, PoisonError};
+
+pub(crate) struct ShardedMap<K, V> {
+    shards: Box<[Mutex<FxHashMap<K, V>>]>,
+}
+
+impl<K: Eq + Hash, V> ShardedMap<K, V> {
+    /// Create an empty map with enough shards to keep contention low on the
+    /// current rayon thread pool.
+    pub(crate) fn new() -> Self {
+        let count = (rayon::current_num_threads() * 4).next_power_of_two();
+        Self {
+            shards: (0..count).map(|_| Mutex::default()).collect(),
+        }
+    }
+
+    fn shard(&self, key: &K) -> &Mutex<FxHashMap<K, V>> {
+        // The shard maps use the lowest bits of the same hash to place their keys,
+        // so the shard is chosen from higher bits to keep them well spread.
+        let hash = FxBuildHasher.hash_one(key);
+        &self.shards[(hash >> 32) as usize & (self.shards.len() - 1)]
+    }
+
+    /// Insert `key` associated with `value` unless it is already present. Return `true`
+    /// if the key has been inserted.
+    pub(crate) fn insert_if_absent(&self, key: K, value: V) -> bool {
+        let mut shard = self
+            .shard(&key)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if shard.contains_key(&key) {
+            return false;
+        }
+        shard.insert(key, value);
+        true
+    }
+
+    /// Merge all the shards into a single map.
+    pub(crate) fn into_inner(self) -> FxHashMap<K, V> {
+        let mut shards = self
+            .shards
+            .into_iter()
+            .map(|shard| shard.into_inner().unwrap_or_else(PoisonError::into_inner));
+        let mut map = shards.next().unwrap_or_default();
+        for shard in shards {
+            map.extend(shard);
+        }
+        map
+    }
+}
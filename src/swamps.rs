@@ -0,0 +1,327 @@
+//! Detect the dead ends and the swamps of a [`Grid`], which shortest paths can avoid.
+
+use crate::FxIndexSet;
+use crate::directed::astar::astar;
+use crate::grid::Grid;
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+
+type Vertex = (usize, usize);
+
+/// Marker of a vertex which does not belong to any swamp.
+const NO_SWAMP: usize = usize::MAX;
+
+/// A [`Grid`] in which the regions that shortest paths never need to cross have been
+/// identified, so that they can be pruned from repeated searches.
+///
+/// Two kinds of regions are detected:
+///
+/// - A dead end is a region connected to the rest of the grid through a single vertex.
+///   A shortest path between two vertices located outside a dead end never enters it.
+/// - A swamp is a region around which paths of the same length always exist, for
+///   example a vertex in an open area when diagonal moves are allowed. Swamps are grown
+///   from every vertex which is not in a dead end, up to a maximum number of vertices,
+///   and are numbered in the order in which they have been found. Given a start and a
+///   goal, the swamps numbered before the first swamp containing one of them can be
+///   avoided altogether without making the shortest path longer.
+///
+/// Detecting dead ends takes a time linear in the size of the grid, and detecting swamps
+/// a time linear in the size of the grid and polynomial in the maximum size of the swamps.
+///
+/// The grid is copied during the preprocessing, so later modifications of the
+/// original grid are not taken into account.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{Grid, PrunedGrid};
+///
+/// // A room with a corridor leading to a closet on its right.
+/// let mut grid = Grid::new(12, 5);
+/// for x in 0..5 {
+///     for y in 0..5 {
+///         grid.add_vertex((x, y));
+///     }
+/// }
+/// for x in 5..12 {
+///     grid.add_vertex((x, 2));
+/// }
+/// let pruned = PrunedGrid::new(&grid, 0);
+/// assert!(pruned.is_dead_end((8, 2)));
+/// assert!(!pruned.is_dead_end((2, 2)));
+///
+/// // The corridor is avoided when going from a corner of the room to another one.
+/// assert!(pruned.is_pruned((5, 2), (0, 0), (4, 4)));
+/// let (_, cost) = pruned.find_path((0, 0), (4, 4)).unwrap();
+/// assert_eq!(cost, 8);
+///
+/// // It is not when going to the closet.
+/// assert!(!pruned.is_pruned((5, 2), (0, 0), (11, 2)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrunedGrid {
+    grid: Grid,
+    /// Rank of every vertex in the depth-first search used to find the dead ends.
+    discovery: Vec<usize>,
+    /// Innermost dead end containing every vertex, as a range of discovery ranks.
+    dead_ends: Vec<Option<(usize, usize)>>,
+    /// Number of the swamp containing every vertex, or `NO_SWAMP`.
+    swamps: Vec<usize>,
+    swamps_len: usize,
+}
+
+impl PrunedGrid {
+    /// Detect the dead ends of `grid` along with its swamps made of at most
+    /// `max_swamp_size` vertices. No swamp is detected if `max_swamp_size` is zero.
+    #[must_use]
+    pub fn new(grid: &Grid, max_swamp_size: usize) -> Self {
+        let size = grid.width * grid.height;
+        let mut pruned = Self {
+            grid: grid.clone(),
+            discovery: vec![usize::MAX; size],
+            dead_ends: vec![None; size],
+            swamps: vec![NO_SWAMP; size],
+            swamps_len: 0,
+        };
+        pruned.build_dead_ends();
+        if max_swamp_size > 0 {
+            pruned.build_swamps(max_swamp_size);
+        }
+        pruned
+    }
+
+    /// The grid the regions have been detected in.
+    #[must_use]
+    pub const fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Check whether `vertex` belongs to a dead end.
+    #[must_use]
+    pub fn is_dead_end(&self, vertex: Vertex) -> bool {
+        self.index(vertex)
+            .is_some_and(|i| self.dead_ends[i].is_some())
+    }
+
+    /// The number of the swamp `vertex` belongs to, if any.
+    #[must_use]
+    pub fn swamp(&self, vertex: Vertex) -> Option<usize> {
+        self.index(vertex)
+            .map(|i| self.swamps[i])
+            .filter(|&s| s != NO_SWAMP)
+    }
+
+    /// Number of swamps detected.
+    #[must_use]
+    pub const fn swamps_len(&self) -> usize {
+        self.swamps_len
+    }
+
+    /// Check whether `vertex` can be ignored when looking for a shortest path from
+    /// `start` to `goal`.
+    #[must_use]
+    pub fn is_pruned(&self, vertex: Vertex, start: Vertex, goal: Vertex) -> bool {
+        let Some(i) = self.index(vertex) else {
+            return false;
+        };
+        let inside = |(from, to): (usize, usize), v| {
+            self.index(v)
+                .is_some_and(|j| (from..to).contains(&self.discovery[j]))
+        };
+        if self.dead_ends[i].is_some_and(|d| !inside(d, start) && !inside(d, goal)) {
+            return true;
+        }
+        let swamp = |v| self.index(v).map_or(NO_SWAMP, |j| self.swamps[j]);
+        self.swamps[i] < swamp(start).min(swamp(goal))
+    }
+
+    /// Neighbours of `vertex` which cannot be ignored when looking for a shortest path
+    /// from `start` to `goal`. They can be used as the successors of such a search.
+    #[must_use]
+    pub fn neighbours(&self, vertex: Vertex, start: Vertex, goal: Vertex) -> Vec<Vertex> {
+        let mut neighbours = self.grid.neighbours(vertex);
+        neighbours.retain(|&n| !self.is_pruned(n, start, goal));
+        neighbours
+    }
+
+    /// Shortest path from `start` to `goal`, found by an A* search ignoring the pruned
+    /// vertices, along with its cost.
+    ///
+    /// `None` is returned if `start` or `goal` is not a vertex of the grid, or if no
+    /// path exists between them.
+    #[must_use]
+    pub fn find_path(&self, start: Vertex, goal: Vertex) -> Option<(Vec<Vertex>, usize)> {
+        if !self.grid.has_vertex(start) || !self.grid.has_vertex(goal) {
+            return None;
+        }
+        astar(
+            &start,
+            |&n| self.neighbours(n, start, goal).into_iter().map(|m| (m, 1)),
+            |&n| self.grid.distance(n, goal),
+            |&n| n == goal,
+        )
+    }
+
+    fn index(&self, vertex: Vertex) -> Option<usize> {
+        self.grid
+            .has_vertex(vertex)
+            .then(|| vertex.1 * self.grid.width + vertex.0)
+    }
+
+    /// Find the articulation points of the grid with a depth-first search. The
+    /// subtree rooted at a child of an articulation point is only connected to
+    /// the rest of the grid through it, and is a dead end unless it is larger than
+    /// the rest of its connected component.
+    fn build_dead_ends(&mut self) {
+        let width = self.grid.width;
+        let index = |(x, y): Vertex| y * width + x;
+        let mut low = vec![0; self.discovery.len()];
+        let mut ends = vec![0; self.discovery.len()];
+        let mut order = Vec::new();
+        let mut dead_ends = Vec::new();
+        for root in &self.grid {
+            if self.discovery[index(root)] != usize::MAX {
+                continue;
+            }
+            let component_start = order.len();
+            let mut candidates = Vec::new();
+            self.discovery[index(root)] = order.len();
+            low[index(root)] = order.len();
+            order.push(root);
+            let mut stack = vec![(root, self.grid.neighbours(root), 0)];
+            while let Some((v, neighbours, k)) = stack.last_mut() {
+                let v = index(*v);
+                if let Some(&w) = neighbours.get(*k) {
+                    *k += 1;
+                    let i = index(w);
+                    if self.discovery[i] == usize::MAX {
+                        self.discovery[i] = order.len();
+                        low[i] = order.len();
+                        order.push(w);
+                        stack.push((w, self.grid.neighbours(w), 0));
+                    } else {
+                        low[v] = low[v].min(self.discovery[i]);
+                    }
+                } else {
+                    stack.pop();
+                    ends[v] = order.len();
+                    if let Some(&(p, _, _)) = stack.last() {
+                        let p = index(p);
+                        low[p] = low[p].min(low[v]);
+                        if low[v] >= self.discovery[p] {
+                            candidates.push(v);
+                        }
+                    }
+                }
+            }
+            let component_size = order.len() - component_start;
+            for c in candidates {
+                let (start, end) = (self.discovery[c], ends[c]);
+                if 2 * (end - start) <= component_size {
+                    dead_ends.push((start, end));
+                }
+            }
+        }
+        // Intervals are either nested or disjoint, and the innermost one containing a
+        // vertex is the last one opened before it and not closed yet.
+        dead_ends.sort_unstable();
+        let mut dead_ends = dead_ends.into_iter().peekable();
+        let mut open: Vec<(usize, usize)> = Vec::new();
+        for (rank, &v) in order.iter().enumerate() {
+            while open.last().is_some_and(|&(_, end)| end <= rank) {
+                open.pop();
+            }
+            if let Some(d) = dead_ends.next_if(|&(start, _)| start == rank) {
+                open.push(d);
+            }
+            self.dead_ends[index(v)] = open.last().copied();
+        }
+    }
+
+    fn build_swamps(&mut self, max_swamp_size: usize) {
+        let vertices = self.grid.iter().collect::<Vec<_>>();
+        for seed in vertices {
+            if self.swamp(seed).is_some() || self.is_dead_end(seed) {
+                continue;
+            }
+            let mut region = FxIndexSet::from_iter([seed]);
+            if !self.is_swamp(&region) {
+                continue;
+            }
+            'grow: while region.len() < max_swamp_size {
+                for b in self.boundary(&region) {
+                    if self.is_dead_end(b) {
+                        continue;
+                    }
+                    region.insert(b);
+                    if self.is_swamp(&region) {
+                        continue 'grow;
+                    }
+                    region.pop();
+                }
+                break;
+            }
+            for (x, y) in region {
+                self.swamps[y * self.grid.width + x] = self.swamps_len;
+            }
+            self.swamps_len += 1;
+        }
+    }
+
+    /// Vertices outside `region` and the existing swamps which are adjacent to `region`.
+    fn boundary(&self, region: &FxIndexSet<Vertex>) -> FxIndexSet<Vertex> {
+        region
+            .iter()
+            .flat_map(|&v| self.grid.neighbours(v))
+            .filter(|v| !region.contains(v) && self.swamp(*v).is_none())
+            .collect()
+    }
+
+    /// Check that removing `region` in addition to the existing swamps does not make
+    /// any path between the vertices around it longer, in which case no shortest path
+    /// between two vertices outside the swamps needs to go through `region`.
+    fn is_swamp(&self, region: &FxIndexSet<Vertex>) -> bool {
+        let boundary = self.boundary(region);
+        boundary.iter().all(|&b| {
+            // Going through `region` takes at most one more step than its size.
+            let through = self.distances(b, &boundary, region.len() + 1, |_| false);
+            let limit = through.iter().copied().filter(|&d| d != usize::MAX).max();
+            let around = self.distances(b, &boundary, limit.unwrap_or(0), |v| region.contains(&v));
+            through == around
+        })
+    }
+
+    /// Distances from `from` to every vertex of `targets`, avoiding the existing swamps
+    /// and the vertices for which `avoid` returns `true`. Targets further than `limit`
+    /// are considered unreachable and get `usize::MAX`.
+    fn distances<F>(
+        &self,
+        from: Vertex,
+        targets: &FxIndexSet<Vertex>,
+        limit: usize,
+        avoid: F,
+    ) -> Vec<usize>
+    where
+        F: Fn(Vertex) -> bool,
+    {
+        let mut seen = FxHashMap::default();
+        seen.insert(from, 0);
+        let mut to_see = VecDeque::from([from]);
+        while let Some(v) = to_see.pop_front() {
+            let depth = seen[&v];
+            if depth == limit {
+                continue;
+            }
+            for n in self.grid.neighbours(v) {
+                if self.swamp(n).is_none() && !avoid(n) && !seen.contains_key(&n) {
+                    seen.insert(n, depth + 1);
+                    to_see.push_back(n);
+                }
+            }
+        }
+        targets
+            .iter()
+            .map(|t| seen.get(t).copied().unwrap_or(usize::MAX))
+            .collect()
+    }
+}
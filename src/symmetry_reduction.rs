@@ -0,0 +1,297 @@
+//! Rectangular symmetry reduction, as described by Harabor, Botea and Kilby, on a [`Grid`].
+
+use crate::directed::astar::astar;
+use crate::grid::Grid;
+
+type Vertex = (usize, usize);
+
+/// A rectangle given by its top-left and bottom-right corners, both included.
+type Rectangle = (Vertex, Vertex);
+
+/// Marker of a vertex which does not belong to any rectangle.
+const NO_RECTANGLE: usize = usize::MAX;
+
+/// A [`Grid`] whose open areas have been decomposed into empty rectangles, so that
+/// searches only need to expand the vertices on the perimeter of those rectangles.
+///
+/// Inside an empty rectangle, many paths of the same length link any two vertices.
+/// The vertices strictly inside the rectangles are pruned, and every vertex on the
+/// perimeter of a rectangle is instead linked to the vertices of the opposite side
+/// (and, in diagonal mode, to the vertices of the adjacent sides) it can reach
+/// through the rectangle. The costs of those macro edges are their distances on the
+/// grid, and the shortest paths found this way have the same cost as on the grid.
+///
+/// Paths found using [`successors`](Self::successors) can be turned back into paths
+/// made of the grid edges using [`expand`](Self::expand), which is done automatically
+/// by [`find_path`](Self::find_path).
+///
+/// The preprocessing takes a time linear in the size of the grid, and only stores the
+/// rectangles along with the rectangle every vertex belongs to. The grid is copied
+/// during the preprocessing, so later modifications of the original grid are not taken
+/// into account.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{Grid, SymmetryReducedGrid};
+///
+/// let mut grid = Grid::new(20, 20);
+/// grid.fill();
+/// // A wall with a single opening at the bottom.
+/// for y in 0..19 {
+///     grid.remove_vertex((10, y));
+/// }
+/// let reduced = SymmetryReducedGrid::new(&grid);
+/// assert!(reduced.is_pruned((5, 5)));
+///
+/// let (path, cost) = reduced.find_path((2, 2), (17, 2)).expect("no path found");
+/// assert_eq!(cost, 15 + 2 * 17);
+/// assert_eq!(path.len(), cost + 1);
+/// assert!(path.contains(&(10, 19)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SymmetryReducedGrid {
+    grid: Grid,
+    rectangles: Vec<Rectangle>,
+    /// Index of the rectangle containing every vertex, or `NO_RECTANGLE`.
+    owners: Vec<usize>,
+    diagonal: bool,
+}
+
+impl SymmetryReducedGrid {
+    /// Decompose the open areas of `grid` into empty rectangles.
+    #[must_use]
+    pub fn new(grid: &Grid) -> Self {
+        let mut reduced = Self {
+            grid: grid.clone(),
+            rectangles: Vec::new(),
+            owners: vec![NO_RECTANGLE; grid.width * grid.height],
+            diagonal: grid.distance((0, 0), (1, 1)) == 1,
+        };
+        reduced.build_rectangles();
+        reduced
+    }
+
+    /// The grid the rectangles have been built from.
+    #[must_use]
+    pub const fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// The empty rectangles, as their top-left and bottom-right corners. Only the
+    /// rectangles at least three vertices wide and high, which have vertices strictly
+    /// inside them, are kept.
+    #[must_use]
+    pub fn rectangles(&self) -> &[Rectangle] {
+        &self.rectangles
+    }
+
+    /// Check whether `vertex` is strictly inside a rectangle, in which case it is only
+    /// expanded when it is the start of a search.
+    #[must_use]
+    pub fn is_pruned(&self, vertex: Vertex) -> bool {
+        self.rectangle(vertex)
+            .is_some_and(|r| is_interior(r, vertex))
+    }
+
+    /// Successors of `vertex`, along with the cost to reach them, when looking for a
+    /// shortest path to `goal`. Successors which are not neighbours of `vertex` are
+    /// reached through a rectangle, and the path between them can be rebuilt using
+    /// [`expand`](Self::expand).
+    #[must_use]
+    pub fn successors(&self, vertex: Vertex, goal: Vertex) -> Vec<(Vertex, usize)> {
+        let mut successors = Vec::new();
+        if !self.grid.has_vertex(vertex) {
+            return successors;
+        }
+        let goal_rectangle = self.rectangle(goal).filter(|&r| is_interior(r, goal));
+        if let Some(r) = self.rectangle(vertex) {
+            if is_interior(r, vertex) {
+                // Only the start of a search can be strictly inside a rectangle, and
+                // it can go straight to any vertex of the perimeter.
+                successors.extend(perimeter(r).map(|p| (p, self.grid.distance(vertex, p))));
+                if goal_rectangle == Some(r) {
+                    successors.push((goal, self.grid.distance(vertex, goal)));
+                }
+                return successors;
+            }
+            self.macro_edges(vertex, r, &mut successors);
+            if goal_rectangle == Some(r) {
+                successors.push((goal, self.grid.distance(vertex, goal)));
+            }
+        }
+        successors.extend(
+            self.grid
+                .neighbours(vertex)
+                .into_iter()
+                .filter(|&n| !self.is_pruned(n))
+                .map(|n| (n, 1)),
+        );
+        successors
+    }
+
+    /// Rebuild a path made of grid edges from a path whose consecutive vertices may be
+    /// linked through a rectangle, such as one found using [`successors`](Self::successors).
+    #[must_use]
+    pub fn expand(&self, path: &[Vertex]) -> Vec<Vertex> {
+        let mut expanded = path.first().copied().into_iter().collect::<Vec<_>>();
+        for w in path.windows(2) {
+            let (mut current, target) = (w[0], w[1]);
+            while current != target {
+                let dx = target.0.cmp(&current.0) as isize;
+                let dy = target.1.cmp(&current.1) as isize;
+                current = if self.diagonal || dx == 0 {
+                    (
+                        current.0.wrapping_add_signed(dx),
+                        current.1.wrapping_add_signed(dy),
+                    )
+                } else {
+                    (current.0.wrapping_add_signed(dx), current.1)
+                };
+                expanded.push(current);
+            }
+        }
+        expanded
+    }
+
+    /// Shortest path from `start` to `goal`, found by an A* search over the vertices
+    /// which are not pruned and expanded back into grid edges, along with its cost.
+    ///
+    /// `None` is returned if `start` or `goal` is not a vertex of the grid, or if no
+    /// path exists between them.
+    #[must_use]
+    pub fn find_path(&self, start: Vertex, goal: Vertex) -> Option<(Vec<Vertex>, usize)> {
+        if !self.grid.has_vertex(start) || !self.grid.has_vertex(goal) {
+            return None;
+        }
+        let (path, cost) = astar(
+            &start,
+            |&n| self.successors(n, goal),
+            |&n| self.grid.distance(n, goal),
+            |&n| n == goal,
+        )?;
+        Some((self.expand(&path), cost))
+    }
+
+    fn rectangle(&self, vertex: Vertex) -> Option<Rectangle> {
+        if !self.grid.has_vertex(vertex) {
+            return None;
+        }
+        let owner = self.owners[vertex.1 * self.grid.width + vertex.0];
+        (owner != NO_RECTANGLE).then(|| self.rectangles[owner])
+    }
+
+    /// Vertices of the perimeter of `r` reachable from `vertex`, which lies on this
+    /// perimeter, by going through `r` along a shortest path.
+    fn macro_edges(&self, vertex: Vertex, r: Rectangle, edges: &mut Vec<(Vertex, usize)>) {
+        let ((x0, y0), (x1, y1)) = r;
+        let (x, y) = vertex;
+        if x == x0 {
+            self.across(vertex, (y0..=y1).map(|ty| (x1, ty)), x1 - x0, edges);
+        }
+        if x == x1 {
+            self.across(vertex, (y0..=y1).map(|ty| (x0, ty)), x1 - x0, edges);
+        }
+        if y == y0 {
+            self.across(vertex, (x0..=x1).map(|tx| (tx, y1)), y1 - y0, edges);
+        }
+        if y == y1 {
+            self.across(vertex, (x0..=x1).map(|tx| (tx, y0)), y1 - y0, edges);
+        }
+        if self.diagonal {
+            // Diagonal lines crossing the rectangle, ending on an adjacent side.
+            for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                let step = |(x, y): Vertex| (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+                let mut target = step(vertex);
+                let mut cost = 1;
+                if !is_interior(r, target) {
+                    continue;
+                }
+                while is_interior(r, target) {
+                    target = step(target);
+                    cost += 1;
+                }
+                edges.push((target, cost));
+            }
+        }
+    }
+
+    /// Vertices of the side opposite to `vertex`, at `length` from it, which are not
+    /// further from `vertex` than the side itself.
+    fn across(
+        &self,
+        vertex: Vertex,
+        side: impl Iterator<Item = Vertex>,
+        length: usize,
+        edges: &mut Vec<(Vertex, usize)>,
+    ) {
+        edges.extend(
+            side.filter(|&t| self.grid.distance(vertex, t) == length)
+                .map(|t| (t, length)),
+        );
+    }
+
+    /// Greedily cover the grid with empty rectangles, by growing squares from the
+    /// vertices which are not covered yet and extending them horizontally then
+    /// vertically.
+    fn build_rectangles(&mut self) {
+        let (width, height) = (self.grid.width, self.grid.height);
+        for y in 0..height {
+            for x in 0..width {
+                if !self.is_free(x..=x, y..=y) {
+                    continue;
+                }
+                let (mut x1, mut y1) = (x, y);
+                while x1 + 1 < width
+                    && y1 + 1 < height
+                    && self.is_free(x1 + 1..=x1 + 1, y..=y1 + 1)
+                    && self.is_free(x..=x1, y1 + 1..=y1 + 1)
+                {
+                    x1 += 1;
+                    y1 += 1;
+                }
+                while x1 + 1 < width && self.is_free(x1 + 1..=x1 + 1, y..=y1) {
+                    x1 += 1;
+                }
+                while y1 + 1 < height && self.is_free(x..=x1, y1 + 1..=y1 + 1) {
+                    y1 += 1;
+                }
+                if x1 - x < 2 || y1 - y < 2 {
+                    continue;
+                }
+                for ry in y..=y1 {
+                    for rx in x..=x1 {
+                        self.owners[ry * width + rx] = self.rectangles.len();
+                    }
+                }
+                self.rectangles.push(((x, y), (x1, y1)));
+            }
+        }
+    }
+
+    /// Check that all the vertices in the given ranges exist and do not belong to
+    /// a rectangle yet.
+    fn is_free(
+        &self,
+        xs: std::ops::RangeInclusive<usize>,
+        ys: std::ops::RangeInclusive<usize>,
+    ) -> bool {
+        ys.into_iter().all(|y| {
+            xs.clone().all(|x| {
+                self.grid.has_vertex((x, y)) && self.owners[y * self.grid.width + x] == NO_RECTANGLE
+            })
+        })
+    }
+}
+
+/// Check whether `vertex` is strictly inside `r`.
+const fn is_interior(((x0, y0), (x1, y1)): Rectangle, (x, y): Vertex) -> bool {
+    x0 < x && x < x1 && y0 < y && y < y1
+}
+
+/// Vertices on the perimeter of `r`.
+fn perimeter(((x0, y0), (x1, y1)): Rectangle) -> impl Iterator<Item = Vertex> {
+    (x0..=x1)
+        .flat_map(move |x| [(x, y0), (x, y1)])
+        .chain((y0 + 1..y1).flat_map(move |y| [(x0, y), (x1, y)]))
+}
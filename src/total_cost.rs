@@ -0,0 +1,242 @@
+//! Totally ordered floating-point costs usable with every weighted algorithm.
+//!
+//! Most algorithms in this crate require the cost type to implement `Ord`,
+//! which Rust built-in floating-point types do not implement because of
+//! NaN. [`TotalCost`] wraps a floating-point value which is guaranteed not
+//! to be NaN, and implements all the traits (`Ord`, `Zero`, `Bounded`,
+//! `Signed`, etc.) required by the weighted algorithms.
+
+use num_traits::{Bounded, Float, Num, One, Signed, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+use thiserror::Error;
+
+/// A floating-point cost which cannot be NaN and is thus totally ordered.
+///
+/// Building a `TotalCost` from a NaN value panics, as does any arithmetic
+/// operation producing a NaN value (for example adding positive and
+/// negative infinities). [`TotalCost::try_new`] can be used to validate
+/// a value without panicking.
+///
+/// Positive and negative zeroes are considered equal.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{dijkstra, TotalCost};
+///
+/// let result = dijkstra(
+///     &0u32,
+///     |&n| vec![(n + 1, TotalCost::new(0.5)), (n + 2, TotalCost::new(1.25))],
+///     |&n| n == 4,
+/// );
+/// let (path, cost) = result.expect("no path found");
+/// assert_eq!(path, vec![0, 1, 2, 3, 4]);
+/// assert_eq!(cost.into_inner(), 2.0);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct TotalCost<F>(F);
+
+/// Error returned when attempting to build a [`TotalCost`] from NaN.
+#[derive(Clone, Copy, Debug, Error, PartialEq, Eq)]
+#[error("a cost cannot be NaN")]
+pub struct NanCostError;
+
+impl<F: Float> TotalCost<F> {
+    /// Build a new cost.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `value` is NaN.
+    #[must_use]
+    pub fn new(value: F) -> Self {
+        Self::try_new(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Build a new cost, or return an error if `value` is NaN.
+    ///
+    /// # Errors
+    ///
+    /// [`NanCostError`] is returned if `value` is NaN.
+    pub fn try_new(value: F) -> Result<Self, NanCostError> {
+        if value.is_nan() {
+            Err(NanCostError)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Return the wrapped floating-point value.
+    #[must_use]
+    pub const fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<F: Float> From<F> for TotalCost<F> {
+    /// Build a new cost.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `value` is NaN.
+    fn from(value: F) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<F: Float> PartialEq for TotalCost<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<F: Float> Eq for TotalCost<F> {}
+
+impl<F: Float> PartialOrd for TotalCost<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Float> Ord for TotalCost<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // NaN values are rejected at construction time.
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<F: Float> Hash for TotalCost<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Positive and negative zeroes are equal and must hash identically.
+        if self.0.is_zero() {
+            F::zero().integer_decode().hash(state);
+        } else {
+            self.0.integer_decode().hash(state);
+        }
+    }
+}
+
+impl<F: fmt::Debug> fmt::Debug for TotalCost<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for TotalCost<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! binary_op {
+    ($trait:ident, $method:ident) => {
+        impl<F: Float> $trait for TotalCost<F> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self::new(self.0.$method(rhs.0))
+            }
+        }
+    };
+}
+
+binary_op!(Add, add);
+binary_op!(Sub, sub);
+binary_op!(Mul, mul);
+binary_op!(Div, div);
+binary_op!(Rem, rem);
+
+impl<F: Float> AddAssign for TotalCost<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Float> SubAssign for TotalCost<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Float> Neg for TotalCost<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<F: Float> Sum for TotalCost<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<F: Float> Zero for TotalCost<F> {
+    fn zero() -> Self {
+        Self(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<F: Float> One for TotalCost<F> {
+    fn one() -> Self {
+        Self(F::one())
+    }
+}
+
+impl<F: Float> Bounded for TotalCost<F> {
+    fn min_value() -> Self {
+        Self(F::min_value())
+    }
+
+    fn max_value() -> Self {
+        Self(F::max_value())
+    }
+}
+
+impl<F: Float> Num for TotalCost<F> {
+    type FromStrRadixErr = Option<F::FromStrRadixErr>;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        F::from_str_radix(str, radix)
+            .map_err(Some)
+            .and_then(|value| Self::try_new(value).map_err(|_| None))
+    }
+}
+
+impl<F: Float> Signed for TotalCost<F> {
+    fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.0 <= other.0 {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        if self.0.is_zero() {
+            Self::zero()
+        } else {
+            Self(self.0.signum())
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0 > F::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 < F::zero()
+    }
+}
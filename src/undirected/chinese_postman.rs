@@ -0,0 +1,144 @@
+//! Solve the [Chinese postman problem](https://en.wikipedia.org/wiki/Chinese_postman_problem)
+//! (or route inspection problem) in an undirected graph.
+
+use crate::FxIndexSet;
+use crate::directed::chinese_postman::eulerian_circuit;
+use crate::directed::dijkstra::{build_path, dijkstra_all};
+use num_traits::Zero;
+use std::hash::Hash;
+use thiserror::Error;
+
+/// Maximum number of nodes with an odd number of edges accepted by [`chinese_postman`].
+pub const CHINESE_POSTMAN_MAX_ODD_NODES: usize = 20;
+
+/// Error encountered while looking for a closed walk with [`chinese_postman`] or
+/// [`chinese_postman_directed`](crate::directed::chinese_postman::chinese_postman_directed).
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ChinesePostmanError {
+    /// The edges do not form a connected graph, or a strongly connected one if they are
+    /// directed, so no closed walk goes through all of them
+    #[error("the graph is not connected")]
+    Disconnected,
+    /// More than [`CHINESE_POSTMAN_MAX_ODD_NODES`] nodes have an odd number of edges
+    #[error("too many nodes with an odd number of edges ({0})")]
+    TooManyOddNodes(usize),
+}
+
+/// Find a closed walk of minimum cost going through every edge of an undirected graph
+/// at least once.
+///
+/// - `edges` is the list of edges of the graph, given as `(a, b, cost)` tuples.
+///   Costs must be non-negative.
+///
+/// When some nodes have an odd number of edges, some edges must be used several times.
+/// The cheapest way to duplicate edges so that every node has an even number of edges
+/// is found by pairing the nodes with an odd number of edges through shortest paths.
+/// A walk is then built from the resulting Eulerian graph.
+///
+/// The pairing is computed exactly with a dynamic programming algorithm whose time
+/// and space complexity is exponential in the number of nodes with an odd number of
+/// edges, which is why this number is limited to [`CHINESE_POSTMAN_MAX_ODD_NODES`].
+///
+/// The walk is returned as a list of nodes starting and ending with the first node of
+/// the first edge, along with its total cost. An empty list of edges gives an empty walk.
+///
+/// # Errors
+///
+/// - [`ChinesePostmanError::Disconnected`] if no closed walk exists because the graph
+///   is not connected.
+/// - [`ChinesePostmanError::TooManyOddNodes`] if more than
+///   [`CHINESE_POSTMAN_MAX_ODD_NODES`] nodes have an odd number of edges.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::chinese_postman;
+///
+/// // A square a-b-c-d with a diagonal a-c: a and c have three edges, and the cheapest
+/// // way to link them is the diagonal, which is used twice.
+/// let edges = [('a', 'b', 1), ('b', 'c', 1), ('c', 'd', 1), ('d', 'a', 1), ('a', 'c', 1)];
+/// let (walk, cost) = chinese_postman(&edges).unwrap();
+/// assert_eq!(cost, 6);
+/// assert_eq!(walk.len(), 7);
+/// assert_eq!(walk.first(), Some(&'a'));
+/// assert_eq!(walk.last(), Some(&'a'));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn chinese_postman<N, C>(edges: &[(N, N, C)]) -> Result<(Vec<N>, C), ChinesePostmanError>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+{
+    let nodes = edges
+        .iter()
+        .flat_map(|(a, b, _)| [a, b])
+        .collect::<FxIndexSet<_>>();
+    let index = |n| nodes.get_index_of(n).unwrap(); // Cannot fail
+    let mut arcs = edges
+        .iter()
+        .map(|(a, b, _)| (index(a), index(b)))
+        .collect::<Vec<_>>();
+    let mut neighbours = vec![vec![]; nodes.len()];
+    let mut degrees = vec![0; nodes.len()];
+    for (&(a, b), &(_, _, c)) in arcs.iter().zip(edges) {
+        neighbours[a].push((b, c));
+        neighbours[b].push((a, c));
+        degrees[a] += 1;
+        degrees[b] += 1;
+    }
+    let mut cost = edges.iter().fold(C::zero(), |cost, &(_, _, c)| cost + c);
+
+    // Pair the nodes with an odd degree through shortest paths which are added to the
+    // graph. `pairings[mask]` holds the cost of the best pairing of the odd nodes whose
+    // bit is set in `mask` along with the node paired with the lowest one.
+    let odd = (0..nodes.len())
+        .filter(|&n| degrees[n] % 2 == 1)
+        .collect::<Vec<_>>();
+    if odd.len() > CHINESE_POSTMAN_MAX_ODD_NODES {
+        return Err(ChinesePostmanError::TooManyOddNodes(odd.len()));
+    }
+    let reachables = odd
+        .iter()
+        .map(|&n| dijkstra_all(&n, |&n| neighbours[n].iter().copied()))
+        .collect::<Vec<_>>();
+    let mut distances = vec![vec![C::zero(); odd.len()]; odd.len()];
+    for (i, reachable) in reachables.iter().enumerate() {
+        for (j, n) in odd.iter().enumerate() {
+            if i != j {
+                distances[i][j] = reachable.get(n).ok_or(ChinesePostmanError::Disconnected)?.1;
+            }
+        }
+    }
+    let mut pairings: Vec<Option<(C, usize)>> = vec![None; 1 << odd.len()];
+    pairings[0] = Some((C::zero(), 0));
+    for mask in 1..pairings.len() {
+        if mask.count_ones() % 2 == 1 {
+            continue;
+        }
+        let i = mask.trailing_zeros() as usize;
+        pairings[mask] = (i + 1..odd.len())
+            .filter(|&j| mask & (1 << j) != 0)
+            .filter_map(|j| {
+                let (c, _) = pairings[mask & !(1 << i) & !(1 << j)]?;
+                Some((c + distances[i][j], j))
+            })
+            .min_by_key(|&(c, _)| c);
+    }
+    let mut mask = pairings.len() - 1;
+    // Every odd node can reach the others, so all the even-sized masks have a pairing.
+    cost = cost + pairings[mask].unwrap().0;
+    while mask != 0 {
+        let i = mask.trailing_zeros() as usize;
+        let (_, j) = pairings[mask].unwrap();
+        let path = build_path(&odd[j], &reachables[i]);
+        arcs.extend(path.windows(2).map(|w| (w[0], w[1])));
+        mask &= !(1 << i) & !(1 << j);
+    }
+
+    let Some(&(start, _)) = arcs.first() else {
+        return Ok((vec![], cost));
+    };
+    let walk = eulerian_circuit(nodes.len(), &arcs, false, start)
+        .ok_or(ChinesePostmanError::Disconnected)?;
+    Ok((walk.into_iter().map(|n| nodes[n].clone()).collect(), cost))
+}
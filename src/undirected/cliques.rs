@@ -1,5 +1,6 @@
 //! Find cliques in an undirected graph.
 
+use crate::FxIndexSet;
 use std::collections::HashSet;
 use std::hash::Hash;
 
@@ -22,12 +23,12 @@ where
 {
     let mut result = Vec::new();
     let mut consumer = |n: &HashSet<N>| result.push(n.to_owned());
-    let mut remaining_nodes: HashSet<N> = vertices.into_iter().collect::<HashSet<_>>();
+    let mut remaining_nodes = vertices.into_iter().collect::<FxIndexSet<_>>();
     bron_kerbosch(
         connected,
         &HashSet::new(),
         &mut remaining_nodes,
-        &mut HashSet::new(),
+        &mut FxIndexSet::default(),
         &mut consumer,
     );
     result
@@ -52,12 +53,12 @@ where
     IN: IntoIterator<Item = N>,
     CO: FnMut(&HashSet<N>),
 {
-    let mut remaining_nodes: HashSet<N> = vertices.into_iter().collect();
+    let mut remaining_nodes = vertices.into_iter().collect::<FxIndexSet<_>>();
     bron_kerbosch(
         connected,
         &HashSet::new(),
         &mut remaining_nodes,
-        &mut HashSet::new(),
+        &mut FxIndexSet::default(),
         consumer,
     );
 }
@@ -65,8 +66,8 @@ where
 fn bron_kerbosch<N, FN, CO>(
     connected: &mut FN,
     potential_clique: &HashSet<N>,
-    remaining_nodes: &mut HashSet<N>,
-    skip_nodes: &mut HashSet<N>,
+    remaining_nodes: &mut FxIndexSet<N>,
+    skip_nodes: &mut FxIndexSet<N>,
     consumer: &mut CO,
 ) where
     N: Eq + Hash + Clone,
@@ -82,13 +83,13 @@ fn bron_kerbosch<N, FN, CO>(
         let mut new_potential_clique = potential_clique.clone();
         new_potential_clique.insert(node.to_owned());
 
-        let mut new_remaining_nodes: HashSet<N> = remaining_nodes
+        let mut new_remaining_nodes: FxIndexSet<N> = remaining_nodes
             .iter()
             .filter(|n| *n != node && connected(node, n))
             .cloned()
             .collect();
 
-        let mut new_skip_list: HashSet<N> = skip_nodes
+        let mut new_skip_list: FxIndexSet<N> = skip_nodes
             .iter()
             .filter(|n| *n != node && connected(node, n))
             .cloned()
@@ -104,7 +105,7 @@ fn bron_kerbosch<N, FN, CO>(
         // We're done considering this node. If there was a way to form a clique with it, we
         // already discovered its maximal clique in the recursive call above.  So, go ahead
         // and remove it from the list of remaining nodes and add it to the skip list.
-        remaining_nodes.remove(node);
+        remaining_nodes.swap_remove(node);
         skip_nodes.insert(node.to_owned());
     }
 }
@@ -5,7 +5,8 @@ use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::marker::PhantomData;
 
-use rustc_hash::{FxHashMap, FxHashSet};
+use crate::{FxIndexMap, FxIndexSet};
+use rustc_hash::FxHashSet;
 
 /// A connected component implementation for various generic types.
 ///
@@ -99,11 +100,11 @@ where
     ///   acceptable for a group to contain only one node.
     ///
     /// This function returns a list of sets of nodes forming disjoint connected
-    /// sets.
+    /// sets, in the order of their first group.
     #[must_use]
     pub fn components(groups: &[It]) -> C2 {
         let (_, gindices) = Self::separate_components(groups);
-        let mut gb: FxHashMap<usize, FxHashSet<N>> = FxHashMap::default();
+        let mut gb: FxIndexMap<usize, FxIndexSet<N>> = FxIndexMap::default();
         for (i, n) in gindices
             .into_iter()
             .enumerate()
@@ -142,6 +143,47 @@ where
         )
     }
 
+    /// Extract connected components from an implicit graph, by exploring it from some
+    /// start vertices.
+    ///
+    /// - `starts` is a collection of vertices to be considered as start points.
+    /// - `neighbours` is a function returning the neighbours of a given node, which
+    ///   must be symmetric: a node must be a neighbour of its neighbours.
+    ///
+    /// Unlike [`connected_components`](Self::connected_components), which only looks
+    /// at the immediate neighbours of the start vertices, this function explores the
+    /// graph breadth-first from every start vertex which has not been reached yet, and
+    /// calls `neighbours` exactly once for every reached vertex.
+    ///
+    /// This function returns a list of sets of nodes forming disjoint connected
+    /// sets, in the order of their first start vertex.
+    pub fn components_reach<FN, IN>(starts: &[N], mut neighbours: FN) -> C2
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N>,
+    {
+        let mut seen = FxHashSet::default();
+        starts
+            .iter()
+            .filter_map(|start| {
+                if !seen.insert(start.clone()) {
+                    return None;
+                }
+                let mut component = vec![start.clone()];
+                let mut i = 0;
+                while let Some(node) = component.get(i) {
+                    let new = neighbours(node)
+                        .into_iter()
+                        .filter(|n| seen.insert(n.clone()))
+                        .collect::<Vec<_>>();
+                    component.extend(new);
+                    i += 1;
+                }
+                Some(component.into_iter().collect())
+            })
+            .collect()
+    }
+
     /// Locate vertices amongst disjoint sets.
     ///
     /// - `components` are disjoint vertices sets.
@@ -225,6 +267,56 @@ where
     ConnectedComponents::<N>::connected_components(starts, neighbours)
 }
 
+/// Extract connected components from an implicit graph, by exploring it from some
+/// start vertices.
+///
+/// - `starts` is a collection of vertices to be considered as start points.
+/// - `neighbours` is a function returning the neighbours of a given node, which
+///   must be symmetric: a node must be a neighbour of its neighbours.
+///
+/// Unlike [`connected_components`], which only looks at the immediate neighbours of
+/// the start vertices, this function explores the graph breadth-first from every
+/// start vertex which has not been reached yet, as
+/// [`bfs_reach`](crate::directed::bfs::bfs_reach) would do, but shares the set of
+/// reached vertices between the explorations. The graph never has to be turned into
+/// a list of edges, and `neighbours` is called exactly once for every reached vertex.
+///
+/// This function returns a list of sets of nodes forming disjoint connected
+/// sets, in the order of their first start vertex.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::components_reach;
+///
+/// // Count the islands of a map, where land cells are connected to their four
+/// // neighbours.
+/// let map = ["##..#", "#...#", ".....", "..###"];
+/// let land = |x: usize, y: usize| {
+///     map.get(y).and_then(|row| row.as_bytes().get(x)) == Some(&b'#')
+/// };
+/// let cells = (0..4)
+///     .flat_map(|y| (0..5).map(move |x| (x, y)))
+///     .filter(|&(x, y)| land(x, y))
+///     .collect::<Vec<_>>();
+/// let islands = components_reach(&cells, |&(x, y)| {
+///     [(x + 1, y), (x.wrapping_sub(1), y), (x, y + 1), (x, y.wrapping_sub(1))]
+///         .into_iter()
+///         .filter(|&(x, y)| land(x, y))
+/// });
+/// let sizes = islands.iter().map(|island| island.len()).collect::<Vec<_>>();
+/// assert_eq!(sizes, vec![3, 2, 3]);
+/// assert!(islands[1].contains(&(4, 1)));
+/// ```
+pub fn components_reach<N, FN, IN>(starts: &[N], neighbours: FN) -> Vec<HashSet<N>>
+where
+    N: Clone + Hash + Eq,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    ConnectedComponents::<N>::components_reach(starts, neighbours)
+}
+
 /// Locate vertices amongst disjoint sets.
 ///
 /// - `components` are disjoint vertices sets.
@@ -1,8 +1,9 @@
 //! Find minimum-spanning-tree in an undirected graph using
 //! [Kruskal's algorithm](https://en.wikipedia.org/wiki/Kruskal's_algorithm).
 
-use crate::FxIndexSet;
-use std::hash::Hash;
+use indexmap::IndexSet;
+use rustc_hash::FxHasher;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 use std::mem;
 
 // Find parent and compress path by path halving.
@@ -40,7 +41,13 @@ where
     let mut parents = (0..number_of_nodes).collect::<Vec<_>>();
     let mut ranks = vec![1; number_of_nodes];
     let mut edges = edges.as_ref().to_vec();
-    edges.sort_unstable_by(|a, b| a.2.cmp(&b.2));
+    // With the `deterministic` feature, edges with the same weight are considered in the
+    // order they were given.
+    if cfg!(feature = "deterministic") {
+        edges.sort_by(|a, b| a.2.cmp(&b.2));
+    } else {
+        edges.sort_unstable_by(|a, b| a.2.cmp(&b.2));
+    }
     edges.into_iter().filter_map(move |(a, b, w)| {
         let ra = find(&mut parents, a);
         let rb = find(&mut parents, b);
@@ -61,7 +68,21 @@ where
     N: Hash + Eq,
     C: Clone + Ord,
 {
-    let mut nodes = FxIndexSet::default();
+    kruskal_with_hasher(edges, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Find a minimum-spanning-tree using a custom hasher. From a collection of
+/// weighted edges, return an iterator of edges forming a minimum-spanning-tree.
+pub fn kruskal_with_hasher<N, C, H>(
+    edges: &[(N, N, C)],
+    hasher: H,
+) -> impl Iterator<Item = (&N, &N, C)>
+where
+    N: Hash + Eq,
+    C: Clone + Ord,
+    H: BuildHasher,
+{
+    let mut nodes = IndexSet::with_hasher(hasher);
     let edges = edges
         .iter()
         .map(|(a, b, w)| {
@@ -1,6 +1,8 @@
 //! Algorithms for undirected graphs.
 
+pub mod chinese_postman;
 pub mod cliques;
 pub mod connected_components;
 pub mod kruskal;
 pub mod prim;
+pub mod shortest_cycle;
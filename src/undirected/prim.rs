@@ -3,7 +3,9 @@
 
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashSet};
-use std::hash::Hash;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+
+use rustc_hash::FxHasher;
 
 /// Find a minimum-spanning-tree. From a collection of weighted edges,
 /// return a vector of edges forming a minimum-spanning-tree.
@@ -11,6 +13,17 @@ pub fn prim<N, C>(edges: &[(N, N, C)]) -> Vec<(&N, &N, C)>
 where
     N: Hash + Eq + Ord,
     C: Clone + Ord,
+{
+    prim_with_hasher(edges, BuildHasherDefault::<FxHasher>::default())
+}
+
+/// Find a minimum-spanning-tree using a custom hasher. From a collection of
+/// weighted edges, return a vector of edges forming a minimum-spanning-tree.
+pub fn prim_with_hasher<N, C, H>(edges: &[(N, N, C)], hasher: H) -> Vec<(&N, &N, C)>
+where
+    N: Hash + Eq + Ord,
+    C: Clone + Ord,
+    H: BuildHasher,
 {
     let Some((start, ..)) = edges.first() else {
         return vec![];
@@ -21,7 +34,7 @@ where
         .filter_map(|(n, n1, c)| (n == start).then_some(Reverse((c, n, n1))))
         .collect::<BinaryHeap<_>>();
 
-    let (mut mst, mut visited) = (Vec::new(), HashSet::new());
+    let (mut mst, mut visited) = (Vec::new(), HashSet::with_hasher(hasher));
     visited.insert(start);
     while let Some(Reverse((c, n, n1))) = priority_queue.pop() {
         if visited.contains(n1) {
@@ -0,0 +1,148 @@
+//! Find a shortest cycle in an undirected graph, whose length is the
+//! [girth](https://en.wikipedia.org/wiki/Girth_(graph_theory)) of the graph.
+
+use crate::directed::shortest_cycle::index_graph;
+use num_traits::Zero;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+/// Find a shortest cycle in a weighted undirected graph.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `neighbours` are
+///   also taken into account.
+/// - `neighbours` returns a list of neighbours for a given node, along with the cost of
+///   the edge between the node and the neighbour. If `b` is a neighbour of `a`, `a` must
+///   be a neighbour of `b` with the same cost. Costs must be positive.
+///
+/// The cycle is returned along with its total cost, starting with its node which comes
+/// first in the exploration order, and without repeating this node at the end. Since
+/// going back and forth along an edge is not a cycle, cycles have at least three nodes,
+/// except for a node which is its own neighbour. When several edges link the same
+/// nodes, only the cheapest one is taken into account. `None` is returned if the graph is
+/// a forest.
+///
+/// A Dijkstra search is run from every node, only going through nodes coming later in
+/// the exploration order, and stops as soon as it cannot improve on the best cycle found
+/// so far. Every edge outside of the shortest paths tree closes a cycle going through
+/// the starting node.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::shortest_cycle_undirected;
+///
+/// // A square with a diagonal, where the triangle made of 0, 1 and 2 is the cheapest.
+/// let edges = [(0, 1, 3), (1, 2, 3), (2, 3, 4), (3, 0, 3), (0, 2, 5)];
+/// let neighbours = |&n: &u8| {
+///     edges
+///         .iter()
+///         .filter_map(move |&(a, b, c)| (a == n).then_some((b, c)).or((b == n).then_some((a, c))))
+/// };
+/// assert_eq!(shortest_cycle_undirected(&[0], neighbours), Some((vec![0, 2, 1], 11)));
+/// ```
+#[expect(clippy::missing_panics_doc)]
+pub fn shortest_cycle_undirected<N, C, FN, IN>(nodes: &[N], neighbours: FN) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (indices, adjacency) = index_graph(nodes, neighbours);
+    // The best cycle is made of the paths from its first node to two nodes, and of the
+    // edge between those two nodes.
+    let mut best: Option<(C, Vec<usize>)> = None;
+    let mut parents = vec![usize::MAX; adjacency.len()];
+    let mut costs = vec![None; adjacency.len()];
+    let mut settled = vec![false; adjacency.len()];
+    let path_to = |parents: &[usize], mut node: usize| {
+        let mut path = vec![node];
+        while parents[node] != usize::MAX {
+            node = parents[node];
+            path.push(node);
+        }
+        path
+    };
+    for start in 0..adjacency.len() {
+        costs[start..].fill(None);
+        settled[start..].fill(false);
+        costs[start] = Some(Zero::zero());
+        parents[start] = usize::MAX;
+        let mut to_see = BinaryHeap::from([Reverse((C::zero(), start))]);
+        while let Some(Reverse((cost, node))) = to_see.pop() {
+            if best.as_ref().is_some_and(|&(c, _)| cost >= c) {
+                break;
+            }
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+            for &(next, edge) in &adjacency[node] {
+                if next < start {
+                    continue;
+                }
+                if settled[next] {
+                    if parents[node] == next {
+                        continue;
+                    }
+                    let new_cost = cost + edge + costs[next].unwrap(); // Cannot fail
+                    if best.as_ref().is_none_or(|&(c, _)| new_cost < c) {
+                        let mut cycle = path_to(&parents, node);
+                        cycle.reverse();
+                        if next != node {
+                            let mut other = path_to(&parents, next);
+                            other.pop();
+                            cycle.extend(other);
+                        }
+                        best = Some((new_cost, cycle));
+                    }
+                } else if costs[next].is_none_or(|c| cost + edge < c) {
+                    costs[next] = Some(cost + edge);
+                    parents[next] = node;
+                    to_see.push(Reverse((cost + edge, next)));
+                }
+            }
+        }
+    }
+    best.map(|(cost, cycle)| {
+        (
+            cycle.into_iter().map(|i| indices[i].clone()).collect(),
+            cost,
+        )
+    })
+}
+
+/// Find a shortest cycle in an unweighted undirected graph, whose length is the
+/// [girth](https://en.wikipedia.org/wiki/Girth_(graph_theory)) of the graph.
+///
+/// - `nodes` is a list of nodes. Nodes reachable from them through `neighbours` are
+///   also taken into account.
+/// - `neighbours` returns a list of neighbours for a given node. If `b` is a neighbour of
+///   `a`, `a` must be a neighbour of `b`.
+///
+/// The cycle is returned along with its number of edges, as described in
+/// [`shortest_cycle_undirected`]. `None` is returned if the graph is a forest.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::girth_undirected;
+///
+/// // The Petersen graph has a girth of 5.
+/// let neighbours = |&n: &u8| match n {
+///     0..5 => vec![(n + 1) % 5, (n + 4) % 5, n + 5],
+///     _ => vec![(n - 5 + 2) % 5 + 5, (n - 5 + 3) % 5 + 5, n - 5],
+/// };
+/// let (cycle, length) = girth_undirected(&[0], neighbours).unwrap();
+/// assert_eq!(length, 5);
+/// assert_eq!(cycle.len(), 5);
+/// ```
+pub fn girth_undirected<N, FN, IN>(nodes: &[N], mut neighbours: FN) -> Option<(Vec<N>, usize)>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    shortest_cycle_undirected(nodes, |n| neighbours(n).into_iter().map(|n| (n, 1)))
+}
@@ -1,7 +1,7 @@
 //! Miscellaneous utilities
 
 use integer_sqrt::IntegerSquareRoot;
-use num_traits::{PrimInt, Unsigned};
+use num_traits::{PrimInt, Unsigned, Zero};
 
 /// Return the square root of `n` if `n` is square, `None` otherwise.
 ///
@@ -98,3 +98,19 @@ pub const fn constrain(value: isize, upper: usize) -> usize {
         (upper - (-value) as usize % upper) % upper
     }
 }
+
+/// Multiply `value` by `n` using additions only, for cost types which cannot be
+/// built from a `usize`.
+pub(crate) fn times<C: Zero + Copy>(mut value: C, mut n: usize) -> C {
+    let mut result = C::zero();
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result + value;
+        }
+        n >>= 1;
+        if n > 0 {
+            value = value + value;
+        }
+    }
+    result
+}
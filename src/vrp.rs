@@ -0,0 +1,271 @@
+//! Build delivery routes for the [capacitated vehicle routing
+//! problem](https://en.wikipedia.org/wiki/Vehicle_routing_problem).
+//!
+//! Locations are numbered from `0` to `n - 1`, location `0` being the depot from which
+//! every vehicle starts and to which it comes back, and the other ones being customers.
+//! Every customer has a demand, and the sum of the demands of the customers served by a
+//! vehicle must not exceed its capacity. The distance between two locations is given by
+//! a function, which can for example read a [`Matrix`](crate::matrix::Matrix) with
+//! `|a, b| matrix[(a, b)]`. Distances do not need to be symmetric.
+//!
+//! A route is described by the list of customers it serves in order, without the depot.
+//! [`vrp_savings`] builds a first set of routes, which [`vrp_local_search`] can then
+//! improve. Both are heuristics, the problem being NP-hard.
+
+use num_traits::Zero;
+use std::ops::Sub;
+
+/// Build routes for the capacitated vehicle routing problem using the [savings
+/// algorithm](https://en.wikipedia.org/wiki/Clarke_and_Wright_algorithm) of Clarke and
+/// Wright.
+///
+/// - `demands` is the demand of every location. The demand of the depot, `demands[0]`,
+///   is ignored.
+/// - `capacity` is the capacity of every vehicle.
+/// - `distance` returns the distance from a location to another one.
+///
+/// Every customer starts in a route of its own. Routes are then merged, as long as
+/// the capacity allows it, in decreasing order of the distance saved by going directly
+/// from the last customer of a route to the first customer of the other one instead
+/// of going through the depot.
+///
+/// The routes are returned without the depot, in the order of their first customer
+/// before merging. `None` is returned if the demand of a customer exceeds the capacity.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{Matrix, vrp_cost, vrp_savings};
+///
+/// // The depot is in the middle of two groups of customers, each of which fits in a
+/// // vehicle.
+/// let positions = [0i32, -10, -11, -12, 10, 11, 12];
+/// let distances = Matrix::from_fn(7, 7, |(a, b)| positions[a].abs_diff(positions[b]));
+/// let demands = [0, 3, 3, 3, 3, 3, 3];
+/// let routes = vrp_savings(&demands, 9, |a, b| distances[(a, b)]).unwrap();
+/// assert_eq!(routes.len(), 2);
+/// assert_eq!(vrp_cost(&routes, |a, b| distances[(a, b)]), 48);
+/// ```
+pub fn vrp_savings<C, Q, FD>(
+    demands: &[Q],
+    capacity: Q,
+    mut distance: FD,
+) -> Option<Vec<Vec<usize>>>
+where
+    C: Zero + Ord + Copy + Sub<Output = C>,
+    Q: Zero + Ord + Copy,
+    FD: FnMut(usize, usize) -> C,
+{
+    if demands.iter().skip(1).any(|&q| q > capacity) {
+        return None;
+    }
+    let mut routes = (0..demands.len())
+        .map(|c| (c > 0).then(|| (vec![c], demands[c])))
+        .collect::<Vec<_>>();
+    let mut route_of = (0..demands.len()).collect::<Vec<_>>();
+    let mut savings = Vec::new();
+    for i in 1..demands.len() {
+        for j in (1..demands.len()).filter(|&j| j != i) {
+            let through_depot = distance(i, 0) + distance(0, j);
+            let direct = distance(i, j);
+            if direct < through_depot {
+                savings.push((through_depot - direct, i, j));
+            }
+        }
+    }
+    // A stable sort keeps the pairs with the same savings in a predictable order.
+    savings.sort_by_key(|&(saving, _, _)| std::cmp::Reverse(saving));
+    for (_, i, j) in savings {
+        let (ri, rj) = (route_of[i], route_of[j]);
+        let (Some((first, first_load)), Some((second, second_load))) = (&routes[ri], &routes[rj])
+        else {
+            continue;
+        };
+        if ri != rj
+            && first.last() == Some(&i)
+            && second.first() == Some(&j)
+            && *first_load + *second_load <= capacity
+        {
+            let Some((second, second_load)) = routes[rj].take() else {
+                continue;
+            };
+            for &c in &second {
+                route_of[c] = ri;
+            }
+            if let Some((first, first_load)) = &mut routes[ri] {
+                first.extend(second);
+                *first_load = *first_load + second_load;
+            }
+        }
+    }
+    Some(
+        routes
+            .into_iter()
+            .flatten()
+            .map(|(route, _)| route)
+            .collect(),
+    )
+}
+
+/// Improve routes for the capacitated vehicle routing problem using a local search.
+///
+/// - `routes` is a list of routes, without the depot, respecting the capacity of the
+///   vehicles, as returned by [`vrp_savings`].
+/// - `demands` is the demand of every location. The demand of the depot, `demands[0]`,
+///   is ignored.
+/// - `capacity` is the capacity of every vehicle.
+/// - `distance` returns the distance from a location to another one.
+///
+/// The following moves are applied as long as one of them reduces the total distance:
+///
+/// - reversing a part of a route (2-opt);
+/// - moving a customer to another route;
+/// - exchanging two customers of different routes.
+///
+/// Moves exceeding the capacity of a vehicle are never applied. Routes which end up
+/// empty are removed, the order of the other ones is kept.
+///
+/// # Example
+///
+/// ```
+/// use pathfinding::prelude::{vrp_cost, vrp_local_search};
+///
+/// // Customers on a line, served in a crossing order.
+/// let distance = |a: usize, b: usize| a.abs_diff(b);
+/// let routes = vrp_local_search(vec![vec![3, 1, 4, 2]], &[0, 1, 1, 1, 1], 4, distance);
+/// assert_eq!(vrp_cost(&routes, distance), 8);
+/// ```
+pub fn vrp_local_search<C, Q, FD>(
+    mut routes: Vec<Vec<usize>>,
+    demands: &[Q],
+    capacity: Q,
+    mut distance: FD,
+) -> Vec<Vec<usize>>
+where
+    C: Zero + Ord + Copy,
+    Q: Zero + Ord + Copy,
+    FD: FnMut(usize, usize) -> C,
+{
+    let mut costs = routes
+        .iter()
+        .map(|route| route_cost(route, &mut distance))
+        .collect::<Vec<_>>();
+    let mut loads = routes
+        .iter()
+        .map(|route| route_load(route, demands))
+        .collect::<Vec<_>>();
+    while improve_once(
+        &mut routes,
+        &mut costs,
+        &mut loads,
+        demands,
+        capacity,
+        &mut distance,
+    ) {}
+    routes.retain(|route| !route.is_empty());
+    routes
+}
+
+/// Compute the total distance travelled by the vehicles following `routes`, every
+/// route starting from and ending at the depot.
+///
+/// This function can be used to compare routes returned by [`vrp_savings`] and
+/// [`vrp_local_search`].
+pub fn vrp_cost<C, FD>(routes: &[Vec<usize>], mut distance: FD) -> C
+where
+    C: Zero + Copy,
+    FD: FnMut(usize, usize) -> C,
+{
+    routes.iter().fold(C::zero(), |cost, route| {
+        cost + route_cost(route, &mut distance)
+    })
+}
+
+/// Distance travelled to serve the customers of `route` from the depot.
+fn route_cost<C, FD>(route: &[usize], distance: &mut FD) -> C
+where
+    C: Zero + Copy,
+    FD: FnMut(usize, usize) -> C,
+{
+    let stops = std::iter::once(&0).chain(route).chain(std::iter::once(&0));
+    stops
+        .clone()
+        .zip(stops.skip(1))
+        .fold(C::zero(), |cost, (&a, &b)| cost + distance(a, b))
+}
+
+/// Sum of the demands of the customers of `route`.
+fn route_load<Q: Zero + Copy>(route: &[usize], demands: &[Q]) -> Q {
+    route.iter().fold(Q::zero(), |load, &c| load + demands[c])
+}
+
+/// Apply the first improving move found, and return `true` if there was one.
+fn improve_once<C, Q, FD>(
+    routes: &mut [Vec<usize>],
+    costs: &mut [C],
+    loads: &mut [Q],
+    demands: &[Q],
+    capacity: Q,
+    distance: &mut FD,
+) -> bool
+where
+    C: Zero + Ord + Copy,
+    Q: Zero + Ord + Copy,
+    FD: FnMut(usize, usize) -> C,
+{
+    // Reverse a part of a route.
+    for r in 0..routes.len() {
+        for a in 0..routes[r].len() {
+            for b in a + 1..routes[r].len() {
+                let mut candidate = routes[r].clone();
+                candidate[a..=b].reverse();
+                let cost = route_cost(&candidate, distance);
+                if cost < costs[r] {
+                    routes[r] = candidate;
+                    costs[r] = cost;
+                    return true;
+                }
+            }
+        }
+    }
+    // Move a customer from route `r` to route `s`, or exchange it with a customer of
+    // route `s`.
+    for r in 0..routes.len() {
+        for s in (0..routes.len()).filter(|&s| s != r) {
+            for p in 0..routes[r].len() {
+                let c = routes[r][p];
+                let mut candidates = Vec::new();
+                if loads[s] + demands[c] <= capacity {
+                    let mut shortened = routes[r].clone();
+                    shortened.remove(p);
+                    for q in 0..=routes[s].len() {
+                        let mut extended = routes[s].clone();
+                        extended.insert(q, c);
+                        candidates.push((shortened.clone(), extended));
+                    }
+                }
+                for q in 0..routes[s].len() {
+                    let (mut first, mut second) = (routes[r].clone(), routes[s].clone());
+                    first[p] = routes[s][q];
+                    second[q] = c;
+                    candidates.push((first, second));
+                }
+                for (first, second) in candidates {
+                    let (first_load, second_load) =
+                        (route_load(&first, demands), route_load(&second, demands));
+                    if first_load > capacity || second_load > capacity {
+                        continue;
+                    }
+                    let (first_cost, second_cost) =
+                        (route_cost(&first, distance), route_cost(&second, distance));
+                    if first_cost + second_cost < costs[r] + costs[s] {
+                        (routes[r], costs[r], loads[r]) = (first, first_cost, first_load);
+                        (routes[s], costs[s], loads[s]) = (second, second_cost, second_load);
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,187 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+type Vertex = (usize, usize);
+
+/// A grid whose vertices have a cost for entering them.
+struct Weighted {
+    grid: Grid,
+    weights: Vec<usize>,
+}
+
+impl Weighted {
+    fn random(rng: &mut XorShiftRng, size: usize) -> Self {
+        let mut grid = Grid::new(size, size);
+        grid.fill();
+        for _ in 0..size * size / 5 {
+            grid.remove_vertex((rng.random_range(0..size), rng.random_range(0..size)));
+        }
+        let weights = (0..size * size).map(|_| rng.random_range(1..=5)).collect();
+        Self { grid, weights }
+    }
+
+    fn weight(&self, (x, y): Vertex) -> usize {
+        self.weights[y * self.grid.width + x]
+    }
+
+    fn successors(&self, &n: &Vertex) -> Vec<(Vertex, usize)> {
+        self.grid
+            .neighbours(n)
+            .into_iter()
+            .map(|m| (m, self.weight(m)))
+            .collect()
+    }
+
+    fn predecessors(&self, &n: &Vertex) -> Vec<(Vertex, usize)> {
+        if !self.grid.has_vertex(n) {
+            return Vec::new();
+        }
+        let weight = self.weight(n);
+        self.grid
+            .neighbours(n)
+            .into_iter()
+            .map(|m| (m, weight))
+            .collect()
+    }
+
+    fn shortest(&self, start: Vertex, goal: Vertex) -> Option<usize> {
+        dijkstra(&start, |n| self.successors(n), |&n| n == goal).map(|(_, cost)| cost)
+    }
+
+    fn plan(&self, planner: &mut AnytimeDStar<Vertex, usize>) -> Option<(Vec<Vertex>, usize)> {
+        let start = *planner.start();
+        planner.plan(
+            |n| self.successors(n),
+            |n| self.predecessors(n),
+            |&n| self.grid.distance(n, start),
+        )
+    }
+
+    fn check(&self, path: &[Vertex], cost: usize) {
+        assert!(
+            path.windows(2).all(|w| self.grid.has_edge(w[0], w[1])),
+            "invalid path {path:?}"
+        );
+        assert_eq!(
+            cost,
+            path[1..].iter().map(|&n| self.weight(n)).sum::<usize>()
+        );
+    }
+}
+
+#[test]
+#[expect(clippy::cast_precision_loss, clippy::float_cmp)]
+fn improving_suboptimality() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..20 {
+        let world = Weighted::random(&mut rng, 15);
+        let (start, goal) = ((0, 0), (14, 14));
+        if !world.grid.has_vertex(start) || !world.grid.has_vertex(goal) {
+            continue;
+        }
+        let shortest = world.shortest(start, goal);
+        let mut planner = AnytimeDStar::new(start, goal, 3.0);
+        for epsilon in [3.0, 2.0, 1.5, 1.0] {
+            planner.set_suboptimality(epsilon);
+            assert_eq!(planner.suboptimality(), epsilon);
+            let found = world.plan(&mut planner);
+            assert_eq!(found.is_some(), shortest.is_some());
+            if let (Some((path, cost)), Some(shortest)) = (found, shortest) {
+                world.check(&path, cost);
+                assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+                let bound = epsilon * shortest as f64;
+                assert!(cost as f64 <= bound, "cost {cost} above {bound}");
+                if epsilon == 1.0 {
+                    assert_eq!(cost, shortest);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn changing_costs() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    for _ in 0..20 {
+        let mut world = Weighted::random(&mut rng, 12);
+        let (start, goal) = ((0, 0), (11, 11));
+        world.grid.add_vertex(start);
+        world.grid.add_vertex(goal);
+        let mut planner = AnytimeDStar::new(start, goal, 1.0);
+        world.plan(&mut planner);
+        for _ in 0..5 {
+            let vertex = (rng.random_range(0..12), rng.random_range(0..12));
+            if vertex == start || vertex == goal {
+                continue;
+            }
+            let mut changed = world.grid.neighbours(vertex);
+            if rng.random_bool(0.5) {
+                world.grid.remove_vertex(vertex);
+            } else {
+                world.grid.add_vertex(vertex);
+                world.weights[vertex.1 * 12 + vertex.0] = rng.random_range(1..=5);
+                changed = world.grid.neighbours(vertex);
+            }
+            changed.push(vertex);
+            planner.update_costs(changed, |n| world.successors(n));
+            let found = world.plan(&mut planner);
+            assert_eq!(
+                found.as_ref().map(|&(_, cost)| cost),
+                world.shortest(start, goal)
+            );
+            if let Some((path, cost)) = found {
+                world.check(&path, cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn moving_start() {
+    let mut rng = XorShiftRng::seed_from_u64(2);
+    let mut world = Weighted::random(&mut rng, 15);
+    let goal = (14, 14);
+    world.grid.add_vertex(goal);
+    let mut start = (0, 0);
+    world.grid.add_vertex(start);
+    let mut planner = AnytimeDStar::new(start, goal, 2.0);
+    planner.set_suboptimality(1.0);
+    while let Some((path, cost)) = world.plan(&mut planner) {
+        world.check(&path, cost);
+        assert_eq!(Some(cost), world.shortest(start, goal));
+        if path.len() == 1 {
+            break;
+        }
+        start = path[1];
+        planner.set_start(start);
+        assert_eq!(*planner.start(), start);
+    }
+    assert_eq!(start, goal);
+}
+
+#[test]
+fn unreachable_goal() {
+    let mut grid = Grid::new(5, 5);
+    grid.fill();
+    for y in 0..5 {
+        grid.remove_vertex((2, y));
+    }
+    let world = Weighted {
+        grid,
+        weights: vec![1; 25],
+    };
+    let mut planner = AnytimeDStar::new((0, 0), (4, 4), 1.5);
+    assert_eq!(world.plan(&mut planner), None);
+    planner.set_start((3, 0));
+    let (path, cost) = world.plan(&mut planner).expect("no path found");
+    assert_eq!(cost, 5);
+    world.check(&path, cost);
+}
+
+#[test]
+#[should_panic(expected = "suboptimality factor must be at least 1")]
+fn invalid_suboptimality() {
+    let mut planner = AnytimeDStar::<u32, u32>::new(0, 1, 1.0);
+    planner.set_suboptimality(0.5);
+}
@@ -0,0 +1,74 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 80;
+
+fn build_network() -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 10) {
+                network[(a, b)] = rng.random_range(1..20);
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl Fn(&usize) -> Vec<(usize, u32)> + '_ {
+    move |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+#[test]
+fn same_costs_as_dijkstra() {
+    let network = build_network();
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    let flags = ArcFlags::new(&nodes, |&n| n % 7, successors(&network));
+    assert_eq!(flags.regions(), 7);
+    for start in 0..SIZE {
+        for goal in 0..SIZE {
+            let expected = dijkstra(&start, successors(&network), |&n| n == goal);
+            let result = flags.dijkstra(&start, &goal);
+            assert_eq!(
+                result.as_ref().map(|(_, c)| *c),
+                expected.as_ref().map(|(_, c)| *c),
+                "{start} -> {goal}"
+            );
+            if let Some((path, _)) = result {
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&goal));
+            }
+        }
+    }
+}
+
+#[test]
+fn prunes_edges() {
+    // A line 0 -> 1 -> … -> 9, with each node also leading back to 0.
+    let successors = |&n: &u8| {
+        let mut succ = vec![(0, 1)];
+        if n < 9 {
+            succ.push((n + 1, 1));
+        }
+        succ
+    };
+    let nodes = (0..10).collect::<Vec<_>>();
+    let flags = ArcFlags::new(&nodes, |&n| usize::from(n / 5), successors);
+    assert_eq!(flags.region(&7), Some(1));
+    assert_eq!(flags.region(&10), None);
+    // Going back to 0 never helps reaching the second region.
+    assert_eq!(
+        flags.successors_towards(&3, 1).collect::<Vec<_>>(),
+        vec![(4, 1)]
+    );
+    assert_eq!(flags.dijkstra(&2, &8), Some((vec![2, 3, 4, 5, 6, 7, 8], 6)));
+    assert_eq!(flags.dijkstra(&8, &1), Some((vec![8, 0, 1], 2)));
+    assert_eq!(flags.dijkstra(&8, &10), None);
+}
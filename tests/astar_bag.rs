@@ -1,5 +1,9 @@
 use itertools::Itertools;
 use pathfinding::directed::astar::astar_bag;
+use pathfinding::directed::bfs::bfs_bag;
+use pathfinding::directed::dijkstra::dijkstra_bag;
+use pathfinding::directed::fringe::fringe_bag;
+use pathfinding::directed::idastar::idastar_bag;
 
 #[test]
 fn multiple_sinks() {
@@ -55,3 +59,208 @@ fn numerous_solutions() {
     assert_eq!(cost, N * 2);
     assert_eq!(solutions.count(), 1 << N);
 }
+
+#[test]
+fn same_paths_as_yen() {
+    use pathfinding::prelude::{Matrix, yen};
+    use rand::{Rng as _, SeedableRng as _};
+    use rand_xorshift::XorShiftRng;
+
+    const SIZE: usize = 10;
+    for seed in 1..20 {
+        // Small costs, so that many paths have the same cost.
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let mut network = Matrix::new(SIZE, SIZE, 0);
+        for a in 0..SIZE {
+            for b in 0..SIZE {
+                if a != b && rng.random_ratio(1, 3) {
+                    network[(a, b)] = rng.random_range(1..4);
+                }
+            }
+        }
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect_vec()
+        };
+        let Some((solutions, cost)) = astar_bag(&0, successors, |_| 0, |&n| n == SIZE - 1) else {
+            assert!(yen(&0, successors, |&n| n == SIZE - 1, 1).is_empty());
+            continue;
+        };
+        let expected = yen(&0, successors, |&n| n == SIZE - 1, 1000)
+            .into_iter()
+            .take_while(|&(_, c)| c == cost)
+            .map(|(path, _)| path)
+            .sorted()
+            .collect_vec();
+        assert_eq!(solutions.sorted().collect_vec(), expected);
+    }
+}
+
+#[test]
+fn other_bags_same_paths_as_astar() {
+    use pathfinding::prelude::Matrix;
+    use rand::{Rng as _, SeedableRng as _};
+    use rand_xorshift::XorShiftRng;
+
+    const SIZE: usize = 12;
+    for seed in 1..40 {
+        // Small costs and a weak heuristic, so that many paths have the same cost.
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let mut network = Matrix::new(SIZE, SIZE, 0);
+        for a in 0..SIZE {
+            for b in 0..SIZE {
+                if a != b && rng.random_ratio(1, 3) {
+                    network[(a, b)] = rng.random_range(1..4);
+                }
+            }
+        }
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect_vec()
+        };
+        let success = |&n: &usize| n == SIZE - 1 || n == SIZE - 2;
+        let heuristic = |n: &usize| usize::from(!success(n));
+        let expected = astar_bag(&0, successors, heuristic, success)
+            .map(|(solutions, cost)| (solutions.sorted().collect_vec(), cost));
+        let result = fringe_bag(&0, successors, heuristic, success)
+            .map(|(solutions, cost)| (solutions.sorted().collect_vec(), cost));
+        assert_eq!(result, expected);
+        let result = idastar_bag(&0, successors, heuristic, success)
+            .map(|(solutions, cost)| (solutions.sorted().collect_vec(), cost));
+        assert_eq!(result, expected);
+        let result = dijkstra_bag(&0, successors, success)
+            .map(|(solutions, cost)| (solutions.sorted().collect_vec(), cost));
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn fringe_numerous_solutions() {
+    const N: usize = 10;
+    const GOAL: usize = 3 * N;
+    // Same graph as in `numerous_solutions`.
+    let (solutions, cost) = fringe_bag(
+        &0,
+        |&n| match n {
+            x if x % 3 == 2 => vec![(x + 1, 1)],
+            x => vec![(x + 1, 1), (x + 2, 1)],
+        },
+        |&n| GOAL.saturating_sub(n) / 2,
+        |&n| n == GOAL,
+    )
+    .unwrap();
+    assert_eq!(cost, N * 2);
+    assert_eq!(solutions.count(), 1 << N);
+}
+
+#[test]
+fn idastar_numerous_solutions() {
+    const N: usize = 10;
+    const GOAL: usize = 3 * N;
+    // Same graph as in `numerous_solutions`.
+    let (solutions, cost) = idastar_bag(
+        &0,
+        |&n| match n {
+            x if x % 3 == 2 => vec![(x + 1, 1)],
+            x => vec![(x + 1, 1), (x + 2, 1)],
+        },
+        |&n| GOAL.saturating_sub(n) / 2,
+        |&n| n == GOAL,
+    )
+    .unwrap();
+    assert_eq!(cost, N * 2);
+    assert_eq!(solutions.unique().count(), 1 << N);
+}
+
+#[test]
+fn idastar_no_path() {
+    assert!(
+        idastar_bag(
+            &0,
+            |&n| [(n + 1, 1)].into_iter().filter(|&(m, _)| m < 5),
+            |_| 0,
+            |&n| n == 7
+        )
+        .is_none()
+    );
+}
+
+#[test]
+fn bfs_same_paths_as_astar() {
+    use rand::{Rng as _, SeedableRng as _};
+    use rand_xorshift::XorShiftRng;
+
+    const SIZE: usize = 15;
+    for seed in 1..40 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let graph = (0..SIZE)
+            .map(|a| {
+                (0..SIZE)
+                    .filter(|&b| a != b && rng.random_ratio(1, 4))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let success = |&n: &usize| n == SIZE - 1 || n == SIZE - 2;
+        let expected = astar_bag(&0, |&n| graph[n].iter().map(|&m| (m, 1)), |_| 0, success)
+            .map(|(solutions, _)| solutions.sorted().collect_vec());
+        let result = bfs_bag(&0, |&n| graph[n].clone(), success)
+            .map(|solutions| solutions.sorted().collect_vec());
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn bfs_start_or_goal() {
+    // 1 --> 3 --> 5
+    // 1 --> 4 --> 5
+    let successors = |&n: &u8| match n {
+        1 => vec![3, 4],
+        3 | 4 => vec![5],
+        _ => vec![],
+    };
+    let solutions = bfs_bag(&1, successors, |&n| n == 5).unwrap();
+    assert_eq!(
+        solutions.sorted().collect_vec(),
+        vec![vec![1, 3, 5], vec![1, 4, 5]]
+    );
+    let solutions = bfs_bag(&1, successors, |&n| n == 1 || n == 3).unwrap();
+    assert_eq!(solutions.collect_vec(), vec![vec![1]]);
+    assert!(bfs_bag(&3, successors, |&n| n == 1).is_none());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_iter_same_order() {
+    use rayon::prelude::*;
+
+    // Layers of 4 nodes fully connected to the next layer, giving 4⁶ paths.
+    let successors = |&(layer, i): &(u8, u8)| {
+        if layer == 6 {
+            vec![((7, 0), 1)]
+        } else {
+            (0..4).map(|j| ((layer + 1, (i + j) % 4), 1)).collect()
+        }
+    };
+    let (mut solutions, cost) = astar_bag(&(0, 0), successors, |_| 0, |&(l, _)| l == 7).unwrap();
+    assert_eq!(cost, 7);
+    let all = solutions.par_iter().collect::<Vec<_>>();
+    assert_eq!(all.len(), 4096);
+    assert_eq!(all, solutions.clone().collect_vec());
+    assert_eq!(all.iter().unique().count(), 4096);
+    // Only the paths which have not been consumed yet are enumerated.
+    solutions.nth(99);
+    assert_eq!(solutions.par_iter().collect::<Vec<_>>(), all[100..]);
+    assert_eq!(
+        solutions
+            .par_iter()
+            .filter(|path| path[3] == (3, 0))
+            .count(),
+        all[100..].iter().filter(|path| path[3] == (3, 0)).count()
+    );
+    solutions.by_ref().for_each(drop);
+    assert_eq!(solutions.par_iter().count(), 0);
+}
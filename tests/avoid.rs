@@ -0,0 +1,75 @@
+use indexmap::IndexSet;
+use pathfinding::prelude::*;
+use std::collections::{BTreeSet, HashSet};
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    vec![(n + 1, 1), (n * 2, 1)]
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn unweighted(&n: &u32) -> Vec<u32> {
+    vec![n + 1, n * 2]
+}
+
+#[test]
+fn every_filter_kind() {
+    let expected = Some(vec![1, 2, 4, 5, 6, 12]);
+    let route = |path: Option<(Vec<u32>, u32)>| path.map(|(p, _)| p);
+    let pred = |&n: &u32| n == 3;
+    assert_eq!(
+        route(dijkstra(&1, avoiding_weighted(&pred, successors), |&n| n == 12)),
+        expected
+    );
+    let set = HashSet::from([3]);
+    assert_eq!(
+        route(dijkstra(&1, avoiding_weighted(&set, successors), |&n| n == 12)),
+        expected
+    );
+    let set = BTreeSet::from([3]);
+    assert_eq!(
+        route(astar(
+            &1,
+            avoiding_weighted(&set, successors),
+            |_| 0,
+            |&n| n == 12
+        )),
+        expected
+    );
+    let set = [3].into_iter().collect::<IndexSet<_>>();
+    assert_eq!(
+        route(fringe(
+            &1,
+            avoiding_weighted(&set, successors),
+            |_| 0,
+            |&n| n == 12
+        )),
+        expected
+    );
+    let forbidden = vec![3];
+    assert_eq!(
+        bfs(&1, avoiding(&forbidden, unweighted), |&n| n == 12),
+        expected
+    );
+    assert_eq!(
+        bfs(&1, avoiding(&forbidden[..], unweighted), |&n| n == 12),
+        expected
+    );
+}
+
+#[test]
+fn unreachable_when_blocked() {
+    let blocked = HashSet::from([2, 3]);
+    // Both successors of 1 are 2.
+    assert_eq!(bfs(&1, avoiding(&blocked, unweighted), |&n| n == 12), None);
+    assert_eq!(dfs(1, avoiding(&blocked, unweighted), |&n| n == 12), None);
+    assert_eq!(
+        bfs_reach(1, avoiding(&blocked, unweighted)).collect::<Vec<_>>(),
+        vec![1]
+    );
+    // The start node itself is never filtered.
+    assert_eq!(
+        bfs(&2, avoiding(&blocked, unweighted), |&n| n == 4),
+        Some(vec![2, 4])
+    );
+}
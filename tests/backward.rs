@@ -0,0 +1,84 @@
+use pathfinding::prelude::*;
+
+// A small graph with edges in both directions having different costs.
+const EDGES: &[(u8, u8, u32)] = &[
+    (0, 1, 4),
+    (0, 2, 1),
+    (2, 1, 2),
+    (1, 3, 1),
+    (2, 3, 5),
+    (3, 4, 3),
+    (5, 4, 1),
+    (3, 5, 1),
+];
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(n: &u8) -> Vec<(u8, u32)> {
+    EDGES
+        .iter()
+        .filter(|&&(from, _, _)| from == *n)
+        .map(|&(_, to, c)| (to, c))
+        .collect()
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn predecessors(n: &u8) -> Vec<(u8, u32)> {
+    EDGES
+        .iter()
+        .filter(|&&(_, to, _)| to == *n)
+        .map(|&(from, _, c)| (from, c))
+        .collect()
+}
+
+#[test]
+fn dijkstra_matches_forward() {
+    for start in 0..6 {
+        for goal in 0..6 {
+            let forward = dijkstra(&start, successors, |&n| n == goal);
+            let backward = dijkstra_backward(&goal, predecessors, |&n| n == start);
+            assert_eq!(
+                forward.as_ref().map(|(_, c)| *c),
+                backward.as_ref().map(|(_, c)| *c),
+                "{start} -> {goal}"
+            );
+            if let Some((path, _)) = backward {
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&goal));
+            }
+        }
+    }
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    for goal in 0..6 {
+        assert_eq!(
+            astar_backward(&goal, predecessors, |_| 0, |&n| n == 0),
+            dijkstra_backward(&goal, predecessors, |&n| n == 0)
+        );
+    }
+}
+
+#[test]
+fn several_starts() {
+    assert_eq!(
+        dijkstra_backward(&4, predecessors, |&n| n == 0 || n == 2),
+        Some((vec![2, 1, 3, 5, 4], 5))
+    );
+    assert_eq!(
+        bfs_backward(
+            &4,
+            |n| predecessors(n).into_iter().map(|(p, _)| p),
+            |&n| n <= 2
+        ),
+        Some(vec![1, 3, 4])
+    );
+    assert_eq!(
+        bfs_backward(
+            &0,
+            |n| predecessors(n).into_iter().map(|(p, _)| p),
+            |&n| n == 4
+        ),
+        None
+    );
+}
@@ -0,0 +1,106 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_graph(rng: &mut XorShiftRng) -> Vec<Vec<(usize, u32)>> {
+    (0..200)
+        .map(|_| {
+            (0..rng.random_range(0..6))
+                .map(|_| (rng.random_range(0..200), rng.random_range(1..10)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn dedup_keeps_lowest_cost() {
+    let mut successors =
+        dedup_successors(|&n: &u8| [(n + 2, 5), (n + 1, 4), (n + 2, 3), (n + 1, 4), (n + 2, 6)]);
+    assert_eq!(successors(&0), vec![(2, 3), (1, 4)]);
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let graph = random_graph(&mut rng);
+        for goal in 0..20 {
+            let expected = dijkstra(&0, |&n| graph[n].clone(), |&n| n == goal);
+            let result = dijkstra(&0, dedup_successors(|&n: &usize| graph[n].clone()), |&n| {
+                n == goal
+            });
+            assert_eq!(expected, result);
+        }
+    }
+}
+
+#[test]
+fn batched_searches() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    for batch_size in [1, 2, 16] {
+        for _ in 0..10 {
+            let graph = random_graph(&mut rng);
+            let mut batched = BatchedSuccessors::new(batch_size, |nodes: &[usize]| {
+                assert!(!nodes.is_empty() && nodes.len() <= batch_size);
+                nodes.iter().map(|&n| graph[n].clone()).collect()
+            });
+            for goal in 0..20 {
+                batched.clear();
+                let expected = dijkstra(&0, |&n| graph[n].clone(), |&n| n == goal);
+                let result = dijkstra(&0, |n| batched.successors(n), |&n| n == goal);
+                assert_eq!(expected.map(|(_, c)| c), result.as_ref().map(|&(_, c)| c));
+                if batch_size == 1 {
+                    assert_eq!(batched.calls(), batched.expanded());
+                } else {
+                    assert!(batched.calls() <= batched.expanded());
+                }
+            }
+            batched.clear();
+            let reached = bfs_reach(0, |&n| {
+                batched
+                    .successors(&n)
+                    .into_iter()
+                    .map(|(m, _)| m)
+                    .collect::<Vec<_>>()
+            })
+            .count();
+            assert_eq!(
+                reached,
+                bfs_reach(0, |&n| graph[n].iter().map(|&(m, _)| m)).count()
+            );
+            assert_eq!(batched.expanded(), reached);
+            if batch_size > 1 && reached > batch_size {
+                assert!(batched.calls() < reached);
+            }
+        }
+    }
+}
+
+#[test]
+fn batched_successors_are_merged() {
+    let mut batched = BatchedSuccessors::new(4, |nodes: &[u32]| {
+        nodes
+            .iter()
+            .map(|&n| vec![(n + 1, 2), (n + 1, 1), (n * 2, 1)])
+            .collect()
+    });
+    assert_eq!(batched.successors(&1), vec![(2, 1)]);
+    assert_eq!(batched.successors(&2), vec![(3, 1), (4, 1)]);
+    assert_eq!(batched.calls(), 2);
+    // 3 is computed along with 4, as it has been discovered before.
+    assert_eq!(batched.successors(&4), vec![(5, 1), (8, 1)]);
+    assert_eq!(batched.successors(&3), vec![(4, 1), (6, 1)]);
+    assert_eq!(batched.calls(), 3);
+    assert_eq!(batched.expanded(), 4);
+}
+
+#[test]
+#[should_panic(expected = "batch function must return successors for every node")]
+fn missing_batch_results() {
+    let mut batched = BatchedSuccessors::new(4, |_: &[u32]| Vec::<Vec<(u32, u32)>>::new());
+    batched.successors(&1);
+}
+
+#[test]
+#[should_panic(expected = "batch size must be at least 1")]
+fn empty_batches() {
+    BatchedSuccessors::new(0, |nodes: &[u32]| {
+        vec![Vec::<(u32, u32)>::new(); nodes.len()]
+    });
+}
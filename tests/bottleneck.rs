@@ -0,0 +1,150 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 9;
+
+fn build_network(seed: u8) -> Matrix<Option<i32>> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, None);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 3) {
+                network[(a, b)] = Some(rng.random_range(-50..50));
+            }
+        }
+    }
+    network
+}
+
+// Bottlenecks of all the simple paths from `node` to `goal`, computed with `combine`.
+fn all_bottlenecks(
+    network: &Matrix<Option<i32>>,
+    node: usize,
+    goal: usize,
+    visited: &mut Vec<usize>,
+    current: Option<i32>,
+    combine: fn(i32, i32) -> i32,
+    found: &mut Vec<Option<i32>>,
+) {
+    if node == goal {
+        found.push(current);
+        return;
+    }
+    visited.push(node);
+    for next in 0..SIZE {
+        if let Some(c) = network[(node, next)] {
+            if !visited.contains(&next) {
+                let current = Some(current.map_or(c, |b| combine(b, c)));
+                all_bottlenecks(network, next, goal, visited, current, combine, found);
+            }
+        }
+    }
+    visited.pop();
+}
+
+fn successors(network: &Matrix<Option<i32>>) -> impl Fn(&usize) -> Vec<(usize, i32)> + '_ {
+    |&a| {
+        (0..SIZE)
+            .filter_map(|b| network[(a, b)].map(|c| (b, c)))
+            .collect()
+    }
+}
+
+fn check_path(network: &Matrix<Option<i32>>, path: &[usize], goal: usize) -> Vec<i32> {
+    assert_eq!(path.first(), Some(&0));
+    assert_eq!(path.last(), Some(&goal));
+    path.windows(2)
+        .map(|w| network[(w[0], w[1])].unwrap())
+        .collect()
+}
+
+#[test]
+fn widest_matches_brute_force() {
+    for seed in 1..6 {
+        let network = build_network(seed);
+        for goal in 1..SIZE {
+            let mut found = Vec::new();
+            all_bottlenecks(
+                &network,
+                0,
+                goal,
+                &mut Vec::new(),
+                None,
+                i32::min,
+                &mut found,
+            );
+            let expected = found.into_iter().flatten().max();
+            let result = widest_path(&0, successors(&network), |&n| n == goal);
+            assert_eq!(result.as_ref().map(|&(_, c)| c), expected, "0 -> {goal}");
+            if let Some((path, width)) = result {
+                let edges = check_path(&network, &path, goal);
+                assert_eq!(edges.into_iter().min(), Some(width));
+            }
+        }
+    }
+}
+
+#[test]
+fn minimax_matches_brute_force() {
+    for seed in 1..6 {
+        let network = build_network(seed);
+        for goal in 1..SIZE {
+            let mut found = Vec::new();
+            all_bottlenecks(
+                &network,
+                0,
+                goal,
+                &mut Vec::new(),
+                None,
+                i32::max,
+                &mut found,
+            );
+            let expected = found.into_iter().flatten().min();
+            let result = minimax_path(&0, successors(&network), |&n| n == goal);
+            assert_eq!(result.as_ref().map(|&(_, c)| c), expected, "0 -> {goal}");
+            if let Some((path, cost)) = result {
+                let edges = check_path(&network, &path, goal);
+                assert_eq!(edges.into_iter().max(), Some(cost));
+            }
+        }
+    }
+}
+
+#[test]
+fn minimax_is_widest_of_negated() {
+    for seed in 1..6 {
+        let network = build_network(seed);
+        let negated = network.clone().map(|c| c.map(|c| -c));
+        for goal in 1..SIZE {
+            assert_eq!(
+                minimax_path(&0, successors(&network), |&n| n == goal).map(|(_, c)| c),
+                widest_path(&0, successors(&negated), |&n| n == goal).map(|(_, c)| -c)
+            );
+        }
+    }
+}
+
+#[test]
+fn empty_path() {
+    assert_eq!(
+        widest_path(&0, |&n: &u8| vec![(n + 1, 3u8)], |&n| n == 0),
+        Some((vec![0], u8::MAX))
+    );
+    assert_eq!(
+        minimax_path(&0, |&n: &u8| vec![(n + 1, 3u8)], |&n| n == 0),
+        Some((vec![0], u8::MIN))
+    );
+}
+
+#[test]
+fn no_path() {
+    assert_eq!(
+        widest_path(&0, |&n: &u8| vec![(n / 2, 3u8)], |&n| n == 1),
+        None
+    );
+    assert_eq!(
+        minimax_path(&0, |&n: &u8| vec![(n / 2, 3u8)], |&n| n == 1),
+        None
+    );
+}
@@ -0,0 +1,134 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+type Vertex = (usize, usize);
+
+fn check_solution(grid: &Grid, agents: &[(Vertex, Vertex)], paths: &[Vec<Vertex>]) {
+    assert_eq!(paths.len(), agents.len());
+    for (path, &(start, goal)) in paths.iter().zip(agents) {
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        for w in path.windows(2) {
+            assert!(w[0] == w[1] || grid.has_edge(w[0], w[1]), "{w:?}");
+        }
+    }
+    let at = |path: &[Vertex], t: usize| path[t.min(path.len() - 1)];
+    let len = paths.iter().map(Vec::len).max().unwrap();
+    for t in 0..len {
+        for (i, a) in paths.iter().enumerate() {
+            for b in &paths[i + 1..] {
+                assert_ne!(at(a, t), at(b, t), "vertex conflict at time {t}");
+                if t > 0 {
+                    assert!(
+                        at(a, t - 1) != at(b, t) || at(b, t - 1) != at(a, t),
+                        "edge conflict at time {t}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn solve(grid: &Grid, agents: &[(Vertex, Vertex)]) -> Option<Vec<Vec<Vertex>>> {
+    cbs(
+        agents,
+        |&n| grid.neighbours(n),
+        |&n, &goal| grid.distance(n, goal),
+    )
+}
+
+#[test]
+fn single_agent() {
+    let mut grid = Grid::new(10, 10);
+    grid.fill();
+    for y in 0..9 {
+        grid.remove_vertex((4, y));
+    }
+    let paths = solve(&grid, &[((0, 0), (9, 0))]).unwrap();
+    let expected = bfs(&(0, 0), |&n| grid.neighbours(n), |&n| n == (9, 0)).unwrap();
+    assert_eq!(paths[0].len(), expected.len());
+}
+
+#[test]
+fn independent_agents() {
+    let mut grid = Grid::new(6, 6);
+    grid.fill();
+    let agents = [((0, 0), (5, 0)), ((0, 5), (5, 5))];
+    let paths = solve(&grid, &agents).unwrap();
+    check_solution(&grid, &agents, &paths);
+    assert_eq!(paths[0].len(), 6);
+    assert_eq!(paths[1].len(), 6);
+}
+
+#[test]
+fn crossing_in_open_space() {
+    let mut grid = Grid::new(5, 5);
+    grid.fill();
+    let agents = [
+        ((0, 2), (4, 2)),
+        ((4, 2), (0, 2)),
+        ((2, 0), (2, 4)),
+        ((2, 4), (2, 0)),
+    ];
+    let paths = solve(&grid, &agents).unwrap();
+    check_solution(&grid, &agents, &paths);
+    // Every agent needs at least 4 moves, and they cannot all go straight.
+    let cost = paths.iter().map(|p| p.len() - 1).sum::<usize>();
+    assert!(cost > 16);
+    assert!(cost <= 24);
+}
+
+#[test]
+fn warehouse() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    // Shelves on every other column, with aisles at the top and bottom.
+    let mut grid = Grid::new(9, 7);
+    grid.fill();
+    for x in (1..9).step_by(2) {
+        for y in 1..6 {
+            grid.remove_vertex((x, y));
+        }
+    }
+    let mut vertices = grid.iter().collect::<Vec<_>>();
+    for _ in 0..3 {
+        for i in (1..vertices.len()).rev() {
+            vertices.swap(i, rng.random_range(0..=i));
+        }
+        let agents = (0..5)
+            .map(|i| (vertices[i], vertices[vertices.len() - 1 - i]))
+            .collect::<Vec<_>>();
+        let paths = solve(&grid, &agents).unwrap();
+        check_solution(&grid, &agents, &paths);
+    }
+}
+
+#[test]
+fn generic_graph() {
+    // A ring of 6 nodes, where two agents must cross each other.
+    let paths = cbs(
+        &[(0u8, 3), (3, 0)],
+        |&n| [(n + 1) % 6, (n + 5) % 6],
+        |&n, &goal| {
+            let d = n.abs_diff(goal);
+            usize::from(d.min(6 - d))
+        },
+    )
+    .unwrap();
+    // Going around the ring in the same direction avoids any conflict.
+    assert_eq!(paths[0].len(), 4);
+    assert_eq!(paths[1].len(), 4);
+}
+
+#[test]
+fn unsolvable() {
+    let mut grid = Grid::new(3, 3);
+    grid.fill();
+    grid.remove_vertex((1, 1));
+    // Same goal for two agents.
+    assert_eq!(solve(&grid, &[((0, 0), (2, 2)), ((2, 0), (2, 2))]), None);
+    // Unreachable goal.
+    grid.remove_vertex((1, 0));
+    grid.remove_vertex((1, 2));
+    assert_eq!(solve(&grid, &[((0, 0), (2, 2))]), None);
+}
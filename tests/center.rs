@@ -0,0 +1,112 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 12;
+
+fn build_network(seed: u8, ratio: u32) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(ratio, 10) {
+                network[(a, b)] = rng.random_range(1..50);
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl FnMut(&usize) -> Vec<(usize, u32)> {
+    move |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+// Costs from `start` to every node, or `None` if some node cannot be reached.
+fn all_costs(network: &Matrix<u32>, start: usize) -> Option<Vec<u32>> {
+    let reachables = dijkstra_all(&start, successors(network));
+    (0..SIZE)
+        .map(|n| {
+            if n == start {
+                Some(0)
+            } else {
+                reachables.get(&n).map(|&(_, c)| c)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn random_networks() {
+    let mut complete = 0;
+    for seed in 1..40 {
+        let network = build_network(seed, 1 + u32::from(seed) % 4);
+        let nodes = (0..SIZE).collect::<Vec<_>>();
+        let costs = (0..SIZE)
+            .map(|n| all_costs(&network, n))
+            .collect::<Vec<_>>();
+        let center = graph_center(&nodes, successors(&network));
+        let median = graph_median(&nodes, successors(&network));
+        let eccentricities = costs
+            .iter()
+            .map(|c| c.as_ref().map(|c| *c.iter().max().unwrap()))
+            .collect::<Vec<_>>();
+        let totals = costs
+            .iter()
+            .map(|c| c.as_ref().map(|c| c.iter().sum::<u32>()))
+            .collect::<Vec<_>>();
+        if let Some((node, eccentricity)) = center {
+            complete += 1;
+            assert_eq!(eccentricities[node], Some(eccentricity));
+            assert_eq!(eccentricities.iter().flatten().min(), Some(&eccentricity));
+            let (node, total) = median.unwrap();
+            assert_eq!(totals[node], Some(total));
+            assert_eq!(totals.iter().flatten().min(), Some(&total));
+        } else {
+            assert!(eccentricities.iter().all(Option::is_none));
+            assert_eq!(median, None);
+        }
+    }
+    assert!(complete > 5);
+}
+
+#[test]
+fn tree_center_and_median_differ() {
+    // A path 0 - 1 - 2 - 3 - 4 with leaves 5, 6 and 7 hanging from 1.
+    let edges = [(0, 1), (1, 2), (2, 3), (3, 4), (1, 5), (1, 6), (1, 7)];
+    let successors = |&n: &u8| {
+        edges
+            .iter()
+            .filter_map(move |&(a, b)| match n {
+                _ if n == a => Some((b, 1)),
+                _ if n == b => Some((a, 1)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(graph_center(&[0], successors), Some((2, 2)));
+    assert_eq!(graph_median(&[0], successors), Some((1, 10)));
+}
+
+#[test]
+fn single_node() {
+    assert_eq!(graph_center(&['a'], |_| vec![]), Some(('a', 0)));
+    assert_eq!(graph_median(&['a'], |_| vec![]), Some(('a', 0)));
+}
+
+#[test]
+fn unreachable() {
+    // Two disconnected nodes.
+    assert_eq!(graph_center(&[1, 2], |_: &u8| Vec::<(u8, u8)>::new()), None);
+    assert_eq!(graph_median(&[1, 2], |_: &u8| Vec::<(u8, u8)>::new()), None);
+    // Only the source of a directed path reaches all the nodes, which are
+    // limited to the ones reachable from the given nodes.
+    let successors = |&n: &u8| if n < 3 { vec![(n + 1, 1u8)] } else { vec![] };
+    assert_eq!(graph_center(&[2], successors), Some((2, 1)));
+    assert_eq!(graph_center(&[2, 0], successors), Some((0, 3)));
+    assert_eq!(graph_median(&[2, 0], successors), Some((0, 6)));
+}
@@ -0,0 +1,174 @@
+use pathfinding::prelude::*;
+use std::ops::ControlFlow;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    vec![(n + 1, 1), (n * 2, 3)]
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+const fn heuristic(_: &u32) -> u32 {
+    0
+}
+
+#[test]
+fn dijkstra_matches_full_search() {
+    let expected = dijkstra(&1, successors, |&n| n == 77);
+    for steps in [1, 5, 1000] {
+        let mut checkpoint = DijkstraCheckpoint::new(&1);
+        let result = loop {
+            if let ControlFlow::Break(r) = checkpoint.resume(successors, |&n| n == 77, steps) {
+                break r;
+            }
+        };
+        assert_eq!(result.map(|(_, c)| c), expected.clone().map(|(_, c)| c));
+    }
+}
+
+#[test]
+fn astar_matches_full_search() {
+    let expected = astar(&1, successors, heuristic, |&n| n == 77);
+    let mut checkpoint = AstarCheckpoint::new(&1);
+    let result = loop {
+        if let ControlFlow::Break(r) = checkpoint.resume(successors, heuristic, |&n| n == 77, 3) {
+            break r;
+        }
+    };
+    assert_eq!(result.map(|(_, c)| c), expected.map(|(_, c)| c));
+}
+
+#[test]
+fn bfs_matches_full_search() {
+    let succ = |&n: &u32| vec![n + 1, n * 2];
+    let expected = bfs(&1, succ, |&n| n == 77);
+    let mut checkpoint = BfsCheckpoint::new(&1);
+    let result = loop {
+        if let ControlFlow::Break(r) = checkpoint.resume(succ, |&n| n == 77, 2) {
+            break r;
+        }
+    };
+    assert_eq!(result, expected);
+    assert!(checkpoint.discovered() > 1);
+}
+
+#[test]
+fn exhausted_searches() {
+    let mut checkpoint = DijkstraCheckpoint::new(&0u8);
+    assert_eq!(
+        checkpoint.resume(|&n| (n < 3).then_some((n + 1, 1)), |_| false, 10),
+        ControlFlow::Break(None)
+    );
+    let mut checkpoint = BfsCheckpoint::new(&0u8);
+    assert_eq!(
+        checkpoint.resume(|&n| (n < 3).then_some(n + 1), |_| false, 4),
+        ControlFlow::Break(None)
+    );
+    let mut checkpoint = BfsCheckpoint::new(&0u8);
+    assert_eq!(
+        checkpoint.resume(|&n| (n < 3).then_some(n + 1), |&n| n == 0, 0),
+        ControlFlow::Continue(())
+    );
+    assert_eq!(
+        checkpoint.resume(|&n| (n < 3).then_some(n + 1), |&n| n == 0, 1),
+        ControlFlow::Break(Some(vec![0]))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialized_checkpoints() {
+    let mut checkpoint = DijkstraCheckpoint::new(&(1u32, 1u32));
+    let succ = |&(x, y): &(u32, u32)| vec![((x + 1, y), 2), ((x, y + 1), 3)];
+    assert_eq!(
+        checkpoint.resume(succ, |&p| p == (5, 5), 4),
+        ControlFlow::Continue(())
+    );
+    let saved = serde_json::to_string(&checkpoint).unwrap();
+    // The parent of the start is stored the same way whatever the width of the indices.
+    assert!(saved.starts_with(&format!("{{\"parents\":[[[1,1],[{},0]]", u64::MAX)));
+    let mut restored: DijkstraCheckpoint<(u32, u32), u32> = serde_json::from_str(&saved).unwrap();
+    let result = loop {
+        if let ControlFlow::Break(r) = restored.resume(succ, |&p| p == (5, 5), 4) {
+            break r;
+        }
+    };
+    assert_eq!(result.map(|(_, c)| c), Some(20));
+
+    let mut checkpoint = AstarCheckpoint::new(&1u32);
+    _ = checkpoint.resume(successors, heuristic, |&n| n == 50, 3);
+    let saved = serde_json::to_vec(&checkpoint).unwrap();
+    let mut restored: AstarCheckpoint<u32, u32> = serde_json::from_slice(&saved).unwrap();
+    let result = loop {
+        if let ControlFlow::Break(r) = restored.resume(successors, heuristic, |&n| n == 50, 3) {
+            break r;
+        }
+    };
+    assert_eq!(
+        result.map(|(_, c)| c),
+        dijkstra(&1, successors, |&n| n == 50).map(|(_, c)| c)
+    );
+
+    let mut checkpoint = BfsCheckpoint::new(&1u32);
+    let succ = |&n: &u32| vec![n + 1, n * 2];
+    _ = checkpoint.resume(succ, |&n| n == 19, 2);
+    let saved = serde_json::to_string(&checkpoint).unwrap();
+    assert!(saved.starts_with(&format!("{{\"parents\":[[1,{}]", u64::MAX)));
+    let mut restored: BfsCheckpoint<u32> = serde_json::from_str(&saved).unwrap();
+    let result = loop {
+        if let ControlFlow::Break(r) = restored.resume(succ, |&n| n == 19, 2) {
+            break r;
+        }
+    };
+    assert_eq!(result, bfs(&1, succ, |&n| n == 19));
+}
+
+#[test]
+fn search_drivers_match_full_search() {
+    let expected = astar(&1, successors, heuristic, |&n| n == 77);
+    let mut search = AstarSearch::new(&1, successors, heuristic, |&n| n == 77);
+    let mut steps = 0;
+    let result = loop {
+        steps += 1;
+        if let ControlFlow::Break(r) = search.step() {
+            break r;
+        }
+    };
+    assert!(steps > 1);
+    assert!(search.is_finished());
+    assert_eq!(result.map(|(_, c)| c), expected.map(|(_, c)| c));
+
+    let expected = dijkstra(&1, successors, |&n| n == 77);
+    let mut search = DijkstraSearch::new(&1, successors, |&n| n == 77);
+    assert_eq!(search.run_for(0), ControlFlow::Continue(()));
+    assert!(!search.is_finished());
+    assert_eq!(search.run().map(|(_, c)| c), expected.map(|(_, c)| c));
+
+    let succ = |&n: &u32| vec![n + 1, n * 2];
+    let expected = bfs(&1, succ, |&n| n == 77);
+    let mut search = BfsSearch::new(&1, succ, |&n| n == 77);
+    assert_eq!(search.run(), expected);
+    assert!(search.discovered() > 1);
+}
+
+#[test]
+fn finished_search_keeps_result() {
+    let mut search = DijkstraSearch::new(&1, successors, |&n| n == 10);
+    let result = search.run();
+    assert!(result.is_some());
+    assert_eq!(search.step(), ControlFlow::Break(result.clone()));
+    assert_eq!(search.run(), result);
+
+    let mut search = BfsSearch::new(&0u8, |&n| (n < 3).then_some(n + 1), |_| false);
+    assert_eq!(search.run_for(10), ControlFlow::Break(None));
+    assert_eq!(search.step(), ControlFlow::Break(None));
+}
+
+#[test]
+fn search_from_checkpoint() {
+    let mut search = AstarSearch::new(&1, successors, heuristic, |&n| n == 77);
+    assert_eq!(search.run_for(5), ControlFlow::Continue(()));
+    let checkpoint = search.checkpoint().clone();
+    let mut resumed = AstarSearch::from_checkpoint(checkpoint, successors, heuristic, |&n| n == 77);
+    assert_eq!(resumed.discovered(), search.discovered());
+    assert_eq!(resumed.run(), search.run());
+}
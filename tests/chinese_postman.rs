@@ -0,0 +1,136 @@
+use pathfinding::prelude::{
+    CHINESE_POSTMAN_MAX_ODD_NODES, ChinesePostmanError, chinese_postman, chinese_postman_directed,
+    dijkstra,
+};
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+/// Cheapest closed walk from the first node of the first edge covering all the edges,
+/// found by exploring the (node, covered edges) states.
+fn brute_force(edges: &[(u8, u8, i32)], directed: bool) -> Option<i32> {
+    let all = (1u32 << edges.len()) - 1;
+    let start = (edges[0].0, 0);
+    dijkstra(
+        &start,
+        |&(node, covered)| {
+            let mut moves = vec![];
+            for (i, &(a, b, c)) in edges.iter().enumerate() {
+                if a == node {
+                    moves.push(((b, covered | (1 << i)), c));
+                }
+                if !directed && b == node {
+                    moves.push(((a, covered | (1 << i)), c));
+                }
+            }
+            moves
+        },
+        |&(node, covered)| node == start.0 && covered == all,
+    )
+    .map(|(_, cost)| cost)
+}
+
+/// Check that the walk is closed, only uses existing edges and covers all of them.
+fn check_walk(edges: &[(u8, u8, i32)], walk: &[u8], directed: bool) {
+    assert_eq!(walk.first(), Some(&edges[0].0));
+    assert_eq!(walk.first(), walk.last());
+    let mut covered = vec![false; edges.len()];
+    for w in walk.windows(2) {
+        let i = edges
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(a, b, _))| {
+                (a, b) == (w[0], w[1]) || (!directed && (b, a) == (w[0], w[1]))
+            })
+            .map(|(i, _)| i)
+            .find(|&i| !covered[i])
+            .or_else(|| {
+                edges.iter().position(|&(a, b, _)| {
+                    (a, b) == (w[0], w[1]) || (!directed && (b, a) == (w[0], w[1]))
+                })
+            })
+            .expect("walk uses a non-existing edge");
+        covered[i] = true;
+    }
+    assert!(covered.iter().all(|&c| c));
+}
+
+fn random_edges(rng: &mut XorShiftRng, nodes: u8, count: usize) -> Vec<(u8, u8, i32)> {
+    (0..count)
+        .map(|_| {
+            (
+                rng.random_range(0..nodes),
+                rng.random_range(0..nodes),
+                rng.random_range(1..10),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn undirected_random() {
+    for seed in 1..80 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let edges = random_edges(&mut rng, 5, 7);
+        let result = chinese_postman(&edges).ok();
+        assert_eq!(result.as_ref().map(|r| r.1), brute_force(&edges, false));
+        if let Some((walk, _)) = result {
+            check_walk(&edges, &walk, false);
+        }
+    }
+}
+
+#[test]
+fn directed_random() {
+    for seed in 1..80 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let edges = random_edges(&mut rng, 4, 7);
+        let result = chinese_postman_directed(&edges).ok();
+        assert_eq!(result.as_ref().map(|r| r.1), brute_force(&edges, true));
+        if let Some((walk, _)) = result {
+            check_walk(&edges, &walk, true);
+        }
+    }
+}
+
+#[test]
+fn eulerian_graphs() {
+    let edges = [(1, 2, 3), (2, 3, 4), (3, 1, 5)];
+    assert_eq!(chinese_postman(&edges), Ok((vec![1, 3, 2, 1], 12)));
+    assert_eq!(chinese_postman_directed(&edges), Ok((vec![1, 2, 3, 1], 12)));
+}
+
+#[test]
+fn disconnected_or_empty() {
+    let edges = [(1, 2, 1), (2, 1, 1), (3, 4, 1), (4, 3, 1)];
+    assert_eq!(
+        chinese_postman(&edges),
+        Err(ChinesePostmanError::Disconnected)
+    );
+    assert_eq!(
+        chinese_postman_directed(&edges),
+        Err(ChinesePostmanError::Disconnected)
+    );
+    // Going back from 2 to 1 is impossible.
+    assert_eq!(
+        chinese_postman_directed(&[(1, 2, 1)]),
+        Err(ChinesePostmanError::Disconnected)
+    );
+    assert_eq!(chinese_postman::<u8, i32>(&[]), Ok((vec![], 0)));
+    assert_eq!(chinese_postman_directed::<u8, i32>(&[]), Ok((vec![], 0)));
+}
+
+#[test]
+fn too_many_odd_nodes() {
+    // In a star, every leaf has a single edge and must be paired with another leaf
+    // through the center, so every edge is used twice.
+    let star = |leaves: usize| (1..=leaves).map(|n| (0, n, 1)).collect::<Vec<_>>();
+    let (walk, cost) = chinese_postman(&star(CHINESE_POSTMAN_MAX_ODD_NODES)).unwrap();
+    assert_eq!(cost, 2 * CHINESE_POSTMAN_MAX_ODD_NODES);
+    assert_eq!(walk.len(), 2 * CHINESE_POSTMAN_MAX_ODD_NODES + 1);
+    assert_eq!(
+        chinese_postman(&star(CHINESE_POSTMAN_MAX_ODD_NODES + 2)),
+        Err(ChinesePostmanError::TooManyOddNodes(
+            CHINESE_POSTMAN_MAX_ODD_NODES + 2
+        ))
+    );
+}
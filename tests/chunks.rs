@@ -0,0 +1,153 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 6;
+
+/// Cut `grid` into chunks of `SIZE` × `SIZE` vertices, with portals between all the
+/// facing vertices of adjacent chunks.
+fn chunked(grid: &Grid) -> ChunkedWorld<(usize, usize)> {
+    let mut world = ChunkedWorld::new();
+    for cx in 0..grid.width / SIZE {
+        for cy in 0..grid.height / SIZE {
+            let mut chunk = Grid::new(SIZE, SIZE);
+            for x in 0..SIZE {
+                for y in 0..SIZE {
+                    if grid.has_vertex((cx * SIZE + x, cy * SIZE + y)) {
+                        chunk.add_vertex((x, y));
+                    }
+                }
+            }
+            world.load_chunk((cx, cy), chunk);
+        }
+    }
+    for (a, b) in grid.edges() {
+        let (ka, kb) = (location(a), location(b));
+        if ka.0 != kb.0 {
+            world.add_portal(&ka, &kb, 1);
+        }
+    }
+    world
+}
+
+const fn location((x, y): (usize, usize)) -> ((usize, usize), (usize, usize)) {
+    ((x / SIZE, y / SIZE), (x % SIZE, y % SIZE))
+}
+
+const fn global(((cx, cy), (x, y)): ((usize, usize), (usize, usize))) -> (usize, usize) {
+    (cx * SIZE + x, cy * SIZE + y)
+}
+
+#[test]
+fn same_cost_as_grid() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    let mut grid = Grid::new(4 * SIZE, 3 * SIZE);
+    grid.fill();
+    for _ in 0..100 {
+        grid.remove_vertex((
+            rng.random_range(0..grid.width),
+            rng.random_range(0..grid.height),
+        ));
+    }
+    let world = chunked(&grid);
+    let vertices = grid.iter().step_by(13).collect::<Vec<_>>();
+    for &start in &vertices {
+        for &goal in &vertices {
+            let optimal = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal);
+            let found = world.find_path(&location(start), &location(goal));
+            assert_eq!(optimal.is_some(), found.is_some(), "{start:?} -> {goal:?}");
+            let (Some(optimal), Some((path, cost))) = (optimal, found) else {
+                continue;
+            };
+            assert_eq!(cost, optimal.len() - 1, "{start:?} -> {goal:?}");
+            let path = path.into_iter().map(global).collect::<Vec<_>>();
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            assert_eq!(cost, path.len() - 1);
+            assert!(
+                path.windows(2).all(|w| grid.has_edge(w[0], w[1])),
+                "invalid path {path:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn load_and_unload() {
+    let mut grid = Grid::new(3 * SIZE, SIZE);
+    grid.fill();
+    let mut world = chunked(&grid);
+    let (start, goal) = (location((0, 0)), location((3 * SIZE - 1, 0)));
+    assert_eq!(world.find_path(&start, &goal).map(|(_, c)| c), Some(17));
+    assert!(world.unload_chunk(&(1, 0)).is_some());
+    assert!(!world.is_loaded(&(1, 0)));
+    assert_eq!(world.loaded_chunks().count(), 2);
+    assert_eq!(world.find_path(&start, &goal), None);
+    assert_eq!(world.find_path(&goal, &location((SIZE, 0))), None);
+    // Reloading a chunk with a wall only leaves a detour.
+    let mut chunk = Grid::new(SIZE, SIZE);
+    chunk.fill();
+    for y in 0..SIZE - 1 {
+        chunk.remove_vertex((2, y));
+    }
+    assert_eq!(world.load_chunk((1, 0), chunk.clone()), None);
+    assert_eq!(
+        world.find_path(&start, &goal).map(|(_, c)| c),
+        Some(17 + 2 * (SIZE - 1))
+    );
+    chunk.remove_vertex((2, SIZE - 1));
+    assert!(world.load_chunk((1, 0), chunk).is_some());
+    assert_eq!(world.find_path(&start, &goal), None);
+    assert_eq!(
+        world.chunk(&(1, 0)).map(Grid::vertices_len),
+        Some(SIZE * SIZE - SIZE)
+    );
+}
+
+#[test]
+fn portals() {
+    let mut chunk = Grid::new(10, 1);
+    chunk.fill();
+    let mut world = ChunkedWorld::new();
+    // A portal declared before the chunks it links are loaded.
+    world.add_portal(&("a", (9, 0)), &("b", (0, 0)), 1);
+    world.load_chunk("a", chunk.clone());
+    assert_eq!(world.find_path(&("a", (0, 0)), &("b", (9, 0))), None);
+    world.load_chunk("b", chunk);
+    assert_eq!(
+        world
+            .find_path(&("a", (0, 0)), &("b", (9, 0)))
+            .map(|(_, c)| c),
+        Some(19)
+    );
+    // A teleporter inside a chunk, then a cheaper link between the chunks.
+    world.add_portal(&("a", (0, 0)), &("a", (8, 0)), 2);
+    world.add_portal(&("a", (9, 0)), &("b", (0, 0)), 0);
+    let (path, cost) = world.find_path(&("a", (0, 0)), &("b", (2, 0))).unwrap();
+    assert_eq!(cost, 5);
+    assert_eq!(
+        path,
+        vec![
+            ("a", (0, 0)),
+            ("a", (8, 0)),
+            ("a", (9, 0)),
+            ("b", (0, 0)),
+            ("b", (1, 0)),
+            ("b", (2, 0))
+        ]
+    );
+    assert!(world.remove_portal(&("a", (8, 0)), &("a", (0, 0))));
+    assert!(!world.remove_portal(&("a", (8, 0)), &("a", (0, 0))));
+    assert_eq!(
+        world
+            .find_path(&("a", (0, 0)), &("b", (2, 0)))
+            .map(|(_, c)| c),
+        Some(11)
+    );
+    assert_eq!(
+        world.find_path(&("a", (0, 0)), &("a", (0, 0))),
+        Some((vec![("a", (0, 0))], 0))
+    );
+    assert_eq!(world.find_path(&("a", (0, 1)), &("a", (0, 0))), None);
+    assert_eq!(world.find_path(&("c", (0, 0)), &("a", (0, 0))), None);
+}
@@ -0,0 +1,106 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 8;
+
+fn build_network(seed: u8) -> Matrix<bool> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, false);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            network[(a, b)] = rng.random_ratio(if a == b { 1 } else { 2 }, 7);
+        }
+    }
+    network
+}
+
+// Find the circuits whose smallest node is the first one of `path`.
+fn brute_force(network: &Matrix<bool>, path: &mut Vec<usize>, found: &mut Vec<Vec<usize>>) {
+    let (start, node) = (path[0], *path.last().unwrap());
+    for next in start..SIZE {
+        if network[(node, next)] {
+            if next == start {
+                found.push(path.clone());
+            } else if !path.contains(&next) {
+                path.push(next);
+                brute_force(network, path, found);
+                path.pop();
+            }
+        }
+    }
+}
+
+// Rotate a circuit so that it starts with its smallest node.
+fn normalize(mut circuit: Vec<usize>) -> Vec<usize> {
+    let smallest = (0..circuit.len()).min_by_key(|&i| circuit[i]).unwrap();
+    circuit.rotate_left(smallest);
+    circuit
+}
+
+#[test]
+fn matches_brute_force() {
+    for seed in 1..10 {
+        let network = &build_network(seed);
+        let mut expected = Vec::new();
+        for start in 0..SIZE {
+            brute_force(network, &mut vec![start], &mut expected);
+        }
+        expected.sort();
+        let nodes = (0..SIZE).collect::<Vec<_>>();
+        let mut circuits =
+            elementary_circuits(&nodes, |&a| (0..SIZE).filter(move |&b| network[(a, b)]))
+                .map(normalize)
+                .collect::<Vec<_>>();
+        circuits.sort();
+        assert_eq!(circuits, expected);
+    }
+}
+
+#[test]
+fn complete_graph() {
+    // Number of circuits of at least two nodes in the complete graph on 6 nodes.
+    let expected = (2..=6)
+        .map(|k| (6 - k + 1..=6).product::<usize>() / k)
+        .sum::<usize>();
+    assert_eq!(expected, 409);
+    let circuits = elementary_circuits(&[0], |&a: &usize| (0..6).filter(move |&b| b != a));
+    assert_eq!(circuits.count(), expected);
+}
+
+#[test]
+fn reachable_nodes_and_duplicates() {
+    // Node 5 is only reachable from 0, and the successors of 1 are duplicated.
+    let successors = |&n: &u8| match n {
+        0 => vec![5],
+        5 => vec![1],
+        1 => vec![0, 0, 1],
+        _ => vec![],
+    };
+    let circuits = elementary_circuits(&[1], successors).collect::<Vec<_>>();
+    assert_eq!(circuits, vec![vec![1], vec![1, 0, 5]]);
+}
+
+#[test]
+fn acyclic() {
+    let nodes = (0..20).collect::<Vec<_>>();
+    assert_eq!(
+        elementary_circuits(&nodes, |&n: &u32| (n + 1..20)
+            .filter(move |m| m % (n + 1) == 0))
+        .next(),
+        None
+    );
+}
+
+#[test]
+fn lazy() {
+    // The complete graph on 12 nodes has too many circuits to enumerate them all.
+    let mut circuits = elementary_circuits(&[0], |&a: &usize| (0..12).filter(move |&b| b != a));
+    for circuit in circuits.by_ref().take(1000) {
+        assert!(circuit.len() >= 2);
+        for w in circuit.windows(2) {
+            assert_ne!(w[0], w[1]);
+        }
+    }
+    assert!(circuits.next().is_some());
+}
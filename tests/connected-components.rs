@@ -155,3 +155,70 @@ fn larger_separate_components() {
     out_groups.sort_by_key(|c| c[0]);
     assert_eq!(out_groups, components);
 }
+
+#[test]
+fn basic_components_reach() {
+    let mut counter = 0;
+    // Numbers from 1 to 20 are connected to their double and half, when it exists.
+    let c = components_reach(&[3, 20, 7, 12, 5], |&n: &u32| {
+        counter += 1;
+        let mut neighbours = vec![];
+        if n * 2 <= 20 {
+            neighbours.push(n * 2);
+        }
+        if n % 2 == 0 {
+            neighbours.push(n / 2);
+        }
+        neighbours
+    });
+    let c = c
+        .into_iter()
+        .map(|c| c.into_iter().sorted().collect_vec())
+        .collect_vec();
+    assert_eq!(c, vec![vec![3, 6, 12], vec![5, 10, 20], vec![7, 14]]);
+    assert_eq!(counter, 8);
+}
+
+#[test]
+fn random_components_reach() {
+    // Connect random pairs of vertices, and check that exploring from every vertex gives
+    // the same components as grouping every vertex with its neighbours.
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..20 {
+        let mut neighbours = vec![Vec::new(); 200];
+        for _ in 0..150 {
+            let (a, b) = (rng.random_range(0..200), rng.random_range(0..200));
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+        let mut starts = (0..200).collect_vec();
+        starts.shuffle(&mut rng);
+        let reached = components_reach(&starts, |&n| neighbours[n].clone());
+        let mut expected = connected_components(&starts, |&n| neighbours[n].clone());
+        assert_eq!(reached.len(), expected.len());
+        for (i, component) in reached.iter().enumerate() {
+            // Components are ordered by their first start vertex.
+            let first = starts.iter().position(|n| component.contains(n)).unwrap();
+            assert!(
+                reached[..i]
+                    .iter()
+                    .all(|c| starts.iter().position(|n| c.contains(n)).unwrap() < first)
+            );
+            let j = expected.iter().position(|c| c == component).unwrap();
+            expected.swap_remove(j);
+        }
+        // Only the components of the start vertices are explored.
+        let mut counter = 0;
+        let partial = components_reach(&starts[..3], |&n| {
+            counter += 1;
+            neighbours[n].clone()
+        });
+        assert!(partial.iter().all(|c| reached.contains(c)));
+        assert!(
+            starts[..3]
+                .iter()
+                .all(|n| partial.iter().any(|c| c.contains(n)))
+        );
+        assert_eq!(counter, partial.iter().map(HashSet::len).sum::<usize>());
+    }
+}
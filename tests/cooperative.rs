@@ -0,0 +1,115 @@
+use pathfinding::prelude::*;
+
+fn assert_no_collision(paths: &[Vec<(usize, usize)>]) {
+    let len = paths.iter().map(Vec::len).max().unwrap_or(0);
+    // Agents stay on their last vertex once their path is over.
+    let at = |path: &[(usize, usize)], t: usize| path[t.min(path.len() - 1)];
+    for t in 0..len {
+        for (i, a) in paths.iter().enumerate() {
+            for b in &paths[i + 1..] {
+                assert_ne!(at(a, t), at(b, t), "vertex collision at time {t}");
+                if t > 0 {
+                    assert!(
+                        at(a, t - 1) != at(b, t) || at(b, t - 1) != at(a, t),
+                        "swap at time {t}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn check_moves(grid: &Grid, path: &[(usize, usize)]) {
+    for w in path.windows(2) {
+        assert!(w[0] == w[1] || grid.has_edge(w[0], w[1]), "{w:?}");
+    }
+}
+
+#[test]
+fn single_agent_is_optimal() {
+    let mut grid = Grid::new(10, 10);
+    grid.fill();
+    for y in 1..10 {
+        grid.remove_vertex((5, y));
+    }
+    let table = ReservationTable::new();
+    let path = cooperative_astar(&grid, &table, 0, (0, 9), (9, 9), 0, 40).unwrap();
+    check_moves(&grid, &path);
+    assert_eq!(
+        path.len() - 1,
+        bfs(&(0, 9), |&n| grid.neighbours(n), |&n| n == (9, 9))
+            .unwrap()
+            .len()
+            - 1
+    );
+}
+
+#[test]
+fn crossing_agents() {
+    let mut grid = Grid::new(8, 8);
+    grid.fill();
+    let agents = [
+        ((0, 0), (7, 7)),
+        ((7, 7), (0, 0)),
+        ((0, 7), (7, 0)),
+        ((7, 0), (0, 7)),
+        ((3, 0), (3, 7)),
+        ((4, 7), (4, 0)),
+    ];
+    let mut table = ReservationTable::new();
+    let mut paths = Vec::new();
+    for (agent, &(start, goal)) in agents.iter().enumerate() {
+        let path = cooperative_astar(&grid, &table, agent, start, goal, 0, 32).unwrap();
+        check_moves(&grid, &path);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        table.reserve_path(agent, &path, 0);
+        // Once arrived, the agent stays on its goal.
+        for t in path.len()..64 {
+            table.reserve(agent, goal, t);
+        }
+        paths.push(path);
+    }
+    assert_no_collision(&paths);
+}
+
+#[test]
+fn window_limits_path() {
+    let mut grid = Grid::new(20, 1);
+    grid.fill();
+    let table = ReservationTable::new();
+    let path = cooperative_astar(&grid, &table, 0, (0, 0), (19, 0), 5, 4).unwrap();
+    assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]);
+}
+
+#[test]
+fn blocked_agent() {
+    let mut grid = Grid::new(3, 1);
+    grid.fill();
+    let mut table = ReservationTable::new();
+    // Another agent occupies the whole corridor at time 1.
+    for x in 0..3 {
+        assert!(table.reserve(0, (x, 0), 1));
+    }
+    assert!(!table.reserve(1, (1, 0), 1));
+    assert_eq!(table.owner((1, 0), 1), Some(0));
+    assert_eq!(
+        cooperative_astar(&grid, &table, 1, (0, 0), (2, 0), 0, 5),
+        None
+    );
+    // The agent owning the reservations is not blocked by them.
+    assert!(cooperative_astar(&grid, &table, 0, (0, 0), (2, 0), 0, 5).is_some());
+    table.release(0);
+    assert_eq!(table.owner((1, 0), 1), None);
+    assert!(cooperative_astar(&grid, &table, 1, (0, 0), (2, 0), 0, 5).is_some());
+}
+
+#[test]
+fn outside_grid() {
+    let grid = Grid::new(3, 3);
+    let table = ReservationTable::new();
+    assert_eq!(
+        cooperative_astar(&grid, &table, 0, (0, 0), (1, 1), 0, 5),
+        None
+    );
+}
@@ -0,0 +1,105 @@
+use pathfinding::prelude::*;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(n: &u32) -> Vec<(u32, u32)> {
+    if *n < 50 {
+        vec![(n + 1, 1), (n * 2, 3), (n + 7, 5)]
+    } else {
+        vec![]
+    }
+}
+
+#[test]
+fn same_as_plain_when_ignoring_cost() {
+    for goal in 1..50 {
+        assert_eq!(
+            dijkstra_cost_aware(&1, |n, _| successors(n), |&n| n == goal),
+            dijkstra(&1, successors, |&n| n == goal)
+        );
+        assert_eq!(
+            astar_cost_aware(&1, |n, _| successors(n), |_| 0, |&n| n == goal),
+            astar(&1, successors, |_| 0, |&n| n == goal)
+        );
+    }
+}
+
+#[test]
+fn waiting_at_node() {
+    // Node 2 can only be left at time 10 or later.
+    let succ = |&n: &u8, time: u32| {
+        let wait = if n == 2 {
+            10u32.saturating_sub(time)
+        } else {
+            0
+        };
+        if n < 4 {
+            vec![(n + 1, wait + 1)]
+        } else {
+            vec![]
+        }
+    };
+    assert_eq!(
+        dijkstra_cost_aware(&0, succ, |&n| n == 4),
+        Some((vec![0, 1, 2, 3, 4], 12))
+    );
+    assert_eq!(
+        astar_cost_aware(&0, succ, |&n| u32::from(4 - n), |&n| n == 4),
+        Some((vec![0, 1, 2, 3, 4], 12))
+    );
+    assert_eq!(dijkstra_cost_aware(&0, succ, |&n| n == 9), None);
+}
+
+// Every edge is served by a scheduled service leaving every `period` time units,
+// shifted by `offset`, and taking `duration` time units.
+const SERVICES: &[(u8, u8, usize, usize, usize)] = &[
+    (0, 1, 4, 1, 3),
+    (0, 2, 7, 0, 1),
+    (1, 3, 5, 2, 2),
+    (2, 3, 3, 1, 6),
+    (2, 4, 6, 4, 2),
+    (3, 5, 2, 0, 3),
+    (4, 5, 9, 3, 1),
+    (1, 4, 8, 5, 2),
+];
+
+const fn wait_for_service(time: usize, period: usize, offset: usize) -> usize {
+    (offset + period - time % period) % period
+}
+
+#[test]
+fn time_dependent_matches_time_expanded() {
+    for departure in 0..30 {
+        for goal in 1..6 {
+            let result = dijkstra_time_dependent(
+                &0,
+                departure,
+                |&n, time| {
+                    SERVICES.iter().filter(move |s| s.0 == n).map(
+                        move |&(_, to, period, offset, duration)| {
+                            (to, wait_for_service(time, period, offset) + duration)
+                        },
+                    )
+                },
+                |&n| n == goal,
+            );
+            // Same problem, where waiting is explicit and services only leave at
+            // their scheduled times.
+            let expanded = time_expanded_successors(
+                |&n, time| {
+                    SERVICES
+                        .iter()
+                        .filter(move |s| s.0 == n && time % s.2 == s.3)
+                        .map(|&(_, to, _, _, duration)| (to, duration, duration))
+                },
+                Some(1),
+                departure + 100,
+            );
+            let expected = dijkstra(&(0, departure), expanded, |&(n, _)| n == goal);
+            assert_eq!(
+                result.map(|(_, arrival)| arrival),
+                expected.map(|(path, _)| path.last().unwrap().1),
+                "leaving at {departure} for {goal}"
+            );
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use pathfinding::directed::count_paths::count_paths;
+use pathfinding::directed::count_paths::{
+    count_paths, count_paths_generic, count_paths_with_length,
+};
 
 #[test]
 fn grid() {
@@ -13,3 +15,113 @@ fn grid() {
     );
     assert_eq!(n, 3432);
 }
+
+fn grid_successors(size: u32) -> impl Fn(&(u32, u32)) -> Vec<(u32, u32)> {
+    move |&(x, y)| {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| x < size && y < size)
+            .collect()
+    }
+}
+
+#[test]
+fn generic_matches_usize() {
+    let n: u64 = count_paths_generic((0, 0), grid_successors(8), |&c| c == (7, 7), None);
+    assert_eq!(n, 3432);
+}
+
+#[test]
+fn large_grid() {
+    // Central binomial coefficient C(120, 60), which does not fit in a u64.
+    let n: u128 = count_paths_generic((0, 0), grid_successors(61), |&c| c == (60, 60), None);
+    assert_eq!(n, 96_614_908_840_363_322_603_893_139_521_372_656);
+}
+
+#[test]
+fn modular() {
+    let exact: u128 = count_paths_generic((0, 0), grid_successors(61), |&c| c == (60, 60), None);
+    for modulus in [1u128, 2, 97, 1_000_000_007, 998_244_353] {
+        let n = count_paths_generic(
+            (0, 0),
+            grid_successors(61),
+            |&c| c == (60, 60),
+            Some(modulus),
+        );
+        assert_eq!(n, exact % modulus);
+    }
+    // Intermediate values never overflow a u64 thanks to the reduction.
+    let n: u64 = count_paths_generic(
+        (0, 0),
+        grid_successors(61),
+        |&c| c == (60, 60),
+        Some(1_000_000_007),
+    );
+    assert_eq!(u128::from(n), exact % 1_000_000_007);
+}
+
+#[test]
+fn lengths_on_acyclic_grid() {
+    // Every path on the grid has 14 edges.
+    let counts: Vec<usize> =
+        count_paths_with_length((0, 0), grid_successors(8), |&c| c == (7, 7), 20, None);
+    let mut expected = vec![0; 21];
+    expected[14] = 3432;
+    assert_eq!(counts, expected);
+    let counts: Vec<usize> =
+        count_paths_with_length((0, 0), grid_successors(8), |&c| c == (7, 7), 13, None);
+    assert_eq!(counts, vec![0; 14]);
+}
+
+#[test]
+fn lengths_sum_to_count_paths() {
+    // Edges go from n to every multiple of n up to 60, plus n + 1.
+    let successors = |&n: &u32| {
+        (2..=60 / n)
+            .map(move |k| k * n)
+            .chain((n < 60).then_some(n + 1))
+            .collect::<Vec<_>>()
+    };
+    let counts: Vec<usize> = count_paths_with_length(1, successors, |&n| n == 60, 60, None);
+    assert_eq!(
+        counts.iter().sum::<usize>(),
+        count_paths(1, successors, |&n| n == 60)
+    );
+    assert_eq!(counts[0], 0);
+    // The only path of length 59 goes through every node, using one of the two edges from 1 to 2.
+    assert_eq!(counts[59], 2);
+}
+
+#[test]
+fn lengths_with_loops() {
+    // Node 0 loops on itself and goes to 1, which is the destination.
+    let counts: Vec<usize> = count_paths_with_length(
+        0,
+        |&n: &u8| if n == 0 { vec![0, 1] } else { vec![] },
+        |&n| n == 1,
+        5,
+        None,
+    );
+    assert_eq!(counts, vec![0, 1, 1, 1, 1, 1]);
+    // Paths stop at the destination, even if it has successors.
+    let counts: Vec<usize> =
+        count_paths_with_length(0, |&n: &u8| vec![n ^ 1], |&n| n == 1, 5, None);
+    assert_eq!(counts, vec![0, 1, 0, 0, 0, 0]);
+    // The start may be the destination.
+    assert_eq!(
+        count_paths_with_length(0, |&n: &u8| vec![n], |&n| n == 0, 2, None::<usize>),
+        vec![1, 0, 0]
+    );
+}
+
+#[test]
+fn lengths_beyond_usize() {
+    // On a cycle of 4 nodes, there are 2^k paths of length 2k from 0 to 2.
+    let successors = |&n: &u8| [(n + 1) % 4, (n + 3) % 4];
+    let exact: Vec<u128> = count_paths_with_length(0, successors, |&n| n == 2, 200, None);
+    assert_eq!(exact[200], 1 << 100);
+    let counts = count_paths_with_length(0, successors, |&n| n == 2, 200, Some(1_000_000_007u64));
+    for (n, e) in counts.into_iter().zip(exact) {
+        assert_eq!(u128::from(n), e % 1_000_000_007);
+    }
+}
@@ -9,3 +9,75 @@ fn floyd_works() {
 fn brent_works() {
     assert_eq!(brent(-10, |x| (x + 5) % 6 + 3), (3, 6, 2));
 }
+
+// Check that `cycle` is an elementary cycle of the graph.
+fn check_cycle<F: Fn(&u32) -> Vec<u32>>(cycle: &[u32], successors: F) {
+    let mut nodes = cycle.to_vec();
+    nodes.sort_unstable();
+    nodes.dedup();
+    assert_eq!(nodes.len(), cycle.len());
+    for i in 0..cycle.len() {
+        assert!(successors(&cycle[i]).contains(&cycle[(i + 1) % cycle.len()]));
+    }
+}
+
+#[test]
+fn find_cycle_in_dag() {
+    let successors = |&n: &u32| (n + 1..30).filter(|m| m % (n + 1) == 0).collect::<Vec<_>>();
+    let nodes = (0..30).collect::<Vec<_>>();
+    assert_eq!(find_cycle(&nodes, successors), None);
+}
+
+#[test]
+fn find_cycle_from_several_starts() {
+    // 0 → 1 → 2 is acyclic, while 3 → 4 → 5 → 3 is a cycle.
+    let successors = |&n: &u32| match n {
+        0 => vec![1, 4],
+        1 => vec![2],
+        3 => vec![4],
+        4 => vec![5],
+        5 => vec![3],
+        _ => vec![],
+    };
+    assert_eq!(find_cycle(&[1], successors), None);
+    assert_eq!(find_cycle(&[1, 2, 3], successors), Some(vec![3, 4, 5]));
+    let cycle = find_cycle(&[0], successors).unwrap();
+    assert_eq!(cycle, vec![4, 5, 3]);
+    check_cycle(&cycle, successors);
+}
+
+#[test]
+fn find_cycle_self_loop() {
+    assert_eq!(
+        find_cycle(&[0], |&n: &u32| vec![n + 1, n]
+            .into_iter()
+            .filter(|&m| m < 5)),
+        Some(vec![4])
+    );
+}
+
+#[test]
+fn find_cycle_deep() {
+    // A very long chain looping back to its middle would overflow the stack if the
+    // search was recursive.
+    let successors = |&n: &u32| vec![if n == 999_999 { 500_000 } else { n + 1 }];
+    let cycle = find_cycle(&[0], successors).unwrap();
+    assert_eq!(cycle.len(), 500_000);
+    assert_eq!(cycle[0], 500_000);
+    check_cycle(&cycle, successors);
+}
+
+#[test]
+fn find_cycle_random() {
+    use pathfinding::prelude::elementary_circuits;
+    for seed in 0..50u32 {
+        // Edges go from n to m if a simple hash of (n, m, seed) is small.
+        let edge = move |n: u32, m: u32| (n * 31 + m * 17 + seed * 7) % 23 == 0;
+        let successors = move |&n: &u32| (0..10).filter(|&m| edge(n, m)).collect::<Vec<_>>();
+        let nodes = (0..10).collect::<Vec<_>>();
+        match find_cycle(&nodes, successors) {
+            Some(cycle) => check_cycle(&cycle, successors),
+            None => assert_eq!(elementary_circuits(&nodes, successors).next(), None),
+        }
+    }
+}
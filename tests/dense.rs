@@ -0,0 +1,117 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+// A random graph stored in compressed sparse row form.
+fn random_csr(rng: &mut XorShiftRng, nodes: usize) -> (Vec<usize>, Vec<(usize, u32)>) {
+    let mut offsets = vec![0];
+    let mut edges = Vec::new();
+    for _ in 0..nodes {
+        for _ in 0..rng.random_range(0..4) {
+            edges.push((rng.random_range(0..nodes), rng.random_range(0..20)));
+        }
+        offsets.push(edges.len());
+    }
+    (offsets, edges)
+}
+
+#[test]
+fn same_costs_as_generic() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    for _ in 0..50 {
+        let (offsets, edges) = random_csr(&mut rng, 100);
+        let successors = |&n: &usize| edges[offsets[n]..offsets[n + 1]].iter().copied();
+        let goal = rng.random_range(0..100);
+        let expected = dijkstra(&0, successors, |&n| n == goal);
+        let result = dijkstra_dense(&0, 100, successors, |&n| n == goal);
+        assert_eq!(
+            result.as_ref().map(|(_, c)| *c),
+            expected.as_ref().map(|(_, c)| *c)
+        );
+        if let Some((path, cost)) = result {
+            assert_eq!(path.first(), Some(&0));
+            assert_eq!(path.last(), Some(&goal));
+            let total = path
+                .windows(2)
+                .map(|w| {
+                    successors(&w[0])
+                        .filter(|&(m, _)| m == w[1])
+                        .map(|(_, c)| c)
+                        .min()
+                        .unwrap()
+                })
+                .sum::<u32>();
+            assert_eq!(total, cost);
+        }
+        let astar_result = astar_dense(&0, 100, successors, |_| 0, |&n| n == goal);
+        assert_eq!(
+            astar_result.map(|(_, c)| c),
+            expected.as_ref().map(|(_, c)| *c)
+        );
+        let unweighted = |&n: &usize| edges[offsets[n]..offsets[n + 1]].iter().map(|&(m, _)| m);
+        assert_eq!(
+            bfs_dense(&0, 100, unweighted, |&n| n == goal).map(|p| p.len()),
+            bfs(&0, unweighted, |&n| n == goal).map(|p| p.len())
+        );
+    }
+}
+
+#[test]
+fn all_reachable() {
+    let mut rng = XorShiftRng::from_seed([6; 16]);
+    for _ in 0..20 {
+        let (offsets, edges) = random_csr(&mut rng, 60);
+        let successors = |&n: &usize| edges[offsets[n]..offsets[n + 1]].iter().copied();
+        let expected = dijkstra_all(&0, successors);
+        let reachables = dijkstra_all_dense(&0, 60, successors);
+        assert_eq!(reachables.len(), 60);
+        assert_eq!(reachables[0], None);
+        for (node, entry) in reachables.iter().enumerate() {
+            assert_eq!(entry.map(|(_, c)| c), expected.get(&node).map(|&(_, c)| c));
+            if entry.is_some() {
+                let path = build_path_dense(&node, &reachables);
+                assert_eq!(path[0], 0);
+                assert_eq!(path.last(), Some(&node));
+            }
+        }
+    }
+}
+
+#[test]
+fn astar_grid() {
+    let successors = |&n: &usize| {
+        let (x, y) = (n % 8, n / 8);
+        let mut next = vec![];
+        if x > 0 {
+            next.push((n - 1, 1));
+        }
+        if x < 7 {
+            next.push((n + 1, 1));
+        }
+        if y > 0 && x != 4 {
+            next.push((n - 8, 1));
+        }
+        if y < 7 && x != 4 {
+            next.push((n + 8, 1));
+        }
+        next
+    };
+    let heuristic = |&n: &usize| (n % 8).abs_diff(7) + (n / 8).abs_diff(7);
+    let (path, cost) = astar_dense(&0, 64, successors, heuristic, |&n| n == 63).unwrap();
+    assert_eq!(cost, 14);
+    assert_eq!(path.len(), 15);
+    assert_eq!(
+        astar_dense(&0, 64, |_| vec![], heuristic, |&n| n == 63),
+        None
+    );
+    assert_eq!(
+        dijkstra_dense(&0, 64, successors, |&n| n == 0),
+        Some((vec![0], 0))
+    );
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn out_of_range() {
+    let _ = bfs_dense(&0, 3, |&n| [n + 1], |&n| n == 5);
+}
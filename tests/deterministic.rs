@@ -0,0 +1,182 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+/// A random graph with small weights, so that many paths have the same cost.
+fn random_graph(seed: u8, size: usize) -> Matrix<Option<u32>> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut graph = Matrix::new(size, size, None);
+    for from in 0..size {
+        for to in 0..size {
+            if from != to && rng.random_ratio(1, 4) {
+                graph[(from, to)] = Some(rng.random_range(1..3));
+            }
+        }
+    }
+    graph
+}
+
+fn successors(graph: &Matrix<Option<u32>>, node: usize) -> Vec<(usize, u32)> {
+    (0..graph.columns)
+        .filter_map(|to| graph[(node, to)].map(|c| (to, c)))
+        .collect()
+}
+
+#[test]
+fn astar_bag_order_is_stable() {
+    for seed in 1..20 {
+        let graph = random_graph(seed, 12);
+        let run = || {
+            astar_bag_collect(&0, |&n| successors(&graph, n), |_| 0, |&n| n == 11)
+                .map(|(paths, _)| paths)
+        };
+        let first = run();
+        for _ in 0..5 {
+            assert_eq!(run(), first);
+        }
+    }
+}
+
+#[test]
+fn cliques_order_is_stable() {
+    for seed in 1..20 {
+        let graph = random_graph(seed, 10);
+        let run = || {
+            maximal_cliques_collect(0..10, &mut |&a: &usize, &b: &usize| {
+                graph[(a.min(b), a.max(b))].is_some()
+            })
+        };
+        let first = run();
+        for _ in 0..5 {
+            assert_eq!(run(), first);
+        }
+    }
+}
+
+#[test]
+fn components_order_is_stable() {
+    for seed in 1..20 {
+        let graph = random_graph(seed, 30);
+        let starts = (0..30).collect::<Vec<_>>();
+        let run = || {
+            connected_components(&starts, |&n| {
+                successors(&graph, n)
+                    .into_iter()
+                    .filter(|&(_, c)| c == 1)
+                    .map(|(n, _)| n)
+            })
+        };
+        let first = run();
+        for _ in 0..5 {
+            assert_eq!(run(), first);
+        }
+    }
+}
+
+/// A straightforward Dijkstra search which expands, among the nodes with the smallest
+/// cost, the one discovered first.
+#[cfg(feature = "deterministic")]
+fn reference_dijkstra(graph: &Matrix<Option<u32>>, goal: usize) -> Option<(Vec<usize>, u32)> {
+    // Discovered nodes, in discovery order, with their cost, parent and expansion status.
+    let mut discovered = vec![(0, 0, usize::MAX, false)];
+    loop {
+        let (i, &(node, cost, _, _)) = discovered
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| !d.3)
+            .min_by_key(|(i, d)| (d.1, *i))?;
+        if node == goal {
+            let mut path = vec![node];
+            let mut parent = discovered[i].2;
+            while parent != usize::MAX {
+                path.push(discovered[parent].0);
+                parent = discovered[parent].2;
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        discovered[i].3 = true;
+        for (next, c) in successors(graph, node) {
+            match discovered.iter().position(|d| d.0 == next) {
+                None => discovered.push((next, cost + c, i, false)),
+                Some(j) if cost + c < discovered[j].1 => {
+                    discovered[j].1 = cost + c;
+                    discovered[j].2 = i;
+                }
+                Some(_) => (),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn ties_broken_in_discovery_order() {
+    for seed in 1..100 {
+        let graph = random_graph(seed, 15);
+        let expected = reference_dijkstra(&graph, 14);
+        assert_eq!(
+            dijkstra(&0, |&n| successors(&graph, n), |&n| n == 14),
+            expected
+        );
+        assert_eq!(
+            astar(&0, |&n| successors(&graph, n), |_| 0, |&n| n == 14),
+            expected
+        );
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn simple_ties() {
+    let successors = |&n: &u8| match n {
+        0 => vec![(1, 1), (2, 1)],
+        1 | 2 => vec![(3, 1)],
+        _ => vec![],
+    };
+    let reversed = |n: &u8| {
+        let mut s = successors(n);
+        s.reverse();
+        s
+    };
+    assert_eq!(
+        dijkstra(&0, successors, |&n| n == 3),
+        Some((vec![0, 1, 3], 2))
+    );
+    assert_eq!(
+        dijkstra(&0, reversed, |&n| n == 3),
+        Some((vec![0, 2, 3], 2))
+    );
+    assert_eq!(
+        yen(&0, successors, |&n| n == 3, 2),
+        vec![(vec![0, 1, 3], 2), (vec![0, 2, 3], 2)]
+    );
+    assert_eq!(
+        yen(&0, reversed, |&n| n == 3, 2),
+        vec![(vec![0, 2, 3], 2), (vec![0, 1, 3], 2)]
+    );
+    assert_eq!(
+        idastar(&0, successors, |_| 0, |&n| n == 3),
+        Some((vec![0, 1, 3], 2))
+    );
+    assert_eq!(
+        idastar(&0, reversed, |_| 0, |&n| n == 3),
+        Some((vec![0, 2, 3], 2))
+    );
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn kruskal_ties_in_input_order() {
+    // A square whose edges all have the same weight: the first three edges are kept.
+    let edges = [(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1)];
+    assert_eq!(
+        kruskal(&edges).collect::<Vec<_>>(),
+        vec![(&0, &1, 1), (&1, &2, 1), (&2, &3, 1)]
+    );
+    let edges = [(3, 0, 1), (2, 3, 1), (1, 2, 1), (0, 1, 1)];
+    assert_eq!(
+        kruskal(&edges).collect::<Vec<_>>(),
+        vec![(&3, &0, 1), (&2, &3, 1), (&1, &2, 1)]
+    );
+}
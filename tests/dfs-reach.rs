@@ -1,4 +1,7 @@
-use pathfinding::directed::dfs::dfs_reach;
+use pathfinding::directed::dfs::{
+    DfsEdgeKind, DfsEvent, depth_first_visit, dfs_edges, dfs_reach, dfs_reach_tree,
+};
+use std::ops::ControlFlow;
 
 #[test]
 fn issue_511() {
@@ -14,3 +17,178 @@ fn issue_511_branches() {
     let it = dfs_reach(0, |&n| [n + 2, n + 5].into_iter().filter(|&x| x <= 10));
     assert_eq!(vec![0, 2, 4, 6, 8, 10, 9, 7, 5], it.collect::<Vec<_>>());
 }
+
+#[test]
+fn tree_matches_reach_order() {
+    let succ = |&n: &u32| [n + 2, n + 5].into_iter().filter(|&x| x <= 10);
+    let items = dfs_reach_tree(0, succ).collect::<Vec<_>>();
+    assert_eq!(
+        items.iter().map(|item| item.node).collect::<Vec<_>>(),
+        dfs_reach(0, succ).collect::<Vec<_>>()
+    );
+    assert_eq!(items[0].parent, None);
+    for item in &items[1..] {
+        let parent = items
+            .iter()
+            .find(|p| Some(p.node) == item.parent)
+            .expect("parent must have been visited before");
+        assert_eq!(parent.depth + 1, item.depth);
+        assert!(succ(&parent.node).any(|n| n == item.node));
+    }
+    // 9 is reached through 0 -> 2 -> 4 -> 9
+    assert_eq!(
+        items
+            .iter()
+            .find(|item| item.node == 9)
+            .map(|item| (item.parent, item.depth)),
+        Some((Some(4), 3))
+    );
+}
+
+#[test]
+fn visit_discovers_in_reach_order() {
+    let succ = |&n: &u32| [n + 2, n + 5].into_iter().filter(|&x| x <= 10);
+    let mut discovered = vec![];
+    let result = depth_first_visit::<_, _, _, _, ()>(&[0], succ, |event| {
+        if let DfsEvent::Discover(n) = event {
+            discovered.push(n);
+        }
+        ControlFlow::Continue(())
+    });
+    assert_eq!(result, ControlFlow::Continue(()));
+    assert_eq!(discovered, dfs_reach(0, succ).collect::<Vec<_>>());
+}
+
+#[test]
+fn visit_events() {
+    // 1 -> 2 -> 3 -> 1 is a cycle, 1 -> 3 is a forward edge, 4 -> 2 is a cross edge
+    // and 5 is alone.
+    let successors = |&n: &u8| match n {
+        1 => vec![2, 3],
+        2 => vec![3],
+        3 => vec![1, 3],
+        4 => vec![2],
+        _ => vec![],
+    };
+    let mut events = vec![];
+    let result = depth_first_visit::<_, _, _, _, ()>(&[1, 2, 4, 5], successors, |event| {
+        events.push(event);
+        ControlFlow::Continue(())
+    });
+    assert_eq!(result, ControlFlow::Continue(()));
+    assert_eq!(
+        events,
+        vec![
+            DfsEvent::Discover(1),
+            DfsEvent::TreeEdge(1, 2),
+            DfsEvent::Discover(2),
+            DfsEvent::TreeEdge(2, 3),
+            DfsEvent::Discover(3),
+            DfsEvent::BackEdge(3, 1),
+            DfsEvent::BackEdge(3, 3),
+            DfsEvent::Finish(3),
+            DfsEvent::Finish(2),
+            DfsEvent::CrossForwardEdge(1, 3),
+            DfsEvent::Finish(1),
+            DfsEvent::Discover(4),
+            DfsEvent::CrossForwardEdge(4, 2),
+            DfsEvent::Finish(4),
+            DfsEvent::Discover(5),
+            DfsEvent::Finish(5),
+        ]
+    );
+}
+
+#[test]
+fn visit_break() {
+    let mut finished = 0;
+    let result = depth_first_visit(
+        &[0],
+        |&n: &u32| [n + 1].into_iter().filter(|&x| x < 100),
+        |event| match event {
+            DfsEvent::Discover(50) => ControlFlow::Break(50),
+            DfsEvent::Finish(_) => {
+                finished += 1;
+                ControlFlow::Continue(())
+            }
+            _ => ControlFlow::Continue(()),
+        },
+    );
+    assert_eq!(result, ControlFlow::Break(50));
+    assert_eq!(finished, 0);
+}
+
+#[test]
+fn edges_match_visit_events() {
+    let successors = |&n: &u8| match n {
+        1 => vec![2, 3],
+        2 => vec![3],
+        3 => vec![1, 3],
+        4 => vec![2],
+        _ => vec![],
+    };
+    assert_eq!(
+        dfs_edges(&[1, 2, 4, 5], successors).collect::<Vec<_>>(),
+        vec![
+            (1, 2, DfsEdgeKind::Tree),
+            (2, 3, DfsEdgeKind::Tree),
+            (3, 1, DfsEdgeKind::Back),
+            (3, 3, DfsEdgeKind::Back),
+            (1, 3, DfsEdgeKind::Forward),
+            (4, 2, DfsEdgeKind::Cross),
+        ]
+    );
+}
+
+#[test]
+fn edges_random_graphs() {
+    use pathfinding::prelude::topological_sort;
+    use rand::{Rng as _, SeedableRng as _};
+    use rand_xorshift::XorShiftRng;
+
+    const SIZE: usize = 30;
+    for seed in 1..30 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let graph = (0..SIZE)
+            .map(|_| {
+                (0..SIZE)
+                    .filter(|_| rng.random_ratio(1, 12))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let successors = |&n: &usize| graph[n].clone();
+        let nodes = (0..SIZE).collect::<Vec<_>>();
+        let edges = dfs_edges(&nodes, successors).collect::<Vec<_>>();
+        assert_eq!(edges.len(), graph.iter().map(Vec::len).sum::<usize>());
+        // Every node but the roots of the search forest is the target of exactly one
+        // tree edge.
+        let mut parents = vec![None; SIZE];
+        for &(from, to, _) in edges.iter().filter(|e| e.2 == DfsEdgeKind::Tree) {
+            assert_eq!(parents[to], None);
+            parents[to] = Some(from);
+        }
+        let is_ancestor = |a: usize, mut b: usize| {
+            while let Some(p) = parents[b] {
+                if p == a {
+                    return true;
+                }
+                b = p;
+            }
+            false
+        };
+        for &(from, to, kind) in &edges {
+            match kind {
+                DfsEdgeKind::Tree => (),
+                DfsEdgeKind::Back => assert!(from == to || is_ancestor(to, from)),
+                DfsEdgeKind::Forward => assert!(is_ancestor(from, to)),
+                DfsEdgeKind::Cross => {
+                    assert!(!is_ancestor(from, to) && !is_ancestor(to, from));
+                }
+            }
+        }
+        assert_eq!(
+            edges.iter().any(|e| e.2 == DfsEdgeKind::Back),
+            topological_sort(&nodes, successors).is_err()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 60;
+
+fn build_network() -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([11; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in a + 1..SIZE {
+            if rng.random_ratio(1, 10) {
+                let cost = rng.random_range(1..100);
+                network[(a, b)] = cost;
+                network[(b, a)] = cost;
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl Fn(&usize) -> Vec<(usize, u32)> + '_ {
+    move |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+#[test]
+fn admissible_and_optimal() {
+    let network = build_network();
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    let dh = DifferentialHeuristic::new(&nodes, 4, successors(&network));
+    assert_eq!(dh.pivots().len(), 4);
+    for start in 0..SIZE {
+        let distances = dijkstra_all(&start, successors(&network));
+        for goal in 0..SIZE {
+            if let Some(&(_, cost)) = distances.get(&goal) {
+                assert!(dh.estimate(&start, &goal) <= cost, "{start} -> {goal}");
+            }
+        }
+    }
+    for goal in 0..SIZE {
+        assert_eq!(
+            astar(&0, successors(&network), dh.heuristic(&goal), |&n| n
+                == goal)
+            .map(|(_, c)| c),
+            dijkstra(&0, successors(&network), |&n| n == goal).map(|(_, c)| c),
+            "0 -> {goal}"
+        );
+    }
+}
+
+#[test]
+fn exact_on_line() {
+    let successors = |&n: &i32| {
+        [(n - 1, 2), (n + 1, 2)]
+            .into_iter()
+            .filter(|(m, _)| (0..=10).contains(m))
+    };
+    let dh = DifferentialHeuristic::with_pivots(&[0], successors);
+    assert_eq!(dh.estimate(&7, &2), 10);
+    assert_eq!(dh.estimate(&2, &7), 10);
+    let nodes = (0..=10).collect::<Vec<_>>();
+    let dh = DifferentialHeuristic::new(&nodes, 5, successors);
+    assert_eq!(dh.pivots().len(), 5);
+    assert_eq!(dh.pivots()[..3], [10, 0, 5]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let network = build_network();
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    let dh = DifferentialHeuristic::new(&nodes, 3, successors(&network));
+    let saved = serde_json::to_string(&dh).unwrap();
+    let restored: DifferentialHeuristic<usize, u32> = serde_json::from_str(&saved).unwrap();
+    assert_eq!(restored.pivots(), dh.pivots());
+    for a in 0..SIZE {
+        assert_eq!(restored.estimate(&a, &7), dh.estimate(&a, &7));
+    }
+}
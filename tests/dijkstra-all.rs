@@ -103,3 +103,29 @@ fn partial_paths() {
         }
     }
 }
+
+#[test]
+fn paths_within() {
+    const SIZE: usize = 30;
+    let network = build_network(SIZE);
+    for start in 0..SIZE {
+        let all = dijkstra_all(&start, neighbours(network.clone()));
+        let max_cost = 30_000;
+        let within = dijkstra_all_within(&start, neighbours(network.clone()), max_cost);
+        for (target, &(_, cost)) in &all {
+            if cost <= max_cost {
+                assert_eq!(
+                    within.get(target).map(|&(_, c)| c),
+                    Some(cost),
+                    "cost {start} -> {target} differ in {network:?}"
+                );
+            } else {
+                assert!(
+                    !within.contains_key(target),
+                    "path {start} -> {target} is present in {network:?}"
+                );
+            }
+        }
+        assert!(within.len() <= all.len());
+    }
+}
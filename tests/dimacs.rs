@@ -0,0 +1,157 @@
+use pathfinding::prelude::*;
+
+const GRAPH: &str = "c 9th DIMACS Implementation Challenge: Shortest Paths
+c
+p sp 5 7
+c arcs
+a 1 2 10
+a 2 3 5
+a 1 3 20
+a 3 4 1
+a 4 5 2
+a 5 1 3
+
+a 2 5 30
+";
+
+const COORDINATES: &str = "c Coordinates
+p aux sp co 5
+v 1 0 0
+v 2 10 0
+v 3 10 10
+v 5 0 10
+";
+
+#[test]
+fn read_graph() {
+    let graph = read_gr::<_, u64>(GRAPH.as_bytes()).unwrap();
+    assert_eq!(graph.nodes, 5);
+    assert_eq!(graph.arcs.len(), 7);
+    assert_eq!(graph.arcs[0], (1, 2, 10));
+    let successors = graph.successors();
+    assert_eq!(successors.len(), 6);
+    assert!(successors[0].is_empty());
+    assert_eq!(successors[2], vec![(3, 5), (5, 30)]);
+    assert_eq!(
+        dijkstra(&1, |&n| successors[n].iter().copied(), |&n| n == 5),
+        Some((vec![1, 2, 3, 4, 5], 18))
+    );
+}
+
+#[test]
+fn read_graph_with_other_costs() {
+    let graph = read_gr::<_, f64>("p sp 2 1\na 1 2 1.5\n".as_bytes()).unwrap();
+    assert_eq!(graph.arcs, vec![(1, 2, 1.5)]);
+    let graph = read_gr::<_, i32>("p sp 2 1\na 2 1 -3\n".as_bytes()).unwrap();
+    assert_eq!(graph.arcs, vec![(2, 1, -3)]);
+}
+
+#[test]
+fn coordinates_for_astar() {
+    let graph = read_gr::<_, u64>(GRAPH.as_bytes()).unwrap();
+    let coordinates = read_coordinates(COORDINATES.as_bytes()).unwrap();
+    assert_eq!(coordinates.len(), 6);
+    assert_eq!(coordinates[4], None);
+    assert_eq!(coordinates[3], Some((10, 10)));
+    let successors = graph.successors();
+    let heuristic = |&n: &usize| {
+        coordinates[n].map_or(0, |(x, y)| {
+            (x.unsigned_abs() + (y - 10).unsigned_abs()) / 10
+        })
+    };
+    assert_eq!(
+        astar(
+            &1,
+            |&n| successors[n].iter().copied(),
+            heuristic,
+            |&n| n == 5
+        ),
+        dijkstra(&1, |&n| successors[n].iter().copied(), |&n| n == 5)
+    );
+}
+
+#[test]
+fn read_flow() {
+    let input = "c Max flow\np max 6 8\nn 1 s\nn 6 t\na 1 2 5\na 1 3 15\na 2 4 5\na 2 5 5\n\
+                 a 3 4 5\na 3 5 5\na 4 6 15\na 5 6 5\n";
+    let problem = read_max_flow::<_, i64>(input.as_bytes()).unwrap();
+    assert_eq!((problem.nodes, problem.source, problem.sink), (6, 1, 6));
+    assert_eq!(problem.vertices(), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(problem.capacities().count(), 8);
+    let (_, flow, _) = edmonds_karp_sparse(
+        &problem.vertices(),
+        &problem.source,
+        &problem.sink,
+        problem.capacities(),
+    );
+    assert_eq!(flow, 15);
+}
+
+#[test]
+fn errors() {
+    fn gr(input: &str) -> String {
+        read_gr::<_, u32>(input.as_bytes()).unwrap_err().to_string()
+    }
+    assert_eq!(gr(""), "line 0: expected a `p sp` problem line");
+    assert_eq!(gr("a 1 2 3\n"), "line 1: expected a `p sp` problem line");
+    assert_eq!(gr("p max 2 1\n"), "line 1: expected a `p sp` problem line");
+    assert_eq!(
+        gr("p sp 2 1\np sp 2 1\n"),
+        "line 2: expected a `p sp` problem line"
+    );
+    assert_eq!(gr("p sp 2\n"), "line 1 is malformed");
+    assert_eq!(gr("p sp 2 1\na 1 2\n"), "line 2 is malformed");
+    assert_eq!(gr("p sp 2 1\na 1 2 x\n"), "line 2 is malformed");
+    assert_eq!(gr("p sp 2 1\na 1 2 -1\n"), "line 2 is malformed");
+    assert_eq!(gr("p sp 2 1\nv 1 2 3\n"), "line 2 is malformed");
+    assert_eq!(gr("p sp 2 1\na 1 3 1\n"), "line 2 refers to invalid node 3");
+    assert_eq!(gr("p sp 2 1\na 0 1 1\n"), "line 2 refers to invalid node 0");
+    assert_eq!(gr("p sp 2 2\na 1 2 1\n"), "expected 2 arcs, found 1");
+    assert_eq!(
+        gr("p sp 18446744073709551615 0\n"),
+        "line 1 declares too many nodes (18446744073709551615)"
+    );
+    for nodes in [usize::MAX, usize::MAX / 16, usize::MAX >> 12] {
+        assert!(matches!(
+            read_coordinates(format!("c huge\np aux sp co {nodes}\n").as_bytes()),
+            Err(DimacsError::TooManyNodes { line: 2, nodes: n }) if n == nodes
+        ));
+    }
+    assert_eq!(
+        read_coordinates("p aux sp co 2\nv 3 0 0\n".as_bytes())
+            .unwrap_err()
+            .to_string(),
+        "line 2 refers to invalid node 3"
+    );
+    assert_eq!(
+        read_coordinates("p sp 2 1\n".as_bytes())
+            .unwrap_err()
+            .to_string(),
+        "line 1: expected a `p aux sp co` problem line"
+    );
+    assert!(matches!(
+        read_max_flow::<_, i32>("p max 2 1\nn 1 s\na 1 2 3\n".as_bytes()),
+        Err(DimacsError::MissingTerminal)
+    ));
+    assert!(matches!(
+        read_max_flow::<_, i32>("p max 2 1\nn 1 s\nn 1 t\na 1 2 3\n".as_bytes()),
+        Err(DimacsError::DuplicateTerminal(3))
+    ));
+    assert!(matches!(
+        read_max_flow::<_, i32>("p max 2 1\nn 1 s\nn 2 s\nn 2 t\na 1 2 3\n".as_bytes()),
+        Err(DimacsError::DuplicateTerminal(3))
+    ));
+    assert!(matches!(
+        read_max_flow::<_, i32>("p max 2 1\nn 1 x\n".as_bytes()),
+        Err(DimacsError::Syntax(2))
+    ));
+}
+
+#[test]
+fn io_error() {
+    // Invalid UTF-8 cannot be read as lines.
+    assert!(matches!(
+        read_gr::<_, u32>(&b"p sp 1 0\n\xff\n"[..]),
+        Err(DimacsError::Io(_))
+    ));
+}
@@ -0,0 +1,70 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_graph(rng: &mut XorShiftRng, size: usize) -> Vec<Vec<(usize, u32)>> {
+    (0..size)
+        .map(|_| {
+            (0..rng.random_range(0..4))
+                .map(|_| (rng.random_range(0..size), rng.random_range(0..10)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn same_costs_as_dijkstra() {
+    let mut rng = XorShiftRng::from_seed([9; 16]);
+    for _ in 0..50 {
+        let size = rng.random_range(1..20);
+        let edges = random_graph(&mut rng, size);
+        let mut reversed = vec![Vec::new(); size];
+        for (from, successors) in edges.iter().enumerate() {
+            for &(to, cost) in successors {
+                reversed[to].push((from, cost));
+            }
+        }
+        let nodes = |rng: &mut XorShiftRng| {
+            (0..rng.random_range(0..5))
+                .map(|_| rng.random_range(0..size))
+                .collect::<Vec<_>>()
+        };
+        let (starts, goals) = (nodes(&mut rng), nodes(&mut rng));
+        let successors = |&n: &usize| edges[n].clone();
+        let expected = starts
+            .iter()
+            .map(|s| {
+                goals
+                    .iter()
+                    .map(|g| dijkstra(s, successors, |n| n == g).map(|(_, cost)| cost))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(many_to_many(&starts, &goals, successors), expected);
+        for (s, row) in starts.iter().zip(&expected) {
+            assert_eq!(&one_to_many(s, &goals, successors), row);
+        }
+        for (i, g) in goals.iter().enumerate() {
+            let column = expected.iter().map(|row| row[i]).collect::<Vec<_>>();
+            assert_eq!(
+                many_to_one(&starts, g, |&n: &usize| reversed[n].clone()),
+                column
+            );
+        }
+    }
+}
+
+#[test]
+fn search_stops_once_goals_are_reached() {
+    // The graph is infinite, but the search stops once every goal has been reached.
+    let successors = |&n: &u64| vec![(n + 1, 1), (n * 3, 1)];
+    assert_eq!(
+        one_to_many(&1, &[9, 10, 27], successors),
+        vec![Some(2), Some(3), Some(3)]
+    );
+    assert_eq!(one_to_many(&1, &[], successors), Vec::<Option<u32>>::new());
+    assert_eq!(
+        many_to_many(&[], &[1], successors),
+        Vec::<Vec<Option<u32>>>::new()
+    );
+}
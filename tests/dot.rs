@@ -0,0 +1,89 @@
+use pathfinding::prelude::*;
+
+#[test]
+fn undirected_graph() {
+    let edges = [("a", "b", 1), ("b", "c", 2), ("c", "a", 3)];
+    let dot = graph_to_dot(edges, false, |_| String::new(), |_, _, _| String::new());
+    assert_eq!(
+        dot,
+        "graph {\n    \"\\\"a\\\"\";\n    \"\\\"b\\\"\";\n    \"\\\"c\\\"\";\n    \
+         \"\\\"a\\\"\" -- \"\\\"b\\\"\";\n    \"\\\"b\\\"\" -- \"\\\"c\\\"\";\n    \
+         \"\\\"c\\\"\" -- \"\\\"a\\\"\";\n}\n"
+    );
+}
+
+#[test]
+fn escaping() {
+    let dot = graph_to_dot(
+        [('\\', '"', ())],
+        true,
+        |_| String::new(),
+        |_, _, ()| String::new(),
+    );
+    assert!(dot.contains(r#"    "'\\\\'" -> "'\"'";"#), "{dot}");
+}
+
+#[test]
+fn empty_graph() {
+    let edges: [(u8, u8, ()); 0] = [];
+    assert_eq!(
+        graph_to_dot(edges, true, |_| String::new(), |_, _, ()| String::new()),
+        "digraph {\n}\n"
+    );
+}
+
+#[test]
+fn dijkstra_trace() {
+    let successors = |&n: &u32| match n {
+        0 => vec![(1, 7), (2, 1)],
+        2 => vec![(1, 2), (3, 10)],
+        1 => vec![(3, 1)],
+        _ => vec![],
+    };
+    let mut trace = SearchTrace::new();
+    let (path, cost) = dijkstra(&0, trace.record(successors), |&n| n == 3).unwrap();
+    assert_eq!((path, cost), (vec![0, 2, 1, 3], 4));
+    assert_eq!(trace.expanded(), &[0, 2, 1]);
+    assert_eq!(
+        trace.edges(),
+        &[(0, 1, 7), (0, 2, 1), (2, 1, 2), (2, 3, 10), (1, 3, 1)]
+    );
+    let dot = trace.to_dot(
+        |_, order| order.map_or_else(|| "color=grey".to_string(), |i| format!("label={i}")),
+        |_, _, c| format!("label={c}"),
+    );
+    assert_eq!(
+        dot,
+        "digraph {\n    \"0\" [label=0];\n    \"2\" [label=1];\n    \"1\" [label=2];\n    \
+         \"3\" [color=grey];\n    \"0\" -> \"1\" [label=7];\n    \"0\" -> \"2\" [label=1];\n    \
+         \"2\" -> \"1\" [label=2];\n    \"2\" -> \"3\" [label=10];\n    \"1\" -> \"3\" [label=1];\n}\n"
+    );
+    trace.clear();
+    assert!(trace.expanded().is_empty());
+    assert!(trace.edges().is_empty());
+}
+
+#[test]
+fn bfs_trace_is_transparent() {
+    let successors = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && (x, y) != (2, 2))
+    };
+    let mut trace = SearchTrace::new();
+    let traced = bfs(&(0, 0), trace.record_unweighted(successors), |&n| {
+        n == (4, 4)
+    });
+    assert_eq!(traced, bfs(&(0, 0), successors, |&n| n == (4, 4)));
+    // Every expanded node is only expanded once by a BFS.
+    let mut expanded = trace.expanded().to_vec();
+    expanded.sort_unstable();
+    expanded.dedup();
+    assert_eq!(expanded.len(), trace.expanded().len());
+    assert!(
+        trace
+            .edges()
+            .iter()
+            .all(|(from, _, ())| expanded.contains(from))
+    );
+}
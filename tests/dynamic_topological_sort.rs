@@ -0,0 +1,74 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn check_order(dto: &DynamicTopologicalOrder<u32>, edges: &[(u32, u32)]) {
+    let order = dto.order();
+    assert_eq!(order.len(), dto.len());
+    for (i, n) in order.iter().enumerate() {
+        assert_eq!(dto.position(n), Some(i));
+    }
+    for &(a, b) in edges {
+        assert!(
+            dto.position(&a) < dto.position(&b),
+            "{a} should come before {b} in {order:?}"
+        );
+    }
+}
+
+#[test]
+fn random_insertions() {
+    const SIZE: u32 = 50;
+    let mut rng = XorShiftRng::from_seed([17; 16]);
+    let mut dto = DynamicTopologicalOrder::new();
+    let mut edges = Vec::new();
+    for n in (0..SIZE).rev() {
+        assert!(dto.add_node(n));
+    }
+    let mut rejected = 0;
+    for _ in 0..400 {
+        let (a, b) = (rng.random_range(0..SIZE), rng.random_range(0..SIZE));
+        match dto.add_edge(a, b) {
+            Ok(()) => {
+                assert!(dto.has_edge(&a, &b));
+                edges.push((a, b));
+            }
+            Err(CycleError { path }) => {
+                rejected += 1;
+                assert!(!dto.has_edge(&a, &b) || a == b);
+                assert_eq!(path.first(), Some(&b));
+                assert_eq!(path.last(), Some(&a));
+                assert!(path.windows(2).all(|w| dto.has_edge(&w[0], &w[1])));
+            }
+        }
+        check_order(&dto, &edges);
+    }
+    assert!(rejected > 0);
+    let successors = |n: &u32| {
+        edges
+            .iter()
+            .filter(|&&(a, _)| a == *n)
+            .map(|&(_, b)| b)
+            .collect::<Vec<_>>()
+    };
+    assert!(topological_sort(&dto.order(), successors).is_ok());
+}
+
+#[test]
+fn self_loop_and_removal() {
+    let mut dto = DynamicTopologicalOrder::new();
+    assert!(dto.is_empty());
+    assert_eq!(dto.add_edge(1, 1), Err(CycleError { path: vec![1] }));
+    dto.add_edge(2, 1).unwrap();
+    assert_eq!(dto.order(), vec![2, 1]);
+    assert_eq!(
+        dto.add_edge(1, 2).unwrap_err().to_string(),
+        "adding this edge would create a cycle"
+    );
+    assert!(dto.remove_edge(&2, &1));
+    assert!(!dto.remove_edge(&2, &1));
+    assert!(!dto.remove_edge(&2, &3));
+    dto.add_edge(1, 2).unwrap();
+    assert_eq!(dto.order(), vec![1, 2]);
+    assert_eq!(dto.position(&3), None);
+}
@@ -0,0 +1,150 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+/// Classical dynamic programming computation of the edit distance.
+fn reference(a: &[u8], b: &[u8], costs: &EditCosts<usize>) -> usize {
+    let mut row = (0..=b.len())
+        .map(|j| j * costs.insertion)
+        .collect::<Vec<_>>();
+    for (i, x) in a.iter().enumerate() {
+        let mut previous = row[0];
+        row[0] = (i + 1) * costs.deletion;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous + if x == y { 0 } else { costs.substitution };
+            previous = row[j + 1];
+            row[j + 1] = substitution
+                .min(row[j + 1] + costs.deletion)
+                .min(row[j] + costs.insertion);
+        }
+    }
+    row[b.len()]
+}
+
+/// Apply an alignment to `a`, and check that it gives `b` for the given cost.
+fn check_alignment(
+    a: &[u8],
+    b: &[u8],
+    costs: &EditCosts<usize>,
+    alignment: &[EditOperation],
+) -> usize {
+    let (mut i, mut j, mut cost) = (0, 0, 0);
+    for op in alignment {
+        match op {
+            EditOperation::Keep => {
+                assert_eq!(a[i], b[j]);
+                i += 1;
+                j += 1;
+            }
+            EditOperation::Substitute => {
+                assert_ne!(a[i], b[j]);
+                cost += costs.substitution;
+                i += 1;
+                j += 1;
+            }
+            EditOperation::Delete => {
+                cost += costs.deletion;
+                i += 1;
+            }
+            EditOperation::Insert => {
+                cost += costs.insertion;
+                j += 1;
+            }
+        }
+    }
+    assert_eq!((i, j), (a.len(), b.len()));
+    cost
+}
+
+fn random_sequence(rng: &mut XorShiftRng, len: usize) -> Vec<u8> {
+    (0..len).map(|_| rng.random_range(b'a'..b'e')).collect()
+}
+
+#[test]
+fn matches_reference() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    let all_costs = [
+        EditCosts::LEVENSHTEIN,
+        EditCosts {
+            insertion: 2,
+            deletion: 3,
+            substitution: 4,
+        },
+        EditCosts {
+            insertion: 1,
+            deletion: 1,
+            substitution: 5,
+        },
+        EditCosts {
+            insertion: 0,
+            deletion: 2,
+            substitution: 1,
+        },
+    ];
+    for _ in 0..200 {
+        let (n, m) = (rng.random_range(0..30), rng.random_range(0..30));
+        let (a, b) = (random_sequence(&mut rng, n), random_sequence(&mut rng, m));
+        for costs in &all_costs {
+            let (alignment, cost) = edit_alignment(&a, &b, costs);
+            assert_eq!(cost, reference(&a, &b, costs));
+            assert_eq!(check_alignment(&a, &b, costs, &alignment), cost);
+            assert_eq!(edit_distance(&a, &b, costs), cost);
+        }
+    }
+}
+
+#[test]
+fn similar_long_sequences() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    let a = random_sequence(&mut rng, 5000);
+    let mut b = a.clone();
+    for _ in 0..20 {
+        let i = rng.random_range(0..b.len());
+        match rng.random_range(0..3) {
+            0 => b[i] = b'z',
+            1 => {
+                b.remove(i);
+            }
+            _ => b.insert(i, b'z'),
+        }
+    }
+    let costs = EditCosts::LEVENSHTEIN;
+    let (alignment, cost) = edit_alignment(&a, &b, &costs);
+    assert!(cost <= 20);
+    assert_eq!(cost, reference(&a, &b, &costs));
+    assert_eq!(check_alignment(&a, &b, &costs, &alignment), cost);
+}
+
+#[test]
+fn banded() {
+    let costs = EditCosts::LEVENSHTEIN;
+    // The optimal alignment deletes 4 elements and inserts 4 others, going 4 cells
+    // away from the diagonal.
+    let (a, b) = (b"xxxxabcdefgh", b"abcdefghyyyy");
+    assert_eq!(
+        banded_edit_alignment(a, b, &costs, 4).map(|(_, c)| c),
+        Some(8)
+    );
+    // In a narrower band, everything gets substituted.
+    assert_eq!(
+        banded_edit_alignment(a, b, &costs, 3).map(|(_, c)| c),
+        Some(12)
+    );
+    assert_eq!(edit_distance(a, b, &costs), 8);
+    assert_eq!(banded_edit_alignment(b"abc", b"", &costs, 2), None);
+    assert_eq!(
+        banded_edit_alignment(b"abc", b"", &costs, 3),
+        Some((vec![EditOperation::Delete; 3], 3))
+    );
+}
+
+#[test]
+fn empty_sequences() {
+    let costs = EditCosts::LEVENSHTEIN;
+    assert_eq!(edit_alignment::<u8, _>(&[], &[], &costs), (vec![], 0));
+    assert_eq!(
+        edit_alignment(b"", b"ab", &costs),
+        (vec![EditOperation::Insert; 2], 2)
+    );
+    assert_eq!(edit_distance(b"same", b"same", &costs), 0);
+}
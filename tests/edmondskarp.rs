@@ -60,6 +60,11 @@ fn wikipedia_example_sparse() {
     wikipedia_example::<SparseCapacity<_>>();
 }
 
+#[test]
+fn wikipedia_example_adjacency() {
+    wikipedia_example::<AdjacencyCapacity<_>>();
+}
+
 #[expect(clippy::cast_possible_truncation)]
 fn wikipedia_progressive_example<EK: EdmondsKarp<i32>>() {
     let successors = successors_wikipedia();
@@ -88,6 +93,11 @@ fn wikipedia_progressive_example_sparse() {
     wikipedia_progressive_example::<SparseCapacity<_>>();
 }
 
+#[test]
+fn wikipedia_progressive_example_adjacency() {
+    wikipedia_progressive_example::<AdjacencyCapacity<_>>();
+}
+
 fn disconnected<EK: EdmondsKarp<isize>>() {
     let (caps, total, _) = edmonds_karp::<_, _, _, EK>(
         &['A', 'B'],
@@ -109,6 +119,11 @@ fn disconnected_sparse() {
     disconnected::<SparseCapacity<_>>();
 }
 
+#[test]
+fn disconnected_adjacency() {
+    disconnected::<AdjacencyCapacity<_>>();
+}
+
 fn modified<EK: EdmondsKarp<i32>>() {
     // Graph is:
     //
@@ -154,6 +169,43 @@ fn modified_sparse() {
     modified::<SparseCapacity<i32>>();
 }
 
+#[test]
+fn modified_adjacency() {
+    modified::<AdjacencyCapacity<i32>>();
+}
+
+#[test]
+fn random_graphs_adjacency() {
+    use rand::{Rng as _, SeedableRng as _};
+    use rand_xorshift::XorShiftRng;
+
+    for seed in 1..20 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let vertices = (0..30).collect::<Vec<_>>();
+        let edges = (0..120)
+            .map(|_| {
+                (
+                    (rng.random_range(0..30), rng.random_range(0..30)),
+                    rng.random_range(1..20),
+                )
+            })
+            .filter(|&((a, b), _)| a != b)
+            .collect::<HashMap<_, _>>();
+        let (_, expected, _) = edmonds_karp_dense(&vertices, &0, &29, edges.clone());
+        let (flows, total, cut) = edmonds_karp_adjacency(&vertices, &0, &29, edges.clone());
+        assert_eq!(total, expected);
+        assert_eq!(cut.iter().map(|&(_, c)| c).sum::<i32>(), total);
+        for &((a, b), flow) in &flows {
+            assert!(flow <= edges[&(a, b)]);
+        }
+        for n in 1..29 {
+            let incoming = flows.iter().filter(|&&((_, b), _)| b == n).map(|&(_, f)| f);
+            let outgoing = flows.iter().filter(|&&((a, _), _)| a == n).map(|&(_, f)| f);
+            assert_eq!(incoming.sum::<i32>(), outgoing.sum::<i32>());
+        }
+    }
+}
+
 #[test]
 #[should_panic(expected = "source is greater or equal than size")]
 fn empty() {
@@ -0,0 +1,48 @@
+use pathfinding::prelude::*;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    vec![(n + 1, 1), (n * 2, 3), (n.saturating_sub(1), 2)]
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn extend_successors(n: &u32, out: &mut Vec<(u32, u32)>) {
+    assert!(out.is_empty());
+    out.extend(successors(n));
+}
+
+#[test]
+fn same_results_as_iterator_api() {
+    for goal in [1, 2, 17, 99] {
+        assert_eq!(
+            dijkstra_extend(&1, extend_successors, |&n| n == goal),
+            dijkstra(&1, successors, |&n| n == goal)
+        );
+        assert_eq!(
+            astar_extend(&1, extend_successors, |_| 0, |&n| n == goal),
+            astar(&1, successors, |_| 0, |&n| n == goal)
+        );
+        assert_eq!(
+            bfs_extend(&1, |&n, out| out.extend([n + 1, n * 2]), |&n| n == goal),
+            bfs(&1, |&n| vec![n + 1, n * 2], |&n| n == goal)
+        );
+    }
+}
+
+#[test]
+fn buffer_is_reused() {
+    let mut capacities = Vec::new();
+    let result = dijkstra_extend(
+        &0u32,
+        |&n, out| {
+            capacities.push(out.capacity());
+            if n < 10 {
+                out.push((n + 1, 1));
+            }
+        },
+        |_| false,
+    );
+    assert_eq!(result, None);
+    assert_eq!(capacities[0], 0);
+    assert!(capacities[1..].iter().all(|&c| c > 0));
+}
@@ -0,0 +1,78 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 40;
+
+fn build_network(seed: u8) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 10) {
+                network[(a, b)] = rng.random_range(1..100);
+            }
+        }
+    }
+    network
+}
+
+#[test]
+fn nearest_goal() {
+    for seed in 1..4 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        let predecessors = |&b: &usize| {
+            (0..SIZE)
+                .filter(|&a| network[(a, b)] != 0)
+                .map(|a| (a, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        let goals = [3, 17, 31];
+        let field = flow_field(&goals, predecessors);
+        for start in 0..SIZE {
+            let expected = dijkstra(&start, successors, |n| goals.contains(n));
+            let Some((path, cost)) = expected else {
+                assert!(!field.contains_key(&start), "{start} reaches no goal");
+                continue;
+            };
+            assert_eq!(field[&start].1, cost, "remaining cost from {start}");
+            // Following the field must lead to a goal with the announced cost.
+            let (mut node, mut total) = (start, 0);
+            while !goals.contains(&node) {
+                let (next, remaining) = field[&node];
+                total += network[(node, next)];
+                assert_eq!(field[&next].1 + network[(node, next)], remaining);
+                node = next;
+            }
+            assert_eq!(field[&node], (node, 0));
+            assert_eq!(total, cost);
+            assert!(goals.contains(path.last().unwrap()));
+        }
+    }
+}
+
+#[test]
+fn no_goal() {
+    let field = flow_field(&[] as &[u32], |&n: &u32| vec![(n + 1, 1)]);
+    assert!(field.is_empty());
+}
+
+#[test]
+fn duplicated_goal() {
+    let field = flow_field(&[2, 2], |&n: &u32| {
+        [(n.wrapping_sub(1), 1), (n + 1, 1)]
+            .into_iter()
+            .filter(|&(m, _)| m < 5)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(field.len(), 5);
+    assert_eq!(field[&2], (2, 0));
+    assert_eq!(field[&0], (1, 2));
+    assert_eq!(field[&4], (3, 2));
+}
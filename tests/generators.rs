@@ -0,0 +1,125 @@
+#![cfg(feature = "generators")]
+
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+fn rng(seed: u8) -> XorShiftRng {
+    XorShiftRng::from_seed([seed; 16])
+}
+
+#[test]
+fn generators_are_reproducible() {
+    for seed in 1..10 {
+        assert_eq!(
+            erdos_renyi(30, 0.2, true, &mut rng(seed)),
+            erdos_renyi(30, 0.2, true, &mut rng(seed))
+        );
+        assert_eq!(
+            random_geometric(30, 0.3, &mut rng(seed)),
+            random_geometric(30, 0.3, &mut rng(seed))
+        );
+        assert_eq!(
+            random_obstacle_grid(10, 10, 0.3, &mut rng(seed)),
+            random_obstacle_grid(10, 10, 0.3, &mut rng(seed))
+        );
+        assert_eq!(
+            barabasi_albert(30, 3, &mut rng(seed)),
+            barabasi_albert(30, 3, &mut rng(seed))
+        );
+    }
+    assert_ne!(
+        erdos_renyi(30, 0.5, false, &mut rng(1)),
+        erdos_renyi(30, 0.5, false, &mut rng(2))
+    );
+}
+
+#[test]
+fn erdos_renyi_edges() {
+    let mut rng = rng(1);
+    assert_eq!(erdos_renyi(10, 1.0, true, &mut rng).len(), 90);
+    assert_eq!(erdos_renyi(10, 1.0, false, &mut rng).len(), 45);
+    assert!(erdos_renyi(10, 0.0, true, &mut rng).is_empty());
+    let edges = erdos_renyi(100, 0.1, false, &mut rng);
+    assert!(edges.iter().all(|&(a, b)| a < b && b < 100));
+    assert!((300..700).contains(&edges.len()));
+    let edges = erdos_renyi(100, 0.1, true, &mut rng);
+    assert!(edges.iter().all(|&(a, b)| a != b));
+    assert!(edges.iter().any(|&(a, b)| a > b));
+}
+
+#[test]
+#[should_panic(expected = "probability must be between 0 and 1")]
+fn erdos_renyi_invalid_probability() {
+    erdos_renyi(10, 1.5, true, &mut rng(1));
+}
+
+#[test]
+fn random_geometric_edges() {
+    let (positions, edges) = random_geometric(100, 0.15, &mut rng(3));
+    assert!(
+        positions
+            .iter()
+            .all(|&(x, y)| (0.0..1.0).contains(&x) && (0.0..1.0).contains(&y))
+    );
+    let close = |a: usize, b: usize| {
+        let ((xa, ya), (xb, yb)) = (positions[a], positions[b]);
+        (xa - xb).hypot(ya - yb) <= 0.15
+    };
+    for a in 0..100 {
+        for b in a + 1..100 {
+            assert_eq!(edges.contains(&(a, b)), close(a, b));
+        }
+    }
+}
+
+#[test]
+fn random_obstacle_grid_density() {
+    let mut rng = rng(4);
+    assert!(random_obstacle_grid(10, 5, 0.0, &mut rng).is_full());
+    assert!(random_obstacle_grid(10, 5, 1.0, &mut rng).is_empty());
+    let grid = random_obstacle_grid(50, 40, 0.25, &mut rng);
+    assert_eq!((grid.width, grid.height), (50, 40));
+    assert!((1300..1700).contains(&grid.vertices_len()));
+}
+
+#[test]
+fn barabasi_albert_structure() {
+    let edges = barabasi_albert(200, 3, &mut rng(5));
+    assert_eq!(edges.len(), 3 * 197);
+    assert!(edges.iter().all(|&(a, b)| b < a && a < 200));
+    let mut degrees = vec![0; 200];
+    for &(a, b) in &edges {
+        degrees[a] += 1;
+        degrees[b] += 1;
+    }
+    assert!(degrees.iter().all(|&d| d >= 3));
+    // Early nodes accumulate many more links than the minimum.
+    assert!(degrees.iter().max().unwrap() > &20);
+    // No duplicated edge.
+    let mut sorted = edges.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(sorted.len(), edges.len());
+}
+
+#[test]
+fn barabasi_albert_smallest() {
+    assert_eq!(barabasi_albert(2, 1, &mut rng(6)), vec![(1, 0)]);
+}
+
+#[test]
+fn generated_graphs_are_searchable() {
+    let mut rng = rng(7);
+    let grid = random_obstacle_grid(30, 30, 0.2, &mut rng);
+    let (start, reachable) = grid
+        .iter()
+        .map(|v| (v, grid.bfs_reachable(v, |_| true)))
+        .max_by_key(|(_, reachable)| reachable.len())
+        .unwrap();
+    for goal in &reachable {
+        let path = bfs(&start, |&n| grid.neighbours(n), |n| n == goal).unwrap();
+        assert!(path.len() > grid.distance(start, *goal));
+    }
+    assert!(reachable.len() > 400);
+}
@@ -0,0 +1,53 @@
+use pathfinding::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Exit {
+    East,
+    South,
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&(x, y): &(u8, u8)) -> Vec<((u8, u8), u32)> {
+    [((x + 1, y), 2), ((x, y + 1), 3)]
+        .into_iter()
+        .filter(|&((x, y), _)| x <= 6 && y <= 3)
+        .collect()
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+const fn exit(&(x, y): &(u8, u8)) -> Option<Exit> {
+    if x == 6 {
+        Some(Exit::East)
+    } else if y == 3 {
+        Some(Exit::South)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn dijkstra_reports_goal() {
+    let (path, cost, goal) = dijkstra_goal(&(0, 0), successors, exit).unwrap();
+    assert_eq!(goal, Exit::South);
+    assert_eq!(cost, 9);
+    assert_eq!(path.last(), Some(&(0, 3)));
+    assert_eq!(dijkstra_goal(&(0, 0), successors, |_| None::<()>), None);
+}
+
+#[test]
+fn astar_reports_goal() {
+    let result = astar_goal(&(0, 0), successors, |_| 0, exit);
+    assert_eq!(result.map(|(_, c, g)| (c, g)), Some((9, Exit::South)));
+    let (_, cost, goal) = astar_goal(&(4, 0), successors, |_| 0, exit).unwrap();
+    assert_eq!((cost, goal), (4, Exit::East));
+}
+
+#[test]
+fn bfs_reports_goal() {
+    let succ = |&(x, y): &(u8, u8)| vec![(x + 1, y), (x, y + 1)];
+    let (path, goal) = bfs_goal(&(0, 0), succ, exit).unwrap();
+    assert_eq!((path.len(), goal), (4, Exit::South));
+    let (path, goal) = bfs_goal(&(0, 3), succ, exit).unwrap();
+    assert_eq!((path, goal), (vec![(0, 3)], Exit::South));
+    assert_eq!(bfs_goal(&(0, 0), |_| vec![], exit), None);
+}
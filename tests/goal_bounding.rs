@@ -0,0 +1,73 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_grid(seed: u8, diagonal: bool) -> Grid {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut grid = Grid::new(23, 17);
+    grid.fill();
+    if diagonal {
+        grid.enable_diagonal_mode();
+    }
+    for _ in 0..120 {
+        grid.remove_vertex((rng.random_range(0..23), rng.random_range(0..17)));
+    }
+    grid
+}
+
+fn check(grid: &Grid) {
+    let bounded = GoalBoundedGrid::new(grid);
+    let vertices = grid.iter().step_by(13).collect::<Vec<_>>();
+    for &start in &vertices {
+        for &goal in &vertices {
+            let optimal = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal);
+            let found = bounded.find_path(start, goal);
+            assert_eq!(optimal.is_some(), found.is_some(), "{start:?} -> {goal:?}");
+            let (Some(optimal), Some((path, cost))) = (optimal, found) else {
+                continue;
+            };
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            assert_eq!(cost, optimal.len() - 1);
+            assert_eq!(cost, path.len() - 1);
+            assert!(
+                path.windows(2).all(|w| grid.has_edge(w[0], w[1])),
+                "invalid path {path:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn random_grids() {
+    for seed in 1..3 {
+        check(&random_grid(seed, false));
+        check(&random_grid(seed, true));
+    }
+}
+
+#[test]
+fn bounding_boxes() {
+    let mut grid = Grid::new(5, 5);
+    grid.fill();
+    let bounded = GoalBoundedGrid::new(&grid);
+    // Every vertex of the rightmost columns is reached optimally by going right first.
+    assert_eq!(bounded.bounding_box((2, 2), (3, 2)), Some(((3, 0), (4, 4))));
+    assert_eq!(bounded.bounding_box((0, 0), (0, 1)), Some(((0, 1), (4, 4))));
+    assert_eq!(bounded.bounding_box((0, 0), (1, 1)), None);
+    assert_eq!(bounded.neighbours((2, 2), (4, 0)), vec![(3, 2), (2, 1)]);
+    assert_eq!(bounded.neighbours((2, 2), (2, 2)), vec![]);
+}
+
+#[test]
+fn missing_vertices() {
+    let mut grid = Grid::new(3, 3);
+    grid.fill();
+    grid.remove_vertex((1, 1));
+    let bounded = GoalBoundedGrid::new(&grid);
+    assert_eq!(bounded.find_path((0, 0), (1, 1)), None);
+    assert_eq!(bounded.find_path((1, 1), (0, 0)), None);
+    assert_eq!(bounded.bounding_box((1, 1), (1, 0)), None);
+    assert!(bounded.neighbours((1, 1), (0, 0)).is_empty());
+    assert_eq!(bounded.find_path((0, 0), (2, 2)).map(|(_, c)| c), Some(4));
+}
@@ -0,0 +1,236 @@
+#![cfg(feature = "graphml")]
+
+use pathfinding::prelude::*;
+
+// As written by NetworkX's `write_graphml`.
+const NETWORKX: &str = r#"<?xml version='1.0' encoding='utf-8'?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">
+  <key id="d3" for="edge" attr.name="weight" attr.type="long" />
+  <key id="d2" for="node" attr.name="visited" attr.type="boolean" />
+  <key id="d1" for="node" attr.name="label" attr.type="string" />
+  <key id="d0" for="graph" attr.name="name" attr.type="string" />
+  <graph edgedefault="undirected">
+    <data key="d0">roads &amp; paths</data>
+    <node id="A">
+      <data key="d1">start</data>
+      <data key="d2">True</data>
+    </node>
+    <node id="B">
+      <data key="d1"><![CDATA[<middle>]]></data>
+    </node>
+    <node id="C" />
+    <edge source="A" target="B">
+      <data key="d3">4</data>
+    </edge>
+    <edge source="B" target="C">
+      <data key="d3">1</data>
+    </edge>
+    <edge source="C" target="A">
+      <data key="d3">6</data>
+    </edge>
+  </graph>
+</graphml>
+"#;
+
+#[test]
+fn read_networkx() {
+    let graph = read_graphml(NETWORKX.as_bytes()).unwrap();
+    assert!(!graph.directed);
+    assert_eq!(graph.attributes["name"].as_str(), Some("roads & paths"));
+    let ids = graph
+        .nodes
+        .iter()
+        .map(|n| n.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, ["A", "B", "C"]);
+    assert_eq!(graph.nodes[0].attributes["visited"].as_bool(), Some(true));
+    assert_eq!(
+        graph.nodes[1].attributes["label"].as_str(),
+        Some("<middle>")
+    );
+    assert!(graph.nodes[2].attributes.is_empty());
+    assert_eq!(graph.edges[2].source, "C");
+    assert_eq!(
+        graph.edges[2].attributes["weight"],
+        GraphmlValue::Integer(6)
+    );
+    let successors = graph.successors(|e| e.attributes["weight"].as_i64().unwrap());
+    assert_eq!(successors[0], vec![(1, 4), (2, 6)]);
+    // C to A goes back through B.
+    let result = dijkstra(&2, |&n| successors[n].iter().copied(), |&n| n == 0);
+    assert_eq!(result, Some((vec![2, 1, 0], 5)));
+}
+
+#[test]
+fn round_trip() {
+    let mut graph = GraphmlGraph::new(true);
+    graph.attributes.insert("scale".into(), 0.5.into());
+    let node = graph.add_node("<a & \"b\">");
+    node.insert("x".into(), 1.25.into());
+    node.insert("label".into(), "first\nnode".into());
+    node.insert("goal".into(), false.into());
+    node.insert("padding".into(), "  padded  ".into());
+    graph.add_node("b").insert("goal".into(), true.into());
+    let node = graph.add_node("c");
+    node.insert("x".into(), f64::INFINITY.into());
+    node.insert("padding".into(), " ".into());
+    graph
+        .add_node("d")
+        .insert("x".into(), f64::NEG_INFINITY.into());
+    graph
+        .add_edge("<a & \"b\">", "b")
+        .insert("cost".into(), (-3).into());
+    graph.add_edge("b", "c");
+    graph
+        .add_edge("c", "c")
+        .insert("cost".into(), i64::MAX.into());
+    let mut output = Vec::new();
+    write_graphml(&graph, &mut output).unwrap();
+    assert_eq!(read_graphml(output.as_slice()).unwrap(), graph);
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains(">INF</data>") && text.contains(">-INF</data>"));
+    graph.nodes[2]
+        .attributes
+        .insert("x".into(), f64::NAN.into());
+    let mut output = Vec::new();
+    write_graphml(&graph, &mut output).unwrap();
+    assert!(String::from_utf8_lossy(&output).contains(">NaN</data>"));
+    let read = read_graphml(output.as_slice()).unwrap();
+    assert!(read.nodes[2].attributes["x"].as_f64().unwrap().is_nan());
+}
+
+#[test]
+fn directed_successors() {
+    let mut graph = GraphmlGraph::new(true);
+    for id in ["s", "a", "t"] {
+        graph.add_node(id);
+    }
+    graph.add_edge("s", "a");
+    graph.add_edge("t", "a");
+    graph.add_edge("a", "a");
+    let successors = graph.successors(|_| ());
+    assert_eq!(
+        successors,
+        vec![vec![(1, ())], vec![(1, ())], vec![(1, ())]]
+    );
+    graph.directed = false;
+    let successors = graph.successors(|_| ());
+    assert_eq!(successors[1], vec![(0, ()), (2, ()), (1, ())]);
+}
+
+#[test]
+fn duplicate_successors() {
+    // Edges refer to the first node with a given identifier, and indices still match
+    // the positions in `nodes`.
+    let mut graph = GraphmlGraph::new(true);
+    for id in ["a", "a", "b", "c"] {
+        graph.add_node(id);
+    }
+    graph.add_edge("b", "c");
+    graph.add_edge("c", "a");
+    let successors = graph.successors(|_| ());
+    assert_eq!(
+        successors,
+        vec![vec![], vec![], vec![(3, ())], vec![(0, ())]]
+    );
+}
+
+#[test]
+fn defaults() {
+    let input = r#"<graphml>
+      <key id="k0" for="node" attr.name="color" attr.type="string"><default>blue</default></key>
+      <key id="k1" for="edge" attr.name="weight" attr.type="double"><default>1.0</default></key>
+      <key id="k2" for="all" attr.name="hidden" attr.type="boolean"><default>false</default></key>
+      <key id="k3" attr.type="int"/>
+      <graph id="G">
+        <edge source="1" target="2"/>
+        <node id="1"><data key="k0">red</data><data key="k3">12</data></node>
+        <node id="2"/>
+        <edge source="2" target="1"><data key="k1">2.5</data><data key="k2">true</data></edge>
+      </graph>
+    </graphml>"#;
+    let graph = read_graphml(input.as_bytes()).unwrap();
+    assert!(graph.directed);
+    assert_eq!(graph.nodes[0].attributes["color"].as_str(), Some("red"));
+    assert_eq!(graph.nodes[1].attributes["color"].as_str(), Some("blue"));
+    // A key without name is designated by its identifier.
+    assert_eq!(graph.nodes[0].attributes["k3"].as_i64(), Some(12));
+    assert_eq!(graph.edges[0].attributes["weight"].as_f64(), Some(1.0));
+    assert_eq!(graph.edges[1].attributes["weight"].as_f64(), Some(2.5));
+    assert_eq!(graph.edges[0].attributes["hidden"].as_bool(), Some(false));
+    assert_eq!(graph.edges[1].attributes["hidden"].as_bool(), Some(true));
+    assert_eq!(graph.attributes["hidden"].as_bool(), Some(false));
+    assert_eq!(graph.nodes[1].attributes["hidden"].as_bool(), Some(false));
+}
+
+#[test]
+fn only_first_graph() {
+    let input = r#"<graphml><graph><node id="a"/></graph><graph><node id="b"/></graph></graphml>"#;
+    let graph = read_graphml(input.as_bytes()).unwrap();
+    assert_eq!(graph.nodes.len(), 1);
+    let graph =
+        read_graphml(r#"<graphml><graph edgedefault="undirected"/></graphml>"#.as_bytes()).unwrap();
+    assert_eq!(graph, GraphmlGraph::new(false));
+}
+
+#[test]
+fn read_errors() {
+    fn error(input: &str) -> GraphmlError {
+        read_graphml(input.as_bytes()).unwrap_err()
+    }
+    assert!(matches!(
+        error("<graphml></graphml>"),
+        GraphmlError::MissingGraph
+    ));
+    assert!(matches!(error("<graphml><graph>"), GraphmlError::Xml(_)));
+    assert!(matches!(
+        error("<graphml><graph><node/></graph></graphml>"),
+        GraphmlError::MissingAttribute {
+            element: "node",
+            attribute: "id"
+        }
+    ));
+    assert!(matches!(
+        error(r#"<graphml><graph><node id="a"><data key="x">1</data></node></graph></graphml>"#),
+        GraphmlError::UnknownKey(k) if k == "x"
+    ));
+    assert!(matches!(
+        error(r#"<graphml><key id="x" attr.type="complex"/></graphml>"#),
+        GraphmlError::UnsupportedType(t) if t == "complex"
+    ));
+    assert!(matches!(
+        error(
+            r#"<graphml><key id="x" attr.type="long"/><graph><node id="a"><data key="x">1.5</data></node></graph></graphml>"#
+        ),
+        GraphmlError::InvalidValue { key, value } if key == "x" && value == "1.5"
+    ));
+    assert!(matches!(
+        error(r#"<graphml><graph><node id="a"/><edge source="a" target="b"/></graph></graphml>"#),
+        GraphmlError::UnknownNode(n) if n == "b"
+    ));
+    assert!(matches!(
+        error(r#"<graphml><graph><node id="a"/><node id="b"/><node id="a"/></graph></graphml>"#),
+        GraphmlError::DuplicateNode(n) if n == "a"
+    ));
+    assert!(matches!(
+        error(r#"<graphml><graph><node id="a"><graph/></node></graph></graphml>"#),
+        GraphmlError::NestedGraph
+    ));
+}
+
+#[test]
+fn write_errors() {
+    let mut graph = GraphmlGraph::new(true);
+    graph.add_node("a").insert("size".into(), 1.into());
+    graph.add_node("b").insert("size".into(), "large".into());
+    assert!(matches!(
+        write_graphml(&graph, Vec::new()),
+        Err(GraphmlError::TypeConflict(name)) if name == "size"
+    ));
+    // The same name can be used with different types for nodes and edges.
+    graph.nodes[1].attributes.clear();
+    graph
+        .add_edge("a", "b")
+        .insert("size".into(), "large".into());
+    assert!(write_graphml(&graph, Vec::new()).is_ok());
+}
@@ -96,6 +96,36 @@ fn diagonal_mode() {
     assert_eq!(ns, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
 }
 
+#[test]
+fn neighbours_with_distance() {
+    let mut g = Grid::new(3, 3);
+    g.fill();
+    g.remove_vertex((0, 0));
+    let mut ns = g.neighbours_with_distance((1, 1), 7);
+    ns.sort_unstable();
+    assert_eq!(ns, vec![((0, 1), 1), ((1, 0), 1), ((1, 2), 1), ((2, 1), 1)]);
+    g.enable_diagonal_mode();
+    let mut ns = g.neighbours_with_distance((1, 1), 7);
+    ns.sort_unstable();
+    assert_eq!(
+        ns,
+        vec![
+            ((0, 1), 1),
+            ((0, 2), 7),
+            ((1, 0), 1),
+            ((1, 2), 1),
+            ((2, 0), 7),
+            ((2, 1), 1),
+            ((2, 2), 7),
+        ]
+    );
+    assert_eq!(g.neighbours_with_distance((0, 0), 7), vec![]);
+    for v in &g {
+        let ns = g.neighbours_with_distance(v, 1.5);
+        assert_eq!(ns.iter().map(|&(n, _)| n).collect_vec(), g.neighbours(v));
+    }
+}
+
 #[test]
 fn resize() {
     let mut g = Grid::new(3, 3);
@@ -614,3 +644,61 @@ fn equality() {
     g2.add_vertex((0, 0));
     assert_eq!(g, g2);
 }
+
+#[test]
+fn shortest_path() {
+    use pathfinding::prelude::{bfs, dijkstra};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for diagonal_mode in [false, true] {
+        for _ in 0..50 {
+            let mut g = Grid::new(15, 10);
+            g.fill();
+            for _ in 0..50 {
+                g.remove_vertex((rng.random_range(0..15), rng.random_range(0..10)));
+            }
+            if diagonal_mode {
+                g.enable_diagonal_mode();
+            }
+            let start = (rng.random_range(0..15), rng.random_range(0..10));
+            let goal = (rng.random_range(0..15), rng.random_range(0..10));
+            let path = g.shortest_path(start, goal);
+            if !g.has_vertex(start) || !g.has_vertex(goal) {
+                assert_eq!(path, None);
+                assert_eq!(g.shortest_path_weighted(start, goal, 3), None);
+                continue;
+            }
+            let expected = bfs(&start, |&n| g.neighbours(n), |&n| n == goal);
+            assert_eq!(path.as_ref().map(Vec::len), expected.map(|p| p.len()));
+            if let Some(path) = path {
+                assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+                assert!(path.windows(2).all(|w| g.has_edge(w[0], w[1])));
+            }
+            for diagonal_cost in [1, 2, 3] {
+                let weighted = g.shortest_path_weighted(start, goal, diagonal_cost);
+                let expected = dijkstra(
+                    &start,
+                    |&n| g.neighbours_with_distance(n, diagonal_cost),
+                    |&n| n == goal,
+                );
+                assert_eq!(
+                    weighted.as_ref().map(|&(_, cost)| cost),
+                    expected.map(|(_, cost)| cost)
+                );
+                if let Some((path, cost)) = weighted {
+                    let total = path
+                        .windows(2)
+                        .map(|w| {
+                            if w[0].0 == w[1].0 || w[0].1 == w[1].1 {
+                                1
+                            } else {
+                                diagonal_cost
+                            }
+                        })
+                        .sum::<i32>();
+                    assert_eq!(total, cost);
+                }
+            }
+        }
+    }
+}
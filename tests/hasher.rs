@@ -0,0 +1,91 @@
+use pathfinding::directed::count_paths::count_paths_with_hasher;
+use pathfinding::prelude::*;
+use pathfinding::undirected::prim::{prim, prim_with_hasher};
+use std::collections::hash_map::RandomState;
+
+#[test]
+fn directed() {
+    let succ = |&n: &u32| match n {
+        n if n < 6 => vec![n + 1, n + 2],
+        6 => vec![7],
+        _ => vec![],
+    };
+    assert_eq!(
+        count_paths_with_hasher(0, succ, |&n| n == 7, RandomState::new()),
+        count_paths(0, succ, |&n| n == 7)
+    );
+    assert_eq!(
+        topological_sort_with_hasher(&[0], succ, RandomState::new()),
+        topological_sort(&[0], succ)
+    );
+    assert_eq!(
+        topological_sort_into_groups_with_hasher(
+            &(0..8).collect::<Vec<_>>(),
+            succ,
+            RandomState::new()
+        )
+        .map(|groups| groups.len()),
+        Ok(8)
+    );
+    assert_eq!(
+        dfs_reach_with_hasher(0, succ, RandomState::new()).collect::<Vec<_>>(),
+        dfs_reach(0, succ).collect::<Vec<_>>()
+    );
+
+    let cyclic = |&n: &u32| {
+        if n < 3 {
+            vec![(n + 1) % 3, 3]
+        } else {
+            vec![7 - n]
+        }
+    };
+    let mut component = strongly_connected_component_with_hasher(&0, cyclic, RandomState::new());
+    component.sort_unstable();
+    assert_eq!(component, vec![0, 1, 2]);
+    let mut sccs =
+        strongly_connected_components_with_hasher(&[0, 1, 2, 3, 4], cyclic, RandomState::new());
+    for c in &mut sccs {
+        c.sort_unstable();
+    }
+    sccs.sort();
+    assert_eq!(sccs, vec![vec![0, 1, 2], vec![3, 4]]);
+    assert_eq!(
+        strongly_connected_components_from_with_hasher(&0, cyclic, RandomState::new()).len(),
+        2
+    );
+}
+
+#[test]
+fn flows() {
+    let caps = vec![
+        ((0, 1), 3),
+        ((0, 2), 2),
+        ((1, 3), 2),
+        ((2, 3), 3),
+        ((1, 2), 1),
+    ];
+    let (_, total, _) = edmonds_karp_with_hasher::<_, _, _, DenseCapacity<_>, _>(
+        &[0, 1, 2, 3],
+        &0,
+        &3,
+        caps.clone(),
+        RandomState::new(),
+    );
+    assert_eq!(total, edmonds_karp_sparse(&[0, 1, 2, 3], &0, &3, caps).1);
+    assert_eq!(total, 5);
+}
+
+#[test]
+fn spanning_trees() {
+    let edges = vec![('a', 'b', 4), ('b', 'c', 1), ('a', 'c', 2), ('c', 'd', 5)];
+    let total = |tree: Vec<(&char, &char, u32)>| tree.iter().map(|&(_, _, w)| w).sum::<u32>();
+    assert_eq!(
+        total(kruskal_with_hasher(&edges, RandomState::new()).collect()),
+        8
+    );
+    assert_eq!(total(kruskal(&edges).collect()), 8);
+    assert_eq!(
+        total(prim_with_hasher(&edges, RandomState::new())),
+        total(prim(&edges))
+    );
+}
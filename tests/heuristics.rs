@@ -0,0 +1,85 @@
+use pathfinding::prelude::*;
+
+#[test]
+fn distances() {
+    let (a, b) = ((1i32, 5i32), (4, 1));
+    assert_eq!(manhattan(a)(&b), 7);
+    assert_eq!(chebyshev(a)(&b), 4);
+    assert_eq!(octile(a, 2, 3)(&b), 2 + 3 * 3);
+    assert_eq!(squared_euclidean(a)(&b), 25);
+    assert_eq!(integer_euclidean(a)(&b), 5);
+    assert!((euclidean((1.0, 5.0))(&(4.0, 1.0)) - 5.0f64).abs() < 1e-9);
+}
+
+#[test]
+fn unsigned_coordinates() {
+    let goal = (2u8, 9u8, 4u8);
+    assert_eq!(manhattan(goal)(&(7, 3, 4)), 11);
+    assert_eq!(chebyshev(goal)(&(7, 3, 4)), 6);
+    assert_eq!(manhattan(goal)(&goal), 0);
+    assert_eq!(octile((9u8, 0u8), 10, 14)(&(0, 3)), 6 * 10 + 3 * 14);
+}
+
+#[test]
+fn arrays() {
+    let goal = [0i64, 0, 0, 0];
+    assert_eq!(manhattan(goal)(&[1, -2, 3, -4]), 10);
+    assert_eq!(chebyshev(goal)(&[1, -2, 3, -4]), 4);
+    assert_eq!(squared_euclidean(goal)(&[1, -2, 3, -4]), 30);
+    assert_eq!(integer_euclidean(goal)(&[1, -2, 3, -4]), 5);
+    assert!((euclidean([0.0, 0.0, 0.0])(&[2.0, 3.0, 6.0]) - 7.0f64).abs() < 1e-9);
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn king_moves(&(x, y): &(i32, i32)) -> Vec<((i32, i32), i32)> {
+    let mut moves = Vec::new();
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            if (dx, dy) != (0, 0) && (0..8).contains(&(x + dx)) && (0..8).contains(&(y + dy)) {
+                let cost = if dx == 0 || dy == 0 { 10 } else { 14 };
+                moves.push(((x + dx, y + dy), cost));
+            }
+        }
+    }
+    moves
+}
+
+#[test]
+fn admissible_on_board() {
+    let goal = (6, 2);
+    let costs = dijkstra_all(&goal, king_moves);
+    let octile = octile(goal, 10, 14);
+    let euclidean = integer_euclidean(goal);
+    let chebyshev = chebyshev(goal);
+    for (node, (_, cost)) in costs {
+        // The board is symmetric, so distances from the goal are also distances
+        // to the goal.
+        assert_eq!(octile(&node), cost);
+        assert!(euclidean(&node) * 10 <= cost);
+        assert!(chebyshev(&node) * 10 <= cost);
+        let path = astar(&node, king_moves, &octile, |&n| n == goal);
+        assert_eq!(path.map(|(_, c)| c), Some(cost));
+    }
+}
+
+#[test]
+fn manhattan_on_grid() {
+    let mut grid = Grid::new(10, 10);
+    grid.fill();
+    for y in 1..9 {
+        grid.remove_vertex((5, y));
+    }
+    let goal = (9, 5);
+    for start in [(0, 0), (0, 5), (4, 4)] {
+        let expected = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal).unwrap();
+        let (path, cost) = astar(
+            &start,
+            |&n| grid.neighbours(n).into_iter().map(|n| (n, 1)),
+            manhattan(goal),
+            |&n| n == goal,
+        )
+        .unwrap();
+        assert_eq!(path.len(), expected.len());
+        assert_eq!(cost, path.len() - 1);
+    }
+}
@@ -0,0 +1,105 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_grid(seed: u8, diagonal: bool) -> Grid {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut grid = Grid::new(37, 29);
+    grid.fill();
+    if diagonal {
+        grid.enable_diagonal_mode();
+    }
+    for _ in 0..300 {
+        grid.remove_vertex((rng.random_range(0..37), rng.random_range(0..29)));
+    }
+    grid
+}
+
+fn check(grid: &Grid, cluster_size: usize) {
+    let hpa = HierarchicalGrid::new(grid, cluster_size);
+    let vertices = grid.iter().step_by(23).collect::<Vec<_>>();
+    for &start in &vertices {
+        for &goal in &vertices {
+            let optimal = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal);
+            let found = hpa.find_path(start, goal);
+            assert_eq!(optimal.is_some(), found.is_some(), "{start:?} -> {goal:?}");
+            let (Some(optimal), Some((path, cost))) = (optimal, found) else {
+                continue;
+            };
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            assert_eq!(cost, path.len() - 1);
+            assert!(cost >= optimal.len() - 1);
+            assert!(
+                path.windows(2).all(|w| grid.has_edge(w[0], w[1])),
+                "invalid path {path:?}"
+            );
+            let (waypoints, abstract_cost) = hpa.abstract_path(start, goal).unwrap();
+            assert_eq!(abstract_cost, cost);
+            assert!(waypoints.iter().all(|w| path.contains(w)));
+        }
+    }
+}
+
+#[test]
+fn random_grids() {
+    for seed in 1..3 {
+        check(&random_grid(seed, false), 8);
+        check(&random_grid(seed, true), 5);
+    }
+}
+
+#[test]
+fn diagonal_crossings() {
+    // The wall between the two clusters can only be crossed diagonally.
+    let mut grid = Grid::new(8, 4);
+    grid.fill();
+    grid.enable_diagonal_mode();
+    for y in 0..4 {
+        if y != 1 {
+            grid.remove_vertex((3, y));
+        }
+        if y != 2 {
+            grid.remove_vertex((4, y));
+        }
+    }
+    let hpa = HierarchicalGrid::new(&grid, 4);
+    assert_eq!(hpa.find_path((0, 0), (7, 3)).map(|(_, c)| c), Some(7));
+    check(&grid, 4);
+    // Four clusters meeting at a point only reachable diagonally.
+    let mut grid = Grid::new(8, 8);
+    grid.fill();
+    grid.enable_diagonal_mode();
+    for i in 0..8 {
+        for v in [(3, i), (4, i), (i, 3), (i, 4)] {
+            grid.remove_vertex(v);
+        }
+    }
+    grid.add_vertex((3, 3));
+    grid.add_vertex((4, 4));
+    let hpa = HierarchicalGrid::new(&grid, 4);
+    assert_eq!(hpa.find_path((0, 0), (7, 7)).map(|(_, c)| c), Some(7));
+    assert_eq!(hpa.find_path((0, 7), (7, 0)), None);
+    check(&grid, 4);
+}
+
+#[test]
+fn empty_grid_and_missing_vertices() {
+    let mut grid = Grid::new(10, 10);
+    let hpa = HierarchicalGrid::new(&grid, 4);
+    assert_eq!(hpa.transitions_len(), 0);
+    assert_eq!(hpa.find_path((0, 0), (1, 1)), None);
+    grid.fill();
+    grid.remove_vertex((5, 5));
+    let hpa = HierarchicalGrid::new(&grid, 4);
+    assert_eq!(hpa.cluster((5, 9)), (1, 2));
+    assert_eq!(hpa.find_path((0, 0), (5, 5)), None);
+    assert_eq!(hpa.find_path((3, 3), (3, 3)), Some((vec![(3, 3)], 0)));
+    assert_eq!(hpa.find_path((2, 0), (2, 9)).map(|(_, c)| c), Some(9));
+}
+
+#[test]
+#[should_panic(expected = "cluster size must be positive")]
+fn zero_cluster_size() {
+    let _ = HierarchicalGrid::new(&Grid::new(3, 3), 0);
+}
@@ -0,0 +1,92 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 80;
+
+fn build_network(seed: u8) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 12) {
+                network[(a, b)] = rng.random_range(1..50);
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl Fn(&usize) -> Vec<(usize, u32)> + '_ {
+    move |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+fn check(labels: &HubLabels<usize, u32>, network: &Matrix<u32>) {
+    for start in 0..SIZE {
+        let distances = dijkstra_all(&start, successors(network));
+        for goal in 0..SIZE {
+            let expected = if start == goal {
+                Some(0)
+            } else {
+                distances.get(&goal).map(|&(_, c)| c)
+            };
+            assert_eq!(
+                labels.distance(&start, &goal),
+                expected,
+                "{start} -> {goal}"
+            );
+        }
+    }
+}
+
+#[test]
+fn contraction_order() {
+    for seed in 1..4 {
+        let network = build_network(seed);
+        let nodes = (0..SIZE).collect::<Vec<_>>();
+        let labels = HubLabels::new(&nodes, successors(&network));
+        check(&labels, &network);
+        assert!(labels.labels_size() < 2 * SIZE * SIZE);
+    }
+}
+
+#[test]
+fn contraction_order_on_a_line() {
+    // Processing a line from one end gives quadratic labels, whereas contracting it
+    // gives each node a logarithmic number of hubs.
+    let nodes = (0..64).collect::<Vec<_>>();
+    let successors = |&n: &u32| {
+        [n.checked_sub(1), Some(n + 1)]
+            .into_iter()
+            .flatten()
+            .map(|m| (m, 1))
+    };
+    let contracted = HubLabels::new(&nodes, successors);
+    let in_line = HubLabels::with_order(&nodes, successors);
+    assert_eq!(in_line.labels_size(), 64 * 65);
+    assert!(contracted.labels_size() < 2 * 64 * 7);
+    assert_eq!(contracted.distance(&5, &60), Some(55));
+    assert_eq!(contracted.distance(&63, &0), Some(63));
+}
+
+#[test]
+fn given_order() {
+    let network = build_network(9);
+    let nodes = (0..SIZE).rev().collect::<Vec<_>>();
+    let labels = HubLabels::with_order(&nodes, successors(&network));
+    check(&labels, &network);
+}
+
+#[test]
+fn unknown_nodes() {
+    let labels = HubLabels::new(&[1, 2, 3], |&n: &u8| vec![(n + 1, 1)]);
+    assert_eq!(labels.distance(&1, &3), Some(2));
+    assert_eq!(labels.distance(&3, &1), None);
+    assert_eq!(labels.distance(&1, &4), None);
+    assert_eq!(labels.distance(&0, &1), None);
+}
@@ -0,0 +1,61 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn normalize(mut components: Vec<Vec<u32>>) -> Vec<Vec<u32>> {
+    for c in &mut components {
+        c.sort_unstable();
+    }
+    components.sort();
+    components
+}
+
+#[test]
+fn matches_batch_computation() {
+    const SIZE: u32 = 40;
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    let mut scc = IncrementalScc::new();
+    let mut edges = vec![Vec::new(); SIZE as usize];
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    for &n in &nodes {
+        assert!(scc.add_node(n));
+    }
+    assert!(!scc.add_node(3));
+    for _ in 0..120 {
+        let (a, b) = (rng.random_range(0..SIZE), rng.random_range(0..SIZE));
+        let before = scc.components_len();
+        let merged = scc.add_edge(a, b);
+        edges[a as usize].push(b);
+        let expected = normalize(strongly_connected_components(&nodes, |&n| {
+            edges[n as usize].clone()
+        }));
+        assert_eq!(normalize(scc.components()), expected);
+        assert_eq!(scc.components_len(), expected.len());
+        assert_eq!(merged, scc.components_len() < before);
+        assert!(scc.same_component(&a, &b) || !merged);
+    }
+    assert_eq!(scc.len(), SIZE as usize);
+}
+
+#[test]
+fn chain_closing() {
+    let mut scc = IncrementalScc::new();
+    assert!(scc.is_empty());
+    for i in 0..10 {
+        assert!(!scc.add_edge(i, i + 1));
+    }
+    assert!(!scc.add_edge(3, 3));
+    assert!(!scc.add_edge(0, 5));
+    assert_eq!(scc.components_len(), 11);
+    assert!(scc.add_edge(7, 2));
+    assert_eq!(scc.components_len(), 6);
+    assert_eq!(
+        normalize(vec![scc.component(&4).unwrap()]),
+        vec![vec![2, 3, 4, 5, 6, 7]]
+    );
+    assert!(!scc.same_component(&1, &2));
+    assert!(scc.add_edge(10, 0));
+    assert_eq!(scc.components_len(), 1);
+    assert_eq!(scc.component(&11), None);
+    assert!(!scc.same_component(&1, &11));
+}
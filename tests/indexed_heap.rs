@@ -0,0 +1,105 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn decrease_key() {
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    let mut heap = IndexedHeap::new();
+    let mut reference = vec![None; 100];
+    for _ in 0..5000 {
+        if rng.random_ratio(3, 4) {
+            let index = rng.random_range(0..100);
+            let key = rng.random_range(0..1000u32);
+            let improved = reference[index].is_none_or(|k| key < k);
+            assert_eq!(heap.push(index, key), improved);
+            if improved {
+                reference[index] = Some(key);
+            }
+        } else {
+            let expected = reference.iter().flatten().min().copied();
+            let popped = heap.pop();
+            assert_eq!(popped.map(|(_, k)| k), expected);
+            if let Some((index, key)) = popped {
+                assert_eq!(reference[index], Some(key));
+                reference[index] = None;
+            }
+        }
+        assert_eq!(heap.len(), reference.iter().flatten().count());
+        for (index, key) in reference.iter().enumerate() {
+            assert_eq!(heap.key(index), key.as_ref());
+        }
+    }
+}
+
+#[test]
+fn clear() {
+    let mut heap = IndexedHeap::new();
+    heap.push(3, 'c');
+    heap.push(8, 'a');
+    heap.clear();
+    assert!(heap.is_empty());
+    assert!(!heap.contains(3));
+    assert!(heap.push(3, 'z'));
+    assert!(heap.contains(3));
+    assert_eq!(heap.pop(), Some((3, 'z')));
+}
+
+const SIZE: usize = 50;
+
+fn build_network(seed: u8) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 3) {
+                network[(a, b)] = rng.random_range(1..1000);
+            }
+        }
+    }
+    network
+}
+
+#[test]
+fn dijkstra_same_costs() {
+    for seed in 1..5 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        for goal in 0..SIZE {
+            let expected = dijkstra(&0, successors, |&n| n == goal);
+            let result = dijkstra_indexed(&0, successors, |&n| n == goal);
+            assert_eq!(result.as_ref().map(|r| r.1), expected.map(|r| r.1));
+            if let Some((path, cost)) = result {
+                let path_cost = path.windows(2).map(|w| network[(w[0], w[1])]).sum::<u32>();
+                assert_eq!(path_cost, cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn astar_same_costs() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    let mut grid = Grid::new(30, 30);
+    grid.fill();
+    for _ in 0..250 {
+        grid.remove_vertex((rng.random_range(0..30), rng.random_range(0..30)));
+    }
+    let goal: (usize, usize) = (29, 29);
+    for start in [(0, 0), (15, 3), (3, 22)] {
+        let successors = |&p: &(usize, usize)| {
+            grid.neighbours(p)
+                .into_iter()
+                .map(|n| (n, 1 + (n.0 * 7 + n.1 * 3) % 5))
+        };
+        let heuristic = |&(x, y): &(usize, usize)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+        let expected = astar(&start, successors, heuristic, |&p| p == goal);
+        let result = astar_indexed(&start, successors, heuristic, |&p| p == goal);
+        assert_eq!(result.map(|r| r.1), expected.map(|r| r.1));
+    }
+}
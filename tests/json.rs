@@ -0,0 +1,125 @@
+#![cfg(feature = "json")]
+
+use pathfinding::prelude::*;
+
+const GRID: &str = r#"{
+    "nodes": [
+        { "id": 0, "position": [0, 0] },
+        { "id": 1, "position": [1, 0] },
+        { "id": 2, "position": [1, 1] },
+        { "id": 3 }
+    ],
+    "edges": [
+        { "source": 0, "target": 1, "weight": 1.0 },
+        { "source": 1, "target": 2, "weight": 1.5 },
+        { "source": 0, "target": 2, "weight": 3.0 },
+        { "source": 2, "target": 3, "weight": 0.5 }
+    ]
+}"#;
+
+#[test]
+fn directed_by_default() {
+    let graph = JsonGraph::<u32, f64>::from_json(GRID).unwrap();
+    assert!(graph.directed);
+    assert_eq!(graph.nodes[1].position, Some((1.0, 0.0)));
+    assert_eq!(graph.nodes[3].position, None);
+    let successors = graph.successors();
+    assert_eq!(successors(&0), vec![(1, 1.0), (2, 3.0)]);
+    assert_eq!(successors(&3), vec![]);
+    assert_eq!(successors(&42), vec![]);
+}
+
+#[test]
+fn search_with_float_weights() {
+    let graph = JsonGraph::<u32, f64>::from_json(GRID).unwrap();
+    let successors = graph.successors();
+    let successors = |n: &u32| {
+        successors(n)
+            .into_iter()
+            .map(|(n, c)| (n, TotalCost::new(c)))
+    };
+    let heuristic = graph.heuristic(&2, |(x1, y1), (x2, y2)| (x1 - x2).hypot(y1 - y2));
+    let heuristic = |n: &u32| TotalCost::new(heuristic(n));
+    assert_eq!(heuristic(&0), TotalCost::new(2f64.sqrt()));
+    assert_eq!(heuristic(&3), TotalCost::new(0.0));
+    let (path, cost) = astar(&0, successors, heuristic, |&n| n == 2).unwrap();
+    assert_eq!(path, vec![0, 1, 2]);
+    assert_eq!(cost, TotalCost::new(2.5));
+    assert_eq!(dijkstra(&3, successors, |&n| n == 0), None);
+}
+
+#[test]
+fn undirected() {
+    let input = r#"{"directed": false, "nodes": [{"id": "a"}, {"id": "b"}, {"id": "c"}],
+                    "edges": [{"source": "a", "target": "b", "weight": 2},
+                              {"source": "c", "target": "b", "weight": 5},
+                              {"source": "c", "target": "c", "weight": 1}]}"#;
+    let graph = JsonGraph::<String, u8>::from_reader(input.as_bytes()).unwrap();
+    let successors = graph.successors();
+    drop(graph);
+    assert_eq!(
+        successors(&"b".into()),
+        vec![("a".into(), 2), ("c".into(), 5)]
+    );
+    assert_eq!(
+        successors(&"c".into()),
+        vec![("b".into(), 5), ("c".into(), 1)]
+    );
+    let (path, cost) = dijkstra(&"c".to_string(), |n| successors(n), |n| n == "a").unwrap();
+    assert_eq!(path, ["c", "b", "a"]);
+    assert_eq!(cost, 7);
+}
+
+#[test]
+fn round_trip() {
+    let graph = JsonGraph {
+        directed: false,
+        nodes: vec![
+            JsonNode {
+                id: (0, 0),
+                position: Some((0.5, -2.0)),
+            },
+            JsonNode {
+                id: (0, 1),
+                position: None,
+            },
+        ],
+        edges: vec![JsonEdge {
+            source: (0, 0),
+            target: (0, 1),
+            weight: -3i64,
+        }],
+    };
+    let json = graph.to_json().unwrap();
+    assert!(!json.contains("null"));
+    assert_eq!(JsonGraph::from_json(&json).unwrap(), graph);
+}
+
+#[test]
+fn errors() {
+    fn error(input: &str) -> JsonGraphError {
+        JsonGraph::<String, u32>::from_json(input).unwrap_err()
+    }
+    assert!(matches!(error("{"), JsonGraphError::Json(_)));
+    assert!(matches!(error(r#"{"nodes": []}"#), JsonGraphError::Json(_)));
+    assert!(matches!(
+        error(r#"{"nodes": [], "edges": [{"source": "a", "target": "b"}]}"#),
+        JsonGraphError::Json(_)
+    ));
+    assert!(matches!(
+        error(
+            r#"{"nodes": [{"id": "a"}], "edges": [{"source": "a", "target": "b", "weight": -1}]}"#
+        ),
+        JsonGraphError::Json(_)
+    ));
+    assert!(matches!(
+        error(r#"{"nodes": [{"id": "a"}, {"id": "b"}, {"id": "a"}], "edges": []}"#),
+        JsonGraphError::DuplicateNode(2)
+    ));
+    let unknown = error(
+        r#"{"nodes": [{"id": "a"}], "edges": [{"source": "a", "target": "a", "weight": 1},
+                                              {"source": "a", "target": "b", "weight": 1}]}"#,
+    );
+    assert!(matches!(unknown, JsonGraphError::UnknownNode(1)));
+    assert_eq!(unknown.to_string(), "edge 1 refers to an unknown node");
+}
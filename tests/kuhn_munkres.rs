@@ -1,5 +1,7 @@
 use pathfinding::kuhn_munkres::*;
 use pathfinding::{matrix, matrix::Matrix};
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
 
 #[test]
 fn tryalgo_examples() {
@@ -87,7 +89,7 @@ fn non_square() {
 fn empty() {
     let (total, assignments) = kuhn_munkres(&Matrix::<i32>::new_empty(0));
     assert_eq!(total, 0);
-    assert_eq!(assignments, vec![]);
+    assert_eq!(assignments, Vec::<usize>::new());
 }
 
 #[test]
@@ -95,3 +97,87 @@ fn empty() {
 fn unbalanced() {
     kuhn_munkres(&Matrix::new(3, 2, 0));
 }
+
+fn random_weights(rng: &mut XorShiftRng, rows: usize, columns: usize) -> Matrix<i64> {
+    Matrix::from_fn(rows, columns, |_| rng.random_range(-100..100))
+}
+
+#[test]
+fn duals_certify_optimality() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let rows = rng.random_range(1..8);
+        let columns = rows + rng.random_range(0..3);
+        let weights = random_weights(&mut rng, rows, columns);
+        for (solution, (total, assignments), sign) in [
+            (
+                kuhn_munkres_with_duals(&weights, None),
+                kuhn_munkres(&weights),
+                1,
+            ),
+            (
+                kuhn_munkres_min_with_duals(&weights, None),
+                kuhn_munkres_min(&weights),
+                -1,
+            ),
+        ] {
+            assert_eq!(solution.total(), total);
+            assert_eq!(
+                solution
+                    .assignments()
+                    .iter()
+                    .enumerate()
+                    .map(|(r, &c)| weights[(r, c)])
+                    .sum::<i64>(),
+                total
+            );
+            assert_eq!(solution.assignments().len(), assignments.len());
+            for row in 0..weights.rows {
+                for column in 0..weights.columns {
+                    let slack = solution.slack(&weights, row, column);
+                    assert!(slack >= 0);
+                    assert_eq!(
+                        slack,
+                        sign * (solution.row_labels()[row] + solution.column_labels()[column]
+                            - weights[(row, column)])
+                    );
+                }
+                assert_eq!(
+                    solution.slack(&weights, row, solution.assignments()[row]),
+                    0
+                );
+            }
+            if weights.rows == weights.columns {
+                let labels = solution.row_labels().iter().sum::<i64>()
+                    + solution.column_labels().iter().sum::<i64>();
+                assert_eq!(labels, total);
+            }
+        }
+    }
+}
+
+#[test]
+fn warm_start() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    for _ in 0..100 {
+        let size = rng.random_range(1..8);
+        let mut weights = random_weights(&mut rng, size, size);
+        let solution = kuhn_munkres_with_duals(&weights, None);
+        let min_solution = kuhn_munkres_min_with_duals(&weights, None);
+        for _ in 0..3 {
+            let (row, column) = (rng.random_range(0..size), rng.random_range(0..size));
+            weights[(row, column)] += rng.random_range(-50..50);
+        }
+        let warm = kuhn_munkres_with_duals(&weights, Some(solution.column_labels()));
+        assert_eq!(warm.total(), kuhn_munkres(&weights).0);
+        let warm = kuhn_munkres_min_with_duals(&weights, Some(min_solution.column_labels()));
+        assert_eq!(warm.total(), kuhn_munkres_min(&weights).0);
+        assert!((0..size).all(|row| warm.slack(&weights, row, warm.assignments()[row]) == 0));
+    }
+}
+
+#[test]
+#[should_panic(expected = "column labels can only be given for square weights, one per column")]
+fn warm_start_non_square() {
+    kuhn_munkres_with_duals(&Matrix::new(2, 3, 0), Some(&[0, 0, 0]));
+}
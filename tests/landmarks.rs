@@ -0,0 +1,97 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 60;
+
+fn build_network() -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 8) {
+                network[(a, b)] = rng.random_range(1..100);
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl Fn(&usize) -> Vec<(usize, u32)> + '_ {
+    move |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+fn predecessors(network: &Matrix<u32>) -> impl Fn(&usize) -> Vec<(usize, u32)> + '_ {
+    move |&b| {
+        (0..SIZE)
+            .filter(|&a| network[(a, b)] != 0)
+            .map(|a| (a, network[(a, b)]))
+            .collect()
+    }
+}
+
+#[test]
+fn admissible() {
+    let network = build_network();
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    let landmarks = Landmarks::new(&nodes, 5, successors(&network), predecessors(&network));
+    assert_eq!(landmarks.landmarks().len(), 5);
+    for start in 0..SIZE {
+        let distances = dijkstra_all(&start, successors(&network));
+        for goal in 0..SIZE {
+            if let Some(&(_, cost)) = distances.get(&goal) {
+                assert!(
+                    landmarks.estimate(&start, &goal) <= cost,
+                    "{start} -> {goal}"
+                );
+            }
+        }
+        assert_eq!(landmarks.estimate(&start, &start), 0);
+    }
+}
+
+#[test]
+fn astar_with_landmarks() {
+    let network = build_network();
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    let landmarks = Landmarks::new(&nodes, 4, successors(&network), predecessors(&network));
+    for goal in 0..SIZE {
+        let expected = dijkstra(&0, successors(&network), |&n| n == goal).map(|(_, c)| c);
+        let result = astar(&0, successors(&network), landmarks.heuristic(&goal), |&n| {
+            n == goal
+        })
+        .map(|(_, c)| c);
+        assert_eq!(result, expected, "0 -> {goal}");
+    }
+}
+
+#[test]
+fn exact_on_line() {
+    // On a line, a landmark at one end gives the exact distance.
+    let successors = |&n: &i32| vec![(n - 1, 1), (n + 1, 1)];
+    let nodes = (0..=20).collect::<Vec<_>>();
+    let bounded = |&n: &i32| {
+        successors(&n)
+            .into_iter()
+            .filter(|(m, _)| (0..=20).contains(m))
+            .collect::<Vec<_>>()
+    };
+    let landmarks = Landmarks::with_landmarks(&[0], bounded, bounded);
+    assert_eq!(landmarks.landmarks(), &[0]);
+    assert_eq!(landmarks.estimate(&3, &17), 14);
+    assert_eq!(landmarks.estimate(&17, &3), 14);
+    let selected = Landmarks::new(&nodes, 2, bounded, bounded);
+    assert_eq!(selected.landmarks(), &[20, 0]);
+}
+
+#[test]
+fn empty() {
+    let landmarks = Landmarks::<u8, u32>::new(&[], 3, |_| vec![], |_| vec![]);
+    assert!(landmarks.landmarks().is_empty());
+    assert_eq!(landmarks.estimate(&1, &2), 0);
+}
@@ -841,3 +841,97 @@ fn is_empty() {
     let m: Matrix<i32> = matrix![];
     assert!(m.is_empty());
 }
+
+#[test]
+fn terrain_path() {
+    use pathfinding::directed::dijkstra::dijkstra;
+    use pathfinding::matrix::TerrainMoves;
+    use rand::{Rng as _, SeedableRng as _};
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..50 {
+        let terrain = &Matrix::from_fn(12, 15, |_| rng.random_range(1..10u32));
+        let start = (rng.random_range(0..12), rng.random_range(0..15));
+        let goal = (rng.random_range(0..12), rng.random_range(0..15));
+        for (moves, straight, diagonal) in [
+            (TerrainMoves::Orthogonal, 1, None),
+            (
+                TerrainMoves::Octile {
+                    straight: 5,
+                    diagonal: 7,
+                },
+                5,
+                Some(7),
+            ),
+            (
+                TerrainMoves::Octile {
+                    straight: 1,
+                    diagonal: 10,
+                },
+                1,
+                Some(10),
+            ),
+            (
+                TerrainMoves::Octile {
+                    straight: 7,
+                    diagonal: 5,
+                },
+                7,
+                Some(5),
+            ),
+        ] {
+            let (path, cost) = terrain.terrain_path(start, goal, moves).unwrap();
+            assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+            let mut total = 0;
+            for w in path.windows(2) {
+                let (dr, dc) = (w[0].0.abs_diff(w[1].0), w[0].1.abs_diff(w[1].1));
+                total += terrain[w[1]]
+                    * match (dr, dc) {
+                        (0, 1) | (1, 0) => straight,
+                        (1, 1) => diagonal.unwrap(),
+                        _ => panic!("invalid move from {:?} to {:?}", w[0], w[1]),
+                    };
+            }
+            assert_eq!(total, cost);
+            let expected = dijkstra(
+                &start,
+                |&n| {
+                    terrain.neighbours(n, diagonal.is_some()).map(move |m| {
+                        let weight = if m.0 == n.0 || m.1 == n.1 {
+                            straight
+                        } else {
+                            diagonal.unwrap()
+                        };
+                        (m, terrain[m] * weight)
+                    })
+                },
+                |&n| n == goal,
+            );
+            assert_eq!(Some(cost), expected.map(|(_, c)| c));
+        }
+    }
+    // Diagonal moves more expensive than two straight ones.
+    let terrain = Matrix::from_vec(3, 2, vec![3, 4, 2, 2, 3, 4]).unwrap();
+    let moves = TerrainMoves::Octile {
+        straight: 1,
+        diagonal: 10,
+    };
+    assert_eq!(
+        terrain.terrain_path((0, 0), (2, 1), moves),
+        Some((vec![(0, 0), (1, 0), (1, 1), (2, 1)], 8))
+    );
+    let terrain = Matrix::new(2, 3, 1u32);
+    assert_eq!(
+        terrain.terrain_path((1, 1), (1, 1), TerrainMoves::Orthogonal),
+        Some((vec![(1, 1)], 0))
+    );
+    assert_eq!(
+        terrain.terrain_path((0, 0), (2, 0), TerrainMoves::Orthogonal),
+        None
+    );
+    assert_eq!(
+        terrain.terrain_path((0, 3), (0, 0), TerrainMoves::Orthogonal),
+        None
+    );
+}
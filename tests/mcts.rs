@@ -0,0 +1,136 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+use std::cell::RefCell;
+use std::time::Duration;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn nim_actions(&sticks: &u32) -> Vec<u32> {
+    (1..=sticks.min(3)).collect()
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+const fn nim_apply(&sticks: &u32, &taken: &u32) -> u32 {
+    sticks - taken
+}
+
+#[test]
+fn nim_random_rollouts() {
+    let rng = RefCell::new(XorShiftRng::seed_from_u64(0));
+    // Both players take a random number of sticks until none is left.
+    let rollout = |&sticks: &u32| {
+        let mut rng = rng.borrow_mut();
+        let (mut sticks, mut moves) = (sticks, 0);
+        while sticks > 0 {
+            sticks -= rng.random_range(1..=sticks.min(3));
+            moves += 1;
+        }
+        if moves % 2 == 0 { 1.0 } else { -1.0 }
+    };
+    for sticks in 5..=13 {
+        if sticks % 4 == 0 {
+            continue;
+        }
+        let mut mcts = Mcts::new(sticks);
+        mcts.set_alternating(true);
+        assert_eq!(
+            mcts.search(
+                MctsBudget::Iterations(20_000),
+                nim_actions,
+                nim_apply,
+                rollout
+            ),
+            20_000
+        );
+        assert_eq!(mcts.iterations(), 20_000);
+        assert_eq!(
+            mcts.best_action(),
+            Some(&(sticks % 4)),
+            "from {sticks} sticks"
+        );
+        let stats = mcts.action_stats().collect::<Vec<_>>();
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats.iter().map(|&(_, v, _)| v).sum::<usize>(), 20_000);
+        let &(_, _, best) = stats.iter().find(|&&(&a, _, _)| a == sticks % 4).unwrap();
+        assert!(stats.iter().all(|&(_, _, mean)| mean <= best));
+    }
+}
+
+#[test]
+fn stochastic_bandit() {
+    // Every arm succeeds with a different probability, and the reward is 1 on success.
+    let probabilities = [0.2, 0.7, 0.5, 0.6];
+    let rng = RefCell::new(XorShiftRng::seed_from_u64(1));
+    let mut mcts = Mcts::new(None);
+    mcts.search(
+        MctsBudget::Iterations(5000),
+        |state: &Option<bool>| {
+            if state.is_none() {
+                vec![0, 1, 2, 3]
+            } else {
+                vec![]
+            }
+        },
+        |_, &arm: &usize| Some(rng.borrow_mut().random_bool(probabilities[arm])),
+        |&state| if state == Some(true) { 1.0 } else { 0.0 },
+    );
+    assert_eq!(mcts.best_action(), Some(&1));
+    for (&arm, visits, mean) in mcts.action_stats() {
+        assert!(visits > 0);
+        assert!((mean - probabilities[arm]).abs() < 0.2, "arm {arm}: {mean}");
+    }
+}
+
+#[test]
+fn advance() {
+    let rollout = |&sticks: &u32| if sticks % 4 == 0 { 1.0 } else { -1.0 };
+    let mut mcts = Mcts::new(9);
+    mcts.set_alternating(true);
+    mcts.search(
+        MctsBudget::Iterations(2000),
+        nim_actions,
+        nim_apply,
+        rollout,
+    );
+    assert_eq!(mcts.best_action(), Some(&1));
+    let visits = mcts
+        .action_stats()
+        .find(|&(&a, _, _)| a == 1)
+        .map(|(_, v, _)| v);
+    mcts.advance(&1, 8);
+    assert_eq!(mcts.root(), &8);
+    assert_eq!(Some(mcts.iterations()), visits);
+    for taken in 1..=3 {
+        let mut mcts = mcts.clone();
+        mcts.advance(&taken, 8 - taken);
+        mcts.search(
+            MctsBudget::Iterations(2000),
+            nim_actions,
+            nim_apply,
+            rollout,
+        );
+        assert_eq!(mcts.best_action(), Some(&(4 - taken)));
+    }
+    // The root has no action, and advancing creates a new tree.
+    let mut mcts = Mcts::new(2);
+    mcts.advance(&2, 0);
+    assert_eq!((mcts.root(), mcts.iterations()), (&0, 0));
+    mcts.search(MctsBudget::Iterations(10), nim_actions, nim_apply, rollout);
+    assert_eq!(mcts.iterations(), 10);
+    assert_eq!(mcts.best_action(), None);
+}
+
+#[test]
+fn time_budget() {
+    let mut mcts = Mcts::new(20);
+    mcts.set_exploration(0.5);
+    assert!((mcts.exploration() - 0.5).abs() < f64::EPSILON);
+    let iterations = mcts.search(
+        MctsBudget::Time(Duration::from_millis(20)),
+        nim_actions,
+        nim_apply,
+        |_| 0.0,
+    );
+    assert!(iterations > 0);
+    assert_eq!(mcts.iterations(), iterations);
+}
@@ -0,0 +1,96 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 8;
+
+fn build_network(seed: u8) -> Matrix<Option<i64>> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, None);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if rng.random_ratio(if a == b { 1 } else { 3 }, 14) {
+                network[(a, b)] = Some(rng.random_range(-20..40));
+            }
+        }
+    }
+    network
+}
+
+fn cycle_cost(network: &Matrix<Option<i64>>, cycle: &[usize]) -> i64 {
+    (0..cycle.len())
+        .map(|i| network[(cycle[i], cycle[(i + 1) % cycle.len()])].unwrap())
+        .sum()
+}
+
+#[test]
+fn matches_circuits() {
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    for seed in 1..40 {
+        let network = build_network(seed);
+        let neighbours = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)].is_some())
+                .collect::<Vec<_>>()
+        };
+        let best = elementary_circuits(&nodes, neighbours)
+            .map(|c| (cycle_cost(&network, &c), i64::try_from(c.len()).unwrap()))
+            .min_by(|&(a, b), &(c, d)| (a * d).cmp(&(c * b)));
+        let result = min_mean_cycle(&nodes, |&a| {
+            (0..SIZE)
+                .filter_map(|b| network[(a, b)].map(|c| (b, c)))
+                .collect::<Vec<_>>()
+        });
+        match (best, result) {
+            (None, None) => (),
+            (Some((cost, len)), Some((cycle, result_cost, result_len))) => {
+                let result_len = i64::try_from(result_len).unwrap();
+                assert_eq!(cost * result_len, result_cost * len);
+                assert_eq!(i64::try_from(cycle.len()).unwrap(), result_len);
+                assert_eq!(cycle_cost(&network, &cycle), result_cost);
+                let mut nodes = cycle.clone();
+                nodes.sort_unstable();
+                nodes.dedup();
+                assert_eq!(nodes.len(), cycle.len());
+                assert_eq!(Some(&cycle[0]), nodes.first());
+            }
+            (expected, result) => panic!("expected {expected:?}, got {result:?}"),
+        }
+    }
+}
+
+#[test]
+fn acyclic() {
+    let nodes = (0..20).collect::<Vec<_>>();
+    assert_eq!(
+        min_mean_cycle(&nodes, |&n: &u32| (n + 1..20).map(move |m| (m, n * m))),
+        None
+    );
+}
+
+#[test]
+fn self_loop() {
+    // The self loop on 2 is better than the negative cycle between 0 and 1.
+    let successors = |&n: &u8| match n {
+        0 => vec![(1, -3)],
+        1 => vec![(0, -1), (2, 0)],
+        2 => vec![(2, -5)],
+        _ => vec![],
+    };
+    assert_eq!(min_mean_cycle(&[0], successors), Some((vec![2], -5, 1)));
+}
+
+#[test]
+fn long_cycle() {
+    // A ring of 100 nodes with a cheap edge is better than a shortcut.
+    let successors = |&n: &u32| {
+        let mut next = vec![((n + 1) % 100, if n == 50 { -250 } else { 2 })];
+        if n == 10 {
+            next.push((0, 0));
+        }
+        next
+    };
+    let (cycle, cost, len) = min_mean_cycle(&[0], successors).unwrap();
+    assert_eq!((cost, len), (-52, 100));
+    assert_eq!(cycle, (0..100).collect::<Vec<_>>());
+}
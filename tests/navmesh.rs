@@ -0,0 +1,174 @@
+use pathfinding::prelude::*;
+
+/// Build a mesh made of unit squares for every `#` of the map.
+#[expect(clippy::cast_precision_loss)]
+fn squares(map: &[&str]) -> NavMesh {
+    let (width, height) = (map[0].len(), map.len());
+    let index = |x: usize, y: usize| y * (width + 1) + x;
+    let vertices = (0..=height)
+        .flat_map(|y| (0..=width).map(move |x| (x as f64, y as f64)))
+        .collect();
+    let polygons = map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| {
+                    vec![
+                        index(x, y),
+                        index(x + 1, y),
+                        index(x + 1, y + 1),
+                        index(x, y + 1),
+                    ]
+                })
+        })
+        .collect();
+    NavMesh::new(vertices, polygons).unwrap()
+}
+
+fn length(path: &[(f64, f64)]) -> f64 {
+    path.windows(2)
+        .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+        .sum()
+}
+
+fn assert_inside(mesh: &NavMesh, path: &[(f64, f64)]) {
+    for w in path.windows(2) {
+        for i in 0..=100 {
+            let t = f64::from(i) / 100.0;
+            let p = (
+                w[0].0 + t * (w[1].0 - w[0].0),
+                w[0].1 + t * (w[1].1 - w[0].1),
+            );
+            assert!(mesh.polygon_at(p).is_some(), "{p:?} is outside of the mesh");
+        }
+    }
+}
+
+#[test]
+fn same_polygon() {
+    let mesh = squares(&["#"]);
+    assert_eq!(
+        mesh.find_path((0.1, 0.2), (0.9, 0.8)),
+        Some(vec![(0.1, 0.2), (0.9, 0.8)])
+    );
+}
+
+#[test]
+fn straight_corridor() {
+    let mesh = squares(&["#####"]);
+    let path = mesh.find_path((0.5, 0.5), (4.5, 0.2)).unwrap();
+    assert_eq!(path, vec![(0.5, 0.5), (4.5, 0.2)]);
+}
+
+#[test]
+fn u_turn() {
+    let mesh = squares(&["###", "..#", "###"]);
+    let path = mesh.find_path((0.5, 0.5), (0.5, 2.5)).unwrap();
+    assert_eq!(path, vec![(0.5, 0.5), (2.0, 1.0), (2.0, 2.0), (0.5, 2.5)]);
+    assert_inside(&mesh, &path);
+}
+
+#[test]
+fn maze() {
+    let map = [
+        "#########",
+        "#.....#.#",
+        "#.###.#.#",
+        "#.#...#.#",
+        "###.#####",
+        "#...#...#",
+        "#.###.#.#",
+        "#.....###",
+    ];
+    let mesh = squares(&map);
+    for &(start, goal) in &[
+        ((0.5, 0.5), (8.5, 3.5)),
+        ((7.5, 7.5), (0.5, 7.5)),
+        ((2.5, 3.5), (4.5, 5.5)),
+    ] {
+        let path = mesh.find_path(start, goal).unwrap();
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+        assert_inside(&mesh, &path);
+        // The path must be at least as short as the one going through the corridor
+        // polygon centers.
+        let corridor = mesh
+            .corridor(
+                mesh.polygon_at(start).unwrap(),
+                mesh.polygon_at(goal).unwrap(),
+            )
+            .unwrap();
+        let mut centers = vec![start];
+        centers.extend(corridor[1..corridor.len() - 1].iter().map(|&p| {
+            let v = mesh.polygons()[p][0];
+            let (x, y) = mesh.vertices()[v];
+            (x + 0.5, y + 0.5)
+        }));
+        centers.push(goal);
+        assert!(length(&path) <= length(&centers) + 1e-9);
+        assert_eq!(mesh.portals(&corridor).len(), corridor.len() - 1);
+    }
+}
+
+#[test]
+fn unreachable() {
+    let mesh = squares(&["#.#"]);
+    assert_eq!(mesh.find_path((0.5, 0.5), (2.5, 0.5)), None);
+    assert_eq!(mesh.find_path((0.5, 0.5), (1.5, 0.5)), None);
+    assert_eq!(mesh.find_path((0.5, 0.5), (-1.0, 0.5)), None);
+}
+
+#[test]
+fn neighbours() {
+    let mesh = squares(&["##", "#."]);
+    let mut neighbours = mesh.neighbours(0).collect::<Vec<_>>();
+    neighbours.sort_unstable();
+    assert_eq!(neighbours, vec![1, 2]);
+    assert_eq!(mesh.neighbours(1).collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+fn clockwise_polygons() {
+    let vertices = vec![
+        (0.0, 0.0),
+        (1.0, 0.0),
+        (1.0, 1.0),
+        (0.0, 1.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+    ];
+    let mesh = NavMesh::new(vertices, vec![vec![3, 2, 1, 0], vec![1, 4, 5, 2]]).unwrap();
+    assert_eq!(mesh.polygons()[0], vec![0, 1, 2, 3]);
+    assert_eq!(mesh.polygon_at((0.5, 0.5)), Some(0));
+    assert_eq!(
+        mesh.find_path((0.5, 0.5), (1.5, 0.5)),
+        Some(vec![(0.5, 0.5), (1.5, 0.5)])
+    );
+}
+
+#[test]
+fn invalid_meshes() {
+    let vertices = vec![(0.0, 0.0), (2.0, 0.0), (1.0, 0.5), (2.0, 2.0), (0.0, 2.0)];
+    assert_eq!(
+        NavMesh::new(vertices.clone(), vec![vec![0, 1]]).unwrap_err(),
+        NavMeshError::TooFewVertices(0)
+    );
+    assert_eq!(
+        NavMesh::new(vertices.clone(), vec![vec![0, 1, 3], vec![0, 1, 7]]).unwrap_err(),
+        NavMeshError::MissingVertex {
+            polygon: 1,
+            vertex: 7
+        }
+    );
+    assert_eq!(
+        NavMesh::new(vertices.clone(), vec![vec![0, 1, 2, 3, 4]]).unwrap_err(),
+        NavMeshError::NotConvex(0)
+    );
+    assert_eq!(
+        NavMesh::new(vertices, vec![vec![0, 2, 2]]).unwrap_err(),
+        NavMeshError::NotConvex(0)
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "rayon")]
+
+use pathfinding::prelude::*;
+use std::collections::HashSet;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+    [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+        .into_iter()
+        .filter(|&(x, y)| (0..60).contains(&x) && (0..60).contains(&y) && (x % 7 != 3 || y == 59))
+        .collect()
+}
+
+#[test]
+fn same_length_as_bfs() {
+    for goal in [(0, 0), (2, 40), (58, 0), (30, 30), (6, 58)] {
+        let expected = bfs(&(0, 0), successors, |&n| n == goal).unwrap();
+        let path = par_bfs(&(0, 0), successors, |&n| n == goal).unwrap();
+        assert_eq!(path.len(), expected.len());
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+        for w in path.windows(2) {
+            assert!(successors(&w[0]).contains(&w[1]));
+        }
+    }
+}
+
+#[test]
+fn unreachable() {
+    assert_eq!(par_bfs(&(0, 0), successors, |&n| n == (3, 3)), None);
+    assert_eq!(par_bfs(&(0, 0), successors, |&n| n == (100, 0)), None);
+}
+
+#[test]
+fn reach_in_layer_order() {
+    let nodes = par_bfs_reach((0, 0), successors);
+    let expected = bfs_reach((0, 0), successors).collect::<Vec<_>>();
+    assert_eq!(nodes.len(), expected.len());
+    assert_eq!(
+        nodes.iter().collect::<HashSet<_>>(),
+        expected.iter().collect::<HashSet<_>>()
+    );
+    let depth = |n: &(i32, i32)| bfs(&(0, 0), successors, |m| m == n).unwrap().len();
+    assert!(nodes.windows(2).all(|w| depth(&w[0]) <= depth(&w[1])));
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "rayon")]
+
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn same_paths_as_yen() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    for _ in 0..20 {
+        let edges = (0..40)
+            .map(|_| {
+                (0..rng.random_range(1..5))
+                    .map(|_| (rng.random_range(0..40), rng.random_range(1..10)))
+                    .collect::<Vec<(usize, u32)>>()
+            })
+            .collect::<Vec<_>>();
+        let goal = rng.random_range(1..40);
+        let successors = |&n: &usize| edges[n].clone();
+        assert_eq!(
+            par_yen(&0, successors, |&n| n == goal, 8),
+            yen(&0, successors, |&n| n == goal, 8)
+        );
+    }
+}
+
+#[test]
+fn no_path() {
+    let paths = par_yen(&'c', |_| Vec::<(char, u32)>::new(), |&c| c == 'h', 3);
+    assert!(paths.is_empty());
+}
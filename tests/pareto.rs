@@ -0,0 +1,138 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 9;
+
+fn build_network(seed: u8) -> Matrix<Option<(u32, u32)>> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, None);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 3) {
+                network[(a, b)] = Some((rng.random_range(1..20), rng.random_range(1..20)));
+            }
+        }
+    }
+    network
+}
+
+// Costs of all the simple paths from `node` to `goal`.
+fn all_costs(
+    network: &Matrix<Option<(u32, u32)>>,
+    node: usize,
+    goal: usize,
+    visited: &mut Vec<usize>,
+    costs: (u32, u32),
+    found: &mut Vec<(u32, u32)>,
+) {
+    if node == goal {
+        found.push(costs);
+        return;
+    }
+    visited.push(node);
+    for next in 0..SIZE {
+        if let Some((c1, c2)) = network[(node, next)] {
+            if !visited.contains(&next) {
+                all_costs(
+                    network,
+                    next,
+                    goal,
+                    visited,
+                    (costs.0 + c1, costs.1 + c2),
+                    found,
+                );
+            }
+        }
+    }
+    visited.pop();
+}
+
+#[test]
+fn matches_brute_force() {
+    for seed in 1..6 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter_map(|b| network[(a, b)].map(|c| (b, c)))
+                .collect::<Vec<_>>()
+        };
+        for goal in 1..SIZE {
+            let mut costs = Vec::new();
+            all_costs(&network, 0, goal, &mut Vec::new(), (0, 0), &mut costs);
+            let mut expected = costs
+                .iter()
+                .copied()
+                .filter(|&(a1, a2)| {
+                    !costs
+                        .iter()
+                        .any(|&(b1, b2)| b1 <= a1 && b2 <= a2 && (b1, b2) != (a1, a2))
+                })
+                .collect::<Vec<_>>();
+            expected.sort_unstable();
+            expected.dedup();
+            let front = pareto_paths(&0, successors, |&n| n == goal).collect::<Vec<_>>();
+            assert_eq!(
+                front.iter().map(|(_, c)| *c).collect::<Vec<_>>(),
+                expected,
+                "front from 0 to {goal} with seed {seed}"
+            );
+            for (path, (c1, c2)) in front {
+                assert_eq!(path.first(), Some(&0));
+                assert_eq!(path.last(), Some(&goal));
+                let sum = path
+                    .windows(2)
+                    .map(|w| network[(w[0], w[1])].unwrap())
+                    .fold((0, 0), |(a, b), (c, d)| (a + c, b + d));
+                assert_eq!(sum, (c1, c2));
+            }
+        }
+    }
+}
+
+#[test]
+fn first_path_is_shortest_for_first_cost() {
+    let network = build_network(7);
+    let successors = |&a: &usize| {
+        (0..SIZE)
+            .filter_map(|b| network[(a, b)].map(|c| (b, c)))
+            .collect::<Vec<_>>()
+    };
+    for goal in 1..SIZE {
+        let first = pareto_paths(&0, successors, |&n| n == goal).next();
+        let expected = dijkstra(
+            &0,
+            |n| successors(n).into_iter().map(|(m, (c1, _))| (m, c1)),
+            |&n| n == goal,
+        );
+        assert_eq!(first.map(|(_, (c1, _))| c1), expected.map(|(_, c)| c));
+    }
+}
+
+#[test]
+fn single_objective_when_costs_agree() {
+    // When both costs are proportional, the front contains a single path.
+    let successors = |&n: &u32| {
+        [(n + 1, 1), (n * 2, 3)]
+            .into_iter()
+            .filter(|&(m, _)| m <= 40)
+            .map(|(m, c)| (m, (c, 2 * c)))
+    };
+    let front = pareto_paths(&1, successors, |&n| n == 37).collect::<Vec<_>>();
+    assert_eq!(front.len(), 1);
+    let (_, cost) = dijkstra(
+        &1,
+        |&n| successors(&n).map(|(m, (c, _))| (m, c)),
+        |&n| n == 37,
+    )
+    .unwrap();
+    assert_eq!(front[0].1, (cost, 2 * cost));
+}
+
+#[test]
+fn unreachable_goal() {
+    assert_eq!(
+        pareto_paths(&0u8, |&n| vec![(n / 2, (1u8, 1u8))], |&n| n == 3).count(),
+        0
+    );
+}
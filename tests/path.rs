@@ -0,0 +1,110 @@
+use pathfinding::prelude::*;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    vec![(n + 1, 1), (n * 2, 3)]
+}
+
+#[test]
+fn build_and_combine() {
+    let path = Path::from_steps(1, [(2, 5), (3, 7)]);
+    assert_eq!(path.start(), &1);
+    assert_eq!(path.end(), &3);
+    assert_eq!(path.steps(), 2);
+    assert_eq!(path.total_cost(), 12);
+    assert_eq!(
+        path.edges().rev().collect::<Vec<_>>(),
+        vec![(&2, &3, 7), (&1, &2, 5)]
+    );
+    let reversed = path.clone().reversed();
+    assert_eq!(reversed.nodes(), &[3, 2, 1]);
+    assert_eq!(reversed.step_costs(), &[7, 5]);
+    assert_eq!(reversed.total_cost(), 12);
+    let full = path.concat(reversed);
+    assert_eq!(
+        full.iter().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 2, 1]
+    );
+    assert_eq!(full.total_cost(), 24);
+    assert_eq!(<(Vec<_>, _)>::from(full), (vec![1, 2, 3, 2, 1], 24));
+}
+
+#[test]
+fn single_node() {
+    let path = Path::<_, u32>::new('a');
+    assert_eq!(path.steps(), 0);
+    assert_eq!(path.start(), path.end());
+    assert_eq!(path.edges().count(), 0);
+    assert_eq!(path.into_nodes(), vec!['a']);
+}
+
+#[test]
+#[should_panic(expected = "paths must share their junction node")]
+fn concat_disjoint() {
+    let _ = Path::from_steps(1, [(2, 1)]).concat(Path::from_steps(3, [(4, 1)]));
+}
+
+#[test]
+fn searches() {
+    let expected = dijkstra(&1, successors, |&n| n == 77).unwrap();
+    let path = dijkstra_path(&1, successors, |&n| n == 77).unwrap();
+    assert_eq!(path.total_cost(), expected.1);
+    assert_eq!(path.step_costs().iter().sum::<u32>(), expected.1);
+    assert_eq!(path.nodes(), expected.0.as_slice());
+    for (from, to, cost) in path.edges() {
+        assert!(successors(from).contains(&(*to, cost)));
+    }
+
+    let path = astar_path(&1, successors, |_| 0, |&n| n == 77).unwrap();
+    assert_eq!(path.total_cost(), expected.1);
+    assert_eq!(path.step_costs().iter().sum::<u32>(), expected.1);
+
+    let path = bfs_path(&1, |&n| vec![n + 1, n * 2], |&n| n == 77).unwrap();
+    assert_eq!(
+        path.clone().into_nodes(),
+        bfs(&1, |&n| vec![n + 1, n * 2], |&n| n == 77).unwrap()
+    );
+    assert_eq!(path.total_cost(), path.steps());
+    assert_eq!(path.steps(), path.nodes().len() - 1);
+
+    assert!(dijkstra_path(&1, |_| Vec::<(u32, u32)>::new(), |&n| n == 2).is_none());
+    assert_eq!(
+        bfs_path(&1, |_| Vec::new(), |&n| n == 1).unwrap().steps(),
+        0
+    );
+}
+
+#[test]
+fn edges_in_multigraph() {
+    // Every pair of nodes is linked by an expensive edge and a cheap one, listed in
+    // different orders.
+    let multi = |&n: &u32| {
+        let mut edges = successors(&n)
+            .into_iter()
+            .flat_map(|(m, c)| [(m, (n, m, "cheap"), c), (m, (n, m, "expensive"), c + 10)])
+            .collect::<Vec<_>>();
+        if n % 2 == 0 {
+            edges.reverse();
+        }
+        edges
+    };
+    let expected = dijkstra(&1, successors, |&n| n == 77).unwrap();
+    for (steps, cost) in [
+        dijkstra_edges(&1, multi, |&n| n == 77).unwrap(),
+        astar_edges(&1, multi, |_| 0, |&n| n == 77).unwrap(),
+    ] {
+        assert_eq!(cost, expected.1);
+        assert_eq!(steps.iter().map(|&(_, _, c)| c).sum::<u32>(), cost);
+        let mut from = 1;
+        for (i, &(to, (a, b, kind), _)) in steps.iter().enumerate() {
+            assert_eq!(to, expected.0[i + 1]);
+            assert_eq!((a, b, kind), (from, to, "cheap"));
+            from = to;
+        }
+    }
+    assert_eq!(dijkstra_edges(&1, multi, |&n| n == 1), Some((vec![], 0)));
+    assert_eq!(
+        astar_edges(&1, |_| Vec::<(u32, (), u32)>::new(), |_| 0, |&n| n == 2),
+        None
+    );
+}
@@ -0,0 +1,60 @@
+use pathfinding::prelude::*;
+
+fn successors(removed: &[(u8, u8)]) -> impl FnMut(&u8) -> Vec<(u8, u32)> + '_ {
+    move |&n| {
+        [(n.wrapping_add(1), 1), (n.wrapping_add(3), 2)]
+            .into_iter()
+            .filter(|&(m, _)| m < 20 && !removed.contains(&(n, m)))
+            .collect()
+    }
+}
+
+#[test]
+fn caching() {
+    let mut cache = PathCache::new();
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(&0, &9), None);
+    let mut calls = 0;
+    for _ in 0..3 {
+        let result = cache.get_or_insert_with(&0, &9, |s, g| {
+            calls += 1;
+            dijkstra(s, successors(&[]), |n| n == g)
+        });
+        assert_eq!(result.map(|(_, c)| *c), Some(6));
+    }
+    assert_eq!(calls, 1);
+    assert_eq!(cache.get(&0, &9).unwrap().map(|(p, _)| p.len()), Some(4));
+    assert_eq!(cache.dijkstra(&9, &0, successors(&[])), None);
+    assert_eq!(cache.get(&9, &0), Some(None));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn invalidation() {
+    let mut removed = Vec::new();
+    let mut cache = PathCache::new();
+    let p1 = cache.dijkstra(&0, &6, successors(&removed)).unwrap();
+    let p2 = cache.dijkstra(&10, &16, successors(&removed)).unwrap();
+    assert_eq!(p1, (vec![0, 3, 6], 4));
+    assert_eq!(p2, (vec![10, 13, 16], 4));
+    // Not on any path.
+    assert_eq!(cache.invalidate_edge(&0, &1), 0);
+    // 6 is on the path but not the edge 6 -> 9.
+    assert_eq!(cache.invalidate_edge(&6, &9), 0);
+    removed.push((3, 6));
+    assert_eq!(cache.invalidate_edge(&3, &6), 1);
+    assert_eq!(cache.get(&0, &6), None);
+    assert_eq!(cache.get(&10, &16).unwrap().map(|(_, c)| *c), Some(4));
+    assert_eq!(
+        cache.dijkstra(&0, &6, successors(&removed)).map(|(_, c)| c),
+        Some(5)
+    );
+    assert_eq!(cache.invalidate_node(&16), 1);
+    assert_eq!(cache.invalidate_node(&16), 0);
+    assert_eq!(cache.len(), 1);
+    cache.insert(1, 2, None);
+    assert_eq!(cache.len(), 2);
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.invalidate_node(&0), 0);
+}
@@ -0,0 +1,140 @@
+use pathfinding::prelude::{maximum_antichain, minimum_path_cover};
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn chain() {
+    let paths = minimum_path_cover(&[3, 1, 2, 0], |&n: &u32| [n + 1]).unwrap();
+    assert_eq!(paths, vec![vec![0, 1, 2, 3]]);
+}
+
+#[test]
+fn isolated_nodes() {
+    let paths = minimum_path_cover(&['a', 'b', 'c'], |_| []).unwrap();
+    assert_eq!(paths, vec![vec!['a'], vec!['b'], vec!['c']]);
+}
+
+#[test]
+fn star() {
+    // Only one of the leaves can follow the center.
+    let paths = minimum_path_cover(
+        &[0, 1, 2, 3],
+        |&n: &u32| if n == 0 { vec![1, 2, 3] } else { vec![] },
+    )
+    .unwrap();
+    assert_eq!(paths.len(), 3);
+}
+
+#[test]
+fn cycle() {
+    assert_eq!(
+        minimum_path_cover(&[0, 1, 2, 3], |&n: &u32| [(n + 1) % 3]),
+        Err(0)
+    );
+    assert_eq!(minimum_path_cover(&[0, 1], |&n: &u32| [n]), Err(0));
+}
+
+/// Largest number of edges which can be chosen so that no node has two chosen
+/// outgoing or incoming edges.
+fn brute_force(size: usize, edges: &[(usize, usize)]) -> usize {
+    (0..1u32 << edges.len())
+        .filter_map(|mask| {
+            let mut outgoing = vec![false; size];
+            let mut incoming = vec![false; size];
+            for (i, &(a, b)) in edges.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    if outgoing[a] || incoming[b] {
+                        return None;
+                    }
+                    outgoing[a] = true;
+                    incoming[b] = true;
+                }
+            }
+            Some(mask.count_ones() as usize)
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn random_dags() {
+    const SIZE: usize = 8;
+    for seed in 1..60 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let mut edges = vec![];
+        for a in 0..SIZE {
+            for b in a + 1..SIZE {
+                if edges.len() < 16 && rng.random_ratio(1, 3) {
+                    edges.push((a, b));
+                }
+            }
+        }
+        let nodes = (0..SIZE).collect::<Vec<_>>();
+        let successors = |&n: &usize| edges.iter().filter(move |&&(a, _)| a == n).map(|&(_, b)| b);
+        let paths = minimum_path_cover(&nodes, successors).unwrap();
+        let mut seen = paths.concat();
+        seen.sort_unstable();
+        assert_eq!(seen, nodes);
+        for path in &paths {
+            for w in path.windows(2) {
+                assert!(edges.contains(&(w[0], w[1])));
+            }
+        }
+        assert_eq!(paths.len(), SIZE - brute_force(SIZE, &edges));
+    }
+}
+
+#[test]
+fn antichain_of_chain_and_isolated_nodes() {
+    assert_eq!(
+        maximum_antichain(&[0, 1, 2, 3], |&n: &u32| [n + 1]).map(|a| a.len()),
+        Ok(1)
+    );
+    assert_eq!(
+        maximum_antichain(&['a', 'b', 'c'], |_| []),
+        Ok(vec!['a', 'b', 'c'])
+    );
+    assert_eq!(
+        maximum_antichain(&[0, 1, 2], |&n: &u32| [(n + 1) % 3]),
+        Err(0)
+    );
+}
+
+#[test]
+fn antichain_random_dags() {
+    const SIZE: usize = 10;
+    for seed in 1..60 {
+        let mut rng = XorShiftRng::from_seed([seed; 16]);
+        let mut reachable = [[false; SIZE]; SIZE];
+        let mut edges = vec![];
+        for a in (0..SIZE).rev() {
+            for b in a + 1..SIZE {
+                if rng.random_ratio(1, 4) {
+                    edges.push((a, b));
+                    reachable[a][b] = true;
+                    let row = reachable[b];
+                    for (r, &s) in reachable[a].iter_mut().zip(&row).skip(b + 1) {
+                        *r |= s;
+                    }
+                }
+            }
+        }
+        let unrelated = |set: &[usize]| set.iter().all(|&a| set.iter().all(|&b| !reachable[a][b]));
+        let best = (0..1u32 << SIZE)
+            .map(|mask| {
+                (0..SIZE)
+                    .filter(|&n| mask & (1 << n) != 0)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|set| unrelated(set))
+            .map(|set| set.len())
+            .max()
+            .unwrap();
+        let nodes = (0..SIZE).collect::<Vec<_>>();
+        let successors = |&n: &usize| edges.iter().filter(move |&&(a, _)| a == n).map(|&(_, b)| b);
+        let antichain = maximum_antichain(&nodes, successors).unwrap();
+        assert!(antichain.is_sorted());
+        assert!(unrelated(&antichain));
+        assert_eq!(antichain.len(), best);
+    }
+}
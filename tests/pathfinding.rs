@@ -117,6 +117,43 @@ mod ex1 {
         assert_eq!(None, it.next());
     }
 
+    #[test]
+    fn bfs_reach_depth_ok() {
+        let successors = |n: &u8| successors(n).map(|(n, _)| n);
+        let reached = bfs_reach_depth(1, successors).collect::<Vec<_>>();
+        assert_eq!(
+            reached.iter().map(|&(n, _)| n).collect::<Vec<_>>(),
+            bfs_reach(1, successors).collect::<Vec<_>>()
+        );
+        for (node, depth) in reached {
+            let path = bfs(&1, successors, |&n| n == node).unwrap();
+            assert_eq!(path.len() - 1, depth);
+        }
+        let mut it = bfs_reach_depth(8, successors);
+        assert_eq!(it.next(), Some((8, 0)));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn bfs_layers_ok() {
+        let successors = |n: &u8| successors(n).map(|(n, _)| n);
+        let layers = bfs_layers(1, successors).collect::<Vec<_>>();
+        assert_eq!(
+            layers.concat(),
+            bfs_reach(1, successors).collect::<Vec<_>>()
+        );
+        for (depth, layer) in layers.iter().enumerate() {
+            for node in layer {
+                assert!(bfs_reach_depth(1, successors).any(|r| r == (*node, depth)));
+            }
+        }
+        let mut it = bfs_layers(8, successors);
+        assert_eq!(it.next(), Some(vec![8]));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+    }
+
     #[test]
     fn dfs_reach_is_fused() {
         let mut it = dfs_reach(1, |&n| vec![n * 2, n * 3].into_iter().filter(|&x| x < 15)).skip(1);
@@ -455,4 +492,90 @@ mod ex2 {
             None
         );
     }
+
+    #[test]
+    fn iddfs_bounded_depth() {
+        const GOAL: (usize, usize) = (6, 3);
+        let successors = |n: &(usize, usize)| successors(n).into_iter().map(|(n, _)| n);
+        let IddfsOutcome::Found { path, depth } =
+            iddfs_bounded((2, 3), successors, |n| n == &GOAL, 8)
+        else {
+            panic!("path not found");
+        };
+        assert_eq!(depth, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.last(), Some(&GOAL));
+        assert_eq!(
+            iddfs_bounded((2, 3), successors, |n| n == &GOAL, 7),
+            IddfsOutcome::CutOff
+        );
+        assert_eq!(
+            iddfs_bounded((2, 3), successors, |n| n == &(1, 1), 100),
+            IddfsOutcome::NoSolution
+        );
+        assert_eq!(
+            iddfs_bounded((2, 3), successors, |n| n == &(2, 3), 0),
+            IddfsOutcome::Found {
+                path: vec![(2, 3)],
+                depth: 0
+            }
+        );
+    }
+}
+
+mod bounded {
+    use pathfinding::prelude::*;
+
+    // A cheap but long chain 0 -> 1 -> ... -> 6, and an expensive shortcut 0 -> 6.
+    #[expect(clippy::trivially_copy_pass_by_ref)]
+    fn successors(&n: &u8) -> Vec<(u8, u32)> {
+        match n {
+            0 => vec![(1, 1), (6, 20)],
+            1..6 => vec![(n + 1, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_hops() {
+        assert_eq!(
+            dijkstra_bounded(&0, successors, |&n| n == 6, 6),
+            Some((vec![0, 1, 2, 3, 4, 5, 6], 6))
+        );
+        assert_eq!(
+            dijkstra_bounded(&0, successors, |&n| n == 6, 5),
+            Some((vec![0, 6], 20))
+        );
+        assert_eq!(dijkstra_bounded(&0, successors, |&n| n == 5, 4), None);
+        assert_eq!(
+            dijkstra_bounded(&0, successors, |&n| n == 0, 0),
+            Some((vec![0], 0))
+        );
+    }
+
+    #[test]
+    fn astar_hops() {
+        assert_eq!(
+            astar_bounded(&0, successors, |_| 0, |&n| n == 6, 5),
+            Some((vec![0, 6], 20))
+        );
+        assert_eq!(
+            astar_bounded(&0, successors, |&n| u32::from(6 - n.min(6)), |&n| n == 6, 6),
+            Some((vec![0, 1, 2, 3, 4, 5, 6], 6))
+        );
+        assert_eq!(astar_bounded(&0, successors, |_| 0, |&n| n == 5, 4), None);
+    }
+
+    #[test]
+    fn bfs_hops() {
+        let succ = |n: &u8| successors(n).into_iter().map(|(n, _)| n);
+        assert_eq!(bfs_bounded(&0, succ, |&n| n == 6, 1), Some(vec![0, 6]));
+        assert_eq!(
+            bfs_bounded(&0, succ, |&n| n == 5, 5),
+            Some(vec![0, 1, 2, 3, 4, 5])
+        );
+        assert_eq!(bfs_bounded(&0, succ, |&n| n == 5, 4), None);
+        assert_eq!(bfs_bounded(&0, succ, |&n| n == 0, 0), Some(vec![0]));
+        assert_eq!(bfs_bounded(&0, succ, |&n| n == 1, 0), None);
+    }
 }
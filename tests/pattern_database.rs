@@ -0,0 +1,184 @@
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+type Board = [u8; 9];
+
+const GOAL: Board = [1, 2, 3, 4, 5, 6, 7, 8, 0];
+
+fn neighbours(cell: usize) -> impl Iterator<Item = usize> {
+    let (x, y) = (cell % 3, cell / 3);
+    [
+        (x > 0).then(|| cell - 1),
+        (x < 2).then(|| cell + 1),
+        (y > 0).then(|| cell - 3),
+        (y < 2).then(|| cell + 3),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+fn successors(board: &Board) -> Vec<(Board, u32)> {
+    let blank = board.iter().position(|&t| t == 0).unwrap();
+    neighbours(blank)
+        .map(|cell| {
+            let mut next = *board;
+            next.swap(blank, cell);
+            (next, 1)
+        })
+        .collect()
+}
+
+/// Abstract moves of a pattern made of the positions of some tiles: a tile can move to
+/// any neighbouring cell not occupied by another tile of the pattern.
+fn pattern_moves(pattern: &[usize; 4]) -> Vec<([usize; 4], u8)> {
+    let mut moves = Vec::new();
+    for (i, &cell) in pattern.iter().enumerate() {
+        for to in neighbours(cell) {
+            if !pattern.contains(&to) {
+                let mut next = *pattern;
+                next[i] = to;
+                moves.push((next, 1));
+            }
+        }
+    }
+    moves
+}
+
+fn positions(board: &Board, tiles: [u8; 4]) -> [usize; 4] {
+    tiles.map(|tile| board.iter().position(|&t| t == tile).unwrap())
+}
+
+fn database(tiles: [u8; 4]) -> PatternDatabase {
+    PatternDatabase::new(
+        &[positions(&GOAL, tiles)],
+        arrangement_count(9, 4),
+        |p| arrangement_rank(p, 9),
+        pattern_moves,
+    )
+}
+
+fn scramble(seed: u8, moves: usize) -> Board {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut board = GOAL;
+    for _ in 0..moves {
+        let succ = successors(&board);
+        board = succ[rng.random_range(0..succ.len())].0;
+    }
+    board
+}
+
+fn manhattan_sum(board: &Board) -> u32 {
+    (1..9)
+        .map(|tile| {
+            let (from, to) = (
+                board.iter().position(|&t| t == tile).unwrap(),
+                GOAL.iter().position(|&t| t == tile).unwrap(),
+            );
+            u32::try_from(manhattan((from % 3, from / 3))(&(to % 3, to / 3))).unwrap()
+        })
+        .sum()
+}
+
+#[test]
+fn additive_databases_solve_eight_puzzle() {
+    let low = database([1, 2, 3, 4]);
+    let high = database([5, 6, 7, 8]);
+    assert_eq!(low.size(), 3024);
+    assert_eq!(
+        low.distance(arrangement_rank(&positions(&GOAL, [1, 2, 3, 4]), 9)),
+        Some(0)
+    );
+    let (h1, h2) = (
+        low.heuristic::<_, u32, _>(|b: &Board| arrangement_rank(&positions(b, [1, 2, 3, 4]), 9)),
+        high.heuristic::<_, u32, _>(|b: &Board| arrangement_rank(&positions(b, [5, 6, 7, 8]), 9)),
+    );
+    let heuristic = |b: &Board| h1(b) + h2(b);
+    for seed in 1..8 {
+        let start = scramble(seed, 60);
+        // Additive pattern databases are at least as informed as the Manhattan distance.
+        assert!(heuristic(&start) >= manhattan_sum(&start));
+        let expected = astar(&start, successors, manhattan_sum, |b| *b == GOAL).unwrap();
+        assert!(heuristic(&start) <= expected.1);
+        let (path, cost) = idastar(&start, successors, heuristic, |b| *b == GOAL).unwrap();
+        assert_eq!(cost, expected.1);
+        assert_eq!(path.len(), expected.0.len());
+    }
+}
+
+#[test]
+fn unreachable_patterns() {
+    // Patterns which cannot be reached from the goal have no distance.
+    let database = PatternDatabase::new(
+        &[0usize],
+        4,
+        |&p| p,
+        |&p: &usize| (p < 2).then_some((1 - p, 3)),
+    );
+    assert_eq!(database.distance(0), Some(0));
+    assert_eq!(database.distance(1), Some(3));
+    assert_eq!(database.distance(2), None);
+    assert_eq!(database.heuristic::<usize, u32, _>(|&n| n)(&3), 255);
+}
+
+#[test]
+fn zero_cost_and_large_distances() {
+    // A line of 300 patterns where every other move is free.
+    let database = PatternDatabase::new(
+        &[0usize],
+        300,
+        |&p| p,
+        |&p: &usize| (p < 299).then_some((p + 1, u8::try_from(p % 2).unwrap() * 2)),
+    );
+    assert_eq!(database.distance(1), Some(0));
+    assert_eq!(database.distance(2), Some(2));
+    assert_eq!(database.distance(3), Some(2));
+    assert_eq!(database.distance(253), Some(252));
+    // Distances larger than 254 are capped.
+    assert_eq!(database.distance(299), Some(254));
+}
+
+#[test]
+fn bytes_round_trip() {
+    let database = database([1, 3, 5, 7]);
+    let restored = PatternDatabase::from_bytes(database.as_bytes().to_vec());
+    assert_eq!(restored, database);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let database = database([2, 4, 6, 8]);
+    let json = serde_json::to_string(&database).unwrap();
+    let restored: PatternDatabase = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, database);
+}
+
+/// Check that all the arrangements of `pieces` pieces on `cells` cells have distinct ranks.
+fn mark_arrangements(cells: usize, pieces: usize, positions: &mut Vec<usize>, seen: &mut [bool]) {
+    if positions.len() == pieces {
+        let rank = arrangement_rank(positions, cells);
+        assert!(!seen[rank]);
+        seen[rank] = true;
+        return;
+    }
+    for p in 0..cells {
+        if !positions.contains(&p) {
+            positions.push(p);
+            mark_arrangements(cells, pieces, positions, seen);
+            positions.pop();
+        }
+    }
+}
+
+#[test]
+fn arrangement_ranks_are_a_bijection() {
+    for (cells, pieces) in [(5, 0), (5, 1), (5, 3), (6, 6), (4, 2)] {
+        let count = arrangement_count(cells, pieces);
+        let mut seen = vec![false; count];
+        let mut positions = Vec::new();
+        mark_arrangements(cells, pieces, &mut positions, &mut seen);
+        assert!(seen.into_iter().all(|s| s));
+    }
+    assert_eq!(arrangement_count(3, 4), 0);
+}
@@ -0,0 +1,123 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn monotone_extraction() {
+    let mut rng = XorShiftRng::from_seed([3; 16]);
+    let mut heap = RadixHeap::new();
+    let mut reference = Vec::new();
+    let mut last = 0u64;
+    for _ in 0..2000 {
+        if rng.random_ratio(2, 3) {
+            let key = last + rng.random_range(0..1000);
+            heap.push(key, key);
+            reference.push(key);
+        } else {
+            reference.sort_unstable_by(|a, b| b.cmp(a));
+            let expected = reference.pop();
+            assert_eq!(heap.pop().map(|(k, _)| k), expected);
+            if let Some(k) = expected {
+                last = k;
+            }
+        }
+        assert_eq!(heap.len(), reference.len());
+    }
+    reference.sort_unstable();
+    let rest = std::iter::from_fn(|| {
+        heap.pop().map(|(k, v)| {
+            assert_eq!(k, v);
+            k
+        })
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(rest, reference);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn smaller_keys_are_raised() {
+    let mut heap = RadixHeap::new();
+    heap.push(10u8, 'a');
+    assert_eq!(heap.pop(), Some((10, 'a')));
+    heap.push(3, 'b');
+    heap.push(12, 'c');
+    assert_eq!(heap.pop(), Some((10, 'b')));
+    assert_eq!(heap.pop(), Some((12, 'c')));
+    heap.clear();
+    heap.push(3, 'd');
+    assert_eq!(heap.pop(), Some((3, 'd')));
+}
+
+#[test]
+fn extreme_keys() {
+    let mut heap = RadixHeap::new();
+    heap.push(u128::MAX, ());
+    heap.push(0, ());
+    heap.push(1 << 100, ());
+    assert_eq!(heap.pop(), Some((0, ())));
+    assert_eq!(heap.pop(), Some((1 << 100, ())));
+    assert_eq!(heap.pop(), Some((u128::MAX, ())));
+    assert_eq!(heap.pop(), None);
+}
+
+const SIZE: usize = 50;
+
+fn build_network(seed: u8) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 8) {
+                network[(a, b)] = rng.random_range(1..1000);
+            }
+        }
+    }
+    network
+}
+
+#[test]
+fn dijkstra_same_costs() {
+    for seed in 1..5 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        for goal in 0..SIZE {
+            let expected = dijkstra(&0, successors, |&n| n == goal);
+            let result = dijkstra_radix(&0, successors, |&n| n == goal);
+            assert_eq!(result.as_ref().map(|r| r.1), expected.map(|r| r.1));
+            if let Some((path, cost)) = result {
+                let path_cost = path.windows(2).map(|w| network[(w[0], w[1])]).sum::<u32>();
+                assert_eq!(path_cost, cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn astar_same_costs() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    let mut grid = Grid::new(30, 30);
+    grid.fill();
+    for _ in 0..250 {
+        grid.remove_vertex((rng.random_range(0..30), rng.random_range(0..30)));
+    }
+    let goal = (29, 29);
+    for start in [(0, 0), (15, 3), (3, 22)] {
+        if !grid.has_vertex(start) || !grid.has_vertex(goal) {
+            continue;
+        }
+        let successors = |&p: &(usize, usize)| grid.neighbours(p).into_iter().map(|n| (n, 1usize));
+        let heuristic = |&(x, y): &(usize, usize)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+        let expected = astar(&start, successors, heuristic, |&p| p == goal);
+        let result = astar_radix(&start, successors, heuristic, |&p| p == goal);
+        assert_eq!(result.as_ref().map(|r| r.1), expected.map(|r| r.1));
+        if let Some((path, cost)) = result {
+            assert_eq!(path.len(), cost + 1);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+type Vertex = (usize, usize);
+
+fn random_grid(rng: &mut XorShiftRng) -> Grid {
+    let mut grid = Grid::new(20, 20);
+    grid.fill();
+    for _ in 0..100 {
+        grid.remove_vertex((rng.random_range(0..20), rng.random_range(0..20)));
+    }
+    grid.add_vertex((0, 0));
+    grid.add_vertex((19, 19));
+    grid
+}
+
+fn successors(grid: &Grid) -> impl FnMut(&Vertex) -> Vec<(Vertex, usize)> {
+    |&n| grid.neighbours(n).into_iter().map(|m| (m, 1)).collect()
+}
+
+fn heuristic(grid: &Grid, goal: Vertex) -> impl Fn(&Vertex) -> usize {
+    move |&n| grid.distance(n, goal)
+}
+
+/// Move an agent from `start` to `goal` using LRTA*, and return the cost of the trip.
+fn lrta_trip(search: &mut RealTimeSearch<Vertex, usize>, grid: &Grid, start: Vertex) -> usize {
+    let goal = (19, 19);
+    let (mut agent, mut moves) = (start, 0);
+    while agent != goal {
+        let next = search
+            .lrta_star(&agent, successors(grid), heuristic(grid, goal))
+            .expect("agent is stuck");
+        assert!(grid.has_edge(agent, next));
+        agent = next;
+        moves += 1;
+        assert!(moves < 100_000, "agent is lost");
+    }
+    moves
+}
+
+/// Move an agent from `start` to `goal` using RTAA*, following the whole path returned
+/// by every search, and return the cost of the trip.
+fn rtaa_trip(
+    search: &mut RealTimeSearch<Vertex, usize>,
+    grid: &Grid,
+    start: Vertex,
+    lookahead: usize,
+) -> usize {
+    let goal = (19, 19);
+    let (mut agent, mut moves) = (start, 0);
+    while agent != goal {
+        let (path, cost) = search
+            .rtaa_star(
+                &agent,
+                lookahead,
+                successors(grid),
+                heuristic(grid, goal),
+                |&n| n == goal,
+            )
+            .expect("agent is stuck");
+        assert_eq!(path[0], agent);
+        assert!(path.windows(2).all(|w| grid.has_edge(w[0], w[1])));
+        assert_eq!(cost, path.len() - 1);
+        agent = path[path.len() - 1];
+        moves += cost;
+        assert!(moves < 100_000, "agent is lost");
+    }
+    moves
+}
+
+#[test]
+fn repeated_trials_converge() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let grid = random_grid(&mut rng);
+        let Some((_, shortest)) = dijkstra(&(0, 0), successors(&grid), |&n| n == (19, 19)) else {
+            continue;
+        };
+        let mut search = RealTimeSearch::new();
+        let mut costs = (0..200)
+            .map(|_| lrta_trip(&mut search, &grid, (0, 0)))
+            .collect::<Vec<_>>();
+        assert!(costs.iter().all(|&c| c >= shortest));
+        assert_eq!(costs.pop(), Some(shortest));
+        for lookahead in [1, 5, 50] {
+            let mut search = RealTimeSearch::new();
+            let mut costs = (0..200)
+                .map(|_| rtaa_trip(&mut search, &grid, (0, 0), lookahead))
+                .collect::<Vec<_>>();
+            assert!(costs.iter().all(|&c| c >= shortest));
+            assert_eq!(costs.pop(), Some(shortest));
+        }
+    }
+}
+
+#[test]
+fn learned_values_are_admissible() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    let grid = random_grid(&mut rng);
+    let goal = (19, 19);
+    let mut search = RealTimeSearch::new();
+    if dijkstra(&(0, 0), successors(&grid), |&n| n == goal).is_none() {
+        return;
+    }
+    for _ in 0..5 {
+        rtaa_trip(&mut search, &grid, (0, 0), 10);
+    }
+    assert!(search.learned_len() > 0);
+    for vertex in &grid {
+        let h = search.heuristic(&vertex, heuristic(&grid, goal));
+        assert!(h >= grid.distance(vertex, goal));
+        if let Some((_, cost)) = dijkstra(&vertex, successors(&grid), |&n| n == goal) {
+            assert!(h <= cost, "{vertex:?} learned {h} above {cost}");
+        }
+    }
+    search.forget();
+    assert_eq!(search.learned_len(), 0);
+}
+
+#[test]
+fn large_lookahead_finds_shortest_path() {
+    let mut rng = XorShiftRng::seed_from_u64(2);
+    for _ in 0..10 {
+        let grid = random_grid(&mut rng);
+        let goal = (19, 19);
+        let shortest = dijkstra(&(0, 0), successors(&grid), |&n| n == goal);
+        let found = RealTimeSearch::new().rtaa_star(
+            &(0, 0),
+            400,
+            successors(&grid),
+            heuristic(&grid, goal),
+            |&n| n == goal,
+        );
+        assert_eq!(found.map(|(_, cost)| cost), shortest.map(|(_, cost)| cost));
+    }
+}
+
+#[test]
+fn dead_ends() {
+    let mut search = RealTimeSearch::<u32, u32>::new();
+    assert_eq!(search.lrta_star(&1, |_| vec![], |_| 0), None);
+    assert_eq!(
+        search.rtaa_star(
+            &1,
+            3,
+            |&n| (n < 3).then_some((n + 1, 1)),
+            |_| 0,
+            |&n| n == 5
+        ),
+        None
+    );
+    assert_eq!(
+        search.rtaa_star(&1, 3, |&n| [(n + 1, 1)], |_| 0, |&n| n == 1),
+        Some((vec![1], 0))
+    );
+}
+
+#[test]
+#[should_panic(expected = "lookahead must be at least 1")]
+fn zero_lookahead() {
+    RealTimeSearch::<u32, u32>::new().rtaa_star(&1, 0, |&n| [(n + 1, 1)], |_| 0, |&n| n == 5);
+}
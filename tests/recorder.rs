@@ -0,0 +1,181 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+fn random_graph(rng: &mut XorShiftRng) -> Vec<Vec<(usize, u32)>> {
+    (0..100)
+        .map(|_| {
+            (0..rng.random_range(0..6))
+                .map(|_| (rng.random_range(0..100), rng.random_range(1..10)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn recorded_dijkstra() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let graph = random_graph(&mut rng);
+        for goal in 0..20 {
+            let mut expanded = vec![];
+            let expected = dijkstra(
+                &0,
+                |&n| {
+                    expanded.push(n);
+                    graph[n].clone()
+                },
+                |&n| n == goal,
+            );
+            let recorder = SearchRecorder::new();
+            let result = dijkstra(
+                &0,
+                recorder.successors(|&n: &usize| graph[n].clone()),
+                recorder.success(|&n| n == goal),
+            );
+            assert_eq!(expected, result);
+            let recording = recorder.into_recording();
+            assert_eq!(recording.expanded().copied().collect::<Vec<_>>(), expanded);
+            // Replay the successors found for every expanded node, and the parents.
+            let mut current = None;
+            let mut found = vec![];
+            let mut parents = HashMap::new();
+            for event in recording.events() {
+                match *event {
+                    SearchEvent::Expand(n) => {
+                        if let Some(n) = current {
+                            assert_eq!(graph[n], found);
+                        }
+                        current = Some(*recording.node(n));
+                        found.clear();
+                    }
+                    SearchEvent::Successor { from, to, cost } => {
+                        assert_eq!(Some(*recording.node(from)), current);
+                        found.push((*recording.node(to), cost));
+                    }
+                    SearchEvent::Relax { node, parent, cost } => {
+                        assert_eq!(Some(*recording.node(parent)), current);
+                        assert_eq!(found.last().map(|&(n, _)| n), Some(*recording.node(node)));
+                        parents.insert(*recording.node(node), (*recording.node(parent), cost));
+                    }
+                    SearchEvent::Estimate { .. } => unreachable!(),
+                    SearchEvent::Goal(n) => {
+                        assert_eq!(*recording.node(n), goal);
+                        assert_eq!(Some(event), recording.events().last());
+                    }
+                }
+            }
+            assert_eq!(
+                matches!(recording.events().last(), Some(SearchEvent::Goal(_))),
+                result.is_some()
+            );
+            // The path found can be rebuilt from the last recorded parents.
+            if let Some((path, cost)) = result.filter(|_| goal != 0) {
+                assert_eq!(parents[&goal].1, cost);
+                let mut rebuilt = vec![goal];
+                while let Some(&(parent, _)) = parents.get(rebuilt.last().unwrap()) {
+                    rebuilt.push(parent);
+                }
+                rebuilt.reverse();
+                assert_eq!(rebuilt, path);
+            }
+            let mut nodes = recording.nodes().collect::<Vec<_>>();
+            let count = nodes.len();
+            nodes.sort_unstable();
+            nodes.dedup();
+            assert_eq!(nodes.len(), count);
+        }
+    }
+}
+
+#[test]
+fn recorded_heuristic() {
+    let recorder = SearchRecorder::new();
+    let result = astar(
+        &(0, 0),
+        recorder.successors(|&(x, y): &(i32, i32)| {
+            [((x + 1, y), 1), ((x, y + 1), 1)]
+                .into_iter()
+                .filter(|&((x, y), _)| x <= 3 && y <= 3)
+        }),
+        recorder.heuristic(|&(x, y)| (3 - x) + (3 - y)),
+        |&n| n == (3, 3),
+    );
+    assert_eq!(result.map(|(_, c)| c), Some(6));
+    let recording = recorder.into_recording();
+    for event in recording.events() {
+        if let SearchEvent::Estimate { node, estimate } = *event {
+            let (x, y) = *recording.node(node);
+            assert_eq!(estimate, 6 - x - y);
+        }
+    }
+    assert!(
+        recording
+            .events()
+            .iter()
+            .all(|event| !matches!(event, SearchEvent::Goal(_)))
+    );
+}
+
+#[test]
+fn recorded_bfs() {
+    let recorder = SearchRecorder::new();
+    let path = bfs(
+        &0u8,
+        recorder.unweighted_successors(|&n| [n + 1, n + 2].into_iter().filter(|&n| n < 10)),
+        |&n| n == 9,
+    );
+    assert_eq!(path, Some(vec![0, 1, 3, 5, 7, 9]));
+    let recording = recorder.into_recording();
+    assert_eq!(recording.expanded().next(), Some(&0));
+    // Every node gets a parent once, when it is first found.
+    let parents = recording
+        .events()
+        .iter()
+        .filter_map(|event| match *event {
+            SearchEvent::Relax { node, parent, .. } => {
+                Some((*recording.node(node), *recording.node(parent)))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parents,
+        vec![
+            (1, 0),
+            (2, 0),
+            (3, 1),
+            (4, 2),
+            (5, 3),
+            (6, 4),
+            (7, 5),
+            (8, 6),
+            (9, 7)
+        ]
+    );
+    assert!(
+        recording
+            .events()
+            .iter()
+            .any(|event| matches!(event, SearchEvent::Successor { cost: (), .. }))
+    );
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn json_export() {
+    let recorder = SearchRecorder::new();
+    dijkstra(
+        &"a".to_string(),
+        recorder.successors(|n: &String| (n.len() < 3).then(|| (format!("{n}b"), 2)).into_iter()),
+        recorder.success(|n| n == "abb"),
+    );
+    let recording = recorder.into_recording();
+    let json = recording.to_json().unwrap();
+    assert!(json.starts_with(r#"{"nodes":["a","ab","abb"],"events":[{"expand":0},"#));
+    let imported = SearchRecording::<String, u32>::from_json(&json).unwrap();
+    assert_eq!(imported.events(), recording.events());
+    assert!(imported.nodes().eq(recording.nodes()));
+    assert!(SearchRecording::<String, u32>::from_json("[]").is_err());
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "reference")]
+
+use pathfinding::prelude::*;
+use rand::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+fn random_graph(seed: u8, size: usize, max_weight: i32) -> Matrix<Option<i32>> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut graph = Matrix::new(size, size, None);
+    for from in 0..size {
+        for to in 0..size {
+            if from != to && rng.random_ratio(1, 3) {
+                graph[(from, to)] = Some(rng.random_range(1..=max_weight));
+            }
+        }
+    }
+    graph
+}
+
+fn successors(graph: &Matrix<Option<i32>>, node: usize) -> Vec<(usize, i32)> {
+    (0..graph.columns)
+        .filter_map(|to| graph[(node, to)].map(|c| (to, c)))
+        .collect()
+}
+
+#[test]
+fn shortest_path_matches_dijkstra() {
+    for seed in 1..50 {
+        let graph = random_graph(seed, 8, 10);
+        let expected = dijkstra(&0, |&n| successors(&graph, n), |&n| n == 7);
+        let result = brute_force_shortest_path(&0, |&n| successors(&graph, n), |&n| n == 7);
+        assert_eq!(result.as_ref().map(|(_, c)| *c), expected.map(|(_, c)| c));
+        if let Some((path, cost)) = result {
+            let total = path
+                .windows(2)
+                .map(|w| graph[(w[0], w[1])].unwrap())
+                .sum::<i32>();
+            assert_eq!(total, cost);
+        }
+    }
+}
+
+#[test]
+fn shortest_path_with_negative_costs() {
+    let successors = |&n: &u8| match n {
+        0 => vec![(1, 4), (2, 1)],
+        1 => vec![(3, -3)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+    assert_eq!(
+        brute_force_shortest_path(&0, successors, |&n| n == 3),
+        Some((vec![0, 1, 3], 1))
+    );
+}
+
+#[test]
+fn shortest_path_edge_cases() {
+    assert_eq!(
+        brute_force_shortest_path(&0, |_| Vec::<(u8, u32)>::new(), |&n| n == 0),
+        Some((vec![0], 0))
+    );
+    assert_eq!(
+        brute_force_shortest_path(&0, |&n: &u8| vec![((n + 1) % 3, 1)], |&n| n == 5),
+        None
+    );
+}
+
+#[test]
+fn assignment_matches_kuhn_munkres() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    for _ in 0..50 {
+        let rows = rng.random_range(1..=5);
+        let columns = rng.random_range(rows..=6);
+        let weights = Matrix::from_fn(rows, columns, |_| rng.random_range(-20..20));
+        let (total, assignment) = brute_force_assignment(&weights);
+        assert_eq!(total, kuhn_munkres(&weights).0);
+        assert_eq!(
+            assignment
+                .iter()
+                .enumerate()
+                .map(|(r, &c)| weights[(r, c)])
+                .sum::<i32>(),
+            total
+        );
+    }
+}
+
+#[test]
+fn empty_assignment() {
+    let weights = Matrix::<i32>::new(0, 3, 0);
+    assert_eq!(brute_force_assignment(&weights), (0, vec![]));
+}
+
+#[test]
+#[should_panic(expected = "number of rows must not be larger than number of columns")]
+fn assignment_with_too_many_rows() {
+    let weights = Matrix::new(3, 2, 0);
+    brute_force_assignment(&weights);
+}
+
+#[test]
+fn max_flow_matches_edmonds_karp() {
+    for seed in 1..50 {
+        let graph = random_graph(seed, 7, 20);
+        let vertices = (0..7).collect::<Vec<usize>>();
+        let caps = graph
+            .keys()
+            .filter_map(|(from, to)| graph[(from, to)].map(|c| ((from, to), c)))
+            .collect::<Vec<_>>();
+        let (_, expected, _) = edmonds_karp_dense(&vertices, &0, &6, caps.clone());
+        assert_eq!(brute_force_max_flow(&vertices, &0, &6, caps), expected);
+    }
+}
+
+#[test]
+fn max_flow_uses_last_capacity() {
+    let caps = [((0, 1), 5), ((1, 2), 5), ((0, 1), 2)];
+    assert_eq!(brute_force_max_flow(&[0, 1, 2], &0, &2, caps), 2);
+    assert_eq!(
+        brute_force_max_flow(&[0, 1, 2], &0, &2, Vec::<((_, _), i32)>::new()),
+        0
+    );
+}
@@ -0,0 +1,123 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+use std::cell::Cell;
+use std::collections::HashSet;
+
+type Vertex = (usize, usize);
+
+fn successors(grid: &Grid) -> impl FnMut(&Vertex) -> Vec<(Vertex, usize)> {
+    |&n| grid.neighbours(n).into_iter().map(|m| (m, 1)).collect()
+}
+
+fn check_path(grid: &Grid, path: &[Vertex], cost: usize) {
+    assert_eq!(cost, path.len() - 1);
+    assert!(
+        path.windows(2).all(|w| grid.has_edge(w[0], w[1])),
+        "invalid path {path:?}"
+    );
+}
+
+#[test]
+fn unaffected_path() {
+    let path = vec![1, 2, 3];
+    let successors = |&n: &u32| vec![(n + 1, 2), (n + 1, 1)];
+    assert_eq!(
+        repair_path(&path, &|_: &u32| false, successors, 10),
+        Some((path.clone(), 2))
+    );
+    assert_eq!(
+        repair_path(&[4], &|_: &u32| false, successors, 10),
+        Some((vec![4], 0))
+    );
+    assert_eq!(repair_path(&[], &|_: &u32| false, successors, 10), None);
+}
+
+#[test]
+fn local_detour() {
+    let mut grid = Grid::new(50, 50);
+    grid.fill();
+    let (start, goal) = ((0, 25), (49, 25));
+    let path = (0..50).map(|x| (x, 25)).collect::<Vec<_>>();
+    grid.remove_vertex((20, 25));
+    let expanded = Cell::new(0);
+    let mut successors = successors(&grid);
+    let (repaired, cost) = repair_path(
+        &path,
+        &HashSet::from([(20, 25)]),
+        |n: &(usize, usize)| {
+            expanded.set(expanded.get() + 1);
+            successors(n)
+        },
+        50,
+    )
+    .unwrap();
+    check_path(&grid, &repaired, cost);
+    assert_eq!(repaired.first(), Some(&start));
+    assert_eq!(repaired.last(), Some(&goal));
+    assert_eq!(cost, 51);
+    // Only the neighbourhood of the obstacle has been explored.
+    assert!(expanded.get() < 100, "{} expansions", expanded.get());
+}
+
+#[test]
+fn removed_edge() {
+    // The edge from 2 to 3 disappears without any node being reported as changed.
+    let path = vec![0, 1, 2, 3, 4];
+    let successors = |&n: &u32| {
+        let mut successors = vec![(n + 2, 3)];
+        if n != 2 {
+            successors.push((n + 1, 1));
+        }
+        successors
+    };
+    assert_eq!(
+        repair_path(&path, &|_: &u32| false, successors, 10),
+        Some((vec![0, 1, 2, 4], 5))
+    );
+}
+
+#[test]
+fn full_search_fallback() {
+    // A wall cuts the grid, except at the bottom, which the local search cannot reach.
+    let mut grid = Grid::new(20, 20);
+    grid.fill();
+    let path = (0..20).map(|x| (x, 0)).collect::<Vec<_>>();
+    let wall = (0..19).map(|y| (10, y)).collect::<Vec<_>>();
+    for &v in &wall {
+        grid.remove_vertex(v);
+    }
+    let (repaired, cost) = repair_path(&path, &wall, successors(&grid), 5).unwrap();
+    check_path(&grid, &repaired, cost);
+    assert_eq!(cost, 19 + 2 * 19);
+    let (_, local_cost) = repair_path(&path, &wall, successors(&grid), 1000).unwrap();
+    assert_eq!(local_cost, cost);
+    grid.remove_vertex((10, 19));
+    assert_eq!(repair_path(&path, &wall, successors(&grid), 1000), None);
+}
+
+#[test]
+fn random_obstacles() {
+    let mut rng = XorShiftRng::from_seed([11; 16]);
+    for _ in 0..30 {
+        let mut grid = Grid::new(15, 15);
+        grid.fill();
+        let (start, goal) = ((0, rng.random_range(0..15)), (14, rng.random_range(0..15)));
+        let (path, _) = dijkstra(&start, successors(&grid), |&n| n == goal).unwrap();
+        let changed = (0..rng.random_range(1..6))
+            .map(|_| path[rng.random_range(1..path.len() - 1)])
+            .collect::<Vec<_>>();
+        for &v in &changed {
+            grid.remove_vertex(v);
+        }
+        let expected = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal);
+        let repaired = repair_path(&path, &changed, successors(&grid), rng.random_range(0..100));
+        assert_eq!(expected.is_some(), repaired.is_some());
+        if let Some((repaired, cost)) = repaired {
+            check_path(&grid, &repaired, cost);
+            assert_eq!(repaired.first(), Some(&start));
+            assert_eq!(repaired.last(), Some(&goal));
+            assert!(repaired.iter().all(|v| !changed.contains(v)));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashMap;
+
+fn random_graph(rng: &mut XorShiftRng) -> HashMap<u32, Vec<(u32, u32)>> {
+    (0..100)
+        .map(|a| {
+            let edges = (0..rng.random_range(0..4))
+                .map(|_| (rng.random_range(0..120), rng.random_range(1..20)))
+                .collect();
+            (a, edges)
+        })
+        .collect()
+}
+
+#[test]
+fn reversed_edges() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..20 {
+        let graph = random_graph(&mut rng);
+        let successors = |n: &u32| graph.get(n).cloned().unwrap_or_default();
+        let mut calls = 0;
+        let reversed = ReversedGraph::new(&[0, 1, 0], |n| {
+            calls += 1;
+            successors(n)
+        });
+        let reachable = dfs_reach(0, |n| successors(n).into_iter().map(|(m, _)| m))
+            .chain(dfs_reach(1, |n| successors(n).into_iter().map(|(m, _)| m)))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(calls, reachable.len());
+        assert_eq!(reversed.len(), reachable.len());
+        assert!(!reversed.is_empty());
+        assert_eq!(reversed.nodes().take(2).collect::<Vec<_>>(), vec![&0, &1]);
+        let mut edges = 0;
+        for a in reversed.nodes() {
+            assert!(reachable.contains(a));
+            for (b, cost) in successors(a) {
+                assert!(reversed.contains(&b));
+                assert!(reversed.predecessors(&b).contains(&(*a, cost)));
+                assert!(reversed.predecessor_nodes(&b).contains(a));
+                edges += 1;
+            }
+        }
+        let reversed_edges = reversed
+            .nodes()
+            .map(|n| reversed.predecessors(n).len())
+            .sum::<usize>();
+        assert_eq!(reversed_edges, edges);
+        for n in 0..120 {
+            if !reachable.contains(&n) {
+                assert!(!reversed.contains(&n));
+                assert!(reversed.predecessors(&n).is_empty());
+            }
+        }
+    }
+}
+
+#[test]
+fn backward_search() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    for _ in 0..20 {
+        let graph = random_graph(&mut rng);
+        let successors = |n: &u32| graph.get(n).cloned().unwrap_or_default();
+        let reversed = ReversedGraph::new(&(0..100).collect::<Vec<_>>(), successors);
+        for goal in 0..120 {
+            let forward = dijkstra(&0, successors, |&n| n == goal);
+            let backward = dijkstra_backward(&goal, |n| reversed.predecessors(n), |&n| n == 0);
+            assert_eq!(
+                forward.map(|(_, cost)| cost),
+                backward.map(|(_, cost)| cost)
+            );
+        }
+    }
+}
+
+#[test]
+fn unweighted() {
+    let successors = |&n: &u8| if n < 10 { vec![n + 1, n / 2] } else { vec![] };
+    let reversed = ReversedGraph::new_unweighted(&[7], successors);
+    assert_eq!(reversed.len(), 11);
+    assert_eq!(reversed.predecessor_nodes(&7), vec![6]);
+    assert_eq!(reversed.predecessor_nodes(&3), vec![7, 2, 6]);
+    assert_eq!(reversed.predecessors(&0), vec![(1, ()), (0, ())]);
+    let path = bfs_backward(&10, |n| reversed.predecessor_nodes(n), |&n| n == 1);
+    assert_eq!(path.map(|p| p.len()), Some(10));
+    let empty = ReversedGraph::new_unweighted(&[], successors);
+    assert!(empty.is_empty());
+    assert!(empty.predecessor_nodes(&1).is_empty());
+}
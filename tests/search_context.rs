@@ -0,0 +1,72 @@
+use pathfinding::NodeRefs;
+use pathfinding::prelude::*;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    vec![(n + 1, 1), (n * 2, 3), (n.saturating_sub(1), 2)]
+}
+
+#[test]
+fn repeated_queries_match_fresh_searches() {
+    let mut context = SearchContext::new();
+    for goal in [30, 7, 1, 55, 2] {
+        assert_eq!(
+            dijkstra_with_context(&mut context, &1, successors, |&n| n == goal),
+            dijkstra(&1, successors, |&n| n == goal)
+        );
+        assert_eq!(
+            astar_with_context(&mut context, &1, successors, |_| 0, |&n| n == goal).map(|(_, c)| c),
+            astar(&1, successors, |_| 0, |&n| n == goal).map(|(_, c)| c)
+        );
+    }
+    assert!(context.discovered() > 0);
+    context.clear();
+    assert_eq!(context.discovered(), 0);
+}
+
+#[test]
+fn bfs_context() {
+    let succ = |&n: &u32| vec![n + 1, n * 2];
+    let mut context = SearchContext::default();
+    for goal in [19, 1, 64, 3] {
+        assert_eq!(
+            bfs_with_context(&mut context, &1, succ, |&n| n == goal),
+            bfs(&1, succ, |&n| n == goal)
+        );
+    }
+    assert_eq!(
+        bfs_with_context(
+            &mut context,
+            [1, 10].iter().collect::<NodeRefs<_>>(),
+            succ,
+            |&n| n == 21
+        ),
+        Some(vec![10, 20, 21])
+    );
+    let limited = |&n: &u32| (n < 5).then_some(n + 1);
+    assert_eq!(bfs_with_context(&mut context, &0, limited, |_| false), None);
+    assert_eq!(context.discovered(), 6);
+}
+
+#[test]
+fn custom_hasher() {
+    let mut context = SearchContext::with_hasher(std::collections::hash_map::RandomState::new());
+    assert_eq!(
+        dijkstra_with_context(&mut context, &1, successors, |&n| n == 8).map(|(_, c)| c),
+        Some(6)
+    );
+}
+
+#[test]
+fn preallocated() {
+    let mut context = SearchContext::with_capacity(1024);
+    let capacity = context.capacity();
+    assert!(capacity >= 1024);
+    for goal in 2..40 {
+        assert!(dijkstra_with_context(&mut context, &1, successors, |&n| n == goal).is_some());
+        assert!(context.discovered() <= capacity);
+    }
+    assert_eq!(context.capacity(), capacity);
+    context.reserve(1000);
+    assert!(context.capacity() >= context.discovered() + 1000);
+}
@@ -0,0 +1,165 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+use std::cell::Cell;
+
+fn random_graph(rng: &mut XorShiftRng) -> Vec<Vec<(usize, u32)>> {
+    (0..100)
+        .map(|_| {
+            (0..rng.random_range(0..5))
+                .map(|_| (rng.random_range(0..100), rng.random_range(1..10)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn same_results_without_limits() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let graph = random_graph(&mut rng);
+        let successors = |&n: &usize| graph[n].clone();
+        let unweighted = |&n: &usize| graph[n].iter().map(|&(m, _)| m).collect::<Vec<_>>();
+        let heuristic = |&n: &usize| u32::from(n != 50);
+        for goal in 0..100 {
+            let expected = dijkstra(&0, successors, |&n| n == goal);
+            let result = try_dijkstra(&0, successors, |&n| n == goal, SearchLimits::new());
+            assert_eq!(
+                expected.as_ref().ok_or(&SearchError::NoPath),
+                result.as_ref()
+            );
+            let result = try_astar(
+                &0,
+                successors,
+                |&n| heuristic(&n) * u32::from(n != goal),
+                |&n| n == goal,
+                SearchLimits::new(),
+            );
+            assert_eq!(
+                expected.map(|(_, c)| c).ok_or(SearchError::NoPath),
+                result.map(|(_, c)| c)
+            );
+            let expected = bfs(&0, unweighted, |&n| n == goal);
+            let result = try_bfs(&0, unweighted, |&n| n == goal, SearchLimits::new());
+            assert_eq!(expected.ok_or(SearchError::NoPath), result);
+        }
+    }
+}
+
+#[test]
+fn budget() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    for _ in 0..10 {
+        let graph = random_graph(&mut rng);
+        for goal in 0..20 {
+            let expanded = Cell::new(0);
+            let successors = |&n: &usize| {
+                expanded.set(expanded.get() + 1);
+                graph[n].clone()
+            };
+            let expected = try_dijkstra(&0, successors, |&n| n == goal, SearchLimits::new());
+            let needed = expanded.replace(0);
+            for max_expansions in [0, needed / 2, needed] {
+                let limits = SearchLimits::new().max_expansions(max_expansions);
+                let result = try_dijkstra(&0, successors, |&n| n == goal, limits);
+                assert!(expanded.replace(0) <= max_expansions);
+                match result {
+                    Err(SearchError::BudgetExceeded { closest, .. }) => {
+                        assert!(max_expansions < needed);
+                        assert_eq!(closest, None);
+                    }
+                    result => assert_eq!(result, expected),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn closest_node() {
+    let successors = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..20).contains(&x) && (0..20).contains(&y))
+            .map(|n| (n, 1))
+    };
+    let heuristic = |&(x, y): &(i32, i32)| x.abs_diff(10) + y.abs_diff(10);
+    let limits = SearchLimits::new().max_expansions(10);
+    let error = try_astar(&(0, 0), successors, heuristic, |&n| n == (10, 10), limits).unwrap_err();
+    // Every expansion gets one step closer to the goal.
+    let (path, cost) = error.closest().unwrap();
+    assert_eq!(path.len(), 10);
+    assert_eq!(*cost, 9);
+    assert_eq!(heuristic(path.last().unwrap()), 11);
+    let limits = SearchLimits::new().max_expansions(20);
+    let (path, cost) =
+        try_astar(&(0, 0), successors, heuristic, |&n| n == (10, 10), limits).unwrap();
+    assert_eq!((path.len(), cost), (21, 20));
+}
+
+#[test]
+fn cancellation() {
+    let calls = Cell::new(0);
+    let limits = SearchLimits::new().cancel_when(|| {
+        calls.set(calls.get() + 1);
+        calls.get() > 5
+    });
+    let result = try_bfs(&0u32, |&n| vec![n + 1, n + 2], |&n| n == 100, limits);
+    assert_eq!(
+        result,
+        Err(SearchError::Cancelled {
+            expanded: 5,
+            closest: None
+        })
+    );
+    let error = try_dijkstra(
+        &0u32,
+        |&n| vec![(n + 1, 1u64)],
+        |_| false,
+        cancelled_at_once(),
+    );
+    assert_eq!(
+        error,
+        Err(SearchError::Cancelled {
+            expanded: 0,
+            closest: None
+        })
+    );
+    assert_eq!(
+        error.unwrap_err().to_string(),
+        "the search has been cancelled after 0 expansions"
+    );
+}
+
+fn cancelled_at_once() -> SearchLimits<impl FnMut() -> bool> {
+    SearchLimits::default().cancel_when(|| true)
+}
+
+#[test]
+fn overflow() {
+    let result = try_astar(
+        &0u8,
+        |&n| vec![(n.wrapping_add(1), 100u8)],
+        |_| 0,
+        |&n| n == 3,
+        SearchLimits::new(),
+    );
+    assert_eq!(
+        result,
+        Err(SearchError::CostOverflow {
+            path: vec![0, 1, 2]
+        })
+    );
+    // The heuristic added to the cost overflows as well.
+    let result = try_astar(
+        &0u8,
+        |&n| vec![(n + 1, 100u8)],
+        |_| 200,
+        |&n| n == 1,
+        SearchLimits::new(),
+    );
+    assert_eq!(result, Err(SearchError::CostOverflow { path: vec![0] }));
+    assert_eq!(result.unwrap_err().closest(), None);
+    let result = try_bfs(&0u8, |_| Vec::new(), |&n| n == 1, SearchLimits::new());
+    assert_eq!(result, Err(SearchError::NoPath));
+}
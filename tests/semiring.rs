@@ -0,0 +1,121 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+use std::collections::HashSet;
+
+const SIZE: usize = 40;
+
+fn build_network(seed: u8, acyclic: bool) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && (!acyclic || a < b) && rng.random_ratio(1, 8) {
+                network[(a, b)] = rng.random_range(1..20);
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl Fn(&usize) -> Vec<(usize, u32)> + '_ {
+    |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+#[test]
+fn tropical_matches_dijkstra() {
+    for seed in 1..5 {
+        let network = build_network(seed, false);
+        let succ = successors(&network);
+        let weights = semiring_paths(&0, |n| {
+            succ(n).into_iter().map(|(m, c)| (m, Tropical(Some(c))))
+        });
+        let expected = dijkstra_all(&0, &succ);
+        for n in 1..SIZE {
+            assert_eq!(
+                weights.get(&n).map(|w| w.0.unwrap()),
+                expected.get(&n).map(|&(_, c)| c),
+                "cost to {n}"
+            );
+        }
+        assert_eq!(weights[&0], Tropical(Some(0)));
+    }
+}
+
+#[test]
+fn reachability_matches_bfs() {
+    for seed in 1..5 {
+        let network = build_network(seed, false);
+        let succ = successors(&network);
+        let weights = semiring_paths(&0, |n| {
+            succ(n).into_iter().map(|(m, _)| (m, Reachability(true)))
+        });
+        let expected =
+            bfs_reach(0, |n| succ(n).into_iter().map(|(m, _)| m)).collect::<HashSet<_>>();
+        assert_eq!(weights.keys().copied().collect::<HashSet<_>>(), expected);
+        assert!(weights.values().all(|w| w.0));
+        // Edges of weight `false` cannot be followed.
+        let blocked = semiring_paths(&0, |n| {
+            succ(n)
+                .into_iter()
+                .map(|(m, c)| (m, Reachability(c % 2 == 0)))
+        });
+        let expected = bfs_reach(0, |n| {
+            succ(n)
+                .into_iter()
+                .filter(|&(_, c)| c % 2 == 0)
+                .map(|(m, _)| m)
+        })
+        .collect::<HashSet<_>>();
+        assert_eq!(blocked.keys().copied().collect::<HashSet<_>>(), expected);
+    }
+}
+
+#[test]
+fn counting_matches_count_paths() {
+    for seed in 1..5 {
+        let network = build_network(seed, true);
+        let succ = successors(&network);
+        let weights = semiring_paths(&0, |n| {
+            succ(n).into_iter().map(|(m, _)| (m, Counting(1u64)))
+        });
+        for goal in 0..SIZE {
+            let expected = count_paths(0, |n| succ(n).into_iter().map(|(m, _)| m), |&n| n == goal);
+            assert_eq!(
+                weights.get(&goal).map_or(0, |w| w.0),
+                expected as u64,
+                "paths to {goal}"
+            );
+        }
+    }
+}
+
+#[test]
+fn viterbi_matches_dijkstra() {
+    // Probabilities are powers of 2, so that products are exact and the most probable
+    // path is the one with the smallest sum of exponents.
+    for seed in 1..5 {
+        let network = build_network(seed, false);
+        let succ = successors(&network);
+        let weights = semiring_paths(&0, |n| {
+            succ(n)
+                .into_iter()
+                .map(|(m, c)| (m, Viterbi(0.5f64.powi(i32::try_from(c).unwrap()))))
+        });
+        let expected = dijkstra_all(&0, &succ);
+        for n in 1..SIZE {
+            assert_eq!(
+                weights.get(&n).map(|w| w.0),
+                expected
+                    .get(&n)
+                    .map(|&(_, c)| 0.5f64.powi(i32::try_from(c).unwrap())),
+                "probability of {n}"
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 9;
+
+fn build_network(seed: u8, undirected: bool) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if (!undirected || a < b) && rng.random_ratio(if a == b { 1 } else { 3 }, 14) {
+                let cost = rng.random_range(1..20);
+                network[(a, b)] = cost;
+                if undirected {
+                    network[(b, a)] = cost;
+                }
+            }
+        }
+    }
+    network
+}
+
+fn successors(network: &Matrix<u32>) -> impl FnMut(&usize) -> Vec<(usize, u32)> {
+    move |&a| {
+        (0..SIZE)
+            .filter(|&b| network[(a, b)] != 0)
+            .map(|b| (b, network[(a, b)]))
+            .collect()
+    }
+}
+
+// Check that a cycle is elementary and compute its cost.
+fn cycle_cost(network: &Matrix<u32>, cycle: &[usize]) -> u32 {
+    let mut nodes = cycle.to_vec();
+    nodes.sort_unstable();
+    nodes.dedup();
+    assert_eq!(nodes.len(), cycle.len());
+    (0..cycle.len())
+        .map(|i| {
+            let cost = network[(cycle[i], cycle[(i + 1) % cycle.len()])];
+            assert_ne!(cost, 0);
+            cost
+        })
+        .sum()
+}
+
+#[test]
+fn directed_matches_circuits() {
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    for seed in 1..30 {
+        let network = build_network(seed, false);
+        let expected = elementary_circuits(&nodes, |&a| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .collect::<Vec<_>>()
+        })
+        .map(|c| cycle_cost(&network, &c))
+        .min();
+        let result = shortest_cycle(&nodes, successors(&network));
+        assert_eq!(result.as_ref().map(|&(_, c)| c), expected);
+        if let Some((cycle, cost)) = result {
+            assert_eq!(cycle_cost(&network, &cycle), cost);
+        }
+    }
+}
+
+#[test]
+fn undirected_matches_circuits() {
+    let nodes = (0..SIZE).collect::<Vec<_>>();
+    for seed in 1..30 {
+        let network = build_network(seed, true);
+        // Circuits of two nodes go back and forth along the same edge.
+        let expected = elementary_circuits(&nodes, |&a| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .collect::<Vec<_>>()
+        })
+        .filter(|c| c.len() != 2)
+        .map(|c| cycle_cost(&network, &c))
+        .min();
+        let result = shortest_cycle_undirected(&nodes, successors(&network));
+        assert_eq!(result.as_ref().map(|&(_, c)| c), expected);
+        if let Some((cycle, cost)) = result {
+            assert_ne!(cycle.len(), 2);
+            assert_eq!(cycle_cost(&network, &cycle), cost);
+        }
+    }
+}
+
+#[test]
+fn girth_of_grids() {
+    // A grid of 4×4 nodes has squares as shortest cycles.
+    let neighbours = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..4).contains(&x) && (0..4).contains(&y))
+    };
+    let (cycle, length) = girth_undirected(&[(0, 0)], neighbours).unwrap();
+    assert_eq!(length, 4);
+    assert_eq!(cycle.len(), 4);
+    assert_eq!(cycle[0], (0, 0));
+    // As a directed graph, going back and forth is a cycle.
+    assert_eq!(girth(&[(0, 0)], neighbours).map(|(_, l)| l), Some(2));
+}
+
+#[test]
+fn acyclic() {
+    // A binary tree, oriented from the root or not.
+    let children = |&n: &u32| [2 * n + 1, 2 * n + 2].into_iter().filter(|&c| c < 100);
+    assert_eq!(girth(&[0], children), None);
+    let neighbours = |&n: &u32| {
+        children(&n)
+            .chain((n > 0).then(|| (n - 1) / 2))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(girth_undirected(&[0], neighbours), None);
+}
+
+#[test]
+fn self_loops() {
+    assert_eq!(
+        girth(&[0], |&n: &u8| vec![(n + 1).min(10), n]),
+        Some((vec![0], 1))
+    );
+    assert_eq!(
+        shortest_cycle_undirected(&[0], |&n: &u8| vec![(n, 7), (n ^ 1, 1)]),
+        Some((vec![0], 7))
+    );
+}
@@ -0,0 +1,87 @@
+use pathfinding::prelude::*;
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    if n < 30 {
+        vec![(n + 1, 2), (n * 3, 5)]
+    } else {
+        vec![]
+    }
+}
+
+#[test]
+fn dijkstra_tree_matches_dijkstra_all() {
+    let tree = dijkstra_tree(&1, successors);
+    let all = dijkstra_all(&1, successors);
+    assert_eq!(tree.len(), all.len() + 1);
+    assert_eq!(tree.cost_to(&1), Some(0));
+    assert_eq!(tree.parent(&1), None);
+    for (node, &(parent, cost)) in &all {
+        assert_eq!(tree.cost_to(node), Some(cost));
+        assert_eq!(tree.parent(node), Some(&parent));
+        assert_eq!(tree.path_to(node), Some(build_path(node, &all)));
+    }
+}
+
+#[test]
+fn dijkstra_tree_paths_are_optimal() {
+    let tree = dijkstra_tree(&1, successors);
+    for (node, cost) in &tree {
+        let (_, expected) = dijkstra(&1, successors, |n| n == node).unwrap();
+        assert_eq!(cost, expected);
+        let path_cost = tree
+            .path_to(node)
+            .unwrap()
+            .windows(2)
+            .map(|w| {
+                successors(&w[0])
+                    .into_iter()
+                    .find(|&(n, _)| n == w[1])
+                    .unwrap()
+                    .1
+            })
+            .sum::<u32>();
+        assert_eq!(path_cost, expected);
+    }
+}
+
+#[test]
+fn unreachable_nodes() {
+    let tree = dijkstra_tree(&5, successors);
+    assert!(!tree.contains(&1));
+    assert_eq!(tree.cost_to(&1), None);
+    assert_eq!(tree.path_to(&1), None);
+    assert_eq!(tree.parent(&1), None);
+}
+
+#[test]
+fn single_node() {
+    let tree = bfs_tree(&0, |_: &u8| vec![]);
+    assert_eq!(tree.len(), 1);
+    assert!(!tree.is_empty());
+    assert_eq!(tree.start(), &0);
+    assert_eq!(tree.path_to(&0), Some(vec![0]));
+    assert_eq!(tree.iter().collect::<Vec<_>>(), vec![(&0, 0)]);
+}
+
+#[test]
+fn bfs_tree_depths() {
+    // A 5x5 grid with 4-connectivity.
+    let neighbours = |&(x, y): &(i32, i32)| {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+    };
+    let tree = bfs_tree(&(0, 0), neighbours);
+    assert_eq!(tree.len(), 25);
+    for (&(x, y), depth) in &tree {
+        assert_eq!(depth, usize::try_from(x + y).unwrap());
+        assert_eq!(tree.path_to(&(x, y)).unwrap().len(), depth + 1);
+        assert_eq!(
+            bfs(&(0, 0), neighbours, |&n| n == (x, y)).unwrap().len(),
+            depth + 1
+        );
+    }
+    // Nodes are iterated in discovery order, hence by increasing depth.
+    assert!(tree.iter().map(|(_, d)| d).is_sorted());
+}
@@ -0,0 +1,120 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 8;
+
+fn build_network(seed: u8) -> Matrix<bool> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, false);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            network[(a, b)] = a != b && rng.random_ratio(2, 5);
+        }
+    }
+    network
+}
+
+fn brute_force(
+    network: &Matrix<bool>,
+    path: &mut Vec<usize>,
+    goal: usize,
+    found: &mut Vec<Vec<usize>>,
+) {
+    let node = *path.last().unwrap();
+    if node == goal {
+        found.push(path.clone());
+        return;
+    }
+    for next in 0..SIZE {
+        if network[(node, next)] && !path.contains(&next) {
+            path.push(next);
+            brute_force(network, path, goal, found);
+            path.pop();
+        }
+    }
+}
+
+#[test]
+fn matches_brute_force() {
+    for seed in 1..6 {
+        let network = &build_network(seed);
+        let successors = |&a: &usize| (0..SIZE).filter(move |&b| network[(a, b)]);
+        for goal in 0..SIZE {
+            let mut expected = Vec::new();
+            brute_force(network, &mut vec![0], goal, &mut expected);
+            let paths =
+                all_simple_paths(&0, successors, |&n| n == goal, None, None).collect::<Vec<_>>();
+            assert_eq!(paths, expected);
+        }
+    }
+}
+
+#[test]
+fn max_len() {
+    for seed in 1..6 {
+        let network = &build_network(seed);
+        let successors = |&a: &usize| (0..SIZE).filter(move |&b| network[(a, b)]);
+        for goal in 1..SIZE {
+            let all =
+                all_simple_paths(&0, successors, |&n| n == goal, None, None).collect::<Vec<_>>();
+            for max_len in 0..SIZE {
+                let expected = all
+                    .iter()
+                    .filter(|p| p.len() <= max_len + 1)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let paths = all_simple_paths(&0, successors, |&n| n == goal, Some(max_len), None)
+                    .collect::<Vec<_>>();
+                assert_eq!(paths, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn max_count() {
+    let network = &build_network(1);
+    let successors = |&a: &usize| (0..SIZE).filter(move |&b| network[(a, b)]);
+    let all = all_simple_paths(&0, successors, |&n| n == SIZE - 1, None, None).collect::<Vec<_>>();
+    assert!(all.len() > 10);
+    for max_count in [0, 1, 10, all.len(), all.len() + 1] {
+        let paths = all_simple_paths(&0, successors, |&n| n == SIZE - 1, None, Some(max_count))
+            .collect::<Vec<_>>();
+        assert_eq!(paths[..], all[..max_count.min(all.len())]);
+    }
+}
+
+#[test]
+fn start_is_goal() {
+    let paths =
+        all_simple_paths(&0, |&n: &u8| vec![n + 1], |_| true, None, None).collect::<Vec<_>>();
+    assert_eq!(paths, vec![vec![0]]);
+    assert_eq!(
+        all_simple_paths(&0, |&n: &u8| vec![n + 1], |_| true, Some(0), Some(0)).next(),
+        None
+    );
+}
+
+#[test]
+fn unbounded_graph() {
+    // Without a length limit, this would never end since the graph is infinite.
+    let paths = all_simple_paths(
+        &0,
+        |&n: &u32| vec![n + 1, n + 2],
+        |&n| n == 1000,
+        Some(3),
+        None,
+    )
+    .count();
+    assert_eq!(paths, 0);
+    let paths = all_simple_paths(
+        &0,
+        |&n: &u32| vec![n + 1, n + 2],
+        |&n| n == 6,
+        Some(3),
+        None,
+    )
+    .collect::<Vec<_>>();
+    assert_eq!(paths, vec![vec![0, 2, 4, 6]]);
+}
@@ -0,0 +1,113 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_grid(seed: u8, diagonal: bool) -> Grid {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut grid = Grid::new(23, 17);
+    grid.fill();
+    if diagonal {
+        grid.enable_diagonal_mode();
+    }
+    for _ in 0..150 {
+        grid.remove_vertex((rng.random_range(0..23), rng.random_range(0..17)));
+    }
+    grid
+}
+
+fn check(grid: &Grid, max_swamp_size: usize) {
+    let pruned = PrunedGrid::new(grid, max_swamp_size);
+    let vertices = grid.iter().step_by(11).collect::<Vec<_>>();
+    for &start in &vertices {
+        for &goal in &vertices {
+            let optimal = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal);
+            let found = pruned.find_path(start, goal);
+            assert_eq!(optimal.is_some(), found.is_some(), "{start:?} -> {goal:?}");
+            let (Some(optimal), Some((path, cost))) = (optimal, found) else {
+                continue;
+            };
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            assert_eq!(cost, optimal.len() - 1, "{start:?} -> {goal:?}");
+            assert_eq!(cost, path.len() - 1);
+            assert!(
+                path.windows(2).all(|w| grid.has_edge(w[0], w[1])),
+                "invalid path {path:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn random_grids() {
+    for seed in 1..3 {
+        for max_swamp_size in [0, 1, 4] {
+            check(&random_grid(seed, false), max_swamp_size);
+            check(&random_grid(seed, true), max_swamp_size);
+        }
+    }
+}
+
+#[test]
+fn nested_dead_ends() {
+    // A room with a corridor leaving it, and a closet on the side of the corridor.
+    let mut grid = Grid::new(10, 5);
+    for x in 0..2 {
+        for y in 0..5 {
+            grid.add_vertex((x, y));
+        }
+    }
+    for x in 2..10 {
+        grid.add_vertex((x, 2));
+    }
+    grid.add_vertex((5, 1));
+    grid.add_vertex((5, 0));
+    let pruned = PrunedGrid::new(&grid, 0);
+    assert!(pruned.is_dead_end((5, 0)));
+    assert!(pruned.is_dead_end((3, 2)));
+    assert!(!pruned.is_dead_end((0, 0)));
+    // The closet is pruned when going to the end of the corridor, but not the corridor.
+    assert!(pruned.is_pruned((5, 0), (0, 0), (9, 2)));
+    assert!(!pruned.is_pruned((6, 2), (0, 0), (9, 2)));
+    assert!(!pruned.is_pruned((5, 0), (0, 0), (5, 0)));
+    assert!(!pruned.is_pruned((5, 2), (5, 0), (0, 4)));
+    assert_eq!(pruned.find_path((5, 0), (9, 2)).map(|(_, c)| c), Some(6));
+}
+
+#[test]
+fn swamps_in_open_area() {
+    let mut grid = Grid::new(6, 6);
+    grid.fill();
+    grid.enable_diagonal_mode();
+    let pruned = PrunedGrid::new(&grid, 4);
+    assert!(pruned.swamps_len() > 0);
+    let swamp = grid.iter().find(|&v| pruned.swamp(v) == Some(0)).unwrap();
+    let outside = grid
+        .iter()
+        .filter(|&v| pruned.swamp(v).is_none())
+        .collect::<Vec<_>>();
+    for &start in &outside {
+        for &goal in &outside {
+            assert!(pruned.is_pruned(swamp, start, goal));
+            assert_eq!(
+                pruned.find_path(start, goal).map(|(_, c)| c),
+                Some(grid.distance(start, goal))
+            );
+        }
+    }
+    assert!(!pruned.is_pruned(swamp, swamp, (0, 0)));
+}
+
+#[test]
+fn missing_vertices() {
+    let mut grid = Grid::new(3, 3);
+    grid.fill();
+    grid.remove_vertex((1, 1));
+    let pruned = PrunedGrid::new(&grid, 2);
+    assert_eq!(pruned.find_path((0, 0), (1, 1)), None);
+    assert_eq!(pruned.find_path((1, 1), (0, 0)), None);
+    assert!(!pruned.is_dead_end((1, 1)));
+    assert_eq!(pruned.swamp((1, 1)), None);
+    assert!(pruned.neighbours((1, 1), (0, 0), (2, 2)).is_empty());
+    assert_eq!(pruned.find_path((0, 0), (2, 2)).map(|(_, c)| c), Some(4));
+}
@@ -0,0 +1,102 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_grid(seed: u8, diagonal: bool) -> Grid {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut grid = Grid::new(23, 17);
+    grid.fill();
+    if diagonal {
+        grid.enable_diagonal_mode();
+    }
+    for _ in 0..25 {
+        grid.remove_vertex((rng.random_range(0..23), rng.random_range(0..17)));
+    }
+    grid
+}
+
+fn check(grid: &Grid) {
+    let reduced = SymmetryReducedGrid::new(grid);
+    assert!(!reduced.rectangles().is_empty());
+    let vertices = grid.iter().step_by(17).collect::<Vec<_>>();
+    for &start in &vertices {
+        for &goal in &vertices {
+            let optimal = bfs(&start, |&n| grid.neighbours(n), |&n| n == goal);
+            let found = reduced.find_path(start, goal);
+            assert_eq!(optimal.is_some(), found.is_some(), "{start:?} -> {goal:?}");
+            let (Some(optimal), Some((path, cost))) = (optimal, found) else {
+                continue;
+            };
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&goal));
+            assert_eq!(cost, optimal.len() - 1, "{start:?} -> {goal:?}");
+            assert_eq!(cost, path.len() - 1);
+            assert!(
+                path.windows(2).all(|w| grid.has_edge(w[0], w[1])),
+                "invalid path {path:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn random_grids() {
+    for seed in 1..4 {
+        check(&random_grid(seed, false));
+        check(&random_grid(seed, true));
+    }
+}
+
+#[test]
+fn open_area() {
+    let mut grid = Grid::new(10, 8);
+    grid.fill();
+    let reduced = SymmetryReducedGrid::new(&grid);
+    assert_eq!(reduced.rectangles(), &[((0, 0), (9, 7))]);
+    assert!(reduced.is_pruned((4, 4)));
+    assert!(!reduced.is_pruned((0, 4)));
+    // Going through the rectangle only expands its perimeter.
+    assert_eq!(
+        reduced.successors((0, 4), (9, 4)).first(),
+        Some(&((9, 4), 9))
+    );
+    assert_eq!(reduced.find_path((0, 4), (9, 4)).map(|(_, c)| c), Some(9));
+    // Starting and ending inside the rectangle.
+    let (path, cost) = reduced.find_path((2, 2), (6, 5)).unwrap();
+    assert_eq!(cost, 7);
+    assert_eq!(path.len(), 8);
+    grid.enable_diagonal_mode();
+    let reduced = SymmetryReducedGrid::new(&grid);
+    assert_eq!(reduced.find_path((2, 2), (6, 5)).map(|(_, c)| c), Some(4));
+    assert_eq!(reduced.find_path((0, 1), (8, 0)).map(|(_, c)| c), Some(8));
+}
+
+#[test]
+fn expand() {
+    let mut grid = Grid::new(5, 5);
+    grid.fill();
+    let reduced = SymmetryReducedGrid::new(&grid);
+    assert_eq!(
+        reduced.expand(&[(0, 0), (2, 0), (2, 1)]),
+        vec![(0, 0), (1, 0), (2, 0), (2, 1)]
+    );
+    assert_eq!(reduced.expand(&[]), vec![]);
+    grid.enable_diagonal_mode();
+    let reduced = SymmetryReducedGrid::new(&grid);
+    assert_eq!(
+        reduced.expand(&[(0, 0), (3, 2)]),
+        vec![(0, 0), (1, 1), (2, 2), (3, 2)]
+    );
+}
+
+#[test]
+fn narrow_areas() {
+    // Corridors too narrow to contain rectangles with vertices inside them.
+    let mut grid = Grid::new(6, 2);
+    grid.fill();
+    let reduced = SymmetryReducedGrid::new(&grid);
+    assert!(reduced.rectangles().is_empty());
+    assert_eq!(reduced.find_path((0, 0), (5, 1)).map(|(_, c)| c), Some(6));
+    assert_eq!(reduced.find_path((0, 0), (6, 1)), None);
+    assert!(reduced.successors((6, 1), (0, 0)).is_empty());
+}
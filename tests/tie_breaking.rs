@@ -0,0 +1,112 @@
+use pathfinding::num_traits::Zero as _;
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn random_graph(rng: &mut XorShiftRng) -> Vec<Vec<(usize, u32)>> {
+    (0..100)
+        .map(|_| {
+            (0..rng.random_range(0..6))
+                .map(|_| (rng.random_range(0..100), rng.random_range(1..3)))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn shuffled_order() {
+    let successors = |&n: &u32| (n..n + 20).collect::<Vec<_>>();
+    let mut shuffled = shuffled_successors(7, successors);
+    let mut first = shuffled(&3);
+    assert_ne!(first, successors(&3));
+    assert_eq!(shuffled(&3), first);
+    assert_eq!(shuffled_successors(7, successors)(&3), first);
+    assert_ne!(shuffled_successors(8, successors)(&3), first);
+    first.sort_unstable();
+    assert_eq!(first, successors(&3));
+    assert_eq!(shuffled_successors(0, |_: &u32| [1])(&0), vec![1]);
+}
+
+#[test]
+fn shuffled_searches() {
+    let mut rng = XorShiftRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let graph = random_graph(&mut rng);
+        let unweighted = |&n: &usize| graph[n].iter().map(|&(m, _)| m).collect::<Vec<_>>();
+        for goal in 0..20 {
+            let expected = bfs(&0, unweighted, |&n| n == goal);
+            for seed in 0..5 {
+                let result = bfs(&0, shuffled_successors(seed, unweighted), |&n| n == goal);
+                assert_eq!(
+                    expected.as_ref().map(Vec::len),
+                    result.as_ref().map(Vec::len)
+                );
+                assert_eq!(
+                    result,
+                    bfs(&0, shuffled_successors(seed, unweighted), |&n| n == goal)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tie_broken_searches() {
+    let mut rng = XorShiftRng::seed_from_u64(1);
+    let mut different = false;
+    for _ in 0..10 {
+        let graph = random_graph(&mut rng);
+        let successors = |&n: &usize| graph[n].clone();
+        for goal in 0..20 {
+            let expected = dijkstra(&0, successors, |&n| n == goal);
+            let paths = (0..5)
+                .map(|seed| {
+                    let result =
+                        dijkstra(&0, tie_broken_successors(seed, successors), |&n| n == goal);
+                    assert_eq!(
+                        result,
+                        dijkstra(&0, tie_broken_successors(seed, successors), |&n| n == goal)
+                    );
+                    let (path, cost) = result?;
+                    let edges = path.windows(2).map(|w| {
+                        graph[w[0]]
+                            .iter()
+                            .filter(|&&(m, _)| m == w[1])
+                            .map(|&(_, c)| c)
+                            .min()
+                            .unwrap()
+                    });
+                    assert_eq!(edges.sum::<u32>(), cost.cost);
+                    Some((path, cost.cost))
+                })
+                .collect::<Vec<_>>();
+            for path in &paths {
+                assert_eq!(
+                    expected.as_ref().map(|(_, c)| c),
+                    path.as_ref().map(|(_, c)| c)
+                );
+            }
+            different |= paths.iter().any(|p| p != &paths[0]);
+        }
+    }
+    assert!(different);
+}
+
+#[test]
+fn tie_broken_costs() {
+    assert!(TieBroken::<u32>::zero().is_zero());
+    assert_eq!(TieBroken::new(3).tie, 0);
+    assert!(TieBroken { cost: 2, tie: 10 } < TieBroken { cost: 3, tie: 0 });
+    assert!(TieBroken { cost: 2, tie: 1 } < TieBroken { cost: 2, tie: 2 });
+    let sum = TieBroken {
+        cost: 2,
+        tie: u64::MAX,
+    } + TieBroken { cost: 1, tie: 1 };
+    assert_eq!(
+        sum,
+        TieBroken {
+            cost: 3,
+            tie: u64::MAX
+        }
+    );
+}
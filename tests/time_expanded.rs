@@ -0,0 +1,93 @@
+use pathfinding::prelude::*;
+
+// Positions on a line from 0 to 9, where position 5 is blocked at times 2 to 6.
+fn moves(x: u32, time: usize) -> Vec<u32> {
+    [x.wrapping_sub(1), x + 1]
+        .into_iter()
+        .filter(|&n| n <= 9 && (n != 5 || !(2..=6).contains(&(time + 1))))
+        .collect()
+}
+
+#[test]
+fn waiting_for_blocked_position() {
+    let successors = time_expanded_successors_unweighted(|&x, t| moves(x, t), true, 50);
+    let path = bfs(&(0, 0), successors, |&(x, _)| x == 9).unwrap();
+    // Position 5 cannot be entered before time 7.
+    assert!(path.contains(&(5, 7)));
+    assert_eq!(path.last(), Some(&(9, 11)));
+    for w in path.windows(2) {
+        assert_eq!(w[1].1, w[0].1 + 1);
+    }
+}
+
+#[test]
+fn no_waiting() {
+    let successors = time_expanded_successors_unweighted(|&x, t| moves(x, t), false, 50);
+    let path = bfs(&(0, 0), successors, |&(x, _)| x == 9).unwrap();
+    // Without waiting, the agent has to move back and forth.
+    assert!(path.windows(2).all(|w| w[0].0 != w[1].0));
+    assert_eq!(path.last(), Some(&(9, 11)));
+}
+
+#[test]
+fn horizon() {
+    let successors = time_expanded_successors_unweighted(|&x, t| moves(x, t), true, 10);
+    assert_eq!(bfs(&(0, 0), successors, |&(x, _)| x == 9), None);
+    let successors = time_expanded_successors_unweighted(|&x, t| moves(x, t), true, 11);
+    assert!(bfs(&(0, 0), successors, |&(x, _)| x == 9).is_some());
+    let successors = time_expanded_successors(
+        |&x, t| moves(x, t).into_iter().map(|n| (n, 1, 1)),
+        Some(1),
+        10,
+    );
+    assert_eq!(dijkstra(&(0, 0), successors, |&(x, _)| x == 9), None);
+}
+
+#[test]
+fn weighted_matches_unweighted() {
+    for start in 0..10 {
+        let weighted = time_expanded_successors(
+            |&x, t| moves(x, t).into_iter().map(|n| (n, 1, 1)),
+            Some(1),
+            30,
+        );
+        let unweighted = time_expanded_successors_unweighted(|&x, t| moves(x, t), true, 30);
+        let (_, cost) = dijkstra(&(start, 0), weighted, |&(x, _)| x == 9).unwrap();
+        let path = bfs(&(start, 0), unweighted, |&(x, _)| x == 9).unwrap();
+        assert_eq!(cost, path.len() - 1);
+    }
+}
+
+#[test]
+fn durations_and_costs() {
+    // A slow and cheap action, and a fast and expensive one.
+    let successors =
+        time_expanded_successors(|&x: &u32, _| [(x + 1, 3, 1), (x + 1, 1, 5)], None, 9);
+    let (path, cost) = dijkstra(&(0, 0), successors, |&(x, _)| x == 3).unwrap();
+    assert_eq!(path, vec![(0, 0), (1, 3), (2, 6), (3, 9)]);
+    assert_eq!(cost, 3);
+    // With a tighter horizon, some fast actions are needed.
+    let successors =
+        time_expanded_successors(|&x: &u32, _| [(x + 1, 3, 1), (x + 1, 1, 5)], None, 7);
+    let (path, cost) = dijkstra(&(0, 0), successors, |&(x, _)| x == 3).unwrap();
+    assert_eq!(path.last().unwrap().1, 7);
+    assert_eq!(cost, 7);
+}
+
+#[test]
+fn astar_with_time() {
+    let successors = time_expanded_successors(
+        |&x, t| moves(x, t).into_iter().map(|n| (n, 1, 1)),
+        Some(1),
+        30,
+    );
+    let (path, cost) = astar(
+        &(0, 0),
+        successors,
+        |&(x, _)| 9 - x as usize,
+        |&(x, _)| x == 9,
+    )
+    .unwrap();
+    assert_eq!(cost, 11);
+    assert_eq!(path.last(), Some(&(9, 11)));
+}
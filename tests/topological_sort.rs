@@ -114,3 +114,164 @@ fn tsig_self_edge() {
         Err((vec![vec![0], vec![1, 2]], vec![3]))
     );
 }
+
+#[test]
+fn scheduler_respects_dependencies() {
+    use pathfinding::directed::topological_sort::TopologicalScheduler;
+
+    let successors = |&n: &usize| (2..).map(move |m| m * n).take_while(|&p| p < 100);
+    let ints = (1..100).collect_vec();
+    let mut scheduler = TopologicalScheduler::new(&ints, successors);
+    assert_eq!(scheduler.ready_len(), 1);
+    let mut completed = Vec::new();
+    let mut running = Vec::new();
+    while !scheduler.is_finished() {
+        running.extend(scheduler.take_ready());
+        assert_eq!(scheduler.running_len(), running.len());
+        // Complete the tasks in a different order than they were handed out.
+        running.reverse();
+        let n = running.pop().unwrap();
+        for &d in &completed {
+            assert_ne!(d % n, 0, "{n} is completed after {d}");
+        }
+        for d in 1..n {
+            if n % d == 0 {
+                assert!(completed.contains(&d), "{n} runs before {d} is completed");
+            }
+        }
+        assert!(scheduler.complete(&n));
+        assert!(!scheduler.complete(&n));
+        completed.push(n);
+    }
+    assert_eq!(completed.len(), 99);
+    assert!(!scheduler.is_stalled());
+    assert!(scheduler.remaining().is_empty());
+}
+
+#[test]
+fn scheduler_with_cycle() {
+    use pathfinding::directed::topological_sort::TopologicalScheduler;
+
+    // 1 -> 2 -> 3 -> 2, 3 -> 4, 1 -> 5
+    let mut scheduler = TopologicalScheduler::new(&[1, 2, 3, 4, 5], |&n| match n {
+        1 => vec![2, 5],
+        2 => vec![3],
+        3 => vec![2, 4],
+        _ => vec![],
+    });
+    assert!(!scheduler.complete(&1));
+    assert_eq!(scheduler.next_ready(), Some(1));
+    assert_eq!(scheduler.next_ready(), None);
+    assert!(!scheduler.is_stalled());
+    assert!(scheduler.complete(&1));
+    assert_eq!(scheduler.take_ready(), vec![5]);
+    assert!(scheduler.complete(&5));
+    assert!(scheduler.is_stalled());
+    assert!(!scheduler.is_finished());
+    assert_eq!(scheduler.remaining(), vec![2, 3, 4]);
+}
+
+#[test]
+fn scheduler_duplicates() {
+    use pathfinding::directed::topological_sort::TopologicalScheduler;
+
+    let mut scheduler = TopologicalScheduler::new(&['a', 'b', 'a'], |&c| {
+        if c == 'a' { vec!['b', 'b'] } else { vec![] }
+    });
+    assert_eq!(scheduler.take_ready(), vec!['a']);
+    assert!(scheduler.complete(&'a'));
+    assert_eq!(scheduler.take_ready(), vec!['b']);
+    assert!(scheduler.complete(&'b'));
+    assert!(scheduler.is_finished());
+    assert!(!scheduler.complete(&'z'));
+}
+
+#[test]
+fn timed_groups_with_unit_durations() {
+    use pathfinding::directed::topological_sort::topological_sort_into_timed_groups;
+
+    let graphs: [&[&[usize]]; 3] = [
+        &[&[1, 5], &[2], &[3], &[], &[5], &[3]],
+        &[&[1, 2], &[3], &[3], &[]],
+        &[&[1], &[2], &[3], &[2, 4], &[]],
+    ];
+    for succs in graphs {
+        let nodes = (0..succs.len()).collect_vec();
+        let timed =
+            topological_sort_into_timed_groups(&nodes, |&n| succs[n].iter().copied(), |_| 1);
+        let strip = |groups: Vec<(usize, Vec<usize>)>| {
+            groups
+                .into_iter()
+                .enumerate()
+                .map(|(i, (start, group))| {
+                    assert_eq!(start, i);
+                    group
+                })
+                .collect_vec()
+        };
+        assert_eq!(
+            timed.map(strip).map_err(|(g, r)| (strip(g), r)),
+            tsig(succs)
+        );
+    }
+}
+
+#[test]
+fn timed_groups_earliest_starts() {
+    use pathfinding::directed::topological_sort::topological_sort_into_timed_groups;
+
+    // A task depends on all its divisors and lasts as long as its value.
+    let ints = (1..50).rev().collect_vec();
+    let groups = topological_sort_into_timed_groups(
+        &ints,
+        |&n| (2..).map(move |m| m * n).take_while(|&p| p < 50),
+        |&n| n,
+    )
+    .unwrap();
+    assert!(groups.windows(2).all(|w| w[0].0 < w[1].0));
+    assert_eq!(groups.iter().map(|(_, g)| g.len()).sum::<usize>(), 49);
+    for (start, group) in &groups {
+        assert!(group.windows(2).all(|w| w[0] > w[1]));
+        for &n in group {
+            // The earliest start is the longest chain of proper divisors ending with n.
+            let expected = (1..n)
+                .filter(|d| n % d == 0)
+                .map(|d| {
+                    let (s, _) = groups.iter().find(|(_, g)| g.contains(&d)).unwrap();
+                    s + d
+                })
+                .max()
+                .unwrap_or(0);
+            assert_eq!(*start, expected, "start of {n}");
+        }
+    }
+    assert_eq!(
+        groups[1],
+        (
+            1,
+            vec![47, 43, 41, 37, 31, 29, 23, 19, 17, 13, 11, 7, 5, 3, 2]
+        )
+    );
+}
+
+#[test]
+fn timed_groups_with_cycle() {
+    use pathfinding::directed::topological_sort::topological_sort_into_timed_groups;
+
+    // 1 -> 2 -> 3 -> 2, 3 -> 4, 1 -> 5 -> 6, with zero-duration nodes.
+    let result = topological_sort_into_timed_groups(
+        &[1, 2, 3, 4, 5, 6, 5],
+        |&n| match n {
+            1 => vec![2, 5, 5],
+            2 => vec![3],
+            3 => vec![2, 4],
+            5 => vec![6],
+            _ => vec![],
+        },
+        |&n| if n == 5 { 0 } else { 3 },
+    );
+    assert_eq!(
+        result,
+        Err((vec![(0, vec![1]), (3, vec![5, 6])], vec![2, 3, 4]))
+    );
+}
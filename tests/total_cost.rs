@@ -0,0 +1,103 @@
+use pathfinding::prelude::*;
+use pathfinding::undirected::prim::prim;
+use std::collections::HashSet;
+
+fn c(value: f64) -> TotalCost<f64> {
+    TotalCost::new(value)
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u8) -> Vec<(u8, TotalCost<f64>)> {
+    match n {
+        0 => vec![(1, c(0.5)), (2, c(2.25))],
+        1 => vec![(2, c(0.75)), (3, c(3.0))],
+        2 => vec![(3, c(0.5))],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn ordering() {
+    assert!(c(1.5) < c(2.0));
+    assert!(c(f64::NEG_INFINITY) < c(-1e300));
+    assert!(c(f64::INFINITY) > c(f64::MAX));
+    assert_eq!(c(0.0), c(-0.0));
+    let set = [c(0.0), c(-0.0)].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn nan_rejected() {
+    assert_eq!(TotalCost::try_new(f64::NAN), Err(NanCostError));
+    assert_eq!(
+        TotalCost::try_new(1.0f32).map(TotalCost::into_inner),
+        Ok(1.0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "a cost cannot be NaN")]
+fn nan_panics() {
+    let _ = TotalCost::new(f32::NAN);
+}
+
+#[test]
+#[should_panic(expected = "a cost cannot be NaN")]
+fn nan_arithmetic_panics() {
+    let _ = c(f64::INFINITY) + c(f64::NEG_INFINITY);
+}
+
+#[test]
+fn shortest_paths() {
+    let expected = Some((vec![0, 1, 2, 3], c(1.75)));
+    assert_eq!(dijkstra(&0, successors, |&n| n == 3), expected);
+    assert_eq!(astar(&0, successors, |_| c(0.0), |&n| n == 3), expected);
+    assert_eq!(fringe(&0, successors, |_| c(0.0), |&n| n == 3), expected);
+    assert_eq!(idastar(&0, successors, |_| c(0.0), |&n| n == 3), expected);
+    let paths = yen(&0, successors, |&n| n == 3, 3);
+    assert_eq!(
+        paths
+            .iter()
+            .map(|(_, cost)| cost.into_inner())
+            .collect::<Vec<_>>(),
+        vec![1.75, 2.75, 3.5]
+    );
+}
+
+#[test]
+fn spanning_trees() {
+    let edges = vec![(1, 2, c(1.5)), (2, 3, c(0.5)), (1, 3, c(0.75))];
+    let mut tree = kruskal(&edges).map(|(_, _, w)| w).collect::<Vec<_>>();
+    tree.sort();
+    assert_eq!(tree, vec![c(0.5), c(0.75)]);
+    let total: TotalCost<f64> = prim(&edges).into_iter().map(|(_, _, w)| w).sum();
+    assert_eq!(total, c(1.25));
+}
+
+#[test]
+fn assignment() {
+    let weights = Matrix::from_rows(vec![
+        vec![c(1.5), c(2.5), c(0.5)],
+        vec![c(2.0), c(0.5), c(1.0)],
+        vec![c(0.25), c(1.0), c(3.0)],
+    ])
+    .unwrap();
+    let (total, assignments) = kuhn_munkres(&weights);
+    assert_eq!(total, c(7.5));
+    assert_eq!(assignments, vec![1, 0, 2]);
+}
+
+#[test]
+fn max_flow() {
+    let caps = vec![
+        (('s', 'a'), c(2.5)),
+        (('s', 'b'), c(1.0)),
+        (('a', 't'), c(1.5)),
+        (('a', 'b'), c(1.0)),
+        (('b', 't'), c(3.0)),
+    ];
+    let (_, total, _) = edmonds_karp_dense(&['s', 'a', 'b', 't'], &'s', &'t', caps.clone());
+    assert_eq!(total, c(3.5));
+    let (_, total, _) = edmonds_karp_sparse(&['s', 'a', 'b', 't'], &'s', &'t', caps);
+    assert_eq!(total, c(3.5));
+}
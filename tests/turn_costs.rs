@@ -0,0 +1,175 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 30;
+
+fn build_network(seed: u8) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 8) {
+                network[(a, b)] = rng.random_range(1..50);
+            }
+        }
+    }
+    network
+}
+
+#[test]
+fn free_turns_match_dijkstra() {
+    for seed in 1..4 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        for goal in 1..SIZE {
+            let expected = dijkstra(&0, successors, |&n| n == goal);
+            let result = dijkstra_with_turns(&0, successors, |_, _, _| Some(0), |&n| n == goal);
+            assert_eq!(
+                result.as_ref().map(|(_, c)| *c),
+                expected.as_ref().map(|(_, c)| *c)
+            );
+            if let Some((path, cost)) = result {
+                let sum = path.windows(2).map(|w| network[(w[0], w[1])]).sum::<u32>();
+                assert_eq!(sum, cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn turn_costs_are_added() {
+    for seed in 1..4 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        // Every turn through an odd node costs 7, and U-turns are forbidden.
+        let turn_cost =
+            |a: &usize, b: &usize, c: &usize| (a != c).then_some(if b % 2 == 1 { 7 } else { 0 });
+        for goal in 1..SIZE {
+            if let Some((path, cost)) =
+                dijkstra_with_turns(&0, successors, turn_cost, |&n| n == goal)
+            {
+                let edges = path.windows(2).map(|w| network[(w[0], w[1])]).sum::<u32>();
+                let turns = path
+                    .windows(3)
+                    .map(|w| turn_cost(&w[0], &w[1], &w[2]).unwrap())
+                    .sum::<u32>();
+                assert_eq!(edges + turns, cost);
+                assert!(cost >= dijkstra(&0, successors, |&n| n == goal).unwrap().1);
+            }
+        }
+    }
+}
+
+#[test]
+fn forbidden_left_turn() {
+    // A grid of streets without the (0, 2) corner, where turning left at (1, 1) is
+    // forbidden.
+    let successors = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y) && (x, y) != (0, 2))
+            .map(|n| (n, 1))
+            .collect::<Vec<_>>()
+    };
+    let turn_cost = |a: &(i32, i32), b: &(i32, i32), c: &(i32, i32)| {
+        let (d1, d2) = ((b.0 - a.0, b.1 - a.1), (c.0 - b.0, c.1 - b.1));
+        let left = d1.0 * d2.1 - d1.1 * d2.0 > 0;
+        (a != c && !(left && *b == (1, 1))).then_some(0)
+    };
+    // Going from (0, 1) to (1, 2) requires a left turn at (1, 1) which is forbidden.
+    let (path, cost) =
+        dijkstra_with_turns(&(0, 1), successors, turn_cost, |&n| n == (1, 2)).unwrap();
+    assert_eq!(cost, 4);
+    assert!(!path.windows(3).any(|w| w == [(0, 1), (1, 1), (1, 2)]));
+    // Without the restriction, the direct route is used.
+    assert_eq!(
+        dijkstra_with_turns(&(0, 1), successors, |_, _, _| Some(0), |&n| n == (1, 2)),
+        Some((vec![(0, 1), (1, 1), (1, 2)], 2))
+    );
+}
+
+#[test]
+fn revisiting_nodes() {
+    // Turning from 0 to 2 at 1 is forbidden, and the only detour loops through 1 again.
+    let successors = |&n: &u8| match n {
+        0 => vec![(1, 1)],
+        1 => vec![(2, 1), (3, 1)],
+        3 => vec![(4, 1)],
+        4 => vec![(1, 2)],
+        _ => vec![],
+    };
+    let turn_cost = |&from: &u8, _: &u8, &to: &u8| (from != 0 || to != 2).then_some(1);
+    assert_eq!(
+        dijkstra_with_turns(&0, successors, turn_cost, |&n| n == 2),
+        Some((vec![0, 1, 3, 4, 1, 2], 10))
+    );
+    assert_eq!(
+        dijkstra_with_turns(&0, successors, |_, _, _| None, |&n| n == 2),
+        None
+    );
+}
+
+#[test]
+fn line_graph_matches_edge_based_search() {
+    for seed in 1..4 {
+        let network = build_network(seed);
+        let neighbours = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0 || network[(b, a)] != 0)
+                .collect::<Vec<_>>()
+        };
+        for goal in 1..SIZE {
+            // Without restrictions, the line graph is as good as the graph itself.
+            let free = bfs(
+                &(None, 0),
+                line_graph_successors(neighbours, |_, _, _| true),
+                |&(_, n)| n == goal,
+            );
+            let expected = bfs(&0, neighbours, |&n| n == goal);
+            assert_eq!(free.map(|p| p.len()), expected.map(|p| p.len()));
+            // Without U-turns, it matches an edge-based search with unit costs.
+            let no_u_turn = bfs(
+                &(None, 0),
+                line_graph_successors(neighbours, |from, _, to| from != to),
+                |&(_, n)| n == goal,
+            );
+            let expected = dijkstra(
+                &(None, 0),
+                edge_based_successors(
+                    |n| neighbours(n).into_iter().map(|m| (m, 1)),
+                    |from, _, to| (from != to).then_some(0),
+                ),
+                |&(_, n)| n == goal,
+            )
+            .map(|(_, c)| c);
+            assert_eq!(no_u_turn.as_ref().map(|p| p.len() - 1), expected);
+            if let Some(path) = no_u_turn {
+                for w in path.windows(2) {
+                    assert_eq!(w[1].0, Some(w[0].1));
+                    assert_ne!(w[0].0, Some(w[1].1));
+                }
+            }
+            // Labelling edges with their ends gives the same line graph.
+            let labelled = bfs(
+                &(None, 0),
+                labelled_line_graph_successors(
+                    |&n| neighbours(&n).into_iter().map(move |m| (m, (n, m))),
+                    |&(from, _), _, &(_, to)| from != to,
+                ),
+                |&(_, n)| n == goal,
+            );
+            assert_eq!(labelled.map(|p| p.len() - 1), expected);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use pathfinding::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+const SIZE: usize = 40;
+
+fn build_network(seed: u8) -> Matrix<u32> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut network = Matrix::new(SIZE, SIZE, 0);
+    for a in 0..SIZE {
+        for b in 0..SIZE {
+            if a != b && rng.random_ratio(1, 10) {
+                network[(a, b)] = rng.random_range(1..100);
+            }
+        }
+    }
+    network
+}
+
+#[test]
+fn nearest_seed() {
+    for seed in 1..4 {
+        let network = build_network(seed);
+        let successors = |&a: &usize| {
+            (0..SIZE)
+                .filter(|&b| network[(a, b)] != 0)
+                .map(|b| (b, network[(a, b)]))
+                .collect::<Vec<_>>()
+        };
+        let seeds = [3, 17, 31];
+        let regions = voronoi_partition(&seeds, successors);
+        for node in 0..SIZE {
+            let costs = seeds
+                .iter()
+                .filter_map(|s| dijkstra(s, successors, |&n| n == node).map(|(_, c)| (*s, c)))
+                .collect::<Vec<_>>();
+            let Some(&(_, best)) = costs.iter().min_by_key(|&&(_, c)| c) else {
+                assert!(!regions.contains_key(&node), "{node} is reached by no seed");
+                continue;
+            };
+            let (owner, cost) = regions[&node];
+            assert_eq!(cost, best, "cost to {node}");
+            assert!(costs.contains(&(owner, best)), "owner of {node}");
+        }
+    }
+}
+
+#[test]
+fn seeds_own_themselves() {
+    let regions = voronoi_partition(&[1, 1, 4], |&n: &u32| {
+        vec![(n + 1, 1), (n * 2, 1)]
+            .into_iter()
+            .filter(|&(m, _)| m <= 20)
+    });
+    assert_eq!(regions[&1], (1, 0));
+    assert_eq!(regions[&4], (4, 0));
+    assert_eq!(regions[&5], (4, 1));
+    assert_eq!(regions[&3], (1, 2));
+    assert_eq!(regions.len(), 20);
+}
+
+#[test]
+fn no_seeds() {
+    let regions = voronoi_partition(&[], |&n: &u32| vec![(n + 1, 1)]);
+    assert!(regions.is_empty());
+}
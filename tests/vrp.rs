@@ -0,0 +1,95 @@
+use pathfinding::prelude::{Matrix, vrp_cost, vrp_local_search, vrp_savings};
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+fn check_routes(routes: &[Vec<usize>], demands: &[u32], capacity: u32) {
+    let mut served = routes.concat();
+    served.sort_unstable();
+    assert_eq!(served, (1..demands.len()).collect::<Vec<_>>());
+    for route in routes {
+        assert!(!route.is_empty());
+        assert!(route.iter().map(|&c| demands[c]).sum::<u32>() <= capacity);
+    }
+}
+
+#[test]
+fn demand_exceeding_capacity() {
+    assert_eq!(
+        vrp_savings(&[0, 3, 5], 4, |a: usize, b| a.abs_diff(b)),
+        None
+    );
+}
+
+#[test]
+fn no_customer() {
+    let routes = vrp_savings(&[0], 4, |a: usize, b| a.abs_diff(b)).unwrap();
+    assert!(routes.is_empty());
+    assert!(vrp_local_search(routes, &[0], 4, |a: usize, b| a.abs_diff(b)).is_empty());
+}
+
+#[test]
+fn single_vehicle() {
+    // With enough capacity, customers on a line are served in a single trip.
+    let distance = |a: usize, b: usize| a.abs_diff(b);
+    let routes = vrp_savings(&[0, 1, 1, 1, 1], 10, distance).unwrap();
+    assert_eq!(routes.len(), 1);
+    assert_eq!(vrp_cost(&routes, distance), 8);
+}
+
+#[test]
+fn one_customer_per_vehicle() {
+    let distance = |a: usize, b: usize| a.abs_diff(b);
+    let routes = vrp_savings(&[0, 2, 2, 2], 3, distance).unwrap();
+    assert_eq!(routes, vec![vec![1], vec![2], vec![3]]);
+    assert_eq!(
+        vrp_local_search(routes.clone(), &[0, 2, 2, 2], 3, distance),
+        routes
+    );
+}
+
+#[test]
+fn local_search_merges_routes() {
+    // Moving customers makes some routes empty, and those are removed.
+    let distance = |a: usize, b: usize| a.abs_diff(b);
+    let routes = vrp_local_search(vec![vec![1], vec![2], vec![3]], &[0, 1, 1, 1], 3, distance);
+    assert_eq!(routes.len(), 1);
+    assert_eq!(vrp_cost(&routes, distance), 6);
+}
+
+#[test]
+fn local_search_exchanges_customers() {
+    // Customers on the wrong side of the depot can only be exchanged, as moving them
+    // would exceed the capacity.
+    let positions = [0i32, -10, 10, -11, 11];
+    let distance = |a: usize, b: usize| positions[a].abs_diff(positions[b]);
+    let demands = [0, 1, 1, 1, 1];
+    let routes = vrp_local_search(vec![vec![1, 4], vec![3, 2]], &demands, 2, distance);
+    check_routes(&routes, &demands, 2);
+    assert_eq!(vrp_cost(&routes, distance), 44);
+}
+
+#[test]
+fn random_instances() {
+    let mut rng = XorShiftRng::from_seed([7; 16]);
+    for _ in 0..20 {
+        let size = rng.random_range(1..30);
+        let positions = (0..size)
+            .map(|_| (rng.random_range(-50i32..50), rng.random_range(-50i32..50)))
+            .collect::<Vec<_>>();
+        let distances = Matrix::from_fn(size, size, |(a, b)| {
+            positions[a].0.abs_diff(positions[b].0) + positions[a].1.abs_diff(positions[b].1)
+        });
+        let distance = |a, b| distances[(a, b)];
+        let mut demands = (0..size)
+            .map(|_| rng.random_range(1..10))
+            .collect::<Vec<u32>>();
+        demands[0] = 0;
+        let capacity = rng.random_range(10..40);
+        let routes = vrp_savings(&demands, capacity, distance).unwrap();
+        check_routes(&routes, &demands, capacity);
+        let initial_cost = vrp_cost(&routes, distance);
+        let routes = vrp_local_search(routes, &demands, capacity, distance);
+        check_routes(&routes, &demands, capacity);
+        assert!(vrp_cost(&routes, distance) <= initial_cost);
+    }
+}
@@ -0,0 +1,127 @@
+use pathfinding::NodeRefs;
+use pathfinding::prelude::{astar, dijkstra, fringe, idastar};
+use rand::{Rng as _, SeedableRng as _};
+use rand_xorshift::XorShiftRng;
+
+// Leaving 33 is expensive, so the cheapest path to 35 does not start from the entry
+// point closest to it.
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn successors(&n: &u32) -> Vec<(u32, u32)> {
+    let leave = if n == 33 { 20 } else { 1 };
+    vec![(n + 1, leave), (n.saturating_sub(1), leave)]
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+const fn heuristic(&n: &u32) -> u32 {
+    n.abs_diff(35)
+}
+
+#[test]
+fn cheapest_start_is_used() {
+    let entries = [0, 33, 41];
+    let expected = Some(((35..=41).rev().collect::<Vec<_>>(), 6));
+    let starts = || NodeRefs::from_iter(&entries);
+    assert_eq!(dijkstra(starts(), successors, |&n| n == 35), expected);
+    assert_eq!(
+        astar(starts(), successors, heuristic, |&n| n == 35),
+        expected
+    );
+    assert_eq!(
+        fringe(starts(), successors, heuristic, |&n| n == 35),
+        expected
+    );
+    assert_eq!(
+        idastar(starts(), successors, heuristic, |&n| n == 35),
+        expected
+    );
+}
+
+#[test]
+fn start_is_goal() {
+    let entries = [3, 35];
+    let starts = || NodeRefs::from_iter(&entries);
+    let expected = Some((vec![35], 0));
+    assert_eq!(dijkstra(starts(), successors, |&n| n == 35), expected);
+    assert_eq!(
+        astar(starts(), successors, heuristic, |&n| n == 35),
+        expected
+    );
+    assert_eq!(
+        fringe(starts(), successors, heuristic, |&n| n == 35),
+        expected
+    );
+    assert_eq!(
+        idastar(starts(), successors, heuristic, |&n| n == 35),
+        expected
+    );
+}
+
+#[test]
+fn no_start() {
+    let starts = || std::iter::empty::<&u32>().collect::<NodeRefs<_>>();
+    assert_eq!(dijkstra(starts(), successors, |&n| n == 35), None);
+    assert_eq!(astar(starts(), successors, heuristic, |&n| n == 35), None);
+    assert_eq!(fringe(starts(), successors, heuristic, |&n| n == 35), None);
+    assert_eq!(idastar(starts(), successors, heuristic, |&n| n == 35), None);
+}
+
+#[test]
+fn same_cost_as_virtual_start() {
+    // Node 0 is a virtual start linked to the real ones through zero-cost edges.
+    let mut rng = XorShiftRng::from_seed([5; 16]);
+    for _ in 0..50 {
+        let size = rng.random_range(2..15);
+        let edges = (0..size)
+            .map(|_| {
+                (0..3)
+                    .map(|_| (rng.random_range(1..size), rng.random_range(1..10)))
+                    .collect::<Vec<(usize, u32)>>()
+            })
+            .collect::<Vec<_>>();
+        let entries = (0..rng.random_range(1..4))
+            .map(|_| rng.random_range(1..size))
+            .collect::<Vec<_>>();
+        let goal = rng.random_range(1..size);
+        let expected = dijkstra(
+            &0,
+            |&n| {
+                if n == 0 {
+                    entries.iter().map(|&e| (e, 0)).collect()
+                } else {
+                    edges[n].clone()
+                }
+            },
+            |&n| n == goal,
+        )
+        .map(|(path, cost)| (path[1..].to_vec(), cost));
+        let starts = || NodeRefs::from_iter(&entries);
+        let successors = |&n: &usize| edges[n].clone();
+        for result in [
+            dijkstra(starts(), successors, |&n| n == goal),
+            astar(starts(), successors, |_| 0, |&n| n == goal),
+            fringe(starts(), successors, |_| 0, |&n| n == goal),
+            idastar(starts(), successors, |_| 0, |&n| n == goal),
+        ] {
+            assert_eq!(
+                result.as_ref().map(|(_, cost)| cost),
+                expected.as_ref().map(|(_, cost)| cost)
+            );
+            if let Some((path, cost)) = result {
+                assert!(entries.contains(&path[0]));
+                assert_eq!(path.last(), Some(&goal));
+                let total = path
+                    .windows(2)
+                    .map(|w| {
+                        edges[w[0]]
+                            .iter()
+                            .filter(|&&(m, _)| m == w[1])
+                            .map(|&(_, c)| c)
+                            .min()
+                            .unwrap()
+                    })
+                    .sum::<u32>();
+                assert_eq!(total, cost);
+            }
+        }
+    }
+}